@@ -177,6 +177,49 @@ pub fn is_safe_write_path(tool_call: &serde_json::Value, safe_paths: &SafePaths)
     })
 }
 
+/// Whether `lower` (a lowercased tool name) is a par-term MCP tool that hands
+/// the agent what is on the user's screen.  These always need the user's
+/// consent, even in auto-approve mode.
+pub fn is_screen_content_tool(lower: &str) -> bool {
+    ["terminal_screenshot", "terminal_read"]
+        .iter()
+        .any(|name| lower == *name || lower.contains(&format!("par-term-config__{name}")))
+}
+
+/// Whether `lower` (a lowercased tool name) only reads, so it is approved
+/// without a prompt.
+///
+/// SEC-002: `NotebookEdit` / `notebook_edit` is a *write* operation that
+/// modifies notebook cells. It was previously misclassified as read-only,
+/// which caused it to be auto-approved without a path-safety check. It is
+/// intentionally absent here and falls through to the write-tool check (or
+/// escalates to the UI if it does not match any known write-tool name).
+pub fn is_read_only_tool(lower: &str) -> bool {
+    // Typing into the terminal can run commands, so it is never treated as a
+    // read-only par-term tool.
+    let is_par_term_write_tool =
+        lower.contains("par-term-config__terminal_write") || lower == "terminal_write";
+    matches!(
+        lower,
+        "read"
+            | "read_file"
+            | "readfile"
+            | "readtextfile"
+            | "glob"
+            | "grep"
+            | "find"
+            | "list_directory"
+            | "listdirectory"
+            | "toolsearch"
+            | "tool_search"
+            | "config"
+            | "config_update"
+            | "configupdate"
+    ) || (lower.contains("par-term-config")
+        && !is_screen_content_tool(lower)
+        && !is_par_term_write_tool)
+}
+
 /// Handle a `session/request_permission` RPC call from the agent.
 ///
 /// This function:
@@ -269,33 +312,9 @@ pub async fn handle_permission_request(
                 // Write/edit tools require approval unless writing
                 // to a temp directory (shaders dir, /tmp, etc.).
                 let lower = tool_name.to_lowercase();
-                let is_par_term_screenshot_tool = lower
-                    .contains("par-term-config__terminal_screenshot")
-                    || lower == "terminal_screenshot";
+                let is_screen_tool = is_screen_content_tool(&lower);
                 let is_safe_fs_tool = {
-                    // SEC-002: `NotebookEdit` / `notebook_edit` is a *write* operation that
-                    // modifies notebook cells. It was previously misclassified as read-only,
-                    // which caused it to be auto-approved without a path-safety check. It is
-                    // intentionally absent here and falls through to the `is_write_tool` branch
-                    // (or escalates to the UI if it does not match any known write-tool name).
-                    let is_read_only = matches!(
-                        lower.as_str(),
-                        "read"
-                            | "read_file"
-                            | "readfile"
-                            | "readtextfile"
-                            | "glob"
-                            | "grep"
-                            | "find"
-                            | "list_directory"
-                            | "listdirectory"
-                            | "toolsearch"
-                            | "tool_search"
-                            | "config"
-                            | "config_update"
-                            | "configupdate"
-                    ) || (lower.contains("par-term-config")
-                        && !is_par_term_screenshot_tool);
+                    let is_read_only = is_read_only_tool(&lower);
 
                     let is_write_tool = matches!(
                         lower.as_str(),
@@ -329,7 +348,7 @@ pub async fn handle_permission_request(
                 // ever surfacing a permission dialog.
                 let allow = if is_safe_fs_tool {
                     true
-                } else if auto_approve.load(Ordering::Relaxed) && !is_par_term_screenshot_tool {
+                } else if auto_approve.load(Ordering::Relaxed) && !is_screen_tool {
                     // For write-class tools that did not pass is_safe_write_path,
                     // auto_approve cannot bypass the safe-path restriction.
                     let is_write_tool = matches!(
@@ -444,6 +463,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_screen_content_tools_need_consent() {
+        for name in [
+            "terminal_read",
+            "terminal_screenshot",
+            "mcp__par-term-config__terminal_read",
+            "mcp__par-term-config__terminal_screenshot",
+        ] {
+            assert!(is_screen_content_tool(name), "{name}");
+            assert!(!is_read_only_tool(name), "{name} must prompt");
+        }
+        assert!(!is_read_only_tool("mcp__par-term-config__terminal_write"));
+        assert!(is_read_only_tool("mcp__par-term-config__config_update"));
+        assert!(is_read_only_tool("read_file"));
+    }
+
     #[test]
     fn test_safe_write_path_tmp() {
         let safe_paths = make_safe_paths();
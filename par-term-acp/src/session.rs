@@ -73,9 +73,10 @@ pub fn build_session_meta(
 
 /// Build the MCP server descriptor for the embedded `par-term-config` server.
 ///
-/// The MCP server exposes `config_update` and `terminal_screenshot` tools so
-/// the agent can modify settings and capture screenshots without editing
-/// `config.yaml` directly.
+/// The MCP server exposes `config_update`, `terminal_screenshot`,
+/// `terminal_write` and `terminal_read` tools so the agent can modify
+/// settings, capture screenshots and type into or read the focused terminal
+/// without editing `config.yaml` directly.
 ///
 /// # Arguments
 /// * `config_dir` - Path to the par-term configuration directory.
@@ -89,6 +90,10 @@ pub fn build_mcp_server_descriptor(
     let config_update_path = config_dir.join(".config-update.json");
     let screenshot_request_path = config_dir.join(".screenshot-request.json");
     let screenshot_response_path = config_dir.join(".screenshot-response.json");
+    let write_request_path = config_dir.join(".terminal-write-request.json");
+    let write_response_path = config_dir.join(".terminal-write-response.json");
+    let read_request_path = config_dir.join(".terminal-read-request.json");
+    let read_response_path = config_dir.join(".terminal-read-response.json");
 
     let mut mcp_env = vec![
         serde_json::json!({
@@ -103,6 +108,22 @@ pub fn build_mcp_server_descriptor(
             "name": "PAR_TERM_SCREENSHOT_RESPONSE_PATH",
             "value": screenshot_response_path.to_string_lossy(),
         }),
+        serde_json::json!({
+            "name": "PAR_TERM_WRITE_REQUEST_PATH",
+            "value": write_request_path.to_string_lossy(),
+        }),
+        serde_json::json!({
+            "name": "PAR_TERM_WRITE_RESPONSE_PATH",
+            "value": write_response_path.to_string_lossy(),
+        }),
+        serde_json::json!({
+            "name": "PAR_TERM_READ_REQUEST_PATH",
+            "value": read_request_path.to_string_lossy(),
+        }),
+        serde_json::json!({
+            "name": "PAR_TERM_READ_RESPONSE_PATH",
+            "value": read_response_path.to_string_lossy(),
+        }),
     ];
 
     if let Some(fallback_path) = agent_config
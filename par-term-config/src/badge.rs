@@ -0,0 +1,141 @@
+//! Badge format variables.
+//!
+//! Badge formats reference session values with `\(session.name)` placeholders.
+//! This module holds the list of built-in variables, the placeholder parser
+//! shared by the badge renderer and the settings preview, and the helper that
+//! drives variable autocompletion while a format is being typed.
+
+/// Prefix shared by every badge variable name.
+pub const BADGE_VARIABLE_PREFIX: &str = "session.";
+
+/// Built-in badge variables and their descriptions, in display order.
+pub const BUILTIN_BADGE_VARIABLES: &[(&str, &str)] = &[
+    ("session.hostname", "Remote or local hostname"),
+    ("session.username", "Current username"),
+    ("session.path", "Current working directory"),
+    ("session.job", "Foreground job name"),
+    ("session.last_command", "Last executed command"),
+    ("session.profile_name", "Current profile name"),
+    ("session.tty", "TTY device name"),
+    ("session.columns", "Terminal columns"),
+    ("session.rows", "Terminal rows"),
+    ("session.bell_count", "Number of bells received"),
+    ("session.selection", "Currently selected text"),
+    ("session.tmux_pane_title", "tmux pane title"),
+    ("session.exit_code", "Last command exit code"),
+    ("session.current_command", "Currently running command"),
+];
+
+/// A variable available for badge interpolation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BadgeVariable {
+    /// Full variable name, e.g. `session.hostname`
+    pub name: String,
+    /// Human-readable description
+    pub description: String,
+    /// Current value, if the variable is set
+    pub value: Option<String>,
+    /// Whether the variable was set by the user (OSC 1337 SetUserVar)
+    pub user_defined: bool,
+}
+
+impl BadgeVariable {
+    /// The placeholder that references this variable in a badge format.
+    pub fn placeholder(&self) -> String {
+        format!("\\({})", self.name)
+    }
+}
+
+/// Built-in badge variables without values (for UI display before a session reports in).
+pub fn builtin_badge_variables() -> Vec<BadgeVariable> {
+    BUILTIN_BADGE_VARIABLES
+        .iter()
+        .map(|(name, description)| BadgeVariable {
+            name: (*name).to_string(),
+            description: (*description).to_string(),
+            value: None,
+            user_defined: false,
+        })
+        .collect()
+}
+
+/// Replace `\(name)` placeholders in `format` using `lookup`.
+///
+/// Placeholders whose lookup returns `None` expand to an empty string.
+/// An unterminated placeholder consumes the rest of the format.
+pub fn interpolate_badge_placeholders(
+    format: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> String {
+    let mut result = String::with_capacity(format.len());
+    let mut chars = format.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch == '\\' && chars.peek() == Some(&'(') {
+            // Skip the '('
+            chars.next();
+
+            // Collect variable name until ')'
+            let mut var_name = String::new();
+            for c in chars.by_ref() {
+                if c == ')' {
+                    break;
+                }
+                var_name.push(c);
+            }
+
+            if let Some(value) = lookup(&var_name) {
+                result.push_str(&value);
+            }
+        } else {
+            result.push(ch);
+        }
+    }
+
+    result
+}
+
+/// The partial variable name being typed at the end of `format`, if any.
+///
+/// Returns the text after a trailing unclosed `\(` (possibly empty), which
+/// the settings UI uses to filter its autocomplete list.
+pub fn badge_completion_prefix(format: &str) -> Option<&str> {
+    let start = format.rfind("\\(")?;
+    let partial = &format[start + 2..];
+    (!partial.contains(')')).then_some(partial)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolate_uses_lookup_and_drops_unknown() {
+        let result = interpolate_badge_placeholders("\\(session.a)-\\(session.b)!", |name| {
+            (name == "session.a").then(|| "x".to_string())
+        });
+        assert_eq!(result, "x-!");
+    }
+
+    #[test]
+    fn completion_prefix_only_for_unclosed_placeholder() {
+        assert_eq!(
+            badge_completion_prefix("host: \\(session.ho"),
+            Some("session.ho")
+        );
+        assert_eq!(badge_completion_prefix("\\(session.path) \\("), Some(""));
+        assert_eq!(badge_completion_prefix("\\(session.path)"), None);
+        assert_eq!(badge_completion_prefix("plain"), None);
+    }
+
+    #[test]
+    fn builtin_variables_share_prefix() {
+        let vars = builtin_badge_variables();
+        assert_eq!(vars.len(), BUILTIN_BADGE_VARIABLES.len());
+        assert!(
+            vars.iter()
+                .all(|v| v.name.starts_with(BADGE_VARIABLE_PREFIX) && !v.user_defined)
+        );
+        assert_eq!(vars[0].placeholder(), "\\(session.hostname)");
+    }
+}
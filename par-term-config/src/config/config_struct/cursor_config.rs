@@ -25,6 +25,11 @@ pub struct CursorConfig {
     #[serde(default = "crate::defaults::cursor_blink_interval")]
     pub cursor_blink_interval: u64,
 
+    /// Hold the cursor steady while the window is unfocused; blinking
+    /// resumes when focus returns
+    #[serde(default = "crate::defaults::bool_false")]
+    pub cursor_blink_only_focused: bool,
+
     // --- Style ---
     /// Cursor style (block, beam, underline)
     #[serde(default)]
@@ -93,6 +98,28 @@ pub struct CursorConfig {
     #[serde(default = "crate::defaults::cursor_boost_color")]
     pub cursor_boost_color: [u8; 3],
 
+    // --- Morph ---
+    /// Duration in milliseconds of the morph animation when the cursor style
+    /// changes (e.g. block to bar). 0 switches instantly.
+    #[serde(default)]
+    pub cursor_morph_duration_ms: u64,
+
+    // --- Smear ---
+    /// Length in cells of the fading streak drawn along the cursor's path
+    /// when it jumps two or more cells. 0.0 disables the smear.
+    #[serde(default)]
+    pub cursor_smear_length: f32,
+
+    /// Time in milliseconds for a cursor smear streak to fade out
+    #[serde(default = "crate::defaults::cursor_smear_decay_ms")]
+    pub cursor_smear_decay_ms: u64,
+
+    // --- Ligatures ---
+    /// Widen the cursor to cover every cell of a ligature under it
+    /// (e.g. both cells of `=>`). Requires `enable_ligatures`.
+    #[serde(default)]
+    pub cursor_ligature_width: bool,
+
     // --- Unfocused ---
     /// Cursor appearance when window is unfocused
     /// - hollow: Show outline-only block cursor (default, standard terminal behavior)
@@ -107,6 +134,7 @@ impl Default for CursorConfig {
         Self {
             cursor_blink: crate::defaults::bool_false(),
             cursor_blink_interval: crate::defaults::cursor_blink_interval(),
+            cursor_blink_only_focused: crate::defaults::bool_false(),
             cursor_style: CursorStyle::default(),
             cursor_color: crate::defaults::cursor_color(),
             cursor_text_color: None,
@@ -121,6 +149,10 @@ impl Default for CursorConfig {
             cursor_shadow_blur: crate::defaults::cursor_shadow_blur(),
             cursor_boost: crate::defaults::cursor_boost(),
             cursor_boost_color: crate::defaults::cursor_boost_color(),
+            cursor_morph_duration_ms: 0,
+            cursor_smear_length: 0.0,
+            cursor_smear_decay_ms: crate::defaults::cursor_smear_decay_ms(),
+            cursor_ligature_width: false,
             unfocused_cursor_style: UnfocusedCursorStyle::default(),
         }
     }
@@ -6,11 +6,13 @@
 use std::collections::HashMap;
 
 use crate::types::{
-    BackgroundImageMode, BackgroundMode, DividerStyle, DroppedFileQuoteStyle, ImageScalingMode,
+    BackgroundImageMode, BackgroundMode, BackspaceSends, BlendColorSpace, ClipboardScope,
+    CopyLineEnding, DividerStyle, DroppedFileQuoteStyle, FontAxes, FormFeed, ImageScalingMode,
     InstallPromptState, IntegrationVersions, LogLevel, ModifierRemapping, NewTabPosition,
-    OptionKeyMode, PaneTitlePosition, PowerPreference, ProgressBarPosition, ProgressBarStyle,
-    RemoteTabTitleFormat, SemanticHistoryEditorMode, SessionLogFormat, ShaderInstallPrompt,
-    ShellExitAction, TabBarMode, TabBarPosition, TabStyle, TabTitleMode, VsyncMode, WindowType,
+    OptionKeyMode, PaneTitlePosition, PasteBracketMarkers, PasteControlSequences, PowerPreference,
+    PrinterOutput, ProgressBarPosition, ProgressBarStyle, RemoteTabTitleFormat,
+    SemanticHistoryEditorMode, SessionLogFormat, ShaderInstallPrompt, ShellExitAction, TabBarMode,
+    TabBarPosition, TabFontFamily, TabStyle, TabTitleMode, VsyncMode, WindowType,
     default_smart_selection_rules,
 };
 
@@ -31,6 +33,9 @@ impl Default for Config {
             font_family_italic: None,
             font_family_bold_italic: None,
             font_ranges: Vec::new(),
+            font_axes: FontAxes::default(),
+            font_ligature_fallback: None,
+            synthesize_styles: crate::defaults::bool_true(),
             line_spacing: crate::defaults::line_spacing(),
             char_spacing: crate::defaults::char_spacing(),
             enable_text_shaping: crate::defaults::text_shaping(),
@@ -42,6 +47,7 @@ impl Default for Config {
             unicode: UnicodeConfig::default(),
             cursor: CursorConfig::default(),
             scrollbar_autohide_delay: crate::defaults::scrollbar_autohide_delay(),
+            scrollbar_fade_duration: crate::defaults::scrollbar_fade_duration(),
             window_title: crate::defaults::window_title(),
             allow_title_change: crate::defaults::bool_true(),
             theme: crate::defaults::theme(),
@@ -50,14 +56,27 @@ impl Default for Config {
             dark_theme: crate::defaults::dark_theme(),
             left_option_key_mode: OptionKeyMode::default(),
             right_option_key_mode: OptionKeyMode::default(),
+            alt_sends_escape: false,
+            backspace_sends: BackspaceSends::default(),
+            key_repeat_keys: Vec::new(),
+            key_repeat_delay_ms: crate::defaults::key_repeat_delay_ms(),
+            key_repeat_interval_ms: crate::defaults::key_repeat_interval_ms(),
             modifier_remapping: ModifierRemapping::default(),
             use_physical_keys: crate::defaults::bool_false(),
+            keybinding_chord_timeout_ms: crate::defaults::keybinding_chord_timeout_ms(),
+            keybinding_leader: String::new(),
             auto_copy_selection: crate::defaults::bool_true(),
             copy_trailing_newline: crate::defaults::bool_false(),
+            copy_line_ending: CopyLineEnding::default(),
             middle_click_paste: crate::defaults::bool_true(),
             paste_delay_ms: crate::defaults::paste_delay_ms(),
             warn_paste_control_chars: crate::defaults::bool_true(),
+            paste_bracket_markers: PasteBracketMarkers::default(),
+            paste_control_sequences: PasteControlSequences::default(),
+            multiline_paste_suppress_final_newline: crate::defaults::bool_false(),
             dropped_file_quote_style: DroppedFileQuoteStyle::default(),
+            clipboard_scope: ClipboardScope::default(),
+            selection_corner_radius: crate::defaults::selection_corner_radius(),
             mouse: MouseConfig::default(),
             word_characters: crate::defaults::word_characters(),
             smart_selection_enabled: crate::defaults::smart_selection_enabled(),
@@ -66,6 +85,8 @@ impl Default for Config {
             max_fps: crate::defaults::max_fps(),
             vsync_mode: VsyncMode::default(),
             power_preference: PowerPreference::default(),
+            gpu_adapter: None,
+            blend_color_space: BlendColorSpace::default(),
             reduce_flicker: crate::defaults::reduce_flicker(),
             reduce_flicker_delay_ms: crate::defaults::reduce_flicker_delay_ms(),
             maximize_throughput: crate::defaults::maximize_throughput(),
@@ -75,9 +96,14 @@ impl Default for Config {
             target_monitor: None,
             target_space: None,
             lock_window_size: crate::defaults::bool_false(),
+            remember_profile_window_geometry: crate::defaults::bool_false(),
             show_window_number: crate::defaults::bool_false(),
             transparency_affects_only_default_background: crate::defaults::bool_true(),
             keep_text_opaque: crate::defaults::bool_true(),
+            text_shadow_enabled: crate::defaults::bool_false(),
+            text_shadow_color: crate::defaults::text_shadow_color(),
+            text_shadow_offset: crate::defaults::text_shadow_offset(),
+            text_shadow_blur: crate::defaults::text_shadow_blur(),
             background_image: None,
             background_image_enabled: crate::defaults::bool_true(),
             background_image_mode: BackgroundImageMode::default(),
@@ -91,6 +117,7 @@ impl Default for Config {
             last_download_directory: None,
             shader: GlobalShaderConfig::default(),
             shell_exit_action: ShellExitAction::default(),
+            shell_exit_keep_on_failure: crate::defaults::bool_false(),
             custom_shell: None,
             shell_args: None,
             working_directory: None,
@@ -103,8 +130,14 @@ impl Default for Config {
             initial_text_delay_ms: crate::defaults::initial_text_delay_ms(),
             initial_text_send_newline: crate::defaults::initial_text_send_newline(),
             answerback_string: crate::defaults::answerback_string(),
+            printer_output: PrinterOutput::default(),
+            form_feed: FormFeed::default(),
+            coalesce_sgr_resets: crate::defaults::bool_true(),
+            max_line_length: 0,
+            answer_mode_requests: crate::defaults::bool_true(),
             prompt_on_quit: crate::defaults::bool_false(),
             confirm_close_running_jobs: crate::defaults::bool_false(),
+            confirm_foreground_jobs_only: crate::defaults::bool_false(),
             jobs_to_ignore: crate::defaults::jobs_to_ignore(),
             semantic_history_enabled: crate::defaults::bool_true(),
             semantic_history_editor_mode: SemanticHistoryEditorMode::default(),
@@ -112,8 +145,11 @@ impl Default for Config {
             link_highlight_color: crate::defaults::link_highlight_color(),
             link_highlight_color_enabled: crate::defaults::bool_true(),
             link_highlight_underline: crate::defaults::bool_true(),
+            osc8_links_hover_only: crate::defaults::bool_false(),
+            link_hover_tooltip: crate::defaults::bool_true(),
             link_underline_style: crate::types::LinkUnderlineStyle::default(),
             link_handler_command: String::new(),
+            hyperlink_click_modifier: crate::types::HyperlinkClickModifier::default(),
             allow_file_scheme_urls: crate::defaults::bool_false(),
             scrollbar_position: crate::defaults::scrollbar_position(),
             scrollbar_width: crate::defaults::scrollbar_width(),
@@ -129,8 +165,11 @@ impl Default for Config {
             clipboard_max_sync_events: crate::defaults::clipboard_max_sync_events(),
             clipboard_max_event_bytes: crate::defaults::clipboard_max_event_bytes(),
             osc52_clipboard: crate::defaults::osc52_clipboard(),
+            osc52_reassemble_chunks: crate::defaults::bool_true(),
             max_osc_data_length: crate::defaults::max_osc_data_length(),
             command_history_max_entries: crate::defaults::command_history_max_entries(),
+            password_prompt_detection: crate::defaults::bool_true(),
+            mask_password_input: crate::defaults::bool_false(),
             notifications: NotificationConfig::default(),
             ssh: SshConfig::default(),
             tab_style: TabStyle::default(),
@@ -138,6 +177,7 @@ impl Default for Config {
             dark_tab_style: crate::defaults::dark_tab_style(),
             tab_bar_mode: TabBarMode::default(),
             tab_title_mode: TabTitleMode::default(),
+            tab_title_template: String::new(),
             remote_tab_title_format: RemoteTabTitleFormat::default(),
             remote_tab_title_osc_priority: true,
             tab_bar_height: crate::defaults::tab_bar_height(),
@@ -146,6 +186,7 @@ impl Default for Config {
             tab_show_close_button: crate::defaults::bool_true(),
             tab_show_index: crate::defaults::bool_false(),
             tab_inherit_cwd: crate::defaults::bool_true(),
+            tab_inherit_latest_cwd: crate::defaults::bool_false(),
             max_tabs: crate::defaults::zero(),
             show_profile_drawer_button: crate::defaults::bool_false(),
             new_tab_shortcut_shows_profiles: crate::defaults::bool_false(),
@@ -166,9 +207,13 @@ impl Default for Config {
             tab_min_width: crate::defaults::tab_min_width(),
             tab_stretch_to_fill: crate::defaults::tab_stretch_to_fill(),
             tab_html_titles: crate::defaults::tab_html_titles(),
+            tab_hover_preview: false,
+            tab_hover_preview_width: crate::defaults::tab_hover_preview_width(),
             tab_border_color: crate::defaults::tab_border_color(),
             tab_border_width: crate::defaults::tab_border_width(),
             tab_inactive_outline_only: crate::defaults::bool_true(),
+            tab_font_size: 0.0,
+            tab_font_family: TabFontFamily::default(),
             pane_divider_width: crate::defaults::pane_divider_width(),
             pane_divider_hit_width: crate::defaults::pane_divider_hit_width(),
             pane_padding: crate::defaults::pane_padding(),
@@ -178,6 +223,7 @@ impl Default for Config {
             pane_divider_hover_color: crate::defaults::pane_divider_hover_color(),
             dim_inactive_panes: crate::defaults::bool_false(),
             inactive_pane_opacity: crate::defaults::inactive_pane_opacity(),
+            pane_dim_fade_duration: crate::defaults::pane_dim_fade_duration(),
             show_pane_titles: crate::defaults::bool_false(),
             pane_title_height: crate::defaults::pane_title_height(),
             pane_title_position: PaneTitlePosition::default(),
@@ -186,6 +232,9 @@ impl Default for Config {
             pane_title_font: String::new(),
             pane_divider_style: DividerStyle::default(),
             max_panes: crate::defaults::max_panes(),
+            split_on_commands: Vec::new(),
+            split_on_command_direction: Default::default(),
+            split_on_command_percent: crate::defaults::split_on_command_percent(),
             pane_focus_indicator: crate::defaults::bool_true(),
             pane_focus_color: crate::defaults::pane_focus_color(),
             pane_focus_width: crate::defaults::pane_focus_width(),
@@ -195,6 +244,7 @@ impl Default for Config {
             tmux_auto_attach: crate::defaults::bool_false(),
             tmux_auto_attach_session: crate::defaults::tmux_auto_attach_session(),
             tmux_clipboard_sync: crate::defaults::bool_true(),
+            tmux_unwrap_passthrough: crate::defaults::bool_true(),
             tmux_hide_gateway_tab: crate::defaults::bool_false(),
             tmux_profile: None,
             tmux_show_status_bar: crate::defaults::bool_false(),
@@ -4,7 +4,8 @@
 //!
 //! This is the first phase of a multi-phase Config extraction. Only the
 //! font rendering *quality* settings are moved here in this phase:
-//! `font_antialias`, `font_hinting`, `font_thin_strokes`, and `minimum_contrast`.
+//! `font_antialias`, `font_hinting`, `font_thin_strokes`, `minimum_contrast`,
+//! and the glyph atlas sizing knobs.
 //!
 //! The font *selection* fields (`font_family`, `font_size`, `line_spacing`, etc.)
 //! remain inline on `Config` for now — they have 100+ call sites across the workspace
@@ -59,6 +60,20 @@ pub struct FontRenderingConfig {
     /// Range: 0.0 to 1.0
     #[serde(default = "crate::defaults::minimum_contrast")]
     pub minimum_contrast: f32,
+
+    /// Width and height of each glyph atlas page in pixels.
+    ///
+    /// Clamped to the GPU's maximum texture dimension. Takes effect for new windows.
+    #[serde(default = "crate::defaults::glyph_atlas_size")]
+    pub glyph_atlas_size: u32,
+
+    /// Maximum number of glyph atlas pages.
+    ///
+    /// When a page fills up a new one is allocated instead of evicting glyphs;
+    /// the glyph cache is only cleared once every page is full. Clamped to the
+    /// GPU's maximum texture array layer count. Takes effect for new windows.
+    #[serde(default = "crate::defaults::glyph_atlas_max_pages")]
+    pub glyph_atlas_max_pages: u32,
 }
 
 impl Default for FontRenderingConfig {
@@ -68,6 +83,8 @@ impl Default for FontRenderingConfig {
             font_hinting: crate::defaults::bool_true(),
             font_thin_strokes: ThinStrokesMode::default(),
             minimum_contrast: crate::defaults::minimum_contrast(),
+            glyph_atlas_size: crate::defaults::glyph_atlas_size(),
+            glyph_atlas_max_pages: crate::defaults::glyph_atlas_max_pages(),
         }
     }
 }
@@ -87,6 +87,12 @@ pub struct GlobalShaderConfig {
     /// Brightness cap used while readability mode is enabled.
     pub custom_shader_readability_brightness: f32,
 
+    /// Pixels each pane's content area is grown by, on every side, when the
+    /// terminal content is drawn for the shader (full content mode / auto-dim).
+    /// Lets full-bleed effects such as glows extend past the grid edge
+    /// instead of being clipped there. 0 = clip to the pane bounds.
+    pub custom_shader_overscan: f32,
+
     // ========================================================================
     // Cursor Shader Settings (separate from background shader)
     // ========================================================================
@@ -152,6 +158,7 @@ impl Default for GlobalShaderConfig {
             custom_shader_auto_dim_strength: 0.35,
             custom_shader_readability_mode: crate::defaults::bool_false(),
             custom_shader_readability_brightness: 0.35,
+            custom_shader_overscan: 0.0,
             cursor_shader: None,
             cursor_shader_enabled: crate::defaults::bool_false(),
             cursor_shader_animation: crate::defaults::bool_true(),
@@ -94,22 +94,24 @@ pub use global_shader_config::GlobalShaderConfig;
 pub use mouse_config::MouseConfig;
 pub use notification_config::NotificationConfig;
 pub use scrollback_config::ScrollbackConfig;
-pub use search_config::SearchConfig;
+pub use search_config::{KeywordHighlightSet, SearchConfig};
 pub use ssh_config::SshConfig;
 pub use status_bar_config::StatusBarConfig;
 pub use unicode_config::UnicodeConfig;
 pub use update::UpdateConfig;
 pub use window_config::WindowConfig;
 
-use crate::snippets::{CustomActionConfig, SnippetConfig};
+use crate::snippets::{ActionSplitDirection, CustomActionConfig, SnippetConfig};
 use crate::types::{
-    BackgroundImageMode, BackgroundMode, CursorShaderConfig, DividerStyle, DownloadSaveLocation,
-    DroppedFileQuoteStyle, FontRange, ImageScalingMode, InstallPromptState, IntegrationVersions,
+    BackgroundImageMode, BackgroundMode, BackspaceSends, BlendColorSpace, ClipboardScope,
+    CopyLineEnding, CursorShaderConfig, DividerStyle, DownloadSaveLocation, DroppedFileQuoteStyle,
+    FontAxes, FontRange, FormFeed, ImageScalingMode, InstallPromptState, IntegrationVersions,
     KeyBinding, LogLevel, ModifierRemapping, NewTabPosition, OptionKeyMode, PaneTitlePosition,
-    PowerPreference, ProgressBarPosition, ProgressBarStyle, RemoteTabTitleFormat,
-    SemanticHistoryEditorMode, SessionLogFormat, ShaderConfig, ShaderInstallPrompt,
-    ShellExitAction, SmartSelectionRule, StartupDirectoryMode, TabBarMode, TabBarPosition,
-    TabStyle, TabTitleMode, VsyncMode, WindowType,
+    PasteBracketMarkers, PasteControlSequences, PowerPreference, PrinterOutput,
+    ProgressBarPosition, ProgressBarStyle, RemoteTabTitleFormat, SemanticHistoryEditorMode,
+    SessionLogFormat, ShaderConfig, ShaderInstallPrompt, ShellExitAction, SmartSelectionRule,
+    StartupDirectoryMode, TabBarMode, TabBarPosition, TabFontFamily, TabStyle, TabTitleMode,
+    VsyncMode, WindowType,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -183,6 +185,21 @@ pub struct Config {
     #[serde(default)]
     pub font_ranges: Vec<FontRange>,
 
+    /// Variable font axis values per style, e.g. `regular: [["wght", 450]]`,
+    /// to pick exact weights or widths from variable fonts
+    #[serde(default, skip_serializing_if = "FontAxes::is_empty")]
+    pub font_axes: FontAxes,
+
+    /// Ligature-capable font family used for sequences like `=>` and `!=`
+    /// when the primary font does not ligate them (requires enable_ligatures)
+    #[serde(default)]
+    pub font_ligature_fallback: Option<String>,
+
+    /// Embolden and slant the regular font for bold and italic text when no
+    /// bold or italic face is available
+    #[serde(default = "crate::defaults::bool_true")]
+    pub synthesize_styles: bool,
+
     /// Line height multiplier (1.0 = default/tight, 1.2 = comfortable, 1.5 = spacious)
     #[serde(default = "crate::defaults::line_spacing")]
     pub line_spacing: f32,
@@ -249,6 +266,21 @@ pub struct Config {
     #[serde(default)]
     pub power_preference: PowerPreference,
 
+    /// Name of the GPU adapter to render with (e.g. "NVIDIA GeForce RTX 3080").
+    /// When unset or not found, `power_preference` picks the adapter.
+    ///
+    /// Note: Requires app restart to take effect.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gpu_adapter: Option<String>,
+
+    /// Color space that translucent layers and glyph edges are blended in
+    /// - srgb: Blend the gamma-encoded values, as most terminals do (default)
+    /// - linear: Render to an sRGB surface and blend in linear light
+    ///
+    /// Note: Requires app restart to take effect.
+    #[serde(default)]
+    pub blend_color_space: BlendColorSpace,
+
     /// Reduce flicker by delaying redraws while cursor is hidden (DECTCEM off).
     /// Many terminal programs hide cursor during bulk updates to prevent visual artifacts.
     #[serde(default = "crate::defaults::reduce_flicker")]
@@ -300,6 +332,12 @@ pub struct Config {
     #[serde(default = "crate::defaults::bool_false")]
     pub lock_window_size: bool,
 
+    /// Remember window size and position per profile.
+    /// When true, opening a profile from the menu opens it in its own window
+    /// at the size/position that window had when it was last closed.
+    #[serde(default = "crate::defaults::bool_false")]
+    pub remember_profile_window_geometry: bool,
+
     /// Show window number in title bar
     /// Useful when multiple par-term windows are open
     #[serde(default = "crate::defaults::bool_false")]
@@ -316,6 +354,22 @@ pub struct Config {
     #[serde(default = "crate::defaults::bool_true")]
     pub keep_text_opaque: bool,
 
+    /// Draw a drop shadow behind text, for readability over busy background images
+    #[serde(default = "crate::defaults::bool_false")]
+    pub text_shadow_enabled: bool,
+
+    /// Text shadow color [R, G, B, A] (0-255)
+    #[serde(default = "crate::defaults::text_shadow_color")]
+    pub text_shadow_color: [u8; 4],
+
+    /// Text shadow offset in pixels [x, y]
+    #[serde(default = "crate::defaults::text_shadow_offset")]
+    pub text_shadow_offset: [f32; 2],
+
+    /// Text shadow blur radius in pixels (0 = hard shadow)
+    #[serde(default = "crate::defaults::text_shadow_blur")]
+    pub text_shadow_blur: f32,
+
     /// Background image path (optional, supports ~ for home directory)
     #[serde(default)]
     pub background_image: Option<String>,
@@ -400,6 +454,32 @@ pub struct Config {
     #[serde(default)]
     pub right_option_key_mode: OptionKeyMode,
 
+    /// Alt sends an Escape prefix on every platform (xterm's `altSendsEscape`)
+    /// When true, Alt+key sends ESC followed by the key for both Alt keys,
+    /// overriding `left_option_key_mode` and `right_option_key_mode`
+    #[serde(default = "crate::defaults::bool_false")]
+    pub alt_sends_escape: bool,
+
+    /// Byte sent by the Backspace key
+    /// - delete: DEL (0x7F, `^?`) — xterm default
+    /// - backspace: BS (0x08, `^H`) — for hosts configured with `stty erase ^H`
+    #[serde(default)]
+    pub backspace_sends: BackspaceSends,
+
+    /// Keys that repeat at par-term's own rate instead of the OS key repeat
+    /// (winit key names such as `ArrowUp`, `PageDown`, `Backspace`, or single
+    /// characters). Empty = use the OS repeat for every key.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key_repeat_keys: Vec<String>,
+
+    /// Delay before the first app-level repeat of a held key (milliseconds)
+    #[serde(default = "crate::defaults::key_repeat_delay_ms")]
+    pub key_repeat_delay_ms: u32,
+
+    /// Interval between app-level repeats of a held key (milliseconds)
+    #[serde(default = "crate::defaults::key_repeat_interval_ms")]
+    pub key_repeat_interval_ms: u32,
+
     /// Modifier key remapping configuration
     /// Allows remapping modifier keys to different functions (e.g., swap Ctrl and Caps Lock)
     #[serde(default)]
@@ -412,6 +492,16 @@ pub struct Config {
     #[serde(default = "crate::defaults::bool_false")]
     pub use_physical_keys: bool,
 
+    /// Time allowed between the keys of a chord keybinding such as
+    /// `Ctrl+A Ctrl+S` (milliseconds)
+    #[serde(default = "crate::defaults::keybinding_chord_timeout_ms")]
+    pub keybinding_chord_timeout_ms: u64,
+
+    /// Leader key (e.g. "Ctrl+B") that keybindings on plain keys such as
+    /// "C" must follow; empty disables the leader
+    #[serde(default)]
+    pub keybinding_leader: String,
+
     // ========================================================================
     // Selection & Clipboard
     // ========================================================================
@@ -427,6 +517,10 @@ pub struct Config {
     )]
     pub copy_trailing_newline: bool,
 
+    /// Line ending between lines of copied text: lf, crlf or cr
+    #[serde(default)]
+    pub copy_line_ending: CopyLineEnding,
+
     /// Paste on middle mouse button click
     #[serde(default = "crate::defaults::bool_true")]
     pub middle_click_paste: bool,
@@ -449,6 +543,24 @@ pub struct Config {
     #[serde(default = "crate::defaults::bool_true")]
     pub warn_paste_control_chars: bool,
 
+    /// Bracketed paste markers found inside pasted text
+    /// - strip: remove them (avoids stray `[200~` in nested editors)
+    /// - preserve: paste the marker text as-is
+    #[serde(default)]
+    pub paste_bracket_markers: PasteBracketMarkers,
+
+    /// Terminal control sequences (CSI/OSC/DCS) inside pasted text
+    /// - remove_controls: drop the control characters, pasting the rest as text
+    /// - strip: remove whole sequences, e.g. a copied cursor position report
+    /// - allow: paste complete sequences unchanged
+    #[serde(default)]
+    pub paste_control_sequences: PasteControlSequences,
+
+    /// Paste multi-line text without its final newline, so the last line waits
+    /// for Enter instead of running (single-line pastes are unchanged)
+    #[serde(default = "crate::defaults::bool_false")]
+    pub multiline_paste_suppress_final_newline: bool,
+
     /// Quote style for dropped file paths
     /// - single_quotes: Wrap in single quotes (safest for most shells)
     /// - double_quotes: Wrap in double quotes
@@ -457,6 +569,18 @@ pub struct Config {
     #[serde(default)]
     pub dropped_file_quote_style: DroppedFileQuoteStyle,
 
+    /// Where clipboard history slots are stored and read
+    /// - global: shared by every pane in every window
+    /// - per_tab: shared by the panes of one tab
+    /// - per_pane: each pane keeps an isolated history
+    #[serde(default)]
+    pub clipboard_scope: ClipboardScope,
+
+    /// Corner radius in pixels of selection and filled cursor backgrounds
+    /// (0 = square). Only the outer corners of a selection are rounded.
+    #[serde(default = "crate::defaults::selection_corner_radius")]
+    pub selection_corner_radius: f32,
+
     // ========================================================================
     // Mouse — extracted to MouseConfig
     // ========================================================================
@@ -526,6 +650,11 @@ pub struct Config {
     #[serde(default = "crate::defaults::scrollbar_autohide_delay")]
     pub scrollbar_autohide_delay: u64,
 
+    /// Duration of the scrollbar fade-out once the auto-hide delay elapses
+    /// (milliseconds, 0 = hide instantly)
+    #[serde(default = "crate::defaults::scrollbar_fade_duration")]
+    pub scrollbar_fade_duration: u64,
+
     // ========================================================================
     // Theme & Colors
     // ========================================================================
@@ -566,6 +695,12 @@ pub struct Config {
     )]
     pub shell_exit_action: ShellExitAction,
 
+    /// Keep the pane open with an exit status line when the shell exits with a
+    /// non-zero code, instead of applying `shell_exit_action`. A zero exit still
+    /// closes or restarts as configured.
+    #[serde(default = "crate::defaults::bool_false")]
+    pub shell_exit_keep_on_failure: bool,
+
     /// Custom shell command (defaults to system shell if not specified)
     #[serde(default)]
     pub custom_shell: Option<String>,
@@ -627,6 +762,32 @@ pub struct Config {
     #[serde(default = "crate::defaults::answerback_string")]
     pub answerback_string: String,
 
+    /// Where media copy (printer) output between `CSI 5 i` and `CSI 4 i` goes.
+    /// The bytes are kept off screen either way.
+    #[serde(default)]
+    pub printer_output: PrinterOutput,
+
+    /// How form feed (FF, `0x0C`) is handled: ignored, treated as a line
+    /// feed, or clearing the screen.
+    #[serde(default)]
+    pub form_feed: FormFeed,
+
+    /// Drop SGR resets (`CSI 0 m`) that follow another reset with only text
+    /// in between. The attributes are already at their defaults, so the
+    /// output is unchanged while the emulator does less work.
+    #[serde(default = "crate::defaults::bool_true")]
+    pub coalesce_sgr_resets: bool,
+
+    /// Break output lines longer than this many characters, bounding the cost
+    /// of wrapping and scanning a huge line with no newline. 0 = no limit.
+    #[serde(default)]
+    pub max_line_length: usize,
+
+    /// Reply to DECRQM mode queries (`CSI ? Ps $ p`) with the state of the
+    /// mode; when off, the queries go unanswered.
+    #[serde(default = "crate::defaults::bool_true")]
+    pub answer_mode_requests: bool,
+
     /// Show confirmation dialog before quitting the application
     /// When enabled, closing the window will show a confirmation dialog
     /// if there are any open terminal sessions.
@@ -640,6 +801,15 @@ pub struct Config {
     #[serde(default = "crate::defaults::bool_false")]
     pub confirm_close_running_jobs: bool,
 
+    /// Only count a foreground job as running for close and quit confirmation.
+    /// The shell's process group is compared with its terminal's foreground
+    /// process group, so an idle prompt or jobs left in the background do not
+    /// prompt. With `prompt_on_quit`, quitting then only asks when some tab
+    /// has a foreground job not listed in `jobs_to_ignore`.
+    /// Default: false (any child process of the shell counts)
+    #[serde(default = "crate::defaults::bool_false")]
+    pub confirm_foreground_jobs_only: bool,
+
     /// List of job/process names to ignore when checking for running jobs
     /// These jobs will not trigger a close confirmation dialog.
     /// Common examples: "bash", "zsh", "fish", "cat", "less", "man", "sleep"
@@ -687,6 +857,16 @@ pub struct Config {
     #[serde(default = "crate::defaults::bool_true")]
     pub link_highlight_underline: bool,
 
+    /// Only style OSC 8 hyperlinks (underline/color) while the mouse hovers them.
+    /// Regex-detected URLs and file paths are unaffected.
+    #[serde(default = "crate::defaults::bool_false")]
+    pub osc8_links_hover_only: bool,
+
+    /// Show the full target of the link under the mouse in a tooltip, since
+    /// long URLs and OSC 8 link text rarely show the whole address
+    #[serde(default = "crate::defaults::bool_true")]
+    pub link_hover_tooltip: bool,
+
     /// Style for link highlight underlines (solid or stipple)
     #[serde(default)]
     pub link_underline_style: crate::types::LinkUnderlineStyle,
@@ -704,6 +884,11 @@ pub struct Config {
     #[serde(default)]
     pub link_handler_command: String,
 
+    /// Modifier held while clicking to open a link; a click without it
+    /// selects text (`none` opens links on a plain click)
+    #[serde(default)]
+    pub hyperlink_click_modifier: crate::types::HyperlinkClickModifier,
+
     /// Allow Cmd+Click (macOS) / Ctrl+Click to open `file://` OSC 8 hyperlinks
     /// and file URLs via the OS default handler (browser for `.html`, Finder for
     /// directories, etc.).
@@ -787,6 +972,12 @@ pub struct Config {
     #[serde(default = "crate::defaults::osc52_clipboard")]
     pub osc52_clipboard: bool,
 
+    /// Join OSC 52 clipboard writes that arrive in several chunks (or split
+    /// across reads) before the base64 payload is decoded. The joined payload
+    /// is bounded by `max_osc_data_length`.
+    #[serde(default = "crate::defaults::bool_true")]
+    pub osc52_reassemble_chunks: bool,
+
     // ========================================================================
     // OSC Sequence Limits
     // ========================================================================
@@ -803,6 +994,16 @@ pub struct Config {
     #[serde(default = "crate::defaults::command_history_max_entries")]
     pub command_history_max_entries: usize,
 
+    /// Detect password prompts (`Password:`, `Enter passphrase`, ...) and keep
+    /// the line entered after them out of command history
+    #[serde(default = "crate::defaults::bool_true")]
+    pub password_prompt_detection: bool,
+
+    /// Draw input typed at a detected password prompt as bullets
+    /// (only visible when the program echoes it)
+    #[serde(default = "crate::defaults::bool_false")]
+    pub mask_password_input: bool,
+
     // ========================================================================
     // Notifications — extracted to NotificationConfig
     // ========================================================================
@@ -844,6 +1045,16 @@ pub struct Config {
     #[serde(default)]
     pub tab_title_mode: TabTitleMode,
 
+    /// Template for tab titles, e.g. `"{index}: {cwd}"`.
+    ///
+    /// Placeholders: `{cwd}`, `{command}`, `{exit}` (from shell integration) and
+    /// `{index}` (1-based tab position). Unknown values render empty. When set,
+    /// the rendered template replaces OSC/CWD-derived titles; user-named tabs
+    /// are left alone. A profile's `tab_title_template` overrides this.
+    /// Empty (default) disables templating.
+    #[serde(default)]
+    pub tab_title_template: String,
+
     /// Format for tab title when shell integration detects a remote host
     #[serde(default)]
     pub remote_tab_title_format: RemoteTabTitleFormat,
@@ -876,6 +1087,12 @@ pub struct Config {
     #[serde(default = "crate::defaults::bool_true")]
     pub tab_inherit_cwd: bool,
 
+    /// New tabs start in the directory of the most recent `cd` in any tab of
+    /// the window rather than the active tab's (needs `tab_inherit_cwd` and
+    /// shell integration; falls back to the active tab's directory)
+    #[serde(default = "crate::defaults::bool_false")]
+    pub tab_inherit_latest_cwd: bool,
+
     /// Maximum tabs per window (0 = unlimited)
     #[serde(default = "crate::defaults::zero")]
     pub max_tabs: usize,
@@ -968,6 +1185,15 @@ pub struct Config {
     #[serde(default = "crate::defaults::tab_html_titles")]
     pub tab_html_titles: bool,
 
+    /// Show a small live preview of an inactive tab's content when hovering it
+    #[serde(default)]
+    pub tab_hover_preview: bool,
+
+    /// Width of the tab hover preview in pixels (height follows the grid's
+    /// aspect ratio)
+    #[serde(default = "crate::defaults::tab_hover_preview_width")]
+    pub tab_hover_preview_width: f32,
+
     /// Tab border color [R, G, B] (0-255)
     /// A thin border around each tab to help distinguish them
     #[serde(default = "crate::defaults::tab_border_color")]
@@ -983,6 +1209,14 @@ pub struct Config {
     #[serde(default = "crate::defaults::bool_true")]
     pub tab_inactive_outline_only: bool,
 
+    /// Tab title font size in points (0 = egui's default button text size)
+    #[serde(default)]
+    pub tab_font_size: f32,
+
+    /// Tab title font family
+    #[serde(default)]
+    pub tab_font_family: TabFontFamily,
+
     // ========================================================================
     // Split Pane Settings
     // ========================================================================
@@ -1025,6 +1259,11 @@ pub struct Config {
     #[serde(default = "crate::defaults::inactive_pane_opacity")]
     pub inactive_pane_opacity: f32,
 
+    /// Duration of the fade between dim levels when pane focus changes
+    /// (milliseconds, 0 = switch instantly)
+    #[serde(default = "crate::defaults::pane_dim_fade_duration")]
+    pub pane_dim_fade_duration: u64,
+
     /// Show title bar on each pane
     #[serde(default = "crate::defaults::bool_false")]
     pub show_pane_titles: bool,
@@ -1057,6 +1296,20 @@ pub struct Config {
     #[serde(default = "crate::defaults::max_panes")]
     pub max_panes: usize,
 
+    /// Commands that split the pane when they start (requires shell integration).
+    /// The job keeps its pane and a new shell takes focus beside it. Entries
+    /// match the start of the command line, e.g. `cargo` or `npm run dev`.
+    #[serde(default)]
+    pub split_on_commands: Vec<String>,
+
+    /// Where the new shell goes when a `split_on_commands` entry starts
+    #[serde(default)]
+    pub split_on_command_direction: ActionSplitDirection,
+
+    /// Percentage of the split pane kept by the running job (10-90)
+    #[serde(default = "crate::defaults::split_on_command_percent")]
+    pub split_on_command_percent: u8,
+
     /// Show visual indicator (border) around focused pane
     #[serde(default = "crate::defaults::bool_true")]
     pub pane_focus_indicator: bool,
@@ -1097,6 +1350,11 @@ pub struct Config {
     #[serde(default = "crate::defaults::bool_true")]
     pub tmux_clipboard_sync: bool,
 
+    /// Unwrap tmux DCS passthrough (`ESC P tmux; … ESC \`) in tmux pane output
+    /// so escape sequences from a nested tmux session apply.
+    #[serde(default = "crate::defaults::bool_true")]
+    pub tmux_unwrap_passthrough: bool,
+
     /// Hide the tmux control-mode gateway tab while tmux windows are active.
     /// When enabled, the tab running `tmux -CC` is hidden from the tab bar once
     /// the first tmux window tab appears. It is restored when the session ends.
@@ -4,7 +4,7 @@
 //! All fields serialise at the top level of the YAML config file -- existing
 //! config files remain 100% compatible.
 //!
-//! Covers scroll speed, click timing thresholds, option-click cursor movement,
+//! Covers scroll speed and direction, click timing thresholds, option-click cursor movement,
 //! focus-follows-mouse, and horizontal scroll reporting.
 
 use serde::{Deserialize, Serialize};
@@ -15,10 +15,18 @@ use serde::{Deserialize, Serialize};
 /// positioning, focus-follows-mouse, and horizontal scroll reporting.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MouseConfig {
-    /// Mouse wheel scroll speed multiplier
-    #[serde(default = "crate::defaults::scroll_speed")]
+    /// Lines scrolled per mouse wheel notch; fractions accumulate across notches
+    #[serde(
+        default = "crate::defaults::scroll_speed",
+        alias = "scroll_lines_per_wheel"
+    )]
     pub mouse_scroll_speed: f32,
 
+    /// Invert the wheel and trackpad scroll direction, in scrollback and in
+    /// wheel events reported to applications
+    #[serde(default = "crate::defaults::bool_false")]
+    pub natural_scroll_direction: bool,
+
     /// Double-click timing threshold in milliseconds
     #[serde(default = "crate::defaults::double_click_threshold")]
     pub mouse_double_click_threshold: u64,
@@ -27,6 +35,11 @@ pub struct MouseConfig {
     #[serde(default = "crate::defaults::triple_click_threshold")]
     pub mouse_triple_click_threshold: u64,
 
+    /// Distance in pixels the mouse must move while pressed before a selection starts
+    /// Shorter drags count as a plain click, so trackpad jitter doesn't select text
+    #[serde(default = "crate::defaults::selection_drag_threshold_px")]
+    pub selection_drag_threshold_px: f32,
+
     /// Option+Click (macOS) / Alt+Click (Linux/Windows) moves cursor to clicked position
     /// Sends cursor movement escape sequences to position text cursor at click location
     /// Useful for quick cursor positioning in shells and editors
@@ -38,6 +51,15 @@ pub struct MouseConfig {
     #[serde(default = "crate::defaults::bool_false")]
     pub focus_follows_mouse: bool,
 
+    /// Focus a split pane by hovering over it (without requiring a click)
+    #[serde(default = "crate::defaults::bool_false")]
+    pub pane_focus_follows_mouse: bool,
+
+    /// How long the mouse must rest over a pane before it takes focus, in milliseconds
+    /// Passing over a pane on the way to another one does not focus it
+    #[serde(default = "crate::defaults::pane_focus_hover_delay_ms")]
+    pub pane_focus_hover_delay_ms: u64,
+
     /// Report horizontal scroll events to terminal applications when mouse reporting is enabled
     /// Horizontal scroll uses button codes 6 (left) and 7 (right) in the mouse protocol
     #[serde(default = "crate::defaults::bool_true")]
@@ -48,10 +70,14 @@ impl Default for MouseConfig {
     fn default() -> Self {
         Self {
             mouse_scroll_speed: crate::defaults::scroll_speed(),
+            natural_scroll_direction: crate::defaults::bool_false(),
             mouse_double_click_threshold: crate::defaults::double_click_threshold(),
             mouse_triple_click_threshold: crate::defaults::triple_click_threshold(),
+            selection_drag_threshold_px: crate::defaults::selection_drag_threshold_px(),
             option_click_moves_cursor: crate::defaults::bool_true(),
             focus_follows_mouse: crate::defaults::bool_false(),
+            pane_focus_follows_mouse: crate::defaults::bool_false(),
+            pane_focus_hover_delay_ms: crate::defaults::pane_focus_hover_delay_ms(),
             report_horizontal_scroll: crate::defaults::bool_true(),
         }
     }
@@ -4,12 +4,14 @@
 //! All fields serialise at the top level of the YAML config file — existing
 //! config files remain 100% compatible.
 //!
-//! Covers bell (audio, visual, desktop), activity/silence alerts, anti-idle
-//! keep-alive, and OSC 9/777 notification buffer limits.
+//! Covers bell (audio, visual, desktop), activity/silence alerts, command
+//! completion alerts, anti-idle keep-alive, and OSC 9/777 notification buffer
+//! limits.
 
 use crate::types::{AlertEvent, AlertSoundConfig};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::Duration;
 
 /// Notification and alert settings for the terminal emulator.
 ///
@@ -78,6 +80,29 @@ pub struct NotificationConfig {
     #[serde(default = "crate::defaults::bool_false", alias = "session_ended")]
     pub notification_session_ended: bool,
 
+    /// Notify when a command finishes while the window is unfocused
+    /// (requires shell integration)
+    #[serde(default = "crate::defaults::bool_false")]
+    pub notification_command_finished: bool,
+
+    /// Seconds a command must run before its completion is notified
+    #[serde(default = "crate::defaults::command_finished_threshold")]
+    pub notification_command_finished_threshold: u64,
+
+    /// Only notify for commands that exit with a non-zero status
+    #[serde(default = "crate::defaults::bool_false")]
+    pub notification_command_failures_only: bool,
+
+    /// Show notifications applications request with OSC 9, OSC 777 or OSC 99
+    /// as desktop notifications (profiles can override this)
+    #[serde(default = "crate::defaults::bool_true")]
+    pub notification_osc_enabled: bool,
+
+    /// Most application notifications shown per terminal per minute; the
+    /// rest are dropped (0 = no limit)
+    #[serde(default = "crate::defaults::osc_notification_rate_limit")]
+    pub notification_osc_rate_limit: u32,
+
     /// Suppress desktop notifications when the terminal window is focused
     #[serde(default = "crate::defaults::bool_true")]
     pub suppress_notifications_when_focused: bool,
@@ -111,9 +136,106 @@ impl Default for NotificationConfig {
             notification_silence_enabled: crate::defaults::bool_false(),
             notification_silence_threshold: crate::defaults::silence_threshold(),
             notification_session_ended: crate::defaults::bool_false(),
+            notification_command_finished: crate::defaults::bool_false(),
+            notification_command_finished_threshold: crate::defaults::command_finished_threshold(),
+            notification_command_failures_only: crate::defaults::bool_false(),
+            notification_osc_enabled: crate::defaults::bool_true(),
+            notification_osc_rate_limit: crate::defaults::osc_notification_rate_limit(),
             suppress_notifications_when_focused: crate::defaults::bool_true(),
             notification_max_buffer: crate::defaults::notification_max_buffer(),
             alert_sounds: HashMap::new(),
         }
     }
 }
+
+impl NotificationConfig {
+    /// Whether a finished command should raise a desktop notification.
+    ///
+    /// Only commands that ran at least the configured threshold while the
+    /// window was unfocused qualify; a command with an unknown run time does
+    /// not.  With `notification_command_failures_only`, the exit status must
+    /// also be known and non-zero.
+    pub fn should_notify_command_finished(
+        &self,
+        window_focused: bool,
+        duration: Option<Duration>,
+        exit_code: Option<i32>,
+    ) -> bool {
+        let threshold = Duration::from_secs(self.notification_command_finished_threshold);
+        self.notification_command_finished
+            && !window_focused
+            && duration.is_some_and(|duration| duration >= threshold)
+            && (!self.notification_command_failures_only || exit_code.is_some_and(|code| code != 0))
+    }
+
+    /// Whether OSC 9/777/99 notifications reach the desktop for a tab whose
+    /// profile sets `profile_override` (None = follow this config).
+    pub fn osc_notifications_enabled(&self, profile_override: Option<bool>) -> bool {
+        profile_override.unwrap_or(self.notification_osc_enabled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(failures_only: bool) -> NotificationConfig {
+        NotificationConfig {
+            notification_command_finished: true,
+            notification_command_finished_threshold: 10,
+            notification_command_failures_only: failures_only,
+            ..NotificationConfig::default()
+        }
+    }
+
+    const LONG: Option<Duration> = Some(Duration::from_secs(12));
+
+    #[test]
+    fn long_command_in_unfocused_window_notifies() {
+        let config = config(false);
+        assert!(config.should_notify_command_finished(false, LONG, Some(0)));
+        assert!(config.should_notify_command_finished(false, LONG, Some(1)));
+        assert!(config.should_notify_command_finished(false, LONG, None));
+        // The threshold itself is long enough
+        assert!(config.should_notify_command_finished(
+            false,
+            Some(Duration::from_secs(10)),
+            Some(0)
+        ));
+    }
+
+    #[test]
+    fn focused_window_short_or_untimed_commands_do_not_notify() {
+        let config = config(false);
+        assert!(!config.should_notify_command_finished(true, LONG, Some(0)));
+        assert!(!config.should_notify_command_finished(
+            false,
+            Some(Duration::from_millis(9_999)),
+            Some(0)
+        ));
+        assert!(!config.should_notify_command_finished(false, None, Some(1)));
+
+        let disabled = NotificationConfig::default();
+        assert!(!disabled.notification_command_finished);
+        assert!(!disabled.should_notify_command_finished(false, LONG, Some(0)));
+    }
+
+    #[test]
+    fn profile_overrides_osc_notification_setting() {
+        let mut config = NotificationConfig::default();
+        assert!(config.osc_notifications_enabled(None));
+        assert!(!config.osc_notifications_enabled(Some(false)));
+
+        config.notification_osc_enabled = false;
+        assert!(!config.osc_notifications_enabled(None));
+        assert!(config.osc_notifications_enabled(Some(true)));
+    }
+
+    #[test]
+    fn failures_only_requires_a_non_zero_exit() {
+        let config = config(true);
+        assert!(config.should_notify_command_finished(false, LONG, Some(2)));
+        assert!(!config.should_notify_command_finished(false, LONG, Some(0)));
+        assert!(!config.should_notify_command_finished(false, LONG, None));
+    }
+}
@@ -4,22 +4,47 @@
 //! All fields serialise at the top level of the YAML config file — existing
 //! config files remain 100% compatible.
 
+use crate::types::{MarkTrimPolicy, PromptContinuation};
 use serde::{Deserialize, Serialize};
 
 /// Scrollback buffer configuration.
 ///
-/// Controls the number of lines retained in the scrollback history.
+/// Controls the number of lines retained in the scrollback history, whether
+/// repeated output lines are collapsed, and how prompt marks are placed and
+/// kept when old lines are trimmed.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScrollbackConfig {
     /// Maximum number of lines to keep in scrollback buffer
     #[serde(default = "crate::defaults::scrollback", alias = "scrollback_size")]
     pub scrollback_lines: usize,
+
+    /// Collapse consecutive identical output lines into the first one and a
+    /// "[last line repeated N times]" counter line as they are received.
+    /// Never collapses across shell-integration prompt marks, nor on the
+    /// alternate screen.
+    #[serde(default = "crate::defaults::bool_false")]
+    pub collapse_repeated_lines: bool,
+
+    /// What happens to prompt marks whose lines are trimmed once the
+    /// scrollback is full: drop them, or keep the newest one pinned to the
+    /// oldest remaining line.
+    #[serde(default)]
+    pub mark_trim_policy: MarkTrimPolicy,
+
+    /// How OSC 133 continuation prompts (`k=s`, shown while a command spans
+    /// several input lines) are handled: as part of the command being
+    /// entered, or as new prompts with their own marks.
+    #[serde(default)]
+    pub prompt_continuation: PromptContinuation,
 }
 
 impl Default for ScrollbackConfig {
     fn default() -> Self {
         Self {
             scrollback_lines: crate::defaults::scrollback(),
+            collapse_repeated_lines: crate::defaults::bool_false(),
+            mark_trim_policy: MarkTrimPolicy::default(),
+            prompt_continuation: PromptContinuation::default(),
         }
     }
 }
@@ -1,4 +1,4 @@
-//! `SearchConfig` — search settings.
+//! `SearchConfig` — search settings and saved keyword highlight sets.
 
 use serde::{Deserialize, Serialize};
 
@@ -24,6 +24,57 @@ pub struct SearchConfig {
     /// Wrap around when navigating search matches
     #[serde(default = "crate::defaults::bool_true")]
     pub search_wrap_around: bool,
+
+    /// Saved keyword sets highlighted persistently in terminal output
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keyword_highlights: Vec<KeywordHighlightSet>,
+}
+
+/// A named set of keywords highlighted wherever they appear in the output,
+/// e.g. `errors` = `ERROR`, `FATAL` in red.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeywordHighlightSet {
+    /// Name shown in the settings UI
+    pub name: String,
+
+    /// Whether this set is currently highlighted
+    #[serde(default = "crate::defaults::bool_true")]
+    pub enabled: bool,
+
+    /// Literal keywords to highlight
+    #[serde(default)]
+    pub keywords: Vec<String>,
+
+    /// Text color for matches [R, G, B] (None = unchanged)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub foreground: Option<[u8; 3]>,
+
+    /// Background color for matches [R, G, B] (None = unchanged)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background: Option<[u8; 3]>,
+
+    /// Match keywords case-sensitively
+    #[serde(default = "crate::defaults::bool_false")]
+    pub case_sensitive: bool,
+
+    /// Only match whole words (`ERROR` does not match inside `ERRORS`)
+    #[serde(default = "crate::defaults::bool_true")]
+    pub whole_word: bool,
+}
+
+impl KeywordHighlightSet {
+    /// A new enabled set with red text and no keywords.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            enabled: true,
+            keywords: Vec::new(),
+            foreground: Some([255, 85, 85]),
+            background: None,
+            case_sensitive: false,
+            whole_word: true,
+        }
+    }
 }
 
 impl Default for SearchConfig {
@@ -34,6 +85,7 @@ impl Default for SearchConfig {
             search_case_sensitive: crate::defaults::bool_false(),
             search_regex: crate::defaults::bool_false(),
             search_wrap_around: crate::defaults::bool_true(),
+            keyword_highlights: Vec::new(),
         }
     }
 }
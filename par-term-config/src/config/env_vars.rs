@@ -7,10 +7,11 @@
 use regex::Regex;
 use std::sync::LazyLock;
 
-/// Regex pattern for matching `${VAR_NAME}` or `${VAR_NAME:-default_value}` syntax.
+/// Regex pattern for matching `${VAR_NAME}`, `${VAR_NAME:-default_value}` or
+/// `${VAR_NAME:+alternate_value}` syntax.
 /// Compiled once at startup using LazyLock to avoid recompiling on every substitution call.
 static ENV_VAR_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"\$\{([A-Za-z_][A-Za-z0-9_]*)(?::-((?:[^}\\]|\\.)*))?}")
+    Regex::new(r"\$\{([A-Za-z_][A-Za-z0-9_]*)(?::([-+])((?:[^}\\]|\\.)*))?}")
         .expect("env-var substitution regex is a compile-time constant and must be valid")
 });
 
@@ -83,6 +84,9 @@ pub fn is_env_var_allowed(var_name: &str) -> bool {
 /// - If the variable is not set, the `${VAR}` placeholder is left unchanged.
 /// - `$${VAR}` (doubled dollar sign) is an escape and produces the literal `${VAR}`.
 /// - Supports `${VAR:-default}` syntax for providing a default value when the variable is unset.
+/// - Supports `${VAR:+alt}` syntax, which produces `alt` when the variable is set and
+///   nothing when it is unset (e.g. `${PAR_TERM_PROFILE:+profile }`).
+/// - A `}` inside a default or alternate value is written as `\}`.
 ///
 /// Only variables on the allowlist (see [`ALLOWED_ENV_VARS`]) and those prefixed
 /// with `PAR_TERM_` or `LC_` are substituted by default. Non-allowlisted
@@ -119,14 +123,16 @@ pub fn substitute_variables_with_allowlist(input: &str, allow_all: bool) -> Stri
             return caps[0].to_string();
         }
 
-        match std::env::var(var_name) {
-            Ok(val) => val,
-            Err(_) => {
-                // Use default value if provided, otherwise leave the placeholder as-is
-                caps.get(2)
-                    .map(|m| m.as_str().replace("\\}", "}"))
-                    .unwrap_or_else(|| caps[0].to_string())
-            }
+        let word = || caps[3].replace("\\}", "}");
+        match (std::env::var(var_name), caps.get(2).map(|m| m.as_str())) {
+            // `${VAR:+alt}`: the alternate value when set, nothing otherwise
+            (Ok(_), Some("+")) => word(),
+            (Err(_), Some("+")) => String::new(),
+            (Ok(val), _) => val,
+            // `${VAR:-default}`: the default value when unset
+            (Err(_), Some(_)) => word(),
+            // Leave the placeholder as-is
+            (Err(_), None) => caps[0].to_string(),
         }
     });
 
@@ -124,6 +124,7 @@ impl Config {
                         self.keybindings.push(KeyBinding {
                             key: key.clone(),
                             action,
+                            ..Default::default()
                         });
                         added_count += 1;
                     }
@@ -174,6 +175,7 @@ impl Config {
                         self.keybindings.push(KeyBinding {
                             key: key.to_string(),
                             action,
+                            ..Default::default()
                         });
                         added_count += 1;
                     }
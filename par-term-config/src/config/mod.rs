@@ -12,6 +12,7 @@
 //! - [`persistence`] — `impl Config` methods for load/save/path-resolution and session state
 //! - [`theme_methods`] — `impl Config` methods for theme and tab-style application
 //! - [`env_vars`] — Environment-variable allowlist and `${VAR}` substitution
+//! - [`validation`] — `Config::validate` range and reference checks (`ConfigWarning`)
 
 pub mod acp;
 pub mod config_struct;
@@ -20,6 +21,7 @@ pub mod keybindings_methods;
 pub mod path_validation;
 pub mod persistence;
 pub mod theme_methods;
+pub mod validation;
 
 // Re-export the public API so downstream crates keep working with
 // paths like `crate::config::Config`, `crate::config::ALLOWED_ENV_VARS`, etc.
@@ -27,12 +29,14 @@ pub mod theme_methods;
 pub use acp::{CustomAcpAgentActionConfig, CustomAcpAgentConfig};
 pub use config_struct::{
     AiInspectorConfig, AssistantInputHistoryMode, Config, CopyModeConfig, CursorConfig,
-    FontRenderingConfig, GlobalShaderConfig, MouseConfig, NotificationConfig, ScrollbackConfig,
-    SearchConfig, SshConfig, StatusBarConfig, UnicodeConfig, UpdateConfig, WindowConfig,
+    FontRenderingConfig, GlobalShaderConfig, KeywordHighlightSet, MouseConfig, NotificationConfig,
+    ScrollbackConfig, SearchConfig, SshConfig, StatusBarConfig, UnicodeConfig, UpdateConfig,
+    WindowConfig,
 };
 pub use env_vars::{
     ALLOWED_ENV_VARS, is_env_var_allowed, substitute_variables, substitute_variables_with_allowlist,
 };
+pub use validation::{ConfigWarning, ConfigWarningSeverity};
 
 // KeyBinding is referenced in generate_snippet_action_keybindings via `crate::config::KeyBinding`
 pub use crate::types::KeyBinding;
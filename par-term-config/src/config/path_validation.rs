@@ -62,7 +62,7 @@ impl Config {
     ///
     /// Returns `Ok(())` when safe, `Err(ConfigError::PathTraversal)` when the
     /// name contains a parent-directory component.
-    fn validate_shader_name(shader_name: &str) -> Result<(), ConfigError> {
+    pub(crate) fn validate_shader_name(shader_name: &str) -> Result<(), ConfigError> {
         use std::path::Component;
 
         let path = Path::new(shader_name);
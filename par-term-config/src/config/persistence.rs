@@ -1,7 +1,7 @@
 //! Config persistence, path resolution, and session-state methods for `Config`.
 //!
 //! Covers:
-//! - `load` / `save` (YAML file I/O with atomic write)
+//! - `load` / `load_existing` / `save` (YAML file I/O with atomic write)
 //! - XDG-compliant path helpers (`config_path`, `config_dir`, `state_file_path`, etc.)
 //! - Session-state persistence (`save_last_working_directory`, `load_last_working_directory`)
 //! - Startup-directory resolution (`get_effective_startup_directory`)
@@ -18,109 +18,126 @@ use std::path::PathBuf;
 impl Config {
     /// Load configuration from file or create default
     pub fn load() -> Result<Self> {
+        if let Some(config) = Self::load_existing()? {
+            return Ok(config);
+        }
+
+        let config_path = Self::config_path();
+        log::info!(
+            "Config file not found, creating default at {:?}",
+            config_path
+        );
+        // Create default config and save it
+        let mut config = Self::default();
+        // Generate keybindings for snippets and actions
+        config.generate_snippet_action_keybindings();
+        if let Err(e) = config.save() {
+            log::error!("Failed to save default config: {}", e);
+            return Err(e);
+        }
+
+        // Load last working directory from state file (for "previous session" mode)
+        config.load_last_working_directory();
+
+        log::info!("Default config created successfully");
+        Ok(config)
+    }
+
+    /// Load the configuration file without creating it.
+    ///
+    /// Returns `None` when there is no config file, leaving the config
+    /// directory untouched (used by `--check-config`).
+    pub fn load_existing() -> Result<Option<Self>> {
         let config_path = Self::config_path();
         log::info!("Config path: {:?}", config_path);
 
-        if config_path.exists() {
-            // Validate that the config file has not been redirected (e.g. via a
-            // symlink) to a location outside the expected config directory.
-            let config_dir = Self::config_dir();
-            if let Err(e) = Self::validate_config_path(&config_path, &config_dir) {
-                log::error!("Config path validation failed: {e}");
-                return Err(e.into());
-            }
+        if !config_path.exists() {
+            return Ok(None);
+        }
 
-            log::info!("Loading existing config from {:?}", config_path);
-
-            // Security: warn if the config file is readable by group or others.
-            // The config file may contain sensitive values (API keys, SSH paths,
-            // trigger commands) that should not be exposed to other users on a
-            // shared system.
-            #[cfg(unix)]
-            {
-                use std::os::unix::fs::PermissionsExt;
-                if let Ok(metadata) = fs::metadata(&config_path) {
-                    let mode = metadata.permissions().mode();
-                    // Check group-readable (0o040) or world-readable (0o004) bits.
-                    if mode & 0o044 != 0 {
-                        log::warn!(
-                            "Config file {:?} has insecure permissions (mode {:04o}). \
-                             It is readable by group or others, which may expose sensitive \
-                             configuration values. Run: chmod 600 {:?}",
-                            config_path,
-                            mode & 0o777,
-                            config_path,
-                        );
-                    }
-                }
-            }
+        // Validate that the config file has not been redirected (e.g. via a
+        // symlink) to a location outside the expected config directory.
+        let config_dir = Self::config_dir();
+        if let Err(e) = Self::validate_config_path(&config_path, &config_dir) {
+            log::error!("Config path validation failed: {e}");
+            return Err(e.into());
+        }
 
-            let contents = fs::read_to_string(&config_path)?;
-
-            // Pre-scan the raw YAML for `allow_all_env_vars: true` before
-            // variable substitution, since the config isn't parsed yet.
-            let allow_all = super::env_vars::pre_scan_allow_all_env_vars(&contents);
-
-            // SEC-005: Emit a startup warning when allow_all_env_vars: true is detected.
-            // This setting allows any environment variable (including secrets) to be
-            // substituted into config values, which can expose sensitive data if a
-            // shared or imported config file uses ${SECRET_VAR} references.
-            if allow_all {
-                eprintln!(
-                    "[par-term SECURITY WARNING] Config option `allow_all_env_vars: true` is set.\n\
-                     This allows ALL environment variables to be interpolated into config values,\n\
-                     including sensitive variables such as API keys, tokens, and passwords.\n\
-                     A shared or imported config with ${{SENSITIVE_VAR}} references could expose\n\
-                     your secrets. Only use this setting in a non-shared, local-only config.\n\
-                     Recommendation: use a CLAUDE.local.md-style local override, or remove\n\
-                     `allow_all_env_vars: true` and add needed variables to the allowlist instead."
-                );
+        log::info!("Loading existing config from {:?}", config_path);
+
+        // Security: warn if the config file is readable by group or others.
+        // The config file may contain sensitive values (API keys, SSH paths,
+        // trigger commands) that should not be exposed to other users on a
+        // shared system.
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            if let Ok(metadata) = fs::metadata(&config_path) {
+                let mode = metadata.permissions().mode();
+                // Check group-readable (0o040) or world-readable (0o004) bits.
+                if mode & 0o044 != 0 {
+                    log::warn!(
+                        "Config file {:?} has insecure permissions (mode {:04o}). \
+                         It is readable by group or others, which may expose sensitive \
+                         configuration values. Run: chmod 600 {:?}",
+                        config_path,
+                        mode & 0o777,
+                        config_path,
+                    );
+                }
             }
+        }
 
-            let contents =
-                super::env_vars::substitute_variables_with_allowlist(&contents, allow_all);
-            let mut config: Config = serde_yaml_ng::from_str(&contents)?;
+        let contents = fs::read_to_string(&config_path)?;
+
+        // Pre-scan the raw YAML for `allow_all_env_vars: true` before
+        // variable substitution, since the config isn't parsed yet.
+        let allow_all = super::env_vars::pre_scan_allow_all_env_vars(&contents);
+
+        // SEC-005: Emit a startup warning when allow_all_env_vars: true is detected.
+        // This setting allows any environment variable (including secrets) to be
+        // substituted into config values, which can expose sensitive data if a
+        // shared or imported config file uses ${SECRET_VAR} references.
+        if allow_all {
+            eprintln!(
+                "[par-term SECURITY WARNING] Config option `allow_all_env_vars: true` is set.\n\
+                 This allows ALL environment variables to be interpolated into config values,\n\
+                 including sensitive variables such as API keys, tokens, and passwords.\n\
+                 A shared or imported config with ${{SENSITIVE_VAR}} references could expose\n\
+                 your secrets. Only use this setting in a non-shared, local-only config.\n\
+                 Recommendation: use a CLAUDE.local.md-style local override, or remove\n\
+                 `allow_all_env_vars: true` and add needed variables to the allowlist instead."
+            );
+        }
 
-            // Migrate legacy values that may be stored in user configs.
-            config.migrate_legacy_values();
+        let contents = super::env_vars::substitute_variables_with_allowlist(&contents, allow_all);
+        let mut config: Config = serde_yaml_ng::from_str(&contents)?;
 
-            // Warn about triggers with prompt_before_run: false, since the
-            // denylist is the only protection in that mode and it is bypassable.
-            config.warn_insecure_triggers();
+        // Migrate legacy values that may be stored in user configs.
+        config.migrate_legacy_values();
 
-            // Merge in any new default keybindings that don't exist in user's config
-            config.merge_default_keybindings();
+        // Warn about triggers with prompt_before_run: false, since the
+        // denylist is the only protection in that mode and it is bypassable.
+        config.warn_insecure_triggers();
 
-            // Merge in any new default status bar widgets that don't exist in user's config
-            config.merge_default_widgets();
+        // Report out-of-range values and dangling shader references.
+        for warning in config.validate() {
+            log::warn!("Config {}", warning);
+        }
 
-            // Generate keybindings for snippets and actions
-            config.generate_snippet_action_keybindings();
+        // Merge in any new default keybindings that don't exist in user's config
+        config.merge_default_keybindings();
 
-            // Load last working directory from state file (for "previous session" mode)
-            config.load_last_working_directory();
+        // Merge in any new default status bar widgets that don't exist in user's config
+        config.merge_default_widgets();
 
-            Ok(config)
-        } else {
-            log::info!(
-                "Config file not found, creating default at {:?}",
-                config_path
-            );
-            // Create default config and save it
-            let mut config = Self::default();
-            // Generate keybindings for snippets and actions
-            config.generate_snippet_action_keybindings();
-            if let Err(e) = config.save() {
-                log::error!("Failed to save default config: {}", e);
-                return Err(e);
-            }
+        // Generate keybindings for snippets and actions
+        config.generate_snippet_action_keybindings();
 
-            // Load last working directory from state file (for "previous session" mode)
-            config.load_last_working_directory();
+        // Load last working directory from state file (for "previous session" mode)
+        config.load_last_working_directory();
 
-            log::info!("Default config created successfully");
-            Ok(config)
-        }
+        Ok(Some(config))
     }
 
     /// Save configuration to file
@@ -0,0 +1,296 @@
+//! Semantic validation of a loaded `Config`.
+//!
+//! Serde fills in defaults for missing fields but accepts any value of the
+//! right type, so an opacity of `3.0` or a shader file that was renamed only
+//! shows up as odd behaviour at runtime.  [`Config::validate`] checks value
+//! ranges and the files and profiles the config refers to, and returns one
+//! [`ConfigWarning`] per problem for the settings UI, the log, and
+//! `par-term --check-config`.
+
+use super::config_struct::Config;
+use crate::profile_types::ProfileManager;
+use std::fmt;
+use std::path::Path;
+
+/// How serious a [`ConfigWarning`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConfigWarningSeverity {
+    /// The value works but is probably not what was meant
+    Warning,
+    /// The value is invalid and will not behave as configured
+    Error,
+}
+
+impl ConfigWarningSeverity {
+    /// Lowercase label for messages.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// A problem found by [`Config::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigWarning {
+    /// Config key the problem is in, as written in `config.yaml`
+    pub key: String,
+    /// What is wrong, in plain words
+    pub message: String,
+    pub severity: ConfigWarningSeverity,
+}
+
+impl ConfigWarning {
+    fn new(key: impl Into<String>, severity: ConfigWarningSeverity, message: String) -> Self {
+        Self {
+            key: key.into(),
+            message,
+            severity,
+        }
+    }
+
+    /// Whether this is an [`ConfigWarningSeverity::Error`].
+    pub fn is_error(&self) -> bool {
+        self.severity == ConfigWarningSeverity::Error
+    }
+}
+
+impl fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}: {}",
+            self.severity.label(),
+            self.key,
+            self.message
+        )
+    }
+}
+
+/// Largest shader animation speed that is not flagged.
+const MAX_SHADER_ANIMATION_SPEED: f32 = 10.0;
+
+impl Config {
+    /// Check value ranges and the shader files this config refers to.
+    ///
+    /// Profile references need the loaded profiles; use
+    /// [`validate_with`](Self::validate_with) to check those too.
+    pub fn validate(&self) -> Vec<ConfigWarning> {
+        self.validate_with(&Self::shaders_dir(), None)
+    }
+
+    /// Like [`validate`](Self::validate), resolving relative shader names in
+    /// `shaders_dir` and, when given, checking profile references against
+    /// `profiles`.
+    pub fn validate_with(
+        &self,
+        shaders_dir: &Path,
+        profiles: Option<&ProfileManager>,
+    ) -> Vec<ConfigWarning> {
+        let mut warnings = Vec::new();
+        self.validate_ranges(&mut warnings);
+        self.validate_shader_references(shaders_dir, &mut warnings);
+        if let Some(profiles) = profiles {
+            self.validate_profile_references(profiles, &mut warnings);
+        }
+        warnings
+    }
+
+    fn validate_ranges(&self, warnings: &mut Vec<ConfigWarning>) {
+        let unit_ranges = [
+            ("window_opacity", self.window.window_opacity),
+            (
+                "custom_shader_brightness",
+                self.shader.custom_shader_brightness,
+            ),
+            (
+                "custom_shader_text_opacity",
+                self.shader.custom_shader_text_opacity,
+            ),
+        ];
+        for (key, value) in unit_ranges {
+            if !(0.0..=1.0).contains(&value) {
+                warnings.push(ConfigWarning::new(
+                    key,
+                    ConfigWarningSeverity::Error,
+                    format!("{value} is outside 0.0 to 1.0"),
+                ));
+            }
+        }
+
+        let positive = [
+            ("font_size", self.font_size),
+            ("line_spacing", self.line_spacing),
+            ("char_spacing", self.char_spacing),
+        ];
+        for (key, value) in positive {
+            if !(value.is_finite() && value > 0.0) {
+                warnings.push(ConfigWarning::new(
+                    key,
+                    ConfigWarningSeverity::Error,
+                    format!("{value} must be greater than zero"),
+                ));
+            }
+        }
+
+        let speed = self.shader.custom_shader_animation_speed;
+        if !(speed.is_finite() && speed >= 0.0) {
+            warnings.push(ConfigWarning::new(
+                "custom_shader_animation_speed",
+                ConfigWarningSeverity::Error,
+                format!("{speed} must be zero or more"),
+            ));
+        } else if speed > MAX_SHADER_ANIMATION_SPEED {
+            warnings.push(ConfigWarning::new(
+                "custom_shader_animation_speed",
+                ConfigWarningSeverity::Warning,
+                format!("{speed} is more than {MAX_SHADER_ANIMATION_SPEED}x normal speed"),
+            ));
+        }
+    }
+
+    fn validate_shader_references(&self, shaders_dir: &Path, warnings: &mut Vec<ConfigWarning>) {
+        let shaders = [
+            ("custom_shader", &self.shader.custom_shader),
+            ("cursor_shader", &self.shader.cursor_shader),
+        ];
+        for (key, name) in shaders {
+            let Some(name) = name.as_deref().filter(|name| !name.is_empty()) else {
+                continue;
+            };
+            if let Err(e) = Self::validate_shader_name(name) {
+                warnings.push(ConfigWarning::new(
+                    key,
+                    ConfigWarningSeverity::Error,
+                    e.to_string(),
+                ));
+                continue;
+            }
+            let path = shaders_dir.join(name);
+            if !path.is_file() {
+                warnings.push(ConfigWarning::new(
+                    key,
+                    ConfigWarningSeverity::Warning,
+                    format!("shader '{}' not found at {}", name, path.display()),
+                ));
+            }
+        }
+    }
+
+    fn validate_profile_references(
+        &self,
+        profiles: &ProfileManager,
+        warnings: &mut Vec<ConfigWarning>,
+    ) {
+        if let Some(name) = self.tmux_profile.as_deref().filter(|name| !name.is_empty())
+            && profiles.find_by_name(name).is_none()
+        {
+            warnings.push(ConfigWarning::new(
+                "tmux_profile",
+                ConfigWarningSeverity::Warning,
+                format!("no profile is named '{name}'"),
+            ));
+        }
+
+        for profile in profiles.profiles_ordered() {
+            let key = format!("profiles.{}.parent_id", profile.name);
+            if let Some(parent) = profile.parent_id
+                && profiles.get(&parent).is_none()
+            {
+                warnings.push(ConfigWarning::new(
+                    key,
+                    ConfigWarningSeverity::Warning,
+                    format!("parent profile {parent} does not exist"),
+                ));
+            } else if let Err(e) = profiles.resolve(&profile.id) {
+                warnings.push(ConfigWarning::new(
+                    key,
+                    ConfigWarningSeverity::Error,
+                    e.to_string(),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile_types::Profile;
+
+    fn keys(warnings: &[ConfigWarning]) -> Vec<&str> {
+        warnings.iter().map(|w| w.key.as_str()).collect()
+    }
+
+    #[test]
+    fn default_config_is_valid() {
+        let dir = tempfile::tempdir().unwrap();
+        let warnings = Config::default().validate_with(dir.path(), Some(&ProfileManager::new()));
+        assert_eq!(warnings, []);
+    }
+
+    #[test]
+    fn out_of_range_opacity_is_an_error() {
+        let mut config = Config::default();
+        config.window.window_opacity = 1.5;
+        config.font_size = 0.0;
+        let dir = tempfile::tempdir().unwrap();
+
+        let warnings = config.validate_with(dir.path(), None);
+        assert_eq!(keys(&warnings), ["window_opacity", "font_size"]);
+        assert!(warnings.iter().all(ConfigWarning::is_error));
+        assert_eq!(
+            warnings[0].to_string(),
+            "error: window_opacity: 1.5 is outside 0.0 to 1.0"
+        );
+    }
+
+    #[test]
+    fn dangling_shader_reference_is_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("crt.glsl"), "void main() {}").unwrap();
+        let mut config = Config::default();
+        config.shader.custom_shader = Some("crt.glsl".to_string());
+        assert_eq!(config.validate_with(dir.path(), None), []);
+
+        config.shader.custom_shader = Some("missing.glsl".to_string());
+        config.shader.cursor_shader = Some("../escape.glsl".to_string());
+        let warnings = config.validate_with(dir.path(), None);
+        assert_eq!(keys(&warnings), ["custom_shader", "cursor_shader"]);
+        assert_eq!(warnings[0].severity, ConfigWarningSeverity::Warning);
+        assert!(warnings[0].message.contains("'missing.glsl' not found"));
+        assert!(warnings[1].is_error());
+    }
+
+    #[test]
+    fn unresolved_profile_references_are_reported() {
+        let mut a = Profile::new("A");
+        let b = Profile::new("B").parent_id(a.id);
+        a.parent_id = Some(b.id);
+        let orphan = Profile::new("Orphan").parent_id(crate::profile_types::ProfileId::new_v4());
+        let mut profiles = ProfileManager::new();
+        profiles.add(a);
+        profiles.add(b);
+        profiles.add(orphan);
+
+        let config = Config {
+            tmux_profile: Some("Remote".to_string()),
+            ..Default::default()
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let mut warnings = config.validate_with(dir.path(), Some(&profiles));
+        warnings.sort_by(|a, b| a.key.cmp(&b.key));
+        assert_eq!(
+            keys(&warnings),
+            [
+                "profiles.A.parent_id",
+                "profiles.B.parent_id",
+                "profiles.Orphan.parent_id",
+                "tmux_profile"
+            ]
+        );
+        assert!(warnings[0].is_error());
+        assert!(!warnings[2].is_error());
+    }
+}
@@ -115,6 +115,11 @@ pub fn cursor_shadow_color() -> [u8; 4] {
     [0, 0, 0, 128] // Semi-transparent black
 }
 
+/// Default text drop shadow color as RGBA bytes.
+pub fn text_shadow_color() -> [u8; 4] {
+    [0, 0, 0, 180] // Mostly opaque black
+}
+
 /// Default cursor glow boost color as RGB bytes.
 pub fn cursor_boost_color() -> [u8; 3] {
     [255, 255, 255] // White glow
@@ -30,6 +30,16 @@ pub fn minimum_contrast() -> f32 {
     0.0 // Disabled by default (0.0 = no adjustment, matching iTerm2 convention)
 }
 
+/// Default glyph atlas page size in pixels (width and height).
+pub fn glyph_atlas_size() -> u32 {
+    2048
+}
+
+/// Default maximum number of glyph atlas pages before the glyph cache is cleared.
+pub fn glyph_atlas_max_pages() -> u32 {
+    4
+}
+
 /// Default window blur radius in points (macOS only).
 pub fn blur_radius() -> u32 {
     8 // Default blur radius in points (macOS only)
@@ -41,100 +41,122 @@ pub fn keybindings() -> Vec<crate::types::KeyBinding> {
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Shift+B".to_string(),
             action: "toggle_background_shader".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Shift+U".to_string(),
             action: "toggle_cursor_shader".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Shift+V".to_string(),
             action: "paste_special".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Shift+R".to_string(),
             action: "toggle_session_logging".to_string(),
+            ..Default::default()
         },
         // Split pane shortcuts (Cmd+D / Cmd+Shift+D matches iTerm2)
         crate::types::KeyBinding {
             key: "CmdOrCtrl+D".to_string(),
             action: "split_horizontal".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Shift+D".to_string(),
             action: "split_vertical".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Shift+W".to_string(),
             action: "close_pane".to_string(),
+            ..Default::default()
         },
         // Pane navigation shortcuts
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Alt+Left".to_string(),
             action: "navigate_pane_left".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Alt+Right".to_string(),
             action: "navigate_pane_right".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Alt+Up".to_string(),
             action: "navigate_pane_up".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Alt+Down".to_string(),
             action: "navigate_pane_down".to_string(),
+            ..Default::default()
         },
         // Pane resize shortcuts
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Alt+Shift+Left".to_string(),
             action: "resize_pane_left".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Alt+Shift+Right".to_string(),
             action: "resize_pane_right".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Alt+Shift+Up".to_string(),
             action: "resize_pane_up".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Alt+Shift+Down".to_string(),
             action: "resize_pane_down".to_string(),
+            ..Default::default()
         },
         // Broadcast input mode
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Alt+I".to_string(),
             action: "toggle_broadcast_input".to_string(),
+            ..Default::default()
         },
         // Throughput mode toggle
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Shift+T".to_string(),
             action: "toggle_throughput_mode".to_string(),
+            ..Default::default()
         },
         // tmux session picker
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Alt+T".to_string(),
             action: "toggle_tmux_session_picker".to_string(),
+            ..Default::default()
         },
         // Copy mode (vi-style keyboard-driven selection) - matches iTerm2
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Shift+C".to_string(),
             action: "toggle_copy_mode".to_string(),
+            ..Default::default()
         },
         // Command history fuzzy search
         crate::types::KeyBinding {
             key: "CmdOrCtrl+R".to_string(),
             action: "toggle_command_history".to_string(),
+            ..Default::default()
         },
         // Reopen recently closed tab
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Z".to_string(),
             action: "reopen_closed_tab".to_string(),
+            ..Default::default()
         },
         // SSH Quick Connect
         crate::types::KeyBinding {
             key: "CmdOrCtrl+Shift+S".to_string(),
             action: "ssh_quick_connect".to_string(),
+            ..Default::default()
         },
     ];
 
@@ -143,90 +165,109 @@ pub fn keybindings() -> Vec<crate::types::KeyBinding> {
         crate::types::KeyBinding {
             key: "Ctrl+Shift+B".to_string(),
             action: "toggle_background_shader".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "Ctrl+Shift+U".to_string(),
             action: "toggle_cursor_shader".to_string(),
+            ..Default::default()
         },
         // Ctrl+Shift+V is standard paste on Linux terminals, so use Ctrl+Alt+V for paste special
         crate::types::KeyBinding {
             key: "Ctrl+Alt+V".to_string(),
             action: "paste_special".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "Ctrl+Shift+R".to_string(),
             action: "toggle_session_logging".to_string(),
+            ..Default::default()
         },
         // Split pane shortcuts
         // Ctrl+D is EOF/logout - use Ctrl+Shift+D for horizontal split
         crate::types::KeyBinding {
             key: "Ctrl+Shift+D".to_string(),
             action: "split_horizontal".to_string(),
+            ..Default::default()
         },
         // Ctrl+Shift+E for vertical split (Tilix/Terminator convention)
         crate::types::KeyBinding {
             key: "Ctrl+Shift+E".to_string(),
             action: "split_vertical".to_string(),
+            ..Default::default()
         },
         // Ctrl+Shift+W is standard close tab - use Ctrl+Shift+X for close pane
         crate::types::KeyBinding {
             key: "Ctrl+Shift+X".to_string(),
             action: "close_pane".to_string(),
+            ..Default::default()
         },
         // Pane navigation shortcuts
         crate::types::KeyBinding {
             key: "Ctrl+Alt+Left".to_string(),
             action: "navigate_pane_left".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "Ctrl+Alt+Right".to_string(),
             action: "navigate_pane_right".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "Ctrl+Alt+Up".to_string(),
             action: "navigate_pane_up".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "Ctrl+Alt+Down".to_string(),
             action: "navigate_pane_down".to_string(),
+            ..Default::default()
         },
         // Pane resize shortcuts
         crate::types::KeyBinding {
             key: "Ctrl+Alt+Shift+Left".to_string(),
             action: "resize_pane_left".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "Ctrl+Alt+Shift+Right".to_string(),
             action: "resize_pane_right".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "Ctrl+Alt+Shift+Up".to_string(),
             action: "resize_pane_up".to_string(),
+            ..Default::default()
         },
         crate::types::KeyBinding {
             key: "Ctrl+Alt+Shift+Down".to_string(),
             action: "resize_pane_down".to_string(),
+            ..Default::default()
         },
         // Broadcast input mode
         crate::types::KeyBinding {
             key: "Ctrl+Alt+I".to_string(),
             action: "toggle_broadcast_input".to_string(),
+            ..Default::default()
         },
         // Ctrl+Shift+T is standard new tab - use Ctrl+Shift+M for throughput mode
         crate::types::KeyBinding {
             key: "Ctrl+Shift+M".to_string(),
             action: "toggle_throughput_mode".to_string(),
+            ..Default::default()
         },
         // tmux session picker
         crate::types::KeyBinding {
             key: "Ctrl+Alt+T".to_string(),
             action: "toggle_tmux_session_picker".to_string(),
+            ..Default::default()
         },
         // Copy mode (vi-style keyboard-driven selection)
         // Ctrl+Shift+C is standard copy on Linux, so use Ctrl+Shift+Space
         crate::types::KeyBinding {
             key: "Ctrl+Shift+Space".to_string(),
             action: "toggle_copy_mode".to_string(),
+            ..Default::default()
         },
         // Command history fuzzy search
         // Ctrl+R conflicts with terminal reverse search, so use Ctrl+Shift+R
@@ -234,16 +275,19 @@ pub fn keybindings() -> Vec<crate::types::KeyBinding> {
         crate::types::KeyBinding {
             key: "Ctrl+Alt+R".to_string(),
             action: "toggle_command_history".to_string(),
+            ..Default::default()
         },
         // Reopen recently closed tab
         crate::types::KeyBinding {
             key: "Ctrl+Shift+Z".to_string(),
             action: "reopen_closed_tab".to_string(),
+            ..Default::default()
         },
         // SSH Quick Connect
         crate::types::KeyBinding {
             key: "Ctrl+Shift+S".to_string(),
             action: "ssh_quick_connect".to_string(),
+            ..Default::default()
         },
     ];
 
@@ -279,6 +323,30 @@ pub fn cursor_boost() -> f32 {
     0.0 // Disabled by default
 }
 
+/// Default time for a cursor smear streak to fade out, in milliseconds.
+pub fn cursor_smear_decay_ms() -> u64 {
+    150
+}
+
+// ── Text shadow ────────────────────────────────────────────────────────────
+
+/// Default text drop shadow pixel offset as `[x, y]`.
+pub fn text_shadow_offset() -> [f32; 2] {
+    [1.0, 1.0] // 1 pixel down and to the right
+}
+
+/// Default text drop shadow blur radius in pixels.
+pub fn text_shadow_blur() -> f32 {
+    0.0 // Hard shadow
+}
+
+// ── Selection ──────────────────────────────────────────────────────────────
+
+/// Default corner radius of selection and cursor backgrounds in pixels.
+pub fn selection_corner_radius() -> f32 {
+    0.0 // Square corners
+}
+
 // ── Badge ──────────────────────────────────────────────────────────────────
 
 /// Default badge format string.
@@ -372,11 +440,21 @@ pub fn inactive_pane_opacity() -> f32 {
     0.7 // 70% opacity for inactive panes
 }
 
+/// Default fade duration between pane dim levels on focus change (milliseconds).
+pub fn pane_dim_fade_duration() -> u64 {
+    150
+}
+
 /// Default maximum number of panes allowed per tab.
 pub fn max_panes() -> usize {
     16 // Maximum panes per tab
 }
 
+/// Default share of a split-on-command split kept by the running job.
+pub fn split_on_command_percent() -> u8 {
+    50
+}
+
 /// Default pane title bar height in pixels.
 pub fn pane_title_height() -> f32 {
     20.0 // 20 pixel title bar height for panes
@@ -40,6 +40,11 @@ pub fn scrollbar_autohide_delay() -> u64 {
     0 // 0 = never auto-hide (always visible when scrollback exists)
 }
 
+/// Default scrollbar fade-out duration in milliseconds once the auto-hide delay elapses.
+pub fn scrollbar_fade_duration() -> u64 {
+    250
+}
+
 /// Default paste delay in milliseconds between chunks (0 = no delay).
 pub fn paste_delay_ms() -> u64 {
     0 // No delay by default
@@ -84,6 +89,16 @@ pub fn anti_idle_code() -> u8 {
     0 // Default keep-alive code: NUL (0x00)
 }
 
+/// Default minimum run time in seconds for a command completion notification.
+pub fn command_finished_threshold() -> u64 {
+    10
+}
+
+/// Default number of application notifications shown per terminal per minute.
+pub fn osc_notification_rate_limit() -> u32 {
+    10
+}
+
 /// Default silence threshold in seconds before a silence notification fires.
 pub fn silence_threshold() -> u64 {
     300 // 5 minutes
@@ -99,6 +114,21 @@ pub fn scroll_speed() -> f32 {
     3.0 // Lines per scroll tick
 }
 
+/// Default delay before app-level key repeat starts, in milliseconds.
+pub fn key_repeat_delay_ms() -> u32 {
+    500
+}
+
+/// Default interval between app-level key repeats, in milliseconds.
+pub fn key_repeat_interval_ms() -> u32 {
+    33 // ~30 repeats per second
+}
+
+/// Default time allowed between the keys of a chord keybinding, in milliseconds.
+pub fn keybinding_chord_timeout_ms() -> u64 {
+    1000
+}
+
 /// Default double-click interval threshold in milliseconds.
 pub fn double_click_threshold() -> u64 {
     500 // 500 milliseconds
@@ -109,6 +139,16 @@ pub fn triple_click_threshold() -> u64 {
     500 // 500 milliseconds (same as double-click)
 }
 
+/// Default hover time before a pane takes focus under focus-follows-mouse, in milliseconds.
+pub fn pane_focus_hover_delay_ms() -> u64 {
+    150
+}
+
+/// Default distance, in pixels, a press must move before it starts a selection.
+pub fn selection_drag_threshold_px() -> f32 {
+    8.0
+}
+
 /// Default cursor blink interval in milliseconds.
 pub fn cursor_blink_interval() -> u64 {
     500 // 500 milliseconds (blink twice per second)
@@ -110,6 +110,11 @@ pub fn tab_html_titles() -> bool {
     false // Render tab titles as plain text unless explicitly enabled
 }
 
+/// Default width in pixels of the tab hover preview.
+pub fn tab_hover_preview_width() -> f32 {
+    320.0 // Large enough to make out a shell prompt
+}
+
 /// Default tab border width in pixels.
 pub fn tab_border_width() -> f32 {
     1.0 // 1 pixel border
@@ -0,0 +1,219 @@
+//! GPU adapter listing and diagnostics.
+//!
+//! The renderer enumerates the wgpu adapters available for the window surface
+//! and reports them here as plain data, so the settings UI can offer a device
+//! picker and a diagnostics panel without depending on wgpu.  The selected
+//! adapter is stored by name in `Config::gpu_adapter`; `None` lets
+//! `power_preference` choose.
+
+/// A GPU adapter as reported by the renderer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GpuAdapterInfo {
+    /// Adapter name, e.g. `NVIDIA GeForce RTX 3080`
+    pub name: String,
+    /// Graphics backend, e.g. `Vulkan`, `Metal`, `Dx12`, `Gl`
+    pub backend: String,
+    /// Device type, e.g. `DiscreteGpu`, `IntegratedGpu`, `Cpu`
+    pub device_type: String,
+    /// Driver name and version, if reported
+    pub driver: String,
+}
+
+/// Runtime diagnostics for the adapter in use.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GpuDiagnostics {
+    /// The adapter the renderer is using
+    pub adapter: Option<GpuAdapterInfo>,
+    /// Largest 2D texture dimension supported by the device
+    pub max_texture_dimension_2d: u32,
+    /// Largest buffer size supported by the device, in bytes
+    pub max_buffer_size: u64,
+    /// Maximum bind groups per pipeline
+    pub max_bind_groups: u32,
+    /// Inline graphics textures currently cached on the GPU
+    pub graphics_textures: usize,
+    /// Estimated GPU memory used by cached inline graphics, in bytes
+    pub graphics_texture_bytes: u64,
+}
+
+impl GpuDiagnostics {
+    /// Label/value rows for the diagnostics panel, in display order.
+    pub fn rows(&self) -> Vec<(&'static str, String)> {
+        let mut rows = Vec::new();
+        if let Some(adapter) = &self.adapter {
+            rows.push(("Adapter", adapter.name.clone()));
+            rows.push(("Backend", adapter.backend.clone()));
+            rows.push(("Device type", adapter.device_type.clone()));
+            if !adapter.driver.is_empty() {
+                rows.push(("Driver", adapter.driver.clone()));
+            }
+        }
+        rows.push((
+            "Max texture size",
+            format!("{0}×{0}", self.max_texture_dimension_2d),
+        ));
+        rows.push(("Max buffer size", format_bytes(self.max_buffer_size)));
+        rows.push(("Max bind groups", self.max_bind_groups.to_string()));
+        rows.push((
+            "Graphics cache",
+            format!(
+                "{} textures, {}",
+                self.graphics_textures,
+                format_bytes(self.graphics_texture_bytes)
+            ),
+        ));
+        rows
+    }
+}
+
+/// One entry of the adapter picker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GpuAdapterChoice {
+    /// Text shown in the picker
+    pub label: String,
+    /// Value stored in `Config::gpu_adapter` (`None` = automatic)
+    pub value: Option<String>,
+}
+
+/// Build the adapter picker entries for `adapters`.
+///
+/// The first entry selects automatically by power preference.  The same
+/// device exposed through several backends (e.g. Vulkan and GL) appears once,
+/// listing every backend.
+pub fn gpu_adapter_choices(adapters: &[GpuAdapterInfo]) -> Vec<GpuAdapterChoice> {
+    let mut grouped: Vec<(&GpuAdapterInfo, Vec<&str>)> = Vec::new();
+    for adapter in adapters {
+        match grouped.iter_mut().find(|(a, _)| a.name == adapter.name) {
+            Some((_, backends)) => {
+                if !backends.contains(&adapter.backend.as_str()) {
+                    backends.push(&adapter.backend);
+                }
+            }
+            None => grouped.push((adapter, vec![&adapter.backend])),
+        }
+    }
+
+    std::iter::once(GpuAdapterChoice {
+        label: "Automatic (power preference)".to_string(),
+        value: None,
+    })
+    .chain(
+        grouped
+            .into_iter()
+            .map(|(adapter, backends)| GpuAdapterChoice {
+                label: format!(
+                    "{} ({}, {})",
+                    adapter.name,
+                    backends.join("/"),
+                    adapter.device_type
+                ),
+                value: Some(adapter.name.clone()),
+            }),
+    )
+    .collect()
+}
+
+/// Index of the first adapter named `preferred` (case-insensitive).
+pub fn find_gpu_adapter(adapters: &[GpuAdapterInfo], preferred: &str) -> Option<usize> {
+    let preferred = preferred.trim();
+    adapters
+        .iter()
+        .position(|a| a.name.eq_ignore_ascii_case(preferred))
+}
+
+/// Human-readable byte count (binary units).
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adapter(name: &str, backend: &str, device_type: &str) -> GpuAdapterInfo {
+        GpuAdapterInfo {
+            name: name.to_string(),
+            backend: backend.to_string(),
+            device_type: device_type.to_string(),
+            driver: String::new(),
+        }
+    }
+
+    fn mocked_adapters() -> Vec<GpuAdapterInfo> {
+        vec![
+            adapter("NVIDIA GeForce RTX 3080", "Vulkan", "DiscreteGpu"),
+            adapter("Intel(R) UHD Graphics 630", "Vulkan", "IntegratedGpu"),
+            adapter("NVIDIA GeForce RTX 3080", "Gl", "DiscreteGpu"),
+            adapter("llvmpipe", "Vulkan", "Cpu"),
+        ]
+    }
+
+    #[test]
+    fn choices_start_with_automatic_and_merge_backends() {
+        let choices = gpu_adapter_choices(&mocked_adapters());
+        let labels: Vec<&str> = choices.iter().map(|c| c.label.as_str()).collect();
+        assert_eq!(
+            labels,
+            [
+                "Automatic (power preference)",
+                "NVIDIA GeForce RTX 3080 (Vulkan/Gl, DiscreteGpu)",
+                "Intel(R) UHD Graphics 630 (Vulkan, IntegratedGpu)",
+                "llvmpipe (Vulkan, Cpu)",
+            ]
+        );
+        assert_eq!(choices[0].value, None);
+        assert_eq!(choices[1].value.as_deref(), Some("NVIDIA GeForce RTX 3080"));
+    }
+
+    #[test]
+    fn no_adapters_leaves_only_automatic() {
+        let choices = gpu_adapter_choices(&[]);
+        assert_eq!(choices.len(), 1);
+        assert_eq!(choices[0].value, None);
+    }
+
+    #[test]
+    fn find_adapter_matches_name_case_insensitively() {
+        let adapters = mocked_adapters();
+        assert_eq!(find_gpu_adapter(&adapters, "llvmpipe"), Some(3));
+        assert_eq!(
+            find_gpu_adapter(&adapters, " nvidia geforce rtx 3080 "),
+            Some(0)
+        );
+        assert_eq!(find_gpu_adapter(&adapters, "Radeon"), None);
+    }
+
+    #[test]
+    fn diagnostics_rows_show_limits_and_cache_usage() {
+        let diagnostics = GpuDiagnostics {
+            adapter: Some(adapter("llvmpipe", "Vulkan", "Cpu")),
+            max_texture_dimension_2d: 8192,
+            max_buffer_size: 256 * 1024 * 1024,
+            max_bind_groups: 4,
+            graphics_textures: 3,
+            graphics_texture_bytes: 3 * 1024 * 1024 / 2,
+        };
+        let rows = diagnostics.rows();
+        let value = |label| {
+            rows.iter()
+                .find(|(l, _)| *l == label)
+                .map(|(_, v)| v.as_str())
+        };
+        assert_eq!(value("Backend"), Some("Vulkan"));
+        assert_eq!(value("Driver"), None);
+        assert_eq!(value("Max texture size"), Some("8192×8192"));
+        assert_eq!(value("Max buffer size"), Some("256.0 MiB"));
+        assert_eq!(value("Graphics cache"), Some("3 textures, 1.5 MiB"));
+    }
+}
@@ -22,6 +22,8 @@
 //! - [`prelude::shader`] — Shader controls, metadata, bundles, and resolution
 //! - [`prelude::assistant`] — AI assistant prompts and input history
 //! - [`prelude::snippets`] — Snippets, custom actions, and built-in variables
+//! - [`prelude::badge`] — Badge format variables and interpolation
+//! - [`prelude::gpu`] — GPU adapter listing and diagnostics
 //! - [`prelude::status_bar`] — Status bar widgets and layout
 //! - [`prelude::profile`] — Profiles, profile manager, and dynamic sources
 //! - [`prelude::unicode`] — Unicode width, normalization, and version types
@@ -30,10 +32,12 @@
 pub mod assistant_input_history;
 pub mod assistant_prompts;
 pub mod automation;
+pub mod badge;
 pub mod cell;
 pub mod config;
 pub mod defaults;
 pub mod error;
+pub mod gpu;
 pub mod layout_constants;
 pub mod profile;
 pub mod profile_types;
@@ -77,6 +81,7 @@ pub mod prelude {
             GlobalShaderConfig, MouseConfig, StatusBarConfig, WindowConfig, is_env_var_allowed,
             substitute_variables, substitute_variables_with_allowlist,
         };
+        pub use crate::config::{ConfigWarning, ConfigWarningSeverity};
         pub use crate::error::ConfigError;
         pub use crate::scrollback_mark::ScrollbackMark;
         pub use crate::snapshot_types::TabSnapshot;
@@ -93,7 +98,7 @@ pub mod prelude {
         pub use crate::types::alert::{AlertEvent, AlertSoundConfig};
         // Font and display
         pub use crate::types::font::{
-            DownloadSaveLocation, DroppedFileQuoteStyle, FontRange, ThinStrokesMode,
+            DownloadSaveLocation, DroppedFileQuoteStyle, FontAxes, FontRange, ThinStrokesMode,
         };
         // Integration / install prompts
         pub use crate::types::integration::{
@@ -101,17 +106,18 @@ pub mod prelude {
             ShaderInstallPrompt, UpdateCheckFrequency,
         };
         // Keybindings
-        pub use crate::types::keybinding::KeyBinding;
         #[allow(unused_imports)]
         pub use crate::types::keybinding::KeyModifier;
+        pub use crate::types::keybinding::{KeyBinding, KeybindingMode};
         // Rendering and layout
         pub use crate::types::rendering::{
-            BackgroundImageMode, BackgroundMode, DividerRect, DividerStyle, ImageScalingMode,
-            PaneBackground, PaneBackgroundConfig, PaneId, PaneTitlePosition, PowerPreference,
-            SeparatorMark, TabId, VsyncMode,
+            BackgroundImageMode, BackgroundMode, BlendColorSpace, DividerRect, DividerStyle,
+            ImageScalingMode, PaneBackground, PaneBackgroundConfig, PaneId, PaneTitlePosition,
+            PowerPreference, SeparatorMark, TabId, VsyncMode,
         };
-        // Selection
+        // Selection and clipboard
         pub use crate::types::selection::{
+            ClipboardScope, CopyLineEnding, PasteBracketMarkers, PasteControlSequences,
             SmartSelectionPrecision, SmartSelectionRule, default_smart_selection_rules,
         };
         // Shader types
@@ -127,12 +133,14 @@ pub mod prelude {
         // Tab bar and window
         pub use crate::types::tab_bar::{
             NewTabPosition, RemoteTabTitleFormat, StatusBarPosition, TabBarMode, TabBarPosition,
-            TabStyle, TabTitleMode, WindowType,
+            TabFontFamily, TabStyle, TabTitleMode, TabTitleVars, WindowType,
         };
         // Terminal / cursor / input
         pub use crate::types::terminal::{
-            CursorStyle, LinkUnderlineStyle, LogLevel, ModifierRemapping, ModifierTarget,
-            OptionKeyMode, SemanticHistoryEditorMode, SessionLogFormat, UnfocusedCursorStyle,
+            BackspaceSends, ColorMode, CursorStyle, FormFeed, HyperlinkClickModifier,
+            LinkUnderlineStyle, LogLevel, MarkTrimPolicy, ModifierRemapping, ModifierTarget,
+            OptionKeyMode, PrinterOutput, PromptContinuation, SemanticHistoryEditorMode,
+            SessionLogFormat, UnfocusedCursorStyle,
         };
     }
 
@@ -181,6 +189,21 @@ pub mod prelude {
         };
     }
 
+    /// Badge format variables: built-in list, placeholder interpolation, and autocompletion.
+    pub mod badge {
+        pub use crate::badge::{
+            BADGE_VARIABLE_PREFIX, BUILTIN_BADGE_VARIABLES, BadgeVariable, badge_completion_prefix,
+            builtin_badge_variables, interpolate_badge_placeholders,
+        };
+    }
+
+    /// GPU adapter listing, picker entries, and device diagnostics.
+    pub mod gpu {
+        pub use crate::gpu::{
+            GpuAdapterChoice, GpuAdapterInfo, GpuDiagnostics, find_gpu_adapter, gpu_adapter_choices,
+        };
+    }
+
     /// Status bar widgets, sections, layout, and default widget configuration.
     pub mod status_bar {
         pub use crate::status_bar::{
@@ -192,7 +215,8 @@ pub mod prelude {
     pub mod profile {
         pub use crate::profile::{ConflictResolution, DynamicProfileSource};
         pub use crate::profile_types::{
-            Profile, ProfileId, ProfileManager, ProfileSource, TmuxConnectionMode,
+            CursorOverride, MonitorRect, Profile, ProfileId, ProfileManager, ProfileSource,
+            TmuxConnectionMode, WindowGeometry,
         };
     }
 
@@ -233,6 +257,7 @@ pub use assistant_prompts::{
 };
 
 // Error types
+pub use config::{ConfigWarning, ConfigWarningSeverity};
 pub use error::ConfigError;
 
 // Core types
@@ -240,8 +265,8 @@ pub use cell::Cell;
 pub use config::{
     ALLOWED_ENV_VARS, AiInspectorConfig, AssistantInputHistoryMode, Config, CursorConfig,
     CustomAcpAgentActionConfig, CustomAcpAgentConfig, FontRenderingConfig, GlobalShaderConfig,
-    MouseConfig, StatusBarConfig, WindowConfig, is_env_var_allowed, substitute_variables,
-    substitute_variables_with_allowlist,
+    KeywordHighlightSet, MouseConfig, StatusBarConfig, WindowConfig, is_env_var_allowed,
+    substitute_variables, substitute_variables_with_allowlist,
 };
 pub use scrollback_mark::ScrollbackMark;
 pub use themes::{Color, Theme};
@@ -259,29 +284,43 @@ pub use automation::{
     check_command_denylist, warn_prompt_before_run_false,
 };
 pub use types::{
-    AlertEvent, AlertSoundConfig, BackgroundImageMode, BackgroundMode, CursorShaderConfig,
+    AlertEvent, AlertSoundConfig, BackgroundImageMode, BackgroundMode, BackspaceSends,
+    BlendColorSpace, ClipboardScope, ColorMode, CopyLineEnding, CursorShaderConfig,
     CursorShaderMetadata, CursorStyle, DividerRect, DividerStyle, DownloadSaveLocation,
-    DroppedFileQuoteStyle, FontRange, ImageScalingMode, InstallPromptState, IntegrationVersions,
-    KeyBinding, LinkUnderlineStyle, LogLevel, ModifierRemapping, ModifierTarget, NewTabPosition,
-    OptionKeyMode, PaneBackground, PaneBackgroundConfig, PaneId, PaneTitlePosition,
-    PowerPreference, ProgressBarPosition, ProgressBarStyle, RemoteTabTitleFormat,
-    SemanticHistoryEditorMode, SeparatorMark, SessionLogFormat, ShaderBackgroundBlendMode,
-    ShaderConfig, ShaderInstallPrompt, ShaderMetadata, ShaderSafetyBadge, ShellExitAction,
-    ShellType, SmartSelectionPrecision, SmartSelectionRule, StartupDirectoryMode,
-    StatusBarPosition, TabBarMode, TabBarPosition, TabId, TabStyle, TabTitleMode, ThinStrokesMode,
-    UnfocusedCursorStyle, UpdateCheckFrequency, VsyncMode, WindowType,
-    default_smart_selection_rules,
+    DroppedFileQuoteStyle, FontAxes, FontRange, FormFeed, HyperlinkClickModifier, ImageScalingMode,
+    InstallPromptState, IntegrationVersions, KeyBinding, KeybindingMode, LinkUnderlineStyle,
+    LogLevel, MarkTrimPolicy, ModifierRemapping, ModifierTarget, NewTabPosition, OptionKeyMode,
+    PaneBackground, PaneBackgroundConfig, PaneId, PaneTitlePosition, PasteBracketMarkers,
+    PasteControlSequences, PowerPreference, PrinterOutput, ProgressBarPosition, ProgressBarStyle,
+    PromptContinuation, RemoteTabTitleFormat, SemanticHistoryEditorMode, SeparatorMark,
+    SessionLogFormat, ShaderBackgroundBlendMode, ShaderConfig, ShaderInstallPrompt, ShaderMetadata,
+    ShaderSafetyBadge, ShellExitAction, ShellType, SmartSelectionPrecision, SmartSelectionRule,
+    StartupDirectoryMode, StatusBarPosition, TabBarMode, TabBarPosition, TabFontFamily, TabId,
+    TabStyle, TabTitleMode, TabTitleVars, ThinStrokesMode, UnfocusedCursorStyle,
+    UpdateCheckFrequency, VsyncMode, WindowType, default_smart_selection_rules,
 };
 // Scripting / observer scripts
 pub use scripting::ScriptConfig;
 // Snippets and custom actions
 pub use snippets::{BuiltInVariable, CustomActionConfig, SnippetConfig, SnippetLibrary};
+// Badge format variables
+pub use badge::{
+    BADGE_VARIABLE_PREFIX, BUILTIN_BADGE_VARIABLES, BadgeVariable, badge_completion_prefix,
+    builtin_badge_variables, interpolate_badge_placeholders,
+};
+// GPU adapter listing and diagnostics
+pub use gpu::{
+    GpuAdapterChoice, GpuAdapterInfo, GpuDiagnostics, find_gpu_adapter, gpu_adapter_choices,
+};
 // Status bar configuration
 pub use status_bar::{StatusBarSection, StatusBarWidgetConfig, WidgetId, default_widgets};
 // Profile configuration
 pub use profile::{ConflictResolution, DynamicProfileSource};
 // Profile types and manager
-pub use profile_types::{Profile, ProfileId, ProfileManager, ProfileSource, TmuxConnectionMode};
+pub use profile_types::{
+    CursorOverride, DEFAULT_STARTUP_COMMAND_DELAY_MS, MonitorRect, Profile, ProfileId,
+    ProfileManager, ProfileSource, TmuxConnectionMode, WindowGeometry,
+};
 // Shader bundle manifests
 pub use shader_bundle::ShaderBundleManifest;
 // Shader config resolution
@@ -0,0 +1,85 @@
+//! Per-profile cursor appearance.
+//!
+//! A profile can set its own cursor color, style and blink so its tabs are
+//! easy to tell apart.  [`CursorOverride`] carries the values a profile sets;
+//! each one that is `None` falls back to the global [`CursorConfig`].
+
+use crate::config::CursorConfig;
+use crate::types::CursorStyle;
+
+/// Cursor settings a profile overrides for the tabs opened from it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CursorOverride {
+    /// Cursor color [R, G, B] (None = global `cursor_color`)
+    pub color: Option<[u8; 3]>,
+    /// Cursor style (None = global `cursor_style`)
+    pub style: Option<CursorStyle>,
+    /// Cursor blinking (None = global `cursor_blink`)
+    pub blink: Option<bool>,
+}
+
+impl CursorOverride {
+    /// Whether nothing is overridden.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Cursor color, preferring the override over `global`.
+    pub fn color(&self, global: &CursorConfig) -> [u8; 3] {
+        self.color.unwrap_or(global.cursor_color)
+    }
+
+    /// Cursor style, preferring the override over `global`.
+    pub fn style(&self, global: &CursorConfig) -> CursorStyle {
+        self.style.unwrap_or(global.cursor_style)
+    }
+
+    /// Cursor blinking, preferring the override over `global`.
+    pub fn blink(&self, global: &CursorConfig) -> bool {
+        self.blink.unwrap_or(global.cursor_blink)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile_types::Profile;
+
+    fn global() -> CursorConfig {
+        CursorConfig {
+            cursor_color: [255, 255, 255],
+            cursor_style: CursorStyle::Block,
+            cursor_blink: false,
+            ..CursorConfig::default()
+        }
+    }
+
+    #[test]
+    fn profile_values_take_precedence() {
+        let profile = Profile::new("Prod")
+            .cursor_color([255, 0, 0])
+            .cursor_style(CursorStyle::Beam)
+            .cursor_blink(true);
+        let cursor = profile.cursor_override();
+        assert!(!cursor.is_empty());
+        assert_eq!(cursor.color(&global()), [255, 0, 0]);
+        assert_eq!(cursor.style(&global()), CursorStyle::Beam);
+        assert!(cursor.blink(&global()));
+    }
+
+    #[test]
+    fn unset_values_fall_back_to_global() {
+        let cursor = Profile::new("Plain").cursor_override();
+        assert!(cursor.is_empty());
+        assert_eq!(cursor, CursorOverride::default());
+        assert_eq!(cursor.color(&global()), [255, 255, 255]);
+        assert_eq!(cursor.style(&global()), CursorStyle::Block);
+        assert!(!cursor.blink(&global()));
+
+        let partial = Profile::new("Underline")
+            .cursor_style(CursorStyle::Underline)
+            .cursor_override();
+        assert_eq!(partial.color(&global()), [255, 255, 255]);
+        assert_eq!(partial.style(&global()), CursorStyle::Underline);
+    }
+}
@@ -7,6 +7,7 @@
 use std::collections::HashMap;
 
 use super::profile::{Profile, ProfileId};
+use crate::error::ConfigError;
 
 /// Manages a collection of profiles
 #[derive(Debug, Clone, Default)]
@@ -297,31 +298,50 @@ impl ProfileManager {
     }
 
     /// Resolve a profile with inheritance - returns effective settings
-    /// by merging parent profiles. Child values override parent values.
-    pub fn resolve_profile(&self, id: &ProfileId) -> Option<Profile> {
-        let profile = self.profiles.get(id)?;
+    /// by merging parent profiles recursively. Child values override parent
+    /// values.
+    ///
+    /// Fails with [`ConfigError::Validation`] if `id` is unknown or its
+    /// `parent_id` chain leads back to a profile already in the chain. A
+    /// missing parent is skipped with a warning.
+    pub fn resolve(&self, id: &ProfileId) -> Result<Profile, ConfigError> {
+        let profile = self
+            .profiles
+            .get(id)
+            .ok_or_else(|| ConfigError::Validation(format!("unknown profile {id}")))?;
         self.resolve_profile_chain(profile, &mut vec![*id])
     }
 
+    /// Like [`resolve`](Self::resolve), but a profile whose inheritance
+    /// chain is cyclic is returned without inheriting anything.
+    pub fn resolve_profile(&self, id: &ProfileId) -> Option<Profile> {
+        match self.resolve(id) {
+            Ok(profile) => Some(profile),
+            Err(e) => {
+                log::warn!("{}", e);
+                self.profiles.get(id).cloned()
+            }
+        }
+    }
+
     /// Resolve profile inheritance chain, detecting cycles
     fn resolve_profile_chain(
         &self,
         profile: &Profile,
         visited: &mut Vec<ProfileId>,
-    ) -> Option<Profile> {
+    ) -> Result<Profile, ConfigError> {
         // If no parent, return the profile as-is
         let Some(parent_id) = profile.parent_id else {
-            return Some(profile.clone());
+            return Ok(profile.clone());
         };
 
         // Detect cycles
         if visited.contains(&parent_id) {
-            log::warn!(
-                "Circular profile inheritance detected: {:?} -> {:?}",
-                profile.id,
-                parent_id
-            );
-            return Some(profile.clone());
+            return Err(ConfigError::Validation(format!(
+                "circular profile inheritance: '{}' ({}) inherits from {}, \
+                 which is already in its parent chain",
+                profile.name, profile.id, parent_id
+            )));
         }
 
         // Get parent profile
@@ -331,7 +351,7 @@ impl ProfileManager {
                 parent_id,
                 profile.id
             );
-            return Some(profile.clone());
+            return Ok(profile.clone());
         };
 
         // Recursively resolve parent
@@ -339,7 +359,7 @@ impl ProfileManager {
         let resolved_parent = self.resolve_profile_chain(parent, visited)?;
 
         // Merge: child overrides parent
-        Some(Profile {
+        Ok(Profile {
             id: profile.id,
             name: profile.name.clone(),
             order: profile.order,
@@ -390,6 +410,10 @@ impl ProfileManager {
             } else {
                 profile.directory_patterns.clone()
             },
+            tab_title_template: profile
+                .tab_title_template
+                .clone()
+                .or(resolved_parent.tab_title_template),
             badge_text: profile.badge_text.clone().or(resolved_parent.badge_text),
             badge_color: profile.badge_color.or(resolved_parent.badge_color),
             badge_color_alpha: profile
@@ -421,6 +445,9 @@ impl ProfileManager {
                 .shader_texture_set
                 .clone()
                 .or(resolved_parent.shader_texture_set),
+            cursor_color: profile.cursor_color.or(resolved_parent.cursor_color),
+            cursor_style: profile.cursor_style.or(resolved_parent.cursor_style),
+            cursor_blink: profile.cursor_blink.or(resolved_parent.cursor_blink),
             ssh_host: profile.ssh_host.clone().or(resolved_parent.ssh_host),
             ssh_user: profile.ssh_user.clone().or(resolved_parent.ssh_user),
             ssh_port: profile.ssh_port.or(resolved_parent.ssh_port),
@@ -432,6 +459,27 @@ impl ProfileManager {
                 .ssh_extra_args
                 .clone()
                 .or(resolved_parent.ssh_extra_args),
+            startup_commands: if profile.startup_commands.is_empty() {
+                resolved_parent.startup_commands
+            } else {
+                profile.startup_commands.clone()
+            },
+            startup_command_delay_ms: profile
+                .startup_command_delay_ms
+                .or(resolved_parent.startup_command_delay_ms),
+            color_mode: profile.color_mode.or(resolved_parent.color_mode),
+            alt_screen_color_mode: profile
+                .alt_screen_color_mode
+                .or(resolved_parent.alt_screen_color_mode),
+            input_encoding: profile
+                .input_encoding
+                .clone()
+                .or(resolved_parent.input_encoding),
+            osc_notifications: profile
+                .osc_notifications
+                .or(resolved_parent.osc_notifications),
+            // Geometry belongs to the profile's own windows; never inherited.
+            window_geometry: profile.window_geometry,
             source: profile.source.clone(),
         })
     }
@@ -470,3 +518,68 @@ impl ProfileManager {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_level_inheritance_merges_each_ancestor() {
+        let base = Profile::new("Base")
+            .shell("/bin/zsh")
+            .working_directory("~/src")
+            .icon("B");
+        let middle = Profile::new("Middle").parent_id(base.id).tab_name("work");
+        let child = Profile::new("Child").parent_id(middle.id).icon("C");
+        let child_id = child.id;
+
+        let mut manager = ProfileManager::new();
+        manager.add(base);
+        manager.add(middle);
+        manager.add(child);
+
+        let resolved = manager.resolve(&child_id).unwrap();
+        assert_eq!(resolved.id, child_id);
+        assert_eq!(resolved.name, "Child");
+        assert_eq!(resolved.shell.as_deref(), Some("/bin/zsh"));
+        assert_eq!(resolved.working_directory.as_deref(), Some("~/src"));
+        assert_eq!(resolved.tab_name.as_deref(), Some("work"));
+        // Overridden at the child level
+        assert_eq!(resolved.icon.as_deref(), Some("C"));
+    }
+
+    #[test]
+    fn inheritance_cycle_is_rejected() {
+        let mut a = Profile::new("A").shell("/bin/bash");
+        let b = Profile::new("B").parent_id(a.id);
+        a.parent_id = Some(b.id);
+        let (a_id, b_id) = (a.id, b.id);
+
+        let mut manager = ProfileManager::new();
+        manager.add(a);
+        manager.add(b);
+
+        for id in [a_id, b_id] {
+            assert!(matches!(
+                manager.resolve(&id),
+                Err(ConfigError::Validation(msg)) if msg.contains("circular")
+            ));
+        }
+        // The lenient lookup falls back to the profile's own settings
+        let fallback = manager.resolve_profile(&b_id).unwrap();
+        assert_eq!(fallback.shell, None);
+
+        assert!(manager.resolve(&ProfileId::new_v4()).is_err());
+    }
+
+    #[test]
+    fn parent_field_is_accepted_in_yaml() {
+        let parent = ProfileId::new_v4();
+        let yaml = format!(
+            "id: {}\nname: Variant\nparent: {parent}\n",
+            ProfileId::new_v4()
+        );
+        let profile: Profile = serde_yaml_ng::from_str(&yaml).unwrap();
+        assert_eq!(profile.parent_id, Some(parent));
+    }
+}
@@ -5,16 +5,22 @@
 //!
 //! ## Sub-modules
 //!
+//! - [`cursor_override`]: `CursorOverride` — per-profile cursor color, style and blink
 //! - [`dynamic`]: Runtime `ProfileSource` enum — tracks where a profile was loaded from
 //! - [`profile`]: Core `Profile` struct and its builder/impl methods
 //! - [`matchers`]: `ProfileManager` — collection management and glob-pattern matching
+//! - [`window_geometry`]: `WindowGeometry` — remembered per-profile window rect and monitor clamping
 
+pub mod cursor_override;
 pub mod dynamic;
 pub mod matchers;
 pub mod profile;
+pub mod window_geometry;
 
 // Re-export everything that was previously public from the flat profile_types.rs
 // so that all external call sites continue to compile without any changes.
+pub use cursor_override::CursorOverride;
 pub use dynamic::ProfileSource;
 pub use matchers::ProfileManager;
-pub use profile::{Profile, ProfileId, TmuxConnectionMode};
+pub use profile::{DEFAULT_STARTUP_COMMAND_DELAY_MS, Profile, ProfileId, TmuxConnectionMode};
+pub use window_geometry::{MonitorRect, WindowGeometry};
@@ -3,11 +3,18 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::cursor_override::CursorOverride;
 use super::dynamic::ProfileSource;
+use super::window_geometry::WindowGeometry;
+use crate::types::{ColorMode, CursorStyle};
 
 /// Unique identifier for a profile
 pub type ProfileId = Uuid;
 
+/// Default delay before each profile startup command, giving the shell
+/// time to draw its prompt (and a remote/tmux session time to attach).
+pub const DEFAULT_STARTUP_COMMAND_DELAY_MS: u64 = 300;
+
 /// How to connect this profile to a tmux session when it opens.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
@@ -74,8 +81,9 @@ pub struct Profile {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
 
-    /// Parent profile ID for inheritance (child overrides parent settings)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Parent profile ID for inheritance (child overrides parent settings).
+    /// Also accepted as `parent`.
+    #[serde(default, alias = "parent", skip_serializing_if = "Option::is_none")]
     pub parent_id: Option<ProfileId>,
 
     /// Keyboard shortcut for quick launch (e.g., "Cmd+1", "Ctrl+Shift+1")
@@ -106,6 +114,11 @@ pub struct Profile {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub directory_patterns: Vec<String>,
 
+    /// Tab title template for tabs opened with this profile
+    /// (overrides global tab_title_template; see `TabTitleVars` for placeholders)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tab_title_template: Option<String>,
+
     /// Per-profile badge text (overrides global badge_format when this profile is active)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub badge_text: Option<String>,
@@ -162,6 +175,18 @@ pub struct Profile {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shader_texture_set: Option<[Option<String>; 4]>,
 
+    /// Per-profile cursor color [R, G, B] (overrides global cursor_color in this profile's tabs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor_color: Option<[u8; 3]>,
+
+    /// Per-profile cursor style (overrides global cursor_style in this profile's tabs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor_style: Option<CursorStyle>,
+
+    /// Per-profile cursor blinking (overrides global cursor_blink in this profile's tabs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor_blink: Option<bool>,
+
     /// SSH hostname for direct connection (profile acts as SSH bookmark)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ssh_host: Option<String>,
@@ -198,6 +223,46 @@ pub struct Profile {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ssh_extra_args: Option<String>,
 
+    /// Commands typed into the session after it starts, in order (e.g.
+    /// `tmux attach`, `source venv/bin/activate`).
+    /// Each is sent as if typed followed by Enter; unlike `command`, the
+    /// shell itself is unchanged.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub startup_commands: Vec<String>,
+
+    /// Delay in milliseconds before each startup command
+    /// (None = [`DEFAULT_STARTUP_COMMAND_DELAY_MS`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub startup_command_delay_ms: Option<u64>,
+
+    /// Color depth advertised to applications through `$COLORTERM`
+    /// (None = truecolor).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color_mode: Option<ColorMode>,
+
+    /// Reduce colors to this depth while the alternate screen is active,
+    /// for full-screen apps that render poorly with 24-bit color
+    /// (None = no reduction).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alt_screen_color_mode: Option<ColorMode>,
+
+    /// Encoding of output from a legacy host that does not send UTF-8, as a
+    /// WHATWG label such as `latin1` or `shift_jis` (None = UTF-8).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_encoding: Option<String>,
+
+    /// Per-profile override of `notification_osc_enabled`: whether OSC
+    /// 9/777/99 notifications from this profile's tabs reach the desktop.
+    /// None = inherit the global setting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub osc_notifications: Option<bool>,
+
+    /// Window size/position remembered for this profile (physical pixels).
+    /// Recorded when a window opened from the profile closes and applied the
+    /// next time it is opened, while `remember_profile_window_geometry` is on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window_geometry: Option<WindowGeometry>,
+
     /// Where this profile was loaded from (runtime-only, not persisted to YAML)
     #[serde(skip)]
     pub source: ProfileSource,
@@ -358,6 +423,7 @@ impl Profile {
             tmux_session_name: None,
             tmux_connection_mode: TmuxConnectionMode::default(),
             directory_patterns: Vec::new(),
+            tab_title_template: None,
             badge_text: None,
             badge_color: None,
             badge_color_alpha: None,
@@ -372,11 +438,21 @@ impl Profile {
             shader_text_opacity: None,
             shader_animation_speed: None,
             shader_texture_set: None,
+            cursor_color: None,
+            cursor_style: None,
+            cursor_blink: None,
             ssh_host: None,
             ssh_user: None,
             ssh_port: None,
             ssh_identity_file: None,
             ssh_extra_args: None,
+            startup_commands: Vec::new(),
+            startup_command_delay_ms: None,
+            color_mode: None,
+            alt_screen_color_mode: None,
+            input_encoding: None,
+            osc_notifications: None,
+            window_geometry: None,
             source: ProfileSource::default(),
         }
     }
@@ -402,6 +478,7 @@ impl Profile {
             tmux_session_name: None,
             tmux_connection_mode: TmuxConnectionMode::default(),
             directory_patterns: Vec::new(),
+            tab_title_template: None,
             badge_text: None,
             badge_color: None,
             badge_color_alpha: None,
@@ -416,11 +493,21 @@ impl Profile {
             shader_text_opacity: None,
             shader_animation_speed: None,
             shader_texture_set: None,
+            cursor_color: None,
+            cursor_style: None,
+            cursor_blink: None,
             ssh_host: None,
             ssh_user: None,
             ssh_port: None,
             ssh_identity_file: None,
             ssh_extra_args: None,
+            startup_commands: Vec::new(),
+            startup_command_delay_ms: None,
+            color_mode: None,
+            alt_screen_color_mode: None,
+            input_encoding: None,
+            osc_notifications: None,
+            window_geometry: None,
             source: ProfileSource::default(),
         }
     }
@@ -479,6 +566,48 @@ impl Profile {
         self
     }
 
+    /// Builder method to set startup commands
+    pub fn startup_commands(mut self, commands: Vec<String>) -> Self {
+        self.startup_commands = commands;
+        self
+    }
+
+    /// Builder method to set the delay before each startup command
+    pub fn startup_command_delay_ms(mut self, delay_ms: u64) -> Self {
+        self.startup_command_delay_ms = Some(delay_ms);
+        self
+    }
+
+    /// Delay before each startup command, in milliseconds.
+    pub fn effective_startup_command_delay_ms(&self) -> u64 {
+        self.startup_command_delay_ms
+            .unwrap_or(DEFAULT_STARTUP_COMMAND_DELAY_MS)
+    }
+
+    /// Builder method to set the advertised color mode
+    pub fn color_mode(mut self, mode: ColorMode) -> Self {
+        self.color_mode = Some(mode);
+        self
+    }
+
+    /// Builder method to set the alternate-screen color mode
+    pub fn alt_screen_color_mode(mut self, mode: ColorMode) -> Self {
+        self.alt_screen_color_mode = Some(mode);
+        self
+    }
+
+    /// Builder method to set the input encoding of a legacy host
+    pub fn input_encoding(mut self, label: impl Into<String>) -> Self {
+        self.input_encoding = Some(label.into());
+        self
+    }
+
+    /// Builder method to allow or block OSC desktop notifications
+    pub fn osc_notifications(mut self, enabled: bool) -> Self {
+        self.osc_notifications = Some(enabled);
+        self
+    }
+
     /// Builder method to set parent profile ID
     pub fn parent_id(mut self, parent_id: ProfileId) -> Self {
         self.parent_id = Some(parent_id);
@@ -521,6 +650,12 @@ impl Profile {
         self
     }
 
+    /// Builder method to set the tab title template
+    pub fn tab_title_template(mut self, template: impl Into<String>) -> Self {
+        self.tab_title_template = Some(template.into());
+        self
+    }
+
     /// Builder method to set badge text
     pub fn badge_text(mut self, text: impl Into<String>) -> Self {
         self.badge_text = Some(text.into());
@@ -575,6 +710,33 @@ impl Profile {
         self
     }
 
+    /// Builder method to set cursor color
+    pub fn cursor_color(mut self, color: [u8; 3]) -> Self {
+        self.cursor_color = Some(color);
+        self
+    }
+
+    /// Builder method to set cursor style
+    pub fn cursor_style(mut self, style: CursorStyle) -> Self {
+        self.cursor_style = Some(style);
+        self
+    }
+
+    /// Builder method to set cursor blinking
+    pub fn cursor_blink(mut self, blink: bool) -> Self {
+        self.cursor_blink = Some(blink);
+        self
+    }
+
+    /// Cursor settings this profile overrides.
+    pub fn cursor_override(&self) -> CursorOverride {
+        CursorOverride {
+            color: self.cursor_color,
+            style: self.cursor_style,
+            blink: self.cursor_blink,
+        }
+    }
+
     /// Builder method to set SSH host
     pub fn ssh_host(mut self, host: impl Into<String>) -> Self {
         self.ssh_host = Some(host.into());
@@ -0,0 +1,172 @@
+//! Remembered window geometry for profiles.
+//!
+//! When `remember_profile_window_geometry` is enabled, a window opened from a
+//! profile records its size and position on close and reopens there the next
+//! time the profile is opened.  Monitors can be unplugged or rearranged in
+//! between, so the saved rect is clamped onto an available monitor before use.
+
+use serde::{Deserialize, Serialize};
+
+/// Saved window rect in physical pixels (outer position, inner size).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    /// Outer X position in virtual desktop coordinates (may be negative)
+    pub x: i32,
+    /// Outer Y position in virtual desktop coordinates (may be negative)
+    pub y: i32,
+    /// Inner width in physical pixels
+    pub width: u32,
+    /// Inner height in physical pixels
+    pub height: u32,
+}
+
+/// A monitor's rect in physical pixels, as reported by the windowing system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonitorRect {
+    /// Left edge in virtual desktop coordinates
+    pub x: i32,
+    /// Top edge in virtual desktop coordinates
+    pub y: i32,
+    /// Width in physical pixels
+    pub width: u32,
+    /// Height in physical pixels
+    pub height: u32,
+}
+
+impl MonitorRect {
+    /// Area of the intersection between this monitor and `geometry`.
+    fn overlap_area(&self, geometry: &WindowGeometry) -> i64 {
+        let left = self.x.max(geometry.x) as i64;
+        let top = self.y.max(geometry.y) as i64;
+        let right =
+            (self.x as i64 + self.width as i64).min(geometry.x as i64 + geometry.width as i64);
+        let bottom =
+            (self.y as i64 + self.height as i64).min(geometry.y as i64 + geometry.height as i64);
+        (right - left).max(0) * (bottom - top).max(0)
+    }
+
+    /// Squared distance from the monitor's center to the geometry's center.
+    fn center_distance_sq(&self, geometry: &WindowGeometry) -> i64 {
+        let cx = self.x as i64 + self.width as i64 / 2;
+        let cy = self.y as i64 + self.height as i64 / 2;
+        let gx = geometry.x as i64 + geometry.width as i64 / 2;
+        let gy = geometry.y as i64 + geometry.height as i64 / 2;
+        (cx - gx).pow(2) + (cy - gy).pow(2)
+    }
+}
+
+impl WindowGeometry {
+    /// Smallest window size kept after clamping, so a corrupt or tiny saved
+    /// size never produces an unusable window.
+    pub const MIN_SIZE: u32 = 100;
+
+    /// Clamp this rect so it lies fully on one of `monitors`.
+    ///
+    /// The monitor showing the largest part of the window is chosen; when the
+    /// window is entirely off-screen (e.g. the monitor it was on has been
+    /// disconnected) the monitor nearest to it is used instead.  The size is
+    /// shrunk to fit that monitor and the position moved inside its bounds.
+    /// Returns the rect unchanged when no monitors are known.
+    pub fn clamped_to_monitors(&self, monitors: &[MonitorRect]) -> WindowGeometry {
+        let monitor = monitors
+            .iter()
+            .filter(|m| m.overlap_area(self) > 0)
+            .max_by_key(|m| m.overlap_area(self))
+            .or_else(|| monitors.iter().min_by_key(|m| m.center_distance_sq(self)));
+        let Some(monitor) = monitor else {
+            return *self;
+        };
+
+        let width = self
+            .width
+            .clamp(Self::MIN_SIZE.min(monitor.width), monitor.width);
+        let height = self
+            .height
+            .clamp(Self::MIN_SIZE.min(monitor.height), monitor.height);
+        let max_x = monitor.x + (monitor.width - width) as i32;
+        let max_y = monitor.y + (monitor.height - height) as i32;
+
+        WindowGeometry {
+            x: self.x.clamp(monitor.x, max_x),
+            y: self.y.clamp(monitor.y, max_y),
+            width,
+            height,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEFT: MonitorRect = MonitorRect {
+        x: 0,
+        y: 0,
+        width: 1920,
+        height: 1080,
+    };
+    const RIGHT: MonitorRect = MonitorRect {
+        x: 1920,
+        y: 0,
+        width: 2560,
+        height: 1440,
+    };
+
+    fn geometry(x: i32, y: i32, width: u32, height: u32) -> WindowGeometry {
+        WindowGeometry {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn on_screen_geometry_is_unchanged() {
+        let saved = geometry(2000, 100, 1200, 800);
+        assert_eq!(saved.clamped_to_monitors(&[LEFT, RIGHT]), saved);
+    }
+
+    #[test]
+    fn disconnected_monitor_moves_window_to_nearest() {
+        // Saved on the right monitor, which is no longer attached.
+        let saved = geometry(2400, 200, 1200, 800);
+        let clamped = saved.clamped_to_monitors(&[LEFT]);
+        assert_eq!(clamped, geometry(720, 200, 1200, 800));
+    }
+
+    #[test]
+    fn negative_coordinates_are_pulled_onto_monitor() {
+        // Monitor left of the primary was removed; window sat at negative x.
+        let saved = geometry(-1500, -50, 800, 600);
+        assert_eq!(
+            saved.clamped_to_monitors(&[LEFT, RIGHT]),
+            geometry(0, 0, 800, 600)
+        );
+    }
+
+    #[test]
+    fn straddling_window_stays_on_monitor_with_most_overlap() {
+        // Mostly on the right monitor, hanging off its bottom edge.
+        let saved = geometry(1800, 1000, 1000, 600);
+        assert_eq!(
+            saved.clamped_to_monitors(&[LEFT, RIGHT]),
+            geometry(1920, 840, 1000, 600)
+        );
+    }
+
+    #[test]
+    fn oversized_window_shrinks_to_fit() {
+        let saved = geometry(100, 100, 4000, 3000);
+        assert_eq!(
+            saved.clamped_to_monitors(&[LEFT]),
+            geometry(0, 0, 1920, 1080)
+        );
+    }
+
+    #[test]
+    fn no_monitors_returns_saved_geometry() {
+        let saved = geometry(-5000, -5000, 800, 600);
+        assert_eq!(saved.clamped_to_monitors(&[]), saved);
+    }
+}
@@ -65,6 +65,13 @@ pub struct ScriptConfig {
     #[serde(default)]
     pub allow_change_config: bool,
 
+    /// Allow this script to read the current selection via `GetSelection`.
+    ///
+    /// Defaults to `false`. The selection may contain secrets, so it is only
+    /// handed to scripts that are explicitly trusted with it.
+    #[serde(default)]
+    pub allow_read_selection: bool,
+
     /// Maximum `WriteText` writes per second (0 = use default of 10/s).
     #[serde(default)]
     pub write_text_rate_limit: u32,
@@ -656,4 +656,126 @@ mod tests {
         resolved.cubemap_enabled = false;
         assert!(resolved.cubemap_path().is_none());
     }
+
+    fn cursor_metadata(defaults: CursorShaderConfig) -> CursorShaderMetadata {
+        CursorShaderMetadata {
+            name: Some("Cursor Test".to_string()),
+            defaults,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn cursor_resolve_uses_globals_without_overrides() {
+        let mut config = make_test_config();
+        config.shader.cursor_shader_hides_cursor = true;
+        config.shader.cursor_shader_disable_in_alt_screen = false;
+        config.shader.cursor_shader_glow_radius = 42.0;
+        config.shader.cursor_shader_glow_intensity = 0.6;
+        config.shader.cursor_shader_trail_duration = 1.25;
+
+        let resolved = resolve_cursor_shader_config(None, None, &config);
+
+        assert!(resolved.hides_cursor);
+        assert!(!resolved.disable_in_alt_screen);
+        assert_eq!(resolved.glow_radius, 42.0);
+        assert_eq!(resolved.glow_intensity, 0.6);
+        assert_eq!(resolved.trail_duration, 1.25);
+    }
+
+    #[test]
+    fn cursor_resolve_metadata_beats_globals() {
+        let config = make_test_config();
+        let metadata = cursor_metadata(CursorShaderConfig {
+            hides_cursor: Some(!config.shader.cursor_shader_hides_cursor),
+            glow_radius: Some(120.0),
+            trail_duration: Some(0.1),
+            ..Default::default()
+        });
+
+        let resolved = resolve_cursor_shader_config(None, Some(&metadata), &config);
+
+        assert_eq!(
+            resolved.hides_cursor,
+            !config.shader.cursor_shader_hides_cursor
+        );
+        assert_eq!(resolved.glow_radius, 120.0);
+        assert_eq!(resolved.trail_duration, 0.1);
+        // Fields absent from metadata still fall through to the global config
+        assert_eq!(
+            resolved.glow_intensity,
+            config.shader.cursor_shader_glow_intensity
+        );
+        assert_eq!(
+            resolved.disable_in_alt_screen,
+            config.shader.cursor_shader_disable_in_alt_screen
+        );
+    }
+
+    #[test]
+    fn cursor_resolve_user_override_beats_metadata_per_field() {
+        let config = make_test_config();
+        let metadata = cursor_metadata(CursorShaderConfig {
+            hides_cursor: Some(true),
+            disable_in_alt_screen: Some(true),
+            glow_radius: Some(120.0),
+            glow_intensity: Some(0.9),
+            trail_duration: Some(0.1),
+            ..Default::default()
+        });
+        let user_override = CursorShaderConfig {
+            hides_cursor: Some(false),
+            disable_in_alt_screen: Some(false),
+            glow_radius: Some(10.0),
+            ..Default::default()
+        };
+
+        let resolved = resolve_cursor_shader_config(Some(&user_override), Some(&metadata), &config);
+
+        assert!(!resolved.hides_cursor);
+        assert!(!resolved.disable_in_alt_screen);
+        assert_eq!(resolved.glow_radius, 10.0);
+        // Not overridden by the user, so metadata still wins over globals
+        assert_eq!(resolved.glow_intensity, 0.9);
+        assert_eq!(resolved.trail_duration, 0.1);
+    }
+
+    #[test]
+    fn cursor_for_shader_looks_up_override_by_name() {
+        let mut config = make_test_config();
+        config
+            .get_or_create_cursor_shader_override("glow.glsl")
+            .trail_duration = Some(2.0);
+
+        let resolved = ResolvedCursorShaderConfig::for_shader("glow.glsl", None, &config);
+        assert_eq!(resolved.trail_duration, 2.0);
+
+        let other = ResolvedCursorShaderConfig::for_shader("other.glsl", None, &config);
+        assert_eq!(
+            other.trail_duration,
+            config.shader.cursor_shader_trail_duration
+        );
+    }
+
+    #[test]
+    fn cursor_hides_default_cursor_respects_alt_screen_override() {
+        let config = make_test_config();
+        let metadata = cursor_metadata(CursorShaderConfig {
+            hides_cursor: Some(true),
+            disable_in_alt_screen: Some(true),
+            ..Default::default()
+        });
+
+        let resolved = resolve_cursor_shader_config(None, Some(&metadata), &config);
+        assert!(resolved.hides_default_cursor(false));
+        assert!(!resolved.hides_default_cursor(true));
+
+        // A per-shader override keeps the shader (and hidden cursor) in alt screen
+        let user_override = CursorShaderConfig {
+            disable_in_alt_screen: Some(false),
+            ..Default::default()
+        };
+        let resolved = resolve_cursor_shader_config(Some(&user_override), Some(&metadata), &config);
+        assert!(resolved.hides_default_cursor(true));
+    }
 }
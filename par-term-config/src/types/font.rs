@@ -15,6 +15,41 @@ pub struct FontRange {
     pub end: u32,
     /// Font family name to use for this range
     pub font_family: String,
+    /// OpenType features to shape this range with, as `[tag, value]` pairs,
+    /// e.g. `[["ss01", 1], ["calt", 0]]`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub features: Vec<(String, u32)>,
+}
+
+/// Variable font axis settings for each font style.
+///
+/// Each list holds `[tag, value]` pairs, e.g. `[["wght", 450]]`.  A style
+/// without its own font family uses the regular font at its own axis values,
+/// so a single variable font can provide every weight.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FontAxes {
+    /// Axes for the regular font
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub regular: Vec<(String, f32)>,
+    /// Axes for the bold font
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bold: Vec<(String, f32)>,
+    /// Axes for the italic font
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub italic: Vec<(String, f32)>,
+    /// Axes for the bold italic font
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bold_italic: Vec<(String, f32)>,
+}
+
+impl FontAxes {
+    /// Whether no style sets any axis.
+    pub fn is_empty(&self) -> bool {
+        self.regular.is_empty()
+            && self.bold.is_empty()
+            && self.italic.is_empty()
+            && self.bold_italic.is_empty()
+    }
 }
 
 /// Thin strokes / font smoothing mode
@@ -118,3 +153,45 @@ impl DroppedFileQuoteStyle {
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn font_range_features_are_optional() {
+        let ranges: Vec<FontRange> = serde_yaml_ng::from_str(
+            "- start: 0x4E00\n  end: 0x9FFF\n  font_family: Noto Sans CJK SC\n\
+             - start: 0x20\n  end: 0x7E\n  font_family: Fira Code\n  \
+               features: [[ss01, 1], [calt, 0]]\n",
+        )
+        .unwrap();
+        assert!(ranges[0].features.is_empty());
+        assert_eq!(
+            ranges[1].features,
+            [("ss01".to_string(), 1), ("calt".to_string(), 0)]
+        );
+
+        // No features are left out when saving
+        let yaml = serde_yaml_ng::to_string(&ranges[0]).unwrap();
+        assert!(!yaml.contains("features"), "{yaml}");
+    }
+
+    #[test]
+    fn font_axes_are_set_per_style() {
+        let axes: FontAxes =
+            serde_yaml_ng::from_str("regular: [[wght, 450]]\nbold: [[wght, 650], [wdth, 90.5]]\n")
+                .unwrap();
+        assert_eq!(axes.regular, [("wght".to_string(), 450.0)]);
+        assert_eq!(
+            axes.bold,
+            [("wght".to_string(), 650.0), ("wdth".to_string(), 90.5)]
+        );
+        assert!(axes.italic.is_empty());
+        assert!(!axes.is_empty());
+
+        assert!(FontAxes::default().is_empty());
+        let yaml = serde_yaml_ng::to_string(&FontAxes::default()).unwrap();
+        assert_eq!(yaml.trim(), "{}");
+    }
+}
@@ -24,11 +24,58 @@ pub enum KeyModifier {
     Super,
 }
 
+/// Input context a keybinding applies in.
+///
+/// A binding only fires while its mode is active, so copy mode motions can be
+/// bound to plain keys without affecting typing in the terminal.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeybindingMode {
+    /// Regular terminal input
+    #[default]
+    Normal,
+    /// Copy mode (vi-style navigation and selection)
+    Copy,
+    /// Typing a copy mode search query
+    Search,
+}
+
+impl KeybindingMode {
+    /// Whether this is the default mode (used to omit it when saving).
+    pub fn is_normal(&self) -> bool {
+        *self == Self::Normal
+    }
+}
+
 /// A keybinding configuration entry
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct KeyBinding {
     /// Key combination string, e.g., "CmdOrCtrl+Shift+B"
     pub key: String,
     /// Action name, e.g., "toggle_background_shader"
     pub action: String,
+    /// Mode the binding applies in; bindings without one apply in normal mode
+    #[serde(default, skip_serializing_if = "KeybindingMode::is_normal")]
+    pub mode: KeybindingMode,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_mode_defaults_to_normal() {
+        let bindings: Vec<KeyBinding> = serde_yaml_ng::from_str(
+            "- key: Ctrl+Shift+B\n  action: toggle_background_shader\n\
+             - key: H\n  action: copy_mode_move_left\n  mode: copy\n",
+        )
+        .unwrap();
+        assert_eq!(bindings[0].mode, KeybindingMode::Normal);
+        assert_eq!(bindings[1].mode, KeybindingMode::Copy);
+
+        // Normal mode is left out when saving
+        let yaml = serde_yaml_ng::to_string(&bindings).unwrap();
+        assert!(!yaml.contains("normal"), "{yaml}");
+        assert!(yaml.contains("mode: copy"), "{yaml}");
+    }
 }
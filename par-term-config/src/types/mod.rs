@@ -5,9 +5,9 @@
 //! - `color`      — Color conversion helper functions
 //! - `font`       — Font range, thin strokes, file drop, download location
 //! - `integration`— Shader/shell install prompts, update frequency, progress bar
-//! - `keybinding` — KeyModifier, KeyBinding
+//! - `keybinding` — KeyModifier, KeyBinding, KeybindingMode
 //! - `rendering`  — GPU/VSync/power, image scaling, background, pane/divider layout
-//! - `selection`  — Smart selection rules and defaults
+//! - `selection`  — Smart selection rules and defaults, clipboard scope
 //! - `shader`     — Shader metadata, config, and resolved shader types
 //! - `shell`      — Shell type detection, exit action, startup directory
 //! - `tab_bar`    — Tab style/position/mode, window type, status bar position
@@ -33,29 +33,33 @@ pub use color::{
     color_tuple_to_f32_a, color_u8_to_f32, color_u8_to_f32_a, color_u8x4_rgb_to_f32,
     color_u8x4_rgb_to_f32_a, color_u8x4_to_f32,
 };
-pub use font::{DownloadSaveLocation, DroppedFileQuoteStyle, FontRange, ThinStrokesMode};
+pub use font::{DownloadSaveLocation, DroppedFileQuoteStyle, FontAxes, FontRange, ThinStrokesMode};
 pub use integration::{
     InstallPromptState, IntegrationVersions, ProgressBarPosition, ProgressBarStyle,
     ShaderInstallPrompt, UpdateCheckFrequency,
 };
-pub use keybinding::{KeyBinding, KeyModifier};
+pub use keybinding::{KeyBinding, KeyModifier, KeybindingMode};
 pub use rendering::{
-    BackgroundImageMode, BackgroundMode, DividerRect, DividerStyle, ImageScalingMode,
-    PaneBackground, PaneBackgroundConfig, PaneId, PaneTitlePosition, PowerPreference,
-    SeparatorMark, TabId, VsyncMode,
+    BackgroundImageMode, BackgroundMode, BlendColorSpace, DividerRect, DividerStyle,
+    ImageScalingMode, PaneBackground, PaneBackgroundConfig, PaneId, PaneTitlePosition,
+    PowerPreference, SeparatorMark, TabId, VsyncMode,
+};
+pub use selection::{
+    ClipboardScope, CopyLineEnding, PasteBracketMarkers, PasteControlSequences,
+    SmartSelectionPrecision, SmartSelectionRule, default_smart_selection_rules,
 };
-pub use selection::{SmartSelectionPrecision, SmartSelectionRule, default_smart_selection_rules};
 pub use shader::{
     CursorShaderConfig, CursorShaderMetadata, ResolvedCursorShaderConfig, ResolvedShaderConfig,
     ShaderBackgroundBlendMode, ShaderConfig, ShaderMetadata, ShaderSafetyBadge,
 };
 pub use shell::{ShellExitAction, ShellType, StartupDirectoryMode};
 pub use tab_bar::{
-    NewTabPosition, RemoteTabTitleFormat, StatusBarPosition, TabBarMode, TabBarPosition, TabStyle,
-    TabTitleMode, WindowType,
+    NewTabPosition, RemoteTabTitleFormat, StatusBarPosition, TabBarMode, TabBarPosition,
+    TabFontFamily, TabStyle, TabTitleMode, TabTitleVars, WindowType,
 };
 pub use terminal::{
-    CursorStyle, LinkUnderlineStyle, LogLevel, ModifierRemapping, ModifierTarget, OptionKeyMode,
-    SemanticHistoryEditorMode, SessionLogFormat, UnfocusedCursorStyle,
+    BackspaceSends, ColorMode, CursorStyle, FormFeed, HyperlinkClickModifier, LinkUnderlineStyle,
+    LogLevel, MarkTrimPolicy, ModifierRemapping, ModifierTarget, OptionKeyMode, PrinterOutput,
+    PromptContinuation, SemanticHistoryEditorMode, SessionLogFormat, UnfocusedCursorStyle,
 };
 pub use unicode::{AmbiguousWidth, NormalizationForm, UnicodeVersion};
@@ -63,6 +63,35 @@ impl PowerPreference {
     }
 }
 
+/// Color space that rendered layers are blended in.
+///
+/// Theme colors and images are sRGB-encoded either way; this only changes
+/// where translucent layers and anti-aliased glyph edges are mixed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BlendColorSpace {
+    /// Blend the sRGB-encoded values directly, as most terminals do (default)
+    #[default]
+    Srgb,
+    /// Render to an sRGB surface and blend in linear light
+    Linear,
+}
+
+impl BlendColorSpace {
+    /// Display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            BlendColorSpace::Srgb => "sRGB (gamma-encoded)",
+            BlendColorSpace::Linear => "Linear (gamma-correct)",
+        }
+    }
+
+    /// All available blend color spaces for UI iteration
+    pub fn all() -> &'static [BlendColorSpace] {
+        &[BlendColorSpace::Srgb, BlendColorSpace::Linear]
+    }
+}
+
 // ============================================================================
 // Image / Background Types
 // ============================================================================
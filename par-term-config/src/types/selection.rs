@@ -1,7 +1,171 @@
-//! Smart selection rule types and defaults.
+//! Smart selection rule types and defaults, clipboard history scope, and
+//! paste marker handling.
 
 use serde::{Deserialize, Serialize};
 
+// ============================================================================
+// Clipboard Scope
+// ============================================================================
+
+/// Where clipboard history (`ClipboardSlot` entries) is stored and read.
+///
+/// The system clipboard itself is always global; this only controls which
+/// panes see each other's clipboard history slots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ClipboardScope {
+    /// One history shared by every pane in every window
+    #[default]
+    Global,
+    /// Panes in the same tab share a history
+    PerTab,
+    /// Each pane keeps its own isolated history
+    PerPane,
+}
+
+impl ClipboardScope {
+    /// Display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ClipboardScope::Global => "Global (shared by all panes)",
+            ClipboardScope::PerTab => "Per tab",
+            ClipboardScope::PerPane => "Per pane (isolated)",
+        }
+    }
+
+    /// All available scopes for UI iteration
+    pub fn all() -> &'static [ClipboardScope] {
+        &[
+            ClipboardScope::Global,
+            ClipboardScope::PerTab,
+            ClipboardScope::PerPane,
+        ]
+    }
+}
+
+/// What to do with bracketed paste markers already present in pasted text.
+///
+/// Text copied out of a nested session (an editor inside a shell inside
+/// another terminal) can carry the `ESC [200~` / `ESC [201~` markers the
+/// inner program failed to consume.  Pasted back, they show up as stray
+/// `[200~` text or end the outer bracket early.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PasteBracketMarkers {
+    /// Remove embedded markers before pasting
+    #[default]
+    Strip,
+    /// Leave the marker text in the payload.  The paste sanitizer still
+    /// removes the ESC or CSI that would make it a live marker, even when
+    /// control sequences are allowed, so only `[200~` / `[201~` text remains
+    Preserve,
+}
+
+impl PasteBracketMarkers {
+    /// Display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PasteBracketMarkers::Strip => "Strip",
+            PasteBracketMarkers::Preserve => "Preserve as text",
+        }
+    }
+
+    /// All available modes for UI iteration
+    pub fn all() -> &'static [PasteBracketMarkers] {
+        &[PasteBracketMarkers::Strip, PasteBracketMarkers::Preserve]
+    }
+}
+
+/// What to do with terminal control sequences (CSI, OSC, DCS, ...) in pasted
+/// text.
+///
+/// Copied terminal output can contain replies such as a cursor position
+/// report (`ESC [12;5R`) or a device attributes response; pasted back, they
+/// look to the running program like the terminal answering a query it never
+/// sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PasteControlSequences {
+    /// Remove control characters only; the rest of a sequence (`[12;5R`)
+    /// is pasted as text
+    #[default]
+    RemoveControls,
+    /// Remove whole sequences, parameters and string payloads included
+    Strip,
+    /// Paste complete sequences unchanged (other control characters are
+    /// still removed)
+    Allow,
+}
+
+impl PasteControlSequences {
+    /// Display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PasteControlSequences::RemoveControls => "Remove control characters",
+            PasteControlSequences::Strip => "Strip whole sequences",
+            PasteControlSequences::Allow => "Allow",
+        }
+    }
+
+    /// All available modes for UI iteration
+    pub fn all() -> &'static [PasteControlSequences] {
+        &[
+            PasteControlSequences::RemoveControls,
+            PasteControlSequences::Strip,
+            PasteControlSequences::Allow,
+        ]
+    }
+}
+
+/// Line ending written between lines of copied text.
+///
+/// The grid has no line endings of its own; copied lines are joined with
+/// this separator whatever the shell printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum CopyLineEnding {
+    /// Unix line feed (`\n`)
+    #[default]
+    Lf,
+    /// Windows carriage return + line feed (`\r\n`)
+    Crlf,
+    /// Classic Mac carriage return (`\r`)
+    Cr,
+}
+
+impl CopyLineEnding {
+    /// Display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            CopyLineEnding::Lf => "LF (Unix)",
+            CopyLineEnding::Crlf => "CRLF (Windows)",
+            CopyLineEnding::Cr => "CR (classic Mac)",
+        }
+    }
+
+    /// All available line endings for UI iteration
+    pub fn all() -> &'static [CopyLineEnding] {
+        &[CopyLineEnding::Lf, CopyLineEnding::Crlf, CopyLineEnding::Cr]
+    }
+
+    /// The separator characters
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CopyLineEnding::Lf => "\n",
+            CopyLineEnding::Crlf => "\r\n",
+            CopyLineEnding::Cr => "\r",
+        }
+    }
+
+    /// Replace the `\n` line separators in `text` with this line ending.
+    pub fn apply(&self, text: &str) -> String {
+        match self {
+            CopyLineEnding::Lf => text.to_string(),
+            _ => text.replace('\n', self.as_str()),
+        }
+    }
+}
+
 // ============================================================================
 // Smart Selection Types
 // ============================================================================
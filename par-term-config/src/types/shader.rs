@@ -514,6 +514,16 @@ impl Default for ResolvedCursorShaderConfig {
     }
 }
 
+impl ResolvedCursorShaderConfig {
+    /// Whether the default cursor should be hidden while this shader runs.
+    ///
+    /// A shader that hides the cursor still lets it show in the alt screen
+    /// when `disable_in_alt_screen` is set, since the shader is paused there.
+    pub fn hides_default_cursor(&self, is_alt_screen: bool) -> bool {
+        self.hides_cursor && !(self.disable_in_alt_screen && is_alt_screen)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,6 +170,24 @@ impl ShellExitAction {
             Self::RestartImmediately | Self::RestartWithPrompt | Self::RestartAfterDelay
         )
     }
+
+    /// Action to apply for a shell that exited with `exit_code`.
+    ///
+    /// With `keep_on_failure`, a non-zero exit keeps the pane open (so the
+    /// error stays readable) instead of closing or restarting it; a zero exit
+    /// always uses `self`.
+    pub fn for_exit_code(self, keep_on_failure: bool, exit_code: i32) -> ShellExitAction {
+        if keep_on_failure && exit_code != 0 {
+            Self::Keep
+        } else {
+            self
+        }
+    }
+
+    /// Status line written into a pane kept open after its shell failed.
+    pub fn exit_status_line(exit_code: i32) -> String {
+        format!("\r\n[Process exited with code {exit_code}]\r\n")
+    }
 }
 
 /// Startup directory mode
@@ -206,3 +224,47 @@ impl StartupDirectoryMode {
         ]
     }
 }
+
+#[cfg(test)]
+mod shell_exit_action_tests {
+    use super::*;
+
+    #[test]
+    fn exit_zero_uses_configured_action() {
+        assert_eq!(
+            ShellExitAction::Close.for_exit_code(true, 0),
+            ShellExitAction::Close
+        );
+        assert_eq!(
+            ShellExitAction::RestartImmediately.for_exit_code(true, 0),
+            ShellExitAction::RestartImmediately
+        );
+    }
+
+    #[test]
+    fn non_zero_exit_keeps_pane_when_policy_enabled() {
+        assert_eq!(
+            ShellExitAction::Close.for_exit_code(true, 1),
+            ShellExitAction::Keep
+        );
+        assert_eq!(
+            ShellExitAction::RestartAfterDelay.for_exit_code(true, 130),
+            ShellExitAction::Keep
+        );
+    }
+
+    #[test]
+    fn non_zero_exit_uses_configured_action_when_policy_disabled() {
+        assert_eq!(
+            ShellExitAction::Close.for_exit_code(false, 1),
+            ShellExitAction::Close
+        );
+    }
+
+    #[test]
+    fn exit_status_line_includes_code() {
+        let line = ShellExitAction::exit_status_line(1);
+        assert!(line.contains("[Process exited with code 1]"));
+        assert!(line.starts_with("\r\n"));
+    }
+}
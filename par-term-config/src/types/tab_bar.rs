@@ -164,6 +164,71 @@ impl RemoteTabTitleFormat {
     }
 }
 
+// ============================================================================
+// Tab Title Template
+// ============================================================================
+
+/// Values substituted into a tab title template (`tab_title_template`).
+///
+/// Supported placeholders are `{cwd}`, `{command}`, `{exit}` and `{index}`.
+/// A placeholder whose value is unknown (e.g. no shell integration yet)
+/// renders as an empty string; unrecognised `{names}` are kept verbatim.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TabTitleVars {
+    /// `{cwd}` — working directory from shell integration (home shown as `~`)
+    pub cwd: Option<String>,
+    /// `{command}` — last command reported by shell integration
+    pub command: Option<String>,
+    /// `{exit}` — exit code of the last command
+    pub exit: Option<i32>,
+    /// `{index}` — 1-based position of the tab in the tab bar
+    pub index: usize,
+}
+
+impl TabTitleVars {
+    /// Render `template`, replacing each known placeholder with its value.
+    pub fn render(&self, template: &str) -> String {
+        let mut out = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+            let Some(end) = after
+                .find(['{', '}'])
+                .filter(|&i| after.as_bytes()[i] == b'}')
+            else {
+                // Unterminated or nested brace: keep the `{` literally
+                out.push('{');
+                rest = after;
+                continue;
+            };
+            let name = &after[..end];
+            match self.value(name) {
+                Some(value) => out.push_str(&value),
+                None => {
+                    out.push('{');
+                    out.push_str(name);
+                    out.push('}');
+                }
+            }
+            rest = &after[end + 1..];
+        }
+        out.push_str(rest);
+        out
+    }
+
+    /// Value for a placeholder name, or `None` if the name is not recognised.
+    fn value(&self, name: &str) -> Option<String> {
+        match name {
+            "cwd" => Some(self.cwd.clone().unwrap_or_default()),
+            "command" => Some(self.command.clone().unwrap_or_default()),
+            "exit" => Some(self.exit.map(|code| code.to_string()).unwrap_or_default()),
+            "index" => Some(self.index.to_string()),
+            _ => None,
+        }
+    }
+}
+
 // ============================================================================
 // Window Types
 // ============================================================================
@@ -320,3 +385,77 @@ mod new_tab_position_tests {
         assert_eq!(after, NewTabPosition::AfterActive);
     }
 }
+
+#[cfg(test)]
+mod tab_title_template_tests {
+    use super::*;
+
+    fn vars() -> TabTitleVars {
+        TabTitleVars {
+            cwd: Some("~/src/par-term".to_string()),
+            command: Some("cargo test".to_string()),
+            exit: Some(101),
+            index: 3,
+        }
+    }
+
+    #[test]
+    fn substitutes_all_variables() {
+        assert_eq!(
+            vars().render("{index}: {cwd} [{command} → {exit}]"),
+            "3: ~/src/par-term [cargo test → 101]"
+        );
+    }
+
+    #[test]
+    fn missing_variables_render_empty() {
+        let vars = TabTitleVars {
+            index: 1,
+            ..Default::default()
+        };
+        assert_eq!(vars.render("{index} {cwd}|{command}|{exit}"), "1 ||");
+    }
+
+    #[test]
+    fn unknown_placeholders_are_kept() {
+        assert_eq!(vars().render("{host}:{index}"), "{host}:3");
+    }
+
+    #[test]
+    fn unbalanced_braces_are_literal() {
+        assert_eq!(vars().render("a { b {index}"), "a { b 3");
+        assert_eq!(vars().render("trailing {"), "trailing {");
+        assert_eq!(vars().render("close } only"), "close } only");
+    }
+
+    #[test]
+    fn plain_text_is_unchanged() {
+        assert_eq!(vars().render("no placeholders"), "no placeholders");
+    }
+}
+
+/// Font family for tab titles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TabFontFamily {
+    /// egui's proportional UI font (default)
+    #[default]
+    Proportional,
+    /// Monospace font
+    Monospace,
+}
+
+impl TabFontFamily {
+    /// Human-readable label for the settings UI combo box.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Proportional => "Proportional",
+            Self::Monospace => "Monospace",
+        }
+    }
+
+    /// All variants in display order.
+    pub fn all() -> &'static [Self] {
+        &[Self::Proportional, Self::Monospace]
+    }
+}
@@ -57,6 +57,39 @@ pub enum OptionKeyMode {
     Esc,
 }
 
+/// Byte sent by the Backspace key.
+///
+/// Most modern systems expect DEL (0x7F), but some remote hosts and legacy
+/// applications configured with `stty erase ^H` expect BS (0x08).  The choice
+/// applies regardless of which modifiers are held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum BackspaceSends {
+    /// Send DEL (0x7F, `^?`) - xterm/VT220 default
+    #[default]
+    Delete,
+    /// Send BS (0x08, `^H`) - VT100/legacy hosts
+    Backspace,
+}
+
+impl BackspaceSends {
+    /// Bytes written to the PTY for the Backspace key
+    pub fn bytes(self) -> &'static [u8] {
+        match self {
+            Self::Delete => b"\x7f",
+            Self::Backspace => b"\x08",
+        }
+    }
+
+    /// Human-readable label for the settings UI
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Self::Delete => "DEL (^?)",
+            Self::Backspace => "BS (^H)",
+        }
+    }
+}
+
 /// Target modifier for remapping.
 ///
 /// Allows remapping one modifier key to behave as another.
@@ -298,3 +331,285 @@ impl LinkUnderlineStyle {
         &[LinkUnderlineStyle::Solid, LinkUnderlineStyle::Stipple]
     }
 }
+
+/// Modifier that must be held while clicking to open a link (URL, file path
+/// or OSC 8 hyperlink).  Without it the click selects text as usual.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum HyperlinkClickModifier {
+    /// Cmd on macOS, Ctrl on other platforms
+    #[default]
+    CmdOrCtrl,
+    Ctrl,
+    Alt,
+    Shift,
+    /// Cmd on macOS, the Super/Windows key elsewhere
+    Super,
+    /// A plain click opens links; clicks with any modifier select instead
+    None,
+}
+
+impl HyperlinkClickModifier {
+    /// Display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            HyperlinkClickModifier::CmdOrCtrl => "Cmd/Ctrl (platform default)",
+            HyperlinkClickModifier::Ctrl => "Ctrl",
+            HyperlinkClickModifier::Alt => "Alt/Option",
+            HyperlinkClickModifier::Shift => "Shift",
+            HyperlinkClickModifier::Super => "Cmd/Super",
+            HyperlinkClickModifier::None => "None (plain click)",
+        }
+    }
+
+    /// All available modifiers for UI iteration
+    pub fn all() -> &'static [HyperlinkClickModifier] {
+        &[
+            HyperlinkClickModifier::CmdOrCtrl,
+            HyperlinkClickModifier::Ctrl,
+            HyperlinkClickModifier::Alt,
+            HyperlinkClickModifier::Shift,
+            HyperlinkClickModifier::Super,
+            HyperlinkClickModifier::None,
+        ]
+    }
+
+    /// Whether a left click with these modifiers held opens the link under
+    /// the mouse rather than starting a selection.  Other modifiers held
+    /// alongside the required one don't matter.
+    pub fn opens_link(self, ctrl: bool, alt: bool, shift: bool, super_key: bool) -> bool {
+        match self {
+            HyperlinkClickModifier::CmdOrCtrl if cfg!(target_os = "macos") => super_key,
+            HyperlinkClickModifier::CmdOrCtrl | HyperlinkClickModifier::Ctrl => ctrl,
+            HyperlinkClickModifier::Alt => alt,
+            HyperlinkClickModifier::Shift => shift,
+            HyperlinkClickModifier::Super => super_key,
+            HyperlinkClickModifier::None => !(ctrl || alt || shift || super_key),
+        }
+    }
+}
+
+/// What happens to prompt marks when their lines are trimmed from scrollback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MarkTrimPolicy {
+    /// Drop marks whose lines were trimmed
+    #[default]
+    Drop,
+    /// Keep the newest trimmed mark pinned to the oldest remaining line
+    KeepEarliest,
+}
+
+impl MarkTrimPolicy {
+    /// Display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            MarkTrimPolicy::Drop => "Drop",
+            MarkTrimPolicy::KeepEarliest => "Keep at top of scrollback",
+        }
+    }
+
+    /// All available policies for UI iteration
+    pub fn all() -> &'static [MarkTrimPolicy] {
+        &[MarkTrimPolicy::Drop, MarkTrimPolicy::KeepEarliest]
+    }
+}
+
+/// How OSC 133 continuation prompts (`OSC 133;A;k=s`) are handled.
+///
+/// Shells mark the secondary prompt shown while a command spans several
+/// input lines (`PS2`) with a prompt-start marker carrying a `k=` kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PromptContinuation {
+    /// Treat continuation prompts as part of the command being entered, so
+    /// the command keeps its mark and start line
+    #[default]
+    Merge,
+    /// Treat every prompt marker as a new prompt
+    NewPrompt,
+}
+
+impl PromptContinuation {
+    /// Display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PromptContinuation::Merge => "Part of the command",
+            PromptContinuation::NewPrompt => "New prompt",
+        }
+    }
+
+    /// All available modes for UI iteration
+    pub fn all() -> &'static [PromptContinuation] {
+        &[PromptContinuation::Merge, PromptContinuation::NewPrompt]
+    }
+}
+
+/// Color depth advertised to, or rendered for, terminal applications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ColorMode {
+    /// 24-bit RGB colors
+    #[default]
+    Truecolor,
+    /// The xterm 256-color palette
+    Palette256,
+    /// The 16 ANSI colors of the theme
+    Ansi16,
+}
+
+impl ColorMode {
+    /// Display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ColorMode::Truecolor => "24-bit (truecolor)",
+            ColorMode::Palette256 => "256 colors",
+            ColorMode::Ansi16 => "16 colors",
+        }
+    }
+
+    /// All available modes for UI iteration
+    pub fn all() -> &'static [ColorMode] {
+        &[
+            ColorMode::Truecolor,
+            ColorMode::Palette256,
+            ColorMode::Ansi16,
+        ]
+    }
+
+    /// Value of `$COLORTERM` advertising this mode.
+    ///
+    /// Applications treat an empty `$COLORTERM` as "no 24-bit support".
+    pub fn colorterm(&self) -> &'static str {
+        match self {
+            ColorMode::Truecolor => "truecolor",
+            ColorMode::Palette256 | ColorMode::Ansi16 => "",
+        }
+    }
+}
+
+/// How form feed (FF, `0x0C`) is handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum FormFeed {
+    /// Do nothing
+    #[default]
+    Ignore,
+    /// Move down one line, like a line feed
+    Newline,
+    /// Clear the screen and home the cursor
+    ClearScreen,
+}
+
+impl FormFeed {
+    /// Display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            FormFeed::Ignore => "Ignore",
+            FormFeed::Newline => "Line feed",
+            FormFeed::ClearScreen => "Clear screen",
+        }
+    }
+
+    /// All available modes for UI iteration
+    pub fn all() -> &'static [FormFeed] {
+        &[FormFeed::Ignore, FormFeed::Newline, FormFeed::ClearScreen]
+    }
+}
+
+/// Where media copy (printer) output is sent.
+///
+/// Legacy applications print by bracketing data with `CSI 5 i` (printer on)
+/// and `CSI 4 i` (printer off).  The bytes in between are meant for the
+/// printer and are kept off screen.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PrinterOutput {
+    /// Drop printer output
+    #[default]
+    Discard,
+    /// Append printer output to a file
+    File { path: String },
+}
+
+impl PrinterOutput {
+    /// Display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PrinterOutput::Discard => "Discard",
+            PrinterOutput::File { .. } => "Append to file",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backspace_sends_bytes() {
+        assert_eq!(BackspaceSends::default(), BackspaceSends::Delete);
+        assert_eq!(BackspaceSends::Delete.bytes(), b"\x7f");
+        assert_eq!(BackspaceSends::Backspace.bytes(), b"\x08");
+    }
+
+    #[test]
+    fn backspace_sends_serde_lowercase() {
+        let mode: BackspaceSends = serde_yaml_ng::from_str("backspace").unwrap();
+        assert_eq!(mode, BackspaceSends::Backspace);
+        assert_eq!(
+            serde_yaml_ng::to_string(&BackspaceSends::Delete)
+                .unwrap()
+                .trim(),
+            "delete"
+        );
+    }
+
+    #[test]
+    fn default_link_click_needs_the_platform_modifier() {
+        let modifier = HyperlinkClickModifier::default();
+        assert!(!modifier.opens_link(false, false, false, false));
+        if cfg!(target_os = "macos") {
+            assert!(modifier.opens_link(false, false, false, true));
+            assert!(!modifier.opens_link(true, false, false, false));
+        } else {
+            assert!(modifier.opens_link(true, false, false, false));
+            assert!(!modifier.opens_link(false, false, false, true));
+        }
+    }
+
+    #[test]
+    fn link_click_modifier_decides_open_or_select() {
+        // Ctrl+Shift still counts as Ctrl
+        assert!(HyperlinkClickModifier::Ctrl.opens_link(true, false, true, false));
+        assert!(!HyperlinkClickModifier::Ctrl.opens_link(false, true, false, false));
+        assert!(HyperlinkClickModifier::Alt.opens_link(false, true, false, false));
+        assert!(!HyperlinkClickModifier::Shift.opens_link(false, false, false, false));
+        assert!(HyperlinkClickModifier::Super.opens_link(false, false, false, true));
+
+        // Plain clicks open links; a modified click selects
+        let plain = HyperlinkClickModifier::None;
+        assert!(plain.opens_link(false, false, false, false));
+        assert!(!plain.opens_link(false, false, true, false));
+        assert!(!plain.opens_link(true, false, false, false));
+
+        let parsed: HyperlinkClickModifier = serde_yaml_ng::from_str("cmd_or_ctrl").unwrap();
+        assert_eq!(parsed, HyperlinkClickModifier::CmdOrCtrl);
+        let parsed: HyperlinkClickModifier = serde_yaml_ng::from_str("none").unwrap();
+        assert_eq!(parsed, HyperlinkClickModifier::None);
+    }
+
+    #[test]
+    fn printer_output_serde() {
+        let discard: PrinterOutput = serde_yaml_ng::from_str("type: discard").unwrap();
+        assert_eq!(discard, PrinterOutput::Discard);
+        let file: PrinterOutput =
+            serde_yaml_ng::from_str("type: file\npath: /tmp/print.txt").unwrap();
+        assert_eq!(
+            file,
+            PrinterOutput::File {
+                path: "/tmp/print.txt".to_string()
+            }
+        );
+    }
+}
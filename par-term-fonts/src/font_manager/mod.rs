@@ -5,21 +5,28 @@
 //! - Primary font with bold/italic/bold-italic variants
 //! - Unicode range-specific fonts (e.g., CJK, emoji)
 //! - Automatic fallback chain for missing glyphs
+//! - Ligature fallback font for primary fonts without ligatures
 //! - HarfBuzz-based text shaping via rustybuzz
 
 mod fallbacks;
 mod loader;
+mod presentation;
 mod types;
 
+use std::ops::Range;
 use std::sync::Arc;
 
 use anyhow::Result;
 use fontdb::Database;
-use swash::FontRef;
+use swash::{FontRef, NormalizedCoord};
+use unicode_segmentation::UnicodeSegmentation;
 
-use crate::text_shaper::{ShapedRun, ShapingOptions, TextShaper};
+use crate::text_shaper::{ShapedRun, ShapedSegment, ShapingOptions, TextShaper};
 
 pub use fallbacks::FALLBACK_FAMILIES;
+pub use presentation::{
+    EMOJI_PRESENTATION_SELECTOR, Presentation, TEXT_PRESENTATION_SELECTOR, has_color_glyphs,
+};
 pub use types::{FontData, UnicodeRangeFont};
 
 /// Manages multiple fonts with fallback chain.
@@ -30,7 +37,8 @@ pub use types::{FontData, UnicodeRangeFont};
 /// - 2: Italic font (if available)
 /// - 3: Bold-italic font (if available)
 /// - 4..4+N: Unicode range fonts (N = number of range fonts)
-/// - 4+N..: Fallback fonts
+/// - 4+N..4+N+M: Fallback fonts (M = number of fallback fonts)
+/// - 4+N+M: Ligature fallback font (if configured)
 pub struct FontManager {
     /// Primary font (regular weight, from config or embedded)
     primary: FontData,
@@ -50,12 +58,17 @@ pub struct FontManager {
     /// Fallback fonts in priority order
     fallbacks: Vec<FontData>,
 
+    /// Ligature-capable font for sequences the primary font does not ligate
+    ligature_fallback: Option<FontData>,
+
     /// Font database for system font queries
-    #[allow(dead_code)] // Retained for future runtime font queries
     font_db: Database,
 
     /// Text shaper for ligatures and complex scripts
     text_shaper: TextShaper,
+
+    /// Embolden and slant glyphs when the requested bold/italic face is missing
+    synthesize_styles: bool,
 }
 
 impl FontManager {
@@ -67,12 +80,15 @@ impl FontManager {
     /// * `italic_family` - Italic font family name (optional)
     /// * `bold_italic_family` - Bold italic font family name (optional)
     /// * `font_ranges` - Unicode range-specific font mappings
+    /// * `font_axes` - Variable font axis values for each style; a style without
+    ///   its own family uses the primary font at its axis values
     pub fn new(
         primary_family: Option<&str>,
         bold_family: Option<&str>,
         italic_family: Option<&str>,
         bold_italic_family: Option<&str>,
         font_ranges: &[par_term_config::FontRange],
+        font_axes: &par_term_config::FontAxes,
     ) -> Result<Self> {
         let mut font_db = Database::new();
 
@@ -81,7 +97,8 @@ impl FontManager {
         log::info!("Loaded {} system fonts", font_db.len());
 
         // Load primary font
-        let primary = Self::load_primary_font(&mut font_db, primary_family)?;
+        let primary =
+            Self::load_primary_font(&mut font_db, primary_family)?.with_axes(&font_axes.regular);
 
         // Build fallback chain
         let fallbacks = Self::build_fallback_chain(&mut font_db);
@@ -109,6 +126,9 @@ impl FontManager {
             fontdb::Weight::BOLD,
             Some(fontdb::Style::Italic),
         );
+        let bold = Self::apply_style_axes(bold, &primary, &font_axes.bold);
+        let italic = Self::apply_style_axes(italic, &primary, &font_axes.italic);
+        let bold_italic = Self::apply_style_axes(bold_italic, &primary, &font_axes.bold_italic);
 
         // Load Unicode range-specific fonts
         let range_fonts = Self::load_range_fonts(&mut font_db, font_ranges);
@@ -120,11 +140,66 @@ impl FontManager {
             bold_italic,
             range_fonts,
             fallbacks,
+            ligature_fallback: None,
             font_db,
             text_shaper: TextShaper::new(),
+            synthesize_styles: true,
         })
     }
 
+    /// Set the font used for ligatures the primary font lacks.
+    ///
+    /// # Arguments
+    /// * `family` - Ligature-capable font family name, or `None` to disable
+    ///
+    /// # Returns
+    /// `true` if a font is now in use; `false` if disabled or not found.
+    pub fn set_ligature_fallback(&mut self, family: Option<&str>) -> bool {
+        self.ligature_fallback = family.and_then(|family_name| {
+            let font_data = loader::load_font_from_db(&mut self.font_db, family_name);
+            if font_data.is_some() {
+                log::info!("Loaded ligature fallback font: {}", family_name);
+            } else {
+                log::warn!("Ligature fallback font '{}' not found", family_name);
+            }
+            font_data
+        });
+        // The font index may now refer to a different font
+        self.text_shaper.clear_cache();
+        self.ligature_fallback.is_some()
+    }
+
+    /// Enable or disable synthetic bold and italic (on by default).
+    ///
+    /// See [`synthetic_style`](Self::synthetic_style).
+    pub fn set_synthesize_styles(&mut self, enabled: bool) {
+        self.synthesize_styles = enabled;
+    }
+
+    /// Emphasis the renderer has to synthesize for a glyph from `font_index`.
+    ///
+    /// Returns `(synthetic_bold, synthetic_italic)`: bold (italic) is
+    /// synthesized when it was requested but the glyph comes from a font that
+    /// is not a bold (italic) face, e.g. the primary font standing in for a
+    /// missing bold font, or a fallback font.  Always `(false, false)` when
+    /// synthesis is disabled.
+    pub fn synthetic_style(&self, font_index: usize, bold: bool, italic: bool) -> (bool, bool) {
+        if !self.synthesize_styles {
+            return (false, false);
+        }
+        (
+            bold && !matches!(font_index, 1 | 3),
+            italic && !matches!(font_index, 2 | 3),
+        )
+    }
+
+    /// Font index of the ligature fallback font, if one is loaded.
+    pub fn ligature_fallback_index(&self) -> Option<usize> {
+        self.ligature_fallback
+            .as_ref()
+            .map(|_| 4 + self.range_fonts.len() + self.fallbacks.len())
+    }
+
     /// Load the primary font from system or embedded.
     fn load_primary_font(font_db: &mut Database, family: Option<&str>) -> Result<FontData> {
         if let Some(family_name) = family {
@@ -186,6 +261,25 @@ impl FontManager {
         })
     }
 
+    /// Apply a style's variable font axes.
+    ///
+    /// A style without its own font becomes an instance of the primary font
+    /// when the axes select one that differs from the default.
+    fn apply_style_axes(
+        font: Option<FontData>,
+        primary: &FontData,
+        axes: &[(String, f32)],
+    ) -> Option<FontData> {
+        match font {
+            Some(font) => Some(font.with_axes(axes)),
+            None if axes.is_empty() => None,
+            None => {
+                let instance = primary.clone().with_axes(axes);
+                (!instance.coords.is_empty()).then_some(instance)
+            }
+        }
+    }
+
     /// Load Unicode range-specific fonts.
     fn load_range_fonts(
         font_db: &mut Database,
@@ -208,6 +302,7 @@ impl FontManager {
                     end: range.end,
                     font: font_data,
                     font_index: next_font_index,
+                    features: range.features.clone(),
                 });
                 log::info!(
                     "Successfully loaded range font: {} (index {})",
@@ -415,13 +510,79 @@ impl FontManager {
                     Some(&self.range_fonts[range_offset].font.font_ref)
                 } else {
                     let fallback_offset = range_offset - self.range_fonts.len();
-                    self.fallbacks.get(fallback_offset).map(|fd| &fd.font_ref)
+                    self.fallbacks
+                        .get(fallback_offset)
+                        .or(self.ligature_fallback_at(fallback_offset))
+                        .map(|fd| &fd.font_ref)
                 }
             }
             _ => None,
         }
     }
 
+    /// Normalized variation coordinates of the font at `font_index`.
+    ///
+    /// Pass these to swash metrics and scalers so variable fonts use the
+    /// configured axis values; empty for the default instance.
+    pub fn font_coords(&self, font_index: usize) -> &[NormalizedCoord] {
+        let font = match font_index {
+            0 => Some(&self.primary),
+            1 => self.bold.as_ref(),
+            2 => self.italic.as_ref(),
+            3 => self.bold_italic.as_ref(),
+            _ => None,
+        };
+        font.map_or(&[], |font| font.coords.as_slice())
+    }
+
+    /// OpenType features configured for the font at `font_index`.
+    ///
+    /// Only Unicode range fonts carry features; other fonts return an empty slice.
+    pub fn font_features(&self, font_index: usize) -> &[(String, u32)] {
+        self.range_fonts
+            .iter()
+            .find(|range_font| range_font.font_index == font_index)
+            .map_or(&[], |range_font| range_font.features.as_slice())
+    }
+
+    /// Glyph for a single `character` after the OpenType features of the font
+    /// at `font_index`.
+    ///
+    /// A range font configured with features (e.g. a stylistic set) can
+    /// substitute a character's default glyph, so the character is shaped
+    /// with those features.  Fonts without features, and shaping results that
+    /// are not a single glyph, keep `glyph_id`.
+    pub fn featured_glyph(&mut self, character: char, font_index: usize, glyph_id: u16) -> u16 {
+        if self.font_features(font_index).is_empty() {
+            return glyph_id;
+        }
+        let mut buf = [0u8; 4];
+        let run = self.shape_text_with_font_index(
+            character.encode_utf8(&mut buf),
+            font_index,
+            ShapingOptions::default(),
+        );
+        match run.glyphs.as_slice() {
+            [glyph] if glyph.glyph_id != 0 => u16::try_from(glyph.glyph_id).unwrap_or(glyph_id),
+            _ => glyph_id,
+        }
+    }
+
+    /// Like [`find_glyph`](Self::find_glyph), with the glyph substituted per
+    /// the features of its range font (see [`featured_glyph`](Self::featured_glyph)).
+    pub fn find_featured_glyph(
+        &mut self,
+        character: char,
+        bold: bool,
+        italic: bool,
+    ) -> Option<(usize, u16)> {
+        let (font_index, glyph_id) = self.find_glyph(character, bold, italic)?;
+        Some((
+            font_index,
+            self.featured_glyph(character, font_index, glyph_id),
+        ))
+    }
+
     /// Get the primary font reference.
     pub fn primary_font(&self) -> &FontRef<'static> {
         &self.primary.font_ref
@@ -433,7 +594,10 @@ impl FontManager {
             + self.bold.is_some() as usize
             + self.italic.is_some() as usize
             + self.bold_italic.is_some() as usize;
-        styled_count + self.range_fonts.len() + self.fallbacks.len()
+        styled_count
+            + self.range_fonts.len()
+            + self.fallbacks.len()
+            + self.ligature_fallback.is_some() as usize
     }
 
     /// Get raw font data bytes for a font index.
@@ -451,6 +615,7 @@ impl FontManager {
                     let fallback_offset = range_offset - self.range_fonts.len();
                     self.fallbacks
                         .get(fallback_offset)
+                        .or(self.ligature_fallback_at(fallback_offset))
                         .map(|fd| fd.data.as_slice())
                 }
             }
@@ -485,6 +650,7 @@ impl FontManager {
                     let fallback_offset = range_offset - self.range_fonts.len();
                     self.fallbacks
                         .get(fallback_offset)
+                        .or(self.ligature_fallback_at(fallback_offset))
                         .map(|fd| Arc::clone(&fd.data))
                         .unwrap_or_else(|| Arc::clone(&self.primary.data))
                 }
@@ -496,26 +662,77 @@ impl FontManager {
     /// Shape text using the appropriate font.
     ///
     /// Uses HarfBuzz (via rustybuzz) for ligatures, kerning, and complex script support.
+    /// When the bold or italic face is missing the glyphs are shaped with the
+    /// primary font and marked `synthetic_bold`/`synthetic_italic`.
     pub fn shape_text(
         &mut self,
         text: &str,
         bold: bool,
         italic: bool,
-        options: ShapingOptions,
+        mut options: ShapingOptions,
     ) -> Arc<ShapedRun> {
         let font_index = self.get_styled_font_index(bold, italic);
+        (options.synthetic_bold, options.synthetic_italic) =
+            self.synthetic_style(font_index, bold, italic);
         let font_data_arc = self.get_font_data_arc(font_index);
         self.text_shaper
             .shape_text(text, font_data_arc.as_slice(), font_index, options)
     }
 
+    /// Shape text using the appropriate font, taking ligatures it lacks from
+    /// the ligature fallback font (see [`set_ligature_fallback`](Self::set_ligature_fallback)).
+    ///
+    /// Without a ligature fallback font this returns a single segment shaped
+    /// like [`shape_text`](Self::shape_text).
+    pub fn shape_text_with_ligature_fallback(
+        &mut self,
+        text: &str,
+        bold: bool,
+        italic: bool,
+        mut options: ShapingOptions,
+    ) -> Vec<ShapedSegment> {
+        let font_index = self.get_styled_font_index(bold, italic);
+        (options.synthetic_bold, options.synthetic_italic) =
+            self.synthetic_style(font_index, bold, italic);
+        let font_data_arc = self.get_font_data_arc(font_index);
+        match (&self.ligature_fallback, self.ligature_fallback_index()) {
+            (Some(fallback), Some(fallback_index)) => {
+                let fallback_data = Arc::clone(&fallback.data);
+                self.text_shaper.shape_with_ligature_fallback(
+                    text,
+                    font_data_arc.as_slice(),
+                    font_index,
+                    fallback_data.as_slice(),
+                    fallback_index,
+                    options,
+                )
+            }
+            _ => vec![ShapedSegment {
+                range: 0..text.len(),
+                font_index,
+                run: self.text_shaper.shape_text(
+                    text,
+                    font_data_arc.as_slice(),
+                    font_index,
+                    options,
+                ),
+            }],
+        }
+    }
+
     /// Shape text using a specific font index.
+    ///
+    /// The OpenType features configured for a range font are added to
+    /// `options.features`.
     pub fn shape_text_with_font_index(
         &mut self,
         text: &str,
         font_index: usize,
-        options: ShapingOptions,
+        mut options: ShapingOptions,
     ) -> Arc<ShapedRun> {
+        options
+            .features
+            .extend_from_slice(self.font_features(font_index));
         let font_data_arc = self.get_font_data_arc(font_index);
         self.text_shaper
             .shape_text(text, font_data_arc.as_slice(), font_index, options)
@@ -531,6 +748,31 @@ impl FontManager {
         self.text_shaper.cache_size()
     }
 
+    /// Which font renders each part of `text`, for font coverage previews.
+    ///
+    /// Each grapheme cluster gets the font the renderer picks for it in the
+    /// regular style (see [`find_grapheme_glyph`](Self::find_grapheme_glyph)),
+    /// and neighbouring clusters drawn with the same font are merged into one
+    /// run.  Returns `(byte range, font index)` pairs in text order.  Clusters
+    /// no font covers are left out, so a gap between two ranges is where a
+    /// character would be missing.
+    pub fn resolve_runs(&mut self, text: &str) -> Vec<(Range<usize>, usize)> {
+        let mut runs: Vec<(Range<usize>, usize)> = Vec::new();
+        for (start, grapheme) in text.grapheme_indices(true) {
+            let Some((font_index, _)) = self.find_grapheme_glyph(grapheme, false, false) else {
+                continue;
+            };
+            let end = start + grapheme.len();
+            match runs.last_mut() {
+                Some((range, index)) if *index == font_index && range.end == start => {
+                    range.end = end;
+                }
+                _ => runs.push((start..end, font_index)),
+            }
+        }
+        runs
+    }
+
     /// Find glyph(s) for an entire grapheme cluster.
     ///
     /// This is essential for rendering multi-character sequences like:
@@ -538,6 +780,7 @@ impl FontManager {
     /// - ZWJ sequences (👨‍👩‍👧‍👦) - family emoji
     /// - Skin tone modifiers (👋🏽)
     /// - Combining characters (é = e + acute accent)
+    /// - Text or emoji presentation selectors (☎\u{FE0E}, ☎\u{FE0F})
     ///
     /// # Arguments
     /// * `grapheme` - The grapheme cluster string (may be multiple Unicode codepoints)
@@ -557,7 +800,14 @@ impl FontManager {
 
         // Fast path: single character graphemes use existing lookup
         if chars.len() == 1 {
-            return self.find_glyph(chars[0], bold, italic);
+            return self.find_featured_glyph(chars[0], bold, italic);
+        }
+
+        // VS15/VS16 pick between monochrome and color fonts
+        if let Some(presentation) = Presentation::requested_by(grapheme)
+            && let Some(found) = self.find_presentation_glyph(grapheme, presentation, bold, italic)
+        {
+            return Some(found);
         }
 
         // Multi-character grapheme: use text shaping to find the composed glyph
@@ -570,7 +820,10 @@ impl FontManager {
             if char_code >= range_font.start && char_code <= range_font.end {
                 // Shape the grapheme with this font
                 let font_data = range_font.font.data.as_slice();
-                let options = ShapingOptions::default();
+                let options = ShapingOptions {
+                    features: range_font.features.clone(),
+                    ..ShapingOptions::default()
+                };
                 let shaped = self.text_shaper.shape_text(
                     grapheme,
                     font_data,
@@ -641,6 +894,67 @@ impl FontManager {
         self.find_glyph(first_char, bold, italic)
     }
 
+    /// Find the glyph for a grapheme whose variation selector asks for text or
+    /// emoji presentation.
+    ///
+    /// Fonts are tried in the usual order (styled font, range fonts covering
+    /// the first character, fallbacks) and the first one with the requested
+    /// kind of glyph wins.  `None` when no font has one, in which case the
+    /// selector is ignored.
+    fn find_presentation_glyph(
+        &mut self,
+        grapheme: &str,
+        presentation: Presentation,
+        bold: bool,
+        italic: bool,
+    ) -> Option<(usize, u16)> {
+        let base = grapheme.chars().next()?;
+        let fallback_start_index = 4 + self.range_fonts.len();
+        let range_fonts = self
+            .range_fonts
+            .iter()
+            .filter(|range_font| (range_font.start..=range_font.end).contains(&(base as u32)))
+            .map(|range_font| range_font.font_index);
+        let candidates = std::iter::once(self.get_styled_font_index(bold, italic))
+            .chain(range_fonts)
+            .chain(fallback_start_index..fallback_start_index + self.fallbacks.len())
+            .filter_map(|font_index| Some((font_index, *self.get_font(font_index)?)));
+        let (font_index, glyph_id) = presentation.select_font(candidates, base)?;
+
+        // Shape the whole grapheme so sequences like keycaps (1\u{FE0F}\u{20E3})
+        // still compose in the chosen font
+        let options = ShapingOptions {
+            features: self.font_features(font_index).to_vec(),
+            ..ShapingOptions::default()
+        };
+        let font_data = self.get_font_data_arc(font_index);
+        let shaped =
+            self.text_shaper
+                .shape_text(grapheme, font_data.as_slice(), font_index, options);
+        let shaped_id = shaped
+            .glyphs
+            .first()
+            .map_or(0, |glyph| glyph.glyph_id as u16);
+        log::debug!(
+            "Grapheme '{}' uses {:?} presentation from font index {}",
+            grapheme,
+            presentation,
+            font_index
+        );
+        Some((
+            font_index,
+            if shaped_id != 0 { shaped_id } else { glyph_id },
+        ))
+    }
+
+    /// The ligature fallback font if `fallback_offset` (an offset into the
+    /// fallback fonts) is just past the last fallback font.
+    fn ligature_fallback_at(&self, fallback_offset: usize) -> Option<&FontData> {
+        self.ligature_fallback
+            .as_ref()
+            .filter(|_| fallback_offset == self.fallbacks.len())
+    }
+
     /// Get the font index for a given style combination.
     fn get_styled_font_index(&self, bold: bool, italic: bool) -> usize {
         match (bold, italic) {
@@ -655,10 +969,11 @@ impl FontManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use par_term_config::FontAxes;
 
     #[test]
     fn test_embedded_font_loads() {
-        let fm = FontManager::new(None, None, None, None, &[]);
+        let fm = FontManager::new(None, None, None, None, &[], &FontAxes::default());
         assert!(fm.is_ok(), "FontManager should load with embedded font");
         let fm = fm.unwrap();
         assert!(fm.font_count() >= 1, "Should have at least one font");
@@ -666,7 +981,7 @@ mod tests {
 
     #[test]
     fn test_primary_font_glyph_lookup() {
-        let fm = FontManager::new(None, None, None, None, &[]).unwrap();
+        let fm = FontManager::new(None, None, None, None, &[], &FontAxes::default()).unwrap();
         // ASCII characters should be found in the embedded font
         let result = fm.find_glyph('A', false, false);
         assert!(result.is_some(), "Should find glyph for 'A'");
@@ -675,9 +990,64 @@ mod tests {
         assert!(glyph_id > 0, "Glyph ID should be nonzero");
     }
 
+    #[test]
+    fn test_missing_ligature_fallback_is_ignored() {
+        let mut fm = FontManager::new(None, None, None, None, &[], &FontAxes::default()).unwrap();
+        let count = fm.font_count();
+        assert!(!fm.set_ligature_fallback(Some("No Such Font Family 7f3a")));
+        assert_eq!(fm.ligature_fallback_index(), None);
+        assert_eq!(fm.font_count(), count);
+
+        let segments =
+            fm.shape_text_with_ligature_fallback("a => b", false, false, ShapingOptions::default());
+        assert_eq!(segments.len(), 1);
+        assert_eq!(
+            (segments[0].range.clone(), segments[0].font_index),
+            (0..6, 0)
+        );
+    }
+
+    #[test]
+    fn test_range_font_features_apply_to_single_characters() {
+        let mut fm = FontManager::new(None, None, None, None, &[], &FontAxes::default()).unwrap();
+        let font =
+            FontData::new(include_bytes!("../../tests/fonts/JetBrainsMono-Regular.ttf").to_vec())
+                .unwrap();
+        let plain = |c: char| font.font_ref.charmap().map(c);
+        let font_index = 4;
+        fm.range_fonts.push(UnicodeRangeFont {
+            start: 'a' as u32,
+            end: 'z' as u32,
+            font: font.clone(),
+            font_index,
+            features: vec![("ss01".to_string(), 1)],
+        });
+
+        // ss01 swaps some letters of "Kafka" for alternates
+        let changed: Vec<char> = "afk"
+            .chars()
+            .filter(|&c| fm.featured_glyph(c, font_index, plain(c)) != plain(c))
+            .collect();
+        assert!(
+            !changed.is_empty(),
+            "ss01 should substitute a single letter"
+        );
+        let c = changed[0];
+        assert_ne!(fm.featured_glyph(c, font_index, plain(c)), 0);
+
+        // Fonts without features keep the charmap glyph
+        fm.range_fonts[0].features.clear();
+        assert_eq!(fm.featured_glyph(c, font_index, plain(c)), plain(c));
+        let (primary_index, primary_glyph) = fm.find_glyph('a', false, false).unwrap();
+        assert_eq!(
+            fm.find_featured_glyph('a', false, false),
+            Some((primary_index, primary_glyph))
+        );
+    }
+
     #[test]
     fn test_get_font_by_index() {
-        let fm = FontManager::new(None, None, None, None, &[]).unwrap();
+        let fm = FontManager::new(None, None, None, None, &[], &FontAxes::default()).unwrap();
         assert!(
             fm.get_font(0).is_some(),
             "Primary font should exist at index 0"
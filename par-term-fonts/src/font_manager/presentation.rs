@@ -0,0 +1,67 @@
+//! Text and emoji presentation requested by variation selectors.
+//!
+//! Characters such as ☎ or ✉ have both a text (monochrome outline) and an
+//! emoji (color) form.  A following U+FE0E (VS15) asks for the text form and
+//! U+FE0F (VS16) for the emoji form, so the font chosen for the grapheme must
+//! have the right kind of glyphs: outlines only for text, color glyph tables
+//! for emoji.
+
+use swash::{FontRef, tag_from_bytes};
+
+/// VS15: show the preceding character as text.
+pub const TEXT_PRESENTATION_SELECTOR: char = '\u{FE0E}';
+
+/// VS16: show the preceding character as emoji.
+pub const EMOJI_PRESENTATION_SELECTOR: char = '\u{FE0F}';
+
+/// Tables holding color glyphs: COLR layers, embedded bitmaps, Apple bitmaps
+/// and SVG documents.
+const COLOR_TABLES: [[u8; 4]; 4] = [*b"COLR", *b"CBDT", *b"sbix", *b"SVG "];
+
+/// Whether `font` draws any of its glyphs in color.
+pub fn has_color_glyphs(font: &FontRef) -> bool {
+    COLOR_TABLES
+        .iter()
+        .any(|tag| font.table(tag_from_bytes(tag)).is_some())
+}
+
+/// How a grapheme asks to be shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Presentation {
+    /// Monochrome, like the surrounding text (VS15)
+    Text,
+    /// Color emoji (VS16)
+    Emoji,
+}
+
+impl Presentation {
+    /// Presentation requested by a variation selector right after the first
+    /// character of `grapheme`, if any.
+    pub fn requested_by(grapheme: &str) -> Option<Self> {
+        let mut chars = grapheme.chars().skip(1);
+        match chars.next()? {
+            TEXT_PRESENTATION_SELECTOR => Some(Self::Text),
+            EMOJI_PRESENTATION_SELECTOR => Some(Self::Emoji),
+            _ => None,
+        }
+    }
+
+    /// Whether `font` has the kind of glyphs this presentation needs.
+    pub fn suits(self, font: &FontRef) -> bool {
+        has_color_glyphs(font) == (self == Self::Emoji)
+    }
+
+    /// The first of `candidates` (font index and font, in fallback order)
+    /// that suits this presentation and has a glyph for `base`, with that
+    /// glyph.
+    pub fn select_font<'a>(
+        self,
+        candidates: impl IntoIterator<Item = (usize, FontRef<'a>)>,
+        base: char,
+    ) -> Option<(usize, u16)> {
+        candidates.into_iter().find_map(|(index, font)| {
+            let glyph_id = font.charmap().map(base);
+            (glyph_id != 0 && self.suits(&font)).then_some((index, glyph_id))
+        })
+    }
+}
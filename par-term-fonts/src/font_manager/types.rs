@@ -1,7 +1,7 @@
 //! Font data types and structures for font management.
 
 use std::sync::Arc;
-use swash::FontRef;
+use swash::{FontRef, GlyphMetrics, Metrics, NormalizedCoord};
 
 /// Stores font data with lifetime management.
 ///
@@ -14,12 +14,18 @@ pub struct FontData {
     pub data: Arc<Vec<u8>>,
     /// Swash font reference for glyph operations
     pub font_ref: FontRef<'static>,
+    /// Variable font axis settings as `(tag, value)` pairs, e.g. `("wght", 450.0)`
+    pub axes: Vec<(String, f32)>,
+    /// Normalized variation coordinates for `axes`, one per font axis
+    /// (empty for the default instance)
+    pub coords: Vec<NormalizedCoord>,
 }
 
 impl std::fmt::Debug for FontData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("FontData")
             .field("data_len", &self.data.len())
+            .field("axes", &self.axes)
             .finish()
     }
 }
@@ -62,8 +68,50 @@ impl FontData {
         Some(FontData {
             data: data_arc,
             font_ref,
+            axes: Vec::new(),
+            coords: Vec::new(),
         })
     }
+
+    /// Select an instance of a variable font by axis values.
+    ///
+    /// Axes the font does not have are ignored; values outside an axis's
+    /// range are clamped to it.  An empty `axes` selects the default instance.
+    ///
+    /// # Arguments
+    /// * `axes` - `(tag, value)` pairs, e.g. `[("wght", 450.0)]`
+    pub fn with_axes(mut self, axes: &[(String, f32)]) -> Self {
+        let variations = self.font_ref.variations();
+        for (tag, _) in axes {
+            let known = variations
+                .clone()
+                .any(|variation| variation.tag() == swash::tag_from_str_lossy(tag));
+            if !known {
+                log::warn!("Font has no '{}' axis, ignoring it", tag);
+            }
+        }
+        let coords: Vec<NormalizedCoord> = variations
+            .normalized_coords(axes.iter().map(|(tag, value)| (tag.as_str(), *value)))
+            .collect();
+        // All-zero coordinates are the default instance
+        self.coords = if coords.iter().all(|&coord| coord == 0) {
+            Vec::new()
+        } else {
+            coords
+        };
+        self.axes = axes.to_vec();
+        self
+    }
+
+    /// Global font metrics at the selected axis values.
+    pub fn metrics(&self) -> Metrics {
+        self.font_ref.metrics(&self.coords)
+    }
+
+    /// Glyph metrics at the selected axis values.
+    pub fn glyph_metrics(&self) -> GlyphMetrics<'_> {
+        self.font_ref.glyph_metrics(&self.coords)
+    }
 }
 
 /// Font mapping for a specific Unicode range.
@@ -80,4 +128,6 @@ pub struct UnicodeRangeFont {
     pub font: FontData,
     /// Font index in the overall font list (used for caching)
     pub font_index: usize,
+    /// OpenType features applied when shaping with this font
+    pub features: Vec<(String, u32)>,
 }
@@ -21,5 +21,5 @@ pub mod font_manager;
 pub mod text_shaper;
 
 // Re-export main types for convenience
-pub use font_manager::{FALLBACK_FAMILIES, FontData, FontManager, UnicodeRangeFont};
-pub use text_shaper::{ShapedGlyph, ShapedRun, ShapingOptions, TextShaper};
+pub use font_manager::{FALLBACK_FAMILIES, FontData, FontManager, Presentation, UnicodeRangeFont};
+pub use text_shaper::{ShapedGlyph, ShapedRun, ShapedSegment, ShapingOptions, TextShaper};
@@ -6,6 +6,7 @@
 /// - Complex scripts (Arabic, Devanagari, etc.)
 /// - Bidirectional text (RTL languages)
 /// - Kerning and contextual alternates
+/// - Ligatures from a fallback font when the primary font has none
 ///
 /// # Architecture
 ///
@@ -27,9 +28,12 @@
 ///     ShapingOptions::default()
 /// );
 /// ```
+use crate::font_manager::Presentation;
 use lru::LruCache;
+use rustybuzz::ttf_parser::Tag;
 use rustybuzz::{Face, Feature, GlyphBuffer, Language, Script, UnicodeBuffer};
 use std::num::NonZeroUsize;
+use std::ops::Range;
 use std::str::FromStr;
 use std::sync::Arc;
 use unicode_segmentation::UnicodeSegmentation;
@@ -54,6 +58,12 @@ pub struct ShapedGlyph {
 
     /// Vertical offset from the baseline
     pub y_offset: f32,
+
+    /// Draw the glyph emboldened: bold was requested but the font has no bold face
+    pub synthetic_bold: bool,
+
+    /// Draw the glyph slanted: italic was requested but the font has no italic face
+    pub synthetic_italic: bool,
 }
 
 /// Options for text shaping
@@ -76,6 +86,19 @@ pub struct ShapingOptions {
 
     /// Text direction (true = RTL, false = LTR)
     pub rtl: bool,
+
+    /// Extra OpenType features as `(tag, value)` pairs, applied after the
+    /// features above so they take precedence (e.g. `("ss01", 1)` enables a
+    /// stylistic set, `("calt", 0)` disables contextual alternates)
+    pub features: Vec<(String, u32)>,
+
+    /// Mark the shaped glyphs as needing synthetic bold (set by
+    /// [`FontManager::shape_text`](crate::FontManager::shape_text) when the
+    /// bold face is missing)
+    pub synthetic_bold: bool,
+
+    /// Mark the shaped glyphs as needing synthetic italic
+    pub synthetic_italic: bool,
 }
 
 impl Default for ShapingOptions {
@@ -87,6 +110,9 @@ impl Default for ShapingOptions {
             script: None,
             language: None,
             rtl: false,
+            features: Vec::new(),
+            synthetic_bold: false,
+            synthetic_italic: false,
         }
     }
 }
@@ -107,6 +133,30 @@ pub struct ShapedRun {
     pub cluster_boundaries: Vec<usize>,
 }
 
+/// Part of a run shaped by [`TextShaper::shape_with_ligature_fallback`]
+#[derive(Debug, Clone)]
+pub struct ShapedSegment {
+    /// Byte range of the segment in the shaped text
+    pub range: Range<usize>,
+
+    /// Font index the segment was shaped with
+    pub font_index: usize,
+
+    /// The shaped glyphs (clusters are relative to `range.start`)
+    pub run: Arc<ShapedRun>,
+}
+
+impl ShapedSegment {
+    /// Number of cells the segment covers (one per grapheme)
+    ///
+    /// A segment shaped with the ligature fallback font is composed into this
+    /// many cells of the primary font, so its glyphs are scaled to the span
+    /// rather than drawn at the fallback font's own advance.
+    pub fn cell_count(&self) -> usize {
+        self.run.cluster_boundaries.len()
+    }
+}
+
 /// Cache key for shaped text runs
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 struct ShapeCacheKey {
@@ -114,9 +164,13 @@ struct ShapeCacheKey {
     font_index: usize,
     enable_ligatures: bool,
     enable_kerning: bool,
+    enable_contextual_alternates: bool,
     script: Option<String>,
     language: Option<String>,
     rtl: bool,
+    features: Vec<(String, u32)>,
+    synthetic_bold: bool,
+    synthetic_italic: bool,
 }
 
 /// Text shaper using HarfBuzz via rustybuzz
@@ -147,6 +201,7 @@ impl TextShaper {
     /// - ZWJ sequences (emoji like 👨‍👩‍👧‍👦)
     /// - Combining characters (diacritics like é)
     /// - Emoji with skin tone modifiers (👋🏽)
+    /// - Characters with a text or emoji presentation selector (☎\u{FE0F})
     pub fn detect_grapheme_clusters<'a>(&self, text: &'a str) -> Vec<(usize, &'a str)> {
         text.grapheme_indices(true).collect()
     }
@@ -175,6 +230,14 @@ impl TextShaper {
         grapheme.contains('\u{200D}')
     }
 
+    /// Presentation a grapheme asks for with a variation selector
+    ///
+    /// U+FE0E (VS15) after the first character requests text presentation and
+    /// U+FE0F (VS16) emoji presentation (e.g., ☎\u{FE0F})
+    pub fn presentation_selector(&self, grapheme: &str) -> Option<Presentation> {
+        Presentation::requested_by(grapheme)
+    }
+
     /// Shape a text run using rustybuzz
     ///
     /// This performs the actual text shaping, applying OpenType features
@@ -201,9 +264,13 @@ impl TextShaper {
             font_index,
             enable_ligatures: options.enable_ligatures,
             enable_kerning: options.enable_kerning,
+            enable_contextual_alternates: options.enable_contextual_alternates,
             script: options.script.clone(),
             language: options.language.clone(),
             rtl: options.rtl,
+            features: options.features.clone(),
+            synthetic_bold: options.synthetic_bold,
+            synthetic_italic: options.synthetic_italic,
         };
 
         if let Some(cached) = self.shape_cache.get(&cache_key) {
@@ -273,6 +340,9 @@ impl TextShaper {
             if let Ok(feat) = Feature::from_str("dlig") {
                 features.push(feat);
             }
+        } else {
+            // liga and clig are on by default in HarfBuzz, so turn them off explicitly
+            features.extend(["-liga", "-clig"].into_iter().flat_map(Feature::from_str));
         }
 
         // Kerning adjustments (kern)
@@ -283,9 +353,13 @@ impl TextShaper {
         }
 
         // Contextual alternates (calt) - enables context-sensitive glyph substitution
-        if options.enable_contextual_alternates
-            && let Ok(feat) = Feature::from_str("calt")
-        {
+        // (also on by default, and where fonts like JetBrains Mono put their ligatures)
+        let calt = if options.enable_contextual_alternates {
+            "calt"
+        } else {
+            "-calt"
+        };
+        if let Ok(feat) = Feature::from_str(calt) {
             features.push(feat);
         }
 
@@ -299,11 +373,19 @@ impl TextShaper {
             features.push(feat);
         }
 
+        // Requested features (stylistic sets, character variants, overrides)
+        for (tag, value) in &options.features {
+            match <&[u8; 4]>::try_from(tag.as_bytes()) {
+                Ok(bytes) => features.push(Feature::new(Tag::from_bytes(bytes), *value, ..)),
+                Err(_) => log::warn!("Ignoring OpenType feature '{}': tags are 4 characters", tag),
+            }
+        }
+
         // Shape the text with OpenType features
         let glyph_buffer = rustybuzz::shape(&face, &features, unicode_buffer);
 
         // Extract shaped glyphs
-        let glyphs = self.extract_shaped_glyphs(&glyph_buffer);
+        let glyphs = self.extract_shaped_glyphs(&glyph_buffer, &options);
 
         // Calculate total advance
         let total_advance = glyphs.iter().map(|g| g.x_advance).sum();
@@ -322,8 +404,149 @@ impl TextShaper {
         shaped_run
     }
 
+    /// Shape a text run, taking ligatures the primary font lacks from a
+    /// fallback font
+    ///
+    /// The run is shaped with the fallback font with and without ligatures;
+    /// each sequence of two or more characters whose glyphs differ (e.g. `=>`,
+    /// `!=`) ligates in the fallback font. Sequences that the primary font
+    /// does not ligate itself become segments shaped with the fallback font,
+    /// and the text in between is shaped with the primary font.
+    ///
+    /// # Arguments
+    /// * `text` - The text to shape
+    /// * `primary_data` / `primary_index` - The configured font
+    /// * `fallback_data` / `fallback_index` - The ligature-capable font
+    /// * `options` - Shaping options
+    ///
+    /// # Returns
+    /// Segments covering `text` in order
+    pub fn shape_with_ligature_fallback(
+        &mut self,
+        text: &str,
+        primary_data: &[u8],
+        primary_index: usize,
+        fallback_data: &[u8],
+        fallback_index: usize,
+        options: ShapingOptions,
+    ) -> Vec<ShapedSegment> {
+        let mut ligature_ranges = Vec::new();
+        if options.enable_ligatures && !options.rtl {
+            ligature_ranges = self.ligature_ranges(text, fallback_data, fallback_index, &options);
+            if !ligature_ranges.is_empty() {
+                let primary_ranges =
+                    self.ligature_ranges(text, primary_data, primary_index, &options);
+                ligature_ranges.retain(|range| {
+                    !primary_ranges
+                        .iter()
+                        .any(|own| own.start < range.end && range.start < own.end)
+                });
+            }
+        }
+
+        let mut segments = Vec::with_capacity(ligature_ranges.len() * 2 + 1);
+        let mut start = 0;
+        for range in ligature_ranges {
+            if start < range.start {
+                segments.push(self.shape_segment(
+                    text,
+                    start..range.start,
+                    primary_data,
+                    primary_index,
+                    &options,
+                ));
+            }
+            start = range.end;
+            segments.push(self.shape_segment(text, range, fallback_data, fallback_index, &options));
+        }
+        if start < text.len() || segments.is_empty() {
+            segments.push(self.shape_segment(
+                text,
+                start..text.len(),
+                primary_data,
+                primary_index,
+                &options,
+            ));
+        }
+        segments
+    }
+
+    /// Byte ranges of `text` that ligate in a font: runs of two or more
+    /// characters whose glyphs change when ligatures are turned on
+    fn ligature_ranges(
+        &mut self,
+        text: &str,
+        font_data: &[u8],
+        font_index: usize,
+        options: &ShapingOptions,
+    ) -> Vec<Range<usize>> {
+        let ligated = self.shape_text(text, font_data, font_index, options.clone());
+        let plain = self.shape_text(
+            text,
+            font_data,
+            font_index,
+            ShapingOptions {
+                enable_ligatures: false,
+                enable_contextual_alternates: false,
+                ..options.clone()
+            },
+        );
+        let glyphs_in = |run: &ShapedRun, range: &Range<usize>| -> Vec<u32> {
+            run.glyphs
+                .iter()
+                .filter(|glyph| range.contains(&(glyph.cluster as usize)))
+                .map(|glyph| glyph.glyph_id)
+                .collect()
+        };
+
+        let mut cluster_starts: Vec<usize> = ligated
+            .glyphs
+            .iter()
+            .map(|glyph| glyph.cluster as usize)
+            .collect();
+        cluster_starts.dedup();
+
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        for (i, &start) in cluster_starts.iter().enumerate() {
+            let end = cluster_starts.get(i + 1).copied().unwrap_or(text.len());
+            let cluster = start..end;
+            let glyphs = glyphs_in(&ligated, &cluster);
+            // A missing glyph means the font cannot draw this text at all
+            if glyphs.contains(&0) || glyphs == glyphs_in(&plain, &cluster) {
+                continue;
+            }
+            match ranges.last_mut() {
+                Some(last) if last.end == start => last.end = end,
+                _ => ranges.push(cluster),
+            }
+        }
+        ranges.retain(|range| text[range.clone()].chars().nth(1).is_some());
+        ranges
+    }
+
+    /// Shape `text[range]` as one [`ShapedSegment`]
+    fn shape_segment(
+        &mut self,
+        text: &str,
+        range: Range<usize>,
+        font_data: &[u8],
+        font_index: usize,
+        options: &ShapingOptions,
+    ) -> ShapedSegment {
+        let run = self.shape_text(&text[range.clone()], font_data, font_index, options.clone());
+        ShapedSegment {
+            range,
+            font_index,
+            run,
+        }
+    }
+
     /// Extract shaped glyphs from HarfBuzz glyph buffer
-    fn extract_shaped_glyphs(&self, buffer: &GlyphBuffer) -> Vec<ShapedGlyph> {
+    fn extract_shaped_glyphs(
+        &self,
+        buffer: &GlyphBuffer,
+        options: &ShapingOptions,
+    ) -> Vec<ShapedGlyph> {
         let glyph_infos = buffer.glyph_infos();
         let glyph_positions = buffer.glyph_positions();
 
@@ -337,6 +560,8 @@ impl TextShaper {
                 y_advance: pos.y_advance as f32,
                 x_offset: pos.x_offset as f32,
                 y_offset: pos.y_offset as f32,
+                synthetic_bold: options.synthetic_bold,
+                synthetic_italic: options.synthetic_italic,
             })
             .collect()
     }
@@ -406,4 +631,28 @@ mod tests {
         // Regular text (no ZWJ)
         assert!(!shaper.contains_zwj("hello"));
     }
+
+    #[test]
+    fn test_presentation_selector_detection() {
+        let shaper = TextShaper::new();
+
+        // Telephone with VS16 and VS15 stays one grapheme
+        assert_eq!(
+            shaper.detect_grapheme_clusters("\u{260E}\u{FE0F}\u{260E}\u{FE0E}"),
+            [(0, "\u{260E}\u{FE0F}"), (6, "\u{260E}\u{FE0E}")]
+        );
+        assert_eq!(
+            shaper.presentation_selector("\u{260E}\u{FE0F}"),
+            Some(Presentation::Emoji)
+        );
+        assert_eq!(
+            shaper.presentation_selector("\u{260E}\u{FE0E}"),
+            Some(Presentation::Text)
+        );
+
+        // No selector, or one that doesn't follow the first character
+        assert_eq!(shaper.presentation_selector("\u{260E}"), None);
+        assert_eq!(shaper.presentation_selector("\u{FE0F}"), None);
+        assert_eq!(shaper.presentation_selector("\u{1F44B}\u{1F3FD}"), None);
+    }
 }
@@ -1,11 +1,24 @@
 //! Integration tests for par-term-fonts crate.
 
-use par_term_fonts::font_manager::{FALLBACK_FAMILIES, FontData, FontManager};
+use par_term_config::FontAxes;
+use par_term_fonts::font_manager::{
+    FALLBACK_FAMILIES, FontData, FontManager, Presentation, has_color_glyphs,
+};
 use par_term_fonts::text_shaper::{ShapingOptions, TextShaper};
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Embedded DejaVu Sans Mono for testing.
 const TEST_FONT: &[u8] = include_bytes!("../fonts/DejaVuSansMono.ttf");
 
+/// JetBrains Mono, a font with programming ligatures, for testing.
+const LIGATURE_FONT: &[u8] = include_bytes!("fonts/JetBrainsMono-Regular.ttf");
+
+/// Minimal variable font with a `wght` axis (see fonts/README.md).
+const VARIABLE_FONT: &[u8] = include_bytes!("fonts/ParTestVariable.ttf");
+
+/// Minimal COLR color font mapping only ☎ (see fonts/README.md).
+const COLOR_FONT: &[u8] = include_bytes!("fonts/ParTestColor.ttf");
+
 #[test]
 fn test_font_data_from_embedded() {
     let data = TEST_FONT.to_vec();
@@ -51,10 +64,198 @@ fn test_font_data_debug() {
     assert!(debug_str.contains("data_len"));
 }
 
+fn wght(value: f32) -> Vec<(String, f32)> {
+    vec![("wght".to_string(), value)]
+}
+
+/// Advance width of 'm' and ascent, in font units.
+fn m_metrics(font: &FontData) -> (f32, f32) {
+    let glyph_id = font.font_ref.charmap().map('m');
+    (
+        font.glyph_metrics().advance_width(glyph_id),
+        font.metrics().ascent,
+    )
+}
+
+#[test]
+fn test_variable_font_metrics_follow_weight() {
+    let regular = FontData::new(VARIABLE_FONT.to_vec()).unwrap();
+    assert!(regular.coords.is_empty());
+    assert_eq!(m_metrics(&regular), (600.0, 800.0));
+
+    // wght 700 is 60% of the way from the default (400) to the maximum (900)
+    let semibold = regular.clone().with_axes(&wght(700.0));
+    assert_eq!(semibold.axes, wght(700.0));
+    let (advance, ascent) = m_metrics(&semibold);
+    assert!((advance - 660.0).abs() < 0.01, "advance {advance}");
+    assert!((ascent - 860.0).abs() < 0.01, "ascent {ascent}");
+
+    // Values past the axis range are clamped to it
+    let thin = regular.clone().with_axes(&wght(0.0));
+    assert_eq!(m_metrics(&thin), (550.0, 750.0));
+
+    // The default value selects the default instance
+    let default = regular.with_axes(&wght(400.0));
+    assert!(default.coords.is_empty());
+    assert_eq!(m_metrics(&default), (600.0, 800.0));
+}
+
+#[test]
+fn test_unknown_axes_are_ignored() {
+    let font = FontData::new(TEST_FONT.to_vec())
+        .unwrap()
+        .with_axes(&wght(700.0));
+    assert!(font.coords.is_empty());
+
+    let variable = FontData::new(VARIABLE_FONT.to_vec())
+        .unwrap()
+        .with_axes(&[("wdth".to_string(), 75.0)]);
+    assert!(variable.coords.is_empty());
+}
+
+#[test]
+fn test_font_manager_style_axes_need_a_variable_font() {
+    // The embedded font is not variable, so bold axes cannot make a bold instance
+    let axes = FontAxes {
+        bold: wght(700.0),
+        ..FontAxes::default()
+    };
+    let manager = FontManager::new(None, None, None, None, &[], &axes).unwrap();
+    assert_eq!(
+        manager.find_glyph('m', true, false).map(|(idx, _)| idx),
+        Some(0)
+    );
+    assert!(manager.font_coords(0).is_empty());
+    assert!(manager.font_coords(1).is_empty());
+}
+
+#[test]
+fn test_bold_run_without_bold_face_is_synthetic_bold() {
+    // Only a regular face: bold text is shaped with the primary font
+    let mut manager = FontManager::new(None, None, None, None, &[], &FontAxes::default()).unwrap();
+    let run = manager.shape_text("bold", true, false, ShapingOptions::default());
+    assert_eq!(run.glyphs.len(), 4);
+    assert!(run.glyphs.iter().all(|g| g.synthetic_bold));
+    assert!(run.glyphs.iter().all(|g| !g.synthetic_italic));
+
+    let run = manager.shape_text("both", true, true, ShapingOptions::default());
+    assert!(
+        run.glyphs
+            .iter()
+            .all(|g| g.synthetic_bold && g.synthetic_italic)
+    );
+
+    let run = manager.shape_text("plain", false, false, ShapingOptions::default());
+    assert!(
+        run.glyphs
+            .iter()
+            .all(|g| !g.synthetic_bold && !g.synthetic_italic)
+    );
+}
+
+#[test]
+fn test_synthetic_styles_can_be_disabled() {
+    let mut manager = FontManager::new(None, None, None, None, &[], &FontAxes::default()).unwrap();
+    assert_eq!(manager.synthetic_style(0, true, true), (true, true));
+    // A real bold face needs no synthetic bold, only the missing slant
+    assert_eq!(manager.synthetic_style(1, true, true), (false, true));
+    assert_eq!(manager.synthetic_style(3, true, true), (false, false));
+
+    manager.set_synthesize_styles(false);
+    assert_eq!(manager.synthetic_style(0, true, true), (false, false));
+    let run = manager.shape_text("bold", true, false, ShapingOptions::default());
+    assert!(run.glyphs.iter().all(|g| !g.synthetic_bold));
+}
+
+#[test]
+fn test_resolve_runs_mixing_latin_cjk_and_emoji() {
+    let mut manager = FontManager::new(None, None, None, None, &[], &FontAxes::default()).unwrap();
+    // Latin with a combining accent, CJK, then an emoji with a skin tone modifier
+    let text = "cafe\u{301} 漢字 👍🏽";
+    let runs = manager.resolve_runs(text);
+
+    // The accented Latin text is one run in the primary font
+    assert_eq!(runs[0], (0..7, 0));
+
+    // Each run agrees with the renderer's per-cluster font choice, and the
+    // primary font (which has no CJK or emoji) is never picked for them
+    for (range, font_index) in &runs {
+        for grapheme in text[range.clone()].graphemes(true) {
+            assert_eq!(
+                manager
+                    .find_grapheme_glyph(grapheme, false, false)
+                    .map(|(idx, _)| idx),
+                Some(*font_index),
+                "font for {grapheme:?}"
+            );
+        }
+    }
+    let cjk = text.find('漢').unwrap();
+    let emoji = text.find('👍').unwrap();
+    for (range, font_index) in &runs {
+        if range.contains(&cjk) || range.contains(&emoji) {
+            assert_ne!(*font_index, 0);
+        }
+    }
+
+    // Runs are in order and never overlap; clusters no font has are gaps
+    assert!(runs.windows(2).all(|pair| pair[0].0.end <= pair[1].0.start));
+    assert!(runs.iter().all(|(range, _)| range.end <= text.len()));
+}
+
+#[test]
+fn test_presentation_selectors_choose_text_or_emoji_font() {
+    let text = FontData::new(TEST_FONT.to_vec()).unwrap();
+    let color = FontData::new(COLOR_FONT.to_vec()).unwrap();
+    assert!(!has_color_glyphs(&text.font_ref));
+    assert!(has_color_glyphs(&color.font_ref));
+
+    let phone = '\u{260E}';
+    assert_ne!(text.font_ref.charmap().map(phone), 0);
+    let emoji = Presentation::requested_by("\u{260E}\u{FE0F}").unwrap();
+    let plain = Presentation::requested_by("\u{260E}\u{FE0E}").unwrap();
+    assert_eq!((emoji, plain), (Presentation::Emoji, Presentation::Text));
+
+    // Whichever font comes first, VS16 picks the color font and VS15 the text font
+    for candidates in [
+        [(0, text.font_ref), (5, color.font_ref)],
+        [(5, color.font_ref), (0, text.font_ref)],
+    ] {
+        let (emoji_index, emoji_glyph) = emoji.select_font(candidates, phone).unwrap();
+        assert_eq!(emoji_index, 5);
+        assert_eq!(emoji_glyph, color.font_ref.charmap().map(phone));
+        assert_eq!(
+            plain.select_font(candidates, phone).map(|(index, _)| index),
+            Some(0)
+        );
+    }
+
+    // No font of the requested kind
+    assert_eq!(emoji.select_font([(0, text.font_ref)], phone), None);
+    assert_eq!(plain.select_font([(0, color.font_ref)], phone), None);
+}
+
+#[test]
+fn test_presentation_selector_without_matching_font_falls_back() {
+    let mut manager = FontManager::new(None, None, None, None, &[], &FontAxes::default()).unwrap();
+    let plain = manager.find_glyph('\u{260E}', false, false).unwrap();
+
+    // The text form is in the embedded font
+    assert_eq!(
+        manager.find_grapheme_glyph("\u{260E}\u{FE0E}", false, false),
+        Some(plain)
+    );
+    // Without a color font the emoji request still finds a glyph
+    let (_, glyph_id) = manager
+        .find_grapheme_glyph("\u{260E}\u{FE0F}", false, false)
+        .unwrap();
+    assert_ne!(glyph_id, 0);
+}
+
 #[test]
 fn test_font_manager_with_embedded() {
     // Create FontManager with no specified fonts (uses embedded fallback)
-    let manager = FontManager::new(None, None, None, None, &[]);
+    let manager = FontManager::new(None, None, None, None, &[], &FontAxes::default());
     assert!(
         manager.is_ok(),
         "FontManager should create with embedded font"
@@ -68,7 +269,7 @@ fn test_font_manager_with_embedded() {
 
 #[test]
 fn test_font_manager_find_ascii_glyph() {
-    let manager = FontManager::new(None, None, None, None, &[]).unwrap();
+    let manager = FontManager::new(None, None, None, None, &[], &FontAxes::default()).unwrap();
     // ASCII 'A' should always be found in the primary font
     let result = manager.find_glyph('A', false, false);
     assert!(result.is_some(), "Should find glyph for 'A'");
@@ -79,14 +280,14 @@ fn test_font_manager_find_ascii_glyph() {
 
 #[test]
 fn test_font_manager_find_space_glyph() {
-    let manager = FontManager::new(None, None, None, None, &[]).unwrap();
+    let manager = FontManager::new(None, None, None, None, &[], &FontAxes::default()).unwrap();
     let result = manager.find_glyph(' ', false, false);
     assert!(result.is_some(), "Should find glyph for space");
 }
 
 #[test]
 fn test_font_manager_get_font() {
-    let manager = FontManager::new(None, None, None, None, &[]).unwrap();
+    let manager = FontManager::new(None, None, None, None, &[], &FontAxes::default()).unwrap();
     // Primary font should always be accessible
     assert!(manager.get_font(0).is_some(), "Primary font should exist");
 }
@@ -178,4 +379,120 @@ fn test_shaping_options_default() {
     assert!(!opts.rtl);
     assert!(opts.script.is_none());
     assert!(opts.language.is_none());
+    assert!(!opts.synthetic_bold);
+    assert!(!opts.synthetic_italic);
+}
+
+#[test]
+fn test_ligature_fallback_shapes_arrow_with_fallback_font() {
+    let mut shaper = TextShaper::new();
+    let segments = shaper.shape_with_ligature_fallback(
+        "a => b",
+        TEST_FONT,
+        0,
+        LIGATURE_FONT,
+        5,
+        ShapingOptions::default(),
+    );
+
+    let spans: Vec<_> = segments
+        .iter()
+        .map(|segment| (segment.range.clone(), segment.font_index))
+        .collect();
+    assert_eq!(spans, [(0..2, 0), (2..4, 5), (4..6, 0)]);
+    assert_eq!(segments[1].run.text, "=>");
+    assert_eq!(segments[1].cell_count(), 2);
+    assert!(
+        segments[1]
+            .run
+            .glyphs
+            .iter()
+            .all(|glyph| glyph.glyph_id != 0)
+    );
+}
+
+#[test]
+fn test_ligature_fallback_unused_without_ligating_sequences() {
+    let mut shaper = TextShaper::new();
+    let segments = shaper.shape_with_ligature_fallback(
+        "hello",
+        TEST_FONT,
+        0,
+        LIGATURE_FONT,
+        5,
+        ShapingOptions::default(),
+    );
+    assert_eq!(segments.len(), 1);
+    assert_eq!(
+        (segments[0].range.clone(), segments[0].font_index),
+        (0..5, 0)
+    );
+
+    // Ligatures disabled, and a primary font that ligates `=>` itself
+    let disabled = ShapingOptions {
+        enable_ligatures: false,
+        ..ShapingOptions::default()
+    };
+    for (primary, options) in [
+        (TEST_FONT, disabled),
+        (LIGATURE_FONT, ShapingOptions::default()),
+    ] {
+        let segments =
+            shaper.shape_with_ligature_fallback("a => b", primary, 1, LIGATURE_FONT, 5, options);
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].font_index, 1);
+    }
+}
+
+#[test]
+fn test_shaping_with_stylistic_set() {
+    let mut shaper = TextShaper::new();
+    let glyph_ids = |shaper: &mut TextShaper, features: Vec<(String, u32)>| -> Vec<u32> {
+        let options = ShapingOptions {
+            features,
+            ..ShapingOptions::default()
+        };
+        let run = shaper.shape_text("Kafka", LIGATURE_FONT, 5, options);
+        run.glyphs.iter().map(|glyph| glyph.glyph_id).collect()
+    };
+
+    let plain = glyph_ids(&mut shaper, Vec::new());
+    let ss01 = glyph_ids(&mut shaper, vec![("ss01".to_string(), 1)]);
+    assert_eq!(plain.len(), ss01.len());
+    assert_ne!(plain, ss01, "ss01 should select alternate glyphs");
+    assert_eq!(glyph_ids(&mut shaper, vec![("ss01".to_string(), 0)]), plain);
+
+    // Invalid tags are ignored
+    assert_eq!(
+        glyph_ids(&mut shaper, vec![("bogus".to_string(), 1)]),
+        plain
+    );
+}
+
+#[test]
+fn test_feature_can_disable_default_ligatures() {
+    let mut shaper = TextShaper::new();
+    let shape = |shaper: &mut TextShaper, features: Vec<(String, u32)>| -> Vec<u32> {
+        let options = ShapingOptions {
+            features,
+            ..ShapingOptions::default()
+        };
+        let run = shaper.shape_text("=>", LIGATURE_FONT, 5, options);
+        run.glyphs.iter().map(|glyph| glyph.glyph_id).collect()
+    };
+    let no_calt = ShapingOptions {
+        enable_ligatures: false,
+        enable_contextual_alternates: false,
+        ..ShapingOptions::default()
+    };
+    let unligated: Vec<u32> = shaper
+        .shape_text("=>", LIGATURE_FONT, 5, no_calt)
+        .glyphs
+        .iter()
+        .map(|glyph| glyph.glyph_id)
+        .collect();
+
+    // Defaults keep ligatures; `calt` = 0 turns them off
+    assert_ne!(shape(&mut shaper, Vec::new()), unligated);
+    assert_eq!(shape(&mut shaper, vec![("calt".to_string(), 0)]), unligated);
 }
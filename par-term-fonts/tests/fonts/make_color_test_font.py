@@ -0,0 +1,82 @@
+#!/usr/bin/env python3
+"""Generate ParTestColor.ttf, a tiny color font for the font tests.
+
+The font maps only U+260E (BLACK TELEPHONE) and draws it in color: a `COLR`
+version 0 table paints the glyph with one layer in the first `CPAL` color.
+The glyph outlines themselves are plain boxes, as in ParTestVariable.ttf.
+Run from this directory to rewrite the font.
+"""
+
+import struct
+
+from make_variable_test_font import ADVANCE, ASCENT, DESCENT, UPEM, assemble, cmap_table, glyph_box, name_table
+
+GLYPH_COUNT = 3  # .notdef, telephone, telephone color layer
+TELEPHONE = 0x260E
+# Color of the layer as B, G, R, A
+LAYER_COLOR = (0x20, 0x40, 0xE0, 0xFF)
+
+
+def colr_table():
+    """COLR v0: glyph 1 is drawn as glyph 2 in palette entry 0."""
+    base_offset = 14
+    layer_offset = base_offset + 6
+    return (
+        struct.pack(">HHIIH", 0, 1, base_offset, layer_offset, 1)
+        + struct.pack(">HHH", 1, 0, 1)
+        + struct.pack(">HH", 2, 0)
+    )
+
+
+def cpal_table():
+    """CPAL v0 with one palette of one color."""
+    return struct.pack(">HHHHIH", 0, 1, 1, 1, 14, 0) + bytes(LAYER_COLOR)
+
+
+def build():
+    glyphs = [glyph_box(50, 0, 550, 700), glyph_box(50, 0, 550, 700), glyph_box(50, 0, 550, 700)]
+    loca = b""
+    glyf = b""
+    for glyph in glyphs:
+        loca += struct.pack(">H", len(glyf) // 2)
+        glyf += glyph
+    loca += struct.pack(">H", len(glyf) // 2)
+
+    tables = {
+        b"head": struct.pack(
+            ">IIIIHHqqhhhhHHhhh",
+            0x00010000, 0x00010000, 0, 0x5F0F3CF5, 0x000B, UPEM,
+            0, 0, 50, 0, 550, 700, 0, 8, 2, 0, 0,
+        ),
+        b"hhea": struct.pack(
+            ">IhhhHhhhhhhhhhhhH",
+            0x00010000, ASCENT, DESCENT, 0, ADVANCE, 50, 50, 550,
+            1, 0, 0, 0, 0, 0, 0, 0, GLYPH_COUNT,
+        ),
+        b"maxp": struct.pack(">IHHHHHHHHHHHHHH", 0x00010000, GLYPH_COUNT, 4, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0),
+        b"hmtx": b"".join(struct.pack(">Hh", ADVANCE, 50) for _ in range(GLYPH_COUNT)),
+        b"cmap": cmap_table({TELEPHONE: 1}),
+        b"loca": loca,
+        b"glyf": glyf,
+        b"post": struct.pack(">IihhIIIII", 0x00030000, 0, -100, 50, 1, 0, 0, 0, 0),
+        b"OS/2": struct.pack(
+            ">HhHHHhhhhhhhhhhh10sIIII4sHHHhhhHHIIhhHHH",
+            4, ADVANCE, 400, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 250, 0,
+            b"\0" * 10, 0, 0, 0, 0, b"NONE", 0x0040, TELEPHONE, TELEPHONE,
+            ASCENT, DESCENT, 0, ASCENT, -DESCENT, 1, 0, 500, 700, 0x20, 0x20, 0,
+        ),
+        b"name": name_table([
+            (1, "Par Test Color"),
+            (2, "Regular"),
+            (4, "Par Test Color Regular"),
+            (6, "ParTestColor-Regular"),
+        ]),
+        b"COLR": colr_table(),
+        b"CPAL": cpal_table(),
+    }
+    return assemble(tables)
+
+
+if __name__ == "__main__":
+    with open("ParTestColor.ttf", "wb") as f:
+        f.write(build())
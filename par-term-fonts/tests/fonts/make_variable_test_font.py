@@ -0,0 +1,175 @@
+#!/usr/bin/env python3
+"""Generate ParTestVariable.ttf, a tiny variable font for the font tests.
+
+The font has one `wght` axis (100-900, default 400) and three glyphs:
+`.notdef`, space and `m`.  It varies metrics only (there is no `gvar`):
+
+- HVAR: advances grow by 100 units at wght=900 and shrink by 50 at wght=100
+- MVAR: the ascender (`hasc`) moves the same way
+
+At the default weight every advance is 600 units and the ascender 800, with
+1000 units per em.  Run from this directory to rewrite the font.
+"""
+
+import struct
+
+UPEM = 1000
+ASCENT = 800
+DESCENT = -200
+ADVANCE = 600
+# Deltas at the axis maximum and minimum
+DELTA_MAX = 100
+DELTA_MIN = -50
+
+GLYPH_COUNT = 3  # .notdef, space, m
+
+
+def glyph_box(x0, y0, x1, y1):
+    """A simple glyph with one rectangular contour."""
+    header = struct.pack(">hhhhh", 1, x0, y0, x1, y1)
+    end_pts = struct.pack(">H", 3)
+    instructions = struct.pack(">H", 0)
+    flags = bytes([0x01] * 4)  # on-curve, 16-bit coordinates
+    xs = [x0, x1, x1, x0]
+    ys = [y0, y0, y1, y1]
+    coords = b""
+    prev = 0
+    for x in xs:
+        coords += struct.pack(">h", x - prev)
+        prev = x
+    prev = 0
+    for y in ys:
+        coords += struct.pack(">h", y - prev)
+        prev = y
+    data = header + end_pts + instructions + flags + coords
+    return data + b"\0" * (len(data) % 2)
+
+
+def item_variation_store(delta_sets):
+    """An ItemVariationStore with one wght-up and one wght-down region."""
+    regions = struct.pack(">HH", 1, 2)
+    regions += struct.pack(">hhh", 0, 0x4000, 0x4000)  # 0 -> 1
+    regions += struct.pack(">hhh", -0x4000, -0x4000, 0)  # -1 -> 0
+    data = struct.pack(">HHH", len(delta_sets), 2, 2) + struct.pack(">HH", 0, 1)
+    for up, down in delta_sets:
+        data += struct.pack(">hh", up, down)
+    header_len = 2 + 4 + 2 + 4
+    return (
+        struct.pack(">HIHI", 1, header_len, 1, header_len + len(regions))
+        + regions
+        + data
+    )
+
+
+def name_table(records):
+    entries = b""
+    strings = b""
+    for name_id, text in records:
+        encoded = text.encode("utf-16-be")
+        entries += struct.pack(">HHHHHH", 3, 1, 0x409, name_id, len(encoded), len(strings))
+        strings += encoded
+    header = struct.pack(">HHH", 0, len(records), 6 + 12 * len(records))
+    return header + entries + strings
+
+
+def cmap_table(mapping):
+    segments = sorted(mapping.items()) + [(0xFFFF, 0)]
+    seg_x2 = len(segments) * 2
+    ends = b"".join(struct.pack(">H", c) for c, _ in segments)
+    starts = b"".join(struct.pack(">H", c) for c, _ in segments)
+    deltas = b"".join(struct.pack(">h", ((g - c) + 0x8000) % 0x10000 - 0x8000 if c != 0xFFFF else 1) for c, g in segments)
+    offsets = b"\0\0" * len(segments)
+    search = 2 * 2 ** (len(segments).bit_length() - 1)
+    body = (
+        struct.pack(">HHHH", seg_x2, search, (len(segments).bit_length() - 1), seg_x2 - search)
+        + ends
+        + b"\0\0"
+        + starts
+        + deltas
+        + offsets
+    )
+    subtable = struct.pack(">HHH", 4, 6 + len(body), 0) + body
+    return struct.pack(">HHHHI", 0, 1, 3, 1, 12) + subtable
+
+
+def checksum(data):
+    data += b"\0" * (-len(data) % 4)
+    return sum(struct.unpack(">%dI" % (len(data) // 4), data)) & 0xFFFFFFFF
+
+
+def build():
+    glyphs = [glyph_box(50, 0, 550, 700), b"", glyph_box(60, 0, 540, 500)]
+    loca = b""
+    glyf = b""
+    for glyph in glyphs:
+        loca += struct.pack(">H", len(glyf) // 2)
+        glyf += glyph
+    loca += struct.pack(">H", len(glyf) // 2)
+
+    tables = {
+        b"head": struct.pack(
+            ">IIIIHHqqhhhhHHhhh",
+            0x00010000, 0x00010000, 0, 0x5F0F3CF5, 0x000B, UPEM,
+            0, 0, 0, 0, 550, 700, 0, 8, 2, 0, 0,
+        ),
+        b"hhea": struct.pack(
+            ">IhhhHhhhhhhhhhhhH",
+            0x00010000, ASCENT, DESCENT, 0, ADVANCE, 0, 0, 550,
+            1, 0, 0, 0, 0, 0, 0, 0, GLYPH_COUNT,
+        ),
+        b"maxp": struct.pack(">IHHHHHHHHHHHHHH", 0x00010000, GLYPH_COUNT, 4, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0),
+        b"hmtx": b"".join(struct.pack(">Hh", ADVANCE, lsb) for lsb in (50, 0, 60)),
+        b"cmap": cmap_table({0x20: 1, 0x6D: 2}),
+        b"loca": loca,
+        b"glyf": glyf,
+        b"post": struct.pack(">IihhIIIII", 0x00030000, 0, -100, 50, 1, 0, 0, 0, 0),
+        b"OS/2": struct.pack(
+            ">HhHHHhhhhhhhhhhh10sIIII4sHHHhhhHHIIhhHHH",
+            4, ADVANCE, 400, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 250, 0,
+            b"\0" * 10, 0, 0, 0, 0, b"NONE", 0x0040, 0x20, 0x6D,
+            ASCENT, DESCENT, 0, ASCENT, -DESCENT, 1, 0, 500, 700, 0x20, 0x20, 0,
+        ),
+        b"name": name_table([
+            (1, "Par Test Variable"),
+            (2, "Regular"),
+            (4, "Par Test Variable Regular"),
+            (6, "ParTestVariable-Regular"),
+            (256, "Weight"),
+        ]),
+        b"fvar": struct.pack(">HHHHHHHH", 1, 0, 16, 2, 1, 20, 0, 8)
+        + struct.pack(">4siiiHH", b"wght", 100 << 16, 400 << 16, 900 << 16, 0, 256),
+        b"HVAR": struct.pack(">HHIIII", 1, 0, 20, 0, 0, 0)
+        + item_variation_store([(0, 0)] + [(DELTA_MAX, DELTA_MIN)] * (GLYPH_COUNT - 1)),
+        b"MVAR": struct.pack(">HHHHHH", 1, 0, 0, 8, 1, 20)
+        + struct.pack(">4sHH", b"hasc", 0, 0)
+        + item_variation_store([(DELTA_MAX, DELTA_MIN)]),
+    }
+    return assemble(tables)
+
+
+def assemble(tables):
+    """The font file holding `tables`, keyed by tag."""
+    tags = sorted(tables)
+    num = len(tags)
+    entry_selector = num.bit_length() - 1
+    search_range = 16 * 2**entry_selector
+    header = struct.pack(">IHHHH", 0x00010000, num, search_range, entry_selector, num * 16 - search_range)
+    offset = 12 + 16 * num
+    directory = b""
+    body = b""
+    for tag in tags:
+        data = tables[tag]
+        directory += struct.pack(">4sIII", tag, checksum(data), offset + len(body), len(data))
+        body += data + b"\0" * (-len(data) % 4)
+    font = bytearray(header + directory + body)
+
+    # head.checkSumAdjustment
+    head_offset = offset + sum(len(tables[t]) + (-len(tables[t]) % 4) for t in tags[: tags.index(b"head")])
+    adjustment = (0xB1B0AFBA - checksum(bytes(font))) & 0xFFFFFFFF
+    font[head_offset + 8 : head_offset + 12] = struct.pack(">I", adjustment)
+    return bytes(font)
+
+
+if __name__ == "__main__":
+    with open("ParTestVariable.ttf", "wb") as f:
+        f.write(build())
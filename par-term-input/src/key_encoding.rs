@@ -12,11 +12,43 @@
 use winit::event::{ElementState, KeyEvent};
 use winit::keyboard::{Key, KeyCode, NamedKey, PhysicalKey};
 
-use par_term_config::OptionKeyMode;
+use par_term_config::{BackspaceSends, OptionKeyMode};
 
-use super::InputHandler;
+use super::{InputHandler, KeyRepeat};
 
 impl InputHandler {
+    /// Update the byte sent by the Backspace key from config
+    pub fn update_backspace_sends(&mut self, mode: BackspaceSends) {
+        self.backspace_sends = mode;
+    }
+
+    /// Update app-level key repeat timing and keys from config.
+    ///
+    /// Any key currently repeating stops.
+    pub fn update_key_repeat(&mut self, delay_ms: u32, interval_ms: u32, keys: &[String]) {
+        self.key_repeat = KeyRepeat::new(
+            std::time::Duration::from_millis(delay_ms as u64),
+            std::time::Duration::from_millis(interval_ms as u64),
+            keys.to_vec(),
+        );
+    }
+
+    /// Bytes written to the PTY for the Backspace key.
+    ///
+    /// Honors `backspace_sends` regardless of which modifiers are held.
+    pub fn backspace_bytes(&self) -> Vec<u8> {
+        self.backspace_sends.bytes().to_vec()
+    }
+
+    /// Prefix `bytes` with ESC when Alt is held and the active Option/Alt
+    /// mode is ESC, for keys without an xterm modifier encoding.
+    fn alt_escape_prefixed(&self, alt: bool, mut bytes: Vec<u8>) -> Vec<u8> {
+        if alt && self.get_active_option_mode() == OptionKeyMode::Esc {
+            bytes.insert(0, 0x1b);
+        }
+        bytes
+    }
+
     /// Apply Option/Alt key transformation based on the configured mode
     fn apply_option_key_mode(&self, bytes: &mut Vec<u8>, original_char: char) {
         let mode = self.get_active_option_mode();
@@ -162,6 +194,13 @@ impl InputHandler {
 
             // Special keys
             Key::Named(named_key) => {
+                // Backspace sends the configured byte regardless of modifiers
+                // (ESC-prefixed with Alt in ESC mode, e.g. Alt+Backspace to
+                // delete a word)
+                if matches!(named_key, NamedKey::Backspace) {
+                    return Some(self.alt_escape_prefixed(alt, self.backspace_bytes()));
+                }
+
                 // Handle Ctrl+Space specially - sends NUL (0x00)
                 if ctrl && matches!(named_key, NamedKey::Space) {
                     return Some(vec![0x00]);
@@ -285,13 +324,16 @@ impl InputHandler {
                         }
                     }
                     NamedKey::Space => " ",
-                    NamedKey::Backspace => "\x7f",
                     NamedKey::Escape => "\x1b",
 
                     _ => return None,
                 };
 
-                Some(seq.as_bytes().to_vec())
+                if seq.len() == 1 {
+                    Some(self.alt_escape_prefixed(alt, seq.as_bytes().to_vec()))
+                } else {
+                    Some(seq.as_bytes().to_vec())
+                }
             }
 
             _ => None,
@@ -0,0 +1,261 @@
+//! App-level key repeat for selected keys.
+//!
+//! The OS key repeat applies one delay and rate to every key.  Users on
+//! high-latency remote sessions often want navigation keys to repeat at a
+//! different pace, so for the keys listed in `key_repeat_keys` par-term
+//! ignores the OS repeat events and generates its own: the first repeat
+//! fires `delay` after the press, later ones every `interval`.
+//!
+//! [`KeyRepeat`] only does the scheduling.  The caller feeds it key events
+//! and polls [`KeyRepeat::due`] (waking at [`KeyRepeat::next_deadline`]),
+//! then replays the held event once per returned repeat.
+
+use std::time::{Duration, Instant};
+
+use winit::event::KeyEvent;
+use winit::keyboard::Key;
+
+/// Repeats emitted at most per poll; after a longer stall the schedule
+/// restarts from "now" instead of flushing a burst of keystrokes.
+const MAX_CATCH_UP: u32 = 4;
+
+/// Key being held, with its next scheduled repeat.
+#[derive(Debug, Clone)]
+struct Held<E> {
+    key: Key,
+    event: E,
+    next: Instant,
+}
+
+/// Repeat scheduler for the configured keys.
+///
+/// `E` is the event replayed on each repeat (a [`KeyEvent`] in the app).
+#[derive(Debug, Clone)]
+pub struct KeyRepeat<E = KeyEvent> {
+    /// Time from press to the first repeat
+    delay: Duration,
+    /// Time between subsequent repeats
+    interval: Duration,
+    /// Names of the keys handled here (see [`key_name`]); empty = disabled
+    keys: Vec<String>,
+    held: Option<Held<E>>,
+}
+
+impl<E: Clone> Default for KeyRepeat<E> {
+    fn default() -> Self {
+        Self::new(Duration::ZERO, Duration::ZERO, Vec::new())
+    }
+}
+
+impl<E: Clone> KeyRepeat<E> {
+    /// Create a scheduler repeating `keys` after `delay`, every `interval`.
+    pub fn new(delay: Duration, interval: Duration, keys: Vec<String>) -> Self {
+        Self {
+            delay,
+            // A zero interval would repeat without bound
+            interval: interval.max(Duration::from_millis(1)),
+            keys,
+            held: None,
+        }
+    }
+
+    /// Whether any key uses app-level repeat.
+    pub fn is_enabled(&self) -> bool {
+        !self.keys.is_empty()
+    }
+
+    /// Whether `key` repeats through this scheduler instead of the OS.
+    pub fn handles(&self, key: &Key) -> bool {
+        key_name(key).is_some_and(|name| self.keys.iter().any(|k| k.eq_ignore_ascii_case(&name)))
+    }
+
+    /// Feed a key event; returns `true` if the caller should drop it.
+    ///
+    /// OS repeats of handled keys are dropped.  A press of a handled key
+    /// starts repeating `event`; releasing it, or pressing any other
+    /// non-modifier key, stops.
+    pub fn key_event(
+        &mut self,
+        key: &Key,
+        pressed: bool,
+        os_repeat: bool,
+        event: &E,
+        now: Instant,
+    ) -> bool {
+        let handled = self.handles(key);
+        if os_repeat {
+            return handled;
+        }
+        if pressed {
+            if handled {
+                self.held = Some(Held {
+                    key: key.clone(),
+                    event: event.clone(),
+                    next: now + self.delay,
+                });
+            } else if !is_modifier(key) {
+                self.held = None;
+            }
+        } else if self.held.as_ref().is_some_and(|h| same_key(&h.key, key)) {
+            self.held = None;
+        }
+        false
+    }
+
+    /// Stop repeating (e.g. on focus loss, when no release will arrive).
+    pub fn cancel(&mut self) {
+        self.held = None;
+    }
+
+    /// When the next repeat is due, if a key is held.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.held.as_ref().map(|h| h.next)
+    }
+
+    /// Repeats due at `now`, with the event to replay for each.
+    ///
+    /// Advances the schedule; returns `None` when nothing is due.
+    pub fn due(&mut self, now: Instant) -> Option<(E, u32)> {
+        let interval = self.interval;
+        let held = self.held.as_mut()?;
+        if now < held.next {
+            return None;
+        }
+        let late = now.duration_since(held.next);
+        let count = 1 + (late.as_nanos() / interval.as_nanos()).min(u32::MAX as u128) as u32;
+        if count > MAX_CATCH_UP {
+            held.next = now + interval;
+            return Some((held.event.clone(), MAX_CATCH_UP));
+        }
+        held.next += interval * count;
+        Some((held.event.clone(), count))
+    }
+}
+
+/// Name used to list a key in `key_repeat_keys`.
+///
+/// Named keys use their winit name (`ArrowUp`, `PageDown`, `Backspace`);
+/// character keys use the character itself (`h`, `j`).
+pub fn key_name(key: &Key) -> Option<String> {
+    match key {
+        Key::Named(named) => Some(format!("{named:?}")),
+        Key::Character(c) => Some(c.to_string()),
+        _ => None,
+    }
+}
+
+/// Compare keys ignoring case, so releasing `j` after Shift went down
+/// (reported as `J`) still ends the repeat.
+fn same_key(a: &Key, b: &Key) -> bool {
+    match (key_name(a), key_name(b)) {
+        (Some(a), Some(b)) => a.eq_ignore_ascii_case(&b),
+        _ => a == b,
+    }
+}
+
+fn is_modifier(key: &Key) -> bool {
+    use winit::keyboard::NamedKey;
+    matches!(
+        key,
+        Key::Named(
+            NamedKey::Shift
+                | NamedKey::Control
+                | NamedKey::Alt
+                | NamedKey::Super
+                | NamedKey::Meta
+                | NamedKey::Hyper
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use winit::keyboard::NamedKey;
+
+    const DELAY: Duration = Duration::from_millis(300);
+    const INTERVAL: Duration = Duration::from_millis(50);
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    fn repeat() -> KeyRepeat<&'static str> {
+        KeyRepeat::new(
+            DELAY,
+            INTERVAL,
+            vec!["ArrowUp".to_string(), "j".to_string()],
+        )
+    }
+
+    fn count(r: &mut KeyRepeat<&'static str>, now: Instant) -> u32 {
+        r.due(now).map_or(0, |(_, n)| n)
+    }
+
+    #[test]
+    fn first_repeat_after_delay_then_every_interval() {
+        let t0 = Instant::now();
+        let up = Key::Named(NamedKey::ArrowUp);
+        let mut r = repeat();
+        assert!(!r.key_event(&up, true, false, &"up", t0));
+
+        assert_eq!(r.next_deadline(), Some(t0 + DELAY));
+        assert_eq!(count(&mut r, t0 + ms(299)), 0);
+        assert_eq!(r.due(t0 + DELAY), Some(("up", 1)));
+        assert_eq!(r.next_deadline(), Some(t0 + ms(350)));
+        assert_eq!(count(&mut r, t0 + ms(349)), 0);
+        assert_eq!(count(&mut r, t0 + ms(350)), 1);
+        // Polled late: both missed repeats are emitted, schedule stays aligned
+        assert_eq!(count(&mut r, t0 + ms(455)), 2);
+        assert_eq!(r.next_deadline(), Some(t0 + ms(500)));
+
+        // Release stops repeating
+        assert!(!r.key_event(&up, false, false, &"up", t0 + ms(460)));
+        assert_eq!(r.next_deadline(), None);
+        assert_eq!(count(&mut r, t0 + ms(1000)), 0);
+    }
+
+    #[test]
+    fn os_repeats_of_handled_keys_are_dropped() {
+        let t0 = Instant::now();
+        let mut r = repeat();
+        let j = Key::Character("J".into());
+        let k = Key::Character("k".into());
+        assert!(r.handles(&j), "names match case-insensitively");
+        assert!(!r.handles(&k));
+
+        assert!(!r.key_event(&j, true, false, &"j", t0));
+        assert!(r.key_event(&j, true, true, &"j", t0 + ms(30)));
+        assert!(!r.key_event(&k, true, true, &"k", t0 + ms(30)));
+    }
+
+    #[test]
+    fn other_keys_stop_repeat_but_modifiers_do_not() {
+        let t0 = Instant::now();
+        let mut r = repeat();
+        let up = Key::Named(NamedKey::ArrowUp);
+        r.key_event(&up, true, false, &"up", t0);
+
+        r.key_event(
+            &Key::Named(NamedKey::Shift),
+            true,
+            false,
+            &"shift",
+            t0 + ms(10),
+        );
+        assert!(r.next_deadline().is_some());
+
+        r.key_event(&Key::Character("x".into()), true, false, &"x", t0 + ms(20));
+        assert_eq!(r.next_deadline(), None);
+    }
+
+    #[test]
+    fn long_stall_does_not_flush_a_burst() {
+        let t0 = Instant::now();
+        let mut r = repeat();
+        r.key_event(&Key::Named(NamedKey::ArrowUp), true, false, &"up", t0);
+
+        assert_eq!(count(&mut r, t0 + ms(5000)), MAX_CATCH_UP);
+        assert_eq!(r.next_deadline(), Some(t0 + ms(5050)));
+    }
+}
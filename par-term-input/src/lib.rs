@@ -20,16 +20,22 @@
 //! - [`clipboard`] — paste/copy and X11 primary selection
 //!
 //! The split is purely organizational; the public API is unchanged.
+//!
+//! [`key_repeat`] schedules app-level key repeat for keys configured in
+//! `key_repeat_keys`.
 
 use arboard::Clipboard;
 use winit::event::Modifiers;
 
-use par_term_config::OptionKeyMode;
+use par_term_config::{BackspaceSends, OptionKeyMode};
 
 mod clipboard;
 mod key_encoding;
+pub mod key_repeat;
 mod modifiers;
 
+pub use key_repeat::KeyRepeat;
+
 /// Input handler for converting winit events to terminal input
 pub struct InputHandler {
     pub modifiers: Modifiers,
@@ -38,11 +44,17 @@ pub struct InputHandler {
     pub left_option_key_mode: OptionKeyMode,
     /// Option key mode for right Option/Alt key
     pub right_option_key_mode: OptionKeyMode,
+    /// Both Alt keys send an ESC prefix, overriding the Option key modes
+    pub alt_sends_escape: bool,
+    /// Byte sent by the Backspace key (DEL or BS)
+    pub backspace_sends: BackspaceSends,
     /// Track which Alt key is currently pressed (for determining mode on character input)
     /// True = left Alt is pressed, False = right Alt or no Alt
     left_alt_pressed: bool,
     /// True = right Alt is pressed
     right_alt_pressed: bool,
+    /// App-level repeat for keys listed in `key_repeat_keys`
+    pub key_repeat: KeyRepeat,
 }
 
 impl InputHandler {
@@ -58,8 +70,11 @@ impl InputHandler {
             clipboard,
             left_option_key_mode: OptionKeyMode::default(),
             right_option_key_mode: OptionKeyMode::default(),
+            alt_sends_escape: false,
+            backspace_sends: BackspaceSends::default(),
             left_alt_pressed: false,
             right_alt_pressed: false,
+            key_repeat: KeyRepeat::default(),
         }
     }
 }
@@ -26,6 +26,11 @@ impl InputHandler {
         self.right_option_key_mode = right;
     }
 
+    /// Update whether Alt always sends an ESC prefix from config
+    pub fn update_alt_sends_escape(&mut self, enabled: bool) {
+        self.alt_sends_escape = enabled;
+    }
+
     /// Track Alt key press/release to know which Alt is active
     pub fn track_alt_key(&mut self, event: &KeyEvent) {
         // Check if this is an Alt key event by physical key
@@ -78,7 +83,12 @@ impl InputHandler {
     }
 
     /// Get the active Option key mode based on which Alt key is pressed
+    ///
+    /// `alt_sends_escape` overrides both keys' modes with ESC.
     pub(crate) fn get_active_option_mode(&self) -> OptionKeyMode {
+        if self.alt_sends_escape {
+            return OptionKeyMode::Esc;
+        }
         // If both are pressed, prefer left (arbitrary but consistent)
         // If only one is pressed, use that one's mode
         // If neither is pressed (shouldn't happen when alt modifier is set), default to left
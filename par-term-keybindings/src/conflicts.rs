@@ -0,0 +1,166 @@
+//! Detection of keys bound more than once.
+//!
+//! When a key combo (or chord) is bound twice, the later binding silently
+//! replaces the earlier one.  [`detect_conflicts`] reports each such pair so
+//! the settings UI can warn before saving and the registry can log them.
+//! Combos are compared after resolving `CmdOrCtrl` for the current platform,
+//! so `CmdOrCtrl+C` and `Ctrl+C` conflict on Linux and Windows.  Bindings in
+//! different [`KeybindingMode`]s never conflict.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use par_term_config::{KeyBinding, KeybindingMode};
+
+use crate::parser::{self, KeyCombo};
+use crate::{is_removed_action, is_unbind_action};
+
+/// Two bindings whose keys resolve to the same key presses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeybindingConflict {
+    /// The shared keys, normalized (one combo, or several for a chord)
+    pub keys: Vec<KeyCombo>,
+    /// The earlier binding, which is overridden
+    pub shadowed: KeyBinding,
+    /// The later binding, which takes effect
+    pub winner: KeyBinding,
+}
+
+impl fmt::Display for KeybindingConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let keys: Vec<String> = self.keys.iter().map(ToString::to_string).collect();
+        write!(
+            f,
+            "{} is bound to both '{}' ({}) and '{}' ({}); '{}' wins",
+            keys.join(" "),
+            self.shadowed.action,
+            self.shadowed.key,
+            self.winner.action,
+            self.winner.key,
+            self.winner.action
+        )
+    }
+}
+
+/// Find bindings in `keybindings` whose keys are bound again later in the
+/// list to a different action, in list order.
+///
+/// Bindings that do not parse, removed actions and unbind entries (see
+/// [`is_unbind_action`]) are ignored.  Binding a key twice to the same action,
+/// or in different modes, is not a conflict.
+pub fn detect_conflicts(keybindings: &[KeyBinding]) -> Vec<KeybindingConflict> {
+    let mut seen: HashMap<(KeybindingMode, Vec<KeyCombo>), &KeyBinding> = HashMap::new();
+    let mut conflicts = Vec::new();
+    for binding in keybindings {
+        if is_removed_action(&binding.action) || is_unbind_action(&binding.action) {
+            continue;
+        }
+        let Ok(combos) = parser::parse_key_combos(&binding.key) else {
+            continue;
+        };
+        let keys: Vec<KeyCombo> = combos.iter().map(KeyCombo::normalized).collect();
+        if let Some(previous) = seen.insert((binding.mode, keys.clone()), binding)
+            && previous.action != binding.action
+        {
+            conflicts.push(KeybindingConflict {
+                keys,
+                shadowed: previous.clone(),
+                winner: binding.clone(),
+            });
+        }
+    }
+    conflicts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bind(key: &str, action: &str) -> KeyBinding {
+        KeyBinding {
+            key: key.to_string(),
+            action: action.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn same_combo_bound_twice_conflicts() {
+        let conflicts = detect_conflicts(&[
+            bind("Ctrl+Shift+B", "toggle_background_shader"),
+            bind("Ctrl+Shift+U", "toggle_cursor_shader"),
+            bind("shift+ctrl+b", "toggle_fps_overlay"),
+        ]);
+        assert_eq!(conflicts.len(), 1);
+        let conflict = &conflicts[0];
+        assert_eq!(conflict.shadowed.action, "toggle_background_shader");
+        assert_eq!(conflict.winner.action, "toggle_fps_overlay");
+        assert_eq!(conflict.winner.key, "shift+ctrl+b");
+        assert_eq!(
+            conflict.to_string(),
+            "Ctrl+Shift+B is bound to both 'toggle_background_shader' (Ctrl+Shift+B) \
+             and 'toggle_fps_overlay' (shift+ctrl+b); 'toggle_fps_overlay' wins"
+        );
+    }
+
+    #[test]
+    fn cmd_or_ctrl_conflicts_with_its_platform_modifier() {
+        #[cfg(target_os = "macos")]
+        let platform_key = "Cmd+C";
+        #[cfg(not(target_os = "macos"))]
+        let platform_key = "Ctrl+C";
+
+        let conflicts =
+            detect_conflicts(&[bind("CmdOrCtrl+C", "copy"), bind(platform_key, "interrupt")]);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].shadowed.key, "CmdOrCtrl+C");
+        assert_eq!(conflicts[0].winner.key, platform_key);
+
+        // Physical and logical keys match differently, so they do not conflict
+        assert!(
+            detect_conflicts(&[bind("Ctrl+C", "copy"), bind("Ctrl+[KeyC]", "interrupt")])
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn chords_and_repeats() {
+        let conflicts = detect_conflicts(&[
+            bind("Ctrl+A Ctrl+S", "save_session"),
+            bind("Ctrl+A Ctrl+D", "detach"),
+            bind("Ctrl+A  CmdOrCtrl+S", "split_horizontal"),
+        ]);
+        #[cfg(not(target_os = "macos"))]
+        assert_eq!(conflicts.len(), 1);
+        #[cfg(target_os = "macos")]
+        assert!(conflicts.is_empty());
+
+        // Each override is reported against the binding it replaced
+        let conflicts = detect_conflicts(&[bind("F5", "a"), bind("F5", "b"), bind("F5", "c")]);
+        let pairs: Vec<(&str, &str)> = conflicts
+            .iter()
+            .map(|c| (c.shadowed.action.as_str(), c.winner.action.as_str()))
+            .collect();
+        assert_eq!(pairs, [("a", "b"), ("b", "c")]);
+
+        // Not conflicts: same action twice, unbinding, invalid keys
+        assert!(
+            detect_conflicts(&[
+                bind("Ctrl+V", "paste"),
+                bind("Ctrl+V", "paste"),
+                bind("Ctrl+V", "unbound"),
+                bind("Bogus+V", "copy"),
+                bind("Ctrl+P", "toggle_prettifier"),
+                bind("Ctrl+P", "command_palette"),
+            ])
+            .is_empty()
+        );
+
+        // Nor is the same key in two modes
+        let copy_mode_h = KeyBinding {
+            mode: KeybindingMode::Copy,
+            ..bind("Ctrl+H", "copy_mode_move_left")
+        };
+        assert!(detect_conflicts(&[bind("Ctrl+H", "toggle_help"), copy_mode_h]).is_empty());
+    }
+}
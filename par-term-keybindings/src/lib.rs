@@ -7,11 +7,17 @@
 //! - Configurable key combinations (Ctrl+Shift+B, CmdOrCtrl+V, etc.)
 //! - Modifier remapping (swap Ctrl and Super, etc.)
 //! - Physical key support for language-agnostic bindings
+//! - Multi-key chords (`Ctrl+A Ctrl+S`) with a timeout between keys
+//! - A leader key that plain-key bindings (e.g. `C`) must follow
+//! - Detection of keys bound more than once
+//! - Bindings scoped to an input mode (normal, copy mode, copy mode search)
 
+mod conflicts;
 mod matcher;
 pub mod parser;
 pub mod platform;
 
+pub use conflicts::{KeybindingConflict, detect_conflicts};
 pub use matcher::KeybindingMatcher;
 pub use parser::KeyCombo;
 // `ParseError` is consumed by `src/keybindings/mod.rs` in the root crate.
@@ -19,20 +25,94 @@ pub use parser::KeyCombo;
 // imports this re-export, so the lint fires even though downstream consumers use it.
 #[allow(unused_imports)]
 pub use parser::ParseError;
-pub use parser::{key_combo_to_bytes, parse_key_sequence};
+pub use parser::{key_combo_to_bytes, parse_key_combos, parse_key_sequence};
 
+pub use par_term_config::KeybindingMode;
 use par_term_config::{KeyBinding, ModifierRemapping};
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Default time allowed between the keys of a chord.
+pub const DEFAULT_CHORD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Action returned for a key consumed by the leader: the leader key itself,
+/// or Escape cancelling it.  Treat it as handled without running anything.
+pub const LEADER_ACTION: &str = "leader";
 
 fn is_removed_action(action: &str) -> bool {
     matches!(action, "toggle_prettifier")
 }
 
+/// Whether `action` is a sentinel (`"unbound"` or `"none"`) that removes the
+/// key from the registry instead of binding it, e.g. to turn off a default.
+pub fn is_unbind_action(action: &str) -> bool {
+    matches!(action, "unbound" | "none")
+}
+
+/// Result of feeding a key press to [`KeybindingRegistry::lookup_sequence`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SequenceMatch {
+    /// The key started or continued a chord; wait for the next key
+    Pending,
+    /// The key completed a binding for this action
+    Matched(String),
+    /// No binding starts or continues with this key
+    NoMatch,
+}
+
+/// Chord in progress between key presses.
+#[derive(Debug)]
+struct PendingChord {
+    /// Mode the chord was started in
+    mode: KeybindingMode,
+    /// Indices into the mode's chords of the chords still possible
+    candidates: Vec<usize>,
+    /// Number of keys matched so far
+    depth: usize,
+    /// Action bound to the keys matched so far, run if the chord times out
+    fallback: Option<String>,
+    /// When the last key of the chord was pressed
+    last_key_at: Instant,
+}
+
 /// Registry of keybindings mapping key combinations to action names.
-#[derive(Debug, Default)]
+///
+/// Each [`KeybindingMode`] has its own bindings; a lookup only sees the
+/// bindings of the mode it is given.
+#[derive(Debug)]
 pub struct KeybindingRegistry {
-    /// Map of parsed key combos to action names
-    bindings: HashMap<KeyCombo, String>,
+    /// Map of parsed key combos to action names, per mode
+    bindings: HashMap<KeybindingMode, HashMap<KeyCombo, String>>,
+    /// Multi-key chords and their action names, per mode
+    chords: HashMap<KeybindingMode, Vec<(Vec<KeyCombo>, String)>>,
+    /// Chord in progress, if any
+    pending: Option<PendingChord>,
+    /// Action of a chord prefix that the last key broke off before its
+    /// timeout ran it (see [`KeybindingRegistry::take_interrupted_fallback`])
+    interrupted_fallback: Option<String>,
+    /// Time allowed between the keys of a chord
+    chord_timeout: Duration,
+    /// Key that plain-key bindings must follow (normal mode only)
+    leader: Option<KeyCombo>,
+    /// Whether the leader was the last key pressed
+    leader_armed: bool,
+    /// Keys bound more than once in the config this registry was built from
+    conflicts: Vec<KeybindingConflict>,
+}
+
+impl Default for KeybindingRegistry {
+    fn default() -> Self {
+        Self {
+            bindings: HashMap::new(),
+            chords: HashMap::new(),
+            pending: None,
+            interrupted_fallback: None,
+            chord_timeout: DEFAULT_CHORD_TIMEOUT,
+            leader: None,
+            leader_armed: false,
+            conflicts: Vec::new(),
+        }
+    }
 }
 
 impl KeybindingRegistry {
@@ -41,16 +121,43 @@ impl KeybindingRegistry {
         Self::default()
     }
 
+    /// Set the time allowed between the keys of a chord.
+    pub fn with_chord_timeout(mut self, timeout: Duration) -> Self {
+        self.chord_timeout = timeout;
+        self
+    }
+
+    /// Set the leader key.
+    ///
+    /// With a leader, normal mode bindings on plain keys (see
+    /// [`KeyCombo::is_plain`]) only fire for the key right after the leader,
+    /// so the keys still type text on their own.  Other bindings, and
+    /// bindings in other modes, are unaffected.
+    pub fn with_leader(mut self, leader: Option<KeyCombo>) -> Self {
+        self.leader = leader;
+        self.leader_armed = false;
+        self
+    }
+
     /// Build a registry from config keybindings.
     ///
-    /// Invalid keybinding strings are logged and skipped.
+    /// Each binding goes into the map of its mode.  Invalid keybinding strings
+    /// are logged and skipped.  Keys bound more than once in the same mode are
+    /// logged and available from [`conflicts`](Self::conflicts).
     pub fn from_config(keybindings: &[KeyBinding]) -> Self {
         let mut registry = Self::new();
+        registry.conflicts = detect_conflicts(keybindings);
+        for conflict in &registry.conflicts {
+            log::warn!("Keybinding conflict: {}", conflict);
+        }
 
         log::info!(
             "Building keybinding registry from {} config keybindings",
             keybindings.len()
         );
+        // Applied after every binding is added, so an unbind wins wherever
+        // it appears in the list
+        let mut unbound = Vec::new();
         for binding in keybindings {
             if is_removed_action(&binding.action) {
                 log::info!(
@@ -61,15 +168,31 @@ impl KeybindingRegistry {
                 continue;
             }
 
-            match parser::parse_key_combo(&binding.key) {
-                Ok(combo) => {
+            match parser::parse_key_combos(&binding.key) {
+                Ok(combos) if is_unbind_action(&binding.action) => {
+                    unbound.push((binding.mode, combos))
+                }
+                Ok(mut combos) => {
                     log::info!(
-                        "Registered keybinding: {} -> {} (parsed as: {:?})",
+                        "Registered keybinding: {} -> {} in {:?} mode (parsed as: {:?})",
                         binding.key,
                         binding.action,
-                        combo
+                        binding.mode,
+                        combos
                     );
-                    registry.bindings.insert(combo, binding.action.clone());
+                    if combos.len() == 1 {
+                        let combo = combos.remove(0);
+                        registry
+                            .bindings
+                            .entry(binding.mode)
+                            .or_default()
+                            .insert(combo, binding.action.clone());
+                    } else {
+                        // Like single keys, a later binding replaces an earlier one
+                        let chords = registry.chords.entry(binding.mode).or_default();
+                        chords.retain(|(keys, _)| *keys != combos);
+                        chords.push((combos, binding.action.clone()));
+                    }
                 }
                 Err(e) => {
                     log::warn!(
@@ -82,22 +205,46 @@ impl KeybindingRegistry {
             }
         }
 
+        for (mode, combos) in unbound {
+            if let [combo] = combos.as_slice() {
+                if let Some(action) = registry
+                    .bindings
+                    .get_mut(&mode)
+                    .and_then(|bindings| bindings.remove(combo))
+                {
+                    log::info!("Unbound keybinding {} (was {})", combo, action);
+                }
+            } else if let Some(chords) = registry.chords.get_mut(&mode) {
+                chords.retain(|(keys, _)| *keys != combos);
+            }
+        }
+
         log::info!(
             "Keybinding registry initialized with {} bindings",
-            registry.bindings.len()
+            registry.len()
         );
         registry
     }
 
-    /// Look up an action for a key event.
+    /// Look up a normal mode action for a key event.
     ///
-    /// Returns the action name if a matching keybinding is found.
+    /// Only single-key bindings are considered; use
+    /// [`lookup_sequence`](Self::lookup_sequence) to also match chords.
+    ///
+    /// Returns the action name if a matching keybinding is found, or
+    /// [`LEADER_ACTION`] for a key consumed by the leader.
     pub fn lookup(
-        &self,
+        &mut self,
         event: &winit::event::KeyEvent,
         modifiers: &winit::event::Modifiers,
     ) -> Option<&str> {
-        self.lookup_with_options(event, modifiers, &ModifierRemapping::default(), false)
+        self.lookup_with_options(
+            event,
+            modifiers,
+            &ModifierRemapping::default(),
+            false,
+            KeybindingMode::Normal,
+        )
     }
 
     /// Look up an action for a key event with advanced options.
@@ -108,34 +255,284 @@ impl KeybindingRegistry {
     /// * `remapping` - Modifier key remapping configuration
     /// * `use_physical_keys` - If true, match by physical key position (scan code) for
     ///   language-agnostic bindings. This makes keybindings consistent across keyboard layouts.
+    /// * `mode` - The active input mode; only bindings for this mode match
     ///
-    /// Returns the action name if a matching keybinding is found.
+    /// Returns the action name if a matching keybinding is found, or
+    /// [`LEADER_ACTION`] for a key consumed by the leader.
     pub fn lookup_with_options(
-        &self,
+        &mut self,
         event: &winit::event::KeyEvent,
         modifiers: &winit::event::Modifiers,
         remapping: &ModifierRemapping,
         use_physical_keys: bool,
+        mode: KeybindingMode,
     ) -> Option<&str> {
         let matcher = KeybindingMatcher::from_event_with_remapping(event, modifiers, remapping);
+        self.lookup_matcher(&matcher, use_physical_keys, mode)
+    }
+
+    fn lookup_matcher(
+        &mut self,
+        matcher: &KeybindingMatcher,
+        use_physical_keys: bool,
+        mode: KeybindingMode,
+    ) -> Option<&str> {
+        match self.take_leader(matcher, use_physical_keys, mode) {
+            Some(plain_allowed) => {
+                self.find_binding(matcher, use_physical_keys, plain_allowed, mode)
+            }
+            None => Some(LEADER_ACTION),
+        }
+    }
+
+    /// Apply the leader to a key press.
+    ///
+    /// Returns `None` when the key arms the leader or is Escape cancelling
+    /// it; otherwise whether plain-key bindings may fire for the key.  An
+    /// armed leader only lasts for one key.  Outside normal mode plain keys
+    /// are always allowed and the leader is left alone.
+    fn take_leader(
+        &mut self,
+        matcher: &KeybindingMatcher,
+        use_physical_keys: bool,
+        mode: KeybindingMode,
+    ) -> Option<bool> {
+        let Some(leader) = self.leader.as_ref().filter(|_| mode.is_normal()) else {
+            return Some(true);
+        };
+        if std::mem::take(&mut self.leader_armed) {
+            return (!matcher.is_named(winit::keyboard::NamedKey::Escape)).then_some(true);
+        }
+        if matcher.matches_with_physical_preference(leader, use_physical_keys) {
+            self.leader_armed = true;
+            return None;
+        }
+        Some(false)
+    }
+
+    fn find_binding(
+        &self,
+        matcher: &KeybindingMatcher,
+        use_physical_keys: bool,
+        plain_allowed: bool,
+        mode: KeybindingMode,
+    ) -> Option<&str> {
+        self.bindings
+            .get(&mode)?
+            .iter()
+            .filter(|(combo, _)| plain_allowed || !combo.is_plain())
+            .find(|(combo, _)| matcher.matches_with_physical_preference(combo, use_physical_keys))
+            .map(|(_, action)| action.as_str())
+    }
+
+    /// Whether the leader was pressed and the next key may fire a plain-key
+    /// binding.
+    pub fn is_leader_armed(&self) -> bool {
+        self.leader_armed
+    }
+
+    /// Feed a key press to the chord state machine.
+    ///
+    /// Returns [`SequenceMatch::Pending`] while the keys pressed so far are
+    /// the start of a chord, and [`SequenceMatch::Matched`] once a chord or
+    /// single-key binding is complete.  A key that does not continue the
+    /// pending chord cancels it and is looked up on its own.  A chord whose
+    /// next key comes later than the chord timeout starts over.
+    ///
+    /// When the keys so far are both a complete binding and the start of a
+    /// longer chord, the result is `Pending`; [`expire_pending`](Self::expire_pending)
+    /// returns the shorter binding's action once the timeout passes.  If a
+    /// key that does not continue the chord comes first (or the timeout
+    /// passed unnoticed), the shorter binding's action is left for
+    /// [`take_interrupted_fallback`](Self::take_interrupted_fallback), to be
+    /// run before this key's own result.
+    ///
+    /// With a leader set, a normal mode chord starting with a plain key must
+    /// follow the leader like any plain-key binding; keys consumed by the
+    /// leader match [`LEADER_ACTION`].
+    ///
+    /// Only bindings for `mode` are considered.  A chord pending from another
+    /// mode is dropped.
+    pub fn lookup_sequence(
+        &mut self,
+        event: &winit::event::KeyEvent,
+        modifiers: &winit::event::Modifiers,
+        remapping: &ModifierRemapping,
+        use_physical_keys: bool,
+        mode: KeybindingMode,
+        now: Instant,
+    ) -> SequenceMatch {
+        let matcher = KeybindingMatcher::from_event_with_remapping(event, modifiers, remapping);
+        self.advance_sequence(&matcher, use_physical_keys, mode, now)
+    }
+
+    fn advance_sequence(
+        &mut self,
+        matcher: &KeybindingMatcher,
+        use_physical_keys: bool,
+        mode: KeybindingMode,
+        now: Instant,
+    ) -> SequenceMatch {
+        let pending = match self.pending.take() {
+            Some(p) if p.mode != mode => None,
+            Some(p) if now.saturating_duration_since(p.last_key_at) > self.chord_timeout => {
+                self.interrupted_fallback = p.fallback;
+                None
+            }
+            pending => pending,
+        };
+
+        let (candidates, depth, mut complete) = match &pending {
+            Some(p) => (p.candidates.clone(), p.depth, None),
+            None => {
+                let Some(plain_allowed) = self.take_leader(matcher, use_physical_keys, mode) else {
+                    return SequenceMatch::Matched(LEADER_ACTION.to_string());
+                };
+                let chords = self
+                    .chords
+                    .get(&mode)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+                let candidates = (0..chords.len())
+                    .filter(|&index| plain_allowed || !chords[index].0[0].is_plain())
+                    .collect();
+                let single = self.find_binding(matcher, use_physical_keys, plain_allowed, mode);
+                (candidates, 0, single.map(str::to_string))
+            }
+        };
+        let chords = self
+            .chords
+            .get(&mode)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let mut longer = Vec::new();
+        for index in candidates {
+            let (keys, action) = &chords[index];
+            if !matcher.matches_with_physical_preference(&keys[depth], use_physical_keys) {
+                continue;
+            }
+            if keys.len() == depth + 1 {
+                complete = Some(action.clone());
+            } else {
+                longer.push(index);
+            }
+        }
 
-        for (combo, action) in &self.bindings {
-            if matcher.matches_with_physical_preference(combo, use_physical_keys) {
-                return Some(action.as_str());
+        if !longer.is_empty() {
+            self.pending = Some(PendingChord {
+                mode,
+                candidates: longer,
+                depth: depth + 1,
+                fallback: complete,
+                last_key_at: now,
+            });
+            return SequenceMatch::Pending;
+        }
+        match (complete, pending) {
+            (Some(action), _) => SequenceMatch::Matched(action),
+            // The key broke off a chord: keep the keys so far's own action,
+            // then try the key as the start of a new one
+            (None, Some(broken)) => {
+                self.interrupted_fallback = broken.fallback;
+                self.advance_sequence(matcher, use_physical_keys, mode, now)
             }
+            (None, None) => SequenceMatch::NoMatch,
+        }
+    }
+
+    /// Whether a chord is waiting for its next key.
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// When the pending chord times out, if there is one.
+    pub fn pending_deadline(&self) -> Option<Instant> {
+        self.pending
+            .as_ref()
+            .map(|p| p.last_key_at + self.chord_timeout)
+    }
+
+    /// Keys still needed by each chord the pending keys can lead to, with
+    /// the chord's action, for a which-key style hint.
+    pub fn pending_continuations(&self) -> Vec<(&[KeyCombo], &str)> {
+        let Some(pending) = &self.pending else {
+            return Vec::new();
+        };
+        pending
+            .candidates
+            .iter()
+            .map(|&index| {
+                let (keys, action) = &self.chords[&pending.mode][index];
+                (&keys[pending.depth..], action.as_str())
+            })
+            .collect()
+    }
+
+    /// Drop the pending chord once its timeout has passed.
+    ///
+    /// Returns the action bound to the keys pressed so far, if any.
+    pub fn expire_pending(&mut self, now: Instant) -> Option<String> {
+        if self
+            .pending_deadline()
+            .is_none_or(|deadline| now < deadline)
+        {
+            return None;
         }
+        self.pending.take().and_then(|p| p.fallback)
+    }
+
+    /// Action bound to a chord prefix that the last looked-up key broke off
+    /// instead of continuing, such as `Ctrl+B` when `Ctrl+B` and `Ctrl+B C`
+    /// are both bound and `X` follows.  Callers run it before acting on the
+    /// [`SequenceMatch`] for that key, so the prefix behaves as if it had
+    /// timed out first.
+    pub fn take_interrupted_fallback(&mut self) -> Option<String> {
+        self.interrupted_fallback.take()
+    }
 
-        None
+    /// Drop the pending chord and disarm the leader without running anything.
+    pub fn cancel_pending(&mut self) {
+        self.pending = None;
+        self.interrupted_fallback = None;
+        self.leader_armed = false;
+    }
+
+    /// Normal mode single-key combos bound to `action`, for showing its
+    /// shortcut in help and settings.  Sorted by their display form; chords
+    /// are not included.
+    pub fn combos_for_action(&self, action: &str) -> Vec<&KeyCombo> {
+        self.combos_for_action_in(KeybindingMode::Normal, action)
+    }
+
+    /// Single-key combos bound to `action` in `mode`, sorted like
+    /// [`combos_for_action`](Self::combos_for_action).
+    pub fn combos_for_action_in(&self, mode: KeybindingMode, action: &str) -> Vec<&KeyCombo> {
+        let Some(bindings) = self.bindings.get(&mode) else {
+            return Vec::new();
+        };
+        let mut combos: Vec<&KeyCombo> = bindings
+            .iter()
+            .filter(|(_, bound)| bound.as_str() == action)
+            .map(|(combo, _)| combo)
+            .collect();
+        combos.sort_by_cached_key(|combo| combo.to_string());
+        combos
+    }
+
+    /// Keys bound more than once in the config, in config order.
+    pub fn conflicts(&self) -> &[KeybindingConflict] {
+        &self.conflicts
     }
 
     /// Check if the registry has any bindings.
     pub fn is_empty(&self) -> bool {
-        self.bindings.is_empty()
+        self.len() == 0
     }
 
-    /// Get the number of registered bindings.
+    /// Get the number of registered bindings in all modes, chords included.
     pub fn len(&self) -> usize {
-        self.bindings.len()
+        self.bindings.values().map(HashMap::len).sum::<usize>()
+            + self.chords.values().map(Vec::len).sum::<usize>()
     }
 }
 
@@ -156,10 +553,12 @@ mod tests {
             KeyBinding {
                 key: "Ctrl+Shift+B".to_string(),
                 action: "toggle_background_shader".to_string(),
+                ..Default::default()
             },
             KeyBinding {
                 key: "Ctrl+Shift+U".to_string(),
                 action: "toggle_cursor_shader".to_string(),
+                ..Default::default()
             },
         ];
 
@@ -173,10 +572,12 @@ mod tests {
             KeyBinding {
                 key: "Ctrl+Shift+P".to_string(),
                 action: "toggle_prettifier".to_string(),
+                ..Default::default()
             },
             KeyBinding {
                 key: "Ctrl+Shift+B".to_string(),
                 action: "toggle_background_shader".to_string(),
+                ..Default::default()
             },
         ];
 
@@ -184,16 +585,504 @@ mod tests {
         assert_eq!(registry.len(), 1);
     }
 
+    #[test]
+    fn test_unbound_action_removes_binding() {
+        let paste = |key: &str| KeyBinding {
+            key: key.to_string(),
+            action: "paste".to_string(),
+            ..Default::default()
+        };
+        let unbind = |key: &str, action: &str| KeyBinding {
+            key: key.to_string(),
+            action: action.to_string(),
+            ..Default::default()
+        };
+
+        // The unbind comes before the default it turns off
+        let mut registry = KeybindingRegistry::from_config(&[
+            unbind("Ctrl+V", "unbound"),
+            paste("Ctrl+V"),
+            paste("Ctrl+Shift+V"),
+        ]);
+        assert_eq!(registry.len(), 1);
+        let ctrl_v = KeybindingMatcher::for_combo("Ctrl+V");
+        assert_eq!(
+            registry.lookup_matcher(&ctrl_v, false, KeybindingMode::Normal),
+            None
+        );
+        let ctrl_shift_v = KeybindingMatcher::for_combo("Ctrl+Shift+V");
+        assert_eq!(
+            registry.lookup_matcher(&ctrl_shift_v, false, KeybindingMode::Normal),
+            Some("paste")
+        );
+
+        // "none" works the same, and also removes chords
+        let mut registry = KeybindingRegistry::from_config(&[
+            paste("Ctrl+V"),
+            unbind("Ctrl+V", "none"),
+            unbind("Ctrl+A Ctrl+S", "save_session"),
+            unbind("Ctrl+A Ctrl+S", "unbound"),
+        ]);
+        assert!(registry.is_empty());
+        assert_eq!(
+            registry.lookup_matcher(&ctrl_v, false, KeybindingMode::Normal),
+            None
+        );
+
+        // Unbinding a key that was never bound is harmless
+        let registry = KeybindingRegistry::from_config(&[unbind("Ctrl+Q", "unbound")]);
+        assert!(registry.is_empty());
+    }
+
+    #[test]
+    fn test_from_config_reports_conflicts() {
+        let bind = |key: &str, action: &str| KeyBinding {
+            key: key.to_string(),
+            action: action.to_string(),
+            ..Default::default()
+        };
+        let mut registry = KeybindingRegistry::from_config(&[
+            bind("Ctrl+Shift+B", "toggle_background_shader"),
+            bind("Ctrl+Shift+B", "toggle_cursor_shader"),
+        ]);
+        assert_eq!(registry.len(), 1);
+        let [conflict] = registry.conflicts() else {
+            panic!("expected one conflict: {:?}", registry.conflicts());
+        };
+        assert_eq!(conflict.winner.action, "toggle_cursor_shader");
+        let ctrl_shift_b = KeybindingMatcher::for_combo("Ctrl+Shift+B");
+        assert_eq!(
+            registry.lookup_matcher(&ctrl_shift_b, false, KeybindingMode::Normal),
+            Some("toggle_cursor_shader"),
+            "the later binding wins"
+        );
+    }
+
+    #[test]
+    fn test_combos_for_action() {
+        let bind = |key: &str, action: &str| KeyBinding {
+            key: key.to_string(),
+            action: action.to_string(),
+            ..Default::default()
+        };
+        let registry = KeybindingRegistry::from_config(&[
+            bind("Ctrl+Shift+V", "paste"),
+            bind("CmdOrCtrl+V", "paste"),
+            bind("Ctrl+[KeyZ]", "undo"),
+            bind("F5", "reload_config"),
+            bind("Ctrl+A Ctrl+V", "paste"),
+        ]);
+
+        let keys = |action: &str| -> Vec<String> {
+            registry
+                .combos_for_action(action)
+                .iter()
+                .map(|combo| combo.to_string())
+                .collect()
+        };
+        assert_eq!(keys("paste"), ["CmdOrCtrl+V", "Ctrl+Shift+V"]);
+        assert_eq!(keys("undo"), ["Ctrl+[KeyZ]"]);
+        assert_eq!(keys("reload_config"), ["F5"]);
+        assert!(keys("save_session").is_empty());
+    }
+
+    fn chord_registry() -> KeybindingRegistry {
+        KeybindingRegistry::from_config(&[
+            KeyBinding {
+                key: "Ctrl+A Ctrl+S".to_string(),
+                action: "save_session".to_string(),
+                ..Default::default()
+            },
+            KeyBinding {
+                key: "Ctrl+B".to_string(),
+                action: "toggle_tab_bar".to_string(),
+                ..Default::default()
+            },
+            KeyBinding {
+                key: "Ctrl+B C".to_string(),
+                action: "new_tab".to_string(),
+                ..Default::default()
+            },
+        ])
+    }
+
+    fn press(registry: &mut KeybindingRegistry, key: &str, now: Instant) -> SequenceMatch {
+        registry.advance_sequence(
+            &KeybindingMatcher::for_combo(key),
+            false,
+            KeybindingMode::Normal,
+            now,
+        )
+    }
+
+    #[test]
+    fn test_two_key_chord() {
+        let mut registry = chord_registry();
+        assert_eq!(registry.len(), 3);
+        let t0 = Instant::now();
+
+        assert_eq!(press(&mut registry, "Ctrl+A", t0), SequenceMatch::Pending);
+        assert_eq!(
+            registry.pending_continuations(),
+            vec![(&parse_key_combos("Ctrl+S").unwrap()[..], "save_session")]
+        );
+        assert_eq!(
+            press(&mut registry, "Ctrl+S", t0 + Duration::from_millis(200)),
+            SequenceMatch::Matched("save_session".to_string())
+        );
+        assert!(!registry.is_pending());
+
+        // The second key alone is not bound
+        assert_eq!(press(&mut registry, "Ctrl+S", t0), SequenceMatch::NoMatch);
+        // Single-key lookup ignores chords
+        let matcher = KeybindingMatcher::for_combo("Ctrl+A");
+        assert_eq!(
+            registry.lookup_matcher(&matcher, false, KeybindingMode::Normal),
+            None
+        );
+
+        // A key that breaks the chord is looked up on its own
+        press(&mut registry, "Ctrl+A", t0);
+        assert_eq!(press(&mut registry, "X", t0), SequenceMatch::NoMatch);
+        assert!(!registry.is_pending());
+        press(&mut registry, "Ctrl+A", t0);
+        assert_eq!(press(&mut registry, "Ctrl+B", t0), SequenceMatch::Pending);
+        assert_eq!(
+            press(&mut registry, "C", t0),
+            SequenceMatch::Matched("new_tab".to_string())
+        );
+    }
+
+    #[test]
+    fn test_chord_prefix_that_is_also_a_binding() {
+        let mut registry = chord_registry();
+        let t0 = Instant::now();
+
+        // Ctrl+B alone is bound but also starts "Ctrl+B C": wait for more keys
+        assert_eq!(press(&mut registry, "Ctrl+B", t0), SequenceMatch::Pending);
+        assert_eq!(
+            press(&mut registry, "C", t0),
+            SequenceMatch::Matched("new_tab".to_string())
+        );
+
+        // With no follow-up key, the shorter binding runs at the timeout
+        press(&mut registry, "Ctrl+B", t0);
+        let deadline = t0 + DEFAULT_CHORD_TIMEOUT;
+        assert_eq!(registry.pending_deadline(), Some(deadline));
+        assert_eq!(
+            registry.expire_pending(deadline - Duration::from_millis(1)),
+            None
+        );
+        assert!(registry.is_pending());
+        assert_eq!(
+            registry.expire_pending(deadline),
+            Some("toggle_tab_bar".to_string())
+        );
+        assert!(!registry.is_pending());
+
+        // A chord prefix without its own binding expires silently
+        press(&mut registry, "Ctrl+A", t0);
+        assert_eq!(registry.expire_pending(deadline), None);
+        assert!(!registry.is_pending());
+    }
+
+    #[test]
+    fn test_broken_chord_keeps_prefix_binding() {
+        let mut registry = chord_registry();
+        let t0 = Instant::now();
+
+        // Ctrl+B then X: Ctrl+B's own binding still runs, and X is looked up
+        // on its own afterwards
+        assert_eq!(press(&mut registry, "Ctrl+B", t0), SequenceMatch::Pending);
+        assert_eq!(press(&mut registry, "X", t0), SequenceMatch::NoMatch);
+        assert_eq!(
+            registry.take_interrupted_fallback(),
+            Some("toggle_tab_bar".to_string())
+        );
+        assert_eq!(registry.take_interrupted_fallback(), None);
+        assert!(!registry.is_pending());
+
+        // The breaking key can start a chord of its own
+        press(&mut registry, "Ctrl+B", t0);
+        assert_eq!(press(&mut registry, "Ctrl+A", t0), SequenceMatch::Pending);
+        assert_eq!(
+            registry.take_interrupted_fallback(),
+            Some("toggle_tab_bar".to_string())
+        );
+        assert_eq!(
+            press(&mut registry, "Ctrl+S", t0),
+            SequenceMatch::Matched("save_session".to_string())
+        );
+        assert_eq!(registry.take_interrupted_fallback(), None);
+
+        // A key after an unnoticed timeout also runs the prefix's binding
+        press(&mut registry, "Ctrl+B", t0);
+        let late = t0 + DEFAULT_CHORD_TIMEOUT + Duration::from_millis(1);
+        assert_eq!(press(&mut registry, "X", late), SequenceMatch::NoMatch);
+        assert_eq!(
+            registry.take_interrupted_fallback(),
+            Some("toggle_tab_bar".to_string())
+        );
+
+        // Completing the chord does not run the prefix's binding
+        press(&mut registry, "Ctrl+B", t0);
+        press(&mut registry, "C", t0);
+        assert_eq!(registry.take_interrupted_fallback(), None);
+    }
+
+    #[test]
+    fn test_chord_timeout_resets_pending_state() {
+        let mut registry = chord_registry().with_chord_timeout(Duration::from_millis(500));
+        let t0 = Instant::now();
+
+        assert_eq!(press(&mut registry, "Ctrl+A", t0), SequenceMatch::Pending);
+        // Too late: the chord starts over, and Ctrl+S alone matches nothing
+        assert_eq!(
+            press(&mut registry, "Ctrl+S", t0 + Duration::from_millis(501)),
+            SequenceMatch::NoMatch
+        );
+        assert!(!registry.is_pending());
+
+        // A late key can start a new chord
+        press(&mut registry, "Ctrl+A", t0);
+        let late = t0 + Duration::from_secs(2);
+        assert_eq!(press(&mut registry, "Ctrl+A", late), SequenceMatch::Pending);
+        assert_eq!(
+            press(&mut registry, "Ctrl+S", late),
+            SequenceMatch::Matched("save_session".to_string())
+        );
+
+        press(&mut registry, "Ctrl+A", t0);
+        registry.cancel_pending();
+        assert!(!registry.is_pending());
+    }
+
+    fn leader_registry() -> KeybindingRegistry {
+        KeybindingRegistry::from_config(&[
+            KeyBinding {
+                key: "C".to_string(),
+                action: "new_tab".to_string(),
+                ..Default::default()
+            },
+            KeyBinding {
+                key: "Ctrl+Shift+W".to_string(),
+                action: "close_tab".to_string(),
+                ..Default::default()
+            },
+            KeyBinding {
+                key: "G G".to_string(),
+                action: "scroll_to_top".to_string(),
+                ..Default::default()
+            },
+        ])
+        .with_leader(Some(parser::parse_key_combo("Ctrl+B").unwrap()))
+    }
+
+    fn lookup(registry: &mut KeybindingRegistry, key: &str) -> Option<String> {
+        registry
+            .lookup_matcher(
+                &KeybindingMatcher::for_combo(key),
+                false,
+                KeybindingMode::Normal,
+            )
+            .map(str::to_string)
+    }
+
+    #[test]
+    fn test_leader_gates_plain_key_bindings() {
+        let mut registry = leader_registry();
+
+        // Pressing C alone types c
+        assert_eq!(lookup(&mut registry, "C"), None);
+
+        // Leader then C opens a tab
+        assert_eq!(
+            lookup(&mut registry, "Ctrl+B").as_deref(),
+            Some(LEADER_ACTION)
+        );
+        assert!(registry.is_leader_armed());
+        assert_eq!(lookup(&mut registry, "C").as_deref(), Some("new_tab"));
+        assert!(!registry.is_leader_armed());
+        assert_eq!(lookup(&mut registry, "C"), None, "leader lasts one key");
+
+        // Bindings with modifiers do not need the leader
+        assert_eq!(
+            lookup(&mut registry, "Ctrl+Shift+W").as_deref(),
+            Some("close_tab")
+        );
+
+        // Without a leader, plain keys fire on their own
+        let mut registry = leader_registry().with_leader(None);
+        assert_eq!(lookup(&mut registry, "C").as_deref(), Some("new_tab"));
+    }
+
+    #[test]
+    fn test_leader_cleared_by_any_key_or_escape() {
+        let mut registry = leader_registry();
+
+        // An unbound key after the leader uses it up
+        lookup(&mut registry, "Ctrl+B");
+        assert_eq!(lookup(&mut registry, "X"), None);
+        assert_eq!(lookup(&mut registry, "C"), None);
+
+        // Escape cancels the leader and is consumed
+        lookup(&mut registry, "Ctrl+B");
+        assert_eq!(
+            lookup(&mut registry, "Escape").as_deref(),
+            Some(LEADER_ACTION)
+        );
+        assert!(!registry.is_leader_armed());
+        assert_eq!(lookup(&mut registry, "C"), None);
+
+        lookup(&mut registry, "Ctrl+B");
+        registry.cancel_pending();
+        assert!(!registry.is_leader_armed());
+    }
+
+    #[test]
+    fn test_leader_gates_plain_key_chords() {
+        let mut registry = leader_registry();
+        let t0 = Instant::now();
+
+        assert_eq!(press(&mut registry, "G", t0), SequenceMatch::NoMatch);
+        assert_eq!(
+            press(&mut registry, "Ctrl+B", t0),
+            SequenceMatch::Matched(LEADER_ACTION.to_string())
+        );
+        assert_eq!(press(&mut registry, "G", t0), SequenceMatch::Pending);
+        assert_eq!(
+            press(&mut registry, "G", t0),
+            SequenceMatch::Matched("scroll_to_top".to_string())
+        );
+    }
+
+    fn mode_registry() -> KeybindingRegistry {
+        let bind = |key: &str, action: &str, mode| KeyBinding {
+            key: key.to_string(),
+            action: action.to_string(),
+            mode,
+        };
+        KeybindingRegistry::from_config(&[
+            bind(
+                "Ctrl+Shift+B",
+                "toggle_background_shader",
+                KeybindingMode::Normal,
+            ),
+            bind("H", "copy_mode_move_left", KeybindingMode::Copy),
+            bind(
+                "Ctrl+Shift+B",
+                "copy_mode_move_word_backward",
+                KeybindingMode::Copy,
+            ),
+            bind(
+                "Ctrl+W",
+                "copy_mode_search_delete_word",
+                KeybindingMode::Search,
+            ),
+            bind("G G", "copy_mode_goto_top", KeybindingMode::Copy),
+        ])
+        .with_leader(Some(parser::parse_key_combo("Ctrl+A").unwrap()))
+    }
+
+    fn lookup_in(
+        registry: &mut KeybindingRegistry,
+        key: &str,
+        mode: KeybindingMode,
+    ) -> Option<String> {
+        registry
+            .lookup_matcher(&KeybindingMatcher::for_combo(key), false, mode)
+            .map(str::to_string)
+    }
+
+    #[test]
+    fn test_mode_scoped_bindings() {
+        let mut registry = mode_registry();
+        assert_eq!(registry.len(), 5);
+
+        // A copy binding never fires in normal mode, and vice versa
+        assert_eq!(lookup_in(&mut registry, "H", KeybindingMode::Normal), None);
+        assert_eq!(
+            lookup_in(&mut registry, "H", KeybindingMode::Copy).as_deref(),
+            Some("copy_mode_move_left"),
+            "the normal mode leader does not gate copy mode keys"
+        );
+        assert_eq!(
+            lookup_in(&mut registry, "Ctrl+Shift+B", KeybindingMode::Normal).as_deref(),
+            Some("toggle_background_shader")
+        );
+        assert_eq!(
+            lookup_in(&mut registry, "Ctrl+Shift+B", KeybindingMode::Copy).as_deref(),
+            Some("copy_mode_move_word_backward")
+        );
+        assert_eq!(
+            lookup_in(&mut registry, "Ctrl+W", KeybindingMode::Copy),
+            None
+        );
+        assert_eq!(
+            lookup_in(&mut registry, "Ctrl+W", KeybindingMode::Search).as_deref(),
+            Some("copy_mode_search_delete_word")
+        );
+
+        // Chords are scoped too, and a pending chord does not cross modes
+        let t0 = Instant::now();
+        let mut press_in = |key: &str, mode| {
+            registry.advance_sequence(&KeybindingMatcher::for_combo(key), false, mode, t0)
+        };
+        assert_eq!(
+            press_in("G", KeybindingMode::Normal),
+            SequenceMatch::NoMatch
+        );
+        assert_eq!(press_in("G", KeybindingMode::Copy), SequenceMatch::Pending);
+        assert_eq!(
+            press_in("G", KeybindingMode::Normal),
+            SequenceMatch::NoMatch
+        );
+        assert_eq!(press_in("G", KeybindingMode::Copy), SequenceMatch::Pending);
+        assert_eq!(
+            press_in("G", KeybindingMode::Copy),
+            SequenceMatch::Matched("copy_mode_goto_top".to_string())
+        );
+
+        assert_eq!(
+            registry.combos_for_action_in(KeybindingMode::Copy, "copy_mode_move_left")[0]
+                .to_string(),
+            "H"
+        );
+        assert!(registry.combos_for_action("copy_mode_move_left").is_empty());
+    }
+
+    #[test]
+    fn test_unbind_is_scoped_to_its_mode() {
+        let mut registry = KeybindingRegistry::from_config(&[
+            KeyBinding {
+                key: "Ctrl+Shift+B".to_string(),
+                action: "toggle_background_shader".to_string(),
+                ..Default::default()
+            },
+            KeyBinding {
+                key: "Ctrl+Shift+B".to_string(),
+                action: "unbound".to_string(),
+                mode: KeybindingMode::Copy,
+            },
+        ]);
+        assert_eq!(
+            lookup_in(&mut registry, "Ctrl+Shift+B", KeybindingMode::Normal).as_deref(),
+            Some("toggle_background_shader")
+        );
+    }
+
     #[test]
     fn test_invalid_keybinding_skipped() {
         let bindings = vec![
             KeyBinding {
                 key: "InvalidKey".to_string(),
                 action: "some_action".to_string(),
+                ..Default::default()
             },
             KeyBinding {
                 key: "Ctrl+A".to_string(),
                 action: "valid_action".to_string(),
+                ..Default::default()
             },
         ];
 
@@ -294,6 +294,11 @@ impl KeybindingMatcher {
         }
     }
 
+    /// Whether the event's logical key is `named`, whatever the modifiers.
+    pub(crate) fn is_named(&self, named: NamedKey) -> bool {
+        matches!(self.key, Some(MatchKey::Named(key)) if key == named)
+    }
+
     /// Check if this event matches the given key combo.
     pub fn matches(&self, combo: &KeyCombo) -> bool {
         self.matches_with_physical_preference(combo, false)
@@ -367,6 +372,34 @@ impl KeybindingMatcher {
     }
 }
 
+#[cfg(test)]
+impl KeybindingMatcher {
+    /// Matcher for a logical key press written like a binding, e.g. "Ctrl+A".
+    pub(crate) fn for_combo(s: &str) -> Self {
+        let combo = crate::parser::parse_key_combo(s).expect("valid key combo");
+        let (ctrl, super_key) = platform::resolve_cmd_or_ctrl(
+            combo.modifiers.cmd_or_ctrl,
+            combo.modifiers.ctrl,
+            combo.modifiers.super_key,
+        );
+        let key = match combo.key {
+            ParsedKey::Character(c) => Some(MatchKey::Character(c)),
+            ParsedKey::Named(named) => Some(MatchKey::Named(named)),
+            ParsedKey::Physical(_) => None,
+        };
+        Self {
+            modifiers: Modifiers {
+                ctrl,
+                super_key,
+                cmd_or_ctrl: false,
+                ..combo.modifiers
+            },
+            key,
+            physical_key: None,
+        }
+    }
+}
+
 // Note: Integration tests for KeybindingMatcher require constructing winit KeyEvent
 // which has private fields. The matcher is tested indirectly through the registry tests
 // and the actual runtime behavior.
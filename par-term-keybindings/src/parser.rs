@@ -4,6 +4,7 @@
 //! Also supports physical key codes for language-agnostic bindings (e.g., "Ctrl+[KeyZ]").
 
 use crate::platform;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 use std::fmt;
 use winit::keyboard::{KeyCode, NamedKey};
 
@@ -43,6 +44,35 @@ pub struct KeyCombo {
     pub key: ParsedKey,
 }
 
+impl KeyCombo {
+    /// Whether pressing this combo would type text: a character or physical
+    /// key with no Ctrl, Alt or Super modifier (Shift is allowed).
+    pub fn is_plain(&self) -> bool {
+        let m = &self.modifiers;
+        !(m.ctrl || m.alt || m.super_key || m.cmd_or_ctrl)
+            && matches!(self.key, ParsedKey::Character(_) | ParsedKey::Physical(_))
+    }
+
+    /// This combo with `CmdOrCtrl` resolved for the current platform, so
+    /// combos that match the same key presses compare equal.
+    pub fn normalized(&self) -> KeyCombo {
+        let m = self.modifiers;
+        let (ctrl, super_key) = platform::resolve_cmd_or_ctrl(m.cmd_or_ctrl, m.ctrl, m.super_key);
+        KeyCombo {
+            modifiers: Modifiers {
+                ctrl,
+                super_key,
+                cmd_or_ctrl: false,
+                ..m
+            },
+            key: self.key.clone(),
+        }
+    }
+}
+
+/// Formats the canonical string form accepted by [`parse_key_combo`]:
+/// modifiers in a fixed order (`CmdOrCtrl`, `Ctrl`, `Alt`, `Shift`, `Super`)
+/// followed by the key, e.g. `Ctrl+Shift+B`, `CmdOrCtrl+[KeyZ]` or `Alt+F5`.
 impl fmt::Display for KeyCombo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut parts = Vec::new();
@@ -73,6 +103,23 @@ impl fmt::Display for KeyCombo {
     }
 }
 
+/// Serializes as the canonical string form (see the [`Display`](fmt::Display)
+/// impl), keeping stored combos human-readable.
+impl Serialize for KeyCombo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes from any string [`parse_key_combo`] accepts; invalid combos
+/// are a deserialization error.
+impl<'de> Deserialize<'de> for KeyCombo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        parse_key_combo(&s).map_err(de::Error::custom)
+    }
+}
+
 /// The actual key (either a character or a named key).
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ParsedKey {
@@ -278,29 +325,37 @@ pub fn key_combo_to_bytes(combo: &KeyCombo) -> Result<Vec<u8>, String> {
     }
 }
 
+/// Parse a whitespace-separated sequence of key combos.
+///
+/// Used for chord keybindings, where the keys are pressed one after another.
+///
+/// Example: "Ctrl+A Ctrl+S" → two combos
+/// Example: "Ctrl+B" → a single combo
+pub fn parse_key_combos(keys: &str) -> Result<Vec<KeyCombo>, ParseError> {
+    let parts: Vec<&str> = keys.split_whitespace().collect();
+    if parts.is_empty() {
+        return Err(ParseError("Empty key sequence".to_string()));
+    }
+
+    parts
+        .into_iter()
+        .map(|part| parse_key_combo(part).map_err(|e| ParseError(format!("'{}': {}", part, e))))
+        .collect()
+}
+
 /// Parse a key sequence string into a list of byte sequences.
 ///
 /// The input string contains whitespace-separated key combos.
-/// Each key combo is parsed with `parse_key_combo()` and converted to bytes.
+/// Each key combo is parsed with `parse_key_combos()` and converted to bytes.
 ///
 /// Example: "Up Up Down Down" → four arrow key escape sequences
 /// Example: "Ctrl+C" → single \x03 byte
 pub fn parse_key_sequence(keys: &str) -> Result<Vec<Vec<u8>>, String> {
-    let trimmed = keys.trim();
-    if trimmed.is_empty() {
-        return Err("Empty key sequence".to_string());
-    }
-
-    let parts: Vec<&str> = trimmed.split_whitespace().collect();
-    let mut result = Vec::with_capacity(parts.len());
-
-    for part in parts {
-        let combo = parse_key_combo(part).map_err(|e| format!("'{}': {}", part, e))?;
-        let bytes = key_combo_to_bytes(&combo)?;
-        result.push(bytes);
-    }
-
-    Ok(result)
+    parse_key_combos(keys)
+        .map_err(|e| e.to_string())?
+        .iter()
+        .map(key_combo_to_bytes)
+        .collect()
 }
 
 #[cfg(test)]
@@ -564,6 +619,68 @@ mod tests {
         assert!(parse_key_sequence("InvalidKey").is_err());
     }
 
+    #[test]
+    fn test_parse_key_combos_chord() {
+        let combos = parse_key_combos("Ctrl+A  Ctrl+S").unwrap();
+        assert_eq!(combos.len(), 2);
+        assert_eq!(combos[0], parse_key_combo("Ctrl+A").unwrap());
+        assert_eq!(combos[1], parse_key_combo("Ctrl+S").unwrap());
+
+        assert_eq!(parse_key_combos("F5").unwrap().len(), 1);
+        assert!(parse_key_combos(" ").is_err());
+        let err = parse_key_combos("Ctrl+A Bogus").unwrap_err();
+        assert!(err.to_string().contains("'Bogus'"));
+    }
+
+    #[test]
+    fn test_is_plain() {
+        assert!(parse_key_combo("C").unwrap().is_plain());
+        assert!(parse_key_combo("Shift+C").unwrap().is_plain());
+        assert!(parse_key_combo("[KeyC]").unwrap().is_plain());
+        assert!(!parse_key_combo("Ctrl+C").unwrap().is_plain());
+        assert!(!parse_key_combo("CmdOrCtrl+C").unwrap().is_plain());
+        assert!(!parse_key_combo("Alt+C").unwrap().is_plain());
+        assert!(!parse_key_combo("F5").unwrap().is_plain());
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        let cases = [
+            ("Ctrl+Shift+B", "Ctrl+Shift+B"),
+            ("shift+ctrl+b", "Ctrl+Shift+B"),
+            ("CmdOrCtrl+V", "CmdOrCtrl+V"),
+            ("CmdOrCtrl+Shift+[KeyZ]", "CmdOrCtrl+Shift+[KeyZ]"),
+            ("Ctrl+[digit1]", "Ctrl+[Digit1]"),
+            ("[KeyA]", "[KeyA]"),
+            ("F5", "F5"),
+            ("Alt+f12", "Alt+F12"),
+            ("Cmd+Option+PgUp", "Alt+Super+PageUp"),
+            ("Ctrl+Up", "Ctrl+ArrowUp"),
+            ("Esc", "Escape"),
+        ];
+        for (input, canonical) in cases {
+            let combo = parse_key_combo(input).unwrap();
+            assert_eq!(combo.to_string(), canonical, "{input}");
+            assert_eq!(parse_key_combo(canonical).unwrap(), combo, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let combo = parse_key_combo("cmdorctrl+shift+[keyz]").unwrap();
+        assert!(matches!(combo.key, ParsedKey::Physical(KeyCode::KeyZ)));
+        let json = serde_json::to_string(&combo).unwrap();
+        assert_eq!(json, "\"CmdOrCtrl+Shift+[KeyZ]\"");
+        assert_eq!(serde_json::from_str::<KeyCombo>(&json).unwrap(), combo);
+
+        // Any accepted spelling deserializes; invalid combos are errors
+        let combo: KeyCombo = serde_json::from_str("\"Cmd+Option+PgUp\"").unwrap();
+        assert_eq!(combo.to_string(), "Alt+Super+PageUp");
+        let err = serde_json::from_str::<KeyCombo>("\"Ctrl+Bogus\"").unwrap_err();
+        assert!(err.to_string().contains("Bogus"), "{err}");
+        assert!(serde_json::from_str::<KeyCombo>("42").is_err());
+    }
+
     #[test]
     fn test_key_combo_to_bytes_physical_key_error() {
         let combo = parse_key_combo("Ctrl+[KeyZ]").unwrap();
@@ -40,14 +40,17 @@ fn registry_counts_valid_bindings() {
         KeyBinding {
             key: "Ctrl+A".to_string(),
             action: "action_a".to_string(),
+            ..Default::default()
         },
         KeyBinding {
             key: "Ctrl+B".to_string(),
             action: "action_b".to_string(),
+            ..Default::default()
         },
         KeyBinding {
             key: "Ctrl+C".to_string(),
             action: "action_c".to_string(),
+            ..Default::default()
         },
     ];
 
@@ -62,18 +65,22 @@ fn registry_skips_invalid_bindings() {
         KeyBinding {
             key: "Ctrl+A".to_string(),
             action: "valid_action".to_string(),
+            ..Default::default()
         },
         KeyBinding {
             key: "NotAKey".to_string(),
             action: "invalid_action".to_string(),
+            ..Default::default()
         },
         KeyBinding {
             key: "Ctrl+Shift".to_string(), // no key — ends with modifier
             action: "also_invalid".to_string(),
+            ..Default::default()
         },
         KeyBinding {
             key: "F5".to_string(),
             action: "another_valid".to_string(),
+            ..Default::default()
         },
     ];
 
@@ -87,10 +94,12 @@ fn registry_skips_all_invalid() {
         KeyBinding {
             key: "BadKey1".to_string(),
             action: "action_a".to_string(),
+            ..Default::default()
         },
         KeyBinding {
             key: "BadKey2".to_string(),
             action: "action_b".to_string(),
+            ..Default::default()
         },
     ];
 
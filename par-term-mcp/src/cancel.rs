@@ -0,0 +1,111 @@
+//! Cancellation of in-flight `tools/call` requests.
+//!
+//! Tools that wait on the running app poll its response file for up to 15
+//! seconds.  [`run_mcp_server`](crate::run_mcp_server) reads stdin on its own
+//! thread so a cancel notification can arrive while a call is still polling:
+//! the reader registers each request's id as it queues it and drops the id
+//! from the [`CancelRegistry`] on cancel.  A request cancelled while still
+//! queued is never dispatched; one already running sees the cancel through
+//! its [`CancelToken`] and gives up early.
+//!
+//! Both the MCP `notifications/cancelled` (`requestId`) and the
+//! `notifications/cancelRequest` (`id`) spellings are accepted.
+
+use crate::jsonrpc::IncomingMessage;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+/// Notification methods that cancel an in-flight request.
+pub const CANCEL_METHODS: &[&str] = &["notifications/cancelled", "notifications/cancelRequest"];
+
+/// Registry key for a JSON-RPC request id.
+///
+/// Uses the id's JSON form, so the number `7` and the string `"7"` differ.
+fn request_key(id: &Value) -> String {
+    id.to_string()
+}
+
+/// Id of the request cancelled by `message`, if it is a cancel notification.
+pub fn cancelled_request_id(message: &IncomingMessage) -> Option<&Value> {
+    let method = message.method.as_deref()?;
+    if message.id.is_some() || !CANCEL_METHODS.contains(&method) {
+        return None;
+    }
+    let params = message.params.as_ref()?;
+    params
+        .get("requestId")
+        .or_else(|| params.get("id"))
+        .filter(|id| !id.is_null())
+}
+
+/// Ids of the requests queued or being handled, shared with the stdin reader.
+#[derive(Debug, Clone, Default)]
+pub struct CancelRegistry {
+    in_flight: Arc<Mutex<HashSet<String>>>,
+}
+
+impl CancelRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Track request `id` from the moment it is queued.
+    pub fn queue(&self, id: &Value) {
+        self.lock().insert(request_key(id));
+    }
+
+    /// Start handling queued request `id`; it stays tracked until the
+    /// returned token is dropped.  The token is already cancelled if a
+    /// cancel arrived while the request waited in the queue.
+    pub fn begin(&self, id: &Value) -> CancelToken {
+        CancelToken {
+            registry: Some(self.clone()),
+            key: request_key(id),
+        }
+    }
+
+    /// Cancel request `id`; returns `false` if it is not queued or in flight.
+    pub fn cancel(&self, id: &Value) -> bool {
+        self.lock().remove(&request_key(id))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashSet<String>> {
+        // A panicking handler cannot leave the set half-updated.
+        self.in_flight
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Cancellation handle for one in-flight request.
+#[derive(Debug)]
+pub struct CancelToken {
+    registry: Option<CancelRegistry>,
+    key: String,
+}
+
+impl CancelToken {
+    /// A token that is never cancelled, for calls made outside the server loop.
+    pub fn never() -> Self {
+        Self {
+            registry: None,
+            key: String::new(),
+        }
+    }
+
+    /// Whether the client has cancelled this request.
+    pub fn is_cancelled(&self) -> bool {
+        self.registry
+            .as_ref()
+            .is_some_and(|registry| !registry.lock().contains(&self.key))
+    }
+}
+
+impl Drop for CancelToken {
+    fn drop(&mut self) {
+        if let Some(registry) = &self.registry {
+            registry.lock().remove(&self.key);
+        }
+    }
+}
@@ -1,14 +1,19 @@
 //! IPC file path resolution and file helpers.
 //!
-//! Resolves platform-appropriate paths for config-update and screenshot IPC
-//! files, and provides atomic write / restricted-permission helpers used by
-//! both the MCP tool handlers and external consumers (e.g. the ACP harness).
+//! Resolves platform-appropriate paths for config-update, screenshot,
+//! diagnostics and terminal read/write IPC files, and provides atomic write /
+//! restricted-permission helpers used by both the MCP tool handlers and
+//! external consumers (e.g. the ACP harness).
 
 use crate::{
-    CONFIG_UPDATE_FILENAME, CONFIG_UPDATE_PATH_ENV, SCREENSHOT_REQUEST_FILENAME,
+    CONFIG_FILENAME, CONFIG_UPDATE_FILENAME, CONFIG_UPDATE_PATH_ENV, SCREENSHOT_REQUEST_FILENAME,
     SCREENSHOT_REQUEST_PATH_ENV, SCREENSHOT_RESPONSE_FILENAME, SCREENSHOT_RESPONSE_PATH_ENV,
     SHADER_DIAGNOSTICS_REQUEST_FILENAME, SHADER_DIAGNOSTICS_REQUEST_PATH_ENV,
     SHADER_DIAGNOSTICS_RESPONSE_FILENAME, SHADER_DIAGNOSTICS_RESPONSE_PATH_ENV,
+    TERMINAL_READ_REQUEST_FILENAME, TERMINAL_READ_REQUEST_PATH_ENV,
+    TERMINAL_READ_RESPONSE_FILENAME, TERMINAL_READ_RESPONSE_PATH_ENV,
+    TERMINAL_WRITE_REQUEST_FILENAME, TERMINAL_WRITE_REQUEST_PATH_ENV,
+    TERMINAL_WRITE_RESPONSE_FILENAME, TERMINAL_WRITE_RESPONSE_PATH_ENV,
 };
 use serde::Serialize;
 use std::io::Write;
@@ -97,6 +102,38 @@ pub fn shader_diagnostics_response_path() -> PathBuf {
     )
 }
 
+/// Resolve the path where terminal write requests should be written.
+pub fn terminal_write_request_path() -> PathBuf {
+    resolve_ipc_path(
+        TERMINAL_WRITE_REQUEST_PATH_ENV,
+        TERMINAL_WRITE_REQUEST_FILENAME,
+    )
+}
+
+/// Resolve the path where terminal write responses should be written.
+pub fn terminal_write_response_path() -> PathBuf {
+    resolve_ipc_path(
+        TERMINAL_WRITE_RESPONSE_PATH_ENV,
+        TERMINAL_WRITE_RESPONSE_FILENAME,
+    )
+}
+
+/// Resolve the path where terminal read requests should be written.
+pub fn terminal_read_request_path() -> PathBuf {
+    resolve_ipc_path(
+        TERMINAL_READ_REQUEST_PATH_ENV,
+        TERMINAL_READ_REQUEST_FILENAME,
+    )
+}
+
+/// Resolve the path where terminal read responses should be written.
+pub fn terminal_read_response_path() -> PathBuf {
+    resolve_ipc_path(
+        TERMINAL_READ_RESPONSE_PATH_ENV,
+        TERMINAL_READ_RESPONSE_FILENAME,
+    )
+}
+
 /// Resolve a path from env var or default filename under the par-term config dir.
 ///
 /// Uses the same directory logic as `Config::config_dir()` in par-term-config
@@ -108,7 +145,17 @@ pub fn resolve_ipc_path(env_var: &str, default_filename: &str) -> PathBuf {
         return PathBuf::from(path);
     }
 
-    // Mirror Config::config_dir() from par-term-config to ensure the same path.
+    par_term_config_dir().join(default_filename)
+}
+
+/// Path of the app's `config.yaml`, mirroring `Config::config_path()`.
+pub fn config_file_path() -> PathBuf {
+    par_term_config_dir().join(CONFIG_FILENAME)
+}
+
+/// The par-term config directory, mirroring `Config::config_dir()` from
+/// par-term-config.
+fn par_term_config_dir() -> PathBuf {
     #[cfg(target_os = "windows")]
     let config_dir = dirs::config_dir()
         .unwrap_or_else(|| PathBuf::from("."))
@@ -120,7 +167,7 @@ pub fn resolve_ipc_path(env_var: &str, default_filename: &str) -> PathBuf {
         .join(".config")
         .join("par-term");
 
-    config_dir.join(default_filename)
+    config_dir
 }
 
 // ---------------------------------------------------------------------------
@@ -181,6 +228,20 @@ pub fn try_read_shader_diagnostics_response(
     try_read_json_response(path)
 }
 
+/// Read and parse a terminal write response file, returning `None` for empty files.
+pub fn try_read_terminal_write_response(
+    path: &Path,
+) -> Result<Option<crate::TerminalWriteResponse>, String> {
+    try_read_json_response(path)
+}
+
+/// Read and parse a terminal read response file, returning `None` for empty files.
+pub fn try_read_terminal_read_response(
+    path: &Path,
+) -> Result<Option<crate::TerminalReadResponse>, String> {
+    try_read_json_response(path)
+}
+
 fn try_read_json_response<T>(path: &Path) -> Result<Option<T>, String>
 where
     T: serde::de::DeserializeOwned,
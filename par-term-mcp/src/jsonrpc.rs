@@ -1,12 +1,21 @@
 //! JSON-RPC 2.0 wire types and response helpers.
 //!
 //! This module contains the minimal set of types needed to implement a
-//! JSON-RPC 2.0 server over stdio: incoming message deserialization,
-//! outgoing response serialization, and the standard error constructors.
+//! JSON-RPC 2.0 server over stdio: message framing, incoming message
+//! deserialization, outgoing response serialization, and the standard error
+//! constructors.
+//!
+//! Messages are normally one JSON document per line.  Some clients instead
+//! send LSP-style `Content-Length:` header blocks followed by a body with no
+//! trailing newline; [`read_framed_message`] detects this per message and
+//! replies are framed the same way.
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::io::Write;
+use std::io::{BufRead, Read, Write};
+
+/// Largest `Content-Length` body accepted; larger bodies are discarded.
+pub const MAX_MESSAGE_BYTES: usize = 16 * 1024 * 1024;
 
 // ---------------------------------------------------------------------------
 // Wire types
@@ -88,14 +97,127 @@ pub fn parse_error() -> Response {
 }
 
 // ---------------------------------------------------------------------------
-// I/O helper
+// I/O helpers
 // ---------------------------------------------------------------------------
 
+/// How a message on the stdio channel is delimited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Framing {
+    /// One JSON document per line.
+    Newline,
+    /// `Content-Length:` header block, blank line, then the body.
+    ContentLength,
+}
+
+/// Read the next message body, auto-detecting its framing.
+///
+/// Returns `None` once the stream is closed or a read fails.
+pub fn read_message(reader: &mut impl BufRead) -> Option<String> {
+    read_framed_message(reader).map(|(message, _)| message)
+}
+
+/// Read the next message body and the framing it arrived in.
+///
+/// Blank lines between messages are skipped.  A line that starts like a
+/// JSON document is a newline-framed message; a `Name: value` line starts a
+/// header block whose `Content-Length` gives the body size.  Anything else is
+/// returned as-is so the caller reports a parse error.
+pub fn read_framed_message(reader: &mut impl BufRead) -> Option<(String, Framing)> {
+    loop {
+        let line = read_line(reader)?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if trimmed.starts_with(['{', '[']) || !is_header_line(trimmed) {
+            return Some((trimmed.to_string(), Framing::Newline));
+        }
+
+        let mut content_length = header_content_length(trimmed);
+        loop {
+            let header = read_line(reader)?;
+            let header = header.trim();
+            if header.is_empty() {
+                break;
+            }
+            content_length = content_length.or_else(|| header_content_length(header));
+        }
+
+        let Some(length) = content_length else {
+            eprintln!("[mcp-server] Header block without Content-Length; skipping");
+            continue;
+        };
+        if length > MAX_MESSAGE_BYTES {
+            eprintln!(
+                "[mcp-server] Message of {length} bytes exceeds {MAX_MESSAGE_BYTES}; discarding"
+            );
+            if let Err(e) = std::io::copy(&mut reader.take(length as u64), &mut std::io::sink()) {
+                eprintln!("[mcp-server] Error reading stdin: {e}");
+                return None;
+            }
+            continue;
+        }
+
+        let mut body = vec![0; length];
+        if let Err(e) = reader.read_exact(&mut body) {
+            eprintln!("[mcp-server] Error reading stdin: {e}");
+            return None;
+        }
+        return Some((
+            String::from_utf8_lossy(&body).into_owned(),
+            Framing::ContentLength,
+        ));
+    }
+}
+
+/// Read one line, returning `None` at end of stream or on error.
+fn read_line(reader: &mut impl BufRead) -> Option<String> {
+    let mut line = Vec::new();
+    match reader.read_until(b'\n', &mut line) {
+        Ok(0) => None,
+        Ok(_) => Some(String::from_utf8_lossy(&line).into_owned()),
+        Err(e) => {
+            eprintln!("[mcp-server] Error reading stdin: {e}");
+            None
+        }
+    }
+}
+
+/// Whether `line` looks like an HTTP-style `Name: value` header.
+fn is_header_line(line: &str) -> bool {
+    line.split_once(':').is_some_and(|(name, _)| {
+        !name.is_empty()
+            && name
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+    })
+}
+
+/// The value of a `Content-Length` header line (case-insensitive name).
+fn header_content_length(line: &str) -> Option<usize> {
+    let (name, value) = line.split_once(':')?;
+    if !name.trim().eq_ignore_ascii_case("content-length") {
+        return None;
+    }
+    value.trim().parse().ok()
+}
+
 /// Send a JSON-RPC response to a writer as a single newline-terminated line.
 pub fn send_response(stdout: &mut impl Write, response: &Response) {
+    send_framed_response(stdout, response, Framing::Newline);
+}
+
+/// Send a JSON-RPC response to a writer using `framing`.
+pub fn send_framed_response(stdout: &mut impl Write, response: &Response, framing: Framing) {
     match serde_json::to_string(response) {
         Ok(json) => {
-            if let Err(e) = writeln!(stdout, "{json}") {
+            let written = match framing {
+                Framing::Newline => writeln!(stdout, "{json}"),
+                Framing::ContentLength => {
+                    write!(stdout, "Content-Length: {}\r\n\r\n{json}", json.len())
+                }
+            };
+            if let Err(e) = written {
                 eprintln!("[mcp-server] Failed to write response: {e}");
             }
             if let Err(e) = stdout.flush() {
@@ -1,6 +1,7 @@
 //! Minimal MCP (Model Context Protocol) server over stdio.
 //!
-//! Reads line-delimited JSON-RPC 2.0 from stdin and writes responses to stdout.
+//! Reads JSON-RPC 2.0 from stdin, either line-delimited or with LSP-style
+//! `Content-Length` headers, and writes responses to stdout in the same framing.
 //! Exposes tools for par-term ACP integrations:
 //! - `config_update`: writes configuration changes to a file for the main app
 //!   to pick up
@@ -9,15 +10,26 @@
 //!   non-GUI test harnesses)
 //! - `shader_diagnostics`: requests live shader state and last compile/reload
 //!   errors from the running app via file-based IPC
+//! - `terminal_write`: writes text (optionally followed by Enter) to the
+//!   focused terminal's PTY via file-based IPC
+//! - `terminal_read`: returns the focused terminal's visible screen (and
+//!   optionally some scrollback) as plain text via file-based IPC
+//!
+//! A `notifications/cancelled` (or `notifications/cancelRequest`) naming a
+//! queued request drops it; one naming an in-flight `tools/call` stops that
+//! call's wait for the app early, unless the app already took the request.
 //!
 //! # Module layout
 //!
 //! - [`jsonrpc`] — JSON-RPC 2.0 wire types, response helpers, and stdout framing
 //! - [`ipc`] — IPC path resolution, atomic writes, and restricted-permission helpers
+//! - [`cancel`] — cancellation registry for in-flight tool calls
 //! - [`tools`] — tool registration, descriptors, and dispatch
 //! - [`tools::config_update`] — `config_update` tool handler
 //! - [`tools::screenshot`] — `terminal_screenshot` tool handler
 //! - [`tools::diagnostics`] — `shader_diagnostics` tool handler
+//! - [`tools::terminal_write`] — `terminal_write` tool handler
+//! - [`tools::terminal_read`] — `terminal_read` tool handler
 //!
 //! # SEC-006 / SEC-008: Trust Boundary — stdin/stdout IPC Channel
 //!
@@ -45,20 +57,21 @@
 //! TOML files (which define which agents are launched) are themselves a trust
 //! boundary — only install agents from sources you trust.
 //!
-//! The file-based IPC paths used for screenshot and diagnostics requests use
-//! restrictive permissions (0o600) to prevent unauthorized reads or writes.
+//! The file-based IPC paths used for screenshot, diagnostics and terminal
+//! read/write requests use restrictive permissions (0o600) to prevent unauthorized reads or writes.
 
+pub mod cancel;
 pub mod ipc;
 pub mod jsonrpc;
 pub mod tools;
 
 use serde::{Deserialize, Serialize};
-use std::io::BufRead;
 use std::sync::OnceLock;
 
+use cancel::{CancelRegistry, CancelToken, cancelled_request_id};
 use jsonrpc::{
-    IncomingMessage, Response, RpcError, method_not_found, parse_error, send_response,
-    success_response,
+    IncomingMessage, Response, RpcError, method_not_found, parse_error, read_framed_message,
+    send_framed_response, success_response,
 };
 use tools::{handle_tools_call, handle_tools_list};
 
@@ -114,6 +127,14 @@ pub const SCREENSHOT_RESPONSE_PATH_ENV: &str = "PAR_TERM_SCREENSHOT_RESPONSE_PAT
 pub const SHADER_DIAGNOSTICS_REQUEST_PATH_ENV: &str = "PAR_TERM_SHADER_DIAGNOSTICS_REQUEST_PATH";
 /// Environment variable for shader diagnostics response IPC file path.
 pub const SHADER_DIAGNOSTICS_RESPONSE_PATH_ENV: &str = "PAR_TERM_SHADER_DIAGNOSTICS_RESPONSE_PATH";
+/// Environment variable for terminal write request IPC file path.
+pub const TERMINAL_WRITE_REQUEST_PATH_ENV: &str = "PAR_TERM_WRITE_REQUEST_PATH";
+/// Environment variable for terminal write response IPC file path.
+pub const TERMINAL_WRITE_RESPONSE_PATH_ENV: &str = "PAR_TERM_WRITE_RESPONSE_PATH";
+/// Environment variable for terminal read request IPC file path.
+pub const TERMINAL_READ_REQUEST_PATH_ENV: &str = "PAR_TERM_READ_REQUEST_PATH";
+/// Environment variable for terminal read response IPC file path.
+pub const TERMINAL_READ_RESPONSE_PATH_ENV: &str = "PAR_TERM_READ_RESPONSE_PATH";
 /// Optional environment variable for a static fallback screenshot file path.
 /// Used by the ACP harness to test the screenshot tool flow without a GUI.
 pub const SCREENSHOT_FALLBACK_PATH_ENV: &str = "PAR_TERM_SCREENSHOT_FALLBACK_PATH";
@@ -195,6 +216,7 @@ fn dispatch(
     params: Option<serde_json::Value>,
     expected_token: Option<&str>,
     authenticated: &mut bool,
+    cancel: &CancelToken,
 ) -> Response {
     match method {
         "initialize" => {
@@ -230,7 +252,7 @@ fn dispatch(
             )
         }
         "tools/list" => success_response(id, handle_tools_list()),
-        "tools/call" => success_response(id, handle_tools_call(params)),
+        "tools/call" => success_response(id, handle_tools_call(params, cancel)),
         _ => method_not_found(id, method),
     }
 }
@@ -247,6 +269,8 @@ fn tool_call_allowed(expected_token: Option<&str>, authenticated: bool) -> bool
 
 /// Default config update filename (relative to config dir).
 pub const CONFIG_UPDATE_FILENAME: &str = ".config-update.json";
+/// The app's config filename (relative to config dir).
+pub const CONFIG_FILENAME: &str = "config.yaml";
 /// Default screenshot request filename (relative to config dir).
 pub const SCREENSHOT_REQUEST_FILENAME: &str = ".screenshot-request.json";
 /// Default screenshot response filename (relative to config dir).
@@ -255,6 +279,17 @@ pub const SCREENSHOT_RESPONSE_FILENAME: &str = ".screenshot-response.json";
 pub const SHADER_DIAGNOSTICS_REQUEST_FILENAME: &str = ".shader-diagnostics-request.json";
 /// Default shader diagnostics response filename (relative to config dir).
 pub const SHADER_DIAGNOSTICS_RESPONSE_FILENAME: &str = ".shader-diagnostics-response.json";
+/// Default terminal write request filename (relative to config dir).
+pub const TERMINAL_WRITE_REQUEST_FILENAME: &str = ".terminal-write-request.json";
+/// Default terminal write response filename (relative to config dir).
+pub const TERMINAL_WRITE_RESPONSE_FILENAME: &str = ".terminal-write-response.json";
+/// Default terminal read request filename (relative to config dir).
+pub const TERMINAL_READ_REQUEST_FILENAME: &str = ".terminal-read-request.json";
+/// Default terminal read response filename (relative to config dir).
+pub const TERMINAL_READ_RESPONSE_FILENAME: &str = ".terminal-read-response.json";
+
+/// Largest `text` accepted by the `terminal_write` tool, in bytes.
+pub const TERMINAL_WRITE_MAX_BYTES: usize = 64 * 1024;
 
 /// Screenshot request written by the MCP server for the GUI app to fulfill.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -314,10 +349,55 @@ pub struct ShaderDiagnosticsResponse {
     pub diagnostics: Option<ShaderDiagnostics>,
 }
 
+/// Terminal write request written by the MCP server for the GUI app to fulfill.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerminalWriteRequest {
+    pub request_id: String,
+    /// Text to write to the focused terminal's PTY
+    pub text: String,
+    /// Press Enter (send a carriage return) after the text
+    #[serde(default)]
+    pub submit: bool,
+}
+
+/// Terminal write response written by the GUI app for the MCP server to read.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerminalWriteResponse {
+    pub request_id: String,
+    pub ok: bool,
+    #[serde(default)]
+    pub error: Option<String>,
+    /// Bytes written to the PTY, including the Enter when `submit` was set
+    #[serde(default)]
+    pub bytes_written: Option<usize>,
+}
+
+/// Terminal read request written by the MCP server for the GUI app to fulfill.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerminalReadRequest {
+    pub request_id: String,
+    /// Scrollback lines to include above the visible screen
+    #[serde(default)]
+    pub include_scrollback_lines: u32,
+}
+
+/// Terminal read response written by the GUI app for the MCP server to read.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerminalReadResponse {
+    pub request_id: String,
+    pub ok: bool,
+    #[serde(default)]
+    pub error: Option<String>,
+    /// Plain text of the requested lines
+    #[serde(default)]
+    pub text: Option<String>,
+}
+
 // Re-export IPC path helpers so callers don't need to name the submodule.
 pub use ipc::{
     screenshot_request_path, screenshot_response_path, shader_diagnostics_request_path,
-    shader_diagnostics_response_path,
+    shader_diagnostics_response_path, terminal_read_request_path, terminal_read_response_path,
+    terminal_write_request_path, terminal_write_response_path,
 };
 
 /// Run the MCP server loop. Reads JSON-RPC messages from stdin until the
@@ -338,32 +418,42 @@ pub fn run_mcp_server() {
         ),
     }
 
-    let stdin = std::io::stdin();
     let mut stdout = std::io::stdout();
-    let reader = stdin.lock();
     let mut authenticated = false;
 
-    for line in reader.lines() {
-        let line = match line {
-            Ok(l) => l,
-            Err(e) => {
-                eprintln!("[mcp-server] Error reading stdin: {e}");
+    // Read stdin on its own thread so cancel notifications are seen while a
+    // tool call is still waiting on the app; everything else is queued, with
+    // request ids registered up front so a cancel can reach a queued request.
+    let registry = CancelRegistry::new();
+    let (sender, messages) = std::sync::mpsc::channel();
+    let reader_registry = registry.clone();
+    std::thread::spawn(move || {
+        let mut reader = std::io::stdin().lock();
+        while let Some((message, framing)) = read_framed_message(&mut reader) {
+            if let Ok(msg) = serde_json::from_str::<IncomingMessage>(&message) {
+                if let Some(id) = cancelled_request_id(&msg) {
+                    let cancelled = reader_registry.cancel(id);
+                    eprintln!("[mcp-server] <- {message} (in flight: {cancelled})");
+                    continue;
+                }
+                if let (Some(_), Some(id)) = (&msg.method, &msg.id) {
+                    reader_registry.queue(id);
+                }
+            }
+            if sender.send((message, framing)).is_err() {
                 break;
             }
-        };
-
-        let trimmed = line.trim();
-        if trimmed.is_empty() {
-            continue;
         }
+    });
 
-        eprintln!("[mcp-server] <- {trimmed}");
+    for (message, framing) in messages {
+        eprintln!("[mcp-server] <- {message}");
 
-        let msg: IncomingMessage = match serde_json::from_str(trimmed) {
+        let msg: IncomingMessage = match serde_json::from_str(&message) {
             Ok(m) => m,
             Err(e) => {
                 eprintln!("[mcp-server] Parse error: {e}");
-                send_response(&mut stdout, &parse_error());
+                send_framed_response(&mut stdout, &parse_error(), framing);
                 continue;
             }
         };
@@ -387,6 +477,13 @@ pub fn run_mcp_server() {
             }
         };
 
+        // A request cancelled while queued is dropped without a response.
+        let cancel = registry.begin(&id);
+        if cancel.is_cancelled() {
+            eprintln!("[mcp-server] Skipping request {id}: cancelled before dispatch");
+            continue;
+        }
+
         // Dispatch with the SEC-006 authentication gate applied.
         let response = dispatch(
             method,
@@ -394,6 +491,7 @@ pub fn run_mcp_server() {
             msg.params,
             expected_token.as_deref(),
             &mut authenticated,
+            &cancel,
         );
 
         eprintln!(
@@ -401,7 +499,7 @@ pub fn run_mcp_server() {
             serde_json::to_string(&response).unwrap_or_else(|_| "<serialization error>".into())
         );
 
-        send_response(&mut stdout, &response);
+        send_framed_response(&mut stdout, &response, framing);
     }
 
     eprintln!("[mcp-server] stdin closed, exiting");
@@ -415,11 +513,16 @@ pub fn run_mcp_server() {
 mod tests {
     use super::*;
     use ipc::{config_update_path, set_ipc_file_permissions, write_json_atomic};
-    use jsonrpc::{IncomingMessage, method_not_found, parse_error, success_response};
+    use jsonrpc::{
+        Framing, IncomingMessage, method_not_found, parse_error, read_framed_message, read_message,
+        send_framed_response, success_response,
+    };
     use std::path::PathBuf;
-    use tools::config_update::write_config_updates;
+    use tools::config_update::{write_config_updates, write_config_updates_reporting_previous};
     use tools::diagnostics::diagnostics_tool_result;
     use tools::screenshot::image_tool_result_from_file;
+    use tools::terminal_read::parse_terminal_read_arguments;
+    use tools::terminal_write::parse_terminal_write_arguments;
 
     #[test]
     fn test_handle_initialize() {
@@ -449,6 +552,7 @@ mod tests {
             Some(serde_json::json!({})),
             None,
             &mut authed,
+            &CancelToken::never(),
         );
         assert!(
             resp.error.is_none(),
@@ -462,6 +566,7 @@ mod tests {
             Some(serde_json::json!({})),
             None,
             &mut authed,
+            &CancelToken::never(),
         );
         assert!(
             resp.error.is_none(),
@@ -480,6 +585,7 @@ mod tests {
             Some(params),
             Some("secret-token"),
             &mut authed,
+            &CancelToken::never(),
         );
         assert!(resp.result.is_none());
         assert_eq!(resp.error.unwrap().code, -32001);
@@ -496,6 +602,7 @@ mod tests {
             Some(params),
             Some("secret-token"),
             &mut authed,
+            &CancelToken::never(),
         );
         assert_eq!(resp.error.unwrap().code, -32001);
         assert!(!authed);
@@ -511,6 +618,7 @@ mod tests {
             Some(params),
             Some("secret-token"),
             &mut authed,
+            &CancelToken::never(),
         );
         assert!(resp.error.is_none());
         assert_eq!(resp.result.unwrap()["serverInfo"]["name"], SERVER_NAME);
@@ -527,6 +635,7 @@ mod tests {
             Some(params),
             Some("secret-token"),
             &mut authed,
+            &CancelToken::never(),
         );
         assert_eq!(resp.error.unwrap().code, -32001);
         assert!(!authed);
@@ -542,6 +651,7 @@ mod tests {
             Some(params),
             Some("secret-token"),
             &mut authed,
+            &CancelToken::never(),
         );
         assert!(resp.error.is_none());
         assert!(resp.result.unwrap()["tools"].is_array());
@@ -551,11 +661,13 @@ mod tests {
     fn test_handle_tools_list() {
         let result = handle_tools_list();
         let tools = result["tools"].as_array().unwrap();
-        assert_eq!(tools.len(), 3);
+        assert_eq!(tools.len(), 5);
         let names: Vec<_> = tools.iter().filter_map(|t| t["name"].as_str()).collect();
         assert!(names.contains(&"config_update"));
         assert!(names.contains(&"terminal_screenshot"));
         assert!(names.contains(&"shader_diagnostics"));
+        assert!(names.contains(&"terminal_write"));
+        assert!(names.contains(&"terminal_read"));
         for tool in tools {
             assert!(tool["inputSchema"].is_object());
         }
@@ -567,7 +679,7 @@ mod tests {
             "name": "nonexistent_tool",
             "arguments": {}
         });
-        let result = handle_tools_call(Some(params));
+        let result = handle_tools_call(Some(params), &CancelToken::never());
         assert_eq!(result["isError"], true);
         assert!(
             result["content"][0]["text"]
@@ -579,7 +691,7 @@ mod tests {
 
     #[test]
     fn test_handle_tools_call_missing_params() {
-        let result = handle_tools_call(None);
+        let result = handle_tools_call(None, &CancelToken::never());
         assert_eq!(result["isError"], true);
     }
 
@@ -589,7 +701,7 @@ mod tests {
             "name": "config_update",
             "arguments": {}
         });
-        let result = handle_tools_call(Some(params));
+        let result = handle_tools_call(Some(params), &CancelToken::never());
         assert_eq!(result["isError"], true);
         assert!(
             result["content"][0]["text"]
@@ -607,7 +719,7 @@ mod tests {
                 "updates": "not an object"
             }
         });
-        let result = handle_tools_call(Some(params));
+        let result = handle_tools_call(Some(params), &CancelToken::never());
         assert_eq!(result["isError"], true);
         assert!(
             result["content"][0]["text"]
@@ -645,6 +757,56 @@ mod tests {
         assert_eq!(written["custom_shader_enabled"], true);
     }
 
+    #[test]
+    fn test_config_update_reports_previous_values() {
+        let dir = tempfile::tempdir().unwrap();
+        let update_path = dir.path().join("test-update.json");
+        let config_path = dir.path().join("config.yaml");
+        std::fs::write(&config_path, "font_size: 12.0\nwindow_opacity: 0.9\n").unwrap();
+
+        let previous = |updates: serde_json::Value| {
+            let result =
+                write_config_updates_reporting_previous(&updates, &update_path, &config_path);
+            assert!(result.get("isError").is_none(), "{result}");
+            let text = result["content"][0]["text"].as_str().unwrap().to_string();
+            let last_line = text.lines().last().unwrap();
+            let (label, json) = last_line.split_once("): ").unwrap();
+            assert!(label.starts_with("Previous values"), "{text}");
+            serde_json::from_str::<serde_json::Value>(json).unwrap()
+        };
+
+        // First update: the value comes from config.yaml
+        assert_eq!(
+            previous(serde_json::json!({"font_size": 14.0})),
+            serde_json::json!({"font_size": 12.0})
+        );
+        // Second update before the app applied the first: the pending value
+        assert_eq!(
+            previous(serde_json::json!({"font_size": 16.0, "custom_shader": "crt.glsl"})),
+            serde_json::json!({"font_size": 14.0, "custom_shader": null})
+        );
+
+        // Once the app has consumed the pending file, config.yaml is used again
+        std::fs::write(&update_path, "").unwrap();
+        std::fs::write(&config_path, "font_size: 16.0\n").unwrap();
+        assert_eq!(
+            previous(serde_json::json!({"font_size": 18.0})),
+            serde_json::json!({"font_size": 16.0})
+        );
+    }
+
+    #[test]
+    fn test_config_update_include_previous_must_be_boolean() {
+        let result = handle_tools_call(
+            Some(serde_json::json!({
+                "name": "config_update",
+                "arguments": {"updates": {"font_size": 14.0}, "include_previous": "yes"},
+            })),
+            &CancelToken::never(),
+        );
+        assert_eq!(result["isError"], true);
+    }
+
     #[test]
     fn test_success_response_format() {
         let resp = success_response(
@@ -834,6 +996,148 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_terminal_write_paths_env_override() {
+        // SAFETY: same reasoning as `test_screenshot_paths_env_override_and_default`;
+        // these keys are only used by this test and are removed again below.
+        unsafe {
+            std::env::set_var(
+                TERMINAL_WRITE_REQUEST_PATH_ENV,
+                "/tmp/test-par-term-write-req.json",
+            );
+            std::env::set_var(
+                TERMINAL_WRITE_RESPONSE_PATH_ENV,
+                "/tmp/test-par-term-write-resp.json",
+            );
+        }
+        assert_eq!(
+            terminal_write_request_path(),
+            PathBuf::from("/tmp/test-par-term-write-req.json")
+        );
+        assert_eq!(
+            terminal_write_response_path(),
+            PathBuf::from("/tmp/test-par-term-write-resp.json")
+        );
+
+        // SAFETY: see set_var comment above.
+        unsafe {
+            std::env::remove_var(TERMINAL_WRITE_REQUEST_PATH_ENV);
+            std::env::remove_var(TERMINAL_WRITE_RESPONSE_PATH_ENV);
+        }
+        assert!(
+            terminal_write_request_path()
+                .to_string_lossy()
+                .ends_with(TERMINAL_WRITE_REQUEST_FILENAME)
+        );
+    }
+
+    #[test]
+    fn test_terminal_write_arguments() {
+        let args = |arguments: serde_json::Value| {
+            parse_terminal_write_arguments(&serde_json::json!({
+                "name": "terminal_write",
+                "arguments": arguments,
+            }))
+        };
+
+        assert_eq!(
+            args(serde_json::json!({"text": "ls -la"})),
+            Ok(("ls -la".to_string(), false))
+        );
+        assert_eq!(
+            args(serde_json::json!({"text": "make", "submit": true})),
+            Ok(("make".to_string(), true))
+        );
+
+        let err = args(serde_json::json!({"text": ""})).unwrap_err();
+        assert!(err.contains("must not be empty"), "{err}");
+        let err = args(serde_json::json!({"submit": true})).unwrap_err();
+        assert!(err.contains("'text'"), "{err}");
+        let err = args(serde_json::json!({"text": 42})).unwrap_err();
+        assert!(err.contains("must be a string"), "{err}");
+        let err = args(serde_json::json!({"text": "x", "submit": "yes"})).unwrap_err();
+        assert!(err.contains("must be a boolean"), "{err}");
+
+        let max = "a".repeat(TERMINAL_WRITE_MAX_BYTES);
+        assert!(args(serde_json::json!({ "text": max })).is_ok());
+        let too_long = "a".repeat(TERMINAL_WRITE_MAX_BYTES + 1);
+        let err = args(serde_json::json!({ "text": too_long })).unwrap_err();
+        assert!(err.contains("limit"), "{err}");
+    }
+
+    #[test]
+    fn test_terminal_write_rejects_empty_text_without_ipc() {
+        let result = handle_tools_call(
+            Some(serde_json::json!({
+                "name": "terminal_write",
+                "arguments": {"text": ""},
+            })),
+            &CancelToken::never(),
+        );
+        assert_eq!(result["isError"], true);
+    }
+
+    #[test]
+    fn test_terminal_read_paths_env_override() {
+        // SAFETY: same reasoning as `test_screenshot_paths_env_override_and_default`;
+        // these keys are only used by this test and are removed again below.
+        unsafe {
+            std::env::set_var(
+                TERMINAL_READ_REQUEST_PATH_ENV,
+                "/tmp/test-par-term-read-req.json",
+            );
+            std::env::set_var(
+                TERMINAL_READ_RESPONSE_PATH_ENV,
+                "/tmp/test-par-term-read-resp.json",
+            );
+        }
+        assert_eq!(
+            terminal_read_request_path(),
+            PathBuf::from("/tmp/test-par-term-read-req.json")
+        );
+        assert_eq!(
+            terminal_read_response_path(),
+            PathBuf::from("/tmp/test-par-term-read-resp.json")
+        );
+
+        // SAFETY: see set_var comment above.
+        unsafe {
+            std::env::remove_var(TERMINAL_READ_REQUEST_PATH_ENV);
+            std::env::remove_var(TERMINAL_READ_RESPONSE_PATH_ENV);
+        }
+        assert!(
+            terminal_read_response_path()
+                .to_string_lossy()
+                .ends_with(TERMINAL_READ_RESPONSE_FILENAME)
+        );
+    }
+
+    #[test]
+    fn test_terminal_read_arguments() {
+        let args = |arguments: serde_json::Value| {
+            parse_terminal_read_arguments(&serde_json::json!({
+                "name": "terminal_read",
+                "arguments": arguments,
+            }))
+        };
+
+        assert_eq!(
+            parse_terminal_read_arguments(&serde_json::json!({"name": "terminal_read"})),
+            Ok(0)
+        );
+        assert_eq!(args(serde_json::json!({})), Ok(0));
+        assert_eq!(
+            args(serde_json::json!({"include_scrollback_lines": 200})),
+            Ok(200)
+        );
+
+        let err = args(serde_json::json!({"include_scrollback_lines": -1})).unwrap_err();
+        assert!(err.contains("non-negative integer"), "{err}");
+        assert!(args(serde_json::json!({"include_scrollback_lines": "10"})).is_err());
+        assert!(args(serde_json::json!({"include_scrollback_lines": 1.5})).is_err());
+        assert!(args(serde_json::json!({"include_scrollback_lines": 1u64 << 40})).is_err());
+    }
+
     #[test]
     fn test_image_tool_result_from_file_missing() {
         let result = image_tool_result_from_file(std::path::Path::new(
@@ -860,6 +1164,80 @@ mod tests {
         assert_eq!(msg.method.as_deref(), Some("initialize"));
     }
 
+    /// Read one message from `input` and dispatch it as the server loop does.
+    fn respond_to(input: &[u8]) -> String {
+        let mut reader = std::io::Cursor::new(input);
+        let message = read_message(&mut reader).expect("a message");
+        let msg: IncomingMessage = serde_json::from_str(&message).unwrap();
+        let mut authed = false;
+        let response = dispatch(
+            msg.method.as_deref().unwrap(),
+            msg.id.unwrap(),
+            msg.params,
+            None,
+            &mut authed,
+            &CancelToken::never(),
+        );
+        serde_json::to_string(&response).unwrap()
+    }
+
+    #[test]
+    fn test_content_length_framing_matches_newline_framing() {
+        let body = r#"{"jsonrpc":"2.0","id":7,"method":"initialize","params":{}}"#;
+        let newline = respond_to(format!("{body}\n").as_bytes());
+
+        // No trailing newline after the body
+        let framed = format!("Content-Length: {}\r\n\r\n{body}", body.len());
+        assert_eq!(respond_to(framed.as_bytes()), newline);
+
+        // Header names are case-insensitive and other headers are ignored
+        let framed = format!(
+            "content-length: {}\r\nContent-Type: application/vscode-jsonrpc; charset=utf-8\r\n\r\n{body}",
+            body.len()
+        );
+        assert_eq!(respond_to(framed.as_bytes()), newline);
+    }
+
+    #[test]
+    fn test_read_framed_message_sequence() {
+        let first = r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#;
+        let second = r#"{"jsonrpc":"2.0","id":2,"method":"ping"}"#;
+        let input = format!(
+            "\n{first}\nContent-Length: {}\r\n\r\n{second}Content-Length: 2\r\n\r\n{{}}",
+            second.len()
+        );
+        let mut reader = std::io::Cursor::new(input.into_bytes());
+        assert_eq!(
+            read_framed_message(&mut reader),
+            Some((first.to_string(), Framing::Newline))
+        );
+        assert_eq!(
+            read_framed_message(&mut reader),
+            Some((second.to_string(), Framing::ContentLength))
+        );
+        assert_eq!(
+            read_framed_message(&mut reader),
+            Some(("{}".to_string(), Framing::ContentLength))
+        );
+        assert_eq!(read_framed_message(&mut reader), None);
+
+        // A truncated body ends the stream
+        let mut reader = std::io::Cursor::new(b"Content-Length: 50\r\n\r\n{}".to_vec());
+        assert_eq!(read_message(&mut reader), None);
+    }
+
+    #[test]
+    fn test_content_length_response_framing() {
+        let response = success_response(serde_json::json!(1), serde_json::json!({}));
+        let json = serde_json::to_string(&response).unwrap();
+        let mut out = Vec::new();
+        send_framed_response(&mut out, &response, Framing::ContentLength);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("Content-Length: {}\r\n\r\n{json}", json.len())
+        );
+    }
+
     #[cfg(unix)]
     #[test]
     fn test_set_ipc_file_permissions() {
@@ -917,4 +1295,126 @@ mod tests {
             "Atomically written IPC file should have mode 0o600, got {mode:#o}"
         );
     }
+
+    #[test]
+    fn test_cancel_notification_request_id() {
+        let id_of = |json: &str| {
+            let msg: IncomingMessage = serde_json::from_str(json).unwrap();
+            cancelled_request_id(&msg).cloned()
+        };
+
+        assert_eq!(
+            id_of(
+                r#"{"jsonrpc":"2.0","method":"notifications/cancelled","params":{"requestId":3,"reason":"user"}}"#
+            ),
+            Some(serde_json::json!(3))
+        );
+        assert_eq!(
+            id_of(
+                r#"{"jsonrpc":"2.0","method":"notifications/cancelRequest","params":{"id":"abc"}}"#
+            ),
+            Some(serde_json::json!("abc"))
+        );
+        // Requests (with an id) and other notifications are not cancels
+        assert_eq!(
+            id_of(
+                r#"{"jsonrpc":"2.0","id":1,"method":"notifications/cancelled","params":{"requestId":3}}"#
+            ),
+            None
+        );
+        assert_eq!(
+            id_of(r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cancel_registry_tracks_in_flight_requests() {
+        let registry = CancelRegistry::new();
+        assert!(!registry.cancel(&serde_json::json!(1)), "not in flight");
+
+        registry.queue(&serde_json::json!(1));
+        let token = registry.begin(&serde_json::json!(1));
+        assert!(!token.is_cancelled());
+        // The string "1" is a different request id
+        assert!(!registry.cancel(&serde_json::json!("1")));
+        assert!(registry.cancel(&serde_json::json!(1)));
+        assert!(token.is_cancelled());
+
+        // A cancel for a request still waiting in the queue is kept
+        registry.queue(&serde_json::json!(3));
+        assert!(registry.cancel(&serde_json::json!(3)));
+        assert!(registry.begin(&serde_json::json!(3)).is_cancelled());
+
+        // A finished request can no longer be cancelled
+        registry.queue(&serde_json::json!(2));
+        let token = registry.begin(&serde_json::json!(2));
+        drop(token);
+        assert!(!registry.cancel(&serde_json::json!(2)));
+        assert!(!CancelToken::never().is_cancelled());
+    }
+
+    #[test]
+    fn test_cancel_aborts_screenshot_poll() {
+        let dir = tempfile::tempdir().unwrap();
+        let request_path = dir.path().join("shot-req.json");
+        let response_path = dir.path().join("shot-resp.json");
+        std::fs::write(&request_path, r#"{"request_id":"req-1"}"#).unwrap();
+
+        let registry = CancelRegistry::new();
+        registry.queue(&serde_json::json!(7));
+        let token = registry.begin(&serde_json::json!(7));
+        let canceller = std::thread::spawn({
+            let registry = registry.clone();
+            move || {
+                std::thread::sleep(std::time::Duration::from_millis(250));
+                registry.cancel(&serde_json::json!(7))
+            }
+        });
+
+        let start = std::time::Instant::now();
+        let result =
+            tools::screenshot::wait_for_screenshot("req-1", &request_path, &response_path, &token);
+        assert!(canceller.join().unwrap());
+        assert!(
+            start.elapsed() < std::time::Duration::from_secs(5),
+            "returned after {:?} instead of on cancel",
+            start.elapsed()
+        );
+        assert_eq!(result["isError"], true);
+        let text = result["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains("cancelled"), "{text}");
+        // The stale request is withdrawn so the app does not answer it
+        assert_eq!(std::fs::read_to_string(&request_path).unwrap(), "");
+    }
+
+    #[test]
+    fn test_cancel_after_app_took_request_waits_for_answer() {
+        let dir = tempfile::tempdir().unwrap();
+        let request_path = dir.path().join("shot-req.json");
+        let response_path = dir.path().join("shot-resp.json");
+        // The app empties the request file when it takes the request
+        std::fs::write(&request_path, "").unwrap();
+
+        let registry = CancelRegistry::new();
+        let token = registry.begin(&serde_json::json!(8));
+        assert!(token.is_cancelled());
+        let responder = std::thread::spawn({
+            let response_path = response_path.clone();
+            move || {
+                std::thread::sleep(std::time::Duration::from_millis(250));
+                std::fs::write(
+                    &response_path,
+                    r#"{"request_id":"req-2","ok":true,"data_base64":"AAAA"}"#,
+                )
+                .unwrap();
+            }
+        });
+
+        let result =
+            tools::screenshot::wait_for_screenshot("req-2", &request_path, &response_path, &token);
+        responder.join().unwrap();
+        assert!(result.get("isError").is_none(), "{result}");
+        assert_eq!(result["content"][0]["type"], "image");
+    }
 }
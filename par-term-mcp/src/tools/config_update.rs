@@ -2,10 +2,19 @@
 //!
 //! Writes a JSON object of config key-value pairs to the IPC config-update
 //! file for the main par-term app to pick up.
+//!
+//! With `"include_previous": true` the result also lists the value each key
+//! had before the update, so an agent can undo it by sending them back.  A
+//! key's previous value is the one still waiting in the config-update file
+//! (an earlier update the app has not applied yet), else the one in
+//! `config.yaml`, else `null` for a key left at its default.  The app treats
+//! a `null` update as "reset to the default", so sending the previous values
+//! back undoes the update for every key.
 
-use crate::ipc::{config_update_path, open_restricted_write};
-use serde_json::Value;
+use crate::ipc::{config_file_path, config_update_path, open_restricted_write};
+use serde_json::{Map, Value};
 use std::io::Write;
+use std::path::Path;
 
 /// Config keys that the MCP `config_update` tool is allowed to write (SEC-005).
 ///
@@ -56,14 +65,71 @@ pub fn handle_config_update(params: &Value) -> Value {
         }
     };
 
+    let include_previous = match arguments.get("include_previous") {
+        None | Some(Value::Null) => false,
+        Some(Value::Bool(include)) => *include,
+        Some(_) => return super::tool_error("'include_previous' must be a boolean"),
+    };
+
     let path = config_update_path();
-    write_config_updates(updates, &path)
+    if include_previous {
+        write_config_updates_reporting_previous(updates, &path, &config_file_path())
+    } else {
+        write_config_updates(updates, &path)
+    }
 }
 
 /// Write config updates to the specified path atomically.
 ///
 /// Creates parent directories if needed, writes to a temp file, then renames.
-pub fn write_config_updates(updates: &Value, path: &std::path::Path) -> Value {
+pub fn write_config_updates(updates: &Value, path: &Path) -> Value {
+    write_updates(updates, path, None)
+}
+
+/// Like [`write_config_updates`], also reporting each key's previous value
+/// as read from `path` and the app config at `config_path`.
+pub fn write_config_updates_reporting_previous(
+    updates: &Value,
+    path: &Path,
+    config_path: &Path,
+) -> Value {
+    write_updates(updates, path, Some(config_path))
+}
+
+/// Values the keys of `updates` have before they are applied.
+///
+/// Looks in the pending config-update file at `update_path`, then in the
+/// app config at `config_path`; keys found in neither map to `null`, which
+/// the app applies as the key's default.
+/// Missing or unparseable files are treated as empty.
+pub fn previous_config_values(
+    updates: &Map<String, Value>,
+    update_path: &Path,
+    config_path: &Path,
+) -> Map<String, Value> {
+    let pending = std::fs::read_to_string(update_path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<Map<String, Value>>(&content).ok())
+        .unwrap_or_default();
+    let config = std::fs::read_to_string(config_path)
+        .ok()
+        .and_then(|content| serde_yaml_ng::from_str::<Map<String, Value>>(&content).ok())
+        .unwrap_or_default();
+
+    updates
+        .keys()
+        .map(|key| {
+            let previous = pending
+                .get(key)
+                .or_else(|| config.get(key))
+                .cloned()
+                .unwrap_or(Value::Null);
+            (key.clone(), previous)
+        })
+        .collect()
+}
+
+fn write_updates(updates: &Value, path: &Path, previous_from: Option<&Path>) -> Value {
     // SEC-005: enforce an explicit key allowlist BEFORE any disk I/O. The
     // original implementation wrote caller-supplied keys verbatim, so any
     // local MCP client could flip security-sensitive settings like
@@ -86,6 +152,10 @@ pub fn write_config_updates(updates: &Value, path: &std::path::Path) -> Value {
         ));
     }
 
+    // Read the previous values before this update replaces the pending file
+    let previous =
+        previous_from.map(|config_path| previous_config_values(updates_obj, path, config_path));
+
     // Ensure parent directory exists
     if let Some(parent) = path.parent()
         && let Err(e) = std::fs::create_dir_all(parent)
@@ -144,14 +214,22 @@ pub fn write_config_updates(updates: &Value, path: &std::path::Path) -> Value {
         path.display()
     );
 
+    let mut text = format!(
+        "Successfully applied config update ({} key(s): {})",
+        keys.len(),
+        keys.join(", ")
+    );
+    if let Some(previous) = previous {
+        text.push_str(&format!(
+            "\nPrevious values (null = not set in config.yaml): {}",
+            Value::Object(previous)
+        ));
+    }
+
     serde_json::json!({
         "content": [{
             "type": "text",
-            "text": format!(
-                "Successfully applied config update ({} key(s): {})",
-                keys.len(),
-                keys.join(", ")
-            )
+            "text": text
         }]
     })
 }
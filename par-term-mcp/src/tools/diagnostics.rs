@@ -3,6 +3,7 @@
 //! Requests live shader state and last compile/reload errors from the running
 //! par-term app via a file-based IPC handshake.
 
+use crate::cancel::CancelToken;
 use crate::ipc::{
     open_restricted_write, shader_diagnostics_request_path, shader_diagnostics_response_path,
     try_read_shader_diagnostics_response, write_json_atomic,
@@ -12,7 +13,7 @@ use serde_json::Value;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 /// Execute the `shader_diagnostics` tool.
-pub fn handle_shader_diagnostics(params: &Value) -> Value {
+pub fn handle_shader_diagnostics(params: &Value, cancel: &CancelToken) -> Value {
     // MCP tools/call always includes "arguments", but this tool takes none.
     if let Some(arguments) = params.get("arguments")
         && !arguments.is_object()
@@ -62,6 +63,11 @@ pub fn handle_shader_diagnostics(params: &Value) -> Value {
                 ));
             }
         }
+        if cancel.is_cancelled()
+            && let Some(result) = super::cancelled_result(&request_path, &request_id)
+        {
+            return result;
+        }
         std::thread::sleep(poll_interval);
     }
 
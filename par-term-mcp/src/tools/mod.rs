@@ -6,13 +6,20 @@
 pub mod config_update;
 pub mod diagnostics;
 pub mod screenshot;
+pub mod terminal_read;
+pub mod terminal_write;
 
+use crate::cancel::CancelToken;
+use crate::ipc::open_restricted_write;
 use serde_json::Value;
+use std::path::Path;
 
 // Re-export per-tool handlers for use in lib.rs dispatch
 pub use config_update::handle_config_update;
 pub use diagnostics::handle_shader_diagnostics;
 pub use screenshot::handle_terminal_screenshot;
+pub use terminal_read::handle_terminal_read;
+pub use terminal_write::handle_terminal_write;
 
 // ---------------------------------------------------------------------------
 // Tool descriptors
@@ -25,7 +32,11 @@ fn config_update_input_schema() -> Value {
         "properties": {
             "updates": {
                 "type": "object",
-                "description": "Map of config key -> JSON value to apply"
+                "description": "Map of config key -> JSON value to apply; null resets a key to its default"
+            },
+            "include_previous": {
+                "type": "boolean",
+                "description": "Also return each key's value before this update, for undo (default false)"
             }
         },
         "required": ["updates"]
@@ -36,7 +47,7 @@ fn config_update_input_schema() -> Value {
 fn config_update_tool() -> Value {
     serde_json::json!({
         "name": "config_update",
-        "description": "Update par-term configuration settings. Write a JSON object of config key-value pairs to apply immediately. Supported keys include: custom_shader (string|null), custom_shader_enabled (bool), custom_shader_animation (bool), custom_shader_animation_speed (float), custom_shader_brightness (float), custom_shader_text_opacity (float), custom_shader_full_content (bool), cursor_shader (string|null), cursor_shader_enabled (bool), cursor_shader_animation (bool), cursor_shader_animation_speed (float), cursor_shader_glow_radius (float), cursor_shader_glow_intensity (float), cursor_shader_trail_duration (float), cursor_shader_hides_cursor (bool), window_opacity (float), font_size (float). Do NOT edit config.yaml directly.",
+        "description": "Update par-term configuration settings. Write a JSON object of config key-value pairs to apply immediately. Supported keys include: custom_shader (string|null), custom_shader_enabled (bool), custom_shader_animation (bool), custom_shader_animation_speed (float), custom_shader_brightness (float), custom_shader_text_opacity (float), custom_shader_full_content (bool), cursor_shader (string|null), cursor_shader_enabled (bool), cursor_shader_animation (bool), cursor_shader_animation_speed (float), cursor_shader_glow_radius (float), cursor_shader_glow_intensity (float), cursor_shader_trail_duration (float), cursor_shader_hides_cursor (bool), window_opacity (float), font_size (float). Set include_previous to true to get each key's prior value back, for undo; null resets a key to its default. Do NOT edit config.yaml directly.",
         "inputSchema": config_update_input_schema()
     })
 }
@@ -75,6 +86,59 @@ fn shader_diagnostics_tool() -> Value {
     })
 }
 
+/// Build the input schema for the `terminal_write` tool.
+fn terminal_write_input_schema() -> Value {
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "text": {
+                "type": "string",
+                "description": "Text to write to the focused terminal"
+            },
+            "submit": {
+                "type": "boolean",
+                "description": "Press Enter after the text (default false)"
+            }
+        },
+        "required": ["text"]
+    })
+}
+
+/// Build the tool descriptor for `terminal_write`.
+fn terminal_write_tool() -> Value {
+    serde_json::json!({
+        "name": "terminal_write",
+        "description": format!(
+            "Write text to the focused terminal pane of the running par-term app, as if typed. Set submit to true to press Enter afterwards (e.g. to run a command). Text must be non-empty and at most {} bytes. Requires user permission.",
+            crate::TERMINAL_WRITE_MAX_BYTES
+        ),
+        "inputSchema": terminal_write_input_schema()
+    })
+}
+
+/// Build the input schema for the `terminal_read` tool.
+fn terminal_read_input_schema() -> Value {
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "include_scrollback_lines": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Scrollback lines to include above the visible screen (default 0)"
+            }
+        }
+    })
+}
+
+/// Build the tool descriptor for `terminal_read`.
+fn terminal_read_tool() -> Value {
+    serde_json::json!({
+        "name": "terminal_read",
+        "description": "Read the focused terminal pane of the running par-term app as plain text: the visible screen, optionally preceded by include_scrollback_lines lines of scrollback. Soft-wrapped lines are joined and trailing whitespace is trimmed, matching what a user would copy. Use this to inspect command output without a screenshot.",
+        "inputSchema": terminal_read_input_schema()
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Dispatch
 // ---------------------------------------------------------------------------
@@ -86,12 +150,14 @@ pub fn handle_tools_list() -> Value {
             config_update_tool(),
             terminal_screenshot_tool(),
             shader_diagnostics_tool(),
+            terminal_write_tool(),
+            terminal_read_tool(),
         ]
     })
 }
 
 /// Handle the `tools/call` request.
-pub fn handle_tools_call(params: Option<Value>) -> Value {
+pub fn handle_tools_call(params: Option<Value>, cancel: &CancelToken) -> Value {
     let params = match params {
         Some(p) => p,
         None => {
@@ -103,8 +169,10 @@ pub fn handle_tools_call(params: Option<Value>) -> Value {
 
     match name {
         "config_update" => handle_config_update(&params),
-        "terminal_screenshot" => handle_terminal_screenshot(&params),
-        "shader_diagnostics" => handle_shader_diagnostics(&params),
+        "terminal_screenshot" => handle_terminal_screenshot(&params, cancel),
+        "shader_diagnostics" => handle_shader_diagnostics(&params, cancel),
+        "terminal_write" => handle_terminal_write(&params, cancel),
+        "terminal_read" => handle_terminal_read(&params, cancel),
         _ => tool_error(&format!("Unknown tool: {name}")),
     }
 }
@@ -123,3 +191,29 @@ pub fn tool_error(message: &str) -> Value {
         }]
     })
 }
+
+/// Build the result for a call the client cancelled while it waited on the app.
+///
+/// Withdraws request `request_id` from `request_path` so an app that has not
+/// picked it up yet does not act on it.  Returns `None` when the app already
+/// took the request: it has acted (or is acting) on it, so the caller keeps
+/// waiting for its answer instead of reporting a cancel that did not happen.
+pub(crate) fn cancelled_result(request_path: &Path, request_id: &str) -> Option<Value> {
+    if !withdraw_request(request_path, request_id) {
+        return None;
+    }
+    Some(tool_error("Request cancelled by the client"))
+}
+
+/// Clear `request_path` if it still holds request `request_id`; returns
+/// whether it did.  The app empties the file when it takes a request.
+pub(crate) fn withdraw_request(request_path: &Path, request_id: &str) -> bool {
+    let pending = std::fs::read_to_string(request_path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+        .is_some_and(|request| request["request_id"] == request_id);
+    if pending {
+        let _ = open_restricted_write(request_path);
+    }
+    pending
+}
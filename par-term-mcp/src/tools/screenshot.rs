@@ -4,6 +4,7 @@
 //! file-based IPC handshake, with an optional static fallback image path for
 //! non-GUI test harnesses.
 
+use crate::cancel::CancelToken;
 use crate::ipc::{
     config_update_path, open_restricted_write, screenshot_request_path, screenshot_response_path,
     try_read_screenshot_response, write_json_atomic,
@@ -14,7 +15,7 @@ use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 /// Execute the `terminal_screenshot` tool.
-pub fn handle_terminal_screenshot(params: &Value) -> Value {
+pub fn handle_terminal_screenshot(params: &Value, cancel: &CancelToken) -> Value {
     // MCP tools/call always includes "arguments", but this tool takes none.
     if let Some(arguments) = params.get("arguments")
         && !arguments.is_object()
@@ -59,15 +60,27 @@ pub fn handle_terminal_screenshot(params: &Value) -> Value {
         ));
     }
 
+    wait_for_screenshot(&request_id, &request_path, &response_path, cancel)
+}
+
+/// Poll `response_path` for the app's answer to screenshot `request_id`.
+///
+/// Gives up after 15 seconds, or as soon as the client cancels the call.
+pub(crate) fn wait_for_screenshot(
+    request_id: &str,
+    request_path: &Path,
+    response_path: &Path,
+    cancel: &CancelToken,
+) -> Value {
     let timeout = Duration::from_secs(15);
     let poll_interval = Duration::from_millis(100);
     let start = Instant::now();
     while start.elapsed() < timeout {
-        match try_read_screenshot_response(&response_path) {
+        match try_read_screenshot_response(response_path) {
             Ok(Some(response)) if response.request_id == request_id => {
                 // Clear response file after consuming; use restricted permissions
                 // from creation (0o600 on Unix) to avoid a world-readable race.
-                let _ = open_restricted_write(&response_path);
+                let _ = open_restricted_write(response_path);
                 if !response.ok {
                     return super::tool_error(
                         response
@@ -110,6 +123,11 @@ pub fn handle_terminal_screenshot(params: &Value) -> Value {
                 ));
             }
         }
+        if cancel.is_cancelled()
+            && let Some(result) = super::cancelled_result(request_path, request_id)
+        {
+            return result;
+        }
         std::thread::sleep(poll_interval);
     }
 
@@ -0,0 +1,102 @@
+//! Handler for the `terminal_read` MCP tool.
+//!
+//! Asks the running par-term app for the focused terminal's screen text via a
+//! file-based IPC handshake, optionally including lines of scrollback.
+
+use crate::TerminalReadRequest;
+use crate::cancel::CancelToken;
+use crate::ipc::{
+    open_restricted_write, terminal_read_request_path, terminal_read_response_path,
+    try_read_terminal_read_response, write_json_atomic,
+};
+use serde_json::Value;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Validate the `terminal_read` arguments, returning the scrollback line count.
+pub fn parse_terminal_read_arguments(params: &Value) -> Result<u32, String> {
+    let arguments = match params.get("arguments") {
+        None | Some(Value::Null) => return Ok(0),
+        Some(arguments) if arguments.is_object() => arguments,
+        Some(_) => return Err("'arguments' must be an object".to_string()),
+    };
+
+    match arguments.get("include_scrollback_lines") {
+        None | Some(Value::Null) => Ok(0),
+        Some(value) => value
+            .as_u64()
+            .and_then(|lines| u32::try_from(lines).ok())
+            .ok_or_else(|| "'include_scrollback_lines' must be a non-negative integer".to_string()),
+    }
+}
+
+/// Execute the `terminal_read` tool.
+pub fn handle_terminal_read(params: &Value, cancel: &CancelToken) -> Value {
+    let include_scrollback_lines = match parse_terminal_read_arguments(params) {
+        Ok(lines) => lines,
+        Err(e) => return super::tool_error(&e),
+    };
+
+    let request_path = terminal_read_request_path();
+    let response_path = terminal_read_response_path();
+
+    let request_id = format!(
+        "{}-{}",
+        std::process::id(),
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0)
+    );
+    let request = TerminalReadRequest {
+        request_id: request_id.clone(),
+        include_scrollback_lines,
+    };
+
+    if let Err(e) = write_json_atomic(&request, &request_path) {
+        return super::tool_error(&format!(
+            "Failed to write terminal read request {}: {e}",
+            request_path.display()
+        ));
+    }
+
+    let timeout = Duration::from_secs(15);
+    let poll_interval = Duration::from_millis(100);
+    let start = Instant::now();
+    while start.elapsed() < timeout {
+        match try_read_terminal_read_response(&response_path) {
+            Ok(Some(response)) if response.request_id == request_id => {
+                let _ = open_restricted_write(&response_path);
+                if !response.ok {
+                    return super::tool_error(
+                        response.error.as_deref().unwrap_or("Terminal read failed"),
+                    );
+                }
+                return serde_json::json!({
+                    "content": [{
+                        "type": "text",
+                        "text": response.text.unwrap_or_default(),
+                    }]
+                });
+            }
+            Ok(Some(_other_response)) => {
+                // Stale response for a different request ID; keep waiting.
+            }
+            Ok(None) => {}
+            Err(e) => {
+                return super::tool_error(&format!(
+                    "Failed to read terminal read response {}: {e}",
+                    response_path.display()
+                ));
+            }
+        }
+        if cancel.is_cancelled()
+            && let Some(result) = super::cancelled_result(&request_path, &request_id)
+        {
+            return result;
+        }
+        std::thread::sleep(poll_interval);
+    }
+
+    super::withdraw_request(&request_path, &request_id);
+    super::tool_error("Timed out waiting for par-term app terminal read response")
+}
@@ -0,0 +1,123 @@
+//! Handler for the `terminal_write` MCP tool.
+//!
+//! Asks the running par-term app to write text to the focused terminal's PTY
+//! via a file-based IPC handshake, optionally pressing Enter afterwards.
+
+use crate::cancel::CancelToken;
+use crate::ipc::{
+    open_restricted_write, terminal_write_request_path, terminal_write_response_path,
+    try_read_terminal_write_response, write_json_atomic,
+};
+use crate::{TERMINAL_WRITE_MAX_BYTES, TerminalWriteRequest};
+use serde_json::Value;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Validate the `terminal_write` arguments, returning `(text, submit)`.
+pub fn parse_terminal_write_arguments(params: &Value) -> Result<(String, bool), String> {
+    let arguments = match params.get("arguments") {
+        Some(arguments) if arguments.is_object() => arguments,
+        Some(_) => return Err("'arguments' must be an object".to_string()),
+        None => return Err("Missing 'arguments' with a 'text' string".to_string()),
+    };
+
+    let text = match arguments.get("text") {
+        Some(Value::String(text)) => text,
+        Some(_) => return Err("'text' must be a string".to_string()),
+        None => return Err("Missing required 'text' argument".to_string()),
+    };
+    if text.is_empty() {
+        return Err("'text' must not be empty".to_string());
+    }
+    if text.len() > TERMINAL_WRITE_MAX_BYTES {
+        return Err(format!(
+            "'text' is {} bytes; the limit is {TERMINAL_WRITE_MAX_BYTES}",
+            text.len()
+        ));
+    }
+
+    let submit = match arguments.get("submit") {
+        None | Some(Value::Null) => false,
+        Some(Value::Bool(submit)) => *submit,
+        Some(_) => return Err("'submit' must be a boolean".to_string()),
+    };
+
+    Ok((text.clone(), submit))
+}
+
+/// Execute the `terminal_write` tool.
+pub fn handle_terminal_write(params: &Value, cancel: &CancelToken) -> Value {
+    let (text, submit) = match parse_terminal_write_arguments(params) {
+        Ok(args) => args,
+        Err(e) => return super::tool_error(&e),
+    };
+
+    let request_path = terminal_write_request_path();
+    let response_path = terminal_write_response_path();
+
+    let request_id = format!(
+        "{}-{}",
+        std::process::id(),
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0)
+    );
+    let request = TerminalWriteRequest {
+        request_id: request_id.clone(),
+        text,
+        submit,
+    };
+
+    if let Err(e) = write_json_atomic(&request, &request_path) {
+        return super::tool_error(&format!(
+            "Failed to write terminal write request {}: {e}",
+            request_path.display()
+        ));
+    }
+
+    let timeout = Duration::from_secs(15);
+    let poll_interval = Duration::from_millis(100);
+    let start = Instant::now();
+    while start.elapsed() < timeout {
+        match try_read_terminal_write_response(&response_path) {
+            Ok(Some(response)) if response.request_id == request_id => {
+                let _ = open_restricted_write(&response_path);
+                if !response.ok {
+                    return super::tool_error(
+                        response.error.as_deref().unwrap_or("Terminal write failed"),
+                    );
+                }
+                let bytes = response.bytes_written.unwrap_or(0);
+                return serde_json::json!({
+                    "content": [{
+                        "type": "text",
+                        "text": format!(
+                            "Wrote {bytes} bytes to the focused terminal{}.",
+                            if submit { " and pressed Enter" } else { "" }
+                        ),
+                    }]
+                });
+            }
+            Ok(Some(_other_response)) => {
+                // Stale response for a different request ID; keep waiting.
+            }
+            Ok(None) => {}
+            Err(e) => {
+                return super::tool_error(&format!(
+                    "Failed to read terminal write response {}: {e}",
+                    response_path.display()
+                ));
+            }
+        }
+        if cancel.is_cancelled()
+            && let Some(result) = super::cancelled_result(&request_path, &request_id)
+        {
+            return result;
+        }
+        std::thread::sleep(poll_interval);
+    }
+
+    // Withdraw the request so an app that starts late does not type it.
+    super::withdraw_request(&request_path, &request_id);
+    super::tool_error("Timed out waiting for par-term app terminal write response")
+}
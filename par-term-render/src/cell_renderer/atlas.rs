@@ -1,4 +1,13 @@
-use super::{CellRenderer, GlyphInfo};
+use super::{CellRenderer, GlyphInfo, pipeline};
+use par_term_fonts::font_manager::Presentation;
+
+/// Outline growth for synthetic bold, as a fraction of the font size in pixels
+const SYNTHETIC_BOLD_STRENGTH: f32 = 1.0 / 32.0;
+/// Slant of synthetic italic, in degrees
+const SYNTHETIC_ITALIC_ANGLE: f32 = 12.0;
+/// Cache key bits for glyphs drawn with synthetic bold and italic
+const SYNTHETIC_BOLD_KEY_BIT: u64 = 1 << 62;
+const SYNTHETIC_ITALIC_KEY_BIT: u64 = 1 << 61;
 
 pub(crate) struct RasterizedGlyph {
     pub width: u32,
@@ -71,14 +80,29 @@ pub fn should_render_as_symbol(ch: char) -> bool {
     false
 }
 
+/// Check if a grapheme cluster should be rendered as a monochrome symbol.
+///
+/// A presentation selector right after the first character decides: VS15
+/// (U+FE0E) asks for the text form and VS16 (U+FE0F) for the color emoji form.
+/// Without one, single characters follow [`should_render_as_symbol`] and longer
+/// sequences (flags, ZWJ emoji, skin tones) are left to the emoji fonts.
+pub fn should_render_grapheme_as_symbol(grapheme: &str) -> bool {
+    match Presentation::requested_by(grapheme) {
+        Some(Presentation::Text) => true,
+        Some(Presentation::Emoji) => false,
+        None => {
+            let mut chars = grapheme.chars();
+            matches!((chars.next(), chars.next()), (Some(ch), None) if should_render_as_symbol(ch))
+        }
+    }
+}
+
 impl CellRenderer {
     pub fn clear_glyph_cache(&mut self) {
         self.atlas.glyph_cache.clear();
         self.atlas.lru_head = None;
         self.atlas.lru_tail = None;
-        self.atlas.atlas_next_x = 0;
-        self.atlas.atlas_next_y = 0;
-        self.atlas.atlas_row_height = 0;
+        self.atlas.allocator.reset();
         self.dirty_rows.fill(true);
         // Re-upload the solid white pixel for geometric block rendering
         self.upload_solid_pixel();
@@ -141,12 +165,15 @@ impl CellRenderer {
         font_idx: usize,
         glyph_id: u16,
         force_monochrome: bool,
+        synthetic: (bool, bool),
     ) -> Option<RasterizedGlyph> {
         let font = self.font_manager.get_font(font_idx)?;
+        // Variable font axis values configured for this font
+        let coords = self.font_manager.font_coords(font_idx);
         // Use swash to rasterize
         use swash::scale::Render;
         use swash::scale::image::Content;
-        use swash::zeno::Format;
+        use swash::zeno::{Angle, Format, Transform};
 
         // Determine render format before creating the scaler so there is no live
         // mutable borrow of `self.scale_context` when we call `should_use_thin_strokes`.
@@ -183,6 +210,16 @@ impl CellRenderer {
             ]
         };
 
+        // Emphasis the font's missing bold/italic face would have given
+        let (synthetic_bold, synthetic_italic) = synthetic;
+        let embolden = if synthetic_bold {
+            self.font.font_size_pixels * SYNTHETIC_BOLD_STRENGTH
+        } else {
+            0.0
+        };
+        let slant = synthetic_italic
+            .then(|| Transform::skew(Angle::from_degrees(SYNTHETIC_ITALIC_ANGLE), Angle::ZERO));
+
         // Build the scaler after computing `render_format` to avoid a
         // mutable+immutable borrow overlap on `self`.
         let mut scaler = self
@@ -190,10 +227,13 @@ impl CellRenderer {
             .builder(*font)
             .size(self.font.font_size_pixels)
             .hint(self.font.font_hinting)
+            .normalized_coords(coords)
             .build();
 
         let mut image = Render::new(&sources)
             .format(render_format)
+            .embolden(embolden)
+            .transform(slant)
             .render(&mut scaler, glyph_id)?;
 
         // Detect degenerate outlines: some fonts (e.g., Apple Color Emoji) have charmap
@@ -218,6 +258,7 @@ impl CellRenderer {
                 .builder(*font)
                 .size(self.font.font_size_pixels)
                 .hint(self.font.font_hinting)
+                .normalized_coords(coords)
                 .build();
             let color_sources = [
                 swash::scale::Source::ColorBitmap(swash::scale::StrikeWith::BestFit),
@@ -281,23 +322,95 @@ impl CellRenderer {
         })
     }
 
-    pub(crate) fn upload_glyph(&mut self, _key: u64, raster: &RasterizedGlyph) -> GlyphInfo {
+    /// Grow the atlas texture array so it has a layer for `page`.
+    ///
+    /// Existing pages are copied into the new texture and the text bind group is
+    /// rebuilt to reference it, so cached `GlyphInfo` entries stay valid.
+    fn ensure_atlas_page(&mut self, page: u32) {
+        if page < self.atlas.atlas_pages {
+            return;
+        }
+        let old_pages = self.atlas.atlas_pages;
+        let new_pages = (page + 1).min(self.atlas.allocator.max_pages());
+        let size = self.atlas.atlas_size;
+        log::debug!("Glyph atlas growing from {old_pages} to {new_pages} page(s)");
+
+        let (texture, view) = pipeline::create_atlas_texture(&self.device, size, new_pages);
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("atlas grow encoder"),
+            });
+        encoder.copy_texture_to_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &self.atlas.atlas_texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyTextureInfo {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::Extent3d {
+                width: size,
+                height: size,
+                depth_or_array_layers: old_pages,
+            },
+        );
+        // Pending `write_texture` uploads to the old texture run before this copy.
+        self.queue.submit(std::iter::once(encoder.finish()));
+
+        self.pipelines.text_bind_group = pipeline::create_text_bind_group(
+            &self.device,
+            &self.pipelines.text_bind_group_layout,
+            &view,
+            &self.atlas.atlas_sampler,
+        );
+        self.atlas.atlas_texture = texture;
+        self.atlas.atlas_view = view;
+        self.atlas.atlas_pages = new_pages;
+    }
+
+    /// Upload a rasterized glyph to the atlas.
+    ///
+    /// Opens a new atlas page when the current one is full; the glyph cache is
+    /// only cleared once every page is in use.  Returns `None` if the glyph is
+    /// larger than an atlas page.
+    pub(crate) fn upload_glyph(
+        &mut self,
+        _key: u64,
+        raster: &RasterizedGlyph,
+    ) -> Option<GlyphInfo> {
         let padding = super::ATLAS_GLYPH_PADDING;
         let atlas_size = self.atlas.atlas_size;
-        if self.atlas.atlas_next_x + raster.width + padding > atlas_size {
-            self.atlas.atlas_next_x = 0;
-            self.atlas.atlas_next_y += self.atlas.atlas_row_height + padding;
-            self.atlas.atlas_row_height = 0;
+        if !self.atlas.allocator.fits(raster.width, raster.height) {
+            log::warn!(
+                "Glyph {}x{} does not fit in a {}px atlas page; skipping",
+                raster.width,
+                raster.height,
+                atlas_size
+            );
+            return None;
         }
 
-        if self.atlas.atlas_next_y + raster.height + padding > atlas_size {
-            self.clear_glyph_cache();
-        }
+        let slot = match self.atlas.allocator.allocate(raster.width, raster.height) {
+            Some(slot) => slot,
+            None => {
+                // Every page is full: evict everything and start over on page 0.
+                self.clear_glyph_cache();
+                self.atlas.allocator.allocate(raster.width, raster.height)?
+            }
+        };
+        self.ensure_atlas_page(slot.page);
 
         let info = GlyphInfo {
             key: _key,
-            x: self.atlas.atlas_next_x,
-            y: self.atlas.atlas_next_y,
+            page: slot.page,
+            x: slot.x,
+            y: slot.y,
             width: raster.width,
             height: raster.height,
             bearing_x: raster.bearing_x,
@@ -314,7 +427,7 @@ impl CellRenderer {
                 origin: wgpu::Origin3d {
                     x: info.x,
                     y: info.y,
-                    z: 0,
+                    z: info.page,
                 },
                 aspect: wgpu::TextureAspect::All,
             },
@@ -346,7 +459,7 @@ impl CellRenderer {
                     origin: wgpu::Origin3d {
                         x: pad_right_x,
                         y: info.y,
-                        z: 0,
+                        z: info.page,
                     },
                     aspect: wgpu::TextureAspect::All,
                 },
@@ -374,7 +487,7 @@ impl CellRenderer {
                     origin: wgpu::Origin3d {
                         x: info.x,
                         y: pad_bottom_y,
-                        z: 0,
+                        z: info.page,
                     },
                     aspect: wgpu::TextureAspect::All,
                 },
@@ -392,10 +505,7 @@ impl CellRenderer {
             );
         }
 
-        self.atlas.atlas_next_x += raster.width + padding;
-        self.atlas.atlas_row_height = self.atlas.atlas_row_height.max(raster.height);
-
-        info
+        Some(info)
     }
 
     /// Look up a glyph by `cache_key` in the atlas, rasterizing and uploading it on
@@ -411,6 +521,7 @@ impl CellRenderer {
         font_idx: usize,
         glyph_id: u16,
         force_monochrome: bool,
+        synthetic: (bool, bool),
         cache_key: u64,
     ) -> Option<GlyphInfo> {
         if self.atlas.glyph_cache.contains_key(&cache_key) {
@@ -424,8 +535,8 @@ impl CellRenderer {
                     .clone(),
             );
         }
-        let raster = self.rasterize_glyph(font_idx, glyph_id, force_monochrome)?;
-        let info = self.upload_glyph(cache_key, &raster);
+        let raster = self.rasterize_glyph(font_idx, glyph_id, force_monochrome, synthetic)?;
+        let info = self.upload_glyph(cache_key, &raster)?;
         self.atlas.glyph_cache.insert(cache_key, info.clone());
         self.lru_push_front(cache_key);
         Some(info)
@@ -451,7 +562,8 @@ impl CellRenderer {
     /// # Caching
     /// Results are cached in the glyph atlas.  The cache key encodes `(font_idx, glyph_id)`
     /// as `((font_idx as u64) << 32) | (glyph_id as u64)`, with bit 63 set for the
-    /// colored-emoji fallback variant.
+    /// colored-emoji fallback variant and bits 62 and 61 for synthetic bold and
+    /// italic (see [`FontManager::synthetic_style`](par_term_fonts::FontManager::synthetic_style)).
     pub(crate) fn resolve_glyph_with_fallback(
         &mut self,
         base_char: char,
@@ -461,10 +573,14 @@ impl CellRenderer {
         force_monochrome: bool,
     ) -> Option<GlyphInfo> {
         // Initial lookup: use grapheme-aware path for multi-char sequences (flags, ZWJ emoji,
-        // skin-tone modifiers), unless force_monochrome has already stripped VS16 to a single char.
+        // skin-tone modifiers, presentation selectors).  A VS15 text presentation request
+        // keeps that path so it can pick a font with monochrome outlines.  Single
+        // characters take the glyph the OpenType features of their range font select.
         let chars: Vec<char> = grapheme.chars().collect();
-        let mut glyph_result = if force_monochrome || chars.len() == 1 {
-            self.font_manager.find_glyph(base_char, bold, italic)
+        let text_presentation = Presentation::requested_by(grapheme) == Some(Presentation::Text);
+        let mut glyph_result = if (force_monochrome && !text_presentation) || chars.len() == 1 {
+            self.font_manager
+                .find_featured_glyph(base_char, bold, italic)
         } else {
             self.font_manager
                 .find_grapheme_glyph(grapheme, bold, italic)
@@ -477,10 +593,17 @@ impl CellRenderer {
         let resolved = loop {
             match glyph_result {
                 Some((font_idx, glyph_id)) => {
-                    let cache_key = ((font_idx as u64) << 32) | (glyph_id as u64);
-                    if let Some(info) =
-                        self.get_or_rasterize_glyph(font_idx, glyph_id, force_monochrome, cache_key)
-                    {
+                    let synthetic = self.font_manager.synthetic_style(font_idx, bold, italic);
+                    let cache_key = ((font_idx as u64) << 32)
+                        | (glyph_id as u64)
+                        | synthetic_key_bits(synthetic);
+                    if let Some(info) = self.get_or_rasterize_glyph(
+                        font_idx,
+                        glyph_id,
+                        force_monochrome,
+                        synthetic,
+                        cache_key,
+                    ) {
                         break Some(info);
                     }
                     // This font's outline was empty — exclude it and retry.
@@ -506,10 +629,13 @@ impl CellRenderer {
             loop {
                 match glyph_result2 {
                     Some((font_idx, glyph_id)) => {
-                        let cache_key =
-                            ((font_idx as u64) << 32) | (glyph_id as u64) | (1u64 << 63);
-                        if let Some(info) =
-                            self.get_or_rasterize_glyph(font_idx, glyph_id, false, cache_key)
+                        let synthetic = self.font_manager.synthetic_style(font_idx, bold, italic);
+                        let cache_key = ((font_idx as u64) << 32)
+                            | (glyph_id as u64)
+                            | (1u64 << 63)
+                            | synthetic_key_bits(synthetic);
+                        if let Some(info) = self
+                            .get_or_rasterize_glyph(font_idx, glyph_id, false, synthetic, cache_key)
                         {
                             break Some(info);
                         }
@@ -529,6 +655,19 @@ impl CellRenderer {
     }
 }
 
+/// Cache key bits that keep synthetic bold/italic renderings of a glyph apart
+/// from its plain rendering.
+fn synthetic_key_bits((synthetic_bold, synthetic_italic): (bool, bool)) -> u64 {
+    let mut bits = 0;
+    if synthetic_bold {
+        bits |= SYNTHETIC_BOLD_KEY_BIT;
+    }
+    if synthetic_italic {
+        bits |= SYNTHETIC_ITALIC_KEY_BIT;
+    }
+    bits
+}
+
 /// Convert a swash subpixel mask into an RGBA alpha mask.
 /// Some swash builds emit 3 bytes/pixel (RGB), others 4 bytes/pixel (RGBA).
 /// We derive alpha from luminance of RGB and ignore the packed alpha to avoid
@@ -658,7 +797,23 @@ mod tests {
         assert_eq!(converted, expected);
     }
 
-    use super::should_render_as_symbol;
+    use super::{should_render_as_symbol, should_render_grapheme_as_symbol};
+
+    #[test]
+    fn test_presentation_selectors_override_symbol_ranges() {
+        // ☎ is drawn as a symbol unless VS16 asks for the emoji form
+        assert!(should_render_grapheme_as_symbol("\u{260E}"));
+        assert!(should_render_grapheme_as_symbol("\u{260E}\u{FE0E}"));
+        assert!(!should_render_grapheme_as_symbol("\u{260E}\u{FE0F}"));
+
+        // VS15 turns emoji into text as well
+        assert!(!should_render_grapheme_as_symbol("\u{1F600}"));
+        assert!(should_render_grapheme_as_symbol("\u{1F600}\u{FE0E}"));
+
+        // Other sequences stay with the emoji fonts
+        assert!(!should_render_grapheme_as_symbol("\u{1F44B}\u{1F3FD}"));
+        assert!(!should_render_grapheme_as_symbol("e\u{301}"));
+    }
 
     #[test]
     fn test_dingbats_are_symbols() {
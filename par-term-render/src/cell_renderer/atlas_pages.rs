@@ -0,0 +1,168 @@
+//! Multi-page shelf allocator for the glyph atlas.
+//!
+//! Glyphs are packed left-to-right into rows ("shelves") on a square page.
+//! When the current page has no room left, the next page (a layer of the atlas
+//! texture array) is opened instead of evicting cached glyphs.  Allocation only
+//! fails once all `max_pages` pages are full; the caller then clears the glyph
+//! cache and starts over from page 0.
+//!
+//! This module only tracks placement — texture creation and uploads live in
+//! `atlas.rs` and `pipeline.rs`.
+
+/// Position of an allocated rectangle within the atlas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct AtlasSlot {
+    /// Atlas page (texture array layer) holding the rectangle.
+    pub page: u32,
+    /// Left edge in pixels within the page.
+    pub x: u32,
+    /// Top edge in pixels within the page.
+    pub y: u32,
+}
+
+/// Shelf packer spanning up to `max_pages` square pages.
+#[derive(Debug)]
+pub(crate) struct AtlasPageAllocator {
+    page_size: u32,
+    max_pages: u32,
+    padding: u32,
+    page: u32,
+    next_x: u32,
+    next_y: u32,
+    row_height: u32,
+}
+
+impl AtlasPageAllocator {
+    /// Create an allocator for pages of `page_size`×`page_size` pixels.
+    ///
+    /// `padding` pixels are left to the right of and below every allocation so
+    /// bilinear sampling never bleeds into a neighbouring glyph.
+    pub(crate) fn new(page_size: u32, max_pages: u32, padding: u32) -> Self {
+        Self {
+            page_size: page_size.max(1),
+            max_pages: max_pages.max(1),
+            padding,
+            page: 0,
+            next_x: 0,
+            next_y: 0,
+            row_height: 0,
+        }
+    }
+
+    /// Maximum number of pages this allocator may open.
+    pub(crate) fn max_pages(&self) -> u32 {
+        self.max_pages
+    }
+
+    /// Whether a `width`×`height` rectangle can ever fit on an empty page.
+    pub(crate) fn fits(&self, width: u32, height: u32) -> bool {
+        width + self.padding <= self.page_size && height + self.padding <= self.page_size
+    }
+
+    /// Reserve space for a `width`×`height` rectangle.
+    ///
+    /// Moves to a new shelf when the current one is full and to a new page
+    /// when the current page is full.  Returns `None` when the rectangle does
+    /// not fit on a page at all or every page is already full.
+    pub(crate) fn allocate(&mut self, width: u32, height: u32) -> Option<AtlasSlot> {
+        if !self.fits(width, height) {
+            return None;
+        }
+
+        if self.next_x + width + self.padding > self.page_size {
+            self.next_x = 0;
+            self.next_y += self.row_height + self.padding;
+            self.row_height = 0;
+        }
+
+        if self.next_y + height + self.padding > self.page_size {
+            if self.page + 1 >= self.max_pages {
+                return None;
+            }
+            self.page += 1;
+            self.next_x = 0;
+            self.next_y = 0;
+            self.row_height = 0;
+        }
+
+        let slot = AtlasSlot {
+            page: self.page,
+            x: self.next_x,
+            y: self.next_y,
+        };
+        self.next_x += width + self.padding;
+        self.row_height = self.row_height.max(height);
+        Some(slot)
+    }
+
+    /// Forget every allocation and start again at the top of page 0.
+    pub(crate) fn reset(&mut self) {
+        self.page = 0;
+        self.next_x = 0;
+        self.next_y = 0;
+        self.row_height = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn slot(page: u32, x: u32, y: u32) -> AtlasSlot {
+        AtlasSlot { page, x, y }
+    }
+
+    #[test]
+    fn new_page_opens_when_first_page_fills() {
+        // 4 glyphs of 30x30 (+2 padding) fit per 64px page: two per shelf, two shelves.
+        let mut alloc = AtlasPageAllocator::new(64, 3, 2);
+        let slots: Vec<AtlasSlot> = (0..5).map(|_| alloc.allocate(30, 30).unwrap()).collect();
+
+        assert!(slots[..4].iter().all(|s| s.page == 0));
+        assert_eq!(slots[3], slot(0, 32, 32));
+        assert_eq!(slots[4], slot(1, 0, 0));
+    }
+
+    #[test]
+    fn allocation_fails_only_when_all_pages_are_full() {
+        let mut alloc = AtlasPageAllocator::new(64, 2, 2);
+        for _ in 0..8 {
+            assert!(alloc.allocate(30, 30).is_some());
+        }
+        assert_eq!(alloc.allocate(30, 30), None);
+
+        alloc.reset();
+        assert_eq!(alloc.allocate(30, 30), Some(slot(0, 0, 0)));
+    }
+
+    #[test]
+    fn oversized_rect_is_rejected_without_opening_a_page() {
+        let mut alloc = AtlasPageAllocator::new(64, 4, 2);
+        assert!(!alloc.fits(63, 10));
+        assert_eq!(alloc.allocate(63, 10), None);
+        // The failed request did not advance to another page.
+        assert_eq!(alloc.allocate(10, 10), Some(slot(0, 0, 0)));
+    }
+
+    #[test]
+    fn glyph_lookup_spans_pages() {
+        // Cache keyed by glyph id, as the renderer's glyph cache is.
+        let mut alloc = AtlasPageAllocator::new(64, 4, 2);
+        let mut cache: HashMap<u64, AtlasSlot> = HashMap::new();
+        for key in 0..10u64 {
+            cache.insert(key, alloc.allocate(30, 30).unwrap());
+        }
+
+        // Glyphs placed before later pages opened keep their slots.
+        assert_eq!(cache[&0], slot(0, 0, 0));
+        assert_eq!(cache[&5], slot(1, 32, 0));
+        assert_eq!(cache[&9], slot(2, 32, 0));
+
+        // No two glyphs share a slot, even at the same (x, y) on different pages.
+        let mut seen: Vec<AtlasSlot> = cache.values().copied().collect();
+        seen.sort_by_key(|s| (s.page, s.y, s.x));
+        seen.dedup();
+        assert_eq!(seen.len(), cache.len());
+    }
+}
@@ -255,12 +255,11 @@ impl CellRenderer {
     /// Returns None if not in solid color mode.
     pub fn get_solid_color_as_clear(&self) -> Option<wgpu::Color> {
         if self.bg_state.bg_is_solid_color {
-            Some(wgpu::Color {
-                r: self.bg_state.solid_bg_color[0] as f64 * self.window_opacity as f64,
-                g: self.bg_state.solid_bg_color[1] as f64 * self.window_opacity as f64,
-                b: self.bg_state.solid_bg_color[2] as f64 * self.window_opacity as f64,
-                a: self.window_opacity as f64,
-            })
+            Some(crate::color_space::clear_color(
+                self.surface_format(),
+                self.bg_state.solid_bg_color,
+                self.window_opacity as f64,
+            ))
         } else {
             None
         }
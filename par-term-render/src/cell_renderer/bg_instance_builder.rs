@@ -1,6 +1,6 @@
 use super::instance_buffers::{
-    COLOR_COMPONENT_EPSILON, CURSOR_BOOST_MAX_ALPHA, CURSOR_OVERLAY_SLOTS, GUTTER_WIDTH_CELLS,
-    HOLLOW_CURSOR_BORDER_PX,
+    COLOR_COMPONENT_EPSILON, CURSOR_BOOST_MAX_ALPHA, CURSOR_OVERLAY_SLOTS, CURSOR_SMEAR_FIRST_SLOT,
+    GUTTER_WIDTH_CELLS, HOLLOW_CURSOR_BORDER_PX,
 };
 use super::{BackgroundInstance, Cell, CellRenderer};
 use par_term_config::{color_u8x4_rgb_to_f32, color_u8x4_rgb_to_f32_a};
@@ -24,7 +24,7 @@ impl CellRenderer {
             // Check for cursor at this position, accounting for unfocused state
             let cursor_at_cell = !self.cursor.hidden_for_shader
                 && self.cursor.pos.1 == row
-                && self.cursor.pos.0 == col;
+                && self.cursor.cell_span.contains(&col);
             let cursor_visible = self.cursor.opacity > 0.0 && cursor_at_cell;
 
             // Hollow cursor (unfocused + Hollow style) must always show regardless of blink opacity.
@@ -77,27 +77,15 @@ impl CellRenderer {
 
             // Handle cursor at this position
             if has_cursor {
-                use par_term_emu_core_rust::cursor::CursorStyle;
-
                 // render_hollow_here was computed above; reuse it.
-                let render_hollow = render_hollow_here;
-
-                match self.cursor.style {
-                    CursorStyle::SteadyBlock | CursorStyle::BlinkingBlock => {
-                        if render_hollow {
-                            // Hollow cursor: don't fill the cell, outline will be added later
-                            // Keep original background color
-                        } else {
-                            // Solid block cursor
-                            for (bg, &cursor) in bg_color.iter_mut().take(3).zip(&self.cursor.color)
-                            {
-                                *bg = *bg * (1.0 - self.cursor.opacity)
-                                    + cursor * self.cursor.opacity;
-                            }
-                            bg_color[3] = bg_color[3].max(self.cursor.opacity);
-                        }
+                // Hollow cursor: don't fill the cell, outline will be added later.
+                // A morphing cursor is drawn as an overlay instead.
+                if self.cursor_fills_cell() && !render_hollow_here {
+                    // Solid block cursor
+                    for (bg, &cursor) in bg_color.iter_mut().take(3).zip(&self.cursor.color) {
+                        *bg = *bg * (1.0 - self.cursor.opacity) + cursor * self.cursor.opacity;
                     }
-                    _ => {}
+                    bg_color[3] = bg_color[3].max(self.cursor.opacity);
                 }
                 // Cursor cell can't be merged, render it alone
                 // Snap to pixel boundaries to match text pipeline alignment
@@ -128,6 +116,7 @@ impl CellRenderer {
                         (y1 - y0) / self.config.height as f32 * 2.0,
                     ],
                     color: bg_color,
+                    corner_radii: [0.0; 4],
                 });
                 col += 1;
                 continue;
@@ -142,7 +131,7 @@ impl CellRenderer {
                 let next_has_cursor = self.cursor.opacity > 0.0
                     && !self.cursor.hidden_for_shader
                     && self.cursor.pos.1 == row
-                    && self.cursor.pos.0 == col;
+                    && self.cursor.cell_span.contains(&col);
                 // Stop run if color differs, cursor is here, or next cell is a half-block
                 let next_is_half_block = {
                     let mut chars = next_cell.grapheme.chars();
@@ -184,6 +173,7 @@ impl CellRenderer {
                 position: [pos_x, pos_y],
                 size: [sz_x, sz_y],
                 color: bg_color,
+                corner_radii: [0.0; 4],
             });
         }
 
@@ -194,6 +184,7 @@ impl CellRenderer {
                 position: [0.0, 0.0],
                 size: [0.0, 0.0],
                 color: [0.0, 0.0, 0.0, 0.0],
+                corner_radii: [0.0; 4],
             });
         }
     }
@@ -211,12 +202,14 @@ impl CellRenderer {
     ///   [3] cursor boost glow
     ///   [4-7] hollow cursor outline (top, bottom, left, right)
     ///   [8-9] reserved (zero)
+    ///   [10..] cursor smear streak, tail first
     pub(crate) fn build_cursor_overlay_instances(&self) -> Vec<BackgroundInstance> {
         let mut overlay_instances = vec![
             BackgroundInstance {
                 position: [0.0, 0.0],
                 size: [0.0, 0.0],
                 color: [0.0, 0.0, 0.0, 0.0],
+                corner_radii: [0.0; 4],
             };
             CURSOR_OVERLAY_SLOTS
         ];
@@ -228,53 +221,42 @@ impl CellRenderer {
                 || self.cursor.unfocused_style != par_term_config::UnfocusedCursorStyle::Hidden);
 
         // Calculate cursor pixel positions
-        let cursor_col = self.cursor.pos.0;
+        // A cursor widened over a ligature starts at the ligature's first cell
+        let cursor_col = self.cursor.cell_span.start;
         let cursor_row = self.cursor.pos.1;
+        let cursor_width =
+            super::ligature_cursor::span_width(&self.cursor.cell_span, self.grid.cell_width);
         let cursor_x0 = self.grid.window_padding
             + self.grid.content_offset_x
             + cursor_col as f32 * self.grid.cell_width;
-        let cursor_x1 = cursor_x0 + self.grid.cell_width;
+        let cursor_x1 = cursor_x0 + cursor_width;
         let cursor_y0 = self.grid.window_padding
             + self.grid.content_offset_y
             + cursor_row as f32 * self.grid.cell_height;
         let cursor_y1 = cursor_y0 + self.grid.cell_height;
 
-        // Slot 0: Cursor overlay (beam/underline) — computed fresh each frame from current
-        // grid dimensions and cursor color. Computing here (not cached in cursor.overlay)
-        // avoids stale coordinates after a resize or stale color after update_cursor_color().
-        overlay_instances[0] = if cursor_visible {
-            use par_term_emu_core_rust::cursor::CursorStyle;
+        // Slot 0: Cursor overlay (beam/underline, or the shape mid-morph) — computed fresh
+        // each frame from current grid dimensions and cursor color. Computing here (not cached
+        // in cursor.overlay) avoids stale coordinates after a resize or stale color after
+        // update_cursor_color().
+        overlay_instances[0] = if cursor_visible && let Some(rect) = self.cursor_overlay_rect() {
             let w = self.config.width as f32;
             let h = self.config.height as f32;
             let cc = self.cursor.color;
-            let op = self.cursor.opacity;
-            match self.cursor.style {
-                CursorStyle::SteadyBar | CursorStyle::BlinkingBar => BackgroundInstance {
-                    position: [cursor_x0 / w * 2.0 - 1.0, 1.0 - (cursor_y0 / h * 2.0)],
-                    size: [2.0 / w * 2.0, (cursor_y1 - cursor_y0) / h * 2.0],
-                    color: [cc[0], cc[1], cc[2], op],
-                },
-                CursorStyle::SteadyUnderline | CursorStyle::BlinkingUnderline => {
-                    BackgroundInstance {
-                        position: [
-                            cursor_x0 / w * 2.0 - 1.0,
-                            1.0 - ((cursor_y1 - 2.0) / h * 2.0),
-                        ],
-                        size: [(cursor_x1 - cursor_x0) / w * 2.0, 2.0 / h * 2.0],
-                        color: [cc[0], cc[1], cc[2], op],
-                    }
-                }
-                _ => BackgroundInstance {
-                    position: [0.0, 0.0],
-                    size: [0.0, 0.0],
-                    color: [0.0, 0.0, 0.0, 0.0],
-                },
+            let x0 = cursor_x0 + rect.x;
+            let y0 = cursor_y0 + rect.y;
+            BackgroundInstance {
+                position: [x0 / w * 2.0 - 1.0, 1.0 - (y0 / h * 2.0)],
+                size: [rect.width / w * 2.0, rect.height / h * 2.0],
+                color: [cc[0], cc[1], cc[2], self.cursor.opacity],
+                corner_radii: [0.0; 4],
             }
         } else {
             BackgroundInstance {
                 position: [0.0, 0.0],
                 size: [0.0, 0.0],
                 color: [0.0, 0.0, 0.0, 0.0],
+                corner_radii: [0.0; 4],
             }
         };
 
@@ -293,6 +275,7 @@ impl CellRenderer {
                     (cursor_y1 - cursor_y0) / self.config.height as f32 * 2.0,
                 ],
                 color: self.cursor.guide_color,
+                corner_radii: [0.0; 4],
             };
         }
 
@@ -306,10 +289,11 @@ impl CellRenderer {
                     1.0 - (shadow_y0 / self.config.height as f32 * 2.0),
                 ],
                 size: [
-                    self.grid.cell_width / self.config.width as f32 * 2.0,
+                    cursor_width / self.config.width as f32 * 2.0,
                     self.grid.cell_height / self.config.height as f32 * 2.0,
                 ],
                 color: self.cursor.shadow_color,
+                corner_radii: [0.0; 4],
             };
         }
 
@@ -318,7 +302,7 @@ impl CellRenderer {
             let glow_expand = 4.0 * self.scale_factor * self.cursor.boost; // Expand by up to 4 logical pixels
             let glow_x0 = cursor_x0 - glow_expand;
             let glow_y0 = cursor_y0 - glow_expand;
-            let glow_w = self.grid.cell_width + glow_expand * 2.0;
+            let glow_w = cursor_width + glow_expand * 2.0;
             let glow_h = self.grid.cell_height + glow_expand * 2.0;
             overlay_instances[3] = BackgroundInstance {
                 position: [
@@ -335,6 +319,7 @@ impl CellRenderer {
                     self.cursor.boost_color[2],
                     self.cursor.boost * CURSOR_BOOST_MAX_ALPHA * self.cursor.opacity,
                 ],
+                corner_radii: [0.0; 4],
             };
         }
 
@@ -364,10 +349,11 @@ impl CellRenderer {
                         1.0 - (cursor_y0 / self.config.height as f32 * 2.0),
                     ],
                     size: [
-                        self.grid.cell_width / self.config.width as f32 * 2.0,
+                        cursor_width / self.config.width as f32 * 2.0,
                         border_width / self.config.height as f32 * 2.0,
                     ],
                     color,
+                    corner_radii: [0.0; 4],
                 };
 
                 // Bottom border
@@ -377,10 +363,11 @@ impl CellRenderer {
                         1.0 - ((cursor_y1 - border_width) / self.config.height as f32 * 2.0),
                     ],
                     size: [
-                        self.grid.cell_width / self.config.width as f32 * 2.0,
+                        cursor_width / self.config.width as f32 * 2.0,
                         border_width / self.config.height as f32 * 2.0,
                     ],
                     color,
+                    corner_radii: [0.0; 4],
                 };
 
                 // Left border
@@ -395,6 +382,7 @@ impl CellRenderer {
                             * 2.0,
                     ],
                     color,
+                    corner_radii: [0.0; 4],
                 };
 
                 // Right border
@@ -409,6 +397,30 @@ impl CellRenderer {
                             * 2.0,
                     ],
                     color,
+                    corner_radii: [0.0; 4],
+                };
+            }
+        }
+
+        // Slots 10..: Cursor smear streak left by the last cursor jump
+        if cursor_visible {
+            let w = self.config.width as f32;
+            let h = self.config.height as f32;
+            let cc = self.cursor.color;
+            let origin = (
+                self.grid.window_padding + self.grid.content_offset_x,
+                self.grid.window_padding + self.grid.content_offset_y,
+            );
+            let streak = self.cursor_smear_rects(origin);
+            for (slot, (rect, alpha)) in overlay_instances[CURSOR_SMEAR_FIRST_SLOT..]
+                .iter_mut()
+                .zip(streak)
+            {
+                *slot = BackgroundInstance {
+                    position: [rect.x / w * 2.0 - 1.0, 1.0 - (rect.y / h * 2.0)],
+                    size: [rect.width / w * 2.0, rect.height / h * 2.0],
+                    color: [cc[0], cc[1], cc[2], alpha * self.cursor.opacity],
+                    corner_radii: [0.0; 4],
                 };
             }
         }
@@ -426,6 +438,7 @@ impl CellRenderer {
                 position: [0.0, 0.0],
                 size: [0.0, 0.0],
                 color: [0.0, 0.0, 0.0, 0.0],
+                corner_radii: [0.0; 4],
             };
             self.grid.rows
         ];
@@ -448,6 +461,7 @@ impl CellRenderer {
                             self.separator.thickness / height_f * 2.0,
                         ],
                         color,
+                        corner_radii: [0.0; 4],
                     };
                 }
             }
@@ -466,6 +480,7 @@ impl CellRenderer {
                 position: [0.0, 0.0],
                 size: [0.0, 0.0],
                 color: [0.0, 0.0, 0.0, 0.0],
+                corner_radii: [0.0; 4],
             };
             self.grid.rows
         ];
@@ -487,6 +502,7 @@ impl CellRenderer {
                             self.grid.cell_height / height_f * 2.0,
                         ],
                         color,
+                        corner_radii: [0.0; 4],
                     };
                 }
             }
@@ -1,6 +1,8 @@
 use par_term_config::{color_u8_to_f32, color_u8x4_to_f32};
 
 use super::CellRenderer;
+use super::cursor_morph::CursorMorph;
+use super::cursor_smear::CursorSmear;
 
 /// Cursor position, style, colors, and visual enhancement settings.
 pub(crate) struct CursorState {
@@ -31,17 +33,47 @@ pub(crate) struct CursorState {
     pub(crate) boost_color: [f32; 3],
     /// Unfocused cursor style (hollow, same, hidden)
     pub(crate) unfocused_style: par_term_config::UnfocusedCursorStyle,
+    /// How long style changes morph (zero = instant switch)
+    pub(crate) morph_duration: std::time::Duration,
+    /// In-progress style morph, if any
+    pub(crate) morph: Option<CursorMorph>,
+    /// Eased progress of `morph` sampled for the current frame
+    pub(crate) morph_progress: f32,
+    /// Longest smear streak in cells (zero = no smear)
+    pub(crate) smear_length: f32,
+    /// How long a smear streak takes to fade out
+    pub(crate) smear_decay: std::time::Duration,
+    /// Streak left by the last cursor jump, if still visible
+    pub(crate) smear: Option<CursorSmear>,
+    /// Remaining opacity of `smear` sampled for the current frame
+    pub(crate) smear_fade: f32,
+    /// Widen the cursor to cover every cell of a ligature under it
+    pub(crate) ligature_width: bool,
+    /// Columns the cursor covers this frame (`pos.0` alone unless on a ligature)
+    pub(crate) cell_span: std::ops::Range<usize>,
 }
 
 impl CellRenderer {
     /// Update cursor position, opacity and style. Returns `true` if anything changed.
+    ///
+    /// A style change starts a shape morph when a morph duration is set, and
+    /// a jump starts a smear streak when a smear length is set; the cursor
+    /// keeps reporting a change every frame until both have finished.
     pub fn update_cursor(
         &mut self,
         pos: (usize, usize),
         opacity: f32,
         style: par_term_emu_core_rust::cursor::CursorStyle,
     ) -> bool {
-        if self.cursor.pos != pos || self.cursor.opacity != opacity || self.cursor.style != style {
+        let now = std::time::Instant::now();
+        let morphing = self.advance_cursor_morph(self.cursor.style, style, now);
+        let smearing = self.advance_cursor_smear(self.cursor.pos, pos, now);
+        if morphing
+            || smearing
+            || self.cursor.pos != pos
+            || self.cursor.opacity != opacity
+            || self.cursor.style != style
+        {
             self.dirty_rows[self.cursor.pos.1.min(self.grid.rows - 1)] = true;
             self.cursor.pos = pos;
             self.cursor.opacity = opacity;
@@ -58,10 +90,16 @@ impl CellRenderer {
         self.update_cursor(pos, 0.0, style)
     }
 
-    /// Update cursor color
-    pub fn update_cursor_color(&mut self, color: [u8; 3]) {
-        self.cursor.color = color_u8_to_f32(color);
-        self.dirty_rows[self.cursor.pos.1.min(self.grid.rows - 1)] = true;
+    /// Update cursor color.
+    /// Returns `true` if the value changed.
+    pub fn update_cursor_color(&mut self, color: [u8; 3]) -> bool {
+        let color = color_u8_to_f32(color);
+        if self.cursor.color != color {
+            self.cursor.color = color;
+            self.dirty_rows[self.cursor.pos.1.min(self.grid.rows - 1)] = true;
+            return true;
+        }
+        false
     }
 
     /// Update cursor text color (color of text under block cursor)
@@ -127,6 +165,29 @@ impl CellRenderer {
         }
     }
 
+    /// Enable or disable widening the cursor over ligatures
+    pub fn set_cursor_ligature_width(&mut self, enabled: bool) {
+        if self.cursor.ligature_width != enabled {
+            self.cursor.ligature_width = enabled;
+            self.dirty_rows[self.cursor.pos.1.min(self.grid.rows - 1)] = true;
+        }
+    }
+
+    /// Columns covered by a cursor at `col` of `row` (see `ligature_cursor`).
+    pub(crate) fn cursor_columns(
+        &mut self,
+        row: &[super::Cell],
+        col: usize,
+    ) -> std::ops::Range<usize> {
+        super::ligature_cursor::cursor_columns(
+            &mut self.font_manager,
+            self.cursor.ligature_width,
+            self.font.enable_ligatures,
+            row,
+            col,
+        )
+    }
+
     /// Update unfocused cursor style
     pub fn update_unfocused_cursor_style(&mut self, style: par_term_config::UnfocusedCursorStyle) {
         self.cursor.unfocused_style = style;
@@ -0,0 +1,262 @@
+//! Cursor shape morphing between styles.
+//!
+//! When an application switches cursor style (e.g. block → bar via DECSCUSR)
+//! the cursor is drawn as a rectangle interpolated between the old and new
+//! shapes for a short, configurable duration instead of switching instantly.
+//! Blink-only changes (steady ↔ blinking of the same shape) do not morph.
+//!
+//! [`CursorMorph`] tracks the transition; [`CellRenderer::update_cursor`]
+//! starts it and samples its progress once per frame, and the background
+//! builders draw [`CellRenderer::cursor_overlay_rect`] while it runs.
+
+use std::time::{Duration, Instant};
+
+use par_term_emu_core_rust::cursor::CursorStyle;
+
+use super::CellRenderer;
+
+/// Thickness of bar and underline cursors in pixels.
+pub(crate) const CURSOR_BAR_PX: f32 = 2.0;
+
+/// Cursor rectangle in pixels relative to the cursor cell's top-left corner.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct CursorRect {
+    pub(crate) x: f32,
+    pub(crate) y: f32,
+    pub(crate) width: f32,
+    pub(crate) height: f32,
+}
+
+impl CursorRect {
+    /// The rectangle `style` occupies in a `cell_width`×`cell_height` cell.
+    pub(crate) fn for_style(style: CursorStyle, cell_width: f32, cell_height: f32) -> Self {
+        match style {
+            CursorStyle::SteadyBlock | CursorStyle::BlinkingBlock => Self {
+                x: 0.0,
+                y: 0.0,
+                width: cell_width,
+                height: cell_height,
+            },
+            CursorStyle::SteadyBar | CursorStyle::BlinkingBar => Self {
+                x: 0.0,
+                y: 0.0,
+                width: CURSOR_BAR_PX,
+                height: cell_height,
+            },
+            CursorStyle::SteadyUnderline | CursorStyle::BlinkingUnderline => Self {
+                x: 0.0,
+                y: cell_height - CURSOR_BAR_PX,
+                width: cell_width,
+                height: CURSOR_BAR_PX,
+            },
+        }
+    }
+
+    /// Linear interpolation from `self` (t = 0) to `other` (t = 1).
+    pub(crate) fn lerp(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        Self {
+            x: mix(self.x, other.x),
+            y: mix(self.y, other.y),
+            width: mix(self.width, other.width),
+            height: mix(self.height, other.height),
+        }
+    }
+}
+
+/// Whether two styles draw the same shape (differing only in blinking).
+fn same_shape(a: CursorStyle, b: CursorStyle) -> bool {
+    // Any cell larger than the bar thickness tells the shapes apart.
+    CursorRect::for_style(a, 10.0, 20.0) == CursorRect::for_style(b, 10.0, 20.0)
+}
+
+/// An in-progress transition between two cursor styles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct CursorMorph {
+    pub(crate) from: CursorStyle,
+    pub(crate) to: CursorStyle,
+    pub(crate) started: Instant,
+    pub(crate) duration: Duration,
+}
+
+impl CursorMorph {
+    /// Start a morph for a style change, or `None` if no animation is needed.
+    pub(crate) fn start(
+        from: CursorStyle,
+        to: CursorStyle,
+        duration: Duration,
+        now: Instant,
+    ) -> Option<Self> {
+        (!duration.is_zero() && !same_shape(from, to)).then_some(Self {
+            from,
+            to,
+            started: now,
+            duration,
+        })
+    }
+
+    /// Eased progress in `[0.0, 1.0]` at `now`.
+    pub(crate) fn progress(&self, now: Instant) -> f32 {
+        let elapsed = now.saturating_duration_since(self.started).as_secs_f32();
+        let t = (elapsed / self.duration.as_secs_f32()).clamp(0.0, 1.0);
+        // Ease-out cubic: fast start, gentle settle on the new shape.
+        1.0 - (1.0 - t).powi(3)
+    }
+
+    /// Whether the morph has reached the target shape at `now`.
+    pub(crate) fn is_finished(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.started) >= self.duration
+    }
+
+    /// Interpolated cursor rectangle after eased progress `t`.
+    pub(crate) fn rect(&self, t: f32, cell_width: f32, cell_height: f32) -> CursorRect {
+        CursorRect::for_style(self.from, cell_width, cell_height)
+            .lerp(CursorRect::for_style(self.to, cell_width, cell_height), t)
+    }
+}
+
+impl CellRenderer {
+    /// Set how long cursor style changes morph (zero switches instantly).
+    pub fn set_cursor_morph_duration(&mut self, duration: Duration) {
+        self.cursor.morph_duration = duration;
+        if duration.is_zero() {
+            self.cursor.morph = None;
+        }
+    }
+
+    /// Whether a cursor style morph is in progress.
+    pub fn cursor_morph_active(&self) -> bool {
+        self.cursor.morph.is_some()
+    }
+
+    /// Start a morph for a style change and sample the active morph at `now`.
+    ///
+    /// Returns true while a morph is running, so the cursor is redrawn.
+    pub(crate) fn advance_cursor_morph(
+        &mut self,
+        old_style: CursorStyle,
+        new_style: CursorStyle,
+        now: Instant,
+    ) -> bool {
+        if old_style != new_style {
+            self.cursor.morph =
+                CursorMorph::start(old_style, new_style, self.cursor.morph_duration, now);
+        }
+        match self.cursor.morph {
+            Some(morph) if morph.is_finished(now) => {
+                self.cursor.morph = None;
+                // One more redraw to settle on the final shape.
+                true
+            }
+            Some(morph) => {
+                self.cursor.morph_progress = morph.progress(now);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether the cursor cell is filled as a solid block this frame.
+    pub(crate) fn cursor_fills_cell(&self) -> bool {
+        self.cursor.morph.is_none()
+            && matches!(
+                self.cursor.style,
+                CursorStyle::SteadyBlock | CursorStyle::BlinkingBlock
+            )
+    }
+
+    /// Cursor rectangle drawn on top of the text, relative to the cursor cell.
+    ///
+    /// The interpolated shape while morphing; otherwise the bar or underline,
+    /// or `None` for a block cursor (drawn as the cell background).
+    pub(crate) fn cursor_overlay_rect(&self) -> Option<CursorRect> {
+        // A cursor widened over a ligature spans several cells
+        let cell_width =
+            super::ligature_cursor::span_width(&self.cursor.cell_span, self.grid.cell_width);
+        let cell_height = self.grid.cell_height;
+        match self.cursor.morph {
+            Some(morph) => Some(morph.rect(self.cursor.morph_progress, cell_width, cell_height)),
+            None if self.cursor_fills_cell() => None,
+            None => Some(CursorRect::for_style(
+                self.cursor.style,
+                cell_width,
+                cell_height,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DURATION: Duration = Duration::from_millis(100);
+
+    fn morph(from: CursorStyle, to: CursorStyle) -> (CursorMorph, Instant) {
+        let now = Instant::now();
+        (CursorMorph::start(from, to, DURATION, now).unwrap(), now)
+    }
+
+    #[test]
+    fn block_to_bar_passes_through_intermediate_widths() {
+        let (morph, start) = morph(CursorStyle::SteadyBlock, CursorStyle::SteadyBar);
+
+        let mut last_width = f32::INFINITY;
+        for ms in [0, 25, 50, 75, 100] {
+            let t = morph.progress(start + Duration::from_millis(ms));
+            let rect = morph.rect(t, 10.0, 20.0);
+            assert_eq!(rect.height, 20.0);
+            assert!(rect.width <= last_width, "width grew at {ms}ms");
+            last_width = rect.width;
+        }
+
+        let mid = morph.rect(
+            morph.progress(start + Duration::from_millis(50)),
+            10.0,
+            20.0,
+        );
+        assert!(mid.width > CURSOR_BAR_PX && mid.width < 10.0, "{mid:?}");
+        assert_eq!(morph.rect(morph.progress(start), 10.0, 20.0).width, 10.0);
+        assert_eq!(morph.rect(1.0, 10.0, 20.0).width, CURSOR_BAR_PX);
+    }
+
+    #[test]
+    fn bar_to_underline_moves_and_resizes_both_axes() {
+        let (morph, start) = morph(CursorStyle::BlinkingBar, CursorStyle::SteadyUnderline);
+        let t = morph.progress(start + Duration::from_millis(40));
+        let rect = morph.rect(t, 10.0, 20.0);
+
+        assert!(rect.y > 0.0 && rect.y < 18.0, "{rect:?}");
+        assert!(rect.width > CURSOR_BAR_PX && rect.width < 10.0, "{rect:?}");
+        assert!(
+            rect.height > CURSOR_BAR_PX && rect.height < 20.0,
+            "{rect:?}"
+        );
+        assert!(morph.is_finished(start + DURATION));
+        assert!(!morph.is_finished(start + Duration::from_millis(99)));
+    }
+
+    #[test]
+    fn blink_only_changes_and_zero_duration_do_not_morph() {
+        let now = Instant::now();
+        assert!(
+            CursorMorph::start(
+                CursorStyle::SteadyBlock,
+                CursorStyle::BlinkingBlock,
+                DURATION,
+                now
+            )
+            .is_none()
+        );
+        assert!(
+            CursorMorph::start(
+                CursorStyle::SteadyBlock,
+                CursorStyle::SteadyBar,
+                Duration::ZERO,
+                now
+            )
+            .is_none()
+        );
+    }
+}
@@ -0,0 +1,248 @@
+//! Cursor smear: a fading streak along the cursor's path when it jumps.
+//!
+//! When the cursor moves at least [`SMEAR_MIN_DISTANCE_CELLS`], copies of the
+//! cursor shape are drawn along the movement vector between the old and new
+//! positions, getting fainter and smaller towards the old position.  The
+//! streak covers at most the configured length (in cells) behind the cursor
+//! and fades out over the configured decay time.  Typing moves the cursor one
+//! cell at a time, so it never smears.
+//!
+//! [`CursorSmear`] tracks the streak; [`CellRenderer::update_cursor`] starts it
+//! and [`CellRenderer::cursor_smear_rects`] samples it for the background
+//! builders each frame.
+
+use std::time::{Duration, Instant};
+
+use super::CellRenderer;
+use super::cursor_morph::CursorRect;
+
+/// Shortest cursor move, in cells, that leaves a streak.
+pub(crate) const SMEAR_MIN_DISTANCE_CELLS: f32 = 2.0;
+
+/// Most rectangles drawn for one streak.
+pub(crate) const MAX_SMEAR_SEGMENTS: usize = 8;
+
+/// Distance in cells between neighbouring streak rectangles.
+const SMEAR_SEGMENT_SPACING_CELLS: f32 = 0.5;
+
+/// Opacity of the streak rectangle next to the cursor when the streak starts.
+const SMEAR_MAX_ALPHA: f32 = 0.6;
+
+/// One rectangle of a streak.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct SmearSegment {
+    /// Column of the rectangle's cell (fractional between cells)
+    pub(crate) col: f32,
+    /// Row of the rectangle's cell (fractional between cells)
+    pub(crate) row: f32,
+    /// Size relative to the cursor shape, scaled around its center
+    pub(crate) scale: f32,
+    /// Opacity multiplier for the cursor color
+    pub(crate) alpha: f32,
+}
+
+/// Rectangles of a streak from cell `from` to cell `to`, tail first.
+///
+/// The streak is cut to `max_length` cells behind `to` and every rectangle's
+/// opacity is multiplied by `fade`.  The cursor cell itself is not included.
+pub(crate) fn smear_segments(
+    from: (f32, f32),
+    to: (f32, f32),
+    max_length: f32,
+    fade: f32,
+) -> Vec<SmearSegment> {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let distance = dx.hypot(dy);
+    if distance < SMEAR_MIN_DISTANCE_CELLS || max_length <= 0.0 || fade <= 0.0 {
+        return Vec::new();
+    }
+
+    let length = distance.min(max_length);
+    let tail = (to.0 - dx / distance * length, to.1 - dy / distance * length);
+    let count =
+        ((length / SMEAR_SEGMENT_SPACING_CELLS).ceil() as usize).clamp(1, MAX_SMEAR_SEGMENTS);
+    (0..count)
+        .map(|i| {
+            let t = i as f32 / count as f32;
+            // Weight grows from the tail towards the cursor, never reaching 1.0
+            let weight = (i + 1) as f32 / (count + 1) as f32;
+            SmearSegment {
+                col: tail.0 + (to.0 - tail.0) * t,
+                row: tail.1 + (to.1 - tail.1) * t,
+                scale: 0.5 + 0.5 * weight,
+                alpha: SMEAR_MAX_ALPHA * weight * fade.min(1.0),
+            }
+        })
+        .collect()
+}
+
+/// A fading streak left by a cursor jump.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct CursorSmear {
+    pub(crate) from: (usize, usize),
+    pub(crate) to: (usize, usize),
+    pub(crate) started: Instant,
+}
+
+impl CursorSmear {
+    /// Remaining opacity in `[0.0, 1.0]` at `now`, fading linearly over `decay`.
+    pub(crate) fn fade(&self, decay: Duration, now: Instant) -> f32 {
+        if decay.is_zero() {
+            return 0.0;
+        }
+        let elapsed = now.saturating_duration_since(self.started).as_secs_f32();
+        (1.0 - elapsed / decay.as_secs_f32()).clamp(0.0, 1.0)
+    }
+}
+
+impl CellRenderer {
+    /// Set the streak length in cells (zero disables the smear) and how long
+    /// it takes to fade.
+    pub fn set_cursor_smear(&mut self, length: f32, decay: Duration) {
+        self.cursor.smear_length = length.max(0.0);
+        self.cursor.smear_decay = decay;
+        if self.cursor.smear_length == 0.0 || decay.is_zero() {
+            self.cursor.smear = None;
+        }
+    }
+
+    /// Whether a cursor streak is still fading out.
+    pub fn cursor_smear_active(&self) -> bool {
+        self.cursor.smear.is_some()
+    }
+
+    /// Start a streak for a cursor move and drop a streak that has faded.
+    ///
+    /// Returns true while a streak is visible, so the cursor is redrawn.
+    pub(crate) fn advance_cursor_smear(
+        &mut self,
+        old_pos: (usize, usize),
+        new_pos: (usize, usize),
+        now: Instant,
+    ) -> bool {
+        if old_pos != new_pos
+            && self.cursor.smear_length > 0.0
+            && !self.cursor.smear_decay.is_zero()
+        {
+            self.cursor.smear = Some(CursorSmear {
+                from: old_pos,
+                to: new_pos,
+                started: now,
+            });
+        }
+        match self.cursor.smear {
+            Some(smear) if smear.fade(self.cursor.smear_decay, now) > 0.0 => {
+                self.cursor.smear_fade = smear.fade(self.cursor.smear_decay, now);
+                true
+            }
+            Some(_) => {
+                self.cursor.smear = None;
+                // One more redraw to clear the streak.
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Streak rectangles to draw this frame, tail first, with their opacity.
+    ///
+    /// Rectangles are in window pixels for a grid whose cell (0, 0) has its
+    /// top-left corner at `origin`.
+    pub(crate) fn cursor_smear_rects(&self, origin: (f32, f32)) -> Vec<(CursorRect, f32)> {
+        let Some(smear) = self.cursor.smear else {
+            return Vec::new();
+        };
+        let (cell_width, cell_height) = (self.grid.cell_width, self.grid.cell_height);
+        let shape = CursorRect::for_style(self.cursor.style, cell_width, cell_height);
+        let cell = |(col, row): (usize, usize)| (col as f32, row as f32);
+        smear_segments(
+            cell(smear.from),
+            cell(smear.to),
+            self.cursor.smear_length,
+            self.cursor.smear_fade,
+        )
+        .into_iter()
+        .map(|segment| {
+            let width = shape.width * segment.scale;
+            let height = shape.height * segment.scale;
+            let rect = CursorRect {
+                x: origin.0 + segment.col * cell_width + shape.x + (shape.width - width) / 2.0,
+                y: origin.1 + segment.row * cell_height + shape.y + (shape.height - height) / 2.0,
+                width,
+                height,
+            };
+            (rect, segment.alpha)
+        })
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn streak_follows_the_movement_vector() {
+        // A horizontal jump of 4 cells with room for all of it
+        let segments = smear_segments((2.0, 5.0), (6.0, 5.0), 10.0, 1.0);
+        assert_eq!(segments.len(), MAX_SMEAR_SEGMENTS);
+        assert!(close(segments[0].col, 2.0), "starts at the old position");
+        assert!(segments.iter().all(|s| close(s.row, 5.0)));
+        assert!(segments.iter().all(|s| s.col < 6.0), "cursor cell excluded");
+        for pair in segments.windows(2) {
+            assert!(close(pair[1].col - pair[0].col, 0.5));
+            assert!(pair[1].alpha > pair[0].alpha, "fainter towards the tail");
+            assert!(pair[1].scale > pair[0].scale, "smaller towards the tail");
+        }
+        assert!(segments.last().unwrap().alpha < SMEAR_MAX_ALPHA);
+
+        // A diagonal jump moves both axes in proportion
+        let segments = smear_segments((0.0, 0.0), (3.0, 4.0), 10.0, 1.0);
+        for s in &segments {
+            assert!(close(s.col * 4.0, s.row * 3.0), "{s:?}");
+        }
+        assert!(close(segments[0].col, 0.0) && close(segments[0].row, 0.0));
+    }
+
+    #[test]
+    fn streak_is_cut_to_the_maximum_length() {
+        // Jumping up 20 rows with a 2-cell streak: only the last 2 rows smear
+        let segments = smear_segments((4.0, 30.0), (4.0, 10.0), 2.0, 1.0);
+        assert_eq!(segments.len(), 4);
+        assert!(close(segments[0].row, 12.0));
+        assert!(segments.iter().all(|s| s.row > 10.0 && close(s.col, 4.0)));
+    }
+
+    #[test]
+    fn short_moves_and_faded_streaks_draw_nothing() {
+        // Typing: one cell at a time
+        assert!(smear_segments((4.0, 1.0), (5.0, 1.0), 10.0, 1.0).is_empty());
+        assert!(smear_segments((0.0, 0.0), (5.0, 0.0), 0.0, 1.0).is_empty());
+        assert!(smear_segments((0.0, 0.0), (5.0, 0.0), 10.0, 0.0).is_empty());
+
+        let half = smear_segments((0.0, 0.0), (5.0, 0.0), 10.0, 0.5);
+        let full = smear_segments((0.0, 0.0), (5.0, 0.0), 10.0, 1.0);
+        for (h, f) in half.iter().zip(&full) {
+            assert!(close(h.alpha * 2.0, f.alpha));
+        }
+
+        let start = Instant::now();
+        let smear = CursorSmear {
+            from: (0, 0),
+            to: (5, 0),
+            started: start,
+        };
+        let decay = Duration::from_millis(100);
+        assert!(close(smear.fade(decay, start), 1.0));
+        assert!(close(
+            smear.fade(decay, start + Duration::from_millis(25)),
+            0.75
+        ));
+        assert_eq!(smear.fade(decay, start + decay), 0.0);
+        assert_eq!(smear.fade(Duration::ZERO, start), 0.0);
+    }
+}
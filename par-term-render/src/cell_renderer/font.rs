@@ -1,4 +1,5 @@
 use super::CellRenderer;
+use super::font_rendering::FontRenderingOptions;
 
 /// Font configuration (base values), scaled metrics, shaping, and rendering options.
 pub(crate) struct FontState {
@@ -18,7 +19,7 @@ pub(crate) struct FontState {
     // so the renderer can pass them down when a future direct-shaping path is added.
     #[allow(dead_code)] // Config stored for future direct text shaping pipeline integration
     pub(crate) enable_text_shaping: bool,
-    #[allow(dead_code)] // Config stored for future direct text shaping pipeline integration
+    /// Also gates the ligature-aware cursor width (`ligature_cursor`).
     pub(crate) enable_ligatures: bool,
     #[allow(dead_code)] // Config stored for future direct text shaping pipeline integration
     pub(crate) enable_kerning: bool,
@@ -45,40 +46,28 @@ impl CellRenderer {
     /// Update font anti-aliasing setting.
     /// Returns true if the setting changed (requiring glyph cache clear).
     pub fn update_font_antialias(&mut self, enabled: bool) -> bool {
-        if self.font.font_antialias != enabled {
-            self.font.font_antialias = enabled;
-            self.clear_glyph_cache();
-            self.dirty_rows.fill(true);
-            true
-        } else {
-            false
-        }
+        self.update_font_rendering(FontRenderingOptions {
+            antialias: enabled,
+            ..self.font_rendering_options()
+        })
     }
 
     /// Update font hinting setting.
     /// Returns true if the setting changed (requiring glyph cache clear).
     pub fn update_font_hinting(&mut self, enabled: bool) -> bool {
-        if self.font.font_hinting != enabled {
-            self.font.font_hinting = enabled;
-            self.clear_glyph_cache();
-            self.dirty_rows.fill(true);
-            true
-        } else {
-            false
-        }
+        self.update_font_rendering(FontRenderingOptions {
+            hinting: enabled,
+            ..self.font_rendering_options()
+        })
     }
 
     /// Update thin strokes mode.
     /// Returns true if the setting changed (requiring glyph cache clear).
     pub fn update_font_thin_strokes(&mut self, mode: par_term_config::ThinStrokesMode) -> bool {
-        if self.font.font_thin_strokes != mode {
-            self.font.font_thin_strokes = mode;
-            self.clear_glyph_cache();
-            self.dirty_rows.fill(true);
-            true
-        } else {
-            false
-        }
+        self.update_font_rendering(FontRenderingOptions {
+            thin_strokes: mode,
+            ..self.font_rendering_options()
+        })
     }
 
     /// Update minimum contrast value.
@@ -0,0 +1,265 @@
+//! Live switching of glyph rasterization options.
+//!
+//! Anti-aliasing, hinting and thin-strokes mode are baked into every glyph in
+//! the atlas, so changing any of them invalidates the atlas.  The font metrics
+//! are recomputed from the primary font at the same time, and the grid is
+//! recalculated if the cell size no longer matches.
+//!
+//! [`plan_font_rendering_update`] decides what a change requires; the
+//! GPU-side work (clearing the atlas, resizing the grid) happens in
+//! [`CellRenderer::update_font_rendering`].
+
+use super::CellRenderer;
+use par_term_config::ThinStrokesMode;
+
+/// Glyph rasterization options that can be switched at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FontRenderingOptions {
+    /// Anti-aliased (grayscale) glyph edges instead of 1-bit masks
+    pub antialias: bool,
+    /// Grid-fit glyph outlines to whole pixels
+    pub hinting: bool,
+    /// Thin strokes mode
+    pub thin_strokes: ThinStrokesMode,
+}
+
+/// Unscaled-by-spacing metrics of the primary font at the current pixel size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct RawFontMetrics {
+    pub(crate) ascent: f32,
+    pub(crate) descent: f32,
+    pub(crate) leading: f32,
+    pub(crate) char_advance: f32,
+}
+
+/// Font metrics and cell size derived from [`RawFontMetrics`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct CellMetrics {
+    pub(crate) ascent: f32,
+    pub(crate) descent: f32,
+    pub(crate) leading: f32,
+    pub(crate) char_advance: f32,
+    pub(crate) cell_width: f32,
+    pub(crate) cell_height: f32,
+}
+
+impl CellMetrics {
+    /// Derive cell metrics from the raw font metrics and spacing multipliers.
+    ///
+    /// Round to integer pixels so every cell has the same width in device
+    /// pixels.  Without rounding, per-column scale_x alternates (e.g. 7/7.8 vs
+    /// 8/7.8), causing glyphs to sample the atlas at slightly different rates
+    /// and appear at different perceived brightnesses.
+    pub(crate) fn compute(raw: RawFontMetrics, line_spacing: f32, char_spacing: f32) -> Self {
+        let natural_line_height = raw.ascent + raw.descent + raw.leading;
+        Self {
+            ascent: raw.ascent,
+            descent: raw.descent,
+            leading: raw.leading,
+            char_advance: raw.char_advance,
+            cell_width: (raw.char_advance * char_spacing).max(1.0).round(),
+            cell_height: (natural_line_height * line_spacing).max(1.0).round(),
+        }
+    }
+}
+
+/// What applying new [`FontRenderingOptions`] requires.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct FontRenderingPlan {
+    /// Cached glyphs were rasterized with different options and must be dropped.
+    pub(crate) invalidate_atlas: bool,
+    /// Freshly computed metrics (`None` when nothing changed).
+    pub(crate) metrics: Option<CellMetrics>,
+    /// The recomputed cell size differs from the current one.
+    pub(crate) resize_grid: bool,
+}
+
+/// Decide how to move from `current` to `next` options.
+pub(crate) fn plan_font_rendering_update(
+    current: FontRenderingOptions,
+    next: FontRenderingOptions,
+    current_metrics: CellMetrics,
+    raw: RawFontMetrics,
+    line_spacing: f32,
+    char_spacing: f32,
+) -> FontRenderingPlan {
+    if current == next {
+        return FontRenderingPlan {
+            invalidate_atlas: false,
+            metrics: None,
+            resize_grid: false,
+        };
+    }
+    let metrics = CellMetrics::compute(raw, line_spacing, char_spacing);
+    FontRenderingPlan {
+        invalidate_atlas: true,
+        metrics: Some(metrics),
+        resize_grid: metrics.cell_width != current_metrics.cell_width
+            || metrics.cell_height != current_metrics.cell_height,
+    }
+}
+
+impl CellRenderer {
+    /// Current glyph rasterization options.
+    pub fn font_rendering_options(&self) -> FontRenderingOptions {
+        FontRenderingOptions {
+            antialias: self.font.font_antialias,
+            hinting: self.font.font_hinting,
+            thin_strokes: self.font.font_thin_strokes,
+        }
+    }
+
+    /// Metrics of the primary font at the current pixel size.
+    pub(crate) fn raw_font_metrics(&self) -> RawFontMetrics {
+        let primary_font = self
+            .font_manager
+            .get_font(0)
+            .expect("Primary font at index 0 must exist in FontManager");
+        let coords = self.font_manager.font_coords(0);
+        let metrics = primary_font.metrics(coords);
+        let scale = self.font.font_size_pixels / metrics.units_per_em as f32;
+        let glyph_id = primary_font.charmap().map('m');
+        RawFontMetrics {
+            ascent: metrics.ascent * scale,
+            descent: metrics.descent * scale,
+            leading: metrics.leading * scale,
+            char_advance: primary_font.glyph_metrics(coords).advance_width(glyph_id) * scale,
+        }
+    }
+
+    fn current_cell_metrics(&self) -> CellMetrics {
+        CellMetrics {
+            ascent: self.font.font_ascent,
+            descent: self.font.font_descent,
+            leading: self.font.font_leading,
+            char_advance: self.font.char_advance,
+            cell_width: self.grid.cell_width,
+            cell_height: self.grid.cell_height,
+        }
+    }
+
+    /// Store `metrics` as the current font metrics and cell size.
+    pub(crate) fn apply_cell_metrics(&mut self, metrics: CellMetrics) {
+        self.font.font_ascent = metrics.ascent;
+        self.font.font_descent = metrics.descent;
+        self.font.font_leading = metrics.leading;
+        self.font.char_advance = metrics.char_advance;
+        self.grid.cell_width = metrics.cell_width;
+        self.grid.cell_height = metrics.cell_height;
+    }
+
+    /// Switch anti-aliasing, hinting and thin strokes in one step.
+    ///
+    /// Clears the glyph atlas once so every glyph is re-rasterized with the
+    /// new options, and recomputes the font metrics; when the cell size
+    /// changes the grid is recalculated for the current surface size.
+    /// Returns true if any option changed.
+    pub fn update_font_rendering(&mut self, options: FontRenderingOptions) -> bool {
+        let plan = plan_font_rendering_update(
+            self.font_rendering_options(),
+            options,
+            self.current_cell_metrics(),
+            self.raw_font_metrics(),
+            self.font.line_spacing,
+            self.font.char_spacing,
+        );
+        if !plan.invalidate_atlas {
+            return false;
+        }
+
+        self.font.font_antialias = options.antialias;
+        self.font.font_hinting = options.hinting;
+        self.font.font_thin_strokes = options.thin_strokes;
+        self.clear_glyph_cache();
+
+        if let Some(metrics) = plan.metrics {
+            if plan.resize_grid {
+                log::info!(
+                    "Cell size changed with font rendering options: {}x{} -> {}x{}",
+                    self.grid.cell_width,
+                    self.grid.cell_height,
+                    metrics.cell_width,
+                    metrics.cell_height
+                );
+            }
+            self.apply_cell_metrics(metrics);
+            if plan.resize_grid {
+                let (width, height) = (self.config.width, self.config.height);
+                self.resize(width, height);
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RAW: RawFontMetrics = RawFontMetrics {
+        ascent: 11.4,
+        descent: 3.2,
+        leading: 0.0,
+        char_advance: 8.4,
+    };
+
+    fn options(antialias: bool, hinting: bool) -> FontRenderingOptions {
+        FontRenderingOptions {
+            antialias,
+            hinting,
+            thin_strokes: ThinStrokesMode::RetinaOnly,
+        }
+    }
+
+    fn plan(
+        current: FontRenderingOptions,
+        next: FontRenderingOptions,
+        current_metrics: CellMetrics,
+    ) -> FontRenderingPlan {
+        plan_font_rendering_update(current, next, current_metrics, RAW, 1.0, 1.0)
+    }
+
+    #[test]
+    fn unchanged_options_keep_atlas_and_metrics() {
+        let metrics = CellMetrics::compute(RAW, 1.0, 1.0);
+        let plan = plan(options(true, true), options(true, true), metrics);
+        assert!(!plan.invalidate_atlas);
+        assert_eq!(plan.metrics, None);
+        assert!(!plan.resize_grid);
+    }
+
+    #[test]
+    fn any_option_change_invalidates_atlas_and_recomputes_metrics() {
+        let metrics = CellMetrics::compute(RAW, 1.0, 1.0);
+        let thin = FontRenderingOptions {
+            thin_strokes: ThinStrokesMode::Always,
+            ..options(true, true)
+        };
+        for next in [options(false, true), options(true, false), thin] {
+            let plan = plan(options(true, true), next, metrics);
+            assert!(plan.invalidate_atlas, "{next:?}");
+            assert_eq!(plan.metrics, Some(metrics), "{next:?}");
+            assert!(!plan.resize_grid, "{next:?}");
+        }
+    }
+
+    #[test]
+    fn stale_metrics_are_replaced_and_grid_resized() {
+        // Metrics left over from a different pixel size.
+        let stale = CellMetrics::compute(
+            RawFontMetrics {
+                ascent: 9.0,
+                descent: 2.5,
+                leading: 0.0,
+                char_advance: 7.0,
+            },
+            1.0,
+            1.0,
+        );
+        let plan = plan(options(true, true), options(false, true), stale);
+        assert!(plan.invalidate_atlas);
+        let metrics = plan.metrics.expect("metrics recomputed");
+        assert_eq!((metrics.cell_width, metrics.cell_height), (8.0, 15.0));
+        assert!(plan.resize_grid);
+    }
+}
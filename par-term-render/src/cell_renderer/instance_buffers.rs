@@ -2,9 +2,14 @@ use super::{BackgroundInstance, CellRenderer, TextInstance};
 use anyhow::Result;
 
 /// Number of extra background instance slots reserved for cursor overlays
-/// (beam/underline, guide line, shadow, boost glow, hollow outline sides).
-/// Layout: [0] cursor overlay, [1] guide, [2] shadow, [3] boost glow, [4-7] hollow outline.
-pub(crate) const CURSOR_OVERLAY_SLOTS: usize = 10;
+/// (beam/underline, guide line, shadow, boost glow, hollow outline sides, smear).
+/// Layout: [0] cursor overlay, [1] guide, [2] shadow, [3] boost glow, [4-7] hollow outline,
+/// [8-9] reserved, [10..] smear streak.
+pub(crate) const CURSOR_OVERLAY_SLOTS: usize =
+    CURSOR_SMEAR_FIRST_SLOT + super::cursor_smear::MAX_SMEAR_SEGMENTS;
+
+/// First cursor overlay slot used by the smear streak.
+pub(crate) const CURSOR_SMEAR_FIRST_SLOT: usize = 10;
 
 /// Width of gutter indicator bars in terminal cell columns.
 /// Each gutter indicator occupies this many cell-widths on the left side.
@@ -91,6 +96,16 @@ impl CellRenderer {
     /// incrementally. After processing all rows, cursor overlay, separator, and gutter
     /// instances are built and uploaded in a single write per region.
     pub(crate) fn build_instance_buffers(&mut self) -> Result<()> {
+        // Columns covered by the cursor (the whole ligature when widened)
+        let (cursor_col, cursor_row) = self.cursor.pos;
+        self.cursor.cell_span = if self.cursor.ligature_width && cursor_row < self.grid.rows {
+            let start = cursor_row * self.grid.cols;
+            let row_cells = self.cells[start..start + self.grid.cols].to_vec();
+            self.cursor_columns(&row_cells, cursor_col)
+        } else {
+            cursor_col..cursor_col + 1
+        };
+
         for row in 0..self.grid.rows {
             if self.dirty_rows[row] || self.row_cache[row].is_none() {
                 let start = row * self.grid.cols;
@@ -107,6 +122,7 @@ impl CellRenderer {
 
                 self.scratch_row_bg.clear();
                 self.scratch_row_text.clear();
+                self.scratch_text_shadows.clear();
 
                 // --- Background instances (RLE-merged) ---
                 self.build_row_bg_instances(row, &row_cells);
@@ -119,15 +135,20 @@ impl CellRenderer {
                 self.bg_instances[bg_start..bg_start + self.grid.cols]
                     .copy_from_slice(&self.scratch_row_bg);
 
-                let text_start = row * self.grid.cols * 2;
-                // Clear row text segment first
-                for i in 0..(self.grid.cols * 2) {
-                    self.text_instances[text_start + i].size = [0.0, 0.0];
-                }
-                // Copy new text instances
-                let text_count = self.scratch_row_text.len().min(self.grid.cols * 2);
-                self.text_instances[text_start..text_start + text_count]
-                    .copy_from_slice(&self.scratch_row_text[..text_count]);
+                // Text layout: every row's shadow slot, then every row's glyph
+                // slot, so no shadow is drawn over a glyph.
+                let row_shadows = self.grid.cols * self.text_shadow_instances_per_cell();
+                let row_text = self.grid.cols * TEXT_INSTANCES_PER_CELL;
+                let shadows = std::mem::take(&mut self.scratch_text_shadows);
+                self.upload_text_slot(row * row_shadows, row_shadows, &shadows);
+                self.scratch_text_shadows = shadows;
+                let text = std::mem::take(&mut self.scratch_row_text);
+                self.upload_text_slot(
+                    self.grid.rows * row_shadows + row * row_text,
+                    row_text,
+                    &text,
+                );
+                self.scratch_row_text = text;
 
                 // Update GPU-side buffers incrementally
                 self.queue.write_buffer(
@@ -135,13 +156,6 @@ impl CellRenderer {
                     (bg_start * std::mem::size_of::<BackgroundInstance>()) as u64,
                     bytemuck::cast_slice(&self.scratch_row_bg),
                 );
-                self.queue.write_buffer(
-                    &self.buffers.text_instance_buffer,
-                    (text_start * std::mem::size_of::<TextInstance>()) as u64,
-                    bytemuck::cast_slice(
-                        &self.text_instances[text_start..text_start + self.grid.cols * 2],
-                    ),
-                );
 
                 self.row_cache[row] = Some(true);
                 self.dirty_rows[row] = false;
@@ -154,7 +168,7 @@ impl CellRenderer {
         // --- Cursor overlay instances ---
         // Write cursor-related overlays to extra slots at the end of bg_instances.
         // Slot layout: [0] cursor overlay (beam/underline), [1] guide, [2] shadow,
-        //              [3] boost glow, [4-7] hollow outline.
+        //              [3] boost glow, [4-7] hollow outline, [10..] smear streak.
         let base_overlay_index = self.grid.cols * self.grid.rows;
         let overlay_instances = self.build_cursor_overlay_instances();
 
@@ -220,8 +234,27 @@ impl CellRenderer {
             + self.grid.rows
             + self.grid.rows;
         self.buffers.actual_text_instances =
-            self.grid.cols * self.grid.rows * TEXT_INSTANCES_PER_CELL;
+            self.grid.cols * self.grid.rows * self.text_instances_per_cell();
 
         Ok(())
     }
+
+    /// Replace the `len` text instances at `start` with `instances` (blanking the
+    /// rest of the slot) and upload the slot to the GPU.
+    fn upload_text_slot(&mut self, start: usize, len: usize, instances: &[TextInstance]) {
+        if len == 0 {
+            return;
+        }
+        let slot = &mut self.text_instances[start..start + len];
+        for instance in slot.iter_mut() {
+            instance.size = [0.0, 0.0];
+        }
+        let count = instances.len().min(len);
+        slot[..count].copy_from_slice(&instances[..count]);
+        self.queue.write_buffer(
+            &self.buffers.text_instance_buffer,
+            (start * std::mem::size_of::<TextInstance>()) as u64,
+            bytemuck::cast_slice(&self.text_instances[start..start + len]),
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use super::font_rendering::CellMetrics;
 use super::{BackgroundInstance, Cell, CellRenderer, RowCacheEntry, TextInstance, pipeline};
 
 /// Terminal grid dimensions, cell sizes, padding, and content offsets.
@@ -163,7 +164,8 @@ impl CellRenderer {
     pub(crate) fn recreate_instance_buffers(&mut self) {
         self.buffers.max_bg_instances =
             self.grid.cols * self.grid.rows + 10 + self.grid.rows + self.grid.rows; // Extra slots for cursor overlays + separator lines + gutter indicators
-        self.buffers.max_text_instances = self.grid.cols * self.grid.rows * 2;
+        self.buffers.max_text_instances =
+            self.grid.cols * self.grid.rows * self.text_instances_per_cell();
         let (bg_buf, text_buf) = pipeline::create_instance_buffers(
             &self.device,
             self.buffers.max_bg_instances,
@@ -180,6 +182,7 @@ impl CellRenderer {
                 position: [0.0, 0.0],
                 size: [0.0, 0.0],
                 color: [0.0, 0.0, 0.0, 0.0],
+                corner_radii: [0.0; 4],
             };
             self.buffers.max_bg_instances
         ];
@@ -191,6 +194,7 @@ impl CellRenderer {
                 tex_size: [0.0, 0.0],
                 color: [0.0, 0.0, 0.0, 0.0],
                 is_colored: 0,
+                atlas_page: 0,
             };
             self.buffers.max_text_instances
         ];
@@ -201,8 +205,9 @@ impl CellRenderer {
                 .cols
                 .saturating_sub(self.scratch_row_bg.capacity()),
         );
+        let row_text = self.grid.cols * self.text_instances_per_cell();
         self.scratch_row_text
-            .reserve((self.grid.cols * 2).saturating_sub(self.scratch_row_text.capacity()));
+            .reserve(row_text.saturating_sub(self.scratch_row_text.capacity()));
     }
 
     /// Update scale factor and recalculate all font metrics and cell dimensions.
@@ -233,34 +238,13 @@ impl CellRenderer {
             self.font.base_font_size * platform_dpi / crate::cell_renderer::FONT_REFERENCE_DPI;
         self.font.font_size_pixels = (base_font_pixels * new_scale).max(1.0);
 
-        // Re-extract font metrics at new scale
-        let (font_ascent, font_descent, font_leading, char_advance) = {
-            let primary_font = self.font_manager.get_font(0).expect(
-                "Primary font at index 0 must exist in FontManager when updating scale factor",
-            );
-            let metrics = primary_font.metrics(&[]);
-            let scale = self.font.font_size_pixels / metrics.units_per_em as f32;
-            let glyph_id = primary_font.charmap().map('m');
-            let advance = primary_font.glyph_metrics(&[]).advance_width(glyph_id) * scale;
-            (
-                metrics.ascent * scale,
-                metrics.descent * scale,
-                metrics.leading * scale,
-                advance,
-            )
-        };
-
-        self.font.font_ascent = font_ascent;
-        self.font.font_descent = font_descent;
-        self.font.font_leading = font_leading;
-        self.font.char_advance = char_advance;
-
-        // Recalculate cell dimensions (rounded to integer pixels for uniform glyph brightness)
-        let natural_line_height = font_ascent + font_descent + font_leading;
-        self.grid.cell_height = (natural_line_height * self.font.line_spacing)
-            .max(1.0)
-            .round();
-        self.grid.cell_width = (char_advance * self.font.char_spacing).max(1.0).round();
+        // Re-extract font metrics at new scale and recalculate cell dimensions
+        let metrics = CellMetrics::compute(
+            self.raw_font_metrics(),
+            self.font.line_spacing,
+            self.font.char_spacing,
+        );
+        self.apply_cell_metrics(metrics);
 
         log::info!(
             "New cell dimensions: {}x{} (font_size_pixels: {})",
@@ -0,0 +1,260 @@
+//! Ligature-aware cursor width.
+//!
+//! With `cursor_ligature_width` enabled, a cursor on either cell of a
+//! multi-character ligature (e.g. `=>`, `!=`) covers every cell of the
+//! ligature.  The word under the cursor is shaped with the primary font, or
+//! with the ligature fallback font for sequences only it ligates, and the
+//! glyph cluster containing the cursor cell gives the covered columns.
+
+use std::ops::Range;
+
+use par_term_fonts::font_manager::FontManager;
+use par_term_fonts::text_shaper::{ShapedSegment, ShapingOptions};
+
+use super::Cell;
+
+/// Cells shaped on each side of the cursor; ligatures are far shorter.
+const MAX_RUN_CELLS: usize = 16;
+
+/// Text of the cells around the cursor, as passed to the shaper.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CursorRun {
+    /// Column of the first cell in `text`
+    pub(crate) start: usize,
+    /// Concatenated graphemes of the run
+    pub(crate) text: String,
+    /// Byte offset in `text` where each cell starts
+    pub(crate) offsets: Vec<usize>,
+}
+
+impl CursorRun {
+    /// The run of non-blank, single-width cells containing column `col`.
+    ///
+    /// `None` when the cursor cell is blank or wide (nothing to ligate).
+    pub(crate) fn around(row: &[Cell], col: usize) -> Option<Self> {
+        let shapeable = |cell: &Cell| {
+            !cell.wide_char && !cell.wide_char_spacer && !cell.grapheme.trim().is_empty()
+        };
+        if !row.get(col).is_some_and(shapeable) {
+            return None;
+        }
+
+        let mut start = col;
+        while start > 0 && col - start < MAX_RUN_CELLS && shapeable(&row[start - 1]) {
+            start -= 1;
+        }
+        let mut end = col + 1;
+        while end < row.len() && end - col <= MAX_RUN_CELLS && shapeable(&row[end]) {
+            end += 1;
+        }
+
+        let mut text = String::new();
+        let mut offsets = Vec::with_capacity(end - start);
+        for cell in &row[start..end] {
+            offsets.push(text.len());
+            text.push_str(&cell.grapheme);
+        }
+        Some(Self {
+            start,
+            text,
+            offsets,
+        })
+    }
+
+    /// Columns covered by the glyph cluster containing column `col`.
+    ///
+    /// Cluster values are byte offsets into each segment's part of `text`; a
+    /// ligature is one glyph whose cluster starts at its first character.
+    pub(crate) fn cluster_columns(&self, shaped: &[ShapedSegment], col: usize) -> Range<usize> {
+        let single = col..col + 1;
+        let Some(&offset) = col
+            .checked_sub(self.start)
+            .and_then(|i| self.offsets.get(i))
+        else {
+            return single;
+        };
+
+        let mut clusters: Vec<usize> = shaped
+            .iter()
+            .flat_map(|segment| {
+                let start = segment.range.start;
+                segment
+                    .run
+                    .glyphs
+                    .iter()
+                    .map(move |g| start + g.cluster as usize)
+            })
+            .collect();
+        clusters.sort_unstable();
+        clusters.dedup();
+
+        let Some(&cluster_start) = clusters.iter().rev().find(|&&c| c <= offset) else {
+            return single;
+        };
+        let cluster_end = clusters
+            .iter()
+            .find(|&&c| c > cluster_start)
+            .copied()
+            .unwrap_or(self.text.len());
+
+        let first = self.offsets.iter().position(|&o| o >= cluster_start);
+        let last = self.offsets.iter().rposition(|&o| o < cluster_end);
+        match (first, last) {
+            (Some(first), Some(last)) if first <= last => {
+                let cols = self.start + first..self.start + last + 1;
+                if cols.contains(&col) { cols } else { single }
+            }
+            _ => single,
+        }
+    }
+}
+
+/// Columns the cursor at `col` covers in `row`.
+///
+/// A single cell unless `ligature_width` is set and the cursor sits on a
+/// ligature of the shaped word.
+pub(crate) fn cursor_columns(
+    font_manager: &mut FontManager,
+    ligature_width: bool,
+    enable_ligatures: bool,
+    row: &[Cell],
+    col: usize,
+) -> Range<usize> {
+    if !ligature_width || !enable_ligatures {
+        return col..col + 1;
+    }
+    let Some(run) = CursorRun::around(row, col) else {
+        return col..col + 1;
+    };
+    let cell = &row[col];
+    let shaped = font_manager.shape_text_with_ligature_fallback(
+        &run.text,
+        cell.bold,
+        cell.italic,
+        ShapingOptions {
+            enable_ligatures: true,
+            ..ShapingOptions::default()
+        },
+    );
+    run.cluster_columns(&shaped, col)
+}
+
+/// Pixel width of a cursor covering `cols`.
+pub(crate) fn span_width(cols: &Range<usize>, cell_width: f32) -> f32 {
+    cols.len().max(1) as f32 * cell_width
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::cursor_morph::CursorRect;
+    use super::*;
+    use par_term_emu_core_rust::cursor::CursorStyle;
+    use par_term_fonts::text_shaper::{ShapedGlyph, ShapedRun};
+    use std::sync::Arc;
+
+    const CELL_W: f32 = 10.0;
+    const CELL_H: f32 = 20.0;
+
+    fn row(text: &str) -> Vec<Cell> {
+        text.chars()
+            .map(|c| Cell {
+                grapheme: c.to_string(),
+                ..Cell::default()
+            })
+            .collect()
+    }
+
+    /// Segment of `text` starting at byte `start` as a ligature font shapes
+    /// it: one glyph per cluster start.
+    fn segment(text: &str, start: usize, clusters: &[u32]) -> ShapedSegment {
+        let run = ShapedRun {
+            text: text.to_string(),
+            glyphs: clusters
+                .iter()
+                .map(|&cluster| ShapedGlyph {
+                    glyph_id: 1,
+                    cluster,
+                    x_advance: CELL_W,
+                    y_advance: 0.0,
+                    x_offset: 0.0,
+                    y_offset: 0.0,
+                    synthetic_bold: false,
+                    synthetic_italic: false,
+                })
+                .collect(),
+            total_advance: CELL_W * clusters.len() as f32,
+            cluster_boundaries: Vec::new(),
+        };
+        ShapedSegment {
+            range: start..start + text.len(),
+            font_index: 0,
+            run: Arc::new(run),
+        }
+    }
+
+    /// Run shaped in one segment with the primary font.
+    fn shaped(text: &str, clusters: &[u32]) -> Vec<ShapedSegment> {
+        vec![segment(text, 0, clusters)]
+    }
+
+    /// Block cursor rectangle at `col` of `a => b`, with `=>` shaped as a ligature.
+    fn cursor_rect(ligature_width: bool, col: usize) -> (Range<usize>, CursorRect) {
+        let cells = row("a => b");
+        let cols = if ligature_width {
+            let run = CursorRun::around(&cells, col).unwrap();
+            assert_eq!(run.text, "=>");
+            run.cluster_columns(&shaped(&run.text, &[0]), col)
+        } else {
+            col..col + 1
+        };
+        let rect =
+            CursorRect::for_style(CursorStyle::SteadyBlock, span_width(&cols, CELL_W), CELL_H);
+        (cols, rect)
+    }
+
+    #[test]
+    fn cursor_spans_both_cells_of_arrow_ligature_when_enabled() {
+        for col in [2, 3] {
+            let (cols, rect) = cursor_rect(true, col);
+            assert_eq!(cols, 2..4, "cursor on column {col}");
+            assert_eq!(rect.width, 2.0 * CELL_W);
+        }
+    }
+
+    #[test]
+    fn cursor_covers_one_cell_when_disabled() {
+        let (cols, rect) = cursor_rect(false, 3);
+        assert_eq!(cols, 3..4);
+        assert_eq!(rect.width, CELL_W);
+    }
+
+    #[test]
+    fn unligated_glyphs_and_blank_cells_keep_single_width() {
+        let cells = row("x=>y z");
+        let run = CursorRun::around(&cells, 1).unwrap();
+        assert_eq!((run.start, run.text.as_str()), (0, "x=>y"));
+        // No ligature: one cluster per character
+        assert_eq!(run.cluster_columns(&shaped("x=>y", &[0, 1, 2, 3]), 2), 2..3);
+        // Ligature in the middle of the word
+        assert_eq!(run.cluster_columns(&shaped("x=>y", &[0, 1, 3]), 2), 1..3);
+        assert_eq!(run.cluster_columns(&shaped("x=>y", &[0, 1, 3]), 3), 3..4);
+
+        assert_eq!(CursorRun::around(&cells, 4), None);
+    }
+
+    #[test]
+    fn ligature_from_fallback_font_widens_cursor() {
+        let cells = row("x=>y");
+        let run = CursorRun::around(&cells, 2).unwrap();
+        // `=>` shaped with the fallback font between primary font segments
+        let shaped = [
+            segment("x", 0, &[0]),
+            segment("=>", 1, &[0]),
+            segment("y", 3, &[0]),
+        ];
+        assert_eq!(run.cluster_columns(&shaped, 1), 1..3);
+        assert_eq!(run.cluster_columns(&shaped, 2), 1..3);
+        assert_eq!(run.cluster_columns(&shaped, 0), 0..1);
+        assert_eq!(run.cluster_columns(&shaped, 3), 3..4);
+    }
+}
@@ -17,32 +17,43 @@ use winit::window::Window;
 
 use crate::scrollbar::Scrollbar;
 use crate::wgpu_conversions::{PowerPreferenceWgpu, VsyncModeWgpu};
+use font_rendering::{CellMetrics, RawFontMetrics};
 use par_term_config::{SeparatorMark, color_u8_to_f32_a};
 use par_term_fonts::font_manager::FontManager;
 
 pub mod atlas;
+mod atlas_pages;
 pub mod background;
 mod bg_instance_builder;
 pub mod block_chars;
 mod cursor;
+mod cursor_morph;
+mod cursor_smear;
 mod font;
+mod font_rendering;
 mod instance_buffers;
 mod layout;
+mod ligature_cursor;
 pub(crate) mod pane_render;
 pub mod pipeline;
 pub mod render;
+mod rounded_corners;
 mod settings;
 mod surface;
 mod text_instance_builder;
+mod text_shadow;
 pub mod types;
 // Re-export public types for external use
+pub use font_rendering::FontRenderingOptions;
 pub(crate) use pane_render::PaneRenderViewParams;
+pub use rounded_corners::SelectionRegion;
 pub use types::{Cell, PaneViewport};
 // Re-export internal types for use within the cell_renderer module
 pub(crate) use types::{BackgroundInstance, GlyphInfo, RowCacheEntry, TextInstance};
 // Re-export instance buffer constants so mod.rs can reference them
 pub(crate) use instance_buffers::{CURSOR_OVERLAY_SLOTS, TEXT_INSTANCES_PER_CELL};
 // Re-export extracted sub-module types for use within this module
+pub(crate) use atlas_pages::AtlasPageAllocator;
 pub(crate) use cursor::CursorState;
 pub(crate) use font::FontState;
 pub(crate) use layout::GridLayout;
@@ -90,7 +101,7 @@ pub(crate) struct GpuPipelines {
     /// Full-screen flash pipeline used by `render_overlays` when `visual_bell_intensity > 0`.
     pub(crate) visual_bell_pipeline: wgpu::RenderPipeline,
     pub(crate) text_bind_group: wgpu::BindGroup,
-    #[allow(dead_code)] // GPU lifetime: must outlive bind groups created from this layout
+    /// Also used to rebuild `text_bind_group` when the glyph atlas gains a page.
     pub(crate) text_bind_group_layout: wgpu::BindGroupLayout,
     pub(crate) bg_image_bind_group: Option<wgpu::BindGroup>,
     pub(crate) bg_image_bind_group_layout: wgpu::BindGroupLayout,
@@ -120,16 +131,20 @@ pub(crate) struct GpuBuffers {
 
 /// Glyph atlas texture, cache, and LRU eviction state.
 pub(crate) struct GlyphAtlas {
+    /// Texture array holding one layer per allocated atlas page
     pub(crate) atlas_texture: wgpu::Texture,
     #[allow(dead_code)] // GPU lifetime: must outlive text_bind_group which references this view
     pub(crate) atlas_view: wgpu::TextureView,
+    /// Kept so the text bind group can be rebuilt when the atlas texture grows
+    pub(crate) atlas_sampler: wgpu::Sampler,
     pub(crate) glyph_cache: HashMap<u64, GlyphInfo>,
     pub(crate) lru_head: Option<u64>,
     pub(crate) lru_tail: Option<u64>,
-    pub(crate) atlas_next_x: u32,
-    pub(crate) atlas_next_y: u32,
-    pub(crate) atlas_row_height: u32,
-    /// Actual atlas size (may be smaller than preferred on devices with low texture limits)
+    /// Shelf packer deciding which page and position each glyph is uploaded to
+    pub(crate) allocator: AtlasPageAllocator,
+    /// Number of layers in `atlas_texture` (pages are added on demand)
+    pub(crate) atlas_pages: u32,
+    /// Actual atlas page size (may be smaller than configured on devices with low texture limits)
     pub(crate) atlas_size: u32,
     /// Solid white pixel offset in atlas for geometric block rendering
     pub(crate) solid_pixel_offset: (u32, u32),
@@ -180,6 +195,10 @@ pub struct CellRenderer {
     pub(crate) config: wgpu::SurfaceConfiguration,
     /// Supported present modes for this surface (for vsync mode validation)
     pub(crate) supported_present_modes: Vec<wgpu::PresentMode>,
+    /// Adapters able to present to this surface (for the settings device picker)
+    pub(crate) gpu_adapters: Vec<par_term_config::GpuAdapterInfo>,
+    /// The adapter rendering this window
+    pub(crate) adapter_info: par_term_config::GpuAdapterInfo,
 
     // Sub-structs grouping related GPU and rendering state
     pub(crate) pipelines: GpuPipelines,
@@ -218,6 +237,9 @@ pub struct CellRenderer {
     // Scratch buffers reused across dirty-row iterations (avoids per-row Vec allocation)
     pub(crate) scratch_row_bg: Vec<BackgroundInstance>,
     pub(crate) scratch_row_text: Vec<TextInstance>,
+    /// Text shadow instances collected apart from the glyphs, so they can be
+    /// placed ahead of every glyph (see `text_shadow`).
+    pub(crate) scratch_text_shadows: Vec<TextInstance>,
     /// Scratch buffer for a single row of cells, reused in `build_instance_buffers` to
     /// avoid cloning `self.cells[start..end]` into a new Vec on every dirty row.
     pub(crate) scratch_row_cells: Vec<Cell>,
@@ -234,6 +256,10 @@ pub struct CellRenderer {
     pub(crate) transparency_affects_only_default_background: bool,
     /// When true, text is always rendered at full opacity regardless of window transparency.
     pub(crate) keep_text_opaque: bool,
+    /// Drop shadow drawn behind glyphs (disabled by default)
+    pub(crate) text_shadow: text_shadow::TextShadow,
+    /// Corner radius of selection and cursor backgrounds in logical pixels (0 = square)
+    pub(crate) selection_corner_radius: f32,
     /// Style for link underlines (solid or stipple)
     pub(crate) link_underline_style: par_term_config::LinkUnderlineStyle,
 
@@ -251,6 +277,9 @@ pub struct CellRendererConfig<'a> {
     pub font_family_italic: Option<&'a str>,
     pub font_family_bold_italic: Option<&'a str>,
     pub font_ranges: &'a [par_term_config::FontRange],
+    pub font_axes: &'a par_term_config::FontAxes,
+    pub font_ligature_fallback: Option<&'a str>,
+    pub synthesize_styles: bool,
     pub font_size: f32,
     pub cols: usize,
     pub rows: usize,
@@ -270,11 +299,19 @@ pub struct CellRendererConfig<'a> {
     pub minimum_contrast: f32,
     pub vsync_mode: par_term_config::VsyncMode,
     pub power_preference: par_term_config::PowerPreference,
+    /// Preferred GPU adapter name (`None` = choose by power preference).
+    pub gpu_adapter: Option<&'a str>,
+    /// Color space translucent layers are blended in (selects the surface format).
+    pub blend_color_space: par_term_config::BlendColorSpace,
     pub window_opacity: f32,
     pub background_color: [u8; 3],
     pub background_image_path: Option<&'a str>,
     pub background_image_mode: par_term_config::BackgroundImageMode,
     pub background_image_opacity: f32,
+    /// Glyph atlas page size in pixels (clamped to device limits).
+    pub glyph_atlas_size: u32,
+    /// Maximum number of glyph atlas pages before the glyph cache is cleared.
+    pub glyph_atlas_max_pages: u32,
 }
 
 impl CellRenderer {
@@ -285,6 +322,9 @@ impl CellRenderer {
             font_family_italic,
             font_family_bold_italic,
             font_ranges,
+            font_axes,
+            font_ligature_fallback,
+            synthesize_styles,
             font_size,
             cols,
             rows,
@@ -304,11 +344,15 @@ impl CellRenderer {
             minimum_contrast,
             vsync_mode,
             power_preference,
+            gpu_adapter,
+            blend_color_space,
             window_opacity,
             background_color,
             background_image_path,
             background_image_mode,
             background_image_opacity,
+            glyph_atlas_size,
+            glyph_atlas_max_pages,
         } = config;
         // Platform-specific backend selection for better VM compatibility
         // Windows: Use DX12 (Vulkan may not work in VMs like Parallels)
@@ -331,14 +375,47 @@ impl CellRenderer {
             ..wgpu::InstanceDescriptor::new_without_display_handle()
         });
         let surface = instance.create_surface(window.clone())?;
-        let adapter = instance
-            .request_adapter(&wgpu::RequestAdapterOptions {
-                power_preference: power_preference.to_wgpu(),
-                compatible_surface: Some(&surface),
-                force_fallback_adapter: false,
-            })
+
+        // Adapters that can present to this window, for the settings device picker.
+        let mut candidates: Vec<wgpu::Adapter> = instance
+            .enumerate_adapters(wgpu::Backends::all())
             .await
-            .context("Failed to find wgpu adapter")?;
+            .into_iter()
+            .filter(|a| a.is_surface_supported(&surface))
+            .collect();
+        let gpu_adapters: Vec<par_term_config::GpuAdapterInfo> = candidates
+            .iter()
+            .map(|a| crate::gpu_utils::gpu_adapter_info(&a.get_info()))
+            .collect();
+
+        let preferred = gpu_adapter.and_then(|name| {
+            let index = par_term_config::find_gpu_adapter(&gpu_adapters, name);
+            if index.is_none() {
+                log::warn!(
+                    "GPU adapter {:?} not found, using power preference instead",
+                    name
+                );
+            }
+            index
+        });
+        let adapter = match preferred {
+            Some(index) => candidates.swap_remove(index),
+            None => instance
+                .request_adapter(&wgpu::RequestAdapterOptions {
+                    power_preference: power_preference.to_wgpu(),
+                    compatible_surface: Some(&surface),
+                    force_fallback_adapter: false,
+                })
+                .await
+                .context("Failed to find wgpu adapter")?,
+        };
+        let adapter_info = crate::gpu_utils::gpu_adapter_info(&adapter.get_info());
+        log::info!(
+            "Using GPU adapter: {} ({}, {})",
+            adapter_info.name,
+            adapter_info.backend,
+            adapter_info.device_type
+        );
 
         let (device, queue) = adapter
             .request_device(&wgpu::DeviceDescriptor {
@@ -355,12 +432,22 @@ impl CellRenderer {
 
         let size = window.inner_size();
         let surface_caps = surface.get_capabilities(&adapter);
-        let surface_format = surface_caps
-            .formats
-            .iter()
-            .copied()
-            .find(|f| !f.is_srgb())
-            .unwrap_or(surface_caps.formats[0]);
+        // Custom shaders sample the sRGB scene through a non-sRGB view, which
+        // needs view format support
+        let blend_color_space = if blend_color_space == par_term_config::BlendColorSpace::Linear
+            && !adapter
+                .get_downlevel_capabilities()
+                .flags
+                .contains(wgpu::DownlevelFlags::VIEW_FORMATS)
+        {
+            log::warn!("GPU adapter does not support view formats, blending in sRGB");
+            par_term_config::BlendColorSpace::Srgb
+        } else {
+            blend_color_space
+        };
+        let surface_format =
+            crate::color_space::select_surface_format(&surface_caps.formats, blend_color_space)
+                .unwrap_or(surface_caps.formats[0]);
 
         // Store supported present modes for runtime validation
         let supported_present_modes = surface_caps.present_modes.clone();
@@ -432,38 +519,43 @@ impl CellRenderer {
         let base_font_pixels = font_size * platform_dpi / FONT_REFERENCE_DPI;
         let font_size_pixels = (base_font_pixels * scale_factor).max(1.0);
 
-        let font_manager = FontManager::new(
+        let mut font_manager = FontManager::new(
             font_family,
             font_family_bold,
             font_family_italic,
             font_family_bold_italic,
             font_ranges,
+            font_axes,
         )?;
+        if enable_ligatures {
+            font_manager.set_ligature_fallback(font_ligature_fallback);
+        }
+        font_manager.set_synthesize_styles(synthesize_styles);
 
         // Extract font metrics
-        let (font_ascent, font_descent, font_leading, char_advance) = {
+        let raw_metrics = {
             let primary_font = font_manager
                 .get_font(0)
                 .expect("Primary font at index 0 must exist after FontManager initialization");
-            let metrics = primary_font.metrics(&[]);
+            let coords = font_manager.font_coords(0);
+            let metrics = primary_font.metrics(coords);
             let scale = font_size_pixels / metrics.units_per_em as f32;
             let glyph_id = primary_font.charmap().map('m');
-            let advance = primary_font.glyph_metrics(&[]).advance_width(glyph_id) * scale;
-            (
-                metrics.ascent * scale,
-                metrics.descent * scale,
-                metrics.leading * scale,
-                advance,
-            )
+            RawFontMetrics {
+                ascent: metrics.ascent * scale,
+                descent: metrics.descent * scale,
+                leading: metrics.leading * scale,
+                char_advance: primary_font.glyph_metrics(coords).advance_width(glyph_id) * scale,
+            }
         };
-
-        let natural_line_height = font_ascent + font_descent + font_leading;
-        // Round to integer pixels so every cell has the same width in device pixels.
-        // Without rounding, per-column scale_x alternates (e.g. 7/7.8 vs 8/7.8),
-        // causing glyphs to sample the atlas at slightly different rates and appear
-        // at different perceived brightnesses.
-        let cell_height = (natural_line_height * line_spacing).max(1.0).round();
-        let cell_width = (char_advance * char_spacing).max(1.0).round();
+        let CellMetrics {
+            ascent: font_ascent,
+            descent: font_descent,
+            leading: font_leading,
+            char_advance,
+            cell_width,
+            cell_height,
+        } = CellMetrics::compute(raw_metrics, line_spacing, char_spacing);
 
         let scrollbar = Scrollbar::new(
             Arc::clone(&device),
@@ -477,8 +569,8 @@ impl CellRenderer {
         // Create pipelines using the pipeline module
         let bg_pipeline = pipeline::create_bg_pipeline(&device, surface_format);
 
-        let (atlas_texture, atlas_view, atlas_sampler, atlas_size) =
-            pipeline::create_atlas(&device);
+        let (atlas_texture, atlas_view, atlas_sampler, atlas_size, atlas_max_pages) =
+            pipeline::create_atlas(&device, glyph_atlas_size, glyph_atlas_max_pages);
         let text_bind_group_layout = pipeline::create_text_bind_group_layout(&device);
         let text_bind_group = pipeline::create_text_bind_group(
             &device,
@@ -517,6 +609,8 @@ impl CellRenderer {
             surface,
             config,
             supported_present_modes,
+            gpu_adapters,
+            adapter_info,
             pipelines: GpuPipelines {
                 bg_pipeline,
                 text_pipeline,
@@ -543,12 +637,16 @@ impl CellRenderer {
             atlas: GlyphAtlas {
                 atlas_texture,
                 atlas_view,
+                atlas_sampler,
                 glyph_cache: HashMap::new(),
                 lru_head: None,
                 lru_tail: None,
-                atlas_next_x: 0,
-                atlas_next_y: 0,
-                atlas_row_height: 0,
+                allocator: AtlasPageAllocator::new(
+                    atlas_size,
+                    atlas_max_pages,
+                    ATLAS_GLYPH_PADDING,
+                ),
+                atlas_pages: 1,
                 atlas_size,
                 solid_pixel_offset: (0, 0),
             },
@@ -581,6 +679,15 @@ impl CellRenderer {
                 boost: 0.0,
                 boost_color: [1.0, 1.0, 1.0],
                 unfocused_style: par_term_config::UnfocusedCursorStyle::default(),
+                morph_duration: std::time::Duration::ZERO,
+                morph: None,
+                morph_progress: 0.0,
+                smear_length: 0.0,
+                smear_decay: std::time::Duration::ZERO,
+                smear: None,
+                smear_fade: 0.0,
+                ligature_width: false,
+                cell_span: 0..1,
             },
             font: FontState {
                 base_font_size: font_size,
@@ -634,6 +741,7 @@ impl CellRenderer {
                     position: [0.0, 0.0],
                     size: [0.0, 0.0],
                     color: [0.0, 0.0, 0.0, 0.0],
+                    corner_radii: [0.0; 4],
                 };
                 max_bg_instances
             ],
@@ -645,15 +753,19 @@ impl CellRenderer {
                     tex_size: [0.0, 0.0],
                     color: [0.0, 0.0, 0.0, 0.0],
                     is_colored: 0,
+                    atlas_page: 0,
                 };
                 max_text_instances
             ],
             transparency_affects_only_default_background: false,
             keep_text_opaque: true,
+            text_shadow: text_shadow::TextShadow::default(),
+            selection_corner_radius: 0.0,
             link_underline_style: par_term_config::LinkUnderlineStyle::default(),
             gutter_indicators: Vec::new(),
             scratch_row_bg: Vec::with_capacity(cols),
             scratch_row_text: Vec::with_capacity(cols * 2),
+            scratch_text_shadows: Vec::new(),
             scratch_row_cells: Vec::with_capacity(cols),
             scale_context: swash::scale::ScaleContext::new(),
         };
@@ -679,18 +791,27 @@ impl CellRenderer {
         Ok(renderer)
     }
 
-    /// Upload a solid white pixel to the atlas for use in geometric block rendering
+    /// Upload a solid white pixel to the atlas for use in geometric block rendering.
+    ///
+    /// Must be called on a freshly reset allocator so the block lands on page 0,
+    /// which is what every solid-fill `TextInstance` samples from.
     pub(crate) fn upload_solid_pixel(&mut self) {
         let size = SOLID_PIXEL_SIZE;
         let white_pixels: Vec<u8> = vec![255; (size * size * 4) as usize];
+        let slot = self
+            .atlas
+            .allocator
+            .allocate(size, size)
+            .expect("Solid pixel block must fit on an empty atlas page");
+        debug_assert_eq!(slot.page, 0);
 
         self.queue.write_texture(
             wgpu::TexelCopyTextureInfo {
                 texture: &self.atlas.atlas_texture,
                 mip_level: 0,
                 origin: wgpu::Origin3d {
-                    x: self.atlas.atlas_next_x,
-                    y: self.atlas.atlas_next_y,
+                    x: slot.x,
+                    y: slot.y,
                     z: 0,
                 },
                 aspect: wgpu::TextureAspect::All,
@@ -708,9 +829,7 @@ impl CellRenderer {
             },
         );
 
-        self.atlas.solid_pixel_offset = (self.atlas.atlas_next_x, self.atlas.atlas_next_y);
-        self.atlas.atlas_next_x += size + ATLAS_GLYPH_PADDING;
-        self.atlas.atlas_row_height = self.atlas.atlas_row_height.max(size);
+        self.atlas.solid_pixel_offset = (slot.x, slot.y);
     }
 
     pub fn device(&self) -> &wgpu::Device {
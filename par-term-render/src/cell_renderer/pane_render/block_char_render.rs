@@ -8,7 +8,6 @@
 /// The public entry point is `CellRenderer::render_block_char_geometrically`.
 use super::super::block_chars;
 use super::super::{CellRenderer, TextInstance};
-use super::ATLAS_SIZE;
 use par_term_config::{Cell, color_u8x4_rgb_to_f32_a};
 
 /// Parameters for `CellRenderer::render_block_char_geometrically`.
@@ -73,11 +72,12 @@ impl CellRenderer {
         };
         let snapped_cell_height = y1 - y0;
 
+        let atlas_size = self.atlas.atlas_size as f32;
         let solid_tex_offset = [
-            self.atlas.solid_pixel_offset.0 as f32 / ATLAS_SIZE,
-            self.atlas.solid_pixel_offset.1 as f32 / ATLAS_SIZE,
+            self.atlas.solid_pixel_offset.0 as f32 / atlas_size,
+            self.atlas.solid_pixel_offset.1 as f32 / atlas_size,
         ];
-        let solid_tex_size = [1.0 / ATLAS_SIZE, 1.0 / ATLAS_SIZE];
+        let solid_tex_size = [1.0 / atlas_size, 1.0 / atlas_size];
 
         // --- Box drawing geometry ---
         let aspect_ratio = snapped_cell_height / char_w;
@@ -116,6 +116,7 @@ impl CellRenderer {
                         tex_size: solid_tex_size,
                         color: render_fg_color,
                         is_colored: 0,
+                        atlas_page: 0,
                     };
                     text_index += 1;
                 }
@@ -152,6 +153,7 @@ impl CellRenderer {
                     tex_size: solid_tex_size,
                     color: top_color,
                     is_colored: 0,
+                    atlas_page: 0,
                 };
                 text_index += 1;
             }
@@ -171,6 +173,7 @@ impl CellRenderer {
                     tex_size: solid_tex_size,
                     color: bottom_color,
                     is_colored: 0,
+                    atlas_page: 0,
                 };
                 text_index += 1;
             }
@@ -198,6 +201,7 @@ impl CellRenderer {
                     tex_size: solid_tex_size,
                     color: render_fg_color,
                     is_colored: 0,
+                    atlas_page: 0,
                 };
                 text_index += 1;
             }
@@ -237,6 +241,7 @@ impl CellRenderer {
                     tex_size: solid_tex_size,
                     color: render_fg_color,
                     is_colored: 0,
+                    atlas_page: 0,
                 };
                 text_index += 1;
             }
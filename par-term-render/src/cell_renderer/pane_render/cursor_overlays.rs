@@ -1,7 +1,7 @@
 //! Cursor overlay instance generation for pane rendering.
 //!
 //! Provides [`CellRenderer::emit_cursor_overlays`] which appends background instances
-//! for cursor-related overlays (guide, shadow, beam/underline bar, hollow outline) to
+//! for cursor-related overlays (guide, shadow, smear, beam/underline bar, hollow outline) to
 //! `self.bg_instances` starting at `bg_index` and returns the updated index.
 
 use super::super::instance_buffers::HOLLOW_CURSOR_BORDER_PX;
@@ -11,7 +11,8 @@ use super::super::{BackgroundInstance, CellRenderer};
 pub(super) struct CursorOverlayParams {
     /// Pixel X of the left edge of the cursor cell.
     pub cursor_x0: f32,
-    /// Pixel X of the right edge of the cursor cell.
+    /// Pixel X of the right edge of the cursor cell (or of the last cell of a
+    /// ligature the cursor is widened over).
     pub cursor_x1: f32,
     /// Pixel Y of the top edge of the cursor cell.
     pub cursor_y0: f32,
@@ -21,6 +22,10 @@ pub(super) struct CursorOverlayParams {
     pub cols: usize,
     /// Pixel X of the content area origin (left edge after padding).
     pub content_x: f32,
+    /// Pixel Y of the content area origin (top edge after padding).
+    pub content_y: f32,
+    /// Cursor position (col, row) within the pane.
+    pub cursor_pos: (usize, usize),
     /// Current blink opacity (0.0 = invisible, 1.0 = fully visible).
     pub cursor_opacity: f32,
 }
@@ -42,6 +47,8 @@ impl CellRenderer {
             cursor_y1,
             cols,
             content_x,
+            content_y,
+            cursor_pos,
             cursor_opacity,
         } = p;
 
@@ -63,6 +70,7 @@ impl CellRenderer {
                     (cursor_y1 - cursor_y0) / h * 2.0,
                 ],
                 color: self.cursor.guide_color,
+                corner_radii: [0.0; 4],
             };
             bg_index += 1;
         }
@@ -78,44 +86,50 @@ impl CellRenderer {
             self.bg_instances[bg_index] = BackgroundInstance {
                 position: [shadow_x0 / w * 2.0 - 1.0, 1.0 - (shadow_y0 / h * 2.0)],
                 size: [
-                    self.grid.cell_width / w * 2.0,
+                    (cursor_x1 - cursor_x0) / w * 2.0,
                     self.grid.cell_height / h * 2.0,
                 ],
                 color: self.cursor.shadow_color,
+                corner_radii: [0.0; 4],
             };
             bg_index += 1;
         }
 
-        // Beam or underline cursor bar (on top of text)
-        if cursor_opacity > 0.0 && !self.cursor.hidden_for_shader {
-            use par_term_emu_core_rust::cursor::CursorStyle;
+        // Smear streak, only in the pane whose cursor made the jump
+        if cursor_opacity > 0.0 && !self.cursor.hidden_for_shader && cursor_pos == self.cursor.pos {
             let cc = self.cursor.color;
-            let overlay = match self.cursor.style {
-                CursorStyle::SteadyBar | CursorStyle::BlinkingBar => Some(BackgroundInstance {
-                    position: [cursor_x0 / w * 2.0 - 1.0, 1.0 - (cursor_y0 / h * 2.0)],
-                    size: [2.0 / w * 2.0, (cursor_y1 - cursor_y0) / h * 2.0],
-                    color: [cc[0], cc[1], cc[2], cursor_opacity],
-                }),
-                CursorStyle::SteadyUnderline | CursorStyle::BlinkingUnderline => {
-                    Some(BackgroundInstance {
-                        position: [
-                            cursor_x0 / w * 2.0 - 1.0,
-                            1.0 - ((cursor_y1 - 2.0) / h * 2.0),
-                        ],
-                        size: [(cursor_x1 - cursor_x0) / w * 2.0, 2.0 / h * 2.0],
-                        color: [cc[0], cc[1], cc[2], cursor_opacity],
-                    })
+            for (rect, alpha) in self.cursor_smear_rects((content_x, content_y)) {
+                if bg_index >= self.buffers.max_bg_instances {
+                    break;
                 }
-                _ => None,
-            };
-            if let Some(inst) = overlay
-                && bg_index < self.buffers.max_bg_instances
-            {
-                self.bg_instances[bg_index] = inst;
+                self.bg_instances[bg_index] = BackgroundInstance {
+                    position: [rect.x / w * 2.0 - 1.0, 1.0 - (rect.y / h * 2.0)],
+                    size: [rect.width / w * 2.0, rect.height / h * 2.0],
+                    color: [cc[0], cc[1], cc[2], alpha * cursor_opacity],
+                    corner_radii: [0.0; 4],
+                };
                 bg_index += 1;
             }
         }
 
+        // Beam or underline cursor bar, or the shape mid-morph (on top of text)
+        if cursor_opacity > 0.0
+            && !self.cursor.hidden_for_shader
+            && let Some(rect) = self.cursor_overlay_rect()
+            && bg_index < self.buffers.max_bg_instances
+        {
+            let cc = self.cursor.color;
+            let x0 = cursor_x0 + rect.x;
+            let y0 = cursor_y0 + rect.y;
+            self.bg_instances[bg_index] = BackgroundInstance {
+                position: [x0 / w * 2.0 - 1.0, 1.0 - (y0 / h * 2.0)],
+                size: [rect.width / w * 2.0, rect.height / h * 2.0],
+                color: [cc[0], cc[1], cc[2], cursor_opacity],
+                corner_radii: [0.0; 4],
+            };
+            bg_index += 1;
+        }
+
         // Hollow cursor outline (4 borders) — independent of blink opacity
         let render_hollow = !self.cursor.hidden_for_shader
             && !self.is_focused
@@ -140,18 +154,21 @@ impl CellRenderer {
                     position: [cx, cy],
                     size: [cell_w, bh],
                     color,
+                    corner_radii: [0.0; 4],
                 },
                 // Bottom
                 BackgroundInstance {
                     position: [cx, 1.0 - ((cursor_y1 - border_width) / h * 2.0)],
                     size: [cell_w, bh],
                     color,
+                    corner_radii: [0.0; 4],
                 },
                 // Left
                 BackgroundInstance {
                     position: [cx, 1.0 - ((cursor_y0 + border_width) / h * 2.0)],
                     size: [bw, cell_h - bh * 2.0],
                     color,
+                    corner_radii: [0.0; 4],
                 },
                 // Right
                 BackgroundInstance {
@@ -161,6 +178,7 @@ impl CellRenderer {
                     ],
                     size: [bw, cell_h - bh * 2.0],
                     color,
+                    corner_radii: [0.0; 4],
                 },
             ];
             for border in borders {
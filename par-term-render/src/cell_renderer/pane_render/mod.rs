@@ -23,7 +23,9 @@ use super::block_chars;
 use super::instance_buffers::{
     STIPPLE_OFF_PX, STIPPLE_ON_PX, UNDERLINE_HEIGHT_RATIO, compute_cursor_text_color,
 };
-use super::{BackgroundInstance, Cell, CellRenderer, PaneViewport, TextInstance};
+use super::rounded_corners::RoundedCorners;
+use super::{BackgroundInstance, Cell, CellRenderer, PaneViewport, SelectionRegion, TextInstance};
+use crate::custom_shader_renderer::overscan::overscan_scissor_rect;
 use anyhow::Result;
 use par_term_config::{SeparatorMark, color_u8x4_rgb_to_f32, color_u8x4_rgb_to_f32_a};
 mod block_char_render;
@@ -35,10 +37,6 @@ use block_char_render::BlockCharRenderParams;
 
 use cursor_overlays::CursorOverlayParams;
 
-/// Atlas texture size in pixels. Must match the value used at atlas creation time.
-/// See `PREFERRED_ATLAS_SIZE` in `pipeline.rs` and `atlas_size` on `CellRendererAtlas`.
-pub(crate) const ATLAS_SIZE: f32 = 2048.0;
-
 /// Parameters for rendering a single pane to a surface texture view.
 pub struct PaneRenderViewParams<'a> {
     pub viewport: &'a PaneViewport,
@@ -55,6 +53,10 @@ pub struct PaneRenderViewParams<'a> {
     pub fill_default_bg_cells: bool,
     pub separator_marks: &'a [SeparatorMark],
     pub pane_background: Option<&'a par_term_config::PaneBackground>,
+    /// Pixels the pane's clip rect is grown by on every side (shader overscan)
+    pub overscan: f32,
+    /// Selected cells, whose outer corners are rounded when a selection corner radius is set
+    pub selection: Option<SelectionRegion>,
 }
 
 /// Parameters for building GPU instance buffers for a pane.
@@ -68,6 +70,7 @@ pub(super) struct PaneInstanceBuildParams<'a> {
     pub skip_solid_background: bool,
     pub fill_default_bg_cells: bool,
     pub separator_marks: &'a [SeparatorMark],
+    pub selection: Option<SelectionRegion>,
 }
 
 /// Parameters for emitting a cursor cell background instance (QA-006 extraction).
@@ -80,6 +83,10 @@ struct CursorCellBgParams {
     cursor_opacity: f32,
     render_hollow_here: bool,
     opacity_multiplier: f32,
+    /// Corners of a filled cursor to round
+    corners: RoundedCorners,
+    /// Whether the cell's own background needs a quad (seen at rounded cursor corners)
+    paint_cell_bg: bool,
     bg_index: usize,
 }
 
@@ -119,6 +126,8 @@ impl CellRenderer {
             fill_default_bg_cells,
             separator_marks,
             pane_background,
+            overscan,
+            selection,
         } = p;
         // Build instance buffers for this pane's cells.
         // Returns cursor_overlay_start: the bg_instance index where cursor overlays begin.
@@ -133,6 +142,7 @@ impl CellRenderer {
             skip_solid_background: skip_background_image,
             fill_default_bg_cells,
             separator_marks,
+            selection,
         })?;
 
         // Pre-update per-pane background uniform buffer and bind group if needed (must happen
@@ -177,32 +187,16 @@ impl CellRenderer {
 
         // Determine load operation and clear color
         let load_op = if clear_first {
+            let opacity = self.window_opacity as f64 * viewport.opacity as f64;
             let clear_color = if self.bg_state.bg_is_solid_color {
-                wgpu::Color {
-                    r: self.bg_state.solid_bg_color[0] as f64
-                        * self.window_opacity as f64
-                        * viewport.opacity as f64,
-                    g: self.bg_state.solid_bg_color[1] as f64
-                        * self.window_opacity as f64
-                        * viewport.opacity as f64,
-                    b: self.bg_state.solid_bg_color[2] as f64
-                        * self.window_opacity as f64
-                        * viewport.opacity as f64,
-                    a: self.window_opacity as f64 * viewport.opacity as f64,
-                }
+                crate::color_space::clear_color(
+                    self.surface_format(),
+                    self.bg_state.solid_bg_color,
+                    opacity,
+                )
             } else {
-                wgpu::Color {
-                    r: self.background_color[0] as f64
-                        * self.window_opacity as f64
-                        * viewport.opacity as f64,
-                    g: self.background_color[1] as f64
-                        * self.window_opacity as f64
-                        * viewport.opacity as f64,
-                    b: self.background_color[2] as f64
-                        * self.window_opacity as f64
-                        * viewport.opacity as f64,
-                    a: self.window_opacity as f64 * viewport.opacity as f64,
-                }
+                let [r, g, b, _] = self.background_color;
+                crate::color_space::clear_color(self.surface_format(), [r, g, b], opacity)
             };
             wgpu::LoadOp::Clear(clear_color)
         } else {
@@ -228,7 +222,11 @@ impl CellRenderer {
             });
 
             // Set scissor rect to clip rendering to pane bounds
-            let (sx, sy, sw, sh) = viewport.to_scissor_rect();
+            let (sx, sy, sw, sh) = if overscan > 0.0 {
+                overscan_scissor_rect(viewport, overscan, self.config.width, self.config.height)
+            } else {
+                viewport.to_scissor_rect()
+            };
             render_pass.set_scissor_rect(sx, sy, sw, sh);
 
             // Render per-pane background image within scissor rect.
@@ -283,7 +281,31 @@ impl CellRenderer {
             skip_solid_background,
             fill_default_bg_cells,
             separator_marks,
+            selection,
         } = p;
+        let atlas_size = self.atlas.atlas_size as f32;
+
+        // Columns covered by the cursor (the whole ligature when widened)
+        let cursor_cols = match cursor_pos {
+            Some((cx, cy)) if self.cursor.ligature_width => {
+                let start = cy * cols;
+                match cells.get(start..(start + cols).min(cells.len())) {
+                    Some(row_cells) => self.cursor_columns(row_cells, cx),
+                    None => cx..cx + 1,
+                }
+            }
+            Some((cx, _)) => cx..cx + 1,
+            None => 0..0,
+        };
+        self.cursor.cell_span = cursor_cols.clone();
+        let cursor_on = |col: usize, row: usize| {
+            cursor_pos.is_some_and(|(_, cy)| cy == row) && cursor_cols.contains(&col)
+        };
+        let corner_radius = self.background_corner_radius();
+        let selected = |col: usize, row: usize| {
+            corner_radius > 0.0 && selection.is_some_and(|s| s.contains(col, row))
+        };
+
         // Clear previous instance buffers
         for instance in &mut self.bg_instances {
             instance.size = [0.0, 0.0];
@@ -313,6 +335,7 @@ impl CellRenderer {
                     bg_color[2] * opacity,
                     opacity,
                 ],
+                corner_radii: [0.0; 4],
             };
             1 // Start cell backgrounds at index 1
         } else {
@@ -326,6 +349,7 @@ impl CellRenderer {
         // Start at bg_start_index (1 if viewport fill was added, 0 otherwise)
         let mut bg_index = bg_start_index;
         let mut text_index = 0;
+        self.scratch_text_shadows.clear();
 
         // Content offset - positions are relative to content area (with padding applied)
         let (content_x, content_y) = viewport.content_origin();
@@ -349,8 +373,7 @@ impl CellRenderer {
                     && (bg_f[2] - self.background_color[2]).abs() < 0.001;
 
                 // Check for cursor at this position (position check only, no opacity gate)
-                let cursor_at_cell = cursor_pos.is_some_and(|(cx, cy)| cx == col && cy == row)
-                    && !self.cursor.hidden_for_shader;
+                let cursor_at_cell = cursor_on(col, row) && !self.cursor.hidden_for_shader;
                 // Hollow cursor (unfocused + Hollow style) must show regardless of blink opacity
                 let render_hollow_here = cursor_at_cell
                     && !self.is_focused
@@ -401,24 +424,24 @@ impl CellRenderer {
                         cursor_opacity,
                         render_hollow_here,
                         opacity_multiplier,
+                        corners: RoundedCorners::span_ends(col, &cursor_cols),
+                        paint_cell_bg: !is_default_bg || fill_default_bg_cells,
                         bg_index,
                     });
-                    if emitted {
-                        bg_index += 1;
-                    }
+                    bg_index += emitted;
                     col += 1;
                     continue;
                 }
 
-                // RLE: Find run of consecutive cells with same background color
+                // RLE: Find run of consecutive cells with same background color,
+                // split where the selection starts or ends so its corners can be rounded
                 let start_col = col;
                 let run_color = cell.bg_color;
+                let run_selected = selected(col, row);
                 col += 1;
                 while col < row_cells.len() {
                     let next_cell = &row_cells[col];
-                    let next_cursor_at_cell = cursor_pos
-                        .is_some_and(|(cx, cy)| cx == col && cy == row)
-                        && !self.cursor.hidden_for_shader;
+                    let next_cursor_at_cell = cursor_on(col, row) && !self.cursor.hidden_for_shader;
                     let next_hollow = next_cursor_at_cell
                         && !self.is_focused
                         && self.cursor.unfocused_style
@@ -430,7 +453,11 @@ impl CellRenderer {
                         matches!(chars.next(), Some('\u{2580}' | '\u{2584}'))
                             && chars.next().is_none()
                     };
-                    if next_cell.bg_color != run_color || next_has_cursor || next_is_half_block {
+                    if next_cell.bg_color != run_color
+                        || next_has_cursor
+                        || next_is_half_block
+                        || selected(col, row) != run_selected
+                    {
                         break;
                     }
                     col += 1;
@@ -460,6 +487,12 @@ impl CellRenderer {
                         is_default_bg,
                         background_color: self.background_color,
                     });
+                let corner_radii = match selection {
+                    Some(selection) if run_selected => selection
+                        .run_corners(start_col, col, row, cols)
+                        .radii(corner_radius),
+                    _ => [0.0; 4],
+                };
 
                 if bg_index < self.buffers.max_bg_instances {
                     self.bg_instances[bg_index] = BackgroundInstance {
@@ -472,6 +505,7 @@ impl CellRenderer {
                             (y1 - y0) / self.config.height as f32 * 2.0,
                         ],
                         color: bg_color,
+                        corner_radii,
                     };
                     bg_index += 1;
                 }
@@ -496,14 +530,10 @@ impl CellRenderer {
             // Check if this row has the cursor and it's a visible block cursor
             // (for cursor text color override in split-pane rendering)
             let cursor_is_block_on_this_row = {
-                use par_term_emu_core_rust::cursor::CursorStyle;
                 cursor_pos.is_some_and(|(_, cy)| cy == row)
                     && cursor_opacity > 0.0
                     && !self.cursor.hidden_for_shader
-                    && matches!(
-                        self.cursor.style,
-                        CursorStyle::SteadyBlock | CursorStyle::BlinkingBlock
-                    )
+                    && self.cursor_fills_cell()
                     && (self.is_focused
                         || self.cursor.unfocused_style
                             == par_term_config::UnfocusedCursorStyle::Same)
@@ -542,7 +572,7 @@ impl CellRenderer {
                 // Determine text color - apply cursor_text_color (or auto-contrast) when the
                 // block cursor is on this cell, otherwise use the cell's foreground color.
                 let render_fg_color: [f32; 4] = if cursor_is_block_on_this_row
-                    && cursor_pos.is_some_and(|(cx, _)| cx == col_idx)
+                    && cursor_cols.contains(&col_idx)
                 {
                     compute_cursor_text_color(self.cursor.color, self.cursor.text_color, text_alpha)
                 } else {
@@ -573,18 +603,10 @@ impl CellRenderer {
                 }
 
                 // Check if this character should be rendered as a monochrome symbol.
-                // Also handle symbol + VS16 (U+FE0F): strip VS16, render monochrome.
-                let (force_monochrome, base_char) = if grapheme_len == 1 {
-                    (super::atlas::should_render_as_symbol(ch), ch)
-                } else if grapheme_len == 2
-                    && second_char == Some('\u{FE0F}')
-                    && super::atlas::should_render_as_symbol(ch)
-                {
-                    // Symbol + VS16: strip VS16 and render base char as monochrome
-                    (true, ch)
-                } else {
-                    (false, ch)
-                };
+                // A VS15/VS16 presentation selector after it overrides the default.
+                let force_monochrome =
+                    super::atlas::should_render_grapheme_as_symbol(&cell.grapheme);
+                let base_char = ch;
 
                 // Resolve a renderable glyph via the shared font-fallback helper (ARC-004 / QA-003).
                 // This replaces the duplicated excluded_fonts/get_or_rasterize_glyph loop
@@ -657,24 +679,27 @@ impl CellRenderer {
                         (glyph_left, glyph_top, render_w, render_h)
                     };
 
+                    let glyph = TextInstance {
+                        position: [
+                            final_left / self.config.width as f32 * 2.0 - 1.0,
+                            1.0 - (final_top / self.config.height as f32 * 2.0),
+                        ],
+                        size: [
+                            final_w / self.config.width as f32 * 2.0,
+                            final_h / self.config.height as f32 * 2.0,
+                        ],
+                        tex_offset: [info.x as f32 / atlas_size, info.y as f32 / atlas_size],
+                        tex_size: [
+                            info.width as f32 / atlas_size,
+                            info.height as f32 / atlas_size,
+                        ],
+                        color: render_fg_color,
+                        is_colored: if info.is_colored { 1 } else { 0 },
+                        atlas_page: info.page,
+                    };
+                    self.push_text_shadow(&glyph);
                     if text_index < self.buffers.max_text_instances {
-                        self.text_instances[text_index] = TextInstance {
-                            position: [
-                                final_left / self.config.width as f32 * 2.0 - 1.0,
-                                1.0 - (final_top / self.config.height as f32 * 2.0),
-                            ],
-                            size: [
-                                final_w / self.config.width as f32 * 2.0,
-                                final_h / self.config.height as f32 * 2.0,
-                            ],
-                            tex_offset: [info.x as f32 / ATLAS_SIZE, info.y as f32 / ATLAS_SIZE],
-                            tex_size: [
-                                info.width as f32 / ATLAS_SIZE,
-                                info.height as f32 / ATLAS_SIZE,
-                            ],
-                            color: render_fg_color,
-                            is_colored: if info.is_colored { 1 } else { 0 },
-                        };
+                        self.text_instances[text_index] = glyph;
                         text_index += 1;
                     }
                 }
@@ -687,10 +712,10 @@ impl CellRenderer {
                     .max(1.0)
                     .round();
                 let tex_offset = [
-                    self.atlas.solid_pixel_offset.0 as f32 / ATLAS_SIZE,
-                    self.atlas.solid_pixel_offset.1 as f32 / ATLAS_SIZE,
+                    self.atlas.solid_pixel_offset.0 as f32 / atlas_size,
+                    self.atlas.solid_pixel_offset.1 as f32 / atlas_size,
                 ];
-                let tex_size = [1.0 / ATLAS_SIZE, 1.0 / ATLAS_SIZE];
+                let tex_size = [1.0 / atlas_size, 1.0 / atlas_size];
                 let y0 = content_y + (row + 1) as f32 * self.grid.cell_height - underline_thickness;
                 let ndc_y = 1.0 - (y0 / self.config.height as f32 * 2.0);
                 let ndc_h = underline_thickness / self.config.height as f32 * 2.0;
@@ -723,6 +748,7 @@ impl CellRenderer {
                                 tex_size,
                                 color: fg,
                                 is_colored: 0,
+                                atlas_page: 0,
                             };
                             text_index += 1;
                             px += stipple_period;
@@ -735,6 +761,7 @@ impl CellRenderer {
                             tex_size,
                             color: fg,
                             is_colored: 0,
+                            atlas_page: 0,
                         };
                         text_index += 1;
                     }
@@ -759,8 +786,8 @@ impl CellRenderer {
         let cursor_overlay_start = bg_index;
 
         if let Some((cursor_col, cursor_row)) = cursor_pos {
-            let cursor_x0 = content_x + cursor_col as f32 * self.grid.cell_width;
-            let cursor_x1 = cursor_x0 + self.grid.cell_width;
+            let cursor_x0 = content_x + cursor_cols.start as f32 * self.grid.cell_width;
+            let cursor_x1 = content_x + cursor_cols.end as f32 * self.grid.cell_width;
             let cursor_y0 = (content_y + cursor_row as f32 * self.grid.cell_height).round();
             let cursor_y1 = (content_y + (cursor_row + 1) as f32 * self.grid.cell_height).round();
 
@@ -773,12 +800,25 @@ impl CellRenderer {
                     cursor_y1,
                     cols,
                     content_x,
+                    content_y,
+                    cursor_pos: (cursor_col, cursor_row),
                     cursor_opacity,
                 },
                 bg_index,
             );
         }
 
+        // Shadows go ahead of every glyph so none is drawn over text
+        if !self.scratch_text_shadows.is_empty() {
+            let shadows = self
+                .scratch_text_shadows
+                .len()
+                .min(self.buffers.max_text_instances - text_index);
+            self.text_instances.copy_within(0..text_index, shadows);
+            self.text_instances[..shadows].copy_from_slice(&self.scratch_text_shadows[..shadows]);
+            text_index += shadows;
+        }
+
         // Update actual instance counts for draw calls
         self.buffers.actual_bg_instances = bg_index;
         self.buffers.actual_text_instances = text_index;
@@ -805,35 +845,39 @@ impl CellRenderer {
         Ok(cursor_overlay_start)
     }
 
-    /// Emit a background instance for a cell containing the cursor.
+    /// Emit background instances for a cell containing the cursor.
     ///
     /// QA-006: Extracted from the RLE background loop in `build_pane_instance_buffers`.
     /// Handles block-cursor color blending and pixel-snapped background quad placement.
-    /// Returns `true` if an instance was emitted (caller increments bg_index).
-    fn emit_cursor_cell_bg(&mut self, p: CursorCellBgParams) -> bool {
+    /// A filled cursor with rounded corners is drawn over a square quad of the cell's
+    /// own background, which shows at its corners.
+    /// Returns the number of instances emitted (caller advances bg_index).
+    fn emit_cursor_cell_bg(&mut self, p: CursorCellBgParams) -> usize {
         let CursorCellBgParams {
             col,
             row,
             content_x,
             content_y,
-            mut bg_color,
+            bg_color,
             cursor_opacity,
             render_hollow_here,
             opacity_multiplier,
+            corners,
+            paint_cell_bg,
             bg_index,
         } = p;
 
-        use par_term_emu_core_rust::cursor::CursorStyle;
-        match self.cursor.style {
-            CursorStyle::SteadyBlock | CursorStyle::BlinkingBlock if !render_hollow_here => {
-                // Solid block cursor: blend cursor color into background
-                for (bg, &cursor) in bg_color.iter_mut().take(3).zip(&self.cursor.color) {
-                    *bg = *bg * (1.0 - cursor_opacity) + cursor * cursor_opacity;
-                }
-                bg_color[3] = bg_color[3].max(cursor_opacity * opacity_multiplier);
+        // If hollow: keep original background color (outline added as overlay).
+        // A morphing cursor is drawn as an overlay instead.
+        let mut cursor_color = bg_color;
+        let mut corner_radii = [0.0; 4];
+        if self.cursor_fills_cell() && !render_hollow_here {
+            // Solid block cursor: blend cursor color into background
+            for (bg, &cursor) in cursor_color.iter_mut().take(3).zip(&self.cursor.color) {
+                *bg = *bg * (1.0 - cursor_opacity) + cursor * cursor_opacity;
             }
-            // If hollow: keep original background color (outline added as overlay)
-            _ => {}
+            cursor_color[3] = bg_color[3].max(cursor_opacity * opacity_multiplier);
+            corner_radii = corners.radii(self.background_corner_radius());
         }
 
         // Cursor cell can't be merged
@@ -842,22 +886,37 @@ impl CellRenderer {
         let x1 = (content_x + (col + 1) as f32 * self.grid.cell_width).round();
         let y0 = (content_y + row as f32 * self.grid.cell_height).round();
         let y1 = (content_y + (row + 1) as f32 * self.grid.cell_height).round();
+        let position = [
+            x0 / self.config.width as f32 * 2.0 - 1.0,
+            1.0 - (y0 / self.config.height as f32 * 2.0),
+        ];
+        let size = [
+            (x1 - x0) / self.config.width as f32 * 2.0,
+            (y1 - y0) / self.config.height as f32 * 2.0,
+        ];
+
+        let rounded = corner_radii.iter().any(|&radius| radius > 0.0);
+        let cell_quad = (rounded && paint_cell_bg).then_some(BackgroundInstance {
+            position,
+            size,
+            color: bg_color,
+            corner_radii: [0.0; 4],
+        });
+        let cursor_quad = BackgroundInstance {
+            position,
+            size,
+            color: cursor_color,
+            corner_radii,
+        };
 
-        if bg_index < self.buffers.max_bg_instances {
-            self.bg_instances[bg_index] = BackgroundInstance {
-                position: [
-                    x0 / self.config.width as f32 * 2.0 - 1.0,
-                    1.0 - (y0 / self.config.height as f32 * 2.0),
-                ],
-                size: [
-                    (x1 - x0) / self.config.width as f32 * 2.0,
-                    (y1 - y0) / self.config.height as f32 * 2.0,
-                ],
-                color: bg_color,
-            };
-            true
-        } else {
-            false
+        let mut emitted = 0;
+        for quad in cell_quad.into_iter().chain(std::iter::once(cursor_quad)) {
+            if bg_index + emitted >= self.buffers.max_bg_instances {
+                break;
+            }
+            self.bg_instances[bg_index + emitted] = quad;
+            emitted += 1;
         }
+        emitted
     }
 }
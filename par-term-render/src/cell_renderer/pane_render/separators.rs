@@ -45,6 +45,7 @@ impl CellRenderer {
                         self.separator.thickness / height_f * 2.0,
                     ],
                     color,
+                    corner_radii: [0.0; 4],
                 };
                 bg_index += 1;
             }
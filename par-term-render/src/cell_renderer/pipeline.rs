@@ -7,9 +7,9 @@ use wgpu::*;
 
 use super::types::{BackgroundInstance, TextInstance, Vertex};
 
-/// Preferred glyph atlas texture size (width and height in pixels).
-/// This value is validated against device limits at initialization.
-const PREFERRED_ATLAS_SIZE: u32 = 2048;
+/// Smallest glyph atlas page size accepted from configuration (pixels).
+/// Keeps room for the solid pixel block and at least a few glyphs per page.
+const MIN_ATLAS_SIZE: u32 = 256;
 
 /// Size in bytes of the visual bell uniform buffer.
 /// Must be large enough to hold the bell uniforms struct, rounded up to
@@ -89,14 +89,22 @@ pub fn create_bg_pipeline(device: &Device, surface_format: TextureFormat) -> Ren
                 VertexBufferLayout {
                     array_stride: std::mem::size_of::<BackgroundInstance>() as BufferAddress,
                     step_mode: VertexStepMode::Instance,
-                    attributes: &vertex_attr_array![2 => Float32x2, 3 => Float32x2, 4 => Float32x4],
+                    attributes: &vertex_attr_array![
+                        2 => Float32x2,
+                        3 => Float32x2,
+                        4 => Float32x4,
+                        5 => Float32x4
+                    ],
                 },
             ],
         },
         fragment: Some(FragmentState {
             module: &bg_shader,
             entry_point: Some("fs_main"),
-            compilation_options: Default::default(),
+            compilation_options: PipelineCompilationOptions {
+                constants: crate::color_space::pipeline_constants(surface_format),
+                ..Default::default()
+            },
             targets: &[Some(ColorTargetState {
                 format: surface_format,
                 // Use custom blend that replaces alpha to prevent accumulation
@@ -126,7 +134,7 @@ pub fn create_text_bind_group_layout(device: &Device) -> BindGroupLayout {
                 visibility: ShaderStages::FRAGMENT,
                 ty: BindingType::Texture {
                     sample_type: TextureSampleType::Float { filterable: true },
-                    view_dimension: TextureViewDimension::D2,
+                    view_dimension: TextureViewDimension::D2Array,
                     multisampled: false,
                 },
                 count: None,
@@ -200,7 +208,8 @@ pub fn create_text_pipeline(
                         4 => Float32x2,
                         5 => Float32x2,
                         6 => Float32x4,
-                        7 => Uint32
+                        7 => Uint32,
+                        8 => Uint32
                     ],
                 },
             ],
@@ -208,7 +217,10 @@ pub fn create_text_pipeline(
         fragment: Some(FragmentState {
             module: &text_shader,
             entry_point: Some("fs_main"),
-            compilation_options: Default::default(),
+            compilation_options: PipelineCompilationOptions {
+                constants: crate::color_space::pipeline_constants(surface_format),
+                ..Default::default()
+            },
             targets: &[Some(ColorTargetState {
                 format: surface_format,
                 // Use TEXT_BLEND: standard SrcAlpha for RGB, additive for alpha.
@@ -360,7 +372,10 @@ pub fn create_visual_bell_pipeline(
         fragment: Some(FragmentState {
             module: &visual_bell_shader,
             entry_point: Some("fs_main"),
-            compilation_options: Default::default(),
+            compilation_options: PipelineCompilationOptions {
+                constants: crate::color_space::pipeline_constants(surface_format),
+                ..Default::default()
+            },
             targets: &[Some(ColorTargetState {
                 format: surface_format,
                 // Use premultiplied alpha blending for visual bell overlay
@@ -404,43 +419,72 @@ pub fn create_visual_bell_pipeline(
 
 /// Create the glyph atlas texture and sampler.
 ///
-/// Returns (texture, texture_view, sampler, actual_atlas_size).
-/// The actual atlas size may be smaller than PREFERRED_ATLAS_SIZE if the
-/// device has a lower max_texture_dimension_2d limit.
-pub fn create_atlas(device: &Device) -> (Texture, TextureView, Sampler, u32) {
-    let max_texture_size = device.limits().max_texture_dimension_2d;
-    let atlas_size = PREFERRED_ATLAS_SIZE.min(max_texture_size);
-    if atlas_size < PREFERRED_ATLAS_SIZE {
+/// Returns (texture, texture_view, sampler, actual_atlas_size, actual_max_pages).
+/// The texture starts with a single page (array layer); more are added by
+/// `CellRenderer::ensure_atlas_page` as glyphs spill over.  The actual page size
+/// may be smaller than `preferred_size` if the device has a lower
+/// max_texture_dimension_2d limit, and the page count is likewise clamped to
+/// max_texture_array_layers.
+pub fn create_atlas(
+    device: &Device,
+    preferred_size: u32,
+    preferred_max_pages: u32,
+) -> (Texture, TextureView, Sampler, u32, u32) {
+    let limits = device.limits();
+    let preferred_size = preferred_size.max(MIN_ATLAS_SIZE);
+    let atlas_size = preferred_size.min(limits.max_texture_dimension_2d);
+    if atlas_size < preferred_size {
         log::warn!(
-            "GPU texture size limit ({}) is smaller than preferred atlas size ({})",
-            max_texture_size,
-            PREFERRED_ATLAS_SIZE
+            "GPU texture size limit ({}) is smaller than configured atlas size ({})",
+            limits.max_texture_dimension_2d,
+            preferred_size
         );
     }
+    let max_pages = preferred_max_pages.clamp(1, limits.max_texture_array_layers.max(1));
+    let (atlas_texture, atlas_view) = create_atlas_texture(device, atlas_size, 1);
+    let atlas_sampler = device.create_sampler(&SamplerDescriptor {
+        address_mode_u: AddressMode::ClampToEdge,
+        address_mode_v: AddressMode::ClampToEdge,
+        mag_filter: FilterMode::Linear,
+        min_filter: FilterMode::Linear,
+        ..Default::default()
+    });
+
+    (
+        atlas_texture,
+        atlas_view,
+        atlas_sampler,
+        atlas_size,
+        max_pages,
+    )
+}
+
+/// Create a glyph atlas texture array with `pages` layers of `size`×`size` pixels.
+///
+/// The view is always a 2D array view, even for a single layer, so it matches
+/// the text bind group layout.
+pub fn create_atlas_texture(device: &Device, size: u32, pages: u32) -> (Texture, TextureView) {
     let atlas_texture = device.create_texture(&TextureDescriptor {
         label: Some("atlas texture"),
         size: Extent3d {
-            width: atlas_size,
-            height: atlas_size,
-            depth_or_array_layers: 1,
+            width: size,
+            height: size,
+            depth_or_array_layers: pages,
         },
         mip_level_count: 1,
         sample_count: 1,
         dimension: TextureDimension::D2,
         format: TextureFormat::Rgba8Unorm,
-        usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+        // COPY_SRC: existing pages are copied into the larger texture when a page is added
+        usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::COPY_SRC,
         view_formats: &[],
     });
-    let atlas_view = atlas_texture.create_view(&TextureViewDescriptor::default());
-    let atlas_sampler = device.create_sampler(&SamplerDescriptor {
-        address_mode_u: AddressMode::ClampToEdge,
-        address_mode_v: AddressMode::ClampToEdge,
-        mag_filter: FilterMode::Linear,
-        min_filter: FilterMode::Linear,
+    let atlas_view = atlas_texture.create_view(&TextureViewDescriptor {
+        dimension: Some(TextureViewDimension::D2Array),
         ..Default::default()
     });
 
-    (atlas_texture, atlas_view, atlas_sampler, atlas_size)
+    (atlas_texture, atlas_view)
 }
 
 /// Create the vertex buffer with unit quad vertices
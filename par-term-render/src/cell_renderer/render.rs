@@ -170,12 +170,12 @@ impl CellRenderer {
         let clear_color = if use_bg_image_pipeline {
             wgpu::Color::TRANSPARENT
         } else {
-            wgpu::Color {
-                r: self.background_color[0] as f64 * self.window_opacity as f64,
-                g: self.background_color[1] as f64 * self.window_opacity as f64,
-                b: self.background_color[2] as f64 * self.window_opacity as f64,
-                a: self.window_opacity as f64,
-            }
+            let [r, g, b, _] = self.background_color;
+            crate::color_space::clear_color(
+                self.surface_format(),
+                [r, g, b],
+                self.window_opacity as f64,
+            )
         };
 
         let load_op = if clear_first {
@@ -234,12 +234,12 @@ impl CellRenderer {
         let clear_color = if use_bg_image_pipeline {
             wgpu::Color::TRANSPARENT
         } else {
-            wgpu::Color {
-                r: self.background_color[0] as f64 * self.window_opacity as f64,
-                g: self.background_color[1] as f64 * self.window_opacity as f64,
-                b: self.background_color[2] as f64 * self.window_opacity as f64,
-                a: self.window_opacity as f64,
-            }
+            let [r, g, b, _] = self.background_color;
+            crate::color_space::clear_color(
+                self.surface_format(),
+                [r, g, b],
+                self.window_opacity as f64,
+            )
         };
 
         {
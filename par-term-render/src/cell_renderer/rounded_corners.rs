@@ -0,0 +1,278 @@
+//! Rounded corners for selection and cursor backgrounds.
+//!
+//! Selection colors are baked into the cells, so the renderer is also given the
+//! selected region to tell where its outline turns.  A selected cell's corner is
+//! rounded when it is an outer corner of the selection: the cells next to it on
+//! both sides of that corner are unselected.  Inner corners, where a stream
+//! selection steps in from one row to the next, stay square.
+//!
+//! The radius is given in logical pixels and capped at half the cell so a
+//! single selected cell becomes a pill rather than overflowing.  The background
+//! shader cuts the corners out of each quad (see `cell_bg.wgsl`).
+
+use super::CellRenderer;
+
+/// Selected cells of a pane, in viewport cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectionRegion {
+    /// First selected cell (col, row)
+    pub start: (usize, usize),
+    /// Last selected cell (col, row), at or after `start` in reading order
+    pub end: (usize, usize),
+    /// Block selection: the rectangle spanned by `start` and `end`
+    pub rectangular: bool,
+}
+
+impl SelectionRegion {
+    /// Whether the cell at `col`, `row` is selected.
+    pub fn contains(&self, col: usize, row: usize) -> bool {
+        let ((start_col, start_row), (end_col, end_row)) = (self.start, self.end);
+        if self.rectangular {
+            (start_col.min(end_col)..=start_col.max(end_col)).contains(&col)
+                && (start_row.min(end_row)..=start_row.max(end_row)).contains(&row)
+        } else if start_row == end_row {
+            row == start_row && (start_col..=end_col).contains(&col)
+        } else if row == start_row {
+            col >= start_col
+        } else if row == end_row {
+            col <= end_col
+        } else {
+            row > start_row && row < end_row
+        }
+    }
+
+    /// Whether the cell at a possibly off-grid position is selected.
+    fn contains_at(&self, col: Option<usize>, row: Option<usize>, cols: usize) -> bool {
+        match (col, row) {
+            (Some(col), Some(row)) if col < cols => self.contains(col, row),
+            _ => false,
+        }
+    }
+
+    /// Outer corners of the selection at the cell `col`, `row` of a grid
+    /// `cols` cells wide; all square when the cell is not selected.
+    pub(crate) fn cell_corners(&self, col: usize, row: usize, cols: usize) -> RoundedCorners {
+        if !self.contains(col, row) {
+            return RoundedCorners::default();
+        }
+        let above = !self.contains_at(Some(col), row.checked_sub(1), cols);
+        let below = !self.contains_at(Some(col), Some(row + 1), cols);
+        let left = !self.contains_at(col.checked_sub(1), Some(row), cols);
+        let right = !self.contains_at(Some(col + 1), Some(row), cols);
+        RoundedCorners {
+            top_left: above && left,
+            top_right: above && right,
+            bottom_right: below && right,
+            bottom_left: below && left,
+        }
+    }
+
+    /// Outer corners of the selection for a run of cells `start_col..end_col`
+    /// in `row` drawn as one quad: the left corners of its first cell and the
+    /// right corners of its last.
+    pub(crate) fn run_corners(
+        &self,
+        start_col: usize,
+        end_col: usize,
+        row: usize,
+        cols: usize,
+    ) -> RoundedCorners {
+        let first = self.cell_corners(start_col, row, cols);
+        let last = self.cell_corners(end_col.saturating_sub(1), row, cols);
+        RoundedCorners {
+            top_left: first.top_left,
+            top_right: last.top_right,
+            bottom_right: last.bottom_right,
+            bottom_left: first.bottom_left,
+        }
+    }
+}
+
+/// Which corners of a background quad are rounded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct RoundedCorners {
+    pub(crate) top_left: bool,
+    pub(crate) top_right: bool,
+    pub(crate) bottom_right: bool,
+    pub(crate) bottom_left: bool,
+}
+
+impl RoundedCorners {
+    pub(crate) const ALL: Self = Self {
+        top_left: true,
+        top_right: true,
+        bottom_right: true,
+        bottom_left: true,
+    };
+
+    /// Corners of the cell `col` within a span of cells drawn as one shape,
+    /// such as a cursor widened over a ligature: the left corners at its
+    /// first cell and the right corners at its last.
+    pub(crate) fn span_ends(col: usize, span: &std::ops::Range<usize>) -> Self {
+        let first = col == span.start;
+        let last = col + 1 == span.end;
+        Self {
+            top_left: first,
+            top_right: last,
+            bottom_right: last,
+            bottom_left: first,
+        }
+    }
+
+    /// Corner radii in the order the background shader expects: top-left,
+    /// top-right, bottom-right, bottom-left.
+    pub(crate) fn radii(self, radius: f32) -> [f32; 4] {
+        let pick = |rounded: bool| if rounded { radius } else { 0.0 };
+        [
+            pick(self.top_left),
+            pick(self.top_right),
+            pick(self.bottom_right),
+            pick(self.bottom_left),
+        ]
+    }
+}
+
+impl CellRenderer {
+    /// Corner radius for selection and cursor backgrounds in physical pixels,
+    /// capped at half a cell; zero when rounding is off.
+    pub(crate) fn background_corner_radius(&self) -> f32 {
+        (self.selection_corner_radius * self.scale_factor)
+            .min(self.grid.cell_width / 2.0)
+            .min(self.grid.cell_height / 2.0)
+            .max(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLS: usize = 10;
+
+    fn region(start: (usize, usize), end: (usize, usize), rectangular: bool) -> SelectionRegion {
+        SelectionRegion {
+            start,
+            end,
+            rectangular,
+        }
+    }
+
+    /// Cells with any rounded corner, with their corners.
+    fn rounded_cells(
+        selection: &SelectionRegion,
+        rows: usize,
+    ) -> Vec<((usize, usize), RoundedCorners)> {
+        (0..rows)
+            .flat_map(|row| (0..COLS).map(move |col| (col, row)))
+            .map(|(col, row)| ((col, row), selection.cell_corners(col, row, COLS)))
+            .filter(|(_, corners)| *corners != RoundedCorners::default())
+            .collect()
+    }
+
+    fn corners(tl: bool, tr: bool, br: bool, bl: bool) -> RoundedCorners {
+        RoundedCorners {
+            top_left: tl,
+            top_right: tr,
+            bottom_right: br,
+            bottom_left: bl,
+        }
+    }
+
+    #[test]
+    fn rectangular_selection_rounds_its_four_corner_cells() {
+        // Columns 2-5 of rows 1-3, dragged from the bottom-right corner
+        let selection = region((5, 3), (2, 1), true);
+        assert_eq!(
+            rounded_cells(&selection, 6),
+            [
+                ((2, 1), corners(true, false, false, false)),
+                ((5, 1), corners(false, true, false, false)),
+                ((2, 3), corners(false, false, false, true)),
+                ((5, 3), corners(false, false, true, false)),
+            ]
+        );
+
+        // A run drawn as one quad takes the corners of both of its ends
+        assert_eq!(
+            selection.run_corners(2, 6, 1, COLS),
+            corners(true, true, false, false)
+        );
+        assert_eq!(
+            selection.run_corners(2, 6, 2, COLS),
+            RoundedCorners::default()
+        );
+    }
+
+    #[test]
+    fn thin_rectangles_round_both_sides() {
+        // One row: the end cells are rounded top and bottom
+        let row = region((3, 2), (6, 2), true);
+        assert_eq!(
+            rounded_cells(&row, 5),
+            [
+                ((3, 2), corners(true, false, false, true)),
+                ((6, 2), corners(false, true, true, false)),
+            ]
+        );
+        assert_eq!(row.run_corners(3, 7, 2, COLS), RoundedCorners::ALL);
+
+        // One column: the end cells are rounded left and right
+        let column = region((4, 0), (4, 2), true);
+        assert_eq!(
+            rounded_cells(&column, 5),
+            [
+                ((4, 0), corners(true, true, false, false)),
+                ((4, 2), corners(false, false, true, true)),
+            ]
+        );
+
+        // A single cell is rounded all round
+        let cell = region((0, 0), (0, 0), true);
+        assert_eq!(cell.cell_corners(0, 0, COLS), RoundedCorners::ALL);
+    }
+
+    #[test]
+    fn stream_selection_keeps_inner_corners_square() {
+        // From column 6 of row 0 to column 3 of row 2, wrapping through row 1
+        let selection = region((6, 0), (3, 2), false);
+        assert_eq!(
+            rounded_cells(&selection, 4),
+            [
+                ((6, 0), corners(true, false, false, false)),
+                ((9, 0), corners(false, true, false, false)),
+                ((0, 1), corners(true, false, false, false)),
+                ((9, 1), corners(false, false, true, false)),
+                ((0, 2), corners(false, false, false, true)),
+                ((3, 2), corners(false, false, true, false)),
+            ]
+        );
+    }
+
+    #[test]
+    fn radii_follow_shader_corner_order() {
+        assert_eq!(
+            corners(true, false, true, false).radii(4.0),
+            [4.0, 0.0, 4.0, 0.0]
+        );
+        assert_eq!(RoundedCorners::default().radii(4.0), [0.0; 4]);
+    }
+
+    #[test]
+    fn cursor_span_rounds_its_outer_ends() {
+        assert_eq!(RoundedCorners::span_ends(3, &(3..4)), RoundedCorners::ALL);
+        // A cursor widened over a three-cell ligature
+        let span = 3..6;
+        assert_eq!(
+            RoundedCorners::span_ends(3, &span),
+            corners(true, false, false, true)
+        );
+        assert_eq!(
+            RoundedCorners::span_ends(4, &span),
+            RoundedCorners::default()
+        );
+        assert_eq!(
+            RoundedCorners::span_ends(5, &span),
+            corners(false, true, true, false)
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use par_term_config::{SeparatorMark, color_tuple_to_f32_a, color_u8_to_f32};
+use par_term_config::{SeparatorMark, color_tuple_to_f32_a, color_u8_to_f32, color_u8x4_to_f32};
 
 use super::{CellRenderer, PaneViewport};
 
@@ -118,6 +118,36 @@ impl CellRenderer {
         }
     }
 
+    /// Set the drop shadow drawn behind glyphs.
+    /// Offset and blur are in logical pixels; they are scaled when instances are built.
+    pub fn set_text_shadow(&mut self, enabled: bool, color: [u8; 4], offset: [f32; 2], blur: f32) {
+        let shadow = super::text_shadow::TextShadow {
+            enabled,
+            color: color_u8x4_to_f32(color),
+            offset,
+            blur: blur.max(0.0),
+        };
+        if self.text_shadow == shadow {
+            return;
+        }
+        let resize = self.text_shadow.copies_per_glyph() != shadow.copies_per_glyph();
+        self.text_shadow = shadow;
+        if resize {
+            // Each row reserves room for the shadow copies of its glyphs
+            self.recreate_instance_buffers();
+        }
+        self.dirty_rows.fill(true);
+    }
+
+    /// Set the corner radius of selection and cursor backgrounds in logical pixels.
+    pub fn set_selection_corner_radius(&mut self, radius: f32) {
+        let radius = radius.max(0.0);
+        if self.selection_corner_radius != radius {
+            self.selection_corner_radius = radius;
+            self.dirty_rows.fill(true);
+        }
+    }
+
     pub fn set_link_underline_style(&mut self, style: par_term_config::LinkUnderlineStyle) {
         if self.link_underline_style != style {
             self.link_underline_style = style;
@@ -11,6 +11,21 @@ impl CellRenderer {
         &self.supported_present_modes
     }
 
+    /// Adapters able to present to this surface
+    pub fn gpu_adapters(&self) -> &[par_term_config::GpuAdapterInfo] {
+        &self.gpu_adapters
+    }
+
+    /// The adapter rendering this window
+    pub fn adapter_info(&self) -> &par_term_config::GpuAdapterInfo {
+        &self.adapter_info
+    }
+
+    /// Limits of the device in use
+    pub fn device_limits(&self) -> wgpu::Limits {
+        self.device.limits()
+    }
+
     /// Check if a vsync mode is supported
     pub fn is_vsync_mode_supported(&self, mode: par_term_config::VsyncMode) -> bool {
         self.supported_present_modes
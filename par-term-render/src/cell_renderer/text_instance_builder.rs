@@ -1,13 +1,14 @@
 use super::block_chars;
 use super::instance_buffers::{
     GLYPH_SNAP_EXTENSION_PX, GLYPH_SNAP_THRESHOLD_PX, STIPPLE_OFF_PX, STIPPLE_ON_PX,
-    UNDERLINE_HEIGHT_RATIO, compute_cursor_text_color,
+    TEXT_INSTANCES_PER_CELL, UNDERLINE_HEIGHT_RATIO, compute_cursor_text_color,
 };
 use super::{Cell, CellRenderer, TextInstance};
 use par_term_config::color_u8x4_rgb_to_f32_a;
 
 impl CellRenderer {
-    /// Build text (and underline) instances for a single row, populating `self.scratch_row_text`.
+    /// Build text (and underline) instances for a single row, populating `self.scratch_row_text`
+    /// and, with a text shadow, `self.scratch_text_shadows`.
     ///
     /// Handles glyph lookup, atlas rasterization, block-character geometric rendering,
     /// and underline decoration. The scratch buffer is assumed to be cleared on entry.
@@ -34,14 +35,10 @@ impl CellRenderer {
         // Check if this row has the cursor and it's a visible block cursor
         // (for cursor text color override)
         let cursor_is_block_on_this_row = {
-            use par_term_emu_core_rust::cursor::CursorStyle;
             self.cursor.pos.1 == row
                 && self.cursor.opacity > 0.0
                 && !self.cursor.hidden_for_shader
-                && matches!(
-                    self.cursor.style,
-                    CursorStyle::SteadyBlock | CursorStyle::BlinkingBlock
-                )
+                && self.cursor_fills_cell()
                 && (self.is_focused
                     || self.cursor.unfocused_style == par_term_config::UnfocusedCursorStyle::Same)
         };
@@ -72,7 +69,7 @@ impl CellRenderer {
             // Determine text color - use cursor_text_color if this is the cursor position
             // with a block cursor, otherwise use the cell's foreground color
             let render_fg_color: [f32; 4] =
-                if cursor_is_block_on_this_row && current_col == self.cursor.pos.0 {
+                if cursor_is_block_on_this_row && self.cursor.cell_span.contains(&current_col) {
                     compute_cursor_text_color(self.cursor.color, self.cursor.text_color, text_alpha)
                 } else {
                     // Determine the effective background color for contrast calculation
@@ -189,6 +186,7 @@ impl CellRenderer {
                                 ],
                                 color: render_fg_color,
                                 is_colored: 0,
+                                atlas_page: 0,
                             });
                         }
                         x_offset += self.grid.cell_width;
@@ -242,6 +240,7 @@ impl CellRenderer {
                             tex_size,
                             color: top_color,
                             is_colored: 0,
+                            atlas_page: 0,
                         });
 
                         // Bottom half: [y_mid, y1)
@@ -258,6 +257,7 @@ impl CellRenderer {
                             tex_size,
                             color: bottom_color,
                             is_colored: 0,
+                            atlas_page: 0,
                         });
 
                         x_offset += self.grid.cell_width;
@@ -311,6 +311,7 @@ impl CellRenderer {
                             ],
                             color: render_fg_color,
                             is_colored: 0,
+                            atlas_page: 0,
                         });
 
                         x_offset += self.grid.cell_width;
@@ -347,6 +348,7 @@ impl CellRenderer {
                             ],
                             color: render_fg_color,
                             is_colored: 0,
+                            atlas_page: 0,
                         });
 
                         x_offset += self.grid.cell_width;
@@ -356,20 +358,10 @@ impl CellRenderer {
                 }
 
                 // Check if this character should be rendered as a monochrome symbol
-                // (dingbats, etc.) rather than colorful emoji.
-                // Also handle symbol + VS16 (U+FE0F emoji presentation selector):
-                // in terminal contexts, symbols should remain monochrome even with VS16.
-                let (force_monochrome, base_char) = if grapheme_len == 1 {
-                    (super::atlas::should_render_as_symbol(ch), ch)
-                } else if grapheme_len == 2
-                    && second_char == Some('\u{FE0F}')
-                    && super::atlas::should_render_as_symbol(ch)
-                {
-                    // Symbol + VS16: strip VS16 and render base char as monochrome
-                    (true, ch)
-                } else {
-                    (false, ch)
-                };
+                // (dingbats, etc.) rather than colorful emoji.  A VS15 or VS16
+                // presentation selector after it overrides the default.
+                let force_monochrome = super::atlas::should_render_grapheme_as_symbol(grapheme);
+                let base_char = ch;
 
                 // Resolve a renderable glyph via the shared font-fallback helper (ARC-004).
                 // This replaces the duplicated excluded_fonts/get_or_rasterize_glyph loop
@@ -466,7 +458,7 @@ impl CellRenderer {
                     (glyph_left, glyph_top, render_w, render_h)
                 };
 
-                self.scratch_row_text.push(TextInstance {
+                let glyph = TextInstance {
                     position: [
                         final_left / self.config.width as f32 * 2.0 - 1.0,
                         1.0 - (final_top / self.config.height as f32 * 2.0),
@@ -485,7 +477,10 @@ impl CellRenderer {
                     ],
                     color: render_fg_color,
                     is_colored: if info.is_colored { 1 } else { 0 },
-                });
+                    atlas_page: info.page,
+                };
+                self.push_text_shadow(&glyph);
+                self.scratch_row_text.push(glyph);
             }
             x_offset += self.grid.cell_width;
             current_col += 1;
@@ -516,10 +511,11 @@ impl CellRenderer {
             let stipple_on = STIPPLE_ON_PX;
             let stipple_off = STIPPLE_OFF_PX;
             let stipple_period = stipple_on + stipple_off;
+            let row_text_capacity = self.grid.cols * TEXT_INSTANCES_PER_CELL;
 
             for col_idx in 0..self.grid.cols {
                 let cell = &self.cells[start + col_idx];
-                if !cell.underline || self.scratch_row_text.len() >= self.grid.cols * 2 {
+                if !cell.underline || self.scratch_row_text.len() >= row_text_capacity {
                     continue;
                 }
                 let text_alpha = if self.keep_text_opaque {
@@ -536,7 +532,7 @@ impl CellRenderer {
                     // Emit alternating dot segments across the cell width
                     let mut px = 0.0;
                     while px < self.grid.cell_width
-                        && self.scratch_row_text.len() < self.grid.cols * 2
+                        && self.scratch_row_text.len() < row_text_capacity
                     {
                         let seg_w = stipple_on.min(self.grid.cell_width - px);
                         let x = cell_x0 + px;
@@ -547,6 +543,7 @@ impl CellRenderer {
                             tex_size,
                             color: fg,
                             is_colored: 0,
+                            atlas_page: 0,
                         });
                         px += stipple_period;
                     }
@@ -558,6 +555,7 @@ impl CellRenderer {
                         tex_size,
                         color: fg,
                         is_colored: 0,
+                        atlas_page: 0,
                     });
                 }
             }
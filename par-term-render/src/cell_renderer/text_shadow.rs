@@ -0,0 +1,219 @@
+//! Text drop shadow for readability over busy backgrounds.
+//!
+//! With a shadow enabled, the glyph pass draws every atlas glyph a second time
+//! behind itself, as a silhouette in the shadow color shifted by the offset.
+//! All shadow instances come before any glyph in the text instance buffer, so a
+//! shadow never lands on top of a neighbouring glyph.  Box-drawing and block
+//! characters and underlines are left without a shadow so they keep tiling
+//! cleanly.
+//!
+//! The offset and blur radius are given in logical pixels and scaled by the
+//! display's scale factor, so the shadow looks the same on every monitor.  A
+//! blur radius spreads the shadow over [`BLUR_TAPS`] fainter copies around the
+//! offset, which softens its edges without an extra render pass; the copies'
+//! opacities are chosen so that where all of them overlap the shadow has the
+//! configured alpha.
+
+use super::{CellRenderer, TEXT_INSTANCES_PER_CELL, TextInstance};
+
+/// Copies drawn for a blurred shadow, one towards each diagonal.
+pub(crate) const BLUR_TAPS: usize = 4;
+
+/// Shadow drawn behind glyphs.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) struct TextShadow {
+    pub(crate) enabled: bool,
+    /// Shadow color [R, G, B, A] as floats (0.0-1.0)
+    pub(crate) color: [f32; 4],
+    /// Offset from the glyph in logical pixels [x, y]
+    pub(crate) offset: [f32; 2],
+    /// Blur radius in logical pixels (0 = hard shadow)
+    pub(crate) blur: f32,
+}
+
+impl TextShadow {
+    /// Shadow copies drawn behind each glyph; zero when no shadow is visible.
+    pub(crate) fn copies_per_glyph(&self) -> usize {
+        if !self.enabled || self.color[3] <= 0.0 {
+            0
+        } else if self.blur > 0.0 {
+            BLUR_TAPS
+        } else {
+            1
+        }
+    }
+
+    /// Offset of each shadow copy in physical pixels, with the opacity
+    /// multiplier for its color.
+    pub(crate) fn taps(&self, scale_factor: f32) -> impl Iterator<Item = ([f32; 2], f32)> {
+        const DIAGONALS: [(f32, f32); BLUR_TAPS] =
+            [(-1.0, -1.0), (1.0, -1.0), (-1.0, 1.0), (1.0, 1.0)];
+        let copies = self.copies_per_glyph();
+        let offset = [self.offset[0] * scale_factor, self.offset[1] * scale_factor];
+        let (directions, spread, multiplier): (&[(f32, f32)], f32, f32) = if copies > 1 {
+            // Per-copy alpha so that `copies` overlapping layers blend to the
+            // configured alpha: 1 - (1 - a)^n = alpha
+            let alpha = self.color[3].min(1.0);
+            let tap_alpha = 1.0 - (1.0 - alpha).powf(1.0 / copies as f32);
+            (
+                &DIAGONALS,
+                self.blur * scale_factor / 2.0,
+                tap_alpha / alpha,
+            )
+        } else {
+            (&[(0.0, 0.0)], 0.0, 1.0)
+        };
+        directions.iter().take(copies).map(move |(dx, dy)| {
+            (
+                [offset[0] + dx * spread, offset[1] + dy * spread],
+                multiplier,
+            )
+        })
+    }
+
+    /// Push the shadow instances for `glyph` onto `out`.
+    ///
+    /// `surface` is the render target size in physical pixels, used to turn the
+    /// pixel offset into NDC.  The shadow takes the glyph's alpha (window
+    /// opacity) on top of its own.
+    pub(crate) fn push_instances(
+        &self,
+        glyph: &TextInstance,
+        scale_factor: f32,
+        surface: (f32, f32),
+        out: &mut Vec<TextInstance>,
+    ) {
+        out.extend(self.taps(scale_factor).map(|(offset, alpha)| TextInstance {
+            position: [
+                glyph.position[0] + offset[0] / surface.0 * 2.0,
+                glyph.position[1] - offset[1] / surface.1 * 2.0,
+            ],
+            color: [
+                self.color[0],
+                self.color[1],
+                self.color[2],
+                self.color[3] * alpha * glyph.color[3],
+            ],
+            // Draw colored glyphs (emoji) as a silhouette too
+            is_colored: 0,
+            ..*glyph
+        }));
+    }
+}
+
+impl CellRenderer {
+    /// Text instances reserved per cell: room for a wide glyph and its
+    /// underline, plus the shadow copies of each.
+    pub(crate) fn text_instances_per_cell(&self) -> usize {
+        TEXT_INSTANCES_PER_CELL + self.text_shadow_instances_per_cell()
+    }
+
+    /// Shadow instances reserved per cell, ahead of every row's glyphs.
+    pub(crate) fn text_shadow_instances_per_cell(&self) -> usize {
+        TEXT_INSTANCES_PER_CELL * self.text_shadow.copies_per_glyph()
+    }
+
+    /// Push the shadow instances for `glyph` onto `scratch_text_shadows`,
+    /// scaled for this renderer's surface.
+    pub(crate) fn push_text_shadow(&mut self, glyph: &TextInstance) {
+        self.text_shadow.push_instances(
+            glyph,
+            self.scale_factor,
+            (self.config.width as f32, self.config.height as f32),
+            &mut self.scratch_text_shadows,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glyph() -> TextInstance {
+        TextInstance {
+            position: [0.0, 0.0],
+            size: [0.1, 0.2],
+            tex_offset: [0.25, 0.5],
+            tex_size: [0.01, 0.02],
+            color: [1.0, 1.0, 1.0, 1.0],
+            is_colored: 1,
+            atlas_page: 3,
+        }
+    }
+
+    fn shadow() -> TextShadow {
+        TextShadow {
+            enabled: true,
+            color: [0.0, 0.0, 0.0, 0.8],
+            offset: [2.0, 3.0],
+            blur: 0.0,
+        }
+    }
+
+    fn instances(shadow: &TextShadow, scale_factor: f32, surface: (f32, f32)) -> Vec<TextInstance> {
+        let mut out = Vec::new();
+        shadow.push_instances(&glyph(), scale_factor, surface, &mut out);
+        out
+    }
+
+    #[test]
+    fn offset_scales_with_scale_factor() {
+        let surface = (800.0, 600.0);
+        let at_1x = instances(&shadow(), 1.0, surface);
+        let at_2x = instances(&shadow(), 2.0, surface);
+        assert_eq!(at_1x.len(), 1);
+        assert_eq!(at_2x.len(), 1);
+
+        // 2px right and 3px down at 1x; twice that on a 2x display
+        assert!((at_1x[0].position[0] - 2.0 / 800.0 * 2.0).abs() < 1e-6);
+        assert!((at_1x[0].position[1] + 3.0 / 600.0 * 2.0).abs() < 1e-6);
+        assert!((at_2x[0].position[0] - 4.0 / 800.0 * 2.0).abs() < 1e-6);
+        assert!((at_2x[0].position[1] + 6.0 / 600.0 * 2.0).abs() < 1e-6);
+
+        // Same glyph from the atlas, as a silhouette in the shadow color
+        let instance = at_2x[0];
+        assert_eq!(instance.size, glyph().size);
+        assert_eq!(instance.tex_offset, glyph().tex_offset);
+        assert_eq!(instance.atlas_page, 3);
+        assert_eq!(instance.is_colored, 0);
+        assert_eq!(instance.color, [0.0, 0.0, 0.0, 0.8]);
+    }
+
+    #[test]
+    fn disabled_shadow_produces_no_instances() {
+        let disabled = TextShadow {
+            enabled: false,
+            ..shadow()
+        };
+        assert_eq!(disabled.copies_per_glyph(), 0);
+        assert!(instances(&disabled, 2.0, (800.0, 600.0)).is_empty());
+
+        let transparent = TextShadow {
+            color: [0.0, 0.0, 0.0, 0.0],
+            blur: 2.0,
+            ..shadow()
+        };
+        assert_eq!(transparent.copies_per_glyph(), 0);
+        assert!(instances(&transparent, 1.0, (800.0, 600.0)).is_empty());
+        assert!(TextShadow::default().taps(1.0).next().is_none());
+    }
+
+    #[test]
+    fn blur_spreads_copies_that_add_up_to_the_shadow_alpha() {
+        let blurred = TextShadow {
+            blur: 2.0,
+            ..shadow()
+        };
+        let taps: Vec<_> = blurred.taps(2.0).collect();
+        assert_eq!(taps.len(), BLUR_TAPS);
+        // Spread is half the scaled radius around the scaled offset
+        assert_eq!(taps[0].0, [2.0, 4.0]);
+        assert_eq!(taps[3].0, [6.0, 8.0]);
+
+        let instances = instances(&blurred, 2.0, (800.0, 600.0));
+        let remaining = instances
+            .iter()
+            .fold(1.0, |acc, instance| acc * (1.0 - instance.color[3]));
+        assert!((1.0 - remaining - 0.8).abs() < 1e-5);
+    }
+}
@@ -13,6 +13,8 @@ pub(crate) struct BackgroundInstance {
     pub position: [f32; 2],
     pub size: [f32; 2],
     pub color: [f32; 4],
+    /// Corner radii in pixels: top-left, top-right, bottom-right, bottom-left
+    pub corner_radii: [f32; 4],
 }
 
 /// Instance data for text rendering
@@ -25,6 +27,7 @@ pub(crate) struct TextInstance {
     pub tex_size: [f32; 2],
     pub color: [f32; 4],
     pub is_colored: u32, // 1 for emoji/colored glyphs, 0 for regular text
+    pub atlas_page: u32, // Atlas page (texture array layer) holding the glyph
 }
 
 // Re-export Cell from par-term-config (shared type used by terminal and renderer)
@@ -35,6 +38,8 @@ pub use par_term_config::Cell;
 pub(crate) struct GlyphInfo {
     #[allow(dead_code)] // Stored for LRU node identity (maps back to HashMap key)
     pub key: u64,
+    /// Atlas page (texture array layer) the glyph was uploaded to
+    pub page: u32,
     pub x: u32,
     pub y: u32,
     pub width: u32,
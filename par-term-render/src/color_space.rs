@@ -0,0 +1,180 @@
+//! sRGB / linear color handling for the render pipeline.
+//!
+//! With [`BlendColorSpace::Srgb`] the surface uses a plain `Unorm` format:
+//! shaders write the sRGB-encoded theme colors unchanged and the hardware
+//! blends those encoded values.
+//!
+//! With [`BlendColorSpace::Linear`] the surface uses an `*Srgb` format, so the
+//! hardware blends in linear light and encodes on write.  Shaders convert
+//! their sRGB inputs with `srgb_to_linear` (enabled through the
+//! `linear_blending` pipeline constant, see [`pipeline_constants`]).
+//!
+//! Background images are uploaded as `Rgba8UnormSrgb` in both modes, so the
+//! default mode draws them exactly as before; only on the sRGB surface of the
+//! linear mode is the decode on sampling undone on write.
+//!
+//! Custom shaders see the same values in both modes: they sample the
+//! terminal content through a non-sRGB view of their intermediate texture,
+//! and on an sRGB target the GLSL wrapper decodes their output (enabled
+//! through [`custom_shader_constants`]), so the encode on write stores what
+//! sRGB mode stores.  Adapters without view format support (some GL
+//! drivers) therefore always blend in sRGB.
+
+use par_term_config::BlendColorSpace;
+use wgpu::TextureFormat;
+
+/// Pipeline-overridable constant that switches shaders to linear output.
+pub const LINEAR_BLENDING_CONSTANT: &str = "linear_blending";
+
+/// Id of the custom shader wrapper's linear-output constant.
+pub const CUSTOM_SHADER_LINEAR_BLENDING_ID: &str = "0";
+
+/// Decode one sRGB-encoded channel (0.0-1.0) to linear light.
+pub fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encode one linear channel (0.0-1.0) as sRGB.
+pub fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Decode an sRGB-encoded RGBA color; alpha is already linear.
+pub fn srgba_to_linear([r, g, b, a]: [f32; 4]) -> [f32; 4] {
+    [srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b), a]
+}
+
+/// Pick the surface format for `color_space` from the supported `formats`.
+///
+/// Falls back to the first supported format when none matches.
+pub fn select_surface_format(
+    formats: &[TextureFormat],
+    color_space: BlendColorSpace,
+) -> Option<TextureFormat> {
+    let want_srgb = color_space == BlendColorSpace::Linear;
+    formats
+        .iter()
+        .copied()
+        .find(|f| f.is_srgb() == want_srgb)
+        .or_else(|| formats.first().copied())
+}
+
+/// Premultiplied clear color for sRGB-encoded `rgb` at `alpha` on
+/// `surface_format`.
+///
+/// Clear values are written as-is, so an sRGB surface needs them in linear
+/// light.
+pub fn clear_color(surface_format: TextureFormat, rgb: [f32; 3], alpha: f64) -> wgpu::Color {
+    let channel = |c: f32| {
+        let c = if surface_format.is_srgb() {
+            srgb_to_linear(c)
+        } else {
+            c
+        };
+        c as f64 * alpha
+    };
+    wgpu::Color {
+        r: channel(rgb[0]),
+        g: channel(rgb[1]),
+        b: channel(rgb[2]),
+        a: alpha,
+    }
+}
+
+/// Fragment shader constants for pipelines that render to `surface_format`.
+pub fn pipeline_constants(surface_format: TextureFormat) -> &'static [(&'static str, f64)] {
+    if surface_format.is_srgb() {
+        &[(LINEAR_BLENDING_CONSTANT, 1.0)]
+    } else {
+        &[]
+    }
+}
+
+/// Fragment shader constants for custom shader pipelines that render to
+/// `target_format`.
+///
+/// The GLSL wrapper declares its linear-output flag as specialization
+/// constant 0, which keeps the id in WGSL, so the constant is keyed by id.
+pub fn custom_shader_constants(target_format: TextureFormat) -> &'static [(&'static str, f64)] {
+    if target_format.is_srgb() {
+        &[(CUSTOM_SHADER_LINEAR_BLENDING_ID, 1.0)]
+    } else {
+        &[]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn srgb_and_linear_conversions_match_known_values() {
+        assert_eq!(srgb_to_linear(0.0), 0.0);
+        assert!(close(srgb_to_linear(1.0), 1.0));
+        // Mid grey #808080 is about 21.6% linear light
+        assert!(close(srgb_to_linear(128.0 / 255.0), 0.215_861));
+        assert!(close(srgb_to_linear(0.5), 0.214_041));
+        // Linear segment below the threshold
+        assert!(close(srgb_to_linear(0.04), 0.04 / 12.92));
+
+        assert!(close(linear_to_srgb(0.214_041), 0.5));
+        assert!(close(linear_to_srgb(0.18), 0.461_356));
+        assert!(close(linear_to_srgb(0.002), 0.002 * 12.92));
+
+        for i in 0..=255 {
+            let c = i as f32 / 255.0;
+            assert!(close(linear_to_srgb(srgb_to_linear(c)), c), "{i}");
+        }
+
+        let [r, g, b, a] = srgba_to_linear([1.0, 0.5, 0.0, 0.5]);
+        assert!(close(r, 1.0) && close(g, 0.214_041) && b == 0.0);
+        assert_eq!(a, 0.5, "alpha is not converted");
+    }
+
+    #[test]
+    fn formats_follow_blend_color_space() {
+        let formats = [TextureFormat::Bgra8UnormSrgb, TextureFormat::Bgra8Unorm];
+        assert_eq!(
+            select_surface_format(&formats, BlendColorSpace::Srgb),
+            Some(TextureFormat::Bgra8Unorm)
+        );
+        assert_eq!(
+            select_surface_format(&formats, BlendColorSpace::Linear),
+            Some(TextureFormat::Bgra8UnormSrgb)
+        );
+        assert_eq!(
+            select_surface_format(&formats[1..], BlendColorSpace::Linear),
+            Some(TextureFormat::Bgra8Unorm),
+            "falls back to what the surface supports"
+        );
+        assert_eq!(select_surface_format(&[], BlendColorSpace::Srgb), None);
+
+        let gamma = clear_color(TextureFormat::Bgra8Unorm, [0.5, 1.0, 0.0], 0.5);
+        assert_eq!((gamma.r, gamma.g, gamma.b, gamma.a), (0.25, 0.5, 0.0, 0.5));
+        let linear = clear_color(TextureFormat::Bgra8UnormSrgb, [0.5, 1.0, 0.0], 1.0);
+        assert!(close(linear.r as f32, 0.214_041) && linear.a == 1.0);
+
+        assert!(pipeline_constants(TextureFormat::Bgra8Unorm).is_empty());
+        assert_eq!(
+            pipeline_constants(TextureFormat::Bgra8UnormSrgb),
+            &[(LINEAR_BLENDING_CONSTANT, 1.0)]
+        );
+        assert!(custom_shader_constants(TextureFormat::Rgba8Unorm).is_empty());
+        assert_eq!(
+            custom_shader_constants(TextureFormat::Rgba8UnormSrgb),
+            &[(CUSTOM_SHADER_LINEAR_BLENDING_ID, 1.0)]
+        );
+    }
+}
@@ -26,6 +26,7 @@ mod builtin_textures;
 mod cubemap;
 mod cursor;
 mod hot_reload;
+pub(crate) mod overscan;
 pub mod pipeline;
 mod state;
 pub mod textures;
@@ -80,6 +81,9 @@ pub struct CustomShaderRenderer {
     pub(crate) intermediate_texture: Texture,
     /// View of the intermediate texture
     pub(crate) intermediate_texture_view: TextureView,
+    /// Non-sRGB view of the intermediate texture that the shader samples as
+    /// `iChannel4` (see [`crate::color_space`])
+    pub(crate) intermediate_sample_view: TextureView,
     /// Start time for animation
     pub(crate) start_time: Instant,
     /// Whether animation is enabled
@@ -307,7 +311,7 @@ impl CustomShaderRenderer {
         });
 
         // Create intermediate texture for terminal content
-        let (intermediate_texture, intermediate_texture_view) =
+        let (intermediate_texture, intermediate_texture_view, intermediate_sample_view) =
             Self::create_intermediate_texture(device, surface_format, width, height);
 
         // Create sampler for the intermediate texture (terminal content)
@@ -358,7 +362,7 @@ impl CustomShaderRenderer {
             BindGroupInputs {
                 layout: &bind_group_layout,
                 uniform_buffer: &uniform_buffer,
-                intermediate_texture_view: &intermediate_texture_view,
+                intermediate_texture_view: &intermediate_sample_view,
                 custom_uniform_buffer: &custom_uniform_buffer,
                 sampler: &sampler,
                 channel_textures: &channel_textures,
@@ -383,6 +387,7 @@ impl CustomShaderRenderer {
             custom_uniform_buffer,
             intermediate_texture,
             intermediate_texture_view,
+            intermediate_sample_view,
             start_time: now,
             animation_enabled,
             animation_speed,
@@ -607,4 +612,124 @@ mod tests {
         );
         std::fs::remove_file(&path).expect("remove debug wgsl");
     }
+
+    /// Headless device able to run linear blending, or `None` when no such
+    /// adapter is available.
+    fn test_device() -> Option<(Device, Queue)> {
+        let instance = Instance::new(InstanceDescriptor::new_without_display_handle());
+        let adapter =
+            pollster::block_on(instance.request_adapter(&RequestAdapterOptions::default())).ok()?;
+        if !adapter
+            .get_downlevel_capabilities()
+            .flags
+            .contains(DownlevelFlags::VIEW_FORMATS)
+        {
+            return None;
+        }
+        pollster::block_on(adapter.request_device(&DeviceDescriptor::default())).ok()
+    }
+
+    /// Render `shader_path` into a 1x1 `format` target and read the pixel back.
+    fn render_pixel(
+        device: &Device,
+        queue: &Queue,
+        shader_path: &Path,
+        format: TextureFormat,
+    ) -> [u8; 4] {
+        let mut renderer = CustomShaderRenderer::new(
+            device,
+            queue,
+            CustomShaderRendererConfig {
+                surface_format: format,
+                shader_path,
+                width: 1,
+                height: 1,
+                animation_enabled: false,
+                animation_speed: 1.0,
+                window_opacity: 1.0,
+                full_content_mode: false,
+                channel_paths: &[None, None, None, None],
+                cubemap_path: None,
+                custom_uniforms: &BTreeMap::new(),
+                background_channel0_blend_mode: Default::default(),
+            },
+        )
+        .expect("create custom shader renderer");
+        let size = Extent3d {
+            width: 1,
+            height: 1,
+            depth_or_array_layers: 1,
+        };
+        let target = device.create_texture(&TextureDescriptor {
+            label: Some("Custom Shader Test Target"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format,
+            usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = target.create_view(&TextureViewDescriptor::default());
+        renderer
+            .render(device, queue, &view, true)
+            .expect("render custom shader");
+
+        let buffer = device.create_buffer(&BufferDescriptor {
+            label: Some("Custom Shader Test Readback"),
+            size: COPY_BYTES_PER_ROW_ALIGNMENT as u64,
+            usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor::default());
+        encoder.copy_texture_to_buffer(
+            target.as_image_copy(),
+            TexelCopyBufferInfo {
+                buffer: &buffer,
+                layout: TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(COPY_BYTES_PER_ROW_ALIGNMENT),
+                    rows_per_image: None,
+                },
+            },
+            size,
+        );
+        queue.submit(std::iter::once(encoder.finish()));
+        let slice = buffer.slice(..);
+        slice.map_async(MapMode::Read, |result| result.expect("map readback buffer"));
+        device
+            .poll(PollType::wait_indefinitely())
+            .expect("poll device");
+        let data = slice.get_mapped_range();
+        [data[0], data[1], data[2], data[3]]
+    }
+
+    #[test]
+    fn shader_color_is_the_same_in_linear_blending_mode() {
+        let Some((device, queue)) = test_device() else {
+            eprintln!("No GPU adapter with view format support, skipping");
+            return;
+        };
+        let shader_path =
+            std::env::temp_dir().join(format!("par_term_test_{}.glsl", std::process::id()));
+        std::fs::write(
+            &shader_path,
+            "void mainImage(out vec4 fragColor, in vec2 fragCoord) {\n\
+             fragColor = vec4(0.5, 0.25, 0.75, 1.0);\n\
+             }\n",
+        )
+        .expect("write test shader");
+
+        let srgb_blending = render_pixel(&device, &queue, &shader_path, TextureFormat::Rgba8Unorm);
+        let linear_blending =
+            render_pixel(&device, &queue, &shader_path, TextureFormat::Rgba8UnormSrgb);
+        std::fs::remove_file(&shader_path).expect("remove test shader");
+
+        for (srgb, linear) in srgb_blending.iter().zip(linear_blending) {
+            assert!(
+                srgb.abs_diff(linear) <= 1,
+                "{srgb_blending:?} != {linear_blending:?}"
+            );
+        }
+    }
 }
@@ -0,0 +1,87 @@
+//! Content overscan for shader full-bleed effects.
+//!
+//! When the shader reads terminal content (iChannel4), each pane is drawn
+//! into the shader's intermediate texture clipped to its own bounds.  Glows
+//! and other full-bleed effects near the grid edge then stop abruptly at the
+//! pane rectangle.  `custom_shader_overscan` grows that clip rectangle by a
+//! fixed number of pixels on every side, clamped to the render target.
+
+use crate::cell_renderer::PaneViewport;
+
+/// Scissor rect `(x, y, width, height)` for drawing `viewport` into a
+/// `target_width` x `target_height` texture, grown by `overscan` pixels on
+/// every side.
+///
+/// The rect never leaves the target and is at least one pixel in each
+/// dimension, as wgpu requires.
+pub(crate) fn overscan_scissor_rect(
+    viewport: &PaneViewport,
+    overscan: f32,
+    target_width: u32,
+    target_height: u32,
+) -> (u32, u32, u32, u32) {
+    let overscan = overscan.max(0.0);
+    let max_x = target_width.saturating_sub(1) as f32;
+    let max_y = target_height.saturating_sub(1) as f32;
+
+    let left = (viewport.x - overscan).clamp(0.0, max_x);
+    let top = (viewport.y - overscan).clamp(0.0, max_y);
+    let right = (viewport.x + viewport.width + overscan).min(target_width as f32);
+    let bottom = (viewport.y + viewport.height + overscan).min(target_height as f32);
+
+    (
+        left as u32,
+        top as u32,
+        (right - left).max(1.0) as u32,
+        (bottom - top).max(1.0) as u32,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn viewport(x: f32, y: f32, width: f32, height: f32) -> PaneViewport {
+        PaneViewport::new(x, y, width, height, true, 1.0)
+    }
+
+    #[test]
+    fn zero_overscan_matches_pane_bounds() {
+        let pane = viewport(100.0, 50.0, 400.0, 300.0);
+        assert_eq!(
+            overscan_scissor_rect(&pane, 0.0, 1000, 800),
+            pane.to_scissor_rect()
+        );
+        // Negative values are treated as zero
+        assert_eq!(
+            overscan_scissor_rect(&pane, -8.0, 1000, 800),
+            (100, 50, 400, 300)
+        );
+    }
+
+    #[test]
+    fn overscan_grows_every_side() {
+        let pane = viewport(100.0, 50.0, 400.0, 300.0);
+        assert_eq!(
+            overscan_scissor_rect(&pane, 12.0, 1000, 800),
+            (88, 38, 424, 324)
+        );
+    }
+
+    #[test]
+    fn overscan_is_clamped_to_the_target() {
+        // Full-window pane: nothing to grow into
+        let full = viewport(0.0, 0.0, 1000.0, 800.0);
+        assert_eq!(
+            overscan_scissor_rect(&full, 16.0, 1000, 800),
+            (0, 0, 1000, 800)
+        );
+
+        // Pane in the bottom-right corner grows only up and left
+        let corner = viewport(600.0, 500.0, 400.0, 300.0);
+        assert_eq!(
+            overscan_scissor_rect(&corner, 20.0, 1000, 800),
+            (580, 480, 420, 320)
+        );
+    }
+}
@@ -303,7 +303,10 @@ pub fn create_render_pipeline(
                 blend: Some(BlendState::PREMULTIPLIED_ALPHA_BLENDING),
                 write_mask: ColorWrites::ALL,
             })],
-            compilation_options: Default::default(),
+            compilation_options: PipelineCompilationOptions {
+                constants: crate::color_space::custom_shader_constants(surface_format),
+                ..Default::default()
+            },
         }),
         primitive: PrimitiveState {
             topology: PrimitiveTopology::TriangleStrip,
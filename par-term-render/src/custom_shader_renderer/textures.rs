@@ -331,12 +331,24 @@ impl CustomShaderRenderer {
     ///
     /// The terminal scene is rendered into this texture first; the custom
     /// shader then reads it via `iChannel4`.
+    ///
+    /// Returns the texture, the view to render into and the view the shader
+    /// samples.  The texture uses the surface format, so with
+    /// `blend_color_space: linear` it is sRGB; the sampled view drops the
+    /// sRGB decode so shaders see the same encoded values in both modes (see
+    /// [`crate::color_space`]).
     pub(super) fn create_intermediate_texture(
         device: &Device,
         format: TextureFormat,
         width: u32,
         height: u32,
-    ) -> (Texture, TextureView) {
+    ) -> (Texture, TextureView, TextureView) {
+        let sample_format = format.remove_srgb_suffix();
+        let view_formats = if sample_format == format {
+            &[][..]
+        } else {
+            std::slice::from_ref(&sample_format)
+        };
         let texture = device.create_texture(&TextureDescriptor {
             label: Some("Custom Shader Intermediate Texture"),
             size: Extent3d {
@@ -349,11 +361,15 @@ impl CustomShaderRenderer {
             dimension: TextureDimension::D2,
             format,
             usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
-            view_formats: &[],
+            view_formats,
         });
 
         let view = texture.create_view(&TextureViewDescriptor::default());
-        (texture, view)
+        let sample_view = texture.create_view(&TextureViewDescriptor {
+            format: Some(sample_format),
+            ..Default::default()
+        });
+        (texture, view, sample_view)
     }
 
     /// Clear the intermediate texture (e.g., when switching to split pane mode).
@@ -396,10 +412,11 @@ impl CustomShaderRenderer {
         self.texture_height = height;
 
         // Recreate intermediate texture
-        let (texture, view) =
+        let (texture, view, sample_view) =
             Self::create_intermediate_texture(device, self.surface_format, width, height);
         self.intermediate_texture = texture;
         self.intermediate_texture_view = view;
+        self.intermediate_sample_view = sample_view;
 
         // Recreate bind group with new texture view (handles background as channel0 if enabled)
         self.recreate_bind_group(device);
@@ -487,7 +504,7 @@ impl CustomShaderRenderer {
                 // iChannel4 (terminal content)
                 wgpu::BindGroupEntry {
                     binding: 9,
-                    resource: wgpu::BindingResource::TextureView(&self.intermediate_texture_view),
+                    resource: wgpu::BindingResource::TextureView(&self.intermediate_sample_view),
                 },
                 wgpu::BindGroupEntry {
                     binding: 10,
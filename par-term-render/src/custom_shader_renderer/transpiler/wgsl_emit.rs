@@ -114,6 +114,20 @@ layout(set = 0, binding = 13) uniform CustomShaderControls {{
     vec4 iCustomVec2Uniforms[16];
 }};
 
+// Set for sRGB targets (blend_color_space: linear): the output is decoded to
+// linear light so the hardware's encode on write stores what an sRGB-mode
+// target stores.  Keyed by id in the pipeline constants (see color_space.rs).
+layout(constant_id = 0) const bool _linearBlending = false;
+
+vec3 _outputRgb(vec3 c) {{
+    if (!_linearBlending) {{
+        return c;
+    }}
+    vec3 low = c / 12.92;
+    vec3 high = pow((c + 0.055) / 1.055, vec3(2.4));
+    return mix(high, low, step(c, vec3(0.04045)));
+}}
+
 // Combined samplers for texture() calls
 #define iChannel0 sampler2D(_iChannel0Tex, _iChannel0Sampler)
 #define iChannel1 sampler2D(_iChannel1Tex, _iChannel1Sampler)
@@ -183,7 +197,7 @@ void main() {{
         float readabilityDim = mix(1.0, max(0.0, 1.0 - iReadability.y), iReadability.x * hasContent);
         vec3 readableShaderRgb = dimmedShaderRgb * readabilityDim;
         vec3 shaderOverBg = readableShaderRgb + bgColor * (1.0 - terminalColor.a);
-        vec3 finalRgb = mix(readableShaderRgb, shaderOverBg, useSolidBg);
+        vec3 finalRgb = _outputRgb(mix(readableShaderRgb, shaderOverBg, useSolidBg));
 
         // Detect chain mode (iOpacity ≈ 0 signals rendering to intermediate for another shader)
         float isChainMode = step(iOpacity, 0.001);
@@ -212,7 +226,7 @@ void main() {{
         float contentMask = clamp(terminalColor.a, 0.0, 1.0);
         float readabilityDim = mix(1.0, max(0.0, 1.0 - iReadability.y), iReadability.x * contentMask);
         vec3 readableShaderRgb = dimmedShaderRgb * readabilityDim;
-        vec3 bgColor = mix(readableShaderRgb, iBackgroundColor.rgb * iBrightness, useSolidBg);
+        vec3 bgColor = _outputRgb(mix(readableShaderRgb, iBackgroundColor.rgb * iBrightness, useSolidBg));
 
         // Detect chain mode (iOpacity ≈ 0 signals rendering to intermediate for another shader)
         float isChainMode = step(iOpacity, 0.001);
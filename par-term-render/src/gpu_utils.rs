@@ -25,3 +25,16 @@ pub fn create_sampler_with_filter(
         ..Default::default()
     })
 }
+
+/// Describe a wgpu adapter for the settings UI and diagnostics.
+pub fn gpu_adapter_info(info: &wgpu::AdapterInfo) -> par_term_config::GpuAdapterInfo {
+    let driver = format!("{} {}", info.driver, info.driver_info)
+        .trim()
+        .to_string();
+    par_term_config::GpuAdapterInfo {
+        name: info.name.clone(),
+        backend: format!("{:?}", info.backend),
+        device_type: format!("{:?}", info.device_type),
+        driver,
+    }
+}
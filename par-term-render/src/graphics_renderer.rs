@@ -224,7 +224,10 @@ impl GraphicsRenderer {
                     blend: Some(BlendState::PREMULTIPLIED_ALPHA_BLENDING),
                     write_mask: ColorWrites::ALL,
                 })],
-                compilation_options: Default::default(),
+                compilation_options: PipelineCompilationOptions {
+                    constants: crate::color_space::pipeline_constants(format),
+                    ..Default::default()
+                },
             }),
             primitive: PrimitiveState {
                 topology: PrimitiveTopology::TriangleStrip,
@@ -728,6 +731,14 @@ impl GraphicsRenderer {
         self.texture_cache.len()
     }
 
+    /// Estimated GPU memory held by cached textures (RGBA8), in bytes
+    pub fn cache_bytes(&self) -> u64 {
+        self.texture_cache
+            .values()
+            .map(|c| c.texture.width as u64 * c.texture.height as u64 * 4)
+            .sum()
+    }
+
     /// Update cell dimensions (called when window is resized)
     pub fn update_cell_dimensions(
         &mut self,
@@ -6,7 +6,7 @@
 //! - Cell-based GPU rendering with glyph atlas
 //! - Sixel/iTerm2/Kitty inline graphics rendering
 //! - Custom GLSL shader post-processing (Shadertoy/Ghostty compatible)
-//! - Scrollbar rendering with mark overlays
+//! - Scrollbar rendering with mark overlays and auto-hide fading
 //! - Background image rendering
 //! - GPU utility functions
 //!
@@ -30,24 +30,28 @@
 //! conditional on `cfg!(debug_assertions)`.
 
 pub mod cell_renderer;
+pub mod color_space;
 pub mod custom_shader_renderer;
 pub mod error;
 pub mod gpu_utils;
 pub mod graphics_renderer;
 pub mod renderer;
 pub mod scrollbar;
+pub mod scrollbar_fade;
 pub mod wgpu_conversions;
 
 // Re-export main public types
-pub use cell_renderer::{Cell, CellRenderer, PaneViewport};
+pub use cell_renderer::{Cell, CellRenderer, FontRenderingOptions, PaneViewport, SelectionRegion};
 pub use custom_shader_renderer::CustomShaderRenderer;
 pub use error::RenderError;
 pub use graphics_renderer::{GraphicRenderInfo, GraphicsRenderer};
 pub use renderer::{
     DividerRenderInfo, PaneDividerSettings, PaneRenderInfo, PaneTitleInfo, Renderer,
-    RendererParams, compute_visible_separator_marks,
+    RendererParams, TAB_PREVIEW_REFRESH, TabPreviewImage, TabPreviewParams,
+    compute_visible_separator_marks,
 };
 pub use scrollbar::Scrollbar;
+pub use scrollbar_fade::ScrollbarFade;
 
 // Re-export shared types from dependencies for convenience
 pub use par_term_config::{ScrollbackMark, SeparatorMark};
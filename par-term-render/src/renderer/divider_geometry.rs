@@ -0,0 +1,245 @@
+//! Pane divider geometry for each [`DividerStyle`].
+//!
+//! Works in physical pixels along the divider's two axes: the long axis the
+//! divider runs along, and the cross axis spanning its thickness.  Every style
+//! stays within the divider's bounds so the hit area and the drawn line agree.
+
+use super::DividerRenderInfo;
+use par_term_config::DividerStyle;
+
+/// Length of one dash of a dashed divider, in pixels.
+pub(crate) const DASH_LENGTH: f32 = 6.0;
+/// Gap between dashes of a dashed divider, in pixels.
+pub(crate) const DASH_GAP: f32 = 4.0;
+/// Width of the highlight and shadow edges of a shadow divider, in pixels.
+pub(crate) const SHADOW_EDGE: f32 = 1.0;
+
+/// A filled rectangle in physical pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DividerQuad {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub color: [f32; 4],
+}
+
+/// A `(start, length)` span along one axis.
+type Span = (f32, f32);
+
+/// Dash spans along a divider `length` pixels long, starting at `start`.
+///
+/// Dashes are [`DASH_LENGTH`] apart by [`DASH_GAP`]; the last one is cut
+/// short at the divider's end.
+pub(crate) fn dash_segments(start: f32, length: f32) -> Vec<Span> {
+    let end = start + length;
+    let mut segments = Vec::new();
+    let mut pos = start;
+    while pos < end {
+        segments.push((pos, DASH_LENGTH.min(end - pos)));
+        pos += DASH_LENGTH + DASH_GAP;
+    }
+    segments
+}
+
+/// Cross-axis bands of a shadow divider.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ShadowBands {
+    /// Lit top/left edge
+    pub highlight: Span,
+    /// Divider color between the edges
+    pub body: Option<Span>,
+    /// Dark bottom/right edge
+    pub shadow: Option<Span>,
+}
+
+/// Split a shadow divider's `thickness` into highlight, body and shadow.
+///
+/// From 3 px up the edges are [`SHADOW_EDGE`] wide with the body between
+/// them; thinner dividers split in half between highlight and shadow.
+pub(crate) fn shadow_bands(start: f32, thickness: f32) -> ShadowBands {
+    if thickness >= 3.0 {
+        let body = thickness - SHADOW_EDGE * 2.0;
+        ShadowBands {
+            highlight: (start, SHADOW_EDGE),
+            body: (body > 0.0).then_some((start + SHADOW_EDGE, body)),
+            shadow: Some((start + thickness - SHADOW_EDGE, SHADOW_EDGE)),
+        }
+    } else {
+        let half = (thickness / 2.0).max(1.0);
+        let rest = thickness - half;
+        ShadowBands {
+            highlight: (start, half),
+            body: None,
+            shadow: (rest > 0.0).then_some((start + half, rest)),
+        }
+    }
+}
+
+/// Cross-axis lines of a double divider: both edges from 4 px up, otherwise
+/// a single centered 1 px line.
+pub(crate) fn double_lines(start: f32, thickness: f32) -> Vec<Span> {
+    if thickness >= 4.0 {
+        vec![(start, 1.0), (start + thickness - 1.0, 1.0)]
+    } else {
+        vec![(start + (thickness - 1.0) / 2.0, 1.0)]
+    }
+}
+
+/// Quads drawing `divider` in `style` with base `color`.
+pub(crate) fn divider_quads(
+    divider: &DividerRenderInfo,
+    style: DividerStyle,
+    color: [f32; 3],
+) -> Vec<DividerQuad> {
+    let base = [color[0], color[1], color[2], 1.0];
+    let is_horizontal = divider.width > divider.height;
+    let (long_start, long_len, cross_start, cross_len) = if is_horizontal {
+        (divider.x, divider.width, divider.y, divider.height)
+    } else {
+        (divider.y, divider.height, divider.x, divider.width)
+    };
+    // Build a quad from a long-axis span and a cross-axis span
+    let quad = |(along, along_len): Span, (across, across_len): Span, color: [f32; 4]| {
+        if is_horizontal {
+            DividerQuad {
+                x: along,
+                y: across,
+                width: along_len,
+                height: across_len,
+                color,
+            }
+        } else {
+            DividerQuad {
+                x: across,
+                y: along,
+                width: across_len,
+                height: along_len,
+                color,
+            }
+        }
+    };
+    let full_long = (long_start, long_len);
+    let full_cross = (cross_start, cross_len);
+
+    match style {
+        DividerStyle::Solid => vec![quad(full_long, full_cross, base)],
+        DividerStyle::Double => double_lines(cross_start, cross_len)
+            .into_iter()
+            .map(|line| quad(full_long, line, base))
+            .collect(),
+        DividerStyle::Dashed => dash_segments(long_start, long_len)
+            .into_iter()
+            .map(|dash| quad(dash, full_cross, base))
+            .collect(),
+        DividerStyle::Shadow => {
+            let highlight = [
+                (color[0] + 0.3).min(1.0),
+                (color[1] + 0.3).min(1.0),
+                (color[2] + 0.3).min(1.0),
+                1.0,
+            ];
+            let shadow = [color[0] * 0.3, color[1] * 0.3, color[2] * 0.3, 1.0];
+            let bands = shadow_bands(cross_start, cross_len);
+            let mut quads = vec![quad(full_long, bands.highlight, highlight)];
+            quads.extend(bands.body.map(|body| quad(full_long, body, base)));
+            quads.extend(bands.shadow.map(|edge| quad(full_long, edge, shadow)));
+            quads
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn divider(x: f32, y: f32, width: f32, height: f32) -> DividerRenderInfo {
+        DividerRenderInfo {
+            x,
+            y,
+            width,
+            height,
+            hovered: false,
+        }
+    }
+
+    #[test]
+    fn dash_segments_repeat_and_clip_at_end() {
+        assert_eq!(
+            dash_segments(100.0, 25.0),
+            vec![(100.0, 6.0), (110.0, 6.0), (120.0, 5.0)]
+        );
+        // Exactly one dash and gap per period, nothing past the end
+        assert_eq!(dash_segments(0.0, 20.0), vec![(0.0, 6.0), (10.0, 6.0)]);
+        assert_eq!(dash_segments(0.0, 3.0), vec![(0.0, 3.0)]);
+        assert!(dash_segments(0.0, 0.0).is_empty());
+    }
+
+    #[test]
+    fn dashed_vertical_divider_runs_along_y() {
+        let quads = divider_quads(
+            &divider(50.0, 0.0, 2.0, 16.0),
+            DividerStyle::Dashed,
+            [1.0; 3],
+        );
+        let spans: Vec<_> = quads
+            .iter()
+            .map(|q| (q.x, q.y, q.width, q.height))
+            .collect();
+        assert_eq!(spans, vec![(50.0, 0.0, 2.0, 6.0), (50.0, 10.0, 2.0, 6.0)]);
+    }
+
+    #[test]
+    fn shadow_offsets_for_thick_and_thin_dividers() {
+        assert_eq!(
+            shadow_bands(10.0, 5.0),
+            ShadowBands {
+                highlight: (10.0, 1.0),
+                body: Some((11.0, 3.0)),
+                shadow: Some((14.0, 1.0)),
+            }
+        );
+        // 3 px: edges meet a 1 px body
+        assert_eq!(shadow_bands(0.0, 3.0).shadow, Some((2.0, 1.0)));
+        // 2 px: half highlight, half shadow
+        assert_eq!(
+            shadow_bands(0.0, 2.0),
+            ShadowBands {
+                highlight: (0.0, 1.0),
+                body: None,
+                shadow: Some((1.0, 1.0)),
+            }
+        );
+        // 1 px: highlight only
+        assert_eq!(shadow_bands(0.0, 1.0).shadow, None);
+    }
+
+    #[test]
+    fn shadow_horizontal_divider_stays_in_bounds() {
+        let d = divider(0.0, 40.0, 100.0, 4.0);
+        let quads = divider_quads(&d, DividerStyle::Shadow, [0.5, 0.5, 0.5]);
+        assert_eq!(quads.len(), 3);
+        assert_eq!((quads[0].y, quads[0].height), (40.0, 1.0));
+        assert_eq!((quads[2].y, quads[2].height), (43.0, 1.0));
+        assert!(quads[0].color[0] > 0.5 && quads[2].color[0] < 0.5);
+        for q in &quads {
+            assert!(q.y >= d.y && q.y + q.height <= d.y + d.height);
+            assert_eq!((q.x, q.width), (0.0, 100.0));
+        }
+    }
+
+    #[test]
+    fn every_style_draws_something() {
+        let d = divider(10.0, 0.0, 4.0, 30.0);
+        for style in DividerStyle::ALL {
+            let quads = divider_quads(&d, *style, [0.3; 3]);
+            assert!(!quads.is_empty(), "{style:?}");
+        }
+        assert_eq!(
+            double_lines(10.0, 4.0),
+            vec![(10.0, 1.0), (13.0, 1.0)],
+            "double uses both edges"
+        );
+        assert_eq!(double_lines(0.0, 3.0), vec![(1.0, 1.0)]);
+    }
+}
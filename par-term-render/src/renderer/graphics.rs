@@ -581,6 +581,11 @@ impl Renderer {
         self.graphics_renderer.cache_size()
     }
 
+    /// Estimated GPU memory held by cached sixel textures, in bytes
+    pub fn sixel_cache_bytes(&self) -> u64 {
+        self.graphics_renderer.cache_bytes()
+    }
+
     /// Remove a specific sixel texture from cache
     pub fn remove_sixel_texture(&mut self, id: u64) {
         self.graphics_renderer.remove_texture(id);
@@ -12,9 +12,12 @@
 use crate::cell_renderer::{Cell, CellRenderer, CellRendererConfig, PaneViewport};
 use crate::custom_shader_renderer::CustomShaderRenderer;
 use crate::graphics_renderer::GraphicsRenderer;
+use crate::scrollbar_fade::ScrollbarFade;
 use anyhow::Result;
+use std::time::Duration;
 use winit::dpi::PhysicalSize;
 
+mod divider_geometry;
 mod egui_render;
 pub mod graphics;
 pub mod params;
@@ -23,11 +26,13 @@ mod render_passes;
 mod rendering;
 pub mod shaders;
 mod state;
+mod tab_preview;
 
 // Re-export SeparatorMark from par-term-config
 pub use par_term_config::SeparatorMark;
 pub use params::RendererParams;
 pub use rendering::SplitPanesRenderParams;
+pub use tab_preview::{TAB_PREVIEW_REFRESH, TabPreviewImage, TabPreviewParams};
 
 /// Compute which separator marks are visible in the current viewport.
 ///
@@ -104,6 +109,8 @@ pub struct PaneRenderInfo<'a> {
     /// rendering. The actual on-screen position is taken from the placeholder
     /// cells in `cells`, not from each graphic's `position` field.
     pub virtual_placements: Vec<par_term_emu_core_rust::graphics::TerminalGraphic>,
+    /// Selected cells in viewport coordinates, used to round the selection's corners
+    pub selection: Option<crate::cell_renderer::SelectionRegion>,
 }
 
 /// Information needed to render a pane divider
@@ -221,6 +228,9 @@ pub struct Renderer {
     // bounds so that pane splits/resizes correctly trigger a scrollbar geometry update.
     pub(crate) last_scrollbar_state: (usize, usize, usize, usize, u32, u32, u32, u32, u32, u32),
 
+    // Auto-hide fade state for the scrollbar (never hides until configured)
+    pub(crate) scrollbar_fade: ScrollbarFade,
+
     // Skip cursor shader when alt screen is active (TUI apps like vim, htop)
     pub(crate) cursor_shader_disabled_for_alt_screen: bool,
 
@@ -230,6 +240,13 @@ pub struct Renderer {
     // Scratch buffer for divider instances, reused each frame to avoid
     // per-call heap allocations in `render_dividers`.
     pub(crate) scratch_divider_instances: Vec<crate::cell_renderer::BackgroundInstance>,
+
+    // Pixels pane content is grown by when drawn for the custom shader
+    // (see `custom_shader_renderer::overscan`)
+    pub(crate) custom_shader_overscan: f32,
+
+    // Offscreen captures of inactive tabs for the tab bar hover preview
+    pub(crate) tab_previews: tab_preview::TabPreviewCache<tab_preview::TabPreviewTexture>,
 }
 
 impl Renderer {
@@ -241,6 +258,9 @@ impl Renderer {
         let font_family_italic = params.font_family_italic;
         let font_family_bold_italic = params.font_family_bold_italic;
         let font_ranges = params.font_ranges;
+        let font_axes = params.font_axes;
+        let font_ligature_fallback = params.font_ligature_fallback;
+        let synthesize_styles = params.synthesize_styles;
         let font_size = params.font_size;
         let line_spacing = params.line_spacing;
         let char_spacing = params.char_spacing;
@@ -254,8 +274,12 @@ impl Renderer {
         let font_hinting = params.font_hinting;
         let font_thin_strokes = params.font_thin_strokes;
         let minimum_contrast = params.minimum_contrast;
+        let glyph_atlas_size = params.glyph_atlas_size;
+        let glyph_atlas_max_pages = params.glyph_atlas_max_pages;
         let vsync_mode = params.vsync_mode;
         let power_preference = params.power_preference;
+        let gpu_adapter = params.gpu_adapter;
+        let blend_color_space = params.blend_color_space;
         let window_opacity = params.window_opacity;
         let background_color = params.background_color;
         let background_image_path = params.background_image_path;
@@ -304,18 +328,20 @@ impl Renderer {
             font_family_italic,
             font_family_bold_italic,
             font_ranges,
+            font_axes,
         )?;
 
         let (font_ascent, font_descent, font_leading, char_advance) = {
             let primary_font = font_manager
                 .get_font(0)
                 .expect("Primary font at index 0 must exist after FontManager initialization");
-            let metrics = primary_font.metrics(&[]);
+            let coords = font_manager.font_coords(0);
+            let metrics = primary_font.metrics(coords);
             let scale = font_size_pixels / metrics.units_per_em as f32;
 
             // Get advance width of a standard character ('m' is common for monospace width)
             let glyph_id = primary_font.charmap().map('m');
-            let advance = primary_font.glyph_metrics(&[]).advance_width(glyph_id) * scale;
+            let advance = primary_font.glyph_metrics(coords).advance_width(glyph_id) * scale;
 
             (
                 metrics.ascent * scale,
@@ -363,6 +389,9 @@ impl Renderer {
                 font_family_italic,
                 font_family_bold_italic,
                 font_ranges,
+                font_axes,
+                font_ligature_fallback,
+                synthesize_styles,
                 font_size,
                 cols,
                 rows,
@@ -382,11 +411,15 @@ impl Renderer {
                 minimum_contrast,
                 vsync_mode,
                 power_preference,
+                gpu_adapter,
+                blend_color_space,
                 window_opacity,
                 background_color,
                 background_image_path: bg_path,
                 background_image_mode,
                 background_image_opacity,
+                glyph_atlas_size,
+                glyph_atlas_max_pages,
             },
         )
         .await?;
@@ -479,9 +512,12 @@ impl Renderer {
             size,
             dirty: true, // Start dirty to ensure initial render
             last_scrollbar_state: (usize::MAX, 0, 0, 0, 0, 0, 0, 0, 0, 0), // Force first update
+            scrollbar_fade: ScrollbarFade::new(Duration::ZERO, Duration::ZERO),
             cursor_shader_disabled_for_alt_screen: false,
             debug_text: None,
             scratch_divider_instances: Vec::new(),
+            custom_shader_overscan: 0.0,
+            tab_previews: tab_preview::TabPreviewCache::new(TAB_PREVIEW_REFRESH),
         })
     }
 
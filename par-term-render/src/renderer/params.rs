@@ -18,6 +18,10 @@ pub struct RendererParams<'a> {
     pub vsync_mode: par_term_config::VsyncMode,
     /// GPU power preference (low-power vs high-performance).
     pub power_preference: par_term_config::PowerPreference,
+    /// Preferred GPU adapter name (None = choose by power preference).
+    pub gpu_adapter: Option<&'a str>,
+    /// Color space translucent layers are blended in (selects the surface format).
+    pub blend_color_space: par_term_config::BlendColorSpace,
     /// Window opacity (0.0 fully transparent – 1.0 fully opaque).
     pub window_opacity: f32,
 
@@ -32,6 +36,12 @@ pub struct RendererParams<'a> {
     pub font_family_bold_italic: Option<&'a str>,
     /// Additional Unicode ranges and their fallback fonts.
     pub font_ranges: &'a [par_term_config::FontRange],
+    /// Variable font axis values per style.
+    pub font_axes: &'a par_term_config::FontAxes,
+    /// Ligature-capable font for sequences the primary font does not ligate.
+    pub font_ligature_fallback: Option<&'a str>,
+    /// Synthesize bold and italic when the font has no such face.
+    pub synthesize_styles: bool,
     /// Font size in points.
     pub font_size: f32,
     /// Enable HarfBuzz text shaping.
@@ -48,6 +58,10 @@ pub struct RendererParams<'a> {
     pub font_thin_strokes: par_term_config::ThinStrokesMode,
     /// Minimum contrast ratio between foreground and background.
     pub minimum_contrast: f32,
+    /// Glyph atlas page size in pixels.
+    pub glyph_atlas_size: u32,
+    /// Maximum number of glyph atlas pages.
+    pub glyph_atlas_max_pages: u32,
 
     // ── Layout ────────────────────────────────────────────────────────
     /// Padding around the terminal content in logical pixels.
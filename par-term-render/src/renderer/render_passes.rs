@@ -1,8 +1,8 @@
 use anyhow::Result;
 
+use super::divider_geometry::divider_quads;
 use super::{DividerRenderInfo, PaneDividerSettings, PaneTitleInfo, Renderer};
 use crate::cell_renderer::PaneViewport;
-use crate::cell_renderer::pane_render::ATLAS_SIZE;
 
 impl Renderer {
     /// Render pane dividers on top of pane content
@@ -39,225 +39,13 @@ impl Renderer {
                 settings.divider_color
             };
 
-            use par_term_config::DividerStyle;
-            match settings.divider_style {
-                DividerStyle::Solid => {
-                    let x_ndc = divider.x / w * 2.0 - 1.0;
-                    let y_ndc = 1.0 - (divider.y / h * 2.0);
-                    let w_ndc = divider.width / w * 2.0;
-                    let h_ndc = divider.height / h * 2.0;
-
-                    instances.push(crate::cell_renderer::types::BackgroundInstance {
-                        position: [x_ndc, y_ndc],
-                        size: [w_ndc, h_ndc],
-                        color: [color[0], color[1], color[2], 1.0],
-                    });
-                }
-                DividerStyle::Double => {
-                    // Two parallel lines with a visible gap between them
-                    let is_horizontal = divider.width > divider.height;
-                    let thickness = if is_horizontal {
-                        divider.height
-                    } else {
-                        divider.width
-                    };
-
-                    if thickness >= 4.0 {
-                        // Enough space for two 1px lines with visible gap
-                        if is_horizontal {
-                            // Top line
-                            instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                position: [divider.x / w * 2.0 - 1.0, 1.0 - (divider.y / h * 2.0)],
-                                size: [divider.width / w * 2.0, 1.0 / h * 2.0],
-                                color: [color[0], color[1], color[2], 1.0],
-                            });
-                            // Bottom line (gap in between shows background)
-                            let bottom_y = divider.y + divider.height - 1.0;
-                            instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                position: [divider.x / w * 2.0 - 1.0, 1.0 - (bottom_y / h * 2.0)],
-                                size: [divider.width / w * 2.0, 1.0 / h * 2.0],
-                                color: [color[0], color[1], color[2], 1.0],
-                            });
-                        } else {
-                            // Left line
-                            instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                position: [divider.x / w * 2.0 - 1.0, 1.0 - (divider.y / h * 2.0)],
-                                size: [1.0 / w * 2.0, divider.height / h * 2.0],
-                                color: [color[0], color[1], color[2], 1.0],
-                            });
-                            // Right line
-                            let right_x = divider.x + divider.width - 1.0;
-                            instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                position: [right_x / w * 2.0 - 1.0, 1.0 - (divider.y / h * 2.0)],
-                                size: [1.0 / w * 2.0, divider.height / h * 2.0],
-                                color: [color[0], color[1], color[2], 1.0],
-                            });
-                        }
-                    } else {
-                        // Divider too thin for double lines — render centered 1px line
-                        // (visibly thinner than Solid to differentiate)
-                        if is_horizontal {
-                            let center_y = divider.y + (divider.height - 1.0) / 2.0;
-                            instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                position: [divider.x / w * 2.0 - 1.0, 1.0 - (center_y / h * 2.0)],
-                                size: [divider.width / w * 2.0, 1.0 / h * 2.0],
-                                color: [color[0], color[1], color[2], 1.0],
-                            });
-                        } else {
-                            let center_x = divider.x + (divider.width - 1.0) / 2.0;
-                            instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                position: [center_x / w * 2.0 - 1.0, 1.0 - (divider.y / h * 2.0)],
-                                size: [1.0 / w * 2.0, divider.height / h * 2.0],
-                                color: [color[0], color[1], color[2], 1.0],
-                            });
-                        }
-                    }
-                }
-                DividerStyle::Dashed => {
-                    // Dashed line effect using segments
-                    let is_horizontal = divider.width > divider.height;
-                    let dash_len: f32 = 6.0;
-                    let gap_len: f32 = 4.0;
-
-                    if is_horizontal {
-                        let mut x = divider.x;
-                        while x < divider.x + divider.width {
-                            let seg_w = dash_len.min(divider.x + divider.width - x);
-                            instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                position: [x / w * 2.0 - 1.0, 1.0 - (divider.y / h * 2.0)],
-                                size: [seg_w / w * 2.0, divider.height / h * 2.0],
-                                color: [color[0], color[1], color[2], 1.0],
-                            });
-                            x += dash_len + gap_len;
-                        }
-                    } else {
-                        let mut y = divider.y;
-                        while y < divider.y + divider.height {
-                            let seg_h = dash_len.min(divider.y + divider.height - y);
-                            instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                position: [divider.x / w * 2.0 - 1.0, 1.0 - (y / h * 2.0)],
-                                size: [divider.width / w * 2.0, seg_h / h * 2.0],
-                                color: [color[0], color[1], color[2], 1.0],
-                            });
-                            y += dash_len + gap_len;
-                        }
-                    }
-                }
-                DividerStyle::Shadow => {
-                    // Beveled/embossed effect — all rendering stays within divider bounds
-                    // Highlight on top/left edge, shadow on bottom/right edge
-                    let is_horizontal = divider.width > divider.height;
-                    let thickness = if is_horizontal {
-                        divider.height
-                    } else {
-                        divider.width
-                    };
-
-                    // Brighter highlight color
-                    let highlight = [
-                        (color[0] + 0.3).min(1.0),
-                        (color[1] + 0.3).min(1.0),
-                        (color[2] + 0.3).min(1.0),
-                        1.0,
-                    ];
-                    // Darker shadow color
-                    let shadow = [(color[0] * 0.3), (color[1] * 0.3), (color[2] * 0.3), 1.0];
-
-                    if thickness >= 3.0 {
-                        // 3+ px: highlight line / main body / shadow line
-                        let edge = 1.0_f32;
-                        if is_horizontal {
-                            // Top highlight
-                            instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                position: [divider.x / w * 2.0 - 1.0, 1.0 - (divider.y / h * 2.0)],
-                                size: [divider.width / w * 2.0, edge / h * 2.0],
-                                color: highlight,
-                            });
-                            // Main body (middle portion)
-                            let body_y = divider.y + edge;
-                            let body_h = divider.height - edge * 2.0;
-                            if body_h > 0.0 {
-                                instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                    position: [divider.x / w * 2.0 - 1.0, 1.0 - (body_y / h * 2.0)],
-                                    size: [divider.width / w * 2.0, body_h / h * 2.0],
-                                    color: [color[0], color[1], color[2], 1.0],
-                                });
-                            }
-                            // Bottom shadow
-                            let shadow_y = divider.y + divider.height - edge;
-                            instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                position: [divider.x / w * 2.0 - 1.0, 1.0 - (shadow_y / h * 2.0)],
-                                size: [divider.width / w * 2.0, edge / h * 2.0],
-                                color: shadow,
-                            });
-                        } else {
-                            // Left highlight
-                            instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                position: [divider.x / w * 2.0 - 1.0, 1.0 - (divider.y / h * 2.0)],
-                                size: [edge / w * 2.0, divider.height / h * 2.0],
-                                color: highlight,
-                            });
-                            // Main body
-                            let body_x = divider.x + edge;
-                            let body_w = divider.width - edge * 2.0;
-                            if body_w > 0.0 {
-                                instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                    position: [body_x / w * 2.0 - 1.0, 1.0 - (divider.y / h * 2.0)],
-                                    size: [body_w / w * 2.0, divider.height / h * 2.0],
-                                    color: [color[0], color[1], color[2], 1.0],
-                                });
-                            }
-                            // Right shadow
-                            let shadow_x = divider.x + divider.width - edge;
-                            instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                position: [shadow_x / w * 2.0 - 1.0, 1.0 - (divider.y / h * 2.0)],
-                                size: [edge / w * 2.0, divider.height / h * 2.0],
-                                color: shadow,
-                            });
-                        }
-                    } else {
-                        // 2px or less: top/left half highlight, bottom/right half shadow
-                        if is_horizontal {
-                            let half = (divider.height / 2.0).max(1.0);
-                            instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                position: [divider.x / w * 2.0 - 1.0, 1.0 - (divider.y / h * 2.0)],
-                                size: [divider.width / w * 2.0, half / h * 2.0],
-                                color: highlight,
-                            });
-                            let bottom_y = divider.y + half;
-                            let bottom_h = divider.height - half;
-                            if bottom_h > 0.0 {
-                                instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                    position: [
-                                        divider.x / w * 2.0 - 1.0,
-                                        1.0 - (bottom_y / h * 2.0),
-                                    ],
-                                    size: [divider.width / w * 2.0, bottom_h / h * 2.0],
-                                    color: shadow,
-                                });
-                            }
-                        } else {
-                            let half = (divider.width / 2.0).max(1.0);
-                            instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                position: [divider.x / w * 2.0 - 1.0, 1.0 - (divider.y / h * 2.0)],
-                                size: [half / w * 2.0, divider.height / h * 2.0],
-                                color: highlight,
-                            });
-                            let right_x = divider.x + half;
-                            let right_w = divider.width - half;
-                            if right_w > 0.0 {
-                                instances.push(crate::cell_renderer::types::BackgroundInstance {
-                                    position: [
-                                        right_x / w * 2.0 - 1.0,
-                                        1.0 - (divider.y / h * 2.0),
-                                    ],
-                                    size: [right_w / w * 2.0, divider.height / h * 2.0],
-                                    color: shadow,
-                                });
-                            }
-                        }
-                    }
-                }
+            for quad in divider_quads(divider, settings.divider_style, color) {
+                instances.push(crate::cell_renderer::types::BackgroundInstance {
+                    position: [quad.x / w * 2.0 - 1.0, 1.0 - (quad.y / h * 2.0)],
+                    size: [quad.width / w * 2.0, quad.height / h * 2.0],
+                    color: quad.color,
+                    corner_radii: [0.0; 4],
+                });
             }
         }
 
@@ -351,6 +139,7 @@ impl Renderer {
                     border_w / self.size.height as f32 * 2.0,
                 ],
                 color,
+                corner_radii: [0.0; 4],
             },
             // Bottom border
             crate::cell_renderer::types::BackgroundInstance {
@@ -364,6 +153,7 @@ impl Renderer {
                     border_w / self.size.height as f32 * 2.0,
                 ],
                 color,
+                corner_radii: [0.0; 4],
             },
             // Left border (between top and bottom)
             crate::cell_renderer::types::BackgroundInstance {
@@ -376,6 +166,7 @@ impl Renderer {
                     (viewport.height - border_w * 2.0) / self.size.height as f32 * 2.0,
                 ],
                 color,
+                corner_radii: [0.0; 4],
             },
             // Right border (between top and bottom)
             crate::cell_renderer::types::BackgroundInstance {
@@ -388,6 +179,7 @@ impl Renderer {
                     (viewport.height - border_w * 2.0) / self.size.height as f32 * 2.0,
                 ],
                 color,
+                corner_radii: [0.0; 4],
             },
         ];
 
@@ -452,6 +244,7 @@ impl Renderer {
 
         let width = self.size.width as f32;
         let height = self.size.height as f32;
+        let atlas_size = self.cell_renderer.atlas.atlas_size as f32;
 
         // Phase 1: Render title bar backgrounds
         let mut bg_instances = Vec::with_capacity(titles.len());
@@ -474,6 +267,7 @@ impl Renderer {
                     title.bg_color[2] * brightness,
                     1.0, // Always fully opaque
                 ],
+                corner_radii: [0.0; 4],
             });
         }
 
@@ -558,8 +352,10 @@ impl Renderer {
                     break;
                 }
 
-                if let Some((font_idx, glyph_id)) =
-                    self.cell_renderer.font_manager.find_glyph(ch, false, false)
+                if let Some((font_idx, glyph_id)) = self
+                    .cell_renderer
+                    .font_manager
+                    .find_featured_glyph(ch, false, false)
                 {
                     let cache_key = ((font_idx as u64) << 32) | (glyph_id as u64);
                     // Check if this character should be rendered as a monochrome symbol
@@ -573,6 +369,7 @@ impl Renderer {
                         font_idx,
                         glyph_id,
                         force_monochrome,
+                        (false, false),
                         cache_key,
                     ) {
                         Some(info) => info,
@@ -594,13 +391,14 @@ impl Renderer {
                             info.width as f32 / width * 2.0,
                             info.height as f32 / height * 2.0,
                         ],
-                        tex_offset: [info.x as f32 / ATLAS_SIZE, info.y as f32 / ATLAS_SIZE],
+                        tex_offset: [info.x as f32 / atlas_size, info.y as f32 / atlas_size],
                         tex_size: [
-                            info.width as f32 / ATLAS_SIZE,
-                            info.height as f32 / ATLAS_SIZE,
+                            info.width as f32 / atlas_size,
+                            info.height as f32 / atlas_size,
                         ],
                         color: text_color,
                         is_colored: if info.is_colored { 1 } else { 0 },
+                        atlas_page: info.page,
                     });
                 }
 
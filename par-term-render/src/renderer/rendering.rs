@@ -122,23 +122,15 @@ impl Renderer {
         // Clear color for content rendering. When cursor shader will apply opacity,
         // use non-premultiplied color so opacity isn't applied twice.
         let opacity = self.cell_renderer.window_opacity as f64;
+        let [r, g, b, _] = self.cell_renderer.background_color;
+        let rgb = [r, g, b];
         let clear_color = if self.cell_renderer.pipelines.bg_image_bind_group.is_some() {
             wgpu::Color::TRANSPARENT
         } else if use_cursor_shader {
             // Cursor shader applies opacity — use full-opacity background
-            wgpu::Color {
-                r: self.cell_renderer.background_color[0] as f64,
-                g: self.cell_renderer.background_color[1] as f64,
-                b: self.cell_renderer.background_color[2] as f64,
-                a: 1.0,
-            }
+            crate::color_space::clear_color(self.cell_renderer.surface_format(), rgb, 1.0)
         } else {
-            wgpu::Color {
-                r: self.cell_renderer.background_color[0] as f64 * opacity,
-                g: self.cell_renderer.background_color[1] as f64 * opacity,
-                b: self.cell_renderer.background_color[2] as f64 * opacity,
-                a: opacity,
-            }
+            crate::color_space::clear_color(self.cell_renderer.surface_format(), rgb, opacity)
         };
 
         // Determine if the shader needs terminal pixels in iChannel4.
@@ -185,6 +177,14 @@ impl Renderer {
             let mut scratch: Vec<SeparatorMark> = Vec::new();
             for pane in panes.iter() {
                 if pane.show_scrollbar {
+                    // Only the focused pane auto-hides; unfocused panes keep
+                    // their scrollbar fully visible.
+                    let opacity = if pane.viewport.focused {
+                        self.scrollbar_fade.opacity(std::time::Instant::now())
+                    } else {
+                        1.0
+                    };
+                    self.cell_renderer.scrollbar.set_opacity(opacity);
                     let total_lines = pane.scrollback_len + pane.grid_size.1;
                     self.cell_renderer.update_scrollbar_for_pane(
                         pane.scroll_offset,
@@ -216,6 +216,8 @@ impl Renderer {
                         fill_default_bg_cells: false, // Shader shows through default-bg cells
                         separator_marks: &scratch,
                         pane_background: pane.background.as_ref(),
+                        overscan: self.custom_shader_overscan,
+                        selection: pane.selection,
                     },
                 )?;
             }
@@ -316,6 +318,14 @@ impl Renderer {
             let mut scratch: Vec<SeparatorMark> = Vec::new();
             for pane in panes {
                 if pane.show_scrollbar {
+                    // Only the focused pane auto-hides; unfocused panes keep
+                    // their scrollbar fully visible.
+                    let opacity = if pane.viewport.focused {
+                        self.scrollbar_fade.opacity(std::time::Instant::now())
+                    } else {
+                        1.0
+                    };
+                    self.cell_renderer.scrollbar.set_opacity(opacity);
                     let total_lines = pane.scrollback_len + pane.grid_size.1;
                     self.cell_renderer.update_scrollbar_for_pane(
                         pane.scroll_offset,
@@ -347,6 +357,8 @@ impl Renderer {
                         fill_default_bg_cells: has_background_image, // Only fill gaps in bg-image mode; shader shows through
                         separator_marks: &scratch,
                         pane_background: pane.background.as_ref(),
+                        overscan: 0.0,
+                        selection: pane.selection,
                     },
                 )?;
             }
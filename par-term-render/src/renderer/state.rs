@@ -32,6 +32,24 @@ impl Renderer {
         self.dirty = true;
     }
 
+    /// GPU adapters available for this window (for the settings device picker)
+    pub fn gpu_adapters(&self) -> &[par_term_config::GpuAdapterInfo] {
+        self.cell_renderer.gpu_adapters()
+    }
+
+    /// Adapter, limits and graphics cache usage for the diagnostics panel
+    pub fn gpu_diagnostics(&self) -> par_term_config::GpuDiagnostics {
+        let limits = self.cell_renderer.device_limits();
+        par_term_config::GpuDiagnostics {
+            adapter: Some(self.cell_renderer.adapter_info().clone()),
+            max_texture_dimension_2d: limits.max_texture_dimension_2d,
+            max_buffer_size: limits.max_buffer_size,
+            max_bind_groups: limits.max_bind_groups,
+            graphics_textures: self.sixel_cache_size(),
+            graphics_texture_bytes: self.sixel_cache_bytes(),
+        }
+    }
+
     /// Check if a vsync mode is supported
     pub fn is_vsync_mode_supported(&self, mode: par_term_config::VsyncMode) -> bool {
         self.cell_renderer.is_vsync_mode_supported(mode)
@@ -92,6 +110,45 @@ impl Renderer {
         changed
     }
 
+    /// Switch anti-aliasing, hinting and thin strokes live.
+    ///
+    /// Clears the glyph atlas so text is re-rasterized with the new options and
+    /// recomputes font metrics; if the cell size changes, the grid is resized
+    /// for the current window size (callers should compare [`Self::grid_size`]
+    /// before and after to resize their terminals).
+    /// Returns true if any option changed.
+    pub fn update_font_rendering(
+        &mut self,
+        antialias: bool,
+        hinting: bool,
+        thin_strokes: par_term_config::ThinStrokesMode,
+    ) -> bool {
+        let cell_size = (
+            self.cell_renderer.cell_width(),
+            self.cell_renderer.cell_height(),
+        );
+        let changed =
+            self.cell_renderer
+                .update_font_rendering(crate::cell_renderer::FontRenderingOptions {
+                    antialias,
+                    hinting,
+                    thin_strokes,
+                });
+        if changed {
+            self.dirty = true;
+            if cell_size
+                != (
+                    self.cell_renderer.cell_width(),
+                    self.cell_renderer.cell_height(),
+                )
+            {
+                // Propagate new cell dimensions to graphics and shader renderers
+                self.resize(self.size);
+            }
+        }
+        changed
+    }
+
     /// Update minimum contrast value
     /// Returns true if the setting changed (requiring redraw)
     pub fn update_minimum_contrast(&mut self, value: f32) -> bool {
@@ -179,6 +236,32 @@ impl Renderer {
         }
     }
 
+    /// Set how long cursor style changes morph (zero switches instantly)
+    pub fn update_cursor_morph_duration(&mut self, duration_ms: u64) {
+        self.cell_renderer
+            .set_cursor_morph_duration(std::time::Duration::from_millis(duration_ms));
+    }
+
+    /// Set the cursor smear streak length in cells (0 disables it) and its
+    /// fade-out time
+    pub fn update_cursor_smear(&mut self, length: f32, decay_ms: u64) {
+        self.cell_renderer
+            .set_cursor_smear(length, std::time::Duration::from_millis(decay_ms));
+    }
+
+    /// Grow each pane's content area by `overscan` pixels when it is drawn
+    /// for the custom shader
+    pub fn update_custom_shader_overscan(&mut self, overscan: f32) {
+        self.custom_shader_overscan = overscan.max(0.0);
+        self.dirty = true;
+    }
+
+    /// Widen the cursor over every cell of a ligature under it
+    pub fn update_cursor_ligature_width(&mut self, enabled: bool) {
+        self.cell_renderer.set_cursor_ligature_width(enabled);
+        self.dirty = true;
+    }
+
     /// Clear cursor (hide it)
     pub fn clear_cursor(&mut self) {
         if self.cell_renderer.clear_cursor() {
@@ -251,8 +334,9 @@ impl Renderer {
 
     /// Update cursor color for cell rendering
     pub fn update_cursor_color(&mut self, color: [u8; 3]) {
-        self.cell_renderer.update_cursor_color(color);
-        self.dirty = true;
+        if self.cell_renderer.update_cursor_color(color) {
+            self.dirty = true;
+        }
     }
 
     /// Update cursor text color (color of text under block cursor)
@@ -298,6 +382,26 @@ impl Renderer {
         self.dirty = true;
     }
 
+    /// Update text shadow settings.
+    /// Offset and blur are in logical pixels and follow the display's scale factor.
+    pub fn update_text_shadow(
+        &mut self,
+        enabled: bool,
+        color: [u8; 4],
+        offset: [f32; 2],
+        blur: f32,
+    ) {
+        self.cell_renderer
+            .set_text_shadow(enabled, color, offset, blur);
+        self.dirty = true;
+    }
+
+    /// Update the corner radius of selection and cursor backgrounds (logical pixels).
+    pub fn update_selection_corner_radius(&mut self, radius: f32) {
+        self.cell_renderer.set_selection_corner_radius(radius);
+        self.dirty = true;
+    }
+
     /// Update cursor boost settings
     pub fn update_cursor_boost(&mut self, intensity: f32, color: [u8; 3]) {
         self.cell_renderer.update_cursor_boost(intensity, color);
@@ -523,6 +627,38 @@ impl Renderer {
         self.dirty = true;
     }
 
+    /// Set the scrollbar auto-hide delay and fade-out duration (0 delay = never hide)
+    pub fn update_scrollbar_autohide(&mut self, delay_ms: u64, fade_ms: u64) {
+        self.scrollbar_fade.set_timing(
+            std::time::Duration::from_millis(delay_ms),
+            std::time::Duration::from_millis(fade_ms),
+        );
+        self.dirty = true;
+    }
+
+    /// Record scroll or hover activity that reveals an auto-hidden scrollbar.
+    pub fn record_scrollbar_activity(&mut self, at: std::time::Instant) {
+        self.scrollbar_fade.record_activity(at);
+    }
+
+    /// Apply the current auto-hide fade to the scrollbar and return its opacity.
+    ///
+    /// Call once per frame before updating the scrollbar geometry.
+    pub fn apply_scrollbar_fade(&mut self) -> f32 {
+        let opacity = self.scrollbar_fade.opacity(std::time::Instant::now());
+        if self.cell_renderer.scrollbar.set_opacity(opacity) {
+            // Re-upload uniforms with the faded colors.
+            self.last_scrollbar_state = (usize::MAX, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+            self.dirty = true;
+        }
+        opacity
+    }
+
+    /// When the scrollbar will start fading out, for scheduling a redraw.
+    pub fn scrollbar_fade_start(&self) -> Option<std::time::Instant> {
+        self.scrollbar_fade.fade_start(std::time::Instant::now())
+    }
+
     /// Update scrollbar position (left/right) in real-time
     pub fn update_scrollbar_position(&mut self, position: &str) {
         self.cell_renderer.update_scrollbar_position(position);
@@ -559,8 +695,8 @@ impl Renderer {
 
     /// Check if animation requires continuous rendering
     ///
-    /// Returns true if shader animation is enabled or a cursor trail animation
-    /// might still be in progress.
+    /// Returns true if shader animation is enabled, a cursor trail animation
+    /// might still be in progress, or the cursor is morphing between styles.
     pub fn needs_continuous_render(&self) -> bool {
         let custom_needs = self
             .custom_shader_renderer
@@ -570,6 +706,10 @@ impl Renderer {
             .cursor_shader_renderer
             .as_ref()
             .is_some_and(|r| r.animation_enabled() || r.cursor_needs_animation());
-        custom_needs || cursor_needs
+        custom_needs
+            || cursor_needs
+            || self.cell_renderer.cursor_morph_active()
+            || self.cell_renderer.cursor_smear_active()
+            || self.scrollbar_fade.is_fading(std::time::Instant::now())
     }
 }
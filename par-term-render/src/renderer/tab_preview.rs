@@ -0,0 +1,312 @@
+//! Offscreen previews of inactive tabs, shown when hovering the tab bar.
+//!
+//! [`Renderer::capture_tab_preview`] draws a tab's cell grid into its own
+//! texture with the regular pane pipeline and registers that texture with
+//! egui, so the tab bar can show it as an image.  Previews are cached per tab
+//! in a [`TabPreviewCache`] keyed by the terminal's content generation: an
+//! unchanged tab is never redrawn, and a busy one is redrawn at most once per
+//! [`TAB_PREVIEW_REFRESH`].
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use par_term_config::TabId;
+
+use super::Renderer;
+use crate::cell_renderer::{Cell, PaneRenderViewParams, PaneViewport};
+
+/// Shortest time between two captures of the same tab.
+pub const TAB_PREVIEW_REFRESH: Duration = Duration::from_millis(500);
+
+/// A cached capture and the content generation it shows.
+#[derive(Debug)]
+struct CachedPreview<T> {
+    generation: u64,
+    captured_at: Instant,
+    value: T,
+}
+
+/// Latest capture of each tab, with the rules for when to capture again.
+#[derive(Debug)]
+pub(crate) struct TabPreviewCache<T> {
+    entries: HashMap<TabId, CachedPreview<T>>,
+    refresh: Duration,
+}
+
+impl<T> TabPreviewCache<T> {
+    pub(crate) fn new(refresh: Duration) -> Self {
+        Self {
+            entries: HashMap::new(),
+            refresh,
+        }
+    }
+
+    /// Whether `tab`'s preview shows an older generation than `generation`
+    /// (or there is none).
+    pub(crate) fn is_stale(&self, tab: TabId, generation: u64) -> bool {
+        self.entries
+            .get(&tab)
+            .is_none_or(|entry| entry.generation != generation)
+    }
+
+    /// Whether `tab` should be captured at `now`: it has no preview, or its
+    /// preview is stale and was taken at least the refresh interval ago.
+    pub(crate) fn needs_capture(&self, tab: TabId, generation: u64, now: Instant) -> bool {
+        self.is_stale(tab, generation)
+            && self.entries.get(&tab).is_none_or(|entry| {
+                now.saturating_duration_since(entry.captured_at) >= self.refresh
+            })
+    }
+
+    /// Store a capture of `generation`, returning the one it replaces.
+    pub(crate) fn insert(
+        &mut self,
+        tab: TabId,
+        generation: u64,
+        now: Instant,
+        value: T,
+    ) -> Option<T> {
+        self.entries
+            .insert(
+                tab,
+                CachedPreview {
+                    generation,
+                    captured_at: now,
+                    value,
+                },
+            )
+            .map(|old| old.value)
+    }
+
+    /// The latest capture of `tab`, stale or not.
+    pub(crate) fn get(&self, tab: TabId) -> Option<&T> {
+        self.entries.get(&tab).map(|entry| &entry.value)
+    }
+
+    /// Take the latest capture of `tab` out of the cache.
+    pub(crate) fn remove(&mut self, tab: TabId) -> Option<T> {
+        self.entries.remove(&tab).map(|entry| entry.value)
+    }
+
+    /// Drop the captures of tabs for which `keep` is false, returning them.
+    pub(crate) fn retain(&mut self, mut keep: impl FnMut(TabId) -> bool) -> Vec<T> {
+        let gone: Vec<TabId> = self
+            .entries
+            .keys()
+            .copied()
+            .filter(|&id| !keep(id))
+            .collect();
+        gone.into_iter().filter_map(|id| self.remove(id)).collect()
+    }
+}
+
+/// A tab preview ready for egui.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TabPreviewImage {
+    /// Texture registered with the egui renderer
+    pub texture: egui::TextureId,
+    /// Part of the texture covered by the grid, in UV coordinates
+    pub uv: egui::Rect,
+    /// Size of that part in pixels, for the aspect ratio
+    pub size: [f32; 2],
+}
+
+/// GPU side of a cached preview.
+pub(crate) struct TabPreviewTexture {
+    texture: wgpu::Texture,
+    image: TabPreviewImage,
+}
+
+/// Parameters for [`Renderer::capture_tab_preview`].
+pub struct TabPreviewParams<'a> {
+    /// Tab the cells belong to
+    pub tab: TabId,
+    /// Terminal content generation of `cells`
+    pub generation: u64,
+    pub cells: &'a [Cell],
+    pub cols: usize,
+    pub rows: usize,
+}
+
+impl Renderer {
+    /// Whether [`capture_tab_preview`](Self::capture_tab_preview) would draw
+    /// `tab` at `generation` now; check this before gathering its cells.
+    pub fn tab_preview_needs_capture(&self, tab: TabId, generation: u64) -> bool {
+        let size_changed = self.tab_previews.get(tab).is_some_and(|preview| {
+            let texture_size = preview.texture.size();
+            (texture_size.width, texture_size.height) != (self.size.width, self.size.height)
+        });
+        size_changed
+            || self
+                .tab_previews
+                .needs_capture(tab, generation, Instant::now())
+    }
+
+    /// The latest preview of `tab`, if one was captured.
+    pub fn tab_preview(&self, tab: TabId) -> Option<TabPreviewImage> {
+        self.tab_previews.get(tab).map(|preview| preview.image)
+    }
+
+    /// Draw a tab's cells into its preview texture, if the cached preview
+    /// needs refreshing, and return the preview.
+    pub fn capture_tab_preview(
+        &mut self,
+        params: TabPreviewParams<'_>,
+    ) -> Result<Option<TabPreviewImage>> {
+        let TabPreviewParams {
+            tab,
+            generation,
+            cells,
+            cols,
+            rows,
+        } = params;
+        if !self.tab_preview_needs_capture(tab, generation) {
+            return Ok(self.tab_preview(tab));
+        }
+
+        let (width, height) = (self.size.width, self.size.height);
+        let device = self.cell_renderer.device();
+        // Reuse the texture while the window keeps its size
+        let texture = match self.tab_previews.remove(tab) {
+            Some(old)
+                if (old.texture.size().width, old.texture.size().height) == (width, height) =>
+            {
+                self.egui_renderer.free_texture(&old.image.texture);
+                old.texture
+            }
+            old => {
+                if let Some(old) = old {
+                    self.egui_renderer.free_texture(&old.image.texture);
+                }
+                device.create_texture(&wgpu::TextureDescriptor {
+                    label: Some("tab preview texture"),
+                    size: wgpu::Extent3d {
+                        width,
+                        height,
+                        depth_or_array_layers: 1,
+                    },
+                    mip_level_count: 1,
+                    sample_count: 1,
+                    dimension: wgpu::TextureDimension::D2,
+                    format: self.cell_renderer.surface_format(),
+                    usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                        | wgpu::TextureUsages::TEXTURE_BINDING,
+                    view_formats: &[],
+                })
+            }
+        };
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let padding = self.cell_renderer.window_padding();
+        let viewport =
+            PaneViewport::with_padding(0.0, 0.0, width as f32, height as f32, false, 1.0, padding);
+        self.cell_renderer.render_pane_to_view(
+            &view,
+            PaneRenderViewParams {
+                viewport: &viewport,
+                cells,
+                cols,
+                rows,
+                cursor_pos: None,
+                cursor_opacity: 0.0,
+                show_scrollbar: false,
+                clear_first: true,
+                skip_background_image: true,
+                fill_default_bg_cells: true,
+                separator_marks: &[],
+                pane_background: None,
+                overscan: 0.0,
+                selection: None,
+            },
+        )?;
+        // The pane pass replaced the frame's instance data
+        self.dirty = true;
+
+        let grid_width =
+            (cols as f32 * self.cell_renderer.cell_width() + 2.0 * padding).min(width as f32);
+        let grid_height =
+            (rows as f32 * self.cell_renderer.cell_height() + 2.0 * padding).min(height as f32);
+        let texture_id = self.egui_renderer.register_native_texture(
+            self.cell_renderer.device(),
+            &view,
+            wgpu::FilterMode::Linear,
+        );
+        let image = TabPreviewImage {
+            texture: texture_id,
+            uv: egui::Rect::from_min_max(
+                egui::pos2(0.0, 0.0),
+                egui::pos2(grid_width / width as f32, grid_height / height as f32),
+            ),
+            size: [grid_width, grid_height],
+        };
+        self.tab_previews.insert(
+            tab,
+            generation,
+            Instant::now(),
+            TabPreviewTexture { texture, image },
+        );
+        Ok(Some(image))
+    }
+
+    /// Free the previews of tabs for which `keep` is false (closed tabs).
+    pub fn retain_tab_previews(&mut self, keep: impl FnMut(TabId) -> bool) {
+        for preview in self.tab_previews.retain(keep) {
+            self.egui_renderer.free_texture(&preview.image.texture);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn generation_change_invalidates_preview() {
+        let t0 = Instant::now();
+        let mut cache = TabPreviewCache::new(TAB_PREVIEW_REFRESH);
+        assert!(cache.needs_capture(1, 7, t0), "nothing cached yet");
+        assert!(cache.is_stale(1, 7));
+
+        cache.insert(1, 7, t0, "gen 7");
+        assert!(!cache.is_stale(1, 7));
+        assert!(
+            !cache.needs_capture(1, 7, t0 + ms(5000)),
+            "unchanged content is never redrawn"
+        );
+
+        // New output: stale at once, redrawn once the refresh interval is up
+        assert!(cache.is_stale(1, 8));
+        assert!(!cache.needs_capture(1, 8, t0 + ms(100)));
+        assert!(cache.needs_capture(1, 8, t0 + TAB_PREVIEW_REFRESH));
+        assert_eq!(
+            cache.insert(1, 8, t0 + TAB_PREVIEW_REFRESH, "gen 8"),
+            Some("gen 7")
+        );
+        assert_eq!(cache.get(1), Some(&"gen 8"));
+        assert!(!cache.is_stale(1, 8));
+
+        // Other tabs are cached separately
+        assert!(cache.needs_capture(2, 8, t0));
+    }
+
+    #[test]
+    fn closed_tabs_are_dropped() {
+        let t0 = Instant::now();
+        let mut cache = TabPreviewCache::new(TAB_PREVIEW_REFRESH);
+        cache.insert(1, 1, t0, 'a');
+        cache.insert(2, 1, t0, 'b');
+        cache.insert(3, 1, t0, 'c');
+
+        let mut dropped = cache.retain(|id| id == 2);
+        dropped.sort();
+        assert_eq!(dropped, vec!['a', 'c']);
+        assert_eq!(cache.get(2), Some(&'b'));
+        assert_eq!(cache.get(1), None);
+        assert!(cache.needs_capture(1, 1, t0));
+    }
+}
@@ -37,9 +37,10 @@ use wgpu::util::DeviceExt;
 use wgpu::{
     BindGroup, BindGroupDescriptor, BindGroupEntry, BindGroupLayoutDescriptor,
     BindGroupLayoutEntry, BindingType, Buffer, BufferBindingType, BufferUsages, ColorTargetState,
-    ColorWrites, Device, FragmentState, MultisampleState, PipelineLayoutDescriptor, PrimitiveState,
-    PrimitiveTopology, Queue, RenderPass, RenderPipeline, RenderPipelineDescriptor,
-    ShaderModuleDescriptor, ShaderSource, ShaderStages, TextureFormat, VertexState,
+    ColorWrites, Device, FragmentState, MultisampleState, PipelineCompilationOptions,
+    PipelineLayoutDescriptor, PrimitiveState, PrimitiveTopology, Queue, RenderPass, RenderPipeline,
+    RenderPipelineDescriptor, ShaderModuleDescriptor, ShaderSource, ShaderStages, TextureFormat,
+    VertexState,
 };
 
 use par_term_config::{ScrollbackMark, color_tuple_to_f32_a};
@@ -58,6 +59,8 @@ pub struct Scrollbar {
     position_right: bool, // true = right side, false = left side
     thumb_color: [f32; 4],
     track_color: [f32; 4],
+    /// Auto-hide fade multiplier applied to every color's alpha
+    opacity: f32,
 
     // Cached state for hit testing and interaction
     scrollbar_x: f32,      // Pixel position X
@@ -177,7 +180,10 @@ impl Scrollbar {
                     blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
                     write_mask: ColorWrites::ALL,
                 })],
-                compilation_options: Default::default(),
+                compilation_options: PipelineCompilationOptions {
+                    constants: crate::color_space::pipeline_constants(format),
+                    ..Default::default()
+                },
             }),
             primitive: PrimitiveState {
                 topology: PrimitiveTopology::TriangleStrip,
@@ -255,6 +261,7 @@ impl Scrollbar {
             position_right,
             thumb_color,
             track_color,
+            opacity: 1.0,
             scrollbar_x: 0.0,
             scrollbar_y: 0.0,
             scrollbar_height: 0.0,
@@ -363,7 +370,7 @@ impl Scrollbar {
         let track_uniforms = ScrollbarUniforms {
             position: [ndc_x, track_ndc_y],
             size: [ndc_width, track_ndc_height],
-            color: self.track_color,
+            color: self.faded(self.track_color),
         };
         queue.write_buffer(
             &self.track_uniform_buffer,
@@ -378,7 +385,7 @@ impl Scrollbar {
         let thumb_uniforms = ScrollbarUniforms {
             position: [ndc_x, thumb_ndc_y],
             size: [ndc_width, thumb_ndc_height],
-            color: self.thumb_color,
+            color: self.faded(self.thumb_color),
         };
         queue.write_buffer(
             &self.uniform_buffer,
@@ -513,7 +520,7 @@ impl Scrollbar {
             let mark_uniforms = ScrollbarUniforms {
                 position: [ndc_x, ndc_y - mark_height_ndc / 2.0],
                 size: [ndc_width, mark_height_ndc],
-                color,
+                color: self.faded(color),
             };
 
             // Update the pre-allocated buffer using queue.write_buffer (no new allocation)
@@ -532,6 +539,26 @@ impl Scrollbar {
         }
     }
 
+    /// Set the auto-hide fade opacity (0.0 = invisible, 1.0 = configured colors).
+    ///
+    /// Takes effect on the next [`Scrollbar::update`].  Returns true if it changed.
+    pub fn set_opacity(&mut self, opacity: f32) -> bool {
+        let opacity = opacity.clamp(0.0, 1.0);
+        let changed = opacity != self.opacity;
+        self.opacity = opacity;
+        changed
+    }
+
+    /// Current auto-hide fade opacity.
+    pub fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    /// `color` with its alpha scaled by the fade opacity.
+    fn faded(&self, color: [f32; 4]) -> [f32; 4] {
+        [color[0], color[1], color[2], color[3] * self.opacity]
+    }
+
     /// Update scrollbar appearance (width and colors) in real-time
     pub fn update_appearance(&mut self, width: f32, thumb_color: [f32; 4], track_color: [f32; 4]) {
         self.width = width;
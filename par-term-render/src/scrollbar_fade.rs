@@ -0,0 +1,151 @@
+//! Scrollbar auto-hide with a fade-out animation.
+//!
+//! The scrollbar stays fully opaque for `delay` after the last scroll or
+//! hover activity, then fades to transparent over `fade`.  Any new activity
+//! makes it fully visible again immediately.  A zero delay disables
+//! auto-hide; a zero fade hides the scrollbar abruptly once the delay ends.
+
+use std::time::{Duration, Instant};
+
+/// Opacity state machine for the auto-hiding scrollbar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollbarFade {
+    /// Time the scrollbar stays visible after activity (zero = never hide)
+    delay: Duration,
+    /// Length of the fade-out once the delay has elapsed
+    fade: Duration,
+    /// Last scroll or hover activity
+    last_activity: Option<Instant>,
+}
+
+impl ScrollbarFade {
+    /// Create a fade state with no recorded activity.
+    pub fn new(delay: Duration, fade: Duration) -> Self {
+        Self {
+            delay,
+            fade,
+            last_activity: None,
+        }
+    }
+
+    /// Change the delay and fade durations, keeping the recorded activity.
+    pub fn set_timing(&mut self, delay: Duration, fade: Duration) {
+        self.delay = delay;
+        self.fade = fade;
+    }
+
+    /// Record scroll or hover activity at `at`.
+    ///
+    /// Activity older than the recorded one is ignored, so callers can pass
+    /// the same timestamp every frame.
+    pub fn record_activity(&mut self, at: Instant) {
+        if self.last_activity.is_none_or(|last| at > last) {
+            self.last_activity = Some(at);
+        }
+    }
+
+    /// Whether auto-hide is enabled.
+    pub fn auto_hides(&self) -> bool {
+        !self.delay.is_zero()
+    }
+
+    /// Scrollbar opacity in `[0.0, 1.0]` at `now`.
+    pub fn opacity(&self, now: Instant) -> f32 {
+        if !self.auto_hides() {
+            return 1.0;
+        }
+        let Some(last) = self.last_activity else {
+            return 0.0;
+        };
+        let idle = now.saturating_duration_since(last);
+        let Some(fading_for) = idle.checked_sub(self.delay) else {
+            return 1.0;
+        };
+        if fading_for >= self.fade {
+            return 0.0;
+        }
+        1.0 - fading_for.as_secs_f32() / self.fade.as_secs_f32()
+    }
+
+    /// Whether the scrollbar is partway through fading out at `now`.
+    pub fn is_fading(&self, now: Instant) -> bool {
+        let opacity = self.opacity(now);
+        opacity > 0.0 && opacity < 1.0
+    }
+
+    /// When the opacity next starts changing, if it is fully visible and
+    /// waiting for the delay to elapse.  Used to schedule a redraw.
+    pub fn fade_start(&self, now: Instant) -> Option<Instant> {
+        if !self.auto_hides() {
+            return None;
+        }
+        let start = self.last_activity? + self.delay;
+        (start > now).then_some(start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DELAY: Duration = Duration::from_millis(1000);
+    const FADE: Duration = Duration::from_millis(200);
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn stays_visible_for_delay_then_fades_out() {
+        let t0 = Instant::now();
+        let mut fade = ScrollbarFade::new(DELAY, FADE);
+        assert_eq!(fade.opacity(t0), 0.0, "hidden before any activity");
+
+        fade.record_activity(t0);
+        assert_eq!(fade.opacity(t0), 1.0);
+        assert_eq!(fade.opacity(t0 + ms(999)), 1.0);
+        assert_eq!(fade.fade_start(t0 + ms(500)), Some(t0 + DELAY));
+        assert!(!fade.is_fading(t0 + ms(500)));
+
+        let mid = fade.opacity(t0 + ms(1100));
+        assert!((mid - 0.5).abs() < 1e-3, "{mid}");
+        assert!(fade.is_fading(t0 + ms(1100)));
+        assert_eq!(fade.fade_start(t0 + ms(1100)), None);
+        assert!(fade.opacity(t0 + ms(1050)) > fade.opacity(t0 + ms(1150)));
+
+        assert_eq!(fade.opacity(t0 + ms(1200)), 0.0);
+        assert!(!fade.is_fading(t0 + ms(1500)));
+    }
+
+    #[test]
+    fn activity_shows_immediately_and_restarts_delay() {
+        let t0 = Instant::now();
+        let mut fade = ScrollbarFade::new(DELAY, FADE);
+        fade.record_activity(t0);
+        assert!(fade.is_fading(t0 + ms(1150)));
+
+        // A scroll mid-fade brings the scrollbar straight back.
+        fade.record_activity(t0 + ms(1150));
+        assert_eq!(fade.opacity(t0 + ms(1150)), 1.0);
+        assert_eq!(fade.opacity(t0 + ms(2100)), 1.0);
+        assert!(fade.is_fading(t0 + ms(2200)));
+
+        // Stale activity timestamps do not rewind the timer.
+        fade.record_activity(t0);
+        assert!(fade.is_fading(t0 + ms(2200)));
+    }
+
+    #[test]
+    fn zero_delay_never_hides_and_zero_fade_hides_abruptly() {
+        let t0 = Instant::now();
+        let always = ScrollbarFade::new(Duration::ZERO, FADE);
+        assert_eq!(always.opacity(t0 + ms(60_000)), 1.0);
+        assert_eq!(always.fade_start(t0), None);
+
+        let mut abrupt = ScrollbarFade::new(DELAY, Duration::ZERO);
+        abrupt.record_activity(t0);
+        assert_eq!(abrupt.opacity(t0 + ms(999)), 1.0);
+        assert_eq!(abrupt.opacity(t0 + DELAY), 0.0);
+        assert!(!abrupt.is_fading(t0 + DELAY));
+    }
+}
@@ -1,15 +1,31 @@
 // Cell background shader - renders colored quads for each cell
 
+// Set for sRGB surfaces (blend_color_space: linear): sRGB-encoded inputs are
+// decoded so the hardware blends in linear light and re-encodes on write.
+override linear_blending: bool = false;
+
+fn output_rgb(c: vec3<f32>) -> vec3<f32> {
+    if (!linear_blending) {
+        return c;
+    }
+    let low = c / 12.92;
+    let high = pow((c + 0.055) / 1.055, vec3<f32>(2.4));
+    return select(high, low, c <= vec3<f32>(0.04045));
+}
+
 struct VertexInput {
     @builtin(vertex_index) vertex_index: u32,
     @location(2) position: vec2<f32>,  // Cell position in NDC
     @location(3) size: vec2<f32>,      // Cell size in NDC
     @location(4) color: vec4<f32>,     // Background color
+    @location(5) corner_radii: vec4<f32>,  // Pixels: top-left, top-right, bottom-right, bottom-left
 }
 
 struct VertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) color: vec4<f32>,
+    @location(1) local: vec2<f32>,  // Position within the quad, 0..1 from the top-left
+    @location(2) @interpolate(flat) corner_radii: vec4<f32>,
 }
 
 @vertex
@@ -28,12 +44,34 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 
     out.position = vec4<f32>(pos, 0.0, 1.0);
     out.color = input.color;
+    out.local = vec2<f32>(x, y);
+    out.corner_radii = input.corner_radii;
 
     return out;
 }
 
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    // Quad size in pixels; derivatives must be taken before any branch
+    let size = 1.0 / abs(vec2<f32>(dpdx(input.local.x), dpdy(input.local.y)));
+
+    // Cut away the pixels outside a rounded corner.  The edge is left hard
+    // because the blend state replaces the destination alpha.
+    let left = input.local.x < 0.5;
+    let top = input.local.y < 0.5;
+    let radius = select(
+        select(input.corner_radii.z, input.corner_radii.w, left),
+        select(input.corner_radii.y, input.corner_radii.x, left),
+        top
+    );
+    if (radius > 0.0) {
+        let p = input.local * size;
+        let edge_distance = min(p, size - p);
+        if (all(edge_distance < vec2<f32>(radius)) && length(vec2<f32>(radius) - edge_distance) > radius) {
+            discard;
+        }
+    }
+
     // Output straight (non-premultiplied) colors
-    return input.color;
+    return vec4<f32>(output_rgb(input.color.rgb), input.color.a);
 }
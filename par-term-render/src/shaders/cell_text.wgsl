@@ -1,5 +1,18 @@
 // Cell text shader - renders glyphs from texture atlas
 
+// Set for sRGB surfaces (blend_color_space: linear): sRGB-encoded inputs are
+// decoded so the hardware blends in linear light and re-encodes on write.
+override linear_blending: bool = false;
+
+fn output_rgb(c: vec3<f32>) -> vec3<f32> {
+    if (!linear_blending) {
+        return c;
+    }
+    let low = c / 12.92;
+    let high = pow((c + 0.055) / 1.055, vec3<f32>(2.4));
+    return select(high, low, c <= vec3<f32>(0.04045));
+}
+
 struct VertexInput {
     @builtin(vertex_index) vertex_index: u32,
     @location(2) position: vec2<f32>,    // Glyph position in NDC
@@ -8,6 +21,7 @@ struct VertexInput {
     @location(5) tex_size: vec2<f32>,    // Texture size (normalized 0-1)
     @location(6) color: vec4<f32>,       // Foreground color
     @location(7) is_colored: u32,        // 1 if colored (emoji), 0 if monochrome
+    @location(8) atlas_page: u32,        // Atlas page (texture array layer)
 }
 
 struct VertexOutput {
@@ -15,10 +29,11 @@ struct VertexOutput {
     @location(0) tex_coord: vec2<f32>,
     @location(1) color: vec4<f32>,
     @location(2) @interpolate(flat) is_colored: u32,
+    @location(3) @interpolate(flat) atlas_page: u32,
 }
 
 @group(0) @binding(0)
-var glyph_texture: texture_2d<f32>;
+var glyph_texture: texture_2d_array<f32>;
 
 @group(0) @binding(1)
 var glyph_sampler: sampler;
@@ -47,6 +62,7 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 
     out.color = input.color;
     out.is_colored = input.is_colored;
+    out.atlas_page = input.atlas_page;
 
     return out;
 }
@@ -54,7 +70,7 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     // Sample glyph from atlas (RGBA for colored emoji support)
-    let glyph = textureSample(glyph_texture, glyph_sampler, input.tex_coord);
+    let glyph = textureSample(glyph_texture, glyph_sampler, input.tex_coord, input.atlas_page);
 
     // Skip fully transparent pixels
     if (glyph.a < 0.01) {
@@ -64,9 +80,9 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     // Output straight (non-premultiplied) colors for proper alpha blending
     if (input.is_colored == 1u) {
         // Colored glyph (emoji) - use glyph color with combined alpha
-        return vec4<f32>(glyph.rgb, glyph.a * input.color.a);
+        return vec4<f32>(output_rgb(glyph.rgb), glyph.a * input.color.a);
     } else {
         // Monochrome glyph - use foreground color with glyph alpha mask
-        return vec4<f32>(input.color.rgb, input.color.a * glyph.a);
+        return vec4<f32>(output_rgb(input.color.rgb), input.color.a * glyph.a);
     }
 }
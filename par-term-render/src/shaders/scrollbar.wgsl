@@ -1,5 +1,18 @@
 // Scrollbar shader
 
+// Set for sRGB surfaces (blend_color_space: linear): sRGB-encoded inputs are
+// decoded so the hardware blends in linear light and re-encodes on write.
+override linear_blending: bool = false;
+
+fn output_rgb(c: vec3<f32>) -> vec3<f32> {
+    if (!linear_blending) {
+        return c;
+    }
+    let low = c / 12.92;
+    let high = pow((c + 0.055) / 1.055, vec3<f32>(2.4));
+    return select(high, low, c <= vec3<f32>(0.04045));
+}
+
 struct Uniforms {
     position: vec2<f32>,  // Position in NDC
     size: vec2<f32>,      // Size in NDC
@@ -48,5 +61,5 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     }
 
     // Output premultiplied colors for PreMultiplied composite alpha mode
-    return vec4<f32>(uniforms.color.rgb * alpha, alpha);
+    return vec4<f32>(output_rgb(uniforms.color.rgb) * alpha, alpha);
 }
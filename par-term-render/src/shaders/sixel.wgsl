@@ -1,5 +1,18 @@
 // Sixel graphics shader - renders RGBA images at terminal cell positions
 
+// Set for sRGB surfaces (blend_color_space: linear): sRGB-encoded inputs are
+// decoded so the hardware blends in linear light and re-encodes on write.
+override linear_blending: bool = false;
+
+fn output_rgb(c: vec3<f32>) -> vec3<f32> {
+    if (!linear_blending) {
+        return c;
+    }
+    let low = c / 12.92;
+    let high = pow((c + 0.055) / 1.055, vec3<f32>(2.4));
+    return select(high, low, c <= vec3<f32>(0.04045));
+}
+
 struct VertexInput {
     @builtin(vertex_index) vertex_index: u32,
     @location(0) position: vec2<f32>,    // Image position in screen space (normalized 0-1)
@@ -56,5 +69,5 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     // Apply global alpha multiplier and output premultiplied colors
     // for PreMultiplied composite alpha mode
     let final_alpha = color.a * input.alpha;
-    return vec4<f32>(color.rgb * final_alpha, final_alpha);
+    return vec4<f32>(output_rgb(color.rgb) * final_alpha, final_alpha);
 }
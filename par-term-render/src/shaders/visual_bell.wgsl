@@ -3,6 +3,19 @@
 // Renders a fullscreen flash overlay for the terminal bell (\x07).
 // Uses premultiplied alpha blending for smooth fade-out.
 
+// Set for sRGB surfaces (blend_color_space: linear): sRGB-encoded inputs are
+// decoded so the hardware blends in linear light and re-encodes on write.
+override linear_blending: bool = false;
+
+fn output_rgb(c: vec3<f32>) -> vec3<f32> {
+    if (!linear_blending) {
+        return c;
+    }
+    let low = c / 12.92;
+    let high = pow((c + 0.055) / 1.055, vec3<f32>(2.4));
+    return select(high, low, c <= vec3<f32>(0.04045));
+}
+
 struct Uniforms {
     position: vec2<f32>,  // NDC position (-1, -1 for fullscreen)
     size: vec2<f32>,      // NDC size (2, 2 for fullscreen)
@@ -39,5 +52,5 @@ fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     // Output premultiplied color for PreMultiplied composite alpha mode
     let alpha = uniforms.color.a;
-    return vec4<f32>(uniforms.color.rgb * alpha, alpha);
+    return vec4<f32>(output_rgb(uniforms.color.rgb) * alpha, alpha);
 }
@@ -39,6 +39,18 @@ impl ScriptEventForwarder {
         }
     }
 
+    /// Buffer an event raised by the frontend rather than the terminal core
+    /// (e.g. `hyperlink_hover`), applying the same subscription filter.
+    pub fn push_event(&self, event: ScriptEvent) {
+        if let Some(ref filter) = self.subscription_filter
+            && !filter.contains(&event.kind)
+        {
+            return;
+        }
+        let mut buf = self.event_buffer.lock().expect("event_buffer poisoned");
+        buf.push(event);
+    }
+
     /// Drain all buffered events, returning them and clearing the buffer.
     pub fn drain_events(&self) -> Vec<ScriptEvent> {
         let mut buf = self.event_buffer.lock().expect("event_buffer poisoned");
@@ -289,6 +301,29 @@ mod tests {
         assert_eq!(events[0].kind, "bell_rang");
     }
 
+    #[test]
+    fn test_pushed_events_follow_subscription() {
+        let hover = || ScriptEvent::hyperlink_hover(Some("https://example.com".to_string()), None);
+
+        let fwd = ScriptEventForwarder::new(None);
+        fwd.push_event(hover());
+        fwd.push_event(ScriptEvent::hyperlink_hover(None, None));
+        let events = fwd.drain_events();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].kind, "hyperlink_hover");
+        assert_eq!(
+            events[0].data,
+            ScriptEventData::HyperlinkHover {
+                url: Some("https://example.com".to_string()),
+                id: None,
+            }
+        );
+
+        let fwd = ScriptEventForwarder::new(Some(HashSet::from(["bell_rang".to_string()])));
+        fwd.push_event(hover());
+        assert!(fwd.drain_events().is_empty());
+    }
+
     #[test]
     fn test_drain_clears_buffer() {
         let fwd = ScriptEventForwarder::new(None);
@@ -40,6 +40,8 @@
 //!   - Subject to rate limiting
 //! - `ChangeConfig`: Modify terminal configuration (requires `allow_change_config: true`)
 //!   - Must validate config keys against an allowlist
+//! - `GetSelection`: Read the selected text (requires `allow_read_selection: true`)
+//!   - The reply is sent only to the requesting script
 //!
 //! ## Implementation Status
 //!
@@ -50,6 +52,8 @@
 //! - `RunCommand`: Requires `allow_run_command`, rate-limited, denylist-checked,
 //!   tokenised without shell invocation
 //! - `ChangeConfig`: Requires `allow_change_config`, allowlisted keys only
+//! - `GetSelection`: Requires `allow_read_selection`, answered with a
+//!   `selection` event
 //!
 //! ## Dispatcher Responsibility
 //!
@@ -60,6 +64,7 @@
 //!
 //! See `par-term-scripting/SECURITY.md` for the complete security model.
 
+use par_term_config::scripting::ScriptConfig;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -156,6 +161,20 @@ pub enum ScriptEventData {
         event: String,
     },
 
+    /// The hyperlink under the mouse changed.
+    HyperlinkHover {
+        /// Target of the hovered link, or None when the mouse left it.
+        url: Option<String>,
+        /// OSC 8 `id` parameter of the link, if any.
+        id: Option<String>,
+    },
+
+    /// Reply to `GetSelection`.
+    Selection {
+        /// Selected text; empty when nothing is selected.
+        text: String,
+    },
+
     /// Fallback for unmapped events. Carries arbitrary key-value fields.
     Generic {
         /// Arbitrary event fields.
@@ -229,6 +248,49 @@ pub enum ScriptCommand {
 
     /// Clear the markdown panel.
     ClearPanel {},
+
+    /// Request the current selection text.
+    ///
+    /// Answered with a [`ScriptEvent::selection`] event sent to this script.
+    GetSelection {},
+}
+
+impl ScriptEvent {
+    /// Build the `selection` event answering a `GetSelection` command.
+    ///
+    /// `None` (no selection) is reported as empty text.
+    pub fn selection(text: Option<String>) -> Self {
+        Self {
+            kind: "selection".to_string(),
+            data: ScriptEventData::Selection {
+                text: text.unwrap_or_default(),
+            },
+        }
+    }
+
+    /// Answer a `GetSelection` command sent by `script`.
+    ///
+    /// Returns `None` (the command is denied) unless the script is configured
+    /// with `allow_read_selection`.  `selected` is only called once the
+    /// command is allowed, so a denied script never causes a read.
+    pub fn selection_reply(
+        script: Option<&ScriptConfig>,
+        selected: impl FnOnce() -> Option<String>,
+    ) -> Option<Self> {
+        script
+            .is_some_and(|s| s.allow_read_selection)
+            .then(|| Self::selection(selected()))
+    }
+
+    /// Build the `hyperlink_hover` event for the link now under the mouse.
+    ///
+    /// `None` reports that the mouse left the previously hovered link.
+    pub fn hyperlink_hover(url: Option<String>, id: Option<String>) -> Self {
+        Self {
+            kind: "hyperlink_hover".to_string(),
+            data: ScriptEventData::HyperlinkHover { url, id },
+        }
+    }
 }
 
 /// Strip VT/ANSI escape sequences from text before PTY injection.
@@ -317,12 +379,14 @@ impl ScriptCommand {
     /// | `WriteText` | **Yes** | High (PTY injection, command execution) |
     /// | `RunCommand` | **Yes** | Critical (arbitrary process spawn) |
     /// | `ChangeConfig` | **Yes** | High (config modification) |
+    /// | `GetSelection` | **Yes** | Medium (may expose secrets) |
     pub fn requires_permission(&self) -> bool {
         matches!(
             self,
             ScriptCommand::RunCommand { .. }
                 | ScriptCommand::WriteText { .. }
                 | ScriptCommand::ChangeConfig { .. }
+                | ScriptCommand::GetSelection {}
         )
     }
 
@@ -333,11 +397,13 @@ impl ScriptCommand {
     /// - `"allow_run_command"` for `RunCommand`
     /// - `"allow_write_text"` for `WriteText`
     /// - `"allow_change_config"` for `ChangeConfig`
+    /// - `"allow_read_selection"` for `GetSelection`
     pub fn permission_flag_name(&self) -> Option<&'static str> {
         match self {
             ScriptCommand::RunCommand { .. } => Some("allow_run_command"),
             ScriptCommand::WriteText { .. } => Some("allow_write_text"),
             ScriptCommand::ChangeConfig { .. } => Some("allow_change_config"),
+            ScriptCommand::GetSelection {} => Some("allow_read_selection"),
             _ => None,
         }
     }
@@ -366,6 +432,7 @@ impl ScriptCommand {
             ScriptCommand::Log { .. } => "Log",
             ScriptCommand::SetPanel { .. } => "SetPanel",
             ScriptCommand::ClearPanel {} => "ClearPanel",
+            ScriptCommand::GetSelection {} => "GetSelection",
         }
     }
 }
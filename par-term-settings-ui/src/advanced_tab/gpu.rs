@@ -0,0 +1,102 @@
+//! GPU section for the advanced settings tab.
+//!
+//! Covers: adapter selection, the blend color space and a diagnostics panel
+//! (backend, device limits, inline graphics cache usage) for the adapter the
+//! focused window renders with.
+
+use crate::SettingsUI;
+use crate::section::collapsing_section;
+use par_term_config::{BlendColorSpace, gpu_adapter_choices};
+use std::collections::HashSet;
+
+pub(super) fn show_gpu_section(
+    ui: &mut egui::Ui,
+    settings: &mut SettingsUI,
+    changes_this_frame: &mut bool,
+    collapsed: &mut HashSet<String>,
+) {
+    collapsing_section(ui, "GPU", "advanced_gpu", false, collapsed, |ui| {
+        let choices = gpu_adapter_choices(&settings.gpu_adapters);
+        let selected = choices
+            .iter()
+            .find(|c| c.value == settings.config.gpu_adapter)
+            .map(|c| c.label.clone())
+            .unwrap_or_else(|| {
+                // Configured adapter is not present on this machine
+                format!(
+                    "{} (not found)",
+                    settings.config.gpu_adapter.as_deref().unwrap_or_default()
+                )
+            });
+
+        ui.horizontal(|ui| {
+            ui.label("Adapter:");
+            egui::ComboBox::from_id_salt("advanced_gpu_adapter")
+                .width(320.0)
+                .selected_text(selected)
+                .show_ui(ui, |ui| {
+                    for choice in &choices {
+                        if ui
+                            .selectable_label(
+                                settings.config.gpu_adapter == choice.value,
+                                &choice.label,
+                            )
+                            .clicked()
+                            && settings.config.gpu_adapter != choice.value
+                        {
+                            settings.config.gpu_adapter = choice.value.clone();
+                            settings.has_changes = true;
+                            *changes_this_frame = true;
+                        }
+                    }
+                });
+        })
+        .response
+        .on_hover_text(
+            "Automatic picks an adapter using the GPU Power Preference (Window > Performance)",
+        );
+
+        ui.horizontal(|ui| {
+            ui.label("Blend color space:");
+            egui::ComboBox::from_id_salt("advanced_blend_color_space")
+                .selected_text(settings.config.blend_color_space.display_name())
+                .show_ui(ui, |ui| {
+                    for &space in BlendColorSpace::all() {
+                        if ui
+                            .selectable_value(
+                                &mut settings.config.blend_color_space,
+                                space,
+                                space.display_name(),
+                            )
+                            .changed()
+                        {
+                            settings.has_changes = true;
+                            *changes_this_frame = true;
+                        }
+                    }
+                });
+        })
+        .response
+        .on_hover_text(
+            "Where translucent layers and glyph edges are mixed. Linear is gamma-correct; \
+             sRGB matches most other terminals",
+        );
+        ui.colored_label(
+            egui::Color32::GRAY,
+            "Note: Requires app restart to take effect",
+        );
+
+        ui.add_space(8.0);
+        ui.label(egui::RichText::new("Diagnostics").strong());
+        egui::Grid::new("advanced_gpu_diagnostics")
+            .num_columns(2)
+            .spacing([16.0, 4.0])
+            .show(ui, |ui| {
+                for (label, value) in settings.gpu_diagnostics.rows() {
+                    ui.label(egui::RichText::new(label).weak());
+                    ui.label(value);
+                    ui.end_row();
+                }
+            });
+    });
+}
@@ -10,6 +10,7 @@
 //! - Update settings
 //! - File transfer settings
 //! - Debug logging settings
+//! - GPU adapter selection, blend color space and diagnostics
 //! - Security settings (env var allowlist)
 //!
 //! ## Sub-module layout
@@ -20,8 +21,10 @@
 //! | `import_export.rs` | Import/Export section + `merge_config` helper |
 //! | `tmux.rs` | tmux Integration section |
 //! | `logging.rs` | Session Logging section |
+//! | `gpu.rs` | GPU adapter selection and diagnostics section |
 //! | `system.rs` | Screenshots, Updates, File Transfers, Debug Logging, Security sections |
 
+mod gpu;
 mod import_export;
 mod logging;
 mod system;
@@ -74,6 +77,7 @@ pub fn show(
             "prefix key",
             "status bar",
             "clipboard sync",
+            "passthrough",
             "auto-attach",
         ],
     ) {
@@ -161,6 +165,32 @@ pub fn show(
         system::show_debug_logging_section(ui, settings, changes_this_frame, collapsed);
     }
 
+    // GPU section (collapsed by default)
+    if section_matches(
+        &query,
+        "GPU",
+        &[
+            "gpu",
+            "adapter",
+            "device",
+            "graphics card",
+            "vram",
+            "backend",
+            "vulkan",
+            "metal",
+            "directx",
+            "limits",
+            "diagnostics",
+            "gamma",
+            "srgb",
+            "linear",
+            "color space",
+            "blending",
+        ],
+    ) {
+        gpu::show_gpu_section(ui, settings, changes_this_frame, collapsed);
+    }
+
     // Security section
     if section_matches(
         &query,
@@ -197,6 +227,7 @@ pub fn keywords() -> &'static [&'static str] {
         "attach",
         "clipboard sync",
         "tmux clipboard",
+        "passthrough",
         "status bar",
         "tmux status",
         "refresh interval",
@@ -249,6 +280,22 @@ pub fn keywords() -> &'static [&'static str] {
         "trace",
         "verbose",
         "diagnostics",
+        // GPU
+        "gpu",
+        "gpu adapter",
+        "adapter",
+        "device",
+        "graphics card",
+        "vram",
+        "backend",
+        "vulkan",
+        "metal",
+        "directx",
+        "limits",
+        "gamma",
+        "srgb",
+        "color space",
+        "blending",
         // Import/export preferences
         "import",
         "export",
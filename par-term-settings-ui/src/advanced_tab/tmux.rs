@@ -1,6 +1,6 @@
 //! tmux Integration section for the advanced settings tab.
 //!
-//! Covers: tmux enable/disable, path, default session, auto-attach, clipboard sync,
+//! Covers: tmux enable/disable, path, default session, auto-attach, clipboard sync, passthrough,
 //! status bar (left/right format, refresh interval), prefix key.
 
 use crate::SettingsUI;
@@ -143,6 +143,25 @@ pub(super) fn show_tmux_section(
 
             ui.add_space(8.0);
 
+            // Passthrough
+            ui.label(egui::RichText::new("Passthrough").strong());
+            if ui
+                .checkbox(
+                    &mut settings.config.tmux_unwrap_passthrough,
+                    "Unwrap nested tmux passthrough",
+                )
+                .on_hover_text(
+                    "Apply escape sequences that a nested tmux wraps in DCS tmux; passthrough \
+                     (images, titles, colors) instead of discarding them.",
+                )
+                .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+
+            ui.add_space(8.0);
+
             // Gateway Tab
             ui.label(egui::RichText::new("Gateway Tab").strong());
             if ui
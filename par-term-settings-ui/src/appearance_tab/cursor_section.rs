@@ -22,6 +22,7 @@ pub(super) fn show_cursor_section(
             "beam",
             "underline",
             "blink",
+            "blink only when focused",
             "color",
             "text color",
             "cursor text color",
@@ -83,6 +84,18 @@ pub(super) fn show_cursor_section(
                 }
             });
 
+            if ui
+                .checkbox(
+                    &mut settings.config.cursor.cursor_blink_only_focused,
+                    "Blink only when focused",
+                )
+                .on_hover_text("Keep the cursor steady while the window is unfocused")
+                .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+
             ui.horizontal(|ui| {
                 ui.label("Color:");
                 let mut color = settings.config.cursor.cursor_color;
@@ -250,6 +263,13 @@ pub(super) fn show_cursor_effects_section(
             "drop shadow",
             "shadow blur",
             "cursor row",
+            "morph",
+            "cursor animation",
+            "smear",
+            "motion blur",
+            "trail",
+            "ligature",
+            "cursor width",
         ],
     ) {
         collapsing_section(
@@ -391,6 +411,91 @@ pub(super) fn show_cursor_effects_section(
                         }
                     });
                 }
+
+                ui.add_space(4.0);
+
+                // Cursor shape morph
+                ui.horizontal(|ui| {
+                    ui.label("Shape morph duration:");
+                    if ui
+                        .add(
+                            egui::Slider::new(
+                                &mut settings.config.cursor.cursor_morph_duration_ms,
+                                0..=500,
+                            )
+                            .suffix(" ms"),
+                        )
+                        .on_hover_text(
+                            "Animate between cursor shapes when an application changes the cursor style (0 = instant)",
+                        )
+                        .changed()
+                    {
+                        settings.has_changes = true;
+                        *changes_this_frame = true;
+                    }
+                });
+
+                ui.add_space(4.0);
+
+                // Cursor smear
+                ui.horizontal(|ui| {
+                    ui.label("Smear length:");
+                    if ui
+                        .add(
+                            egui::Slider::new(
+                                &mut settings.config.cursor.cursor_smear_length,
+                                0.0..=20.0,
+                            )
+                            .suffix(" cells"),
+                        )
+                        .on_hover_text(
+                            "Draw a fading streak behind the cursor when it jumps two or more cells (0 = off)",
+                        )
+                        .changed()
+                    {
+                        settings.has_changes = true;
+                        *changes_this_frame = true;
+                    }
+                });
+                ui.add_enabled_ui(settings.config.cursor.cursor_smear_length > 0.0, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label("Smear fade:");
+                        if ui
+                            .add(
+                                egui::Slider::new(
+                                    &mut settings.config.cursor.cursor_smear_decay_ms,
+                                    20..=1000,
+                                )
+                                .suffix(" ms"),
+                            )
+                            .on_hover_text("How long the smear streak takes to fade out")
+                            .changed()
+                        {
+                            settings.has_changes = true;
+                            *changes_this_frame = true;
+                        }
+                    });
+                });
+
+                ui.add_space(4.0);
+
+                // Ligature-aware cursor width
+                ui.add_enabled_ui(settings.config.enable_ligatures, |ui| {
+                    if ui
+                        .checkbox(
+                            &mut settings.config.cursor.cursor_ligature_width,
+                            "Widen cursor over ligatures",
+                        )
+                        .on_hover_text(
+                            "Cover every cell of a ligature under the cursor (e.g. both cells of =>).\n\
+                             Requires font ligatures to be enabled.",
+                        )
+                        .changed()
+                    {
+                        settings.has_changes = true;
+                        *changes_this_frame = true;
+                    }
+                });
             },
         );
     }
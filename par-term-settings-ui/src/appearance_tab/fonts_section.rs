@@ -224,7 +224,15 @@ pub(super) fn show_font_variants_section(
     if section_matches(
         &settings.search_query.trim().to_lowercase(),
         "Font Variants",
-        &["bold", "italic", "bold-italic", "font fallback", "variant"],
+        &[
+            "bold",
+            "italic",
+            "bold-italic",
+            "font fallback",
+            "variant",
+            "synthetic bold",
+            "synthesize",
+        ],
     ) {
         collapsing_section(
             ui,
@@ -271,6 +279,19 @@ pub(super) fn show_font_variants_section(
                         settings.font_pending_changes = true;
                     }
                 });
+
+                if ui
+                    .checkbox(
+                        &mut settings.temp_synthesize_styles,
+                        "Synthesize missing bold and italic",
+                    )
+                    .on_hover_text(
+                        "Embolden and slant the regular font when no bold or italic font is available",
+                    )
+                    .changed()
+                {
+                    settings.font_pending_changes = true;
+                }
             },
         );
     }
@@ -288,6 +309,7 @@ pub(super) fn show_text_shaping_section(
         &[
             "shaping",
             "ligatures",
+            "ligature fallback",
             "kerning",
             "harfbuzz",
             "complex scripts",
@@ -318,6 +340,27 @@ pub(super) fn show_text_shaping_section(
                     settings.font_pending_changes = true;
                 }
 
+                ui.add_enabled_ui(settings.temp_enable_ligatures, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label("Ligature fallback font (optional):");
+                        if ui
+                            .add(
+                                egui::TextEdit::singleline(
+                                    &mut settings.temp_font_ligature_fallback,
+                                )
+                                .desired_width(INPUT_WIDTH),
+                            )
+                            .on_hover_text(
+                                "Font to take ligatures such as => and != from when the \
+                                 primary font has none (e.g. JetBrains Mono, Fira Code)",
+                            )
+                            .changed()
+                        {
+                            settings.font_pending_changes = true;
+                        }
+                    });
+                });
+
                 if ui
                     .checkbox(&mut settings.temp_enable_kerning, "Enable kerning")
                     .changed()
@@ -348,6 +391,8 @@ pub(super) fn show_font_rendering_section(
             "brightness",
             "hidpi",
             "retina",
+            "glyph atlas",
+            "atlas pages",
         ],
     ) {
         collapsing_section(
@@ -450,6 +495,48 @@ pub(super) fn show_font_rendering_section(
                 .on_hover_text(
                     "Set to 0 to disable. Higher values push text color further from background.",
                 );
+
+                // Glyph atlas sizing
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    ui.label("Glyph atlas page size:");
+                    if ui
+                        .add(
+                            egui::Slider::new(
+                                &mut settings.config.font_rendering.glyph_atlas_size,
+                                512..=8192,
+                            )
+                            .step_by(256.0)
+                            .suffix(" px"),
+                        )
+                        .on_hover_text(
+                            "Width and height of each glyph atlas page. Larger pages hold more \
+                             glyphs but use more GPU memory. Applies to new windows.",
+                        )
+                        .changed()
+                    {
+                        settings.has_changes = true;
+                        *changes_this_frame = true;
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Glyph atlas max pages:");
+                    if ui
+                        .add(egui::Slider::new(
+                            &mut settings.config.font_rendering.glyph_atlas_max_pages,
+                            1..=16,
+                        ))
+                        .on_hover_text(
+                            "A new page is added when the atlas fills up (many scripts, sizes, \
+                             or emoji). Glyphs are only evicted once every page is full. \
+                             Applies to new windows.",
+                        )
+                        .changed()
+                    {
+                        settings.has_changes = true;
+                        *changes_this_frame = true;
+                    }
+                });
             },
         );
     }
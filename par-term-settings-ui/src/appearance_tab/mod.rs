@@ -75,12 +75,15 @@ pub fn keywords() -> &'static [&'static str] {
         "text shaping",
         "shaping",
         "ligatures",
+        "ligature fallback",
         "kerning",
         // Font rendering
         "anti-alias",
         "antialias",
         "hinting",
         "thin strokes",
+        "glyph atlas",
+        "atlas pages",
         "smoothing",
         "minimum contrast",
         "contrast",
@@ -92,6 +95,7 @@ pub fn keywords() -> &'static [&'static str] {
         "underline",
         "blink",
         "interval",
+        "steady when unfocused",
         // Cursor appearance
         "cursor color",
         "text color",
@@ -108,11 +112,20 @@ pub fn keywords() -> &'static [&'static str] {
         "cursor boost",
         "boost",
         "glow",
+        "cursor morph",
+        "morph",
+        "cursor smear",
+        "smear",
+        "motion blur",
+        "cursor ligature",
+        "ligature cursor",
         // Font variants
         "bold-italic",
         "bold italic",
         "font variant",
         "variant",
+        "synthetic bold",
+        "synthesize",
         // Badge (absorbed from badge_tab)
         "badge",
         "badge enabled",
@@ -132,6 +145,9 @@ pub fn keywords() -> &'static [&'static str] {
         "path",
         "overlay",
         "label",
+        "badge preview",
+        "autocomplete",
+        "user variable",
         // Progress Bar (absorbed from progress_bar_tab)
         "progress",
         "progress bar",
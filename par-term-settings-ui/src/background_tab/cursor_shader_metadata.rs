@@ -1,5 +1,6 @@
 use crate::SettingsUI;
 use crate::section::collapsing_section;
+use par_term_config::shader_config::global_defaults;
 use par_term_config::{CursorShaderConfig, CursorShaderMetadata};
 use std::collections::HashSet;
 
@@ -235,6 +236,46 @@ fn show_per_cursor_shader_settings(
         });
     }
 
+    // Glow / trail parameters (per-shader overrides of the global cursor shader values)
+    let global = &settings.config.shader;
+    let float_overrides: [CursorFloatOverride; 3] = [
+        CursorFloatOverride {
+            label: "Glow radius:",
+            hover: "Radius of the cursor glow in pixels (iCursorGlowRadius)",
+            range: 0.0..=500.0,
+            global: global.cursor_shader_glow_radius,
+            get: |c| c.glow_radius,
+            set: |c, v| c.glow_radius = v,
+        },
+        CursorFloatOverride {
+            label: "Glow intensity:",
+            hover: "Strength of the cursor glow, 0.0-1.0 (iCursorGlowIntensity)",
+            range: 0.0..=1.0,
+            global: global.cursor_shader_glow_intensity,
+            get: |c| c.glow_intensity,
+            set: |c, v| c.glow_intensity = v,
+        },
+        CursorFloatOverride {
+            label: "Trail duration:",
+            hover: "How long the cursor trail lingers, in seconds (iCursorTrailDuration)",
+            range: 0.0..=5.0,
+            global: global.cursor_shader_trail_duration,
+            get: |c| c.trail_duration,
+            set: |c, v| c.trail_duration = v,
+        },
+    ];
+    for entry in float_overrides {
+        show_cursor_float_override(
+            ui,
+            settings,
+            shader_name,
+            current_override.as_ref(),
+            meta_defaults.as_ref(),
+            entry,
+            changes_this_frame,
+        );
+    }
+
     // Reset all overrides button
     if has_override {
         ui.add_space(8.0);
@@ -268,6 +309,61 @@ fn show_per_cursor_shader_settings(
     });
 }
 
+/// A float cursor shader parameter that can be overridden per shader.
+struct CursorFloatOverride {
+    label: &'static str,
+    hover: &'static str,
+    range: std::ops::RangeInclusive<f32>,
+    /// Global fallback from the `shader` config section
+    global: f32,
+    /// Reads the field from a `CursorShaderConfig`
+    get: fn(&CursorShaderConfig) -> Option<f32>,
+    /// Writes the field on a `CursorShaderConfig`
+    set: fn(&mut CursorShaderConfig, Option<f32>),
+}
+
+/// Show a slider + reset button for one float cursor shader override.
+///
+/// Displays the effective value (override -> metadata default -> global); dragging
+/// the slider writes a per-shader override, reset removes it again.
+fn show_cursor_float_override(
+    ui: &mut egui::Ui,
+    settings: &mut SettingsUI,
+    shader_name: &str,
+    current_override: Option<&CursorShaderConfig>,
+    meta_defaults: Option<&CursorShaderConfig>,
+    entry: CursorFloatOverride,
+    changes_this_frame: &mut bool,
+) {
+    let override_val = current_override.and_then(entry.get);
+    let meta_val = meta_defaults.and_then(entry.get);
+    let mut value = override_val.or(meta_val).unwrap_or(entry.global);
+
+    ui.horizontal(|ui| {
+        ui.label(entry.label);
+        let response = ui
+            .add(egui::Slider::new(&mut value, entry.range.clone()))
+            .on_hover_text(entry.hover);
+
+        if response.changed() {
+            let override_entry = settings
+                .config
+                .get_or_create_cursor_shader_override(shader_name);
+            (entry.set)(override_entry, Some(value));
+            settings.has_changes = true;
+            *changes_this_frame = true;
+        }
+
+        if show_reset_button(ui, override_val.is_some())
+            && let Some(override_entry) = settings.config.cursor_shader_configs.get_mut(shader_name)
+        {
+            (entry.set)(override_entry, None);
+            settings.has_changes = true;
+            *changes_this_frame = true;
+        }
+    });
+}
+
 /// Save current effective cursor shader settings to the shader file's metadata block.
 fn save_cursor_settings_to_shader_metadata(
     settings: &mut SettingsUI,
@@ -370,6 +466,41 @@ fn build_cursor_metadata_from_settings(
         new_defaults.disable_in_alt_screen = None;
     }
 
+    // Update glow/trail parameters; only save values that differ from the built-in defaults
+    type Get = fn(&CursorShaderConfig) -> Option<f32>;
+    type Set = fn(&mut CursorShaderConfig, Option<f32>);
+    let shader = &settings.config.shader;
+    let float_fields: [(Get, Set, f32, f32); 3] = [
+        (
+            |c| c.glow_radius,
+            |c, v| c.glow_radius = v,
+            shader.cursor_shader_glow_radius,
+            global_defaults::GLOW_RADIUS,
+        ),
+        (
+            |c| c.glow_intensity,
+            |c, v| c.glow_intensity = v,
+            shader.cursor_shader_glow_intensity,
+            global_defaults::GLOW_INTENSITY,
+        ),
+        (
+            |c| c.trail_duration,
+            |c, v| c.trail_duration = v,
+            shader.cursor_shader_trail_duration,
+            global_defaults::TRAIL_DURATION,
+        ),
+    ];
+    for (get, set, global, builtin) in float_fields {
+        let effective = current_override
+            .and_then(get)
+            .or_else(|| meta_defaults.and_then(get))
+            .unwrap_or(global);
+        set(
+            &mut new_defaults,
+            ((effective - builtin).abs() > 0.001).then_some(effective),
+        );
+    }
+
     metadata.defaults = new_defaults;
     metadata
 }
@@ -460,6 +460,26 @@ fn show_background_shader_controls(
         });
     }
 
+    ui.horizontal(|ui| {
+        ui.label("Content overscan:");
+        if ui
+            .add(
+                egui::Slider::new(
+                    &mut settings.config.shader.custom_shader_overscan,
+                    0.0..=64.0,
+                )
+                .suffix(" px"),
+            )
+            .on_hover_text(
+                "Grow each pane's content area by this many pixels when it is drawn for the shader, so full-bleed effects are not clipped at the grid edge",
+            )
+            .changed()
+        {
+            settings.has_changes = true;
+            *changes_this_frame = true;
+        }
+    });
+
     ui.horizontal(|ui| {
         if ui
             .button("Cycle shader")
@@ -2,12 +2,14 @@
 //!
 //! Contains:
 //! - Badge enable/disable
-//! - Badge format string with variable interpolation
+//! - Badge format string with variable interpolation, live preview, and
+//!   variable autocompletion
 //! - Badge appearance (color, opacity, font)
 //! - Badge positioning (margins, max size)
 
 use super::SettingsUI;
 use super::section::{SLIDER_WIDTH, collapsing_section, section_matches};
+use par_term_config::{badge_completion_prefix, interpolate_badge_placeholders};
 use std::collections::HashSet;
 
 const SLIDER_HEIGHT: f32 = 18.0;
@@ -25,7 +27,15 @@ pub fn show(
     if section_matches(
         &query,
         "General",
-        &["enable", "badge", "format", "overlay", "text"],
+        &[
+            "enable",
+            "badge",
+            "format",
+            "overlay",
+            "text",
+            "preview",
+            "autocomplete",
+        ],
     ) {
         show_general_section(ui, settings, changes_this_frame, collapsed);
     }
@@ -61,6 +71,7 @@ pub fn show(
             "badge",
             "exit code",
             "command",
+            "user variable",
         ],
     ) {
         show_variables_section(ui, settings, changes_this_frame, collapsed);
@@ -105,15 +116,92 @@ fn show_general_section(
             *changes_this_frame = true;
         }
 
+        show_format_autocomplete(ui, settings, changes_this_frame);
+
         ui.add_space(4.0);
         ui.label(
             egui::RichText::new("Use \\(session.variable) syntax for dynamic values")
                 .small()
                 .color(egui::Color32::GRAY),
         );
+
+        // Live preview using the focused session's current variable values
+        ui.add_space(8.0);
+        let preview = interpolate_badge_placeholders(&settings.config.badge_format, |name| {
+            settings
+                .badge_variables
+                .iter()
+                .find(|v| v.name == name)
+                .and_then(|v| v.value.clone())
+        });
+        ui.horizontal(|ui| {
+            ui.label("Preview:");
+            if preview.trim().is_empty() {
+                ui.label(
+                    egui::RichText::new("(empty)")
+                        .italics()
+                        .color(egui::Color32::GRAY),
+                );
+            } else {
+                let [r, g, b] = settings.config.badge_color;
+                ui.label(
+                    egui::RichText::new(preview)
+                        .strong()
+                        .color(egui::Color32::from_rgb(r, g, b)),
+                );
+            }
+        });
     });
 }
 
+/// Suggest variables matching a trailing unclosed `\(` in the badge format.
+///
+/// Clicking a suggestion completes the placeholder, including the closing `)`.
+fn show_format_autocomplete(
+    ui: &mut egui::Ui,
+    settings: &mut SettingsUI,
+    changes_this_frame: &mut bool,
+) {
+    let Some(partial) = badge_completion_prefix(&settings.config.badge_format) else {
+        return;
+    };
+    let partial_len = partial.len();
+    let partial_lower = partial.to_lowercase();
+    let matches: Vec<&par_term_config::BadgeVariable> = settings
+        .badge_variables
+        .iter()
+        .filter(|v| v.name.to_lowercase().starts_with(&partial_lower))
+        .collect();
+    if matches.is_empty() {
+        return;
+    }
+
+    let mut completion: Option<String> = None;
+    ui.add_space(2.0);
+    egui::Frame::group(ui.style()).show(ui, |ui| {
+        for var in matches {
+            let response = ui
+                .selectable_label(false, egui::RichText::new(&var.name).monospace())
+                .on_hover_text(match &var.value {
+                    Some(value) => format!("{} (current: {})", var.description, value),
+                    None => var.description.clone(),
+                });
+            if response.clicked() {
+                completion = Some(var.name.clone());
+            }
+        }
+    });
+
+    if let Some(name) = completion {
+        let format = &mut settings.config.badge_format;
+        format.truncate(format.len() - partial_len);
+        format.push_str(&name);
+        format.push(')');
+        settings.has_changes = true;
+        *changes_this_frame = true;
+    }
+}
+
 // ============================================================================
 // Appearance Section
 // ============================================================================
@@ -298,35 +386,18 @@ fn show_variables_section(
             ui.label("Click a variable to append it to the format string:");
             ui.add_space(4.0);
 
-            let variables = [
-                ("\\(session.hostname)", "Remote or local hostname"),
-                ("\\(session.username)", "Current username"),
-                ("\\(session.path)", "Current working directory"),
-                ("\\(session.job)", "Foreground job name"),
-                ("\\(session.last_command)", "Last executed command"),
-                ("\\(session.profile_name)", "Current profile name"),
-                ("\\(session.tty)", "TTY device name"),
-                ("\\(session.columns)", "Terminal columns"),
-                ("\\(session.rows)", "Terminal rows"),
-                ("\\(session.bell_count)", "Number of bells received"),
-                ("\\(session.selection)", "Currently selected text"),
-                ("\\(session.tmux_pane_title)", "tmux pane title"),
-                ("\\(session.exit_code)", "Last command exit code"),
-                ("\\(session.current_command)", "Currently running command"),
-            ];
-
             // Collect clicked variable to avoid borrow issues
-            let mut clicked_var: Option<&str> = None;
+            let mut clicked_var: Option<String> = None;
 
             egui::Grid::new("badge_variables_grid")
-                .num_columns(2)
+                .num_columns(3)
                 .spacing([10.0, 4.0])
                 .show(ui, |ui| {
-                    for (var, desc) in variables {
+                    for var in &settings.badge_variables {
                         // Make variable a clickable link
                         let response = ui.add(
                             egui::Label::new(
-                                egui::RichText::new(var)
+                                egui::RichText::new(var.placeholder())
                                     .monospace()
                                     .color(egui::Color32::from_rgb(100, 150, 255)),
                             )
@@ -334,7 +405,7 @@ fn show_variables_section(
                         );
 
                         if response.clicked() {
-                            clicked_var = Some(var);
+                            clicked_var = Some(var.placeholder());
                         }
 
                         // Show pointer cursor and tooltip on hover
@@ -343,14 +414,19 @@ fn show_variables_section(
                         }
                         response.on_hover_text("Click to append to format");
 
-                        ui.label(desc);
+                        ui.label(&var.description);
+                        ui.label(
+                            egui::RichText::new(var.value.as_deref().unwrap_or("—"))
+                                .small()
+                                .color(egui::Color32::GRAY),
+                        );
                         ui.end_row();
                     }
                 });
 
             // Handle click outside the grid to avoid borrow conflict
             if let Some(var) = clicked_var {
-                settings.config.badge_format.push_str(var);
+                settings.config.badge_format.push_str(&var);
                 settings.has_changes = true;
                 *changes_this_frame = true;
             }
@@ -358,7 +434,8 @@ fn show_variables_section(
             ui.add_space(8.0);
             ui.label(
                 egui::RichText::new(
-                    "Tip: Badge format can also be set via OSC 1337;SetBadgeFormat=BASE64",
+                    "User variables set via OSC 1337;SetUserVar appear after the built-ins. \
+                     Badge format can also be set via OSC 1337;SetBadgeFormat=BASE64",
                 )
                 .small()
                 .color(egui::Color32::GRAY),
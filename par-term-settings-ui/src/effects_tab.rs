@@ -98,8 +98,12 @@ pub fn show(
         &[
             "cursor shader",
             "trail",
+            "trail duration",
             "glow",
+            "glow radius",
+            "glow intensity",
             "cursor effect",
+            "cursor shader override",
             "glsl",
             "animation",
         ],
@@ -220,9 +224,13 @@ pub fn keywords() -> &'static [&'static str] {
         "cursor shader",
         "cursor effect",
         "trail",
+        "trail duration",
         "glow",
+        "glow radius",
+        "glow intensity",
         "hides cursor",
         "alt screen",
+        "cursor shader override",
         // Per-pane background
         "per-pane background",
         "pane image",
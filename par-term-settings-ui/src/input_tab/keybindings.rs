@@ -69,6 +69,7 @@ pub(super) fn show_keybindings_section(
                             settings.config.keybindings.push(KeyBinding {
                                 key: combo,
                                 action: action_name.to_string(),
+                                ..Default::default()
                             });
                         }
 
@@ -1,8 +1,8 @@
 //! Keyboard and modifier remapping settings sections.
 
 use crate::SettingsUI;
-use crate::section::collapsing_section;
-use par_term_config::{ModifierTarget, OptionKeyMode};
+use crate::section::{INPUT_WIDTH, SLIDER_HEIGHT, SLIDER_WIDTH, collapsing_section};
+use par_term_config::{BackspaceSends, ModifierTarget, OptionKeyMode};
 use std::collections::HashSet;
 
 // ============================================================================
@@ -95,6 +95,116 @@ pub(super) fn show_keyboard_section(
             );
         });
 
+        ui.add_space(8.0);
+
+        if ui
+            .checkbox(
+                &mut settings.config.alt_sends_escape,
+                "Alt sends Escape prefix",
+            )
+            .on_hover_text(
+                "Alt+key sends ESC followed by the key on every platform, for both\n\
+                 Alt keys, overriding the Option/Alt modes above. User keybindings\n\
+                 are still matched first.",
+            )
+            .changed()
+        {
+            settings.has_changes = true;
+            *changes_this_frame = true;
+        }
+
+        ui.add_space(8.0);
+
+        // Backspace key output
+        ui.horizontal(|ui| {
+            ui.label("Backspace sends:");
+            let current = settings.config.backspace_sends;
+            egui::ComboBox::from_id_salt("input_backspace_sends")
+                .selected_text(current.display_name())
+                .show_ui(ui, |ui| {
+                    for mode in [BackspaceSends::Delete, BackspaceSends::Backspace] {
+                        if ui
+                            .selectable_value(
+                                &mut settings.config.backspace_sends,
+                                mode,
+                                mode.display_name(),
+                            )
+                            .changed()
+                        {
+                            settings.has_changes = true;
+                            *changes_this_frame = true;
+                        }
+                    }
+                });
+        })
+        .response
+        .on_hover_text(
+            "Byte sent by the Backspace key, regardless of modifiers.\n\
+             DEL (0x7f) matches most modern systems; BS (0x08) suits some\n\
+             legacy hosts and serial consoles that expect ^H.",
+        );
+
+        ui.add_space(8.0);
+        ui.separator();
+
+        // App-level key repeat
+        ui.label(egui::RichText::new("Key Repeat").strong());
+        ui.horizontal(|ui| {
+            ui.label("Custom repeat keys:");
+            let mut keys_text = settings.config.key_repeat_keys.join(", ");
+            let response = ui
+                .add(
+                    egui::TextEdit::singleline(&mut keys_text)
+                        .desired_width(INPUT_WIDTH)
+                        .hint_text("ArrowUp, ArrowDown, PageUp, PageDown"),
+                )
+                .on_hover_text(
+                    "Comma-separated keys that repeat at the rate below instead of the\n\
+                     OS key repeat (key names like ArrowLeft, Backspace, or characters).\n\
+                     Leave empty to use the OS key repeat for every key.",
+                );
+            if response.changed() {
+                settings.config.key_repeat_keys = keys_text
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect();
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+        });
+
+        ui.add_enabled_ui(!settings.config.key_repeat_keys.is_empty(), |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Repeat delay (ms):");
+                if ui
+                    .add_sized(
+                        [SLIDER_WIDTH, SLIDER_HEIGHT],
+                        egui::Slider::new(&mut settings.config.key_repeat_delay_ms, 50..=2000),
+                    )
+                    .on_hover_text("Time a key is held before it starts repeating")
+                    .changed()
+                {
+                    settings.has_changes = true;
+                    *changes_this_frame = true;
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label("Repeat interval (ms):");
+                if ui
+                    .add_sized(
+                        [SLIDER_WIDTH, SLIDER_HEIGHT],
+                        egui::Slider::new(&mut settings.config.key_repeat_interval_ms, 5..=500),
+                    )
+                    .on_hover_text("Time between repeats while the key stays held")
+                    .changed()
+                {
+                    settings.has_changes = true;
+                    *changes_this_frame = true;
+                }
+            });
+        });
+
         ui.add_space(8.0);
         ui.separator();
 
@@ -115,6 +225,42 @@ pub(super) fn show_keyboard_section(
             *changes_this_frame = true;
         }
 
+        ui.horizontal(|ui| {
+            ui.label("Chord timeout (ms):");
+            if ui
+                .add_sized(
+                    [SLIDER_WIDTH, SLIDER_HEIGHT],
+                    egui::Slider::new(&mut settings.config.keybinding_chord_timeout_ms, 200..=5000),
+                )
+                .on_hover_text(
+                    "Time allowed between the keys of a chord keybinding such as \"Ctrl+A Ctrl+S\"",
+                )
+                .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Leader key:");
+            if ui
+                .add(
+                    egui::TextEdit::singleline(&mut settings.config.keybinding_leader)
+                        .hint_text("e.g. Ctrl+B")
+                        .desired_width(120.0),
+                )
+                .on_hover_text(
+                    "Keybindings on plain keys (such as \"C\") only fire right after this key,\n\
+                     so the keys still type text on their own. Leave empty to disable.",
+                )
+                .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+        });
+
         ui.add_space(8.0);
         ui.separator();
         ui.label(egui::RichText::new("Tips:").strong());
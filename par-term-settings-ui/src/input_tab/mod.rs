@@ -54,9 +54,22 @@ pub fn show(
             "alt",
             "meta",
             "esc",
+            "alt sends escape",
+            "escape prefix",
             "physical",
             "keyboard layout",
+            "chord",
+            "chord timeout",
+            "leader",
+            "leader key",
             "terminal applications",
+            "backspace",
+            "delete",
+            "^h",
+            "key repeat",
+            "repeat delay",
+            "repeat rate",
+            "held key",
         ],
     ) {
         keyboard::show_keyboard_section(ui, settings, changes_this_frame, collapsed);
@@ -89,9 +102,14 @@ pub fn show(
         &[
             "scroll",
             "scroll speed",
+            "natural scroll",
+            "lines per wheel",
             "double-click",
             "triple-click",
+            "drag threshold",
             "focus follows",
+            "pane focus",
+            "hover delay",
             "option+click",
             "alt+click",
             "horizontal scroll",
@@ -111,9 +129,26 @@ pub fn show(
             "auto-copy",
             "delay",
             "trailing newline",
+            "line ending",
+            "crlf",
             "quote style",
             "drop files",
             "dropped file",
+            "clipboard scope",
+            "clipboard history",
+            "per pane",
+            "per tab",
+            "osc 52",
+            "chunk",
+            "bracketed paste",
+            "paste markers",
+            "nested",
+            "control sequences",
+            "paste injection",
+            "multiline paste",
+            "final newline",
+            "corner radius",
+            "rounded",
         ],
     ) {
         selection::show_selection_section(ui, settings, changes_this_frame, collapsed);
@@ -188,8 +223,22 @@ pub fn keywords() -> &'static [&'static str] {
         "alt",
         "meta",
         "esc",
+        "alt sends escape",
+        "escape prefix",
         "physical",
         "physical keys",
+        "chord",
+        "chord timeout",
+        "leader",
+        "leader key",
+        "backspace",
+        "delete",
+        "^h",
+        "key repeat",
+        "repeat delay",
+        "repeat rate",
+        "repeat interval",
+        "held key",
         // Modifier remapping
         "remap",
         "remapping",
@@ -202,13 +251,18 @@ pub fn keywords() -> &'static [&'static str] {
         "mouse",
         "scroll",
         "scroll speed",
+        "natural scroll",
+        "lines per wheel",
         "double-click",
         "triple-click",
         "click threshold",
+        "drag threshold",
         "option+click",
         "alt+click",
         "focus follows",
         "focus follows mouse",
+        "pane focus follows mouse",
+        "hover delay",
         "horizontal scroll",
         // Selection & clipboard
         "selection",
@@ -218,13 +272,28 @@ pub fn keywords() -> &'static [&'static str] {
         "auto-copy",
         "auto copy",
         "trailing newline",
+        "line ending",
+        "crlf",
         "middle-click",
         "middle click",
         "osc 52",
         "osc52",
         "ssh clipboard",
+        "chunked",
+        "clipboard scope",
+        "clipboard history",
+        "per pane",
+        "per tab",
+        "bracketed paste",
+        "paste markers",
+        "control sequences",
+        "paste injection",
+        "multiline paste",
+        "final newline",
         "dropped file",
         "quote style",
+        "corner radius",
+        "rounded selection",
         // Clipboard limits
         "max sync",
         "max bytes",
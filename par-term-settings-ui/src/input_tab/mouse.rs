@@ -24,6 +24,7 @@ pub(super) fn show_mouse_section(
                     [SLIDER_WIDTH, SLIDER_HEIGHT],
                     egui::Slider::new(&mut settings.config.mouse.mouse_scroll_speed, 0.1..=10.0),
                 )
+                .on_hover_text("Lines scrolled per mouse wheel notch")
                 .changed()
             {
                 settings.has_changes = true;
@@ -31,6 +32,20 @@ pub(super) fn show_mouse_section(
             }
         });
 
+        if ui
+            .checkbox(
+                &mut settings.config.mouse.natural_scroll_direction,
+                "Natural scroll direction",
+            )
+            .on_hover_text(
+                "Invert the wheel and trackpad scroll direction, so content follows the fingers",
+            )
+            .changed()
+        {
+            settings.has_changes = true;
+            *changes_this_frame = true;
+        }
+
         ui.horizontal(|ui| {
             ui.label("Double-click threshold (ms):");
             if ui
@@ -65,6 +80,27 @@ pub(super) fn show_mouse_section(
             }
         });
 
+        ui.horizontal(|ui| {
+            ui.label("Selection drag threshold (px):");
+            if ui
+                .add_sized(
+                    [SLIDER_WIDTH, SLIDER_HEIGHT],
+                    egui::Slider::new(
+                        &mut settings.config.mouse.selection_drag_threshold_px,
+                        0.0..=40.0,
+                    ),
+                )
+                .on_hover_text(
+                    "How far the mouse must move while pressed before text is selected.\n\
+                     Shorter drags count as a click.",
+                )
+                .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+        });
+
         ui.separator();
         ui.label("Advanced Mouse Features");
 
@@ -97,6 +133,41 @@ pub(super) fn show_mouse_section(
             *changes_this_frame = true;
         }
 
+        if ui
+            .checkbox(
+                &mut settings.config.mouse.pane_focus_follows_mouse,
+                "Pane focus follows mouse",
+            )
+            .on_hover_text("Focus a split pane by resting the mouse over it, without clicking")
+            .changed()
+        {
+            settings.has_changes = true;
+            *changes_this_frame = true;
+        }
+
+        ui.add_enabled_ui(settings.config.mouse.pane_focus_follows_mouse, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Pane hover delay (ms):");
+                if ui
+                    .add_sized(
+                        [SLIDER_WIDTH, SLIDER_HEIGHT],
+                        egui::Slider::new(
+                            &mut settings.config.mouse.pane_focus_hover_delay_ms,
+                            0..=1000,
+                        ),
+                    )
+                    .on_hover_text(
+                        "How long the mouse must rest over a pane before it takes focus.\n\
+                         Crossing a pane on the way to another one does not focus it.",
+                    )
+                    .changed()
+                {
+                    settings.has_changes = true;
+                    *changes_this_frame = true;
+                }
+            });
+        });
+
         if ui
             .checkbox(
                 &mut settings.config.mouse.report_horizontal_scroll,
@@ -2,7 +2,10 @@
 
 use crate::SettingsUI;
 use crate::section::{SLIDER_WIDTH, collapsing_section};
-use par_term_config::DroppedFileQuoteStyle;
+use par_term_config::{
+    ClipboardScope, CopyLineEnding, DroppedFileQuoteStyle, PasteBracketMarkers,
+    PasteControlSequences,
+};
 use std::collections::HashSet;
 
 const SLIDER_HEIGHT: f32 = 18.0;
@@ -46,6 +49,48 @@ pub(super) fn show_selection_section(
                 *changes_this_frame = true;
             }
 
+            ui.horizontal(|ui| {
+                ui.label("Copied line ending:");
+                egui::ComboBox::from_id_salt("input_copy_line_ending")
+                    .selected_text(settings.config.copy_line_ending.display_name())
+                    .show_ui(ui, |ui| {
+                        for ending in CopyLineEnding::all() {
+                            if ui
+                                .selectable_value(
+                                    &mut settings.config.copy_line_ending,
+                                    *ending,
+                                    ending.display_name(),
+                                )
+                                .changed()
+                            {
+                                settings.has_changes = true;
+                                *changes_this_frame = true;
+                            }
+                        }
+                    });
+            })
+            .response
+            .on_hover_text("Line separator written to the clipboard between copied lines.");
+
+            ui.horizontal(|ui| {
+                ui.label("Selection corner radius:");
+                if ui
+                    .add_sized(
+                        [SLIDER_WIDTH, SLIDER_HEIGHT],
+                        egui::Slider::new(&mut settings.config.selection_corner_radius, 0.0..=8.0)
+                            .suffix(" px"),
+                    )
+                    .on_hover_text(
+                        "Round the outer corners of selections and of a block cursor \
+                         (0 = square corners).",
+                    )
+                    .changed()
+                {
+                    settings.has_changes = true;
+                    *changes_this_frame = true;
+                }
+            });
+
             if ui
                 .checkbox(
                     &mut settings.config.middle_click_paste,
@@ -74,6 +119,21 @@ pub(super) fn show_selection_section(
                 *changes_this_frame = true;
             }
 
+            if ui
+                .checkbox(
+                    &mut settings.config.osc52_reassemble_chunks,
+                    "Reassemble chunked OSC 52 writes",
+                )
+                .on_hover_text(
+                    "Join OSC 52 clipboard writes that arrive in several chunks before \
+                     decoding them. The joined payload is limited by the OSC data length cap.",
+                )
+                .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+
             ui.horizontal(|ui| {
                 ui.label("Paste delay (ms):");
                 if ui
@@ -92,6 +152,100 @@ pub(super) fn show_selection_section(
                 }
             });
 
+            ui.horizontal(|ui| {
+                ui.label("Clipboard history scope:");
+                egui::ComboBox::from_id_salt("input_clipboard_scope")
+                    .selected_text(settings.config.clipboard_scope.display_name())
+                    .show_ui(ui, |ui| {
+                        for scope in ClipboardScope::all() {
+                            if ui
+                                .selectable_value(
+                                    &mut settings.config.clipboard_scope,
+                                    *scope,
+                                    scope.display_name(),
+                                )
+                                .changed()
+                            {
+                                settings.has_changes = true;
+                                *changes_this_frame = true;
+                            }
+                        }
+                    });
+            })
+            .response
+            .on_hover_text(
+                "Which panes share clipboard history slots. The system clipboard is \
+                 always shared.",
+            );
+
+            ui.horizontal(|ui| {
+                ui.label("Embedded paste markers:");
+                egui::ComboBox::from_id_salt("input_paste_bracket_markers")
+                    .selected_text(settings.config.paste_bracket_markers.display_name())
+                    .show_ui(ui, |ui| {
+                        for mode in PasteBracketMarkers::all() {
+                            if ui
+                                .selectable_value(
+                                    &mut settings.config.paste_bracket_markers,
+                                    *mode,
+                                    mode.display_name(),
+                                )
+                                .changed()
+                            {
+                                settings.has_changes = true;
+                                *changes_this_frame = true;
+                            }
+                        }
+                    });
+            })
+            .response
+            .on_hover_text(
+                "Bracketed paste markers (ESC[200~ / ESC[201~) inside copied text, e.g. \
+                 from an editor in a nested session. Strip avoids stray [200~ text.",
+            );
+
+            ui.horizontal(|ui| {
+                ui.label("Control sequences in pastes:");
+                egui::ComboBox::from_id_salt("input_paste_control_sequences")
+                    .selected_text(settings.config.paste_control_sequences.display_name())
+                    .show_ui(ui, |ui| {
+                        for mode in PasteControlSequences::all() {
+                            if ui
+                                .selectable_value(
+                                    &mut settings.config.paste_control_sequences,
+                                    *mode,
+                                    mode.display_name(),
+                                )
+                                .changed()
+                            {
+                                settings.has_changes = true;
+                                *changes_this_frame = true;
+                            }
+                        }
+                    });
+            })
+            .response
+            .on_hover_text(
+                "Escape sequences (CSI/OSC/DCS) inside copied text, such as a cursor \
+                 position report. Strip removes them whole so programs never see a reply \
+                 to a query they did not send; Allow pastes them unchanged.",
+            );
+
+            if ui
+                .checkbox(
+                    &mut settings.config.multiline_paste_suppress_final_newline,
+                    "Hold multi-line pastes for Enter",
+                )
+                .on_hover_text(
+                    "Paste multi-line text without its final newline, so the last line \
+                     waits for Enter instead of running. Single-line pastes are unchanged.",
+                )
+                .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+
             ui.separator();
             ui.label("Dropped Files");
 
@@ -1,4 +1,4 @@
-//! Activity, silence, and session notification settings.
+//! Activity, silence, session, and command completion notification settings.
 
 use crate::SettingsUI;
 use crate::section::{SLIDER_WIDTH, collapsing_section};
@@ -94,6 +94,63 @@ pub(super) fn show_activity_section(
                 settings.has_changes = true;
                 *changes_this_frame = true;
             }
+
+            ui.separator();
+            ui.label("Command Notifications:");
+            if ui
+                .checkbox(
+                    &mut settings.config.notifications.notification_command_finished,
+                    "Notify when a long-running command finishes",
+                )
+                .on_hover_text(
+                    "Send a desktop notification with the exit status and run time when a \
+                     command finishes while the window is unfocused. Requires shell integration.",
+                )
+                .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+
+            ui.add_enabled_ui(
+                settings.config.notifications.notification_command_finished,
+                |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label("Minimum run time (seconds):");
+                        if ui
+                            .add_sized(
+                                [SLIDER_WIDTH, SLIDER_HEIGHT],
+                                egui::Slider::new(
+                                    &mut settings
+                                        .config
+                                        .notifications
+                                        .notification_command_finished_threshold,
+                                    1..=600,
+                                ),
+                            )
+                            .changed()
+                        {
+                            settings.has_changes = true;
+                            *changes_this_frame = true;
+                        }
+                    });
+
+                    if ui
+                        .checkbox(
+                            &mut settings
+                                .config
+                                .notifications
+                                .notification_command_failures_only,
+                            "Only for failed commands",
+                        )
+                        .on_hover_text("Skip commands that exit with status 0")
+                        .changed()
+                    {
+                        settings.has_changes = true;
+                        *changes_this_frame = true;
+                    }
+                },
+            );
         },
     );
 }
@@ -1,4 +1,5 @@
-//! Notification behavior settings — suppression, buffer, and test notification.
+//! Notification behavior settings — suppression, application notifications,
+//! buffer, and test notification.
 
 use crate::SettingsUI;
 use crate::section::{SLIDER_WIDTH, collapsing_section};
@@ -34,6 +35,42 @@ pub(super) fn show_behavior_section(
                 *changes_this_frame = true;
             }
 
+            if ui
+                .checkbox(
+                    &mut settings.config.notifications.notification_osc_enabled,
+                    "Show notifications from applications",
+                )
+                .on_hover_text(
+                    "Raise desktop notifications that programs request with OSC 9, \
+                     OSC 777 or OSC 99. Profiles can override this.",
+                )
+                .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+
+            ui.horizontal(|ui| {
+                ui.label("Max per minute:");
+                if ui
+                    .add_sized(
+                        [SLIDER_WIDTH, SLIDER_HEIGHT],
+                        egui::Slider::new(
+                            &mut settings.config.notifications.notification_osc_rate_limit,
+                            0..=60,
+                        ),
+                    )
+                    .on_hover_text(
+                        "Most application notifications shown per terminal each minute; \
+                         the rest are dropped. 0 = no limit",
+                    )
+                    .changed()
+                {
+                    settings.has_changes = true;
+                    *changes_this_frame = true;
+                }
+            });
+
             ui.horizontal(|ui| {
                 ui.label("Max notification buffer:");
                 if ui
@@ -4,9 +4,10 @@
 //!
 //! Contains:
 //! - [`bell`]: Visual bell, audio bell volume, and desktop notifications
-//! - [`activity`]: Activity, silence, and session notification settings
+//! - [`activity`]: Activity, silence, session, and command notification settings
 //! - [`alert_sounds`]: Per-event sound configuration
-//! - [`behavior`]: Suppress-when-focused, buffer size, and test notification
+//! - [`behavior`]: Suppress-when-focused, application notifications, buffer size,
+//!   and test notification
 //! - [`anti_idle`]: Anti-idle keep-alive settings
 
 mod activity;
@@ -53,6 +54,10 @@ pub fn show(
             "session",
             "session ended",
             "shell exits",
+            "command finished",
+            "long-running",
+            "exit status",
+            "failed",
         ],
     ) {
         activity::show_activity_section(ui, settings, changes_this_frame, collapsed);
@@ -89,6 +94,10 @@ pub fn show(
             "suppress when focused",
             "max notifications",
             "notification limit",
+            "osc 9",
+            "osc 777",
+            "application notifications",
+            "rate limit",
         ],
     ) {
         behavior::show_behavior_section(ui, settings, changes_this_frame, collapsed);
@@ -137,6 +146,12 @@ pub fn keywords() -> &'static [&'static str] {
         // Session
         "session ended",
         "shell exits",
+        // Command completion
+        "command finished",
+        "command completion",
+        "long-running command",
+        "exit status",
+        "failed commands",
         // Behavior
         "suppress",
         "focused",
@@ -145,6 +160,10 @@ pub fn keywords() -> &'static [&'static str] {
         "max buffer",
         "max notifications",
         "notification limit",
+        "osc 9",
+        "osc 777",
+        "application notifications",
+        "rate limit",
         "test notification",
         // Anti-idle
         "anti-idle",
@@ -365,6 +365,140 @@ impl ProfileModalUI {
         );
     }
 
+    /// Render the color mode collapsing section.
+    pub(super) fn render_color_mode_section(
+        &mut self,
+        ui: &mut egui::Ui,
+        collapsed: &mut HashSet<String>,
+    ) {
+        collapsing_section(ui, "Colors", "profile_color_mode", false, collapsed, |ui| {
+            ui.label(
+                egui::RichText::new("Applies to new sessions opened from this profile.")
+                    .small()
+                    .color(egui::Color32::GRAY),
+            );
+            ui.add_space(6.0);
+
+            egui::Grid::new("profile_color_mode_form")
+                .num_columns(2)
+                .spacing([10.0, 8.0])
+                .show(ui, |ui| {
+                    ui.label("Advertised Colors:");
+                    color_mode_combo(
+                        ui,
+                        "profile_color_mode_combo",
+                        &mut self.temp_color_mode,
+                        "Default (24-bit)",
+                    )
+                    .on_hover_text(
+                        "Sets $COLORTERM for the session. Anything other than 24-bit \
+                             leaves it empty so applications fall back to palette colors.",
+                    );
+                    ui.end_row();
+
+                    ui.label("Alternate Screen:");
+                    color_mode_combo(
+                        ui,
+                        "profile_alt_screen_color_mode_combo",
+                        &mut self.temp_alt_screen_color_mode,
+                        "Unchanged",
+                    )
+                    .on_hover_text(
+                        "Reduce colors while a full-screen app (vim, htop, less) \
+                             uses the alternate screen. The normal screen is unaffected.",
+                    );
+                    ui.end_row();
+                });
+        });
+    }
+
+    /// Render the cursor appearance collapsing section.
+    pub(super) fn render_cursor_section(
+        &mut self,
+        ui: &mut egui::Ui,
+        collapsed: &mut HashSet<String>,
+    ) {
+        collapsing_section(ui, "Cursor", "profile_cursor", false, collapsed, |ui| {
+            ui.label(
+                egui::RichText::new(
+                    "Overrides the global cursor in tabs opened from this profile.",
+                )
+                .small()
+                .color(egui::Color32::GRAY),
+            );
+            ui.add_space(6.0);
+
+            egui::Grid::new("profile_cursor_form")
+                .num_columns(2)
+                .spacing([10.0, 8.0])
+                .show(ui, |ui| {
+                    ui.label("Color:");
+                    ui.horizontal(|ui| {
+                        let mut use_custom = self.temp_cursor_color.is_some();
+                        if ui.checkbox(&mut use_custom, "").changed() {
+                            self.temp_cursor_color = use_custom.then_some([255, 255, 255]);
+                        }
+                        if let Some(ref mut color) = self.temp_cursor_color {
+                            let mut egui_color =
+                                egui::Color32::from_rgb(color[0], color[1], color[2]);
+                            if egui::color_picker::color_edit_button_srgba(
+                                ui,
+                                &mut egui_color,
+                                egui::color_picker::Alpha::Opaque,
+                            )
+                            .changed()
+                            {
+                                *color = [egui_color.r(), egui_color.g(), egui_color.b()];
+                            }
+                        } else {
+                            ui.label(egui::RichText::new("(use global)").small().weak());
+                        }
+                    });
+                    ui.end_row();
+
+                    ui.label("Style:");
+                    let selected = match self.temp_cursor_style {
+                        None => "Global",
+                        Some(par_term_config::CursorStyle::Block) => "Block",
+                        Some(par_term_config::CursorStyle::Beam) => "Beam",
+                        Some(par_term_config::CursorStyle::Underline) => "Underline",
+                    };
+                    egui::ComboBox::from_id_salt("profile_cursor_style_combo")
+                        .selected_text(selected)
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut self.temp_cursor_style, None, "Global");
+                            for (style, label) in [
+                                (par_term_config::CursorStyle::Block, "Block"),
+                                (par_term_config::CursorStyle::Beam, "Beam"),
+                                (par_term_config::CursorStyle::Underline, "Underline"),
+                            ] {
+                                ui.selectable_value(
+                                    &mut self.temp_cursor_style,
+                                    Some(style),
+                                    label,
+                                );
+                            }
+                        });
+                    ui.end_row();
+
+                    ui.label("Blink:");
+                    let selected = match self.temp_cursor_blink {
+                        None => "Global",
+                        Some(true) => "On",
+                        Some(false) => "Off",
+                    };
+                    egui::ComboBox::from_id_salt("profile_cursor_blink_combo")
+                        .selected_text(selected)
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut self.temp_cursor_blink, None, "Global");
+                            ui.selectable_value(&mut self.temp_cursor_blink, Some(true), "On");
+                            ui.selectable_value(&mut self.temp_cursor_blink, Some(false), "Off");
+                        });
+                    ui.end_row();
+                });
+        });
+    }
+
     /// Render the SSH connection collapsing section.
     pub(super) fn render_ssh_section(
         &mut self,
@@ -411,6 +545,26 @@ impl ProfileModalUI {
     }
 }
 
+/// Combo box for an optional [`par_term_config::ColorMode`]; `none_label`
+/// names the unset state.
+fn color_mode_combo(
+    ui: &mut egui::Ui,
+    id: &str,
+    value: &mut Option<par_term_config::ColorMode>,
+    none_label: &str,
+) -> egui::Response {
+    let selected = value.map_or(none_label, |mode| mode.display_name());
+    egui::ComboBox::from_id_salt(id)
+        .selected_text(selected)
+        .show_ui(ui, |ui| {
+            ui.selectable_value(value, None, none_label);
+            for mode in par_term_config::ColorMode::all() {
+                ui.selectable_value(value, Some(*mode), mode.display_name());
+            }
+        })
+        .response
+}
+
 fn optional_slider_row(
     ui: &mut egui::Ui,
     label: &str,
@@ -208,6 +208,45 @@ impl ProfileModalUI {
                         });
                         ui.end_row();
 
+                        // OSC notification toggle
+                        ui.label("App Notifications:");
+                        ui.horizontal(|ui| {
+                            let mut use_custom = self.temp_osc_notifications.is_some();
+                            if ui.checkbox(&mut use_custom, "").changed() {
+                                if use_custom {
+                                    self.temp_osc_notifications = Some(true);
+                                } else {
+                                    self.temp_osc_notifications = None;
+                                }
+                            }
+                            if let Some(ref mut enabled) = self.temp_osc_notifications {
+                                ui.checkbox(enabled, "Show OSC 9/777 notifications")
+                                    .on_hover_text(
+                                        "Desktop notifications requested by programs in this \
+                                         profile's tabs (OSC 9, OSC 777 and OSC 99)",
+                                    );
+                            } else {
+                                ui.label(
+                                    egui::RichText::new("(inherit global)")
+                                        .small()
+                                        .color(egui::Color32::GRAY),
+                                );
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label("Input Encoding:");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.temp_input_encoding)
+                                .hint_text("UTF-8")
+                                .desired_width(120.0),
+                        )
+                        .on_hover_text(
+                            "Encoding of output from a legacy host, e.g. latin1, \
+                             iso-8859-15 or shift_jis. Leave empty for UTF-8.",
+                        );
+                        ui.end_row();
+
                         ui.label("Command:");
                         ui.horizontal(|ui| {
                             ui.text_edit_singleline(&mut self.temp_command);
@@ -230,6 +269,53 @@ impl ProfileModalUI {
                         });
                         ui.end_row();
 
+                        ui.label("Startup Commands:");
+                        ui.vertical(|ui| {
+                            ui.add(
+                                egui::TextEdit::multiline(&mut self.temp_startup_commands)
+                                    .desired_rows(2)
+                                    .hint_text("tmux attach"),
+                            )
+                            .on_hover_text(
+                                "Typed into the session after it starts, one per line,\n\
+                                 each followed by Enter. Supports \\n, \\t, \\e and \\xHH escapes.",
+                            );
+                            ui.label(
+                                egui::RichText::new("(one per line; the shell is unchanged)")
+                                    .small()
+                                    .color(egui::Color32::GRAY),
+                            );
+                        });
+                        ui.end_row();
+
+                        ui.label("Startup Delay:");
+                        ui.horizontal(|ui| {
+                            let mut use_custom = self.temp_startup_command_delay_ms.is_some();
+                            if ui.checkbox(&mut use_custom, "").changed() {
+                                self.temp_startup_command_delay_ms = use_custom.then_some(
+                                    par_term_config::DEFAULT_STARTUP_COMMAND_DELAY_MS,
+                                );
+                            }
+                            if let Some(ref mut delay) = self.temp_startup_command_delay_ms {
+                                ui.add(
+                                    egui::DragValue::new(delay)
+                                        .range(0..=10_000)
+                                        .suffix(" ms"),
+                                )
+                                .on_hover_text("Wait before each startup command");
+                            } else {
+                                ui.label(
+                                    egui::RichText::new(format!(
+                                        "(default {} ms before each command)",
+                                        par_term_config::DEFAULT_STARTUP_COMMAND_DELAY_MS
+                                    ))
+                                    .small()
+                                    .color(egui::Color32::GRAY),
+                                );
+                            }
+                        });
+                        ui.end_row();
+
                         ui.label("Tab Name:");
                         ui.horizontal(|ui| {
                             ui.text_edit_singleline(&mut self.temp_tab_name);
@@ -240,6 +326,21 @@ impl ProfileModalUI {
                             );
                         });
                         ui.end_row();
+
+                        ui.label("Tab Title Template:");
+                        ui.horizontal(|ui| {
+                            ui.text_edit_singleline(&mut self.temp_tab_title_template)
+                                .on_hover_text(
+                                    "Placeholders: {cwd}, {command}, {exit}, {index}.\n\
+                                     Overrides the global tab title template.",
+                                );
+                            ui.label(
+                                egui::RichText::new("(optional)")
+                                    .small()
+                                    .color(egui::Color32::GRAY),
+                            );
+                        });
+                        ui.end_row();
                     });
 
                 // === Enhanced Features Section (issue #78) ===
@@ -351,6 +452,14 @@ impl ProfileModalUI {
                 ui.add_space(8.0);
                 self.render_tmux_section(ui, collapsed);
 
+                // Color mode section
+                ui.add_space(8.0);
+                self.render_color_mode_section(ui, collapsed);
+
+                // Cursor section
+                ui.add_space(8.0);
+                self.render_cursor_section(ui, collapsed);
+
                 // SSH Connection section
                 ui.add_space(8.0);
                 self.render_ssh_section(ui, collapsed);
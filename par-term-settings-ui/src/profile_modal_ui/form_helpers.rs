@@ -18,9 +18,13 @@ impl ProfileModalUI {
         self.temp_working_dir.clear();
         self.temp_shell = None;
         self.temp_login_shell = None;
+        self.temp_osc_notifications = None;
         self.temp_command.clear();
         self.temp_args.clear();
+        self.temp_startup_commands.clear();
+        self.temp_startup_command_delay_ms = None;
         self.temp_tab_name.clear();
+        self.temp_tab_title_template.clear();
         self.temp_icon.clear();
         self.temp_tags.clear();
         self.temp_parent_id = None;
@@ -49,6 +53,12 @@ impl ProfileModalUI {
         self.temp_ssh_extra_args.clear();
         self.temp_tmux_session_name.clear();
         self.temp_tmux_connection_mode = par_term_config::TmuxConnectionMode::default();
+        self.temp_color_mode = None;
+        self.temp_alt_screen_color_mode = None;
+        self.temp_input_encoding.clear();
+        self.temp_cursor_color = None;
+        self.temp_cursor_style = None;
+        self.temp_cursor_blink = None;
         self.validation_error = None;
     }
 
@@ -58,13 +68,17 @@ impl ProfileModalUI {
         self.temp_working_dir = profile.working_directory.clone().unwrap_or_default();
         self.temp_shell = profile.shell.clone();
         self.temp_login_shell = profile.login_shell;
+        self.temp_osc_notifications = profile.osc_notifications;
         self.temp_command = profile.command.clone().unwrap_or_default();
         self.temp_args = profile
             .command_args
             .as_ref()
             .map(|args| args.join(" "))
             .unwrap_or_default();
+        self.temp_startup_commands = profile.startup_commands.join("\n");
+        self.temp_startup_command_delay_ms = profile.startup_command_delay_ms;
         self.temp_tab_name = profile.tab_name.clone().unwrap_or_default();
+        self.temp_tab_title_template = profile.tab_title_template.clone().unwrap_or_default();
         self.temp_icon = profile.icon.clone().unwrap_or_default();
         // New fields
         self.temp_tags = profile.tags.join(", ");
@@ -101,6 +115,14 @@ impl ProfileModalUI {
         // Tmux auto-connect fields
         self.temp_tmux_session_name = profile.tmux_session_name.clone().unwrap_or_default();
         self.temp_tmux_connection_mode = profile.tmux_connection_mode;
+        // Color mode fields
+        self.temp_color_mode = profile.color_mode;
+        self.temp_alt_screen_color_mode = profile.alt_screen_color_mode;
+        self.temp_input_encoding = profile.input_encoding.clone().unwrap_or_default();
+        // Cursor fields
+        self.temp_cursor_color = profile.cursor_color;
+        self.temp_cursor_style = profile.cursor_style;
+        self.temp_cursor_blink = profile.cursor_blink;
     }
 
     /// Create a profile from form fields
@@ -113,6 +135,7 @@ impl ProfileModalUI {
         }
         profile.shell = self.temp_shell.clone();
         profile.login_shell = self.temp_login_shell;
+        profile.osc_notifications = self.temp_osc_notifications;
         if !self.temp_command.is_empty() {
             profile.command = Some(self.temp_command.clone());
         }
@@ -125,9 +148,20 @@ impl ProfileModalUI {
                     .collect(),
             );
         }
+        // One startup command per line
+        profile.startup_commands = self
+            .temp_startup_commands
+            .lines()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+        profile.startup_command_delay_ms = self.temp_startup_command_delay_ms;
         if !self.temp_tab_name.is_empty() {
             profile.tab_name = Some(self.temp_tab_name.clone());
         }
+        if !self.temp_tab_title_template.is_empty() {
+            profile.tab_title_template = Some(self.temp_tab_title_template.clone());
+        }
         if !self.temp_icon.is_empty() {
             profile.icon = Some(self.temp_icon.clone());
         }
@@ -219,6 +253,17 @@ impl ProfileModalUI {
             profile.tmux_session_name = Some(self.temp_tmux_session_name.clone());
         }
         profile.tmux_connection_mode = self.temp_tmux_connection_mode;
+        // Color mode fields
+        profile.color_mode = self.temp_color_mode;
+        profile.alt_screen_color_mode = self.temp_alt_screen_color_mode;
+        let encoding = self.temp_input_encoding.trim();
+        if !encoding.is_empty() {
+            profile.input_encoding = Some(encoding.to_string());
+        }
+        // Cursor fields
+        profile.cursor_color = self.temp_cursor_color;
+        profile.cursor_style = self.temp_cursor_style;
+        profile.cursor_blink = self.temp_cursor_blink;
 
         profile
     }
@@ -269,7 +314,9 @@ impl ProfileModalUI {
                         self.working_profiles.iter().position(|p| p.id == *edit_id)
                     {
                         let order = self.working_profiles[existing].order;
-                        let profile = self.form_to_profile(id, order);
+                        let mut profile = self.form_to_profile(id, order);
+                        // Window geometry is recorded by the app, not edited in the form.
+                        profile.window_geometry = self.working_profiles[existing].window_geometry;
                         self.working_profiles[existing] = profile;
                         log::info!("Updated profile: {}", self.temp_name);
                     }
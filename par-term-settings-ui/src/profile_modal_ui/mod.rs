@@ -60,9 +60,13 @@ pub struct ProfileModalUI {
     pub(super) temp_working_dir: String,
     pub(super) temp_shell: Option<String>,
     pub(super) temp_login_shell: Option<bool>,
+    pub(super) temp_osc_notifications: Option<bool>,
     pub(super) temp_command: String,
     pub(super) temp_args: String,
+    pub(super) temp_startup_commands: String,
+    pub(super) temp_startup_command_delay_ms: Option<u64>,
     pub(super) temp_tab_name: String,
+    pub(super) temp_tab_title_template: String,
     pub(super) temp_icon: String,
     // New fields for enhanced profile system (issue #78)
     pub(super) temp_tags: String,
@@ -96,6 +100,14 @@ pub struct ProfileModalUI {
     // tmux auto-connect fields
     pub(super) temp_tmux_session_name: String,
     pub(super) temp_tmux_connection_mode: par_term_config::TmuxConnectionMode,
+    // Color mode fields
+    pub(super) temp_color_mode: Option<par_term_config::ColorMode>,
+    pub(super) temp_alt_screen_color_mode: Option<par_term_config::ColorMode>,
+    pub(super) temp_input_encoding: String,
+    // Cursor fields
+    pub(super) temp_cursor_color: Option<[u8; 3]>,
+    pub(super) temp_cursor_style: Option<par_term_config::CursorStyle>,
+    pub(super) temp_cursor_blink: Option<bool>,
 
     /// Selected profile in list view
     pub(super) selected_id: Option<ProfileId>,
@@ -123,9 +135,13 @@ impl ProfileModalUI {
             temp_working_dir: String::new(),
             temp_shell: None,
             temp_login_shell: None,
+            temp_osc_notifications: None,
             temp_command: String::new(),
             temp_args: String::new(),
+            temp_startup_commands: String::new(),
+            temp_startup_command_delay_ms: None,
             temp_tab_name: String::new(),
+            temp_tab_title_template: String::new(),
             temp_icon: String::new(),
             temp_tags: String::new(),
             temp_parent_id: None,
@@ -154,6 +170,12 @@ impl ProfileModalUI {
             temp_ssh_extra_args: String::new(),
             temp_tmux_session_name: String::new(),
             temp_tmux_connection_mode: par_term_config::TmuxConnectionMode::default(),
+            temp_color_mode: None,
+            temp_alt_screen_color_mode: None,
+            temp_input_encoding: String::new(),
+            temp_cursor_color: None,
+            temp_cursor_style: None,
+            temp_cursor_blink: None,
             selected_id: None,
             has_changes: false,
             validation_error: None,
@@ -2,15 +2,101 @@
 //!
 //! This component provides fast access to the most frequently changed settings
 //! without navigating through the full settings UI.
+//!
+//! The profile-relevant controls (background/cursor shader, opacity, font
+//! size) go through [`apply_quick_toggle`], which writes a single config field
+//! and reports it as a [`QuickSettingsChange`].  Nothing else in the config is
+//! touched, so the live-config diff on the app side only sees the field the
+//! user actually moved.  When the focused tab runs under a profile, the strip
+//! shows that profile's name and shader override.
 
 use super::SettingsUI;
-use par_term_config::Theme;
 use par_term_config::{BackgroundMode, CursorStyle, TabBarMode};
+use par_term_config::{Config, Theme};
+
+/// A single edit made through a profile-aware quick settings control.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QuickToggle {
+    /// Enable/disable the background shader
+    BackgroundShader(bool),
+    /// Enable/disable the cursor shader
+    CursorShader(bool),
+    /// Set the window opacity (clamped to 0.1-1.0)
+    Opacity(f32),
+    /// Set the font size in points (clamped to 6.0-48.0)
+    FontSize(f32),
+}
+
+/// Config field written by a [`QuickToggle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuickSettingsChange {
+    /// `shader.custom_shader_enabled`
+    BackgroundShaderEnabled,
+    /// `shader.cursor_shader_enabled`
+    CursorShaderEnabled,
+    /// `window.window_opacity`
+    WindowOpacity,
+    /// `font_size`
+    FontSize,
+}
+
+/// Apply a quick toggle to `config`.
+///
+/// Returns the field that changed, or `None` when the control already held
+/// the requested value (so no config diff should be produced).
+pub fn apply_quick_toggle(config: &mut Config, toggle: QuickToggle) -> Option<QuickSettingsChange> {
+    match toggle {
+        QuickToggle::BackgroundShader(enabled) => (config.shader.custom_shader_enabled != enabled)
+            .then(|| {
+                config.shader.custom_shader_enabled = enabled;
+                QuickSettingsChange::BackgroundShaderEnabled
+            }),
+        QuickToggle::CursorShader(enabled) => (config.shader.cursor_shader_enabled != enabled)
+            .then(|| {
+                config.shader.cursor_shader_enabled = enabled;
+                QuickSettingsChange::CursorShaderEnabled
+            }),
+        QuickToggle::Opacity(opacity) => {
+            let opacity = opacity.clamp(0.1, 1.0);
+            ((config.window.window_opacity - opacity).abs() > f32::EPSILON).then(|| {
+                config.window.window_opacity = opacity;
+                QuickSettingsChange::WindowOpacity
+            })
+        }
+        QuickToggle::FontSize(size) => {
+            let size = size.clamp(6.0, 48.0);
+            ((config.font_size - size).abs() > f32::EPSILON).then(|| {
+                config.font_size = size;
+                QuickSettingsChange::FontSize
+            })
+        }
+    }
+}
+
+/// Apply a quick toggle from the strip and flag the settings as changed.
+fn commit_toggle(settings: &mut SettingsUI, toggle: QuickToggle, changes_this_frame: &mut bool) {
+    if let Some(change) = apply_quick_toggle(&mut settings.config, toggle) {
+        if change == QuickSettingsChange::FontSize {
+            settings.temp_font_size = settings.config.font_size;
+        }
+        settings.has_changes = true;
+        *changes_this_frame = true;
+    }
+}
 
 /// Render the quick settings strip at the top of the settings UI.
 ///
 /// Returns true if any setting was changed this frame.
 pub fn show(ui: &mut egui::Ui, settings: &mut SettingsUI, changes_this_frame: &mut bool) {
+    if let Some(profile) = &settings.quick_settings_profile {
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(format!("Profile: {}", profile.name)).strong());
+            if let Some(shader) = &profile.shader {
+                ui.label(egui::RichText::new(format!("(shader: {shader})")).weak());
+            }
+        });
+    }
+
     ui.horizontal_wrapped(|ui| {
         ui.spacing_mut().item_spacing.x = 16.0;
 
@@ -29,14 +115,15 @@ pub fn show(ui: &mut egui::Ui, settings: &mut SettingsUI, changes_this_frame: &m
 
         ui.separator();
 
-        // Font Size slider
+        // Font Size slider (applied live)
         ui.horizontal(|ui| {
             ui.label("Size:");
+            let mut size = settings.config.font_size;
             if ui
-                .add(egui::Slider::new(&mut settings.temp_font_size, 6.0..=48.0).show_value(true))
+                .add(egui::Slider::new(&mut size, 6.0..=48.0).show_value(true))
                 .changed()
             {
-                settings.font_pending_changes = true;
+                commit_toggle(settings, QuickToggle::FontSize(size), changes_this_frame);
             }
         });
 
@@ -69,15 +156,9 @@ pub fn show(ui: &mut egui::Ui, settings: &mut SettingsUI, changes_this_frame: &m
         // Window Opacity slider
         ui.horizontal(|ui| {
             ui.label("Opacity:");
-            if ui
-                .add(egui::Slider::new(
-                    &mut settings.config.window.window_opacity,
-                    0.1..=1.0,
-                ))
-                .changed()
-            {
-                settings.has_changes = true;
-                *changes_this_frame = true;
+            let mut opacity = settings.config.window.window_opacity;
+            if ui.add(egui::Slider::new(&mut opacity, 0.1..=1.0)).changed() {
+                commit_toggle(settings, QuickToggle::Opacity(opacity), changes_this_frame);
             }
         });
 
@@ -200,32 +281,42 @@ pub fn show(ui: &mut egui::Ui, settings: &mut SettingsUI, changes_this_frame: &m
 
         ui.separator();
 
-        // Background shader toggle
+        // Background shader toggle (reflects the active profile's shader override)
+        let profile_shader = settings
+            .quick_settings_profile
+            .as_ref()
+            .and_then(|p| p.shader.clone());
+        let mut bg_shader = settings.config.shader.custom_shader_enabled;
+        let hover = match &profile_shader {
+            Some(shader) => format!("Enable background shader effect (profile uses {shader})"),
+            None => "Enable background shader effect".to_string(),
+        };
         if ui
-            .checkbox(
-                &mut settings.config.shader.custom_shader_enabled,
-                "BG Shader",
-            )
-            .on_hover_text("Enable background shader effect")
+            .checkbox(&mut bg_shader, "BG Shader")
+            .on_hover_text(hover)
             .changed()
         {
-            settings.has_changes = true;
-            *changes_this_frame = true;
+            commit_toggle(
+                settings,
+                QuickToggle::BackgroundShader(bg_shader),
+                changes_this_frame,
+            );
         }
 
         ui.separator();
 
         // Cursor shader toggle
+        let mut cursor_shader = settings.config.shader.cursor_shader_enabled;
         if ui
-            .checkbox(
-                &mut settings.config.shader.cursor_shader_enabled,
-                "Cursor Shader",
-            )
+            .checkbox(&mut cursor_shader, "Cursor Shader")
             .on_hover_text("Enable cursor shader effect")
             .changed()
         {
-            settings.has_changes = true;
-            *changes_this_frame = true;
+            commit_toggle(
+                settings,
+                QuickToggle::CursorShader(cursor_shader),
+                changes_this_frame,
+            );
         }
 
         // Apply Font Changes button (only show if pending)
@@ -240,3 +331,64 @@ pub fn show(ui: &mut egui::Ui, settings: &mut SettingsUI, changes_this_frame: &m
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shader_toggle_changes_only_its_field() {
+        let before = Config::default();
+        let mut config = before.clone();
+        let enabled = !before.shader.custom_shader_enabled;
+
+        let change = apply_quick_toggle(&mut config, QuickToggle::BackgroundShader(enabled));
+
+        assert_eq!(change, Some(QuickSettingsChange::BackgroundShaderEnabled));
+        assert_eq!(config.shader.custom_shader_enabled, enabled);
+        assert_eq!(
+            config.shader.cursor_shader_enabled,
+            before.shader.cursor_shader_enabled
+        );
+        assert_eq!(config.window.window_opacity, before.window.window_opacity);
+        assert_eq!(config.font_size, before.font_size);
+    }
+
+    #[test]
+    fn unchanged_value_produces_no_change() {
+        let mut config = Config::default();
+        let opacity = config.window.window_opacity;
+        let size = config.font_size;
+        let cursor = config.shader.cursor_shader_enabled;
+
+        assert_eq!(
+            apply_quick_toggle(&mut config, QuickToggle::Opacity(opacity)),
+            None
+        );
+        assert_eq!(
+            apply_quick_toggle(&mut config, QuickToggle::FontSize(size)),
+            None
+        );
+        assert_eq!(
+            apply_quick_toggle(&mut config, QuickToggle::CursorShader(cursor)),
+            None
+        );
+    }
+
+    #[test]
+    fn opacity_and_font_size_are_clamped() {
+        let mut config = Config::default();
+
+        assert_eq!(
+            apply_quick_toggle(&mut config, QuickToggle::Opacity(0.0)),
+            Some(QuickSettingsChange::WindowOpacity)
+        );
+        assert_eq!(config.window.window_opacity, 0.1);
+
+        assert_eq!(
+            apply_quick_toggle(&mut config, QuickToggle::FontSize(100.0)),
+            Some(QuickSettingsChange::FontSize)
+        );
+        assert_eq!(config.font_size, 48.0);
+    }
+}
@@ -155,6 +155,15 @@ fn show_permissions_section(ui: &mut egui::Ui, settings: &mut SettingsUI) {
             "Allow this script to modify runtime configuration values. \
              Only allowlisted keys (font_size, window_opacity, etc.) may be changed.",
         );
+
+        ui.checkbox(
+            &mut settings.temp_script_allow_read_selection,
+            "Allow GetSelection",
+        )
+        .on_hover_text(
+            "Allow this script to read the currently selected text. \
+             The selection may contain passwords or other secrets.",
+        );
     });
 }
 
@@ -203,6 +212,7 @@ fn show_save_cancel(
                 allow_write_text: settings.temp_script_allow_write_text,
                 allow_run_command: settings.temp_script_allow_run_command,
                 allow_change_config: settings.temp_script_allow_change_config,
+                allow_read_selection: settings.temp_script_allow_read_selection,
                 write_text_rate_limit: settings.temp_script_write_text_rate_limit,
                 run_command_rate_limit: settings.temp_script_run_command_rate_limit,
             };
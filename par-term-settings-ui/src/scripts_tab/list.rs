@@ -80,6 +80,7 @@ pub(super) fn show_scripts_section(
                 settings.temp_script_allow_write_text = script.allow_write_text;
                 settings.temp_script_allow_run_command = script.allow_run_command;
                 settings.temp_script_allow_change_config = script.allow_change_config;
+                settings.temp_script_allow_read_selection = script.allow_read_selection;
                 settings.temp_script_write_text_rate_limit = script.write_text_rate_limit;
                 settings.temp_script_run_command_rate_limit = script.run_command_rate_limit;
             }
@@ -110,6 +111,7 @@ pub(super) fn show_scripts_section(
                 settings.temp_script_allow_write_text = false;
                 settings.temp_script_allow_run_command = false;
                 settings.temp_script_allow_change_config = false;
+                settings.temp_script_allow_read_selection = false;
                 settings.temp_script_write_text_rate_limit = 0;
                 settings.temp_script_run_command_rate_limit = 0;
             }
@@ -36,16 +36,20 @@ pub struct SettingsUI {
     pub temp_font_bold: String,
     pub temp_font_italic: String,
     pub temp_font_bold_italic: String,
+    pub temp_synthesize_styles: bool,
     pub temp_font_family: String,
     pub temp_font_size: f32,
     pub temp_line_spacing: f32,
     pub temp_char_spacing: f32,
     pub temp_enable_text_shaping: bool,
     pub temp_enable_ligatures: bool,
+    pub temp_font_ligature_fallback: String,
     pub temp_enable_kerning: bool,
     pub font_pending_changes: bool,
     pub temp_custom_shell: String,
     pub temp_shell_args: String,
+    /// `split_on_commands`, comma-separated
+    pub temp_split_on_commands: String,
     pub temp_working_directory: String,
     pub temp_startup_directory: String,
     pub temp_initial_text: String,
@@ -105,6 +109,16 @@ pub struct SettingsUI {
     /// Available agent identities for the AI Inspector dropdown (identity, name)
     pub available_agent_ids: Vec<(String, String)>,
 
+    // Badge state
+    /// Badge variables of the focused session with current values (live preview/autocomplete)
+    pub badge_variables: Vec<par_term_config::BadgeVariable>,
+
+    // GPU state
+    /// GPU adapters that can present to the focused window (device picker)
+    pub gpu_adapters: Vec<par_term_config::GpuAdapterInfo>,
+    /// Adapter, limits and graphics cache usage of the focused window
+    pub gpu_diagnostics: par_term_config::GpuDiagnostics,
+
     // Assistant prompt library state
     /// Assistant prompts loaded from the Markdown-backed prompt library
     pub assistant_prompts: Vec<par_term_config::AssistantPrompt>,
@@ -200,6 +214,8 @@ pub struct SettingsUI {
     pub profile_save_requested: bool,
     /// Flag: open a profile was requested from inline UI
     pub profile_open_requested: Option<ProfileId>,
+    /// Profile active in the focused tab, shown by the quick settings strip
+    pub quick_settings_profile: Option<par_term_config::Profile>,
     // Shader install workflow state
     /// Whether a shader install/uninstall operation is running
     pub(crate) shader_installing: bool,
@@ -284,6 +300,8 @@ pub struct SettingsUI {
     pub temp_script_allow_run_command: bool,
     /// Temporary: allow ChangeConfig commands
     pub temp_script_allow_change_config: bool,
+    /// Temporary: allow GetSelection commands
+    pub temp_script_allow_read_selection: bool,
     /// Temporary: WriteText rate limit (writes/sec, 0 = default)
     pub temp_script_write_text_rate_limit: u32,
     /// Temporary: RunCommand rate limit (runs/sec, 0 = default)
@@ -100,12 +100,14 @@ impl SettingsUI {
             temp_font_bold: config.font_family_bold.clone().unwrap_or_default(),
             temp_font_italic: config.font_family_italic.clone().unwrap_or_default(),
             temp_font_bold_italic: config.font_family_bold_italic.clone().unwrap_or_default(),
+            temp_synthesize_styles: config.synthesize_styles,
             temp_font_family: config.font_family.clone(),
             temp_font_size: config.font_size,
             temp_line_spacing: config.line_spacing,
             temp_char_spacing: config.char_spacing,
             temp_enable_text_shaping: config.enable_text_shaping,
             temp_enable_ligatures: config.enable_ligatures,
+            temp_font_ligature_fallback: config.font_ligature_fallback.clone().unwrap_or_default(),
             temp_enable_kerning: config.enable_kerning,
             font_pending_changes: false,
             temp_custom_shell: config.custom_shell.clone().unwrap_or_default(),
@@ -114,6 +116,7 @@ impl SettingsUI {
                 .as_ref()
                 .map(|args| args.join(" "))
                 .unwrap_or_default(),
+            temp_split_on_commands: config.split_on_commands.join(", "),
             temp_working_directory: config.working_directory.clone().unwrap_or_default(),
             temp_startup_directory: config.startup_directory.clone().unwrap_or_default(),
             temp_initial_text: config.initial_text.clone(),
@@ -173,6 +176,9 @@ impl SettingsUI {
             cursor_shader_editor_error: None,
             cursor_shader_editor_original: String::new(),
             available_agent_ids: Vec::new(),
+            badge_variables: par_term_config::builtin_badge_variables(),
+            gpu_adapters: Vec::new(),
+            gpu_diagnostics: par_term_config::GpuDiagnostics::default(),
             assistant_prompts,
             assistant_prompt_error,
             editing_assistant_prompt_index: None,
@@ -210,6 +216,7 @@ impl SettingsUI {
             profile_modal_ui: ProfileModalUI::new(),
             profile_save_requested: false,
             profile_open_requested: None,
+            quick_settings_profile: None,
             shader_installing: false,
             shader_status: None,
             shader_error: None,
@@ -250,6 +257,7 @@ impl SettingsUI {
             temp_script_allow_write_text: false,
             temp_script_allow_run_command: false,
             temp_script_allow_change_config: false,
+            temp_script_allow_read_selection: false,
             temp_script_write_text_rate_limit: 0,
             temp_script_run_command_rate_limit: 0,
             adding_new_script: false,
@@ -414,6 +422,11 @@ impl SettingsUI {
         self.temp_char_spacing = self.config.char_spacing;
         self.temp_enable_text_shaping = self.config.enable_text_shaping;
         self.temp_enable_ligatures = self.config.enable_ligatures;
+        self.temp_font_ligature_fallback = self
+            .config
+            .font_ligature_fallback
+            .clone()
+            .unwrap_or_default();
         self.temp_enable_kerning = self.config.enable_kerning;
         self.temp_font_bold = self.config.font_family_bold.clone().unwrap_or_default();
         self.temp_font_italic = self.config.font_family_italic.clone().unwrap_or_default();
@@ -422,6 +435,7 @@ impl SettingsUI {
             .font_family_bold_italic
             .clone()
             .unwrap_or_default();
+        self.temp_synthesize_styles = self.config.synthesize_styles;
         self.font_pending_changes = false;
     }
 
@@ -435,6 +449,7 @@ impl SettingsUI {
             .as_ref()
             .map(|args| args.join(" "))
             .unwrap_or_default();
+        self.temp_split_on_commands = self.config.split_on_commands.join(", ");
         self.temp_working_directory = self.config.working_directory.clone().unwrap_or_default();
         self.temp_startup_directory = self.config.startup_directory.clone().unwrap_or_default();
         self.temp_initial_text = self.config.initial_text.clone();
@@ -491,6 +506,11 @@ impl SettingsUI {
         self.config.char_spacing = self.temp_char_spacing;
         self.config.enable_text_shaping = self.temp_enable_text_shaping;
         self.config.enable_ligatures = self.temp_enable_ligatures;
+        self.config.font_ligature_fallback = if self.temp_font_ligature_fallback.is_empty() {
+            None
+        } else {
+            Some(self.temp_font_ligature_fallback.clone())
+        };
         self.config.enable_kerning = self.temp_enable_kerning;
         self.config.font_family_bold = if self.temp_font_bold.is_empty() {
             None
@@ -507,6 +527,7 @@ impl SettingsUI {
         } else {
             Some(self.temp_font_bold_italic.clone())
         };
+        self.config.synthesize_styles = self.temp_synthesize_styles;
         self.font_pending_changes = false;
     }
 
@@ -1,6 +1,6 @@
 //! Behavior section for the terminal settings tab.
 //!
-//! Covers: scrollback lines, shell exit action, close confirmation, jobs to ignore.
+//! Covers: scrollback lines, repeated-line collapsing, shell exit action, close confirmation, jobs to ignore.
 
 use crate::SettingsUI;
 use crate::section::{INPUT_WIDTH, SLIDER_WIDTH, collapsing_section};
@@ -32,6 +32,82 @@ pub(super) fn show_behavior_section(
             }
         });
 
+        if ui
+            .checkbox(
+                &mut settings.config.scrollback.collapse_repeated_lines,
+                "Collapse repeated output lines",
+            )
+            .on_hover_text(
+                "Keep the first of a run of identical output lines and count the\n\
+                 rest on a \"[last line repeated N times]\" line, so log spam does\n\
+                 not fill the scrollback. Never crosses a shell prompt.\n\
+                 Applies to new tabs.",
+            )
+            .changed()
+        {
+            settings.has_changes = true;
+            *changes_this_frame = true;
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("Marks on trimmed lines:");
+            egui::ComboBox::from_id_salt("scrollback_mark_trim_policy")
+                .selected_text(settings.config.scrollback.mark_trim_policy.display_name())
+                .show_ui(ui, |ui| {
+                    for policy in par_term_config::MarkTrimPolicy::all() {
+                        if ui
+                            .selectable_value(
+                                &mut settings.config.scrollback.mark_trim_policy,
+                                *policy,
+                                policy.display_name(),
+                            )
+                            .changed()
+                        {
+                            settings.has_changes = true;
+                            *changes_this_frame = true;
+                        }
+                    }
+                })
+                .response
+                .on_hover_text(
+                    "What happens to prompt marks when a full scrollback trims\n\
+                     their lines: drop them, or keep the newest one at the top.",
+                );
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Continuation prompts:");
+            egui::ComboBox::from_id_salt("scrollback_prompt_continuation")
+                .selected_text(
+                    settings
+                        .config
+                        .scrollback
+                        .prompt_continuation
+                        .display_name(),
+                )
+                .show_ui(ui, |ui| {
+                    for mode in par_term_config::PromptContinuation::all() {
+                        if ui
+                            .selectable_value(
+                                &mut settings.config.scrollback.prompt_continuation,
+                                *mode,
+                                mode.display_name(),
+                            )
+                            .changed()
+                        {
+                            settings.has_changes = true;
+                            *changes_this_frame = true;
+                        }
+                    }
+                })
+                .response
+                .on_hover_text(
+                    "How secondary (PS2) prompts marked with OSC 133 k=s are treated:\n\
+                     as part of the command being entered, or as a new prompt\n\
+                     with its own scrollbar mark.",
+                );
+        });
+
         ui.horizontal(|ui| {
             ui.label("Shell exit action:");
             egui::ComboBox::from_id_salt("shell_exit_action")
@@ -53,6 +129,22 @@ pub(super) fn show_behavior_section(
                 });
         });
 
+        if ui
+            .checkbox(
+                &mut settings.config.shell_exit_keep_on_failure,
+                "Keep open when the shell exits with an error",
+            )
+            .on_hover_text(
+                "A non-zero exit keeps the tab/pane open with an exit status line\n\
+                 instead of closing or restarting it. Exit code 0 still uses the\n\
+                 shell exit action above.",
+            )
+            .changed()
+        {
+            settings.has_changes = true;
+            *changes_this_frame = true;
+        }
+
         ui.add_space(8.0);
         ui.label(egui::RichText::new("Close Confirmation").strong());
 
@@ -86,6 +178,22 @@ pub(super) fn show_behavior_section(
             *changes_this_frame = true;
         }
 
+        if ui
+            .checkbox(
+                &mut settings.config.confirm_foreground_jobs_only,
+                "Only count foreground jobs",
+            )
+            .on_hover_text(
+                "Only a command running in the foreground counts as a running job.\n\
+                 An idle prompt or jobs left in the background close without asking,\n\
+                 and quitting only asks when some tab has a foreground job.",
+            )
+            .changed()
+        {
+            settings.has_changes = true;
+            *changes_this_frame = true;
+        }
+
         // Jobs to ignore list (only shown when confirmation is enabled)
         if settings.config.confirm_close_running_jobs {
             ui.horizontal(|ui| {
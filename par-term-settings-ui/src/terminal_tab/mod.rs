@@ -7,7 +7,7 @@
 //! - Unicode settings (version, ambiguous width, answerback)
 //! - Shell settings (custom shell, args, working directory)
 //! - Startup settings (initial text)
-//! - Search settings (highlight colors, defaults)
+//! - Search settings (highlight colors, defaults, keyword highlight sets)
 //!
 //! ## Sub-module layout
 //!
@@ -15,10 +15,10 @@
 //! |------|----------|
 //! | `mod.rs` (this file) | `show()` dispatcher — calls each section in order |
 //! | `behavior.rs` | Behavior section (scrollback, shell exit, close confirmation) |
-//! | `unicode.rs` | Unicode section (version, ambiguous width, normalization, answerback) |
+//! | `unicode.rs` | Unicode section (version, ambiguous width, normalization, answerback, printer output, form feed, SGR reset coalescing) |
 //! | `shell.rs` | Shell section (custom shell, args, login shell, startup directory) |
 //! | `startup.rs` | Startup section (restore session, undo close, initial text) |
-//! | `search.rs` | Search, Command History, and Command Separator sections |
+//! | `search.rs` | Search, Keyword Highlights, Command History, and Command Separator sections |
 //! | `semantic_history.rs` | Semantic History section (link handler, file path detection, editor) |
 
 mod behavior;
@@ -47,13 +47,22 @@ pub fn show(
         "Behavior",
         &[
             "scrollback",
+            "repeated lines",
+            "collapse",
+            "marks",
+            "trim",
+            "continuation",
+            "ps2",
             "exit",
             "shell exit",
+            "exit code",
+            "keep on failure",
             "jobs",
             "confirm",
             "confirm close",
             "close",
             "running jobs",
+            "foreground job",
             "process names",
             "ignore",
             "close tab",
@@ -70,6 +79,14 @@ pub fn show(
             "unicode",
             "width",
             "answerback",
+            "printer",
+            "media copy",
+            "form feed",
+            "sgr reset",
+            "line length",
+            "long line",
+            "decrqm",
+            "mode query",
             "ambiguous",
             "normalization",
             "nfc",
@@ -145,6 +162,22 @@ pub fn show(
         search::show_search_section(ui, settings, changes_this_frame, collapsed);
     }
 
+    // Keyword highlights section (collapsed by default)
+    if section_matches(
+        &query,
+        "Keyword Highlights",
+        &[
+            "keyword",
+            "keywords",
+            "highlight",
+            "saved search",
+            "error",
+            "whole word",
+        ],
+    ) {
+        search::show_keyword_highlights_section(ui, settings, changes_this_frame, collapsed);
+    }
+
     // Semantic History section
     if section_matches(
         &query,
@@ -161,6 +194,11 @@ pub fn show(
             "vim",
             "editor mode",
             "system default",
+            "osc 8",
+            "hyperlink",
+            "hover",
+            "tooltip",
+            "modifier",
         ],
     ) {
         semantic_history::show_semantic_history_section(
@@ -182,6 +220,9 @@ pub fn show(
             "search",
             "entries",
             "max entries",
+            "password",
+            "password prompt",
+            "mask",
         ],
     ) {
         search::show_command_history_section(ui, settings, changes_this_frame, collapsed);
@@ -213,12 +254,22 @@ pub fn keywords() -> &'static [&'static str] {
         "shell",
         "scrollback",
         "scrollback lines",
+        "repeated lines",
+        "collapse repeated",
+        "mark trim",
+        "trimmed marks",
+        "continuation prompt",
+        "ps2",
+        "osc 133",
         "exit",
         "shell exit",
         "exit action",
+        "exit code",
+        "keep on failure",
         "confirm",
         "confirm close",
         "running jobs",
+        "foreground job",
         "jobs",
         "jobs to ignore",
         // Unicode
@@ -228,6 +279,15 @@ pub fn keywords() -> &'static [&'static str] {
         "ambiguous",
         "ambiguous width",
         "answerback",
+        "printer",
+        "printer output",
+        "media copy",
+        "form feed",
+        "sgr reset",
+        "line length",
+        "long line",
+        "decrqm",
+        "mode query",
         // Shell
         "custom shell",
         "shell args",
@@ -258,6 +318,12 @@ pub fn keywords() -> &'static [&'static str] {
         "regex",
         "wrap",
         "wrap around",
+        // Keyword highlights
+        "keyword",
+        "keyword highlight",
+        "keyword set",
+        "saved search",
+        "whole word",
         // Semantic history
         "semantic",
         "semantic history",
@@ -271,6 +337,14 @@ pub fn keywords() -> &'static [&'static str] {
         "link highlight underline",
         "link underline style",
         "stipple",
+        "osc 8",
+        "hyperlink hover",
+        "underline on hover",
+        "link tooltip",
+        "full url",
+        "click modifier",
+        "ctrl click",
+        "cmd click",
         "link color",
         "url color",
         "browser",
@@ -289,6 +363,8 @@ pub fn keywords() -> &'static [&'static str] {
         "command history",
         "history entries",
         "max history",
+        "password prompt",
+        "mask password",
         // Command separators
         "command separator",
         "separator",
@@ -1,6 +1,8 @@
-//! Search, command history, and command separator sections for the terminal settings tab.
+//! Search, keyword highlight, command history, and command separator sections
+//! for the terminal settings tab.
 //!
-//! Covers: search highlight colors, default options, command history, command separators.
+//! Covers: search highlight colors, default options, saved keyword highlight
+//! sets, command history, command separators.
 
 use crate::SettingsUI;
 use crate::section::{SLIDER_WIDTH, collapsing_section};
@@ -109,6 +111,148 @@ pub(super) fn show_search_section(
     });
 }
 
+// ============================================================================
+// Keyword Highlights Section
+// ============================================================================
+
+pub(super) fn show_keyword_highlights_section(
+    ui: &mut egui::Ui,
+    settings: &mut SettingsUI,
+    changes_this_frame: &mut bool,
+    collapsed: &mut HashSet<String>,
+) {
+    collapsing_section(
+        ui,
+        "Keyword Highlights",
+        "terminal_keyword_highlights",
+        false,
+        collapsed,
+        |ui| {
+            ui.label(
+                egui::RichText::new(
+                    "Saved keyword sets highlighted wherever they appear in the output.",
+                )
+                .small()
+                .color(egui::Color32::GRAY),
+            );
+            ui.add_space(4.0);
+
+            let mut changed = false;
+            let mut remove = None;
+            for (index, set) in settings
+                .config
+                .search
+                .keyword_highlights
+                .iter_mut()
+                .enumerate()
+            {
+                ui.push_id(("keyword_highlight", index), |ui| {
+                    ui.horizontal(|ui| {
+                        changed |= ui
+                            .checkbox(&mut set.enabled, "")
+                            .on_hover_text("Highlight this set")
+                            .changed();
+                        changed |= ui
+                            .add(egui::TextEdit::singleline(&mut set.name).desired_width(100.0))
+                            .changed();
+
+                        // Edit keywords as comma-separated text; the buffer is
+                        // kept while typing so a trailing comma is not lost.
+                        let buffer_id = ui.id().with("keywords");
+                        let mut text = ui
+                            .data(|d| d.get_temp::<String>(buffer_id))
+                            .unwrap_or_else(|| set.keywords.join(", "));
+                        let response = ui.add(
+                            egui::TextEdit::singleline(&mut text)
+                                .desired_width(200.0)
+                                .hint_text("ERROR, FATAL"),
+                        );
+                        if response.changed() {
+                            set.keywords = text
+                                .split(',')
+                                .map(|k| k.trim().to_string())
+                                .filter(|k| !k.is_empty())
+                                .collect();
+                            changed = true;
+                        }
+                        ui.data_mut(|d| {
+                            if response.has_focus() {
+                                d.insert_temp(buffer_id, text);
+                            } else {
+                                d.remove::<String>(buffer_id);
+                            }
+                        });
+
+                        if ui.small_button("Remove").clicked() {
+                            remove = Some(index);
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.add_space(24.0);
+                        changed |= optional_color(ui, "Text:", &mut set.foreground, [255, 85, 85]);
+                        changed |=
+                            optional_color(ui, "Background:", &mut set.background, [90, 0, 0]);
+                        changed |= ui.checkbox(&mut set.case_sensitive, "Match case").changed();
+                        changed |= ui.checkbox(&mut set.whole_word, "Whole words").changed();
+                    });
+                });
+                ui.add_space(4.0);
+            }
+
+            if let Some(index) = remove {
+                settings.config.search.keyword_highlights.remove(index);
+                changed = true;
+            }
+            if ui.button("+ Add keyword set").clicked() {
+                let name = format!(
+                    "Set {}",
+                    settings.config.search.keyword_highlights.len() + 1
+                );
+                settings
+                    .config
+                    .search
+                    .keyword_highlights
+                    .push(par_term_config::KeywordHighlightSet::new(name));
+                changed = true;
+            }
+
+            if changed {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+        },
+    );
+}
+
+/// Checkbox + color button for an optional color; returns true on change.
+fn optional_color(
+    ui: &mut egui::Ui,
+    label: &str,
+    value: &mut Option<[u8; 3]>,
+    default: [u8; 3],
+) -> bool {
+    let mut changed = false;
+    let mut enabled = value.is_some();
+    if ui.checkbox(&mut enabled, label).changed() {
+        *value = enabled.then_some(default);
+        changed = true;
+    }
+    if let Some(rgb) = value {
+        let mut color = egui::Color32::from_rgb(rgb[0], rgb[1], rgb[2]);
+        if egui::color_picker::color_edit_button_srgba(
+            ui,
+            &mut color,
+            egui::color_picker::Alpha::Opaque,
+        )
+        .changed()
+        {
+            *rgb = [color.r(), color.g(), color.b()];
+            changed = true;
+        }
+    }
+    changed
+}
+
 // ============================================================================
 // Command History Section
 // ============================================================================
@@ -145,6 +289,37 @@ pub(super) fn show_command_history_section(
                     *changes_this_frame = true;
                 }
             });
+
+            ui.add_space(4.0);
+            if ui
+                .checkbox(
+                    &mut settings.config.password_prompt_detection,
+                    "Detect password prompts",
+                )
+                .on_hover_text(
+                    "Keep the line entered after a password prompt (sudo, ssh, ...) out of command history",
+                )
+                .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+
+            ui.add_enabled_ui(settings.config.password_prompt_detection, |ui| {
+                if ui
+                    .checkbox(
+                        &mut settings.config.mask_password_input,
+                        "Mask password input",
+                    )
+                    .on_hover_text(
+                        "Draw characters echoed at a detected password prompt as bullets",
+                    )
+                    .changed()
+                {
+                    settings.has_changes = true;
+                    *changes_this_frame = true;
+                }
+            });
         },
     );
 }
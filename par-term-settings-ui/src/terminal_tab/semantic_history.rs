@@ -140,6 +140,35 @@ pub(super) fn show_semantic_history_section(
                 *changes_this_frame = true;
             }
 
+            if ui
+                .checkbox(
+                    &mut settings.config.osc8_links_hover_only,
+                    "Style OSC 8 hyperlinks only on hover",
+                )
+                .on_hover_text(
+                    "Hide the underline and color of explicit (OSC 8) hyperlinks until the mouse \
+                     is over them. Detected URLs and file paths are not affected.",
+                )
+                .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+
+            if ui
+                .checkbox(
+                    &mut settings.config.link_hover_tooltip,
+                    "Show link target on hover",
+                )
+                .on_hover_text(
+                    "Show the full URL or path of the link under the mouse in a tooltip.",
+                )
+                .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+
             if settings.config.link_highlight_underline {
                 ui.horizontal(|ui| {
                     ui.label("Underline style:");
@@ -163,6 +192,32 @@ pub(super) fn show_semantic_history_section(
                 });
             }
 
+            ui.horizontal(|ui| {
+                ui.label("Open links with:");
+                egui::ComboBox::from_id_salt("hyperlink_click_modifier")
+                    .selected_text(settings.config.hyperlink_click_modifier.display_name())
+                    .show_ui(ui, |ui| {
+                        for modifier in par_term_config::HyperlinkClickModifier::all() {
+                            if ui
+                                .selectable_value(
+                                    &mut settings.config.hyperlink_click_modifier,
+                                    *modifier,
+                                    modifier.display_name(),
+                                )
+                                .changed()
+                            {
+                                settings.has_changes = true;
+                                *changes_this_frame = true;
+                            }
+                        }
+                    });
+            })
+            .response
+            .on_hover_text(
+                "Modifier to hold while clicking a link to open it. A click without it \
+                 selects text instead.",
+            );
+
             ui.add_space(8.0);
 
             ui.horizontal(|ui| {
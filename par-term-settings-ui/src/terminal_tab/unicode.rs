@@ -1,6 +1,7 @@
 //! Unicode section for the terminal settings tab.
 //!
-//! Covers: unicode version, ambiguous width, normalization form, answerback string.
+//! Covers: unicode version, ambiguous width, normalization form, answerback string,
+//! printer output.
 
 use crate::SettingsUI;
 use crate::section::collapsing_section;
@@ -174,5 +175,124 @@ pub(super) fn show_unicode_section(
             .small()
             .color(egui::Color32::YELLOW),
         );
+
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            ui.label("Printer output:");
+            let mut to_file = matches!(
+                settings.config.printer_output,
+                par_term_config::PrinterOutput::File { .. }
+            );
+            egui::ComboBox::from_id_salt("terminal_printer_output")
+                .selected_text(settings.config.printer_output.display_name())
+                .show_ui(ui, |ui| {
+                    if ui
+                        .selectable_value(&mut to_file, false, "Discard")
+                        .changed()
+                        | ui.selectable_value(&mut to_file, true, "Append to file")
+                            .changed()
+                    {
+                        settings.config.printer_output = if to_file {
+                            par_term_config::PrinterOutput::File {
+                                path: String::new(),
+                            }
+                        } else {
+                            par_term_config::PrinterOutput::Discard
+                        };
+                        settings.has_changes = true;
+                        *changes_this_frame = true;
+                    }
+                })
+                .response
+                .on_hover_text(
+                    "Where media copy output (between CSI 5 i and CSI 4 i) goes.\n\
+                     Printer data is never shown on screen.",
+                );
+            if let par_term_config::PrinterOutput::File { path } =
+                &mut settings.config.printer_output
+                && ui
+                    .add(egui::TextEdit::singleline(path).hint_text("/path/to/printer.txt"))
+                    .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Form feed:");
+            egui::ComboBox::from_id_salt("terminal_form_feed")
+                .selected_text(settings.config.form_feed.display_name())
+                .show_ui(ui, |ui| {
+                    for mode in par_term_config::FormFeed::all() {
+                        if ui
+                            .selectable_value(
+                                &mut settings.config.form_feed,
+                                *mode,
+                                mode.display_name(),
+                            )
+                            .changed()
+                        {
+                            settings.has_changes = true;
+                            *changes_this_frame = true;
+                        }
+                    }
+                })
+                .response
+                .on_hover_text(
+                    "What a form feed (FF, 0x0C) does in terminal output.\n\
+                     Applies to new tabs.",
+                );
+        });
+
+        if ui
+            .checkbox(
+                &mut settings.config.coalesce_sgr_resets,
+                "Skip redundant attribute resets",
+            )
+            .on_hover_text(
+                "Drop SGR resets (CSI 0 m) that repeat an earlier reset.\n\
+                 The output looks the same with less work. Applies to new tabs.",
+            )
+            .changed()
+        {
+            settings.has_changes = true;
+            *changes_this_frame = true;
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("Max line length:");
+            if ui
+                .add(
+                    egui::DragValue::new(&mut settings.config.max_line_length)
+                        .range(0..=10_000_000)
+                        .speed(100.0),
+                )
+                .on_hover_text(
+                    "Break output lines after this many characters, so a huge line\n\
+                     with no newline cannot stall rendering. 0 = no limit. Applies to new tabs.",
+                )
+                .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+        });
+
+        if ui
+            .checkbox(
+                &mut settings.config.answer_mode_requests,
+                "Answer mode queries (DECRQM)",
+            )
+            .on_hover_text(
+                "Tell applications whether a terminal mode is on when they ask (CSI ? Ps $ p).\n\
+                 Applies to new tabs.",
+            )
+            .changed()
+        {
+            settings.has_changes = true;
+            *changes_this_frame = true;
+        }
     });
 }
@@ -49,6 +49,21 @@ pub(super) fn show_behavior_section(
                 *changes_this_frame = true;
             }
 
+            if ui
+                .checkbox(
+                    &mut settings.config.remember_profile_window_geometry,
+                    "Remember window size and position per profile",
+                )
+                .on_hover_text(
+                    "Open profiles from the menu in their own window, restoring the size and\n\
+                     position it had when last closed (moved onto an available monitor if needed)",
+                )
+                .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+
             if ui
                 .checkbox(
                     &mut settings.config.show_window_number,
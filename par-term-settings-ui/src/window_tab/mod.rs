@@ -62,6 +62,8 @@ pub fn show(
             "background",
             "default background",
             "text opaque",
+            "text shadow",
+            "drop shadow",
         ],
     ) {
         transparency::show_transparency_section(ui, settings, changes_this_frame, collapsed);
@@ -105,6 +107,10 @@ pub fn show(
             "edge-anchored",
             "primary monitor",
             "window number",
+            "remember",
+            "window position",
+            "window size",
+            "profile geometry",
         ],
     ) {
         behavior::show_behavior_section(ui, settings, changes_this_frame, collapsed);
@@ -123,8 +129,13 @@ pub fn show(
             "profile drawer",
             "stretch",
             "html titles",
+            "preview",
+            "thumbnail",
             "inherit directory",
+            "last cd",
             "max tabs",
+            "title template",
+            "tab title template",
             "remote tab title",
             "ssh title",
             "remote host",
@@ -147,6 +158,7 @@ pub fn show(
             "dimming",
             "tab style",
             "minimum tab width",
+            "tab font",
             "active indicator",
             "activity indicator",
             "bell indicator",
@@ -169,6 +181,8 @@ pub fn show(
             "max panes",
             "min pane size",
             "pane padding",
+            "split on command",
+            "long-running",
         ],
     ) {
         panes::show_panes_section(ui, settings, changes_this_frame, collapsed);
@@ -185,6 +199,7 @@ pub fn show(
             "pane opacity",
             "hover color",
             "dim inactive",
+            "dim fade",
             "title height",
             "title position",
             "pane background",
@@ -202,6 +217,7 @@ pub fn show(
             "thumb",
             "track",
             "autohide",
+            "fade",
             "marker",
             "command markers",
             "shell integration",
@@ -239,6 +255,8 @@ pub fn keywords() -> &'static [&'static str] {
         "blur",
         "blur radius",
         "keep text opaque",
+        "text shadow",
+        "drop shadow",
         // Performance
         "fps",
         "max fps",
@@ -259,6 +277,8 @@ pub fn keywords() -> &'static [&'static str] {
         "decorations",
         "always on top",
         "lock window size",
+        "remember window position",
+        "profile window size",
         "window number",
         "window type",
         "monitor",
@@ -275,6 +295,8 @@ pub fn keywords() -> &'static [&'static str] {
         "tab bar mode",
         "tab title mode",
         "tab title",
+        "title template",
+        "tab title template",
         "osc only",
         "cwd title",
         "rename tab",
@@ -283,8 +305,12 @@ pub fn keywords() -> &'static [&'static str] {
         "close button",
         "stretch",
         "html titles",
+        "tab preview",
+        "thumbnail",
         "inherit cwd",
         "inherit directory",
+        "last cd",
+        "latest directory",
         "profile drawer",
         "new tab shortcut",
         "profile picker",
@@ -298,6 +324,9 @@ pub fn keywords() -> &'static [&'static str] {
         "osc priority",
         // Tab bar appearance
         "tab min width",
+        "tab font",
+        "tab font size",
+        "monospace tabs",
         "tab border",
         "tab color",
         "inactive tab",
@@ -336,10 +365,13 @@ pub fn keywords() -> &'static [&'static str] {
         "pane focus",
         "max panes",
         "min pane size",
+        "split on command",
+        "long-running",
         // Pane appearance
         "divider color",
         "hover color",
         "dim inactive panes",
+        "dim fade",
         "inactive pane",
         "pane opacity",
         "pane title",
@@ -354,6 +386,7 @@ pub fn keywords() -> &'static [&'static str] {
         "thumb",
         "track",
         "autohide",
+        "fade",
         "command marks",
         "marker",
         "mark",
@@ -5,7 +5,8 @@
 //! - Pane Appearance section (colors and visual styling)
 
 use crate::SettingsUI;
-use crate::section::collapsing_section;
+use crate::section::{INPUT_WIDTH, collapsing_section};
+use par_term_config::snippets::ActionSplitDirection;
 use par_term_config::{DividerStyle, PaneTitlePosition};
 use std::collections::HashSet;
 
@@ -156,6 +157,71 @@ pub(super) fn show_panes_section(
             }
         });
 
+        ui.add_space(8.0);
+        ui.label(egui::RichText::new("Split on Command").strong());
+
+        ui.horizontal(|ui| {
+            ui.label("Commands (comma-separated):");
+            if ui
+                .add(
+                    egui::TextEdit::singleline(&mut settings.temp_split_on_commands)
+                        .hint_text("cargo, npm run dev")
+                        .desired_width(INPUT_WIDTH),
+                )
+                .on_hover_text(
+                    "Split the pane when one of these commands starts (requires shell integration). \
+                     The job keeps its pane and a new shell takes focus beside it.",
+                )
+                .changed()
+            {
+                settings.config.split_on_commands = settings
+                    .temp_split_on_commands
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|command| !command.is_empty())
+                    .map(String::from)
+                    .collect();
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("New shell:");
+            egui::ComboBox::from_id_salt("split_on_command_direction")
+                .selected_text(settings.config.split_on_command_direction.label())
+                .show_ui(ui, |ui| {
+                    for direction in ActionSplitDirection::all() {
+                        if ui
+                            .selectable_value(
+                                &mut settings.config.split_on_command_direction,
+                                *direction,
+                                direction.label(),
+                            )
+                            .changed()
+                        {
+                            settings.has_changes = true;
+                            *changes_this_frame = true;
+                        }
+                    }
+                });
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Job keeps:");
+            if ui
+                .add(
+                    egui::Slider::new(&mut settings.config.split_on_command_percent, 10..=90)
+                        .suffix("%"),
+                )
+                .on_hover_text("Share of the split pane kept by the running command")
+                .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+        });
+
         ui.add_space(8.0);
         ui.label(egui::RichText::new("Keyboard Shortcuts").weak().small());
         #[cfg(target_os = "macos")]
@@ -264,6 +330,23 @@ pub(super) fn show_pane_appearance_section(
                         *changes_this_frame = true;
                     }
                 });
+
+                ui.horizontal(|ui| {
+                    ui.label("Fade duration (ms):");
+                    let mut fade = settings.config.pane_dim_fade_duration as u32;
+                    if ui
+                        .add(egui::Slider::new(&mut fade, 0..=1000))
+                        .on_hover_text(
+                            "How long panes take to fade between dim levels when focus \
+                             changes (0 = instant)",
+                        )
+                        .changed()
+                    {
+                        settings.config.pane_dim_fade_duration = fade as u64;
+                        settings.has_changes = true;
+                        *changes_this_frame = true;
+                    }
+                });
             }
 
             ui.add_space(8.0);
@@ -70,6 +70,23 @@ pub(super) fn show_scrollbar_section(
             }
         });
 
+        ui.add_enabled_ui(settings.config.scrollbar_autohide_delay > 0, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Fade duration (ms, 0=instant):");
+                if ui
+                    .add_sized(
+                        [SLIDER_WIDTH, SLIDER_HEIGHT],
+                        egui::Slider::new(&mut settings.config.scrollbar_fade_duration, 0..=2000),
+                    )
+                    .on_hover_text("How long the scrollbar takes to fade out once the autohide delay has passed")
+                    .changed()
+                {
+                    settings.has_changes = true;
+                    *changes_this_frame = true;
+                }
+            });
+        });
+
         ui.add_space(8.0);
         ui.label(egui::RichText::new("Colors").strong());
 
@@ -1,7 +1,8 @@
-//! Tab Bar Appearance section: colors, borders, dimming, indicator colors.
+//! Tab Bar Appearance section: title font, colors, borders, dimming, indicator colors.
 
 use crate::SettingsUI;
 use crate::section::collapsing_section;
+use par_term_config::TabFontFamily;
 use std::collections::HashSet;
 
 pub(super) fn show_tab_bar_appearance_section(
@@ -33,6 +34,46 @@ pub(super) fn show_tab_bar_appearance_section(
                 }
             });
 
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("Title Font").strong());
+
+            ui.horizontal(|ui| {
+                ui.label("Font size:");
+                if ui
+                    .add(
+                        egui::Slider::new(&mut settings.config.tab_font_size, 0.0..=24.0)
+                            .step_by(0.5)
+                            .suffix("pt"),
+                    )
+                    .on_hover_text("Tab title font size (0 = default UI size)")
+                    .changed()
+                {
+                    settings.has_changes = true;
+                    *changes_this_frame = true;
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Font family:");
+                egui::ComboBox::from_id_salt("window_tab_font_family")
+                    .selected_text(settings.config.tab_font_family.display_name())
+                    .show_ui(ui, |ui| {
+                        for family in TabFontFamily::all() {
+                            if ui
+                                .selectable_value(
+                                    &mut settings.config.tab_font_family,
+                                    *family,
+                                    family.display_name(),
+                                )
+                                .changed()
+                            {
+                                settings.has_changes = true;
+                                *changes_this_frame = true;
+                            }
+                        }
+                    });
+            });
+
             ui.add_space(8.0);
             ui.label(egui::RichText::new("Border").strong());
 
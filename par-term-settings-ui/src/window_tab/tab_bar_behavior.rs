@@ -148,6 +148,25 @@ pub(super) fn show_tab_bar_section(
             }
         });
 
+        ui.horizontal(|ui| {
+            ui.label("Tab title template:");
+            if ui
+                .add(
+                    egui::TextEdit::singleline(&mut settings.config.tab_title_template)
+                        .hint_text("{index}: {cwd}"),
+                )
+                .on_hover_text(
+                    "Placeholders: {cwd}, {command}, {exit}, {index}.\n\
+                     Values not yet reported by shell integration render empty.\n\
+                     Leave empty to use the title mode above. Profiles can override this.",
+                )
+                .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+        });
+
         ui.horizontal(|ui| {
             ui.label("Remote tab title format:");
             egui::ComboBox::from_id_salt("window_remote_tab_title_format")
@@ -296,6 +315,41 @@ pub(super) fn show_tab_bar_section(
             *changes_this_frame = true;
         }
 
+        if ui
+            .checkbox(
+                &mut settings.config.tab_hover_preview,
+                "Preview tabs on hover",
+            )
+            .on_hover_text(
+                "Show a small live preview of an inactive tab's content when hovering it",
+            )
+            .changed()
+        {
+            settings.has_changes = true;
+            *changes_this_frame = true;
+        }
+
+        if settings.config.tab_hover_preview {
+            ui.horizontal(|ui| {
+                ui.label("Preview width:");
+                if ui
+                    .add(
+                        egui::Slider::new(
+                            &mut settings.config.tab_hover_preview_width,
+                            160.0..=640.0,
+                        )
+                        .step_by(10.0)
+                        .suffix("px"),
+                    )
+                    .on_hover_text("Width of the hover preview; height follows the tab's grid")
+                    .changed()
+                {
+                    settings.has_changes = true;
+                    *changes_this_frame = true;
+                }
+            });
+        }
+
         ui.add_space(8.0);
 
         if ui
@@ -310,6 +364,25 @@ pub(super) fn show_tab_bar_section(
             *changes_this_frame = true;
         }
 
+        ui.add_enabled_ui(settings.config.tab_inherit_cwd, |ui| {
+            ui.indent("tab_inherit_latest_cwd", |ui| {
+                if ui
+                    .checkbox(
+                        &mut settings.config.tab_inherit_latest_cwd,
+                        "Follow the most recent cd in any tab",
+                    )
+                    .on_hover_text(
+                        "Start new tabs in the directory the last `cd` in any tab went to, \
+                         instead of the active tab's directory. Needs shell integration.",
+                    )
+                    .changed()
+                {
+                    settings.has_changes = true;
+                    *changes_this_frame = true;
+                }
+            });
+        });
+
         ui.add_space(4.0);
         ui.horizontal(|ui| {
             ui.label("New tab position:");
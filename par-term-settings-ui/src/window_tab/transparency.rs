@@ -60,6 +60,81 @@ pub(super) fn show_transparency_section(
                 *changes_this_frame = true;
             }
 
+            if ui
+                .checkbox(&mut settings.config.text_shadow_enabled, "Text shadow")
+                .on_hover_text(
+                    "Draw a drop shadow behind text, for readability over background images",
+                )
+                .changed()
+            {
+                settings.has_changes = true;
+                *changes_this_frame = true;
+            }
+
+            if settings.config.text_shadow_enabled {
+                ui.horizontal(|ui| {
+                    ui.label("Shadow color:");
+                    let mut color = settings.config.text_shadow_color;
+                    if ui
+                        .color_edit_button_srgba_unmultiplied(&mut color)
+                        .changed()
+                    {
+                        settings.config.text_shadow_color = color;
+                        settings.has_changes = true;
+                        *changes_this_frame = true;
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Shadow offset X:");
+                    if ui
+                        .add(
+                            egui::Slider::new(
+                                &mut settings.config.text_shadow_offset[0],
+                                -5.0..=5.0,
+                            )
+                            .suffix(" px"),
+                        )
+                        .changed()
+                    {
+                        settings.has_changes = true;
+                        *changes_this_frame = true;
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Shadow offset Y:");
+                    if ui
+                        .add(
+                            egui::Slider::new(
+                                &mut settings.config.text_shadow_offset[1],
+                                -5.0..=5.0,
+                            )
+                            .suffix(" px"),
+                        )
+                        .changed()
+                    {
+                        settings.has_changes = true;
+                        *changes_this_frame = true;
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Shadow blur:");
+                    if ui
+                        .add(
+                            egui::Slider::new(&mut settings.config.text_shadow_blur, 0.0..=4.0)
+                                .suffix(" px"),
+                        )
+                        .on_hover_text("Softens the shadow edges (0 = hard shadow)")
+                        .changed()
+                    {
+                        settings.has_changes = true;
+                        *changes_this_frame = true;
+                    }
+                });
+            }
+
             // Blur settings (macOS only)
             #[cfg(target_os = "macos")]
             {
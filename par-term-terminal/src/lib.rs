@@ -22,7 +22,15 @@ pub mod terminal;
 pub use scrollback_metadata::{CommandSnapshot, LineMetadata, ScrollbackMark, ScrollbackMetadata};
 pub use terminal::ShellLifecycleEvent;
 pub use terminal::TerminalManager;
+pub use terminal::clipboard::{
+    ClipboardHistoryStore, SharedClipboardHistory, global_clipboard_history,
+};
 pub use terminal::coprocess_env;
+pub use terminal::cwd_history::most_recent_change;
+pub use terminal::desktop_notifications::DesktopNotification;
+pub use terminal::hyperlinks::{HYPERLINK_HOVER_DEBOUNCE, HyperlinkHoverEvent};
+pub use terminal::metrics::TerminalMetrics;
+pub use terminal::search::{IncrementalSearch, SearchDirection, SearchOptions, is_word_char};
 
 // Re-export types from core that are part of our public API
 pub use par_term_emu_core_rust::terminal::{ClipboardEntry, ClipboardSlot, HyperlinkInfo};
@@ -1,10 +1,12 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use par_term_emu_core_rust::shell_integration::ShellIntegrationMarker;
 use par_term_emu_core_rust::terminal::CommandExecution;
 
 // Re-export ScrollbackMark from par-term-config (shared type used by both terminal and renderer)
+use par_term_config::MarkTrimPolicy;
 pub use par_term_config::ScrollbackMark;
 
 /// Maximum distance (in lines) between the A (PromptStart) and B (CommandStart)
@@ -33,6 +35,10 @@ pub struct CommandSnapshot {
     pub exit_code: Option<i32>,
     /// Elapsed time in milliseconds, or `None` if the command has not yet finished.
     pub duration_ms: Option<u64>,
+    /// Absolute scrollback lines holding the command's output: from the line
+    /// after its mark up to (excluding) the line where it finished.  `None`
+    /// until the command has been recorded against a mark.
+    pub output_lines: Option<Range<usize>>,
 }
 
 impl CommandSnapshot {
@@ -45,6 +51,7 @@ impl CommandSnapshot {
             end_time: command.end_time,
             exit_code: command.exit_code,
             duration_ms: command.duration_ms,
+            output_lines: None,
         }
     }
 }
@@ -103,6 +110,10 @@ pub struct ScrollbackMetadata {
     /// for the corresponding CommandStart (B). While set, B/C markers suppress
     /// prompt_line creation since A already created the entry.
     prompt_start_pending: bool,
+    /// True between a CommandStart (B) marker and the CommandExecuted (C)
+    /// that follows it.  The command then starts on the B line (where the
+    /// input begins), not on the C line below the input.
+    command_input_pending: bool,
 }
 
 impl ScrollbackMetadata {
@@ -127,6 +138,67 @@ impl ScrollbackMetadata {
         self.last_recorded_history_len = 0;
         self.current_command_start_time_ms = None;
         self.prompt_start_pending = false;
+        self.command_input_pending = false;
+    }
+
+    /// Re-index after `lines` lines were trimmed from the top of the scrollback.
+    ///
+    /// Every stored line index shifts down by `lines`, matching the cell trim,
+    /// so marks keep pointing at the same content.  Marks that would land
+    /// above line 0 are dropped, except that [`MarkTrimPolicy::KeepEarliest`]
+    /// keeps the newest of them at line 0 unless another mark already lands
+    /// there.
+    pub fn trim(&mut self, lines: usize, policy: MarkTrimPolicy) {
+        if lines == 0 {
+            return;
+        }
+
+        let kept = match policy {
+            MarkTrimPolicy::Drop => None,
+            MarkTrimPolicy::KeepEarliest => self
+                .prompt_lines
+                .iter()
+                .rev()
+                .find(|&&line| line < lines)
+                .copied()
+                .filter(|_| self.prompt_lines.binary_search(&lines).is_err()),
+        };
+        let shift = |line: usize| {
+            if Some(line) == kept {
+                Some(0)
+            } else {
+                line.checked_sub(lines)
+            }
+        };
+
+        // `kept` is the largest trimmed line, so the result stays sorted.
+        self.prompt_lines = self
+            .prompt_lines
+            .iter()
+            .filter_map(|&line| shift(line))
+            .collect();
+        self.line_to_command = self
+            .line_to_command
+            .drain()
+            .filter_map(|(line, id)| shift(line).map(|line| (line, id)))
+            .collect();
+        self.line_timestamps = self
+            .line_timestamps
+            .drain()
+            .filter_map(|(line, ts)| shift(line).map(|line| (line, ts)))
+            .collect();
+
+        let live: HashSet<usize> = self.line_to_command.values().copied().collect();
+        self.commands.retain(|id, _| live.contains(id));
+        for command in self.commands.values_mut() {
+            if let Some(range) = &mut command.output_lines {
+                *range = range.start.saturating_sub(lines)..range.end.saturating_sub(lines);
+            }
+        }
+
+        self.current_command_start = self.current_command_start.and_then(shift);
+        self.last_marker_line = self.last_marker_line.and_then(shift);
+        self.last_exit_code_line = self.last_exit_code_line.and_then(shift);
     }
 
     /// Apply the latest shell integration marker and update internal metadata.
@@ -153,9 +225,18 @@ impl ScrollbackMetadata {
             Some(ShellIntegrationMarker::PromptStart) if !repeat_marker => {
                 self.record_prompt_line(absolute_line, last_command.as_ref().map(|c| c.start_time));
                 self.prompt_start_pending = true;
+                self.command_input_pending = false;
+            }
+            Some(ShellIntegrationMarker::CommandExecuted) if self.command_input_pending => {
+                // The B marker already placed this command on its input line;
+                // C only marks where execution (and the duration) starts.
+                self.prompt_start_pending = false;
+                self.command_input_pending = false;
+                self.current_command_start_time_ms = Some(now_ms());
             }
             Some(ShellIntegrationMarker::CommandStart)
             | Some(ShellIntegrationMarker::CommandExecuted) => {
+                self.command_input_pending = marker == Some(ShellIntegrationMarker::CommandStart);
                 if !repeat_marker {
                     // Only record a prompt line if no PromptStart (A) marker has
                     // already created one for this prompt cycle. Multi-line prompts
@@ -201,6 +282,7 @@ impl ScrollbackMetadata {
                         end_time: Some(end_time),
                         exit_code: Some(exit_code),
                         duration_ms: Some(duration_ms),
+                        output_lines: None,
                     };
                     let start_line = self.finish_command(absolute_line, synthetic);
                     // Keep ids monotonic to avoid duplicate marks on repeated frames
@@ -249,6 +331,7 @@ impl ScrollbackMetadata {
                     end_time: Some(end_time),
                     exit_code: Some(code),
                     duration_ms: Some(duration_ms),
+                    output_lines: None,
                 };
                 let start_line = self.finish_command(candidate_line, synthetic);
                 self.last_recorded_history_len = self.last_recorded_history_len.saturating_add(1);
@@ -360,6 +443,20 @@ impl ScrollbackMetadata {
         }
     }
 
+    /// The last `n` completed commands, most recent first.
+    ///
+    /// Commands whose marks were trimmed out of the scrollback are no longer
+    /// available.
+    pub fn recent_commands(&self, n: usize) -> Vec<CommandSnapshot> {
+        let mut completed: Vec<&CommandSnapshot> = self
+            .commands
+            .values()
+            .filter(|cmd| cmd.end_time.is_some())
+            .collect();
+        completed.sort_by_key(|cmd| std::cmp::Reverse(cmd.id));
+        completed.into_iter().take(n).cloned().collect()
+    }
+
     fn record_prompt_line(&mut self, line: usize, timestamp: Option<u64>) {
         if let Err(pos) = self.prompt_lines.binary_search(&line) {
             self.prompt_lines.insert(pos, line);
@@ -369,7 +466,7 @@ impl ScrollbackMetadata {
         }
     }
 
-    fn finish_command(&mut self, end_line: usize, command: CommandSnapshot) -> usize {
+    fn finish_command(&mut self, end_line: usize, mut command: CommandSnapshot) -> usize {
         let start_line = self
             .current_command_start
             .take()
@@ -397,6 +494,8 @@ impl ScrollbackMetadata {
         // Ensure a mark exists even if no prompt marker was recorded.
         self.record_prompt_line(start_line, Some(command.start_time));
 
+        let output_start = start_line + 1;
+        command.output_lines = Some(output_start..end_line.max(output_start));
         self.line_to_command.insert(start_line, command.id);
         let start_time = command.start_time;
         self.commands.insert(command.id, command);
@@ -424,6 +523,7 @@ mod tests {
             end_time: Some(start_time + duration_ms),
             exit_code: Some(exit_code),
             duration_ms: Some(duration_ms),
+            output_lines: None,
         }
     }
 
@@ -581,6 +681,33 @@ mod tests {
         assert_eq!(marks[0].exit_code, Some(0));
     }
 
+    #[test]
+    fn command_executed_below_input_line_adds_no_mark() {
+        let mut meta = ScrollbackMetadata::new();
+
+        // Two-line prompt at 10-11; Enter moves the cursor to line 12
+        // before CommandExecuted (C), and the input line keeps the command.
+        meta.apply_event(Some(ShellIntegrationMarker::PromptStart), 10, 0, None, None);
+        for (marker, line) in [
+            (ShellIntegrationMarker::CommandStart, 11),
+            (ShellIntegrationMarker::CommandExecuted, 12),
+        ] {
+            meta.apply_event(Some(marker), line, 0, None, None);
+        }
+        meta.apply_event(
+            Some(ShellIntegrationMarker::CommandFinished),
+            14,
+            1,
+            Some(snapshot(0, 0, 1_000, 500)),
+            None,
+        );
+
+        let marks = meta.marks();
+        assert_eq!(marks.len(), 1);
+        assert_eq!(marks[0].line, 10);
+        assert_eq!(meta.recent_commands(1)[0].output_lines, Some(11..14));
+    }
+
     #[test]
     fn clear_resets_all_state() {
         let mut meta = ScrollbackMetadata::new();
@@ -744,4 +871,129 @@ mod tests {
         assert_eq!(marks[1].exit_code, Some(0));
         assert_eq!(marks[2].exit_code, Some(127));
     }
+
+    fn record_finished(meta: &mut ScrollbackMetadata, line: usize, id: usize) {
+        meta.apply_event(
+            Some(ShellIntegrationMarker::PromptStart),
+            line,
+            0,
+            None,
+            None,
+        );
+        meta.apply_event(
+            Some(ShellIntegrationMarker::CommandExecuted),
+            line,
+            0,
+            None,
+            None,
+        );
+        meta.apply_event(
+            Some(ShellIntegrationMarker::CommandFinished),
+            line,
+            id + 1,
+            Some(snapshot(id, 0, 1_000 + id as u64, 10)),
+            None,
+        );
+    }
+
+    #[test]
+    fn trim_shifts_marks_and_drops_trimmed_lines() {
+        let mut meta = ScrollbackMetadata::new();
+        for (id, line) in [3, 8, 20, 42].into_iter().enumerate() {
+            record_finished(&mut meta, line, id);
+        }
+
+        meta.trim(10, MarkTrimPolicy::Drop);
+
+        let lines: Vec<usize> = meta.marks().iter().map(|m| m.line).collect();
+        assert_eq!(lines, vec![10, 32]);
+        // Command info follows the shifted marks.
+        let meta_at_10 = meta.metadata_for_line(10).expect("metadata follows mark");
+        assert_eq!(meta_at_10.command.as_deref(), Some("cmd-2"));
+        assert_eq!(meta.previous_mark(32), Some(10));
+        assert_eq!(meta.commands.len(), 2);
+    }
+
+    #[test]
+    fn trim_keep_earliest_pins_newest_trimmed_mark_to_top() {
+        let mut meta = ScrollbackMetadata::new();
+        for (id, line) in [3, 8, 20].into_iter().enumerate() {
+            record_finished(&mut meta, line, id);
+        }
+
+        meta.trim(10, MarkTrimPolicy::KeepEarliest);
+
+        let marks = meta.marks();
+        let lines: Vec<usize> = marks.iter().map(|m| m.line).collect();
+        assert_eq!(lines, vec![0, 10]);
+        assert_eq!(marks[0].command.as_deref(), Some("cmd-1"));
+
+        // A mark already landing on line 0 wins over the trimmed ones.
+        meta.trim(10, MarkTrimPolicy::KeepEarliest);
+        let lines: Vec<usize> = meta.marks().iter().map(|m| m.line).collect();
+        assert_eq!(lines, vec![0]);
+        assert_eq!(meta.marks()[0].command.as_deref(), Some("cmd-2"));
+    }
+
+    fn run_command(meta: &mut ScrollbackMetadata, prompt_line: usize, end_line: usize, id: usize) {
+        meta.apply_event(
+            Some(ShellIntegrationMarker::PromptStart),
+            prompt_line,
+            id,
+            None,
+            None,
+        );
+        meta.apply_event(
+            Some(ShellIntegrationMarker::CommandExecuted),
+            prompt_line,
+            id,
+            None,
+            None,
+        );
+        meta.apply_event(
+            Some(ShellIntegrationMarker::CommandFinished),
+            end_line,
+            id + 1,
+            Some(snapshot(id, id as i32, 1_000 + id as u64, 10 * id as u64)),
+            None,
+        );
+    }
+
+    #[test]
+    fn recent_commands_are_most_recent_first_with_output_lines() {
+        let mut meta = ScrollbackMetadata::new();
+        run_command(&mut meta, 0, 4, 0);
+        run_command(&mut meta, 4, 5, 1);
+        run_command(&mut meta, 5, 12, 2);
+
+        let recent = meta.recent_commands(10);
+        let ids: Vec<usize> = recent.iter().map(|c| c.id).collect();
+        assert_eq!(ids, vec![2, 1, 0]);
+
+        assert_eq!(recent[0].command.as_deref(), Some("cmd-2"));
+        assert_eq!(recent[0].exit_code, Some(2));
+        assert_eq!(recent[0].duration_ms, Some(20));
+        assert_eq!(recent[0].output_lines, Some(6..12));
+        // A command that printed nothing has an empty range.
+        assert_eq!(recent[1].output_lines, Some(5..5));
+        assert_eq!(recent[2].output_lines, Some(1..4));
+    }
+
+    #[test]
+    fn recent_commands_caps_at_n_and_follows_trim() {
+        let mut meta = ScrollbackMetadata::new();
+        for id in 0..5 {
+            run_command(&mut meta, id * 10, id * 10 + 3, id);
+        }
+
+        let ids: Vec<usize> = meta.recent_commands(2).iter().map(|c| c.id).collect();
+        assert_eq!(ids, vec![4, 3]);
+        assert!(meta.recent_commands(0).is_empty());
+
+        meta.trim(25, MarkTrimPolicy::Drop);
+        let recent = meta.recent_commands(10);
+        let ids: Vec<usize> = recent.iter().map(|c| c.id).collect();
+        assert_eq!(ids, vec![4, 3]);
+        assert_eq!(recent[0].output_lines, Some(16..18));
+    }
 }
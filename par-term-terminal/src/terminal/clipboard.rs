@@ -1,6 +1,91 @@
+//! Clipboard access and clipboard history slots.
+//!
+//! The core emulator keeps a clipboard history per terminal, i.e. per pane.
+//! To share history between panes (`clipboard_scope: global | per_tab`), a
+//! [`SharedClipboardHistory`] store is attached with
+//! [`TerminalManager::set_shared_clipboard_history`]: entries recorded in the
+//! core's per-terminal history are moved into the shared store, and every
+//! history read is served from it.  Without a shared store each pane's
+//! history stays isolated.
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+use parking_lot::Mutex;
+
 use super::TerminalManager;
 pub use par_term_emu_core_rust::terminal::{ClipboardEntry, ClipboardSlot};
 
+/// Default maximum entries kept per slot (matches the core emulator).
+const DEFAULT_MAX_HISTORY_PER_SLOT: usize = 10;
+
+/// Every slot the core emulator can record history in.
+const ALL_SLOTS: [ClipboardSlot; 13] = [
+    ClipboardSlot::Primary,
+    ClipboardSlot::Clipboard,
+    ClipboardSlot::Selection,
+    ClipboardSlot::Custom(0),
+    ClipboardSlot::Custom(1),
+    ClipboardSlot::Custom(2),
+    ClipboardSlot::Custom(3),
+    ClipboardSlot::Custom(4),
+    ClipboardSlot::Custom(5),
+    ClipboardSlot::Custom(6),
+    ClipboardSlot::Custom(7),
+    ClipboardSlot::Custom(8),
+    ClipboardSlot::Custom(9),
+];
+
+/// Clipboard history shared by several terminals.
+pub type SharedClipboardHistory = Arc<Mutex<ClipboardHistoryStore>>;
+
+/// Clipboard history slots stored outside any single terminal.
+#[derive(Debug)]
+pub struct ClipboardHistoryStore {
+    slots: HashMap<ClipboardSlot, Vec<ClipboardEntry>>,
+    max_per_slot: usize,
+}
+
+impl Default for ClipboardHistoryStore {
+    fn default() -> Self {
+        Self {
+            slots: HashMap::new(),
+            max_per_slot: DEFAULT_MAX_HISTORY_PER_SLOT,
+        }
+    }
+}
+
+impl ClipboardHistoryStore {
+    /// Create an empty store wrapped for sharing between terminals.
+    pub fn new_shared() -> SharedClipboardHistory {
+        Arc::new(Mutex::new(Self::default()))
+    }
+
+    /// Append entries to a slot, keeping them in timestamp order and
+    /// dropping the oldest beyond the per-slot limit.
+    fn extend(&mut self, slot: ClipboardSlot, entries: Vec<ClipboardEntry>) {
+        let history = self.slots.entry(slot).or_default();
+        history.extend(entries);
+        history.sort_by_key(|entry| entry.timestamp);
+        let excess = history.len().saturating_sub(self.max_per_slot);
+        history.drain(..excess);
+    }
+
+    fn set_max_per_slot(&mut self, max: usize) {
+        self.max_per_slot = max;
+        for history in self.slots.values_mut() {
+            let excess = history.len().saturating_sub(max);
+            history.drain(..excess);
+        }
+    }
+}
+
+/// The process-wide store used by `clipboard_scope: global`.
+pub fn global_clipboard_history() -> SharedClipboardHistory {
+    static GLOBAL: OnceLock<SharedClipboardHistory> = OnceLock::new();
+    Arc::clone(GLOBAL.get_or_init(ClipboardHistoryStore::new_shared))
+}
+
 impl TerminalManager {
     /// Get the OSC 52 clipboard content most recently set by a program.
     ///
@@ -14,8 +99,45 @@ impl TerminalManager {
         term.get_clipboard()
     }
 
+    /// Share clipboard history slots through `store`, or isolate this
+    /// terminal's history again with `None`.
+    ///
+    /// History already recorded by this terminal moves into the new store.
+    pub fn set_shared_clipboard_history(&self, store: Option<SharedClipboardHistory>) {
+        *self.shared_clipboard.lock() = store;
+        self.absorb_clipboard_history();
+    }
+
+    /// The shared clipboard history store, if one is attached.
+    pub fn shared_clipboard_history(&self) -> Option<SharedClipboardHistory> {
+        self.shared_clipboard.lock().clone()
+    }
+
+    /// Move history the core recorded into the attached shared store.
+    ///
+    /// Returns the store, or `None` when history is per-terminal.
+    fn absorb_clipboard_history(&self) -> Option<SharedClipboardHistory> {
+        let store = self.shared_clipboard.lock().clone()?;
+        let pty = self.pty_session.lock();
+        let terminal = pty.terminal();
+        let mut term = terminal.write();
+        let mut shared = store.lock();
+        for slot in ALL_SLOTS {
+            let entries = term.get_clipboard_history(slot);
+            if !entries.is_empty() {
+                term.clear_clipboard_history(slot);
+                shared.extend(slot, entries);
+            }
+        }
+        drop(shared);
+        Some(store)
+    }
+
     /// Get clipboard history for a specific slot
     pub fn get_clipboard_history(&self, slot: ClipboardSlot) -> Vec<ClipboardEntry> {
+        if let Some(store) = self.absorb_clipboard_history() {
+            return store.lock().slots.get(&slot).cloned().unwrap_or_default();
+        }
         let pty = self.pty_session.lock();
         let terminal = pty.terminal();
         let term = terminal.write();
@@ -24,6 +146,13 @@ impl TerminalManager {
 
     /// Get the most recent clipboard entry for a slot
     pub fn get_latest_clipboard(&self, slot: ClipboardSlot) -> Option<ClipboardEntry> {
+        if let Some(store) = self.absorb_clipboard_history() {
+            return store
+                .lock()
+                .slots
+                .get(&slot)
+                .and_then(|history| history.last().cloned());
+        }
         let pty = self.pty_session.lock();
         let terminal = pty.terminal();
         let term = terminal.write();
@@ -36,6 +165,17 @@ impl TerminalManager {
         query: &str,
         slot: Option<ClipboardSlot>,
     ) -> Vec<ClipboardEntry> {
+        if let Some(store) = self.absorb_clipboard_history() {
+            let store = store.lock();
+            return store
+                .slots
+                .iter()
+                .filter(|(s, _)| slot.is_none_or(|wanted| **s == wanted))
+                .flat_map(|(_, history)| history.iter())
+                .filter(|entry| entry.content.contains(query))
+                .cloned()
+                .collect();
+        }
         let pty = self.pty_session.lock();
         let terminal = pty.terminal();
         let term = terminal.write();
@@ -49,14 +189,20 @@ impl TerminalManager {
         content: String,
         label: Option<String>,
     ) {
-        let pty = self.pty_session.lock();
-        let terminal = pty.terminal();
-        let mut term = terminal.write();
-        term.add_to_clipboard_history(slot, content, label);
+        {
+            let pty = self.pty_session.lock();
+            let terminal = pty.terminal();
+            let mut term = terminal.write();
+            term.add_to_clipboard_history(slot, content, label);
+        }
+        self.absorb_clipboard_history();
     }
 
     /// Clear clipboard history for a specific slot
     pub fn clear_clipboard_history(&self, slot: ClipboardSlot) {
+        if let Some(store) = self.shared_clipboard.lock().clone() {
+            store.lock().slots.remove(&slot);
+        }
         let pty = self.pty_session.lock();
         let terminal = pty.terminal();
         let mut term = terminal.write();
@@ -65,6 +211,9 @@ impl TerminalManager {
 
     /// Clear all clipboard history
     pub fn clear_all_clipboard_history(&self) {
+        if let Some(store) = self.shared_clipboard.lock().clone() {
+            store.lock().slots.clear();
+        }
         let pty = self.pty_session.lock();
         let terminal = pty.terminal();
         let mut term = terminal.write();
@@ -89,9 +238,76 @@ impl TerminalManager {
 
     /// Set maximum clipboard history entries per slot
     pub fn set_max_clipboard_sync_history(&self, max: usize) {
+        if let Some(store) = self.shared_clipboard.lock().clone() {
+            store.lock().set_max_per_slot(max);
+        }
         let pty = self.pty_session.lock();
         let terminal = pty.terminal();
         let mut term = terminal.write();
         term.set_max_clipboard_sync_history(max);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contents(terminal: &TerminalManager, slot: ClipboardSlot) -> Vec<String> {
+        terminal
+            .get_clipboard_history(slot)
+            .into_iter()
+            .map(|entry| entry.content)
+            .collect()
+    }
+
+    #[test]
+    fn per_pane_history_is_isolated() {
+        let pane1 = TerminalManager::new(20, 5).unwrap();
+        let pane2 = TerminalManager::new(20, 5).unwrap();
+
+        pane1.add_to_clipboard_history(ClipboardSlot::Custom(0), "A".to_string(), None);
+
+        assert_eq!(contents(&pane1, ClipboardSlot::Custom(0)), vec!["A"]);
+        assert!(contents(&pane2, ClipboardSlot::Custom(0)).is_empty());
+    }
+
+    #[test]
+    fn shared_history_is_visible_to_every_attached_pane() {
+        let store = ClipboardHistoryStore::new_shared();
+        let pane1 = TerminalManager::new(20, 5).unwrap();
+        let pane2 = TerminalManager::new(20, 5).unwrap();
+        pane1.set_shared_clipboard_history(Some(Arc::clone(&store)));
+        pane2.set_shared_clipboard_history(Some(Arc::clone(&store)));
+
+        pane1.add_to_clipboard_history(ClipboardSlot::Custom(0), "A".to_string(), None);
+
+        assert_eq!(contents(&pane2, ClipboardSlot::Custom(0)), vec!["A"]);
+        assert_eq!(
+            pane2
+                .get_latest_clipboard(ClipboardSlot::Custom(0))
+                .map(|entry| entry.content),
+            Some("A".to_string())
+        );
+
+        // Clearing from either pane clears the shared slot.
+        pane2.clear_clipboard_history(ClipboardSlot::Custom(0));
+        assert!(contents(&pane1, ClipboardSlot::Custom(0)).is_empty());
+    }
+
+    #[test]
+    fn shared_store_keeps_newest_entries_per_slot() {
+        let store = ClipboardHistoryStore::new_shared();
+        let pane = TerminalManager::new(20, 5).unwrap();
+        pane.set_shared_clipboard_history(Some(store));
+        pane.set_max_clipboard_sync_history(2);
+
+        for text in ["one", "two", "three"] {
+            pane.add_to_clipboard_history(ClipboardSlot::Primary, text.to_string(), None);
+        }
+
+        assert_eq!(
+            contents(&pane, ClipboardSlot::Primary),
+            vec!["two", "three"]
+        );
+    }
+}
@@ -0,0 +1,145 @@
+//! Color reduction for full-screen applications.
+//!
+//! Some full-screen apps pick garish or unreadable 24-bit colors, or render
+//! better with the user's theme palette.  With an alternate-screen color mode
+//! set (per profile), cell colors are reduced to the 256-color palette or to
+//! the theme's 16 ANSI colors while the alternate screen is active.  The
+//! primary screen and scrollback always keep their original colors.
+
+use super::TerminalManager;
+use par_term_config::{ColorMode, Theme};
+use par_term_emu_core_rust::color::{Color as TermColor, NamedColor};
+
+/// Reduce `color` to what `mode` can express.
+///
+/// Named colors are left alone so they keep following the theme.
+pub(crate) fn limit_color(color: TermColor, mode: ColorMode, theme: &Theme) -> TermColor {
+    match (mode, color) {
+        (ColorMode::Truecolor, _) | (_, TermColor::Named(_)) => color,
+        (ColorMode::Palette256, TermColor::Rgb(r, g, b)) => {
+            TermColor::Indexed(nearest_256(r, g, b))
+        }
+        (ColorMode::Palette256, TermColor::Indexed(_)) => color,
+        (ColorMode::Ansi16, TermColor::Indexed(index)) if index < 16 => {
+            TermColor::Named(NamedColor::from_u8(index))
+        }
+        (ColorMode::Ansi16, _) => {
+            let (r, g, b) = color.to_rgb();
+            TermColor::Named(NamedColor::from_u8(nearest_ansi(r, g, b, theme)))
+        }
+    }
+}
+
+/// Closest entry of the 216-color cube or grayscale ramp (indices 16-255),
+/// using the same levels the emulator renders indexed colors with.
+fn nearest_256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |v: u8| (v as u16 + 25) / 51;
+    let (lr, lg, lb) = (level(r), level(g), level(b));
+    let cube = 16 + (lr * 36 + lg * 6 + lb) as u8;
+    let cube_rgb = ((lr * 51) as u8, (lg * 51) as u8, (lb * 51) as u8);
+
+    let average = (r as u16 + g as u16 + b as u16) / 3;
+    let step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray = 8 + step * 10;
+
+    if distance((r, g, b), (gray, gray, gray)) < distance((r, g, b), cube_rgb) {
+        232 + step
+    } else {
+        cube
+    }
+}
+
+/// Index (0-15) of the theme's ANSI color closest to `(r, g, b)`.
+fn nearest_ansi(r: u8, g: u8, b: u8, theme: &Theme) -> u8 {
+    (0..16u8)
+        .min_by_key(|&index| {
+            let c = theme.ansi_color(index);
+            distance((r, g, b), (c.r, c.g, c.b))
+        })
+        .unwrap_or(7)
+}
+
+/// Squared RGB distance weighted toward green, where the eye is most
+/// sensitive.
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    2 * d(a.0, b.0) + 4 * d(a.1, b.1) + 3 * d(a.2, b.2)
+}
+
+impl TerminalManager {
+    /// Set the color depth used while the alternate screen is active
+    /// (`None` renders colors unchanged).
+    pub fn set_alt_screen_color_mode(&mut self, mode: Option<ColorMode>) {
+        self.alt_screen_color_mode = mode.filter(|m| *m != ColorMode::Truecolor);
+    }
+
+    /// Color depth used while the alternate screen is active.
+    pub fn alt_screen_color_mode(&self) -> Option<ColorMode> {
+        self.alt_screen_color_mode
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fg_at(manager: &TerminalManager, col: usize) -> [u8; 4] {
+        manager.get_cells_with_scrollback(0, None, false, None)[col].fg_color
+    }
+
+    #[test]
+    fn rgb_maps_to_nearest_palette_entry() {
+        assert_eq!(nearest_256(255, 0, 0), 196);
+        assert_eq!(nearest_256(0, 0, 0), 16);
+        assert_eq!(nearest_256(128, 128, 128), 232 + 12);
+        let theme = Theme::default();
+        let red = theme.ansi_color(1);
+        let near_red = TermColor::Rgb(red.r.saturating_add(6), red.g, red.b);
+        assert_eq!(
+            limit_color(near_red, ColorMode::Ansi16, &theme),
+            TermColor::Named(NamedColor::Red)
+        );
+        assert_eq!(
+            limit_color(TermColor::Indexed(9), ColorMode::Ansi16, &theme),
+            TermColor::Named(NamedColor::BrightRed)
+        );
+        assert_eq!(
+            limit_color(TermColor::Rgb(1, 2, 3), ColorMode::Truecolor, &theme),
+            TermColor::Rgb(1, 2, 3)
+        );
+    }
+
+    #[test]
+    fn colors_are_reduced_only_while_alt_screen_is_active() {
+        let mut manager = TerminalManager::new(20, 5).unwrap();
+        manager.set_alt_screen_color_mode(Some(ColorMode::Ansi16));
+        let theme = Theme::default();
+        let expected = |index: u8| {
+            let c = theme.ansi_color(index);
+            [c.r, c.g, c.b, 255]
+        };
+        let truecolor = b"\x1b[38;2;250;12;34mX\x1b[0m";
+
+        manager.process_data(truecolor);
+        assert_eq!(fg_at(&manager, 0), [250, 12, 34, 255], "primary screen");
+
+        manager.process_data(b"\x1b[?1049h\x1b[H");
+        manager.process_data(truecolor);
+        let reduced = fg_at(&manager, 0);
+        assert_ne!(reduced, [250, 12, 34, 255], "alt screen");
+        assert!((0..16).any(|i| reduced == expected(i)), "{reduced:?}");
+
+        manager.process_data(b"\x1b[?1049l");
+        assert_eq!(fg_at(&manager, 0), [250, 12, 34, 255], "back on primary");
+    }
+
+    #[test]
+    fn no_reduction_without_mode() {
+        let mut manager = TerminalManager::new(20, 5).unwrap();
+        manager.process_data(b"\x1b[?1049h\x1b[38;2;250;12;34mX");
+        assert_eq!(fg_at(&manager, 0), [250, 12, 34, 255]);
+
+        manager.set_alt_screen_color_mode(Some(ColorMode::Truecolor));
+        assert_eq!(manager.alt_screen_color_mode(), None);
+    }
+}
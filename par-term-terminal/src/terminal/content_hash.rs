@@ -0,0 +1,156 @@
+//! Stable content hashing for external render drivers.
+//!
+//! Headless renderers and tests cannot rely on the frontend's cell cache to
+//! decide whether a frame is needed.  [`TerminalManager::update_generation`]
+//! tells them *something* changed; [`TerminalManager::content_hash`] tells
+//! them whether the visible grid actually looks different (a generation bump
+//! that rewrites identical content yields the same hash).
+//!
+//! The hash is FNV-1a over the characters, colors and attributes of every
+//! visible cell, so it is deterministic across runs and platforms.
+
+use super::TerminalManager;
+use par_term_emu_core_rust::cell::Cell as TermCell;
+use par_term_emu_core_rust::color::Color;
+use par_term_emu_core_rust::grid::Grid;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Minimal FNV-1a 64-bit accumulator.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_color(&mut self, color: Color) {
+        match color {
+            Color::Named(named) => self.write(&[0, named as u8]),
+            Color::Indexed(idx) => self.write(&[1, idx]),
+            Color::Rgb(r, g, b) => self.write(&[2, r, g, b]),
+        }
+    }
+
+    fn write_cell(&mut self, cell: &TermCell) {
+        self.write_u32(cell.c() as u32);
+        for &ch in cell.combining() {
+            self.write_u32(ch as u32);
+        }
+        self.write_color(cell.fg());
+        self.write_color(cell.bg());
+        match cell.underline_color() {
+            Some(color) => self.write_color(color),
+            None => self.write(&[0xff]),
+        }
+        let flags = cell.flags();
+        self.write(&flags.to_bitflags().to_le_bytes());
+        self.write(&[flags.underline_style as u8]);
+        self.write_u32(flags.hyperlink_id.map_or(0, |id| id.get()));
+    }
+}
+
+/// Hash a single row of cells.
+pub(crate) fn row_content_hash(cells: &[TermCell]) -> u64 {
+    let mut hasher = Fnv1a::new();
+    for cell in cells {
+        hasher.write_cell(cell);
+    }
+    hasher.0
+}
+
+/// Hash the visible rows of `grid` (dimensions included).
+pub(crate) fn grid_content_hash(grid: &Grid) -> u64 {
+    let mut hasher = Fnv1a::new();
+    hasher.write_u32(grid.cols() as u32);
+    hasher.write_u32(grid.rows() as u32);
+    for row in 0..grid.rows() {
+        if let Some(cells) = grid.row(row) {
+            for cell in cells {
+                hasher.write_cell(cell);
+            }
+        }
+    }
+    hasher.0
+}
+
+impl TerminalManager {
+    /// Stable hash of the visible grid (active screen, no scrollback).
+    ///
+    /// Identical content always produces the same value, so external render
+    /// drivers can skip frames whose content did not change even when
+    /// [`TerminalManager::update_generation`] advanced.  Cursor position and
+    /// selection are not included.
+    pub fn content_hash(&self) -> u64 {
+        let pty = self.pty_session.lock();
+        let terminal = pty.terminal();
+        let term = terminal.read();
+        grid_content_hash(term.active_grid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(manager: &TerminalManager, data: &[u8]) {
+        let terminal = manager.terminal();
+        terminal.write().process(data);
+    }
+
+    #[test]
+    fn identical_grids_hash_equal() {
+        let a = TerminalManager::new(20, 5).unwrap();
+        let b = TerminalManager::new(20, 5).unwrap();
+        process(&a, b"hello\r\n\x1b[31mworld");
+        process(&b, b"hello\r\n\x1b[31mworld");
+        assert_eq!(a.content_hash(), b.content_hash());
+    }
+
+    #[test]
+    fn single_cell_change_changes_hash() {
+        let manager = TerminalManager::new(20, 5).unwrap();
+        process(&manager, b"hello");
+        let before = manager.content_hash();
+
+        // Overwrite one character with a different one.
+        process(&manager, b"\x1b[1;2Ha");
+        assert_ne!(manager.content_hash(), before);
+    }
+
+    #[test]
+    fn attribute_change_changes_hash() {
+        let manager = TerminalManager::new(20, 5).unwrap();
+        process(&manager, b"x");
+        let before = manager.content_hash();
+
+        // Same character, different foreground color.
+        process(&manager, b"\x1b[1;1H\x1b[32mx");
+        assert_ne!(manager.content_hash(), before);
+    }
+
+    #[test]
+    fn rewriting_same_content_keeps_hash() {
+        let manager = TerminalManager::new(20, 5).unwrap();
+        process(&manager, b"same");
+        let before = manager.content_hash();
+        let generation = manager.update_generation();
+
+        process(&manager, b"\x1b[1;1Hsame");
+        assert_eq!(manager.content_hash(), before);
+        // Generation is a cheap "maybe changed" signal; the hash is exact.
+        assert!(manager.update_generation() >= generation);
+    }
+}
@@ -0,0 +1,187 @@
+//! Per-screen DECSCUSR cursor style tracking for [`TerminalManager`].
+//!
+//! Full-screen apps (vim, htop, less) usually set their own cursor shape via
+//! DECSCUSR after switching to the alternate screen and do not always restore
+//! it on exit.  The core library swaps the whole cursor on 1049 switches, but
+//! a style applied by the frontend while the alternate screen is active (e.g.
+//! a config change) lands on the alternate cursor and is lost on exit.
+//!
+//! [`CursorStyleTracker`] remembers the last style observed on the primary
+//! screen and re-applies it when the terminal returns from the alternate
+//! screen.  When `lock_cursor_style` is enabled the configured style wins over
+//! anything the app requested.
+
+use super::TerminalManager;
+use par_term_emu_core_rust::cursor::CursorStyle;
+
+/// Cursor style bookkeeping for the primary and alternate screens.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct CursorStyleTracker {
+    /// Last style observed (or applied) while the primary screen was active.
+    primary: Option<CursorStyle>,
+    /// Last style observed while the alternate screen was active.
+    alt: Option<CursorStyle>,
+    /// Style most recently applied by the frontend via `set_cursor_style`.
+    configured: Option<CursorStyle>,
+    /// Alternate screen state seen on the previous sync.
+    alt_active: bool,
+}
+
+impl CursorStyleTracker {
+    /// Record a style applied by the frontend.
+    ///
+    /// The configured style also becomes the primary-screen style so that a
+    /// config change made while an alternate-screen app is running survives
+    /// the app's exit.
+    pub(crate) fn record_configured(&mut self, style: CursorStyle, alt_active: bool) {
+        self.configured = Some(style);
+        self.primary = Some(style);
+        if alt_active {
+            self.alt = Some(style);
+        }
+    }
+
+    /// Observe the terminal's current screen and cursor style.
+    ///
+    /// Returns the style that must be written back to the terminal when a
+    /// screen switch requires a restore, or `None` when nothing changes.
+    pub(crate) fn observe(
+        &mut self,
+        alt_active: bool,
+        current: CursorStyle,
+        lock_cursor_style: bool,
+    ) -> Option<CursorStyle> {
+        let switched = alt_active != self.alt_active;
+        self.alt_active = alt_active;
+
+        if switched && !alt_active {
+            // Leaving the alternate screen: restore the primary-screen style.
+            let restore = if lock_cursor_style {
+                self.configured.or(self.primary)
+            } else {
+                self.primary.or(self.configured)
+            };
+            return match restore {
+                Some(style) if style != current => {
+                    self.primary = Some(style);
+                    Some(style)
+                }
+                _ => {
+                    self.primary = Some(current);
+                    None
+                }
+            };
+        }
+
+        if alt_active {
+            self.alt = Some(current);
+        } else {
+            self.primary = Some(current);
+        }
+        None
+    }
+
+    /// Style remembered for the requested screen.
+    pub(crate) fn style_for(&self, alt_screen: bool) -> Option<CursorStyle> {
+        if alt_screen { self.alt } else { self.primary }
+    }
+}
+
+impl TerminalManager {
+    /// Track the cursor style per screen and restore the primary-screen style
+    /// when the terminal leaves the alternate screen.
+    ///
+    /// Call once per frame (or whenever alt-screen state is sampled).  With
+    /// `lock_cursor_style` enabled the style last applied via
+    /// [`TerminalManager::set_cursor_style`] is restored instead of the style
+    /// the app left behind.  Returns `true` if the terminal's style changed.
+    pub fn sync_cursor_style_for_screen(&self, lock_cursor_style: bool) -> bool {
+        let pty = self.pty_session.lock();
+        let terminal = pty.terminal();
+        let mut term = terminal.write();
+
+        let alt_active = term.is_alt_screen_active();
+        let current = term.cursor().style();
+        let restore =
+            self.cursor_style_tracker
+                .lock()
+                .observe(alt_active, current, lock_cursor_style);
+
+        match restore {
+            Some(style) => {
+                log::debug!("Restoring primary-screen cursor style {:?}", style);
+                term.set_cursor_style(style);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Cursor style remembered for the primary (`alt_screen == false`) or
+    /// alternate screen, if one has been observed yet.
+    pub fn screen_cursor_style(&self, alt_screen: bool) -> Option<CursorStyle> {
+        self.cursor_style_tracker.lock().style_for(alt_screen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(manager: &TerminalManager, data: &[u8]) {
+        let terminal = manager.terminal();
+        terminal.write().process(data);
+    }
+
+    #[test]
+    fn leaving_alt_screen_restores_primary_style() {
+        let mut manager = TerminalManager::new(80, 24).unwrap();
+        manager.set_cursor_style(CursorStyle::SteadyBar);
+        manager.sync_cursor_style_for_screen(false);
+
+        // Enter alt screen and let the app pick an underline cursor.
+        process(&manager, b"\x1b[?1049h\x1b[3 q");
+        manager.sync_cursor_style_for_screen(false);
+        assert!(manager.is_alt_screen_active());
+        assert_eq!(manager.cursor_style(), CursorStyle::BlinkingUnderline);
+        assert_eq!(
+            manager.screen_cursor_style(true),
+            Some(CursorStyle::BlinkingUnderline)
+        );
+
+        process(&manager, b"\x1b[?1049l");
+        manager.sync_cursor_style_for_screen(false);
+        assert!(!manager.is_alt_screen_active());
+        assert_eq!(manager.cursor_style(), CursorStyle::SteadyBar);
+    }
+
+    #[test]
+    fn config_change_during_alt_screen_survives_exit() {
+        let mut manager = TerminalManager::new(80, 24).unwrap();
+        manager.set_cursor_style(CursorStyle::SteadyBlock);
+        manager.sync_cursor_style_for_screen(false);
+
+        process(&manager, b"\x1b[?1049h");
+        manager.sync_cursor_style_for_screen(false);
+        manager.set_cursor_style(CursorStyle::BlinkingBar);
+
+        process(&manager, b"\x1b[?1049l");
+        assert!(manager.sync_cursor_style_for_screen(false));
+        assert_eq!(manager.cursor_style(), CursorStyle::BlinkingBar);
+    }
+
+    #[test]
+    fn lock_restores_configured_style_over_app_style() {
+        let mut manager = TerminalManager::new(80, 24).unwrap();
+        manager.set_cursor_style(CursorStyle::SteadyUnderline);
+        // App changes the primary-screen style, then runs a full-screen program.
+        process(&manager, b"\x1b[6 q");
+        manager.sync_cursor_style_for_screen(true);
+
+        process(&manager, b"\x1b[?1049h\x1b[2 q");
+        manager.sync_cursor_style_for_screen(true);
+        process(&manager, b"\x1b[?1049l");
+        manager.sync_cursor_style_for_screen(true);
+        assert_eq!(manager.cursor_style(), CursorStyle::SteadyUnderline);
+    }
+}
@@ -0,0 +1,127 @@
+//! Working directory changes reported by the shell.
+//!
+//! Shells with shell integration report their directory with OSC 7 at every
+//! prompt, and the emulator keeps the recent reports with their timestamps.
+//! Most reports repeat the directory the shell was already in; only the ones
+//! that move it somewhere else (a `cd`) count as changes here.  The first
+//! report of a session is not a change either, since it only tells where the
+//! shell started.
+
+use super::TerminalManager;
+use par_term_emu_core_rust::terminal::CwdChange;
+
+/// Whether `change` moved the shell to another directory.
+pub fn is_directory_change(change: &CwdChange) -> bool {
+    change
+        .old_cwd
+        .as_deref()
+        .is_some_and(|old| old != change.new_cwd)
+}
+
+/// The most recent entry of `history` that moved the shell to another
+/// directory.
+pub fn last_directory_change(history: &[CwdChange]) -> Option<&CwdChange> {
+    history
+        .iter()
+        .rev()
+        .find(|change| is_directory_change(change))
+}
+
+/// The latest of several shells' directory changes, such as the
+/// [`TerminalManager::last_cwd_change`] of each pane in a window.
+pub fn most_recent_change(changes: impl IntoIterator<Item = CwdChange>) -> Option<CwdChange> {
+    changes.into_iter().max_by_key(|change| change.timestamp)
+}
+
+impl TerminalManager {
+    /// The shell's most recent change of directory, if it has reported one.
+    ///
+    /// Unlike [`TerminalManager::poll_cwd_events`] this does not consume
+    /// anything, and it skips reports that repeat the current directory.
+    pub fn last_cwd_change(&self) -> Option<CwdChange> {
+        let pty = self.pty_session.lock();
+        let terminal = pty.terminal();
+        let term = terminal.read();
+        last_directory_change(term.get_cwd_history()).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(old: Option<&str>, new: &str, timestamp: u64) -> CwdChange {
+        CwdChange {
+            old_cwd: old.map(String::from),
+            new_cwd: new.to_string(),
+            hostname: None,
+            username: None,
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn repeated_and_initial_reports_are_not_changes() {
+        assert!(!is_directory_change(&change(None, "/home", 1)));
+        assert!(!is_directory_change(&change(Some("/home"), "/home", 2)));
+        assert!(is_directory_change(&change(Some("/home"), "/tmp", 3)));
+
+        let history = [
+            change(None, "/home", 1),
+            change(Some("/home"), "/srv", 2),
+            change(Some("/srv"), "/srv", 3),
+        ];
+        assert_eq!(last_directory_change(&history).unwrap().timestamp, 2);
+        assert!(last_directory_change(&history[..1]).is_none());
+    }
+
+    #[test]
+    fn last_change_follows_osc7_reports() {
+        let manager = TerminalManager::new(20, 4).unwrap();
+        manager.process_data(b"\x1b]7;file:///home/user\x07");
+        assert!(manager.last_cwd_change().is_none());
+
+        manager.process_data(b"\x1b]7;file:///tmp/a\x07");
+        manager.process_data(b"\x1b]7;file:///tmp/b\x07");
+        // The next prompt reports the same directory again
+        manager.process_data(b"\x1b]7;file:///tmp/b\x07");
+
+        let last = manager.last_cwd_change().unwrap();
+        assert_eq!(last.old_cwd.as_deref(), Some("/tmp/a"));
+        assert_eq!(last.new_cwd, "/tmp/b");
+        assert_eq!(manager.shell_integration_cwd().as_deref(), Some("/tmp/b"));
+
+        // Polling the change events leaves the history alone
+        assert!(!manager.poll_cwd_events().is_empty());
+        assert_eq!(manager.last_cwd_change().unwrap().new_cwd, "/tmp/b");
+    }
+
+    #[test]
+    fn most_recent_change_across_shells_wins() {
+        let changes = [
+            change(Some("/home"), "/srv", 20),
+            change(Some("/home"), "/tmp", 30),
+            change(Some("/tmp"), "/var", 10),
+        ];
+        assert_eq!(most_recent_change(changes).unwrap().new_cwd, "/tmp");
+        assert!(most_recent_change(Vec::new()).is_none());
+
+        let first = TerminalManager::new(20, 4).unwrap();
+        let second = TerminalManager::new(20, 4).unwrap();
+        first.process_data(b"\x1b]7;file:///home\x07\x1b]7;file:///srv\x07");
+        second.process_data(b"\x1b]7;file:///home\x07");
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        second.process_data(b"\x1b]7;file:///opt/project\x07");
+        // Later reports of an unchanged directory don't count as changes
+        first.process_data(b"\x1b]7;file:///srv\x07");
+
+        let latest = most_recent_change(
+            [&first, &second]
+                .iter()
+                .filter_map(|manager| manager.last_cwd_change()),
+        )
+        .unwrap();
+        assert_eq!(latest.new_cwd, "/opt/project");
+        assert_eq!(latest, second.last_cwd_change().unwrap());
+    }
+}
@@ -0,0 +1,160 @@
+//! Desktop notifications requested by applications.
+//!
+//! The emulator parses three notification sequences into one queue:
+//!
+//! - OSC 9 (iTerm2): `OSC 9 ; body ST`, with no title
+//! - OSC 777 (urxvt): `OSC 777 ; notify ; title ; body ST`
+//! - OSC 99 (Kitty): metadata-keyed chunks with an id, urgency and actions
+//!
+//! [`DesktopNotification`] is the normalized form handed to the notification
+//! layer: an empty title is no title, and surrounding whitespace is dropped.
+//! A program in a loop can send these as fast as it likes, so each terminal
+//! passes them through a [`NotificationRateLimiter`] and drops the excess.
+
+use super::TerminalManager;
+use par_term_emu_core_rust::terminal::{Notification, Urgency};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Span over which the notification rate limit is counted.
+pub const NOTIFICATION_RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// A notification an application asked the terminal to show.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DesktopNotification {
+    /// Title, if the sequence carried a non-empty one (never for OSC 9)
+    pub title: Option<String>,
+    pub body: String,
+    /// OSC 99 `i=` identifier, used to replace an earlier notification
+    pub id: Option<String>,
+    pub urgency: Urgency,
+    /// OSC 99 `a=` actions such as `focus` or `report`
+    pub actions: Vec<String>,
+}
+
+impl From<Notification> for DesktopNotification {
+    fn from(notification: Notification) -> Self {
+        let title = notification.title.trim();
+        Self {
+            title: (!title.is_empty()).then(|| title.to_string()),
+            body: notification.message.trim().to_string(),
+            id: notification.id,
+            urgency: notification.urgency,
+            actions: notification.actions,
+        }
+    }
+}
+
+/// Sliding-window limit on how many notifications one terminal may raise.
+#[derive(Debug, Default)]
+pub struct NotificationRateLimiter {
+    /// When the notifications let through in the current window were raised
+    recent: VecDeque<Instant>,
+}
+
+impl NotificationRateLimiter {
+    /// Whether a notification raised at `now` may be shown when at most
+    /// `max_per_window` are allowed per [`NOTIFICATION_RATE_WINDOW`]
+    /// (0 = no limit).  Allowed notifications count against the limit.
+    pub fn allow(&mut self, now: Instant, max_per_window: u32) -> bool {
+        while self
+            .recent
+            .front()
+            .is_some_and(|&at| now.duration_since(at) >= NOTIFICATION_RATE_WINDOW)
+        {
+            self.recent.pop_front();
+        }
+        if max_per_window > 0 && self.recent.len() >= max_per_window as usize {
+            return false;
+        }
+        self.recent.push_back(now);
+        true
+    }
+}
+
+impl TerminalManager {
+    /// Take the pending OSC 9/777/99 notifications, dropping those over
+    /// `max_per_minute` for this terminal (0 = no limit).
+    pub fn take_desktop_notifications(&self, max_per_minute: u32) -> Vec<DesktopNotification> {
+        let notifications = self.take_notifications();
+        if notifications.is_empty() {
+            return Vec::new();
+        }
+        let now = Instant::now();
+        let mut limiter = self.notification_limiter.lock();
+        notifications
+            .into_iter()
+            .map(DesktopNotification::from)
+            .filter(|notification| {
+                let allowed = limiter.allow(now, max_per_minute);
+                if !allowed {
+                    log::debug!("Rate limited notification: {notification:?}");
+                }
+                allowed
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notifications(data: &[u8]) -> Vec<DesktopNotification> {
+        let manager = TerminalManager::new(20, 4).unwrap();
+        manager.process_data(data);
+        manager.take_desktop_notifications(0)
+    }
+
+    #[test]
+    fn osc9_has_a_body_and_no_title() {
+        assert_eq!(
+            notifications(b"\x1b]9;Build finished\x07"),
+            [DesktopNotification {
+                title: None,
+                body: "Build finished".to_string(),
+                id: None,
+                urgency: Urgency::default(),
+                actions: Vec::new(),
+            }]
+        );
+    }
+
+    #[test]
+    fn osc777_carries_title_and_body() {
+        let parsed = notifications(b"\x1b]777;notify;Deploy;All hosts updated\x1b\\");
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].title.as_deref(), Some("Deploy"));
+        assert_eq!(parsed[0].body, "All hosts updated");
+
+        // An empty title is normalized away, like OSC 9's
+        let untitled = notifications(b"\x1b]777;notify; ;Done\x07");
+        assert_eq!(untitled[0].title, None);
+        assert_eq!(untitled[0].body, "Done");
+    }
+
+    #[test]
+    fn rate_limit_drops_notifications_over_the_limit() {
+        let mut limiter = NotificationRateLimiter::default();
+        let start = Instant::now();
+        assert!(limiter.allow(start, 2));
+        assert!(limiter.allow(start + Duration::from_secs(1), 2));
+        assert!(!limiter.allow(start + Duration::from_secs(2), 2));
+        // The first one leaves the window
+        assert!(limiter.allow(start + NOTIFICATION_RATE_WINDOW, 2));
+        assert!(!limiter.allow(start + NOTIFICATION_RATE_WINDOW, 2));
+        // No limit
+        assert!((0..100).all(|_| limiter.allow(start, 0)));
+
+        let manager = TerminalManager::new(20, 4).unwrap();
+        manager.process_data(b"\x1b]9;one\x07\x1b]9;two\x07\x1b]9;three\x07");
+        let bodies: Vec<_> = manager
+            .take_desktop_notifications(2)
+            .into_iter()
+            .map(|notification| notification.body)
+            .collect();
+        assert_eq!(bodies, ["one", "two"]);
+        manager.process_data(b"\x1b]9;four\x07");
+        assert!(manager.take_desktop_notifications(2).is_empty());
+    }
+}
@@ -0,0 +1,967 @@
+//! Scrollback export as plain text, ANSI, or HTML.
+//!
+//! [`TerminalManager::export_scrollback`] renders a range of absolute lines
+//! (0 = oldest scrollback line, continuing through the visible screen) in
+//! one of the [`ScrollbackExportFormat`]s.  Each row is first reduced to
+//! [`StyledSegment`]s — runs of cells sharing the same style, with the
+//! default cell colors left unset — which [`segments_to_plain_text`],
+//! [`segments_to_ansi`] and [`segments_to_html`] then serialize.  Soft-wrapped
+//! rows are joined into one line and trailing blanks are trimmed.
+//!
+//! [`TerminalManager::dump_scrollback`] streams the whole buffer as plain
+//! text to a writer a chunk of rows at a time, optionally with a header line
+//! for each shell-integration command ([`ScrollbackDumpOptions`]).
+//!
+//! [`TerminalManager::read_text`] uses the same path to return the visible
+//! screen (plus optional scrollback) as the text a user would copy, and
+//! [`TerminalManager::export_selection_html`] to turn a selection into an
+//! HTML fragment for pasting colored output into documents or email.
+
+use std::fmt::Write as _;
+use std::io;
+use std::ops::Range;
+
+use anyhow::{Result, bail};
+use par_term_config::{Cell, CopyLineEnding, ScrollbackMark};
+use par_term_emu_core_rust::grid::Grid;
+
+use super::TerminalManager;
+use super::rendering::RowRenderContext;
+use super::search::GridLines;
+
+/// Rows rendered per chunk by [`TerminalManager::dump_scrollback`].
+const DUMP_CHUNK_ROWS: usize = 1000;
+
+/// The active grid as a scrollback dump found it, so later chunks can be
+/// rebased on lines evicted from the top of a full scrollback meanwhile.
+struct DumpAnchor {
+    /// Lines ever pushed into the scrollback
+    scrolled: usize,
+    /// Lines evicted from the top of the scrollback
+    evicted: usize,
+    /// Rows in the buffer, scrollback plus screen
+    total: usize,
+}
+
+/// Rendered lines of one dump chunk, each with the row it starts on.
+type DumpLines = Vec<(usize, Vec<StyledSegment>)>;
+
+/// Output format for [`TerminalManager::export_scrollback`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollbackExportFormat {
+    /// Plain text without styling
+    Text,
+    /// Text with SGR escape sequences (24-bit colors)
+    Ansi,
+    /// Standalone HTML document with inline styles
+    Html,
+}
+
+impl ScrollbackExportFormat {
+    /// Conventional file extension for the format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ScrollbackExportFormat::Text => "txt",
+            ScrollbackExportFormat::Ansi => "ans",
+            ScrollbackExportFormat::Html => "html",
+        }
+    }
+}
+
+/// Options for [`TerminalManager::dump_scrollback_with`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScrollbackDumpOptions {
+    /// Write a `#` header line before each command's prompt, with the
+    /// command text, exit code, start time and duration when known
+    pub command_headers: bool,
+}
+
+/// A run of text with one style.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StyledSegment {
+    /// Text of the run
+    pub text: String,
+    /// Foreground color (`None` = theme default)
+    pub fg: Option<[u8; 3]>,
+    /// Background color (`None` = theme default)
+    pub bg: Option<[u8; 3]>,
+    /// SGR attributes
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikethrough: bool,
+}
+
+impl StyledSegment {
+    fn same_style(&self, other: &StyledSegment) -> bool {
+        self.fg == other.fg
+            && self.bg == other.bg
+            && self.bold == other.bold
+            && self.italic == other.italic
+            && self.underline == other.underline
+            && self.strikethrough == other.strikethrough
+    }
+
+    fn is_plain(&self) -> bool {
+        self.same_style(&StyledSegment::default())
+    }
+}
+
+/// Split one row of rendered cells into styled runs.
+///
+/// Colors equal to `default_fg`/`default_bg` are left unset.
+pub fn cells_to_segments(
+    cells: &[Cell],
+    default_fg: [u8; 3],
+    default_bg: [u8; 3],
+) -> Vec<StyledSegment> {
+    let mut segments: Vec<StyledSegment> = Vec::new();
+    for cell in cells.iter().filter(|c| !c.wide_char_spacer) {
+        let [r, g, b, _] = cell.fg_color;
+        let fg = Some([r, g, b]).filter(|c| *c != default_fg);
+        let [r, g, b, _] = cell.bg_color;
+        let bg = Some([r, g, b]).filter(|c| *c != default_bg);
+        let segment = StyledSegment {
+            text: String::new(),
+            fg,
+            bg,
+            bold: cell.bold,
+            italic: cell.italic,
+            underline: cell.underline,
+            strikethrough: cell.strikethrough,
+        };
+        let text = if cell.grapheme.is_empty() {
+            " "
+        } else {
+            &cell.grapheme
+        };
+        match segments.last_mut() {
+            Some(last) if last.same_style(&segment) => last.text.push_str(text),
+            _ => segments.push(StyledSegment {
+                text: text.to_string(),
+                ..segment
+            }),
+        }
+    }
+    segments
+}
+
+/// Drop trailing blanks that have no visible styling.
+fn trim_trailing_blanks(segments: &mut Vec<StyledSegment>) {
+    while let Some(last) = segments.last_mut() {
+        if last.bg.is_some() || last.underline || last.strikethrough {
+            return;
+        }
+        let trimmed = last.text.trim_end_matches(' ').len();
+        if trimmed > 0 {
+            last.text.truncate(trimmed);
+            return;
+        }
+        segments.pop();
+    }
+}
+
+/// Join lines of segments as plain text separated by `line_ending`,
+/// trimming trailing whitespace on each line (styled or not).
+pub fn segments_to_plain_text(lines: &[Vec<StyledSegment>], line_ending: CopyLineEnding) -> String {
+    lines
+        .iter()
+        .map(|line| {
+            let text: String = line.iter().map(|s| s.text.as_str()).collect();
+            text.trim_end().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join(line_ending.as_str())
+}
+
+/// Serialize lines of segments with SGR escape sequences.
+///
+/// Each styled run starts from a reset, so lines can be read independently.
+pub fn segments_to_ansi(lines: &[Vec<StyledSegment>]) -> String {
+    let mut out = String::new();
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        for segment in line {
+            if segment.is_plain() {
+                out.push_str(&segment.text);
+                continue;
+            }
+            out.push_str("\x1b[0");
+            if segment.bold {
+                out.push_str(";1");
+            }
+            if segment.italic {
+                out.push_str(";3");
+            }
+            if segment.underline {
+                out.push_str(";4");
+            }
+            if segment.strikethrough {
+                out.push_str(";9");
+            }
+            if let Some([r, g, b]) = segment.fg {
+                let _ = write!(out, ";38;2;{r};{g};{b}");
+            }
+            if let Some([r, g, b]) = segment.bg {
+                let _ = write!(out, ";48;2;{r};{g};{b}");
+            }
+            out.push('m');
+            out.push_str(&segment.text);
+            out.push_str("\x1b[0m");
+        }
+    }
+    out
+}
+
+/// Join adjacent runs that share a style, e.g. across a soft wrap.
+fn merge_runs(line: &[StyledSegment]) -> Vec<StyledSegment> {
+    let mut runs: Vec<StyledSegment> = Vec::with_capacity(line.len());
+    for segment in line {
+        match runs.last_mut() {
+            Some(last) if last.same_style(segment) => last.text.push_str(&segment.text),
+            _ => runs.push(segment.clone()),
+        }
+    }
+    runs
+}
+
+/// Serialize lines of segments as an HTML `<pre>` block with inline styles.
+///
+/// Adjacent runs with the same style become one `<span>`.
+pub fn segments_to_html(lines: &[Vec<StyledSegment>]) -> String {
+    let mut out = String::from("<pre>");
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        for segment in &merge_runs(line) {
+            let text = escape_html(&segment.text);
+            if segment.is_plain() {
+                out.push_str(&text);
+                continue;
+            }
+            let mut style = Vec::new();
+            if let Some(fg) = segment.fg {
+                style.push(format!("color:{}", hex(fg)));
+            }
+            if let Some(bg) = segment.bg {
+                style.push(format!("background-color:{}", hex(bg)));
+            }
+            if segment.bold {
+                style.push("font-weight:bold".to_string());
+            }
+            if segment.italic {
+                style.push("font-style:italic".to_string());
+            }
+            let decorations: Vec<&str> = [
+                (segment.underline, "underline"),
+                (segment.strikethrough, "line-through"),
+            ]
+            .iter()
+            .filter(|(on, _)| *on)
+            .map(|(_, name)| *name)
+            .collect();
+            if !decorations.is_empty() {
+                style.push(format!("text-decoration:{}", decorations.join(" ")));
+            }
+            let _ = write!(out, "<span style=\"{}\">{}</span>", style.join(";"), text);
+        }
+    }
+    out.push_str("</pre>");
+    out
+}
+
+/// Header line written before the prompt at `mark`.
+///
+/// Unknown fields are left out, e.g. `# command: make | exit: 2 |
+/// started_ms: 1700000000000 | duration_ms: 5120`, or just `# prompt`.
+fn command_header(mark: &ScrollbackMark) -> String {
+    let mut header = match &mark.command {
+        Some(command) => format!("# command: {}", command.replace(['\r', '\n'], " ")),
+        None => "# prompt".to_string(),
+    };
+    if let Some(code) = mark.exit_code {
+        let _ = write!(header, " | exit: {code}");
+    }
+    if let Some(start) = mark.start_time {
+        let _ = write!(header, " | started_ms: {start}");
+    }
+    if let Some(duration) = mark.duration_ms {
+        let _ = write!(header, " | duration_ms: {duration}");
+    }
+    header
+}
+
+fn hex([r, g, b]: [u8; 3]) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+impl TerminalManager {
+    /// Export absolute lines `range` (scrollback then screen) in `format`.
+    ///
+    /// Errors when the range is reversed or extends past the last line.
+    pub fn export_scrollback(
+        &self,
+        range: Range<usize>,
+        format: ScrollbackExportFormat,
+    ) -> Result<String> {
+        let (default_fg, default_bg) = self.default_cell_colors();
+        let lines = self.styled_lines(range, default_fg, default_bg)?;
+        Ok(match format {
+            ScrollbackExportFormat::Text => segments_to_plain_text(&lines, CopyLineEnding::Lf),
+            ScrollbackExportFormat::Ansi => segments_to_ansi(&lines),
+            ScrollbackExportFormat::Html => {
+                format!(
+                    "<!DOCTYPE html>\n<html>\n<head><meta charset=\"utf-8\"><title>par-term scrollback</title></head>\n\
+                     <body style=\"color:{};background-color:{};font-family:monospace\">\n{}\n</body>\n</html>\n",
+                    hex(default_fg),
+                    hex(default_bg),
+                    segments_to_html(&lines)
+                )
+            }
+        })
+    }
+
+    /// The visible screen as plain text, preceded by up to `scrollback_lines`
+    /// lines of scrollback.
+    ///
+    /// Soft-wrapped rows are joined, wide characters appear once, trailing
+    /// whitespace is trimmed per line and blank lines at the end are dropped.
+    pub fn read_text(&self, scrollback_lines: usize) -> Result<String> {
+        let (scrollback_len, rows) = {
+            let pty = self.pty_session.lock();
+            let terminal = pty.terminal();
+            let term = terminal.write();
+            let grid = term.active_grid();
+            (grid.scrollback_len(), grid.rows())
+        };
+        let start = scrollback_len - scrollback_lines.min(scrollback_len);
+        let (default_fg, default_bg) = self.default_cell_colors();
+        let lines = self.styled_lines(start..scrollback_len + rows, default_fg, default_bg)?;
+        let mut text = segments_to_plain_text(&lines, CopyLineEnding::Lf);
+        text.truncate(text.trim_end_matches('\n').len());
+        Ok(text)
+    }
+
+    /// Write the whole buffer (scrollback then screen) to `writer` as plain
+    /// text, one logical line per line.
+    ///
+    /// Returns the number of lines written.  See
+    /// [`dump_scrollback_with`](Self::dump_scrollback_with).
+    pub fn dump_scrollback<W: io::Write>(&self, writer: &mut W) -> io::Result<usize> {
+        self.dump_scrollback_with(writer, ScrollbackDumpOptions::default())
+    }
+
+    /// Write the whole buffer to `writer` as plain text, as described by
+    /// `options`.
+    ///
+    /// Lines are the same text [`segments_to_plain_text`] gives for copy and
+    /// export.  Rows are rendered a chunk at a time, each chunk under its
+    /// own terminal lock, so memory use does not grow with the scrollback;
+    /// wrap `writer` in a `BufWriter` for files.  The dump covers the buffer
+    /// as it stood when it began: each chunk is rebased on lines evicted from
+    /// a full scrollback since then (those are skipped), later output is
+    /// left out, and the dump ends early if the scrollback is cleared.
+    /// Blank lines at the end of the screen are dropped.  Returns the number
+    /// of buffer lines written, not counting command headers.
+    pub fn dump_scrollback_with<W: io::Write>(
+        &self,
+        writer: &mut W,
+        options: ScrollbackDumpOptions,
+    ) -> io::Result<usize> {
+        let (default_fg, default_bg) = self.default_cell_colors();
+        let marks = if options.command_headers {
+            self.scrollback_marks()
+        } else {
+            Vec::new()
+        };
+        let mut marks = marks.iter().peekable();
+
+        let mut written = 0;
+        // Blank lines held back until a non-blank line follows them
+        let mut pending_blank = 0;
+        let anchor = self.dump_anchor();
+        let mut start = 0;
+        while let Some((end, lines)) = self
+            .dump_chunk(&anchor, start, default_fg, default_bg)
+            .map_err(io::Error::other)?
+        {
+            for (i, (_, segments)) in lines.iter().enumerate() {
+                let text =
+                    segments_to_plain_text(std::slice::from_ref(segments), CopyLineEnding::Lf);
+                let line_end = lines.get(i + 1).map_or(end, |(row, _)| *row);
+                let mut headers = Vec::new();
+                while let Some(mark) = marks.next_if(|mark| mark.line < line_end) {
+                    headers.push(command_header(mark));
+                }
+                if text.is_empty() && headers.is_empty() {
+                    pending_blank += 1;
+                    continue;
+                }
+                for _ in 0..pending_blank {
+                    writeln!(writer)?;
+                }
+                written += std::mem::take(&mut pending_blank);
+                for header in headers {
+                    writeln!(writer, "{header}")?;
+                }
+                writeln!(writer, "{text}")?;
+                written += 1;
+            }
+            start = end;
+        }
+        writer.flush()?;
+        Ok(written)
+    }
+
+    /// Snapshot of the active grid that a scrollback dump rebases on.
+    fn dump_anchor(&self) -> DumpAnchor {
+        let pty = self.pty_session.lock();
+        let terminal = pty.terminal();
+        let term = terminal.read();
+        let grid = term.active_grid();
+        let scrolled = grid.total_lines_scrolled();
+        DumpAnchor {
+            scrolled,
+            evicted: scrolled.saturating_sub(grid.scrollback_len()),
+            total: grid.scrollback_len() + grid.rows(),
+        }
+    }
+
+    /// Render the dump chunk starting at row `start`, ending on a logical line
+    /// boundary, under a single terminal lock.
+    ///
+    /// Rows are counted in the buffer as `anchor` saw it.  Returns the end of
+    /// the chunk with its lines, or `None` once `start` is past that buffer or
+    /// the scrollback has been cleared.
+    fn dump_chunk(
+        &self,
+        anchor: &DumpAnchor,
+        start: usize,
+        default_fg: [u8; 3],
+        default_bg: [u8; 3],
+    ) -> Result<Option<(usize, DumpLines)>> {
+        let pty = self.pty_session.lock();
+        let terminal = pty.terminal();
+        let term = terminal.read();
+        let grid = term.active_grid();
+        let scrolled = grid.total_lines_scrolled();
+        if scrolled < anchor.scrolled {
+            return Ok(None);
+        }
+        // Rows evicted since the anchor; the oldest of them are gone for good
+        let shift = (scrolled - grid.scrollback_len()).saturating_sub(anchor.evicted);
+        let lines = GridLines::new(grid);
+        let from = start.max(shift) - shift;
+        let limit = (anchor.total.saturating_sub(shift)).min(lines.total);
+        if from >= limit {
+            return Ok(None);
+        }
+        let to = lines.logical_end((from + DUMP_CHUNK_ROWS).min(limit));
+        let chunk = self
+            .styled_grid_lines(
+                grid,
+                from..to,
+                default_fg,
+                default_bg,
+                |_| 0..usize::MAX,
+                true,
+            )?
+            .into_iter()
+            .map(|(row, segments)| (row + shift, segments))
+            .collect();
+        Ok(Some((to + shift, chunk)))
+    }
+
+    /// Export a selection as an HTML fragment with inline styles.
+    ///
+    /// `start` and `end` are inclusive `(col, line)` positions with absolute
+    /// lines (0 = oldest scrollback line; a viewport row is
+    /// `scrollback_len - scroll_offset + row`), in either order.  A
+    /// rectangular selection takes the same columns from every row and keeps
+    /// soft-wrapped rows apart; otherwise the first and last lines are cut at
+    /// the selected columns.  The `<pre>` block is wrapped in a `<div>`
+    /// carrying the theme's default colors, so unstyled text keeps the
+    /// terminal look when pasted.  Errors when a line is past the end of the
+    /// buffer.
+    pub fn export_selection_html(
+        &self,
+        start: (usize, usize),
+        end: (usize, usize),
+        rectangular: bool,
+    ) -> Result<String> {
+        let ((start_col, start_line), (end_col, end_line)) = if rectangular {
+            let (first, last) = (start.1.min(end.1), start.1.max(end.1));
+            ((start.0.min(end.0), first), (start.0.max(end.0), last))
+        } else if (start.1, start.0) <= (end.1, end.0) {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        let columns = |line: usize| {
+            if rectangular {
+                return start_col..end_col + 1;
+            }
+            let from = if line == start_line { start_col } else { 0 };
+            let to = if line == end_line {
+                end_col + 1
+            } else {
+                usize::MAX
+            };
+            from..to
+        };
+
+        let (default_fg, default_bg) = self.default_cell_colors();
+        let lines: Vec<Vec<StyledSegment>> = self
+            .styled_lines_in(
+                start_line..end_line + 1,
+                default_fg,
+                default_bg,
+                columns,
+                !rectangular,
+            )?
+            .into_iter()
+            .map(|(_, line)| line)
+            .collect();
+        Ok(format!(
+            "<div style=\"color:{};background-color:{};font-family:monospace\">{}</div>",
+            hex(default_fg),
+            hex(default_bg),
+            segments_to_html(&lines)
+        ))
+    }
+
+    /// Colors an unstyled cell renders with under the current theme.
+    fn default_cell_colors(&self) -> ([u8; 3], [u8; 3]) {
+        let cell = Self::convert_term_cell_with_theme(
+            &par_term_emu_core_rust::cell::Cell::default(),
+            false,
+            None,
+            &self.theme,
+            None,
+        );
+        let [fr, fg, fb, _] = cell.fg_color;
+        let [br, bg, bb, _] = cell.bg_color;
+        ([fr, fg, fb], [br, bg, bb])
+    }
+
+    /// Logical lines in `range` as styled segments (soft wraps joined).
+    fn styled_lines(
+        &self,
+        range: Range<usize>,
+        default_fg: [u8; 3],
+        default_bg: [u8; 3],
+    ) -> Result<Vec<Vec<StyledSegment>>> {
+        let lines = self.styled_lines_in(range, default_fg, default_bg, |_| 0..usize::MAX, true)?;
+        Ok(lines.into_iter().map(|(_, line)| line).collect())
+    }
+
+    /// Lines in `range` as styled segments, each with the row it starts on,
+    /// keeping the cells in `columns(line)` of each row and joining
+    /// soft-wrapped rows when `join_wrapped` is set.
+    fn styled_lines_in(
+        &self,
+        range: Range<usize>,
+        default_fg: [u8; 3],
+        default_bg: [u8; 3],
+        columns: impl Fn(usize) -> Range<usize>,
+        join_wrapped: bool,
+    ) -> Result<Vec<(usize, Vec<StyledSegment>)>> {
+        let pty = self.pty_session.lock();
+        let terminal = pty.terminal();
+        let term = terminal.write();
+        self.styled_grid_lines(
+            term.active_grid(),
+            range,
+            default_fg,
+            default_bg,
+            columns,
+            join_wrapped,
+        )
+    }
+
+    /// [`styled_lines_in`](Self::styled_lines_in) for a grid the caller has
+    /// already locked.
+    fn styled_grid_lines(
+        &self,
+        grid: &Grid,
+        range: Range<usize>,
+        default_fg: [u8; 3],
+        default_bg: [u8; 3],
+        columns: impl Fn(usize) -> Range<usize>,
+        join_wrapped: bool,
+    ) -> Result<Vec<(usize, Vec<StyledSegment>)>> {
+        let scrollback_len = grid.scrollback_len();
+        let total = scrollback_len + grid.rows();
+        if range.start > range.end || range.end > total {
+            bail!(
+                "Invalid export range {}..{} (buffer has {} lines)",
+                range.start,
+                range.end,
+                total
+            );
+        }
+
+        let cols = grid.cols();
+        let mut lines = Vec::new();
+        let mut current: Vec<StyledSegment> = Vec::new();
+        let mut line_start = range.start;
+        for line in range.clone() {
+            let mut cells = Vec::with_capacity(cols);
+            let mut ctx = RowRenderContext {
+                cols,
+                dest: &mut cells,
+                screen_row: 0,
+                selection: None,
+                rectangular: false,
+                cursor: None,
+                theme: &self.theme,
+                color_mode: None,
+            };
+            let wrapped = if line < scrollback_len {
+                match grid.scrollback_line(line) {
+                    Some(slice) => Self::push_line_from_slice(slice, &mut ctx),
+                    None => Self::push_empty_cells(cols, ctx.dest),
+                }
+                grid.is_scrollback_wrapped(line)
+            } else {
+                Self::push_grid_row(grid, line - scrollback_len, &mut ctx);
+                grid.is_line_wrapped(line - scrollback_len)
+            };
+            let span = columns(line);
+            let span = span.start.min(cells.len())..span.end.min(cells.len());
+            current.extend(cells_to_segments(&cells[span], default_fg, default_bg));
+            if !(wrapped && join_wrapped) || line + 1 == range.end {
+                trim_trailing_blanks(&mut current);
+                lines.push((line_start, std::mem::take(&mut current)));
+                line_start = line + 1;
+            }
+        }
+        Ok(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A manager showing "plain", a bold red word, and "end" on three lines.
+    fn styled_manager() -> TerminalManager {
+        let manager = TerminalManager::new(20, 4).unwrap();
+        manager.process_data(b"plain\r\n\x1b[1;38;2;255;0;0mred\x1b[0m <ok>\r\nend");
+        manager
+    }
+
+    #[test]
+    fn text_export_strips_styles() {
+        let manager = styled_manager();
+        let text = manager
+            .export_scrollback(0..3, ScrollbackExportFormat::Text)
+            .unwrap();
+        assert_eq!(text, "plain\nred <ok>\nend");
+        let middle = manager
+            .export_scrollback(1..2, ScrollbackExportFormat::Text)
+            .unwrap();
+        assert_eq!(middle, "red <ok>");
+    }
+
+    #[test]
+    fn plain_text_uses_configured_line_ending() {
+        let manager = styled_manager();
+        let (fg, bg) = manager.default_cell_colors();
+        let lines = manager.styled_lines(0..3, fg, bg).unwrap();
+        assert_eq!(
+            segments_to_plain_text(&lines, CopyLineEnding::Lf),
+            "plain\nred <ok>\nend"
+        );
+        assert_eq!(
+            segments_to_plain_text(&lines, CopyLineEnding::Crlf),
+            "plain\r\nred <ok>\r\nend"
+        );
+        assert_eq!(
+            segments_to_plain_text(&lines, CopyLineEnding::Cr),
+            "plain\rred <ok>\rend"
+        );
+
+        // Text copied from the grid is rewritten the same way
+        assert_eq!(CopyLineEnding::Crlf.apply("a\nb\n"), "a\r\nb\r\n");
+        assert_eq!(CopyLineEnding::Cr.apply("a\nb"), "a\rb");
+        assert_eq!(CopyLineEnding::Lf.apply("a\nb"), "a\nb");
+    }
+
+    #[test]
+    fn ansi_export_uses_sgr_for_styled_runs() {
+        let manager = styled_manager();
+        let ansi = manager
+            .export_scrollback(0..2, ScrollbackExportFormat::Ansi)
+            .unwrap();
+        assert_eq!(ansi, "plain\n\x1b[0;1;38;2;255;0;0mred\x1b[0m <ok>");
+    }
+
+    #[test]
+    fn html_export_escapes_and_styles_spans() {
+        let manager = styled_manager();
+        let html = manager
+            .export_scrollback(1..2, ScrollbackExportFormat::Html)
+            .unwrap();
+        assert!(html.starts_with("<!DOCTYPE html>"), "{html}");
+        assert!(
+            html.contains(
+                "<pre><span style=\"color:#ff0000;font-weight:bold\">red</span> &lt;ok&gt;</pre>"
+            ),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn html_runs_map_styles_and_merge() {
+        let run = |text: &str, bg: Option<[u8; 3]>| StyledSegment {
+            text: text.to_string(),
+            bg,
+            ..StyledSegment::default()
+        };
+        let html = segments_to_html(&[vec![
+            run("ab", Some([0, 0, 0x80])),
+            run("cd", Some([0, 0, 0x80])),
+            run(" ", None),
+            StyledSegment {
+                text: "x".to_string(),
+                fg: Some([1, 2, 3]),
+                italic: true,
+                underline: true,
+                strikethrough: true,
+                ..StyledSegment::default()
+            },
+        ]]);
+        assert_eq!(
+            html,
+            "<pre><span style=\"background-color:#000080\">abcd</span> \
+             <span style=\"color:#010203;font-style:italic;\
+             text-decoration:underline line-through\">x</span></pre>"
+        );
+    }
+
+    #[test]
+    fn selection_exports_as_html_fragment() {
+        let manager = TerminalManager::new(10, 4).unwrap();
+        // A blue background run soft-wrapped across two rows, then a bold line
+        manager.process_data(b"ab\x1b[44mcdefghijkl\x1b[0mm\r\n\x1b[1mbold\x1b[0m line");
+
+        let html = manager
+            .export_selection_html((1, 1), (2, 0), false)
+            .unwrap();
+        assert!(html.starts_with("<div style=\"color:#"), "{html}");
+        let pre = &html[html.find("<pre>").unwrap()..html.find("</div>").unwrap()];
+        // One span across the wrap; the selection starts at column 2
+        assert!(
+            pre.starts_with("<pre><span style=\"background-color:#"),
+            "{pre}"
+        );
+        assert!(pre.contains("\">cdefghijkl</span></pre>"), "{pre}");
+
+        let html = manager
+            .export_selection_html((0, 2), (3, 2), false)
+            .unwrap();
+        assert!(
+            html.contains("<pre><span style=\"font-weight:bold\">bold</span></pre>"),
+            "{html}"
+        );
+
+        // Rectangular: same columns from each row, wraps kept apart
+        let html = manager.export_selection_html((1, 0), (0, 2), true).unwrap();
+        assert!(
+            html.contains("<pre>ab\n<span style=\"background-color:#")
+                && html.contains("\">kl</span>\n<span style=\"font-weight:bold\">bo</span></pre>"),
+            "{html}"
+        );
+
+        assert!(
+            manager
+                .export_selection_html((0, 0), (0, 99), false)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn dump_streams_every_line_in_chunks() {
+        let manager = TerminalManager::new_with_scrollback(10, 5, 5000).unwrap();
+        let mut data = String::new();
+        for i in 0..2500 {
+            data.push_str(&format!("line {i}\r\n"));
+            if i == 995 {
+                // Soft-wrapped across the first chunk boundary
+                data.push_str(&"w".repeat(45));
+                data.push_str("\r\n");
+            }
+        }
+        manager.process_data(data.as_bytes());
+        let total = manager.scrollback_len() + 5;
+        assert!(total > 2 * DUMP_CHUNK_ROWS);
+
+        let mut out = Vec::new();
+        let written = manager.dump_scrollback(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let expected = manager
+            .export_scrollback(0..total, ScrollbackExportFormat::Text)
+            .unwrap();
+        assert_eq!(out, format!("{}\n", expected.trim_end_matches('\n')));
+        assert_eq!(written, 2501, "blank rows below the cursor are dropped");
+        assert_eq!(out.lines().filter(|l| l.starts_with('w')).count(), 1);
+    }
+
+    #[test]
+    fn dump_chunks_rebase_on_evicted_lines() {
+        let manager = TerminalManager::new_with_scrollback(10, 5, 100).unwrap();
+        let mut data = String::new();
+        for i in 0..200 {
+            data.push_str(&format!("line {i}\r\n"));
+        }
+        manager.process_data(data.as_bytes());
+        let (fg, bg) = manager.default_cell_colors();
+        let anchor = manager.dump_anchor();
+        let (_, before) = manager.dump_chunk(&anchor, 0, fg, bg).unwrap().unwrap();
+        let oldest_kept = plain_text(&before[10].1);
+
+        // Ten more lines push the oldest ten out of the full scrollback
+        let mut data = String::new();
+        for i in 200..210 {
+            data.push_str(&format!("line {i}\r\n"));
+        }
+        manager.process_data(data.as_bytes());
+        let (end, after) = manager.dump_chunk(&anchor, 0, fg, bg).unwrap().unwrap();
+        assert_eq!(after[0].0, 10, "evicted rows are skipped");
+        assert_eq!(plain_text(&after[0].1), oldest_kept);
+        assert_eq!(end, anchor.total, "later output is left out");
+        assert!(manager.dump_chunk(&anchor, end, fg, bg).unwrap().is_none());
+    }
+
+    fn plain_text(line: &[StyledSegment]) -> String {
+        segments_to_plain_text(&[line.to_vec()], CopyLineEnding::Lf)
+    }
+
+    #[test]
+    fn dump_writes_command_headers() {
+        let mut manager = TerminalManager::new(30, 10).unwrap();
+        manager.process_data(
+            b"motd\r\n\r\n\x1b]133;A\x07$ \x1b]133;B\x07make\r\n\x1b]133;C\x07error\r\n\
+            \x1b]133;D;2\x07\x1b]133;A\x07$ \x1b]133;B\x07",
+        );
+        let (_, row) = manager.cursor_position();
+        manager.update_scrollback_metadata(manager.scrollback_len(), row);
+
+        let mut out = Vec::new();
+        let options = ScrollbackDumpOptions {
+            command_headers: true,
+        };
+        let written = manager.dump_scrollback_with(&mut out, options).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(written, 5, "headers are not counted");
+        assert_eq!(lines.len(), 7, "{out}");
+        assert_eq!(&lines[..2], ["motd", ""]);
+        assert!(
+            lines[2].starts_with("# command: make | exit: 2 | started_ms: "),
+            "{out}"
+        );
+        assert_eq!(&lines[3..5], ["$ make", "error"]);
+        assert_eq!(&lines[5..], ["# prompt", "$"]);
+
+        // Without the option the dump is just the text
+        let mut plain = Vec::new();
+        manager.dump_scrollback(&mut plain).unwrap();
+        assert_eq!(
+            String::from_utf8(plain).unwrap(),
+            "motd\n\n$ make\nerror\n$\n"
+        );
+    }
+
+    #[test]
+    fn read_text_matches_copied_text() {
+        let manager = TerminalManager::new(10, 3).unwrap();
+        // Scroll two lines off, then a wide character, a styled trailing
+        // blank and a soft-wrapped line on screen
+        manager.process_data(
+            b"one\r\ntwo\r\n\xe4\xb8\xad\xe6\x96\x87!\x1b[44m  \x1b[0m\r\nabcdefghijkl",
+        );
+        assert_eq!(manager.scrollback_len(), 2);
+
+        assert_eq!(manager.read_text(0).unwrap(), "中文!\nabcdefghijkl");
+        assert_eq!(
+            manager.read_text(1).unwrap(),
+            "two\n中文!\nabcdefghijkl",
+            "scrollback lines come first"
+        );
+        assert_eq!(
+            manager.read_text(100).unwrap(),
+            "one\ntwo\n中文!\nabcdefghijkl",
+            "request larger than scrollback is clamped"
+        );
+
+        // Blank rows below the cursor are dropped
+        let manager = TerminalManager::new(10, 5).unwrap();
+        manager.process_data(b"$ ls   \r\n");
+        assert_eq!(manager.read_text(0).unwrap(), "$ ls");
+    }
+
+    #[test]
+    fn dec_line_drawing_renders_and_copies_as_box_drawing() {
+        let manager = TerminalManager::new(10, 3).unwrap();
+        // G0 designated as DEC line drawing, then back to ASCII
+        manager.process_data(b"\x1b(0lqqk\x1b(B q\r\n");
+        // G1 designated as line drawing and shifted in with SO, out with SI
+        manager.process_data(b"\x1b)0\x0ex\x0fx");
+
+        let cells = manager.get_cells_with_scrollback(0, None, false, None);
+        let row: String = cells[..6].iter().map(|c| c.grapheme.as_str()).collect();
+        assert_eq!(row, "┌──┐ q");
+        assert_eq!(manager.read_text(0).unwrap(), "┌──┐ q\n│x");
+        // Select-all copy
+        assert!(manager.export_text().starts_with("┌──┐ q\n│x"));
+        assert_eq!(
+            manager
+                .export_scrollback(0..1, ScrollbackExportFormat::Text)
+                .unwrap(),
+            "┌──┐ q"
+        );
+    }
+
+    #[test]
+    fn invalid_range_errors() {
+        let manager = styled_manager();
+        let total = manager.scrollback_len() + 4;
+        assert!(
+            manager
+                .export_scrollback(0..total + 1, ScrollbackExportFormat::Text)
+                .is_err()
+        );
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 2..1;
+        assert!(
+            manager
+                .export_scrollback(reversed, ScrollbackExportFormat::Ansi)
+                .is_err()
+        );
+        assert_eq!(
+            manager
+                .export_scrollback(total..total, ScrollbackExportFormat::Text)
+                .unwrap(),
+            ""
+        );
+    }
+}
@@ -0,0 +1,233 @@
+//! Form feed (FF, `0x0C`) handling.
+//!
+//! Terminals disagree on form feed: the emulator ignores it, xterm treats it
+//! as a line feed, and output written for printers expects a fresh page.
+//! [`FormFeedFilter`] rewrites FF according to the configured [`FormFeed`]
+//! mode before the emulator parses the data.  Form feeds inside OSC, DCS and
+//! other string sequences are payload, not controls, and are left alone; one
+//! inside a CSI sequence is acted on once the sequence ends.
+//!
+//! The filter runs on both routes into the emulator (see
+//! [`super::output_filters`]): the PTY reader thread for shell and SSH
+//! sessions, and [`TerminalManager::process_data`] for tmux panes.
+
+use super::TerminalManager;
+use par_term_config::FormFeed;
+
+const FF: u8 = 0x0c;
+const ESC: u8 = 0x1b;
+const BEL: u8 = 0x07;
+const CAN: u8 = 0x18;
+const SUB: u8 = 0x1a;
+
+/// Home the cursor and clear the screen.
+const CLEAR_SCREEN: &[u8] = b"\x1b[H\x1b[2J";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Ground,
+    /// After ESC
+    Escape,
+    /// Inside `ESC [` … final byte
+    Csi,
+    /// Inside an OSC/DCS/APC/PM/SOS string
+    String,
+    /// After ESC inside a string (possible ST)
+    StringEscape,
+}
+
+/// Streaming filter that applies the form feed mode.
+#[derive(Debug)]
+pub struct FormFeedFilter {
+    mode: FormFeed,
+    state: State,
+    /// A form feed seen inside a CSI sequence clears once the sequence ends.
+    pending_clear: bool,
+}
+
+impl Default for FormFeedFilter {
+    fn default() -> Self {
+        Self::new(FormFeed::default())
+    }
+}
+
+impl FormFeedFilter {
+    /// Create a filter for `mode`.
+    pub fn new(mode: FormFeed) -> Self {
+        Self {
+            mode,
+            state: State::Ground,
+            pending_clear: false,
+        }
+    }
+
+    /// The configured form feed mode.
+    pub fn mode(&self) -> FormFeed {
+        self.mode
+    }
+
+    /// Rewrite the form feeds in `input`.
+    pub fn filter(&mut self, input: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(input.len());
+        for &byte in input {
+            self.push(byte, &mut out);
+        }
+        out
+    }
+
+    fn push(&mut self, byte: u8, out: &mut Vec<u8>) {
+        match self.state {
+            State::Ground => {
+                if byte == FF {
+                    self.form_feed(out);
+                    return;
+                }
+                if byte == ESC {
+                    self.state = State::Escape;
+                }
+            }
+            State::Escape => self.escape(byte),
+            State::Csi => {
+                if byte == FF {
+                    // C0 controls execute inside CSI; a clear would abort
+                    // the sequence, so it waits for the final byte.
+                    match self.mode {
+                        FormFeed::Newline => out.push(b'\n'),
+                        FormFeed::ClearScreen => self.pending_clear = true,
+                        FormFeed::Ignore => {}
+                    }
+                    return;
+                }
+                match byte {
+                    ESC => {
+                        // Sequence aborted: clear before the new one starts
+                        self.flush_clear(out);
+                        self.state = State::Escape;
+                    }
+                    CAN | SUB | 0x40..=0x7e => {
+                        self.state = State::Ground;
+                        out.push(byte);
+                        self.flush_clear(out);
+                        return;
+                    }
+                    _ => {}
+                }
+            }
+            State::String => match byte {
+                ESC => self.state = State::StringEscape,
+                BEL | CAN | SUB => self.state = State::Ground,
+                _ => {}
+            },
+            State::StringEscape => {
+                if byte == b'\\' {
+                    self.state = State::Ground;
+                } else {
+                    self.escape(byte);
+                }
+            }
+        }
+        out.push(byte);
+    }
+
+    /// Advance from the escape state on `byte`.
+    fn escape(&mut self, byte: u8) {
+        self.state = match byte {
+            b'[' => State::Csi,
+            b']' | b'P' | b'_' | b'^' | b'X' => State::String,
+            ESC => State::Escape,
+            _ => State::Ground,
+        };
+    }
+
+    fn flush_clear(&mut self, out: &mut Vec<u8>) {
+        if std::mem::take(&mut self.pending_clear) {
+            out.extend_from_slice(CLEAR_SCREEN);
+        }
+    }
+
+    fn form_feed(&self, out: &mut Vec<u8>) {
+        match self.mode {
+            FormFeed::Ignore => out.push(FF),
+            FormFeed::Newline => out.push(b'\n'),
+            FormFeed::ClearScreen => out.extend_from_slice(CLEAR_SCREEN),
+        }
+    }
+}
+
+impl TerminalManager {
+    /// Set how form feed (FF) is handled in terminal output.
+    pub fn set_form_feed(&mut self, mode: FormFeed) {
+        *self.output_filters.form_feed.lock() = FormFeedFilter::new(mode);
+    }
+
+    /// How form feed (FF) is handled.
+    pub fn form_feed(&self) -> FormFeed {
+        self.output_filters.form_feed.lock().mode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Screen rows (trailing blanks trimmed) and cursor after `data`.
+    fn grid_after(mode: FormFeed, data: &[u8]) -> (Vec<String>, (usize, usize)) {
+        let mut manager = TerminalManager::new(10, 4).unwrap();
+        manager.set_form_feed(mode);
+        manager.process_data(data);
+        let content = manager.terminal().read().content();
+        let rows = content
+            .lines()
+            .map(|line| line.trim_end().to_string())
+            .collect();
+        (rows, manager.cursor_position())
+    }
+
+    #[test]
+    fn each_mode_produces_expected_grid() {
+        let data = b"one\r\ntwo\x0cx";
+
+        let (rows, cursor) = grid_after(FormFeed::Ignore, data);
+        assert_eq!(rows[..2], ["one", "twox"]);
+        assert_eq!(cursor, (4, 1));
+
+        // Line feed: down one row, same column
+        let (rows, cursor) = grid_after(FormFeed::Newline, data);
+        assert_eq!(rows[..3], ["one", "two", "   x"]);
+        assert_eq!(cursor, (4, 2));
+
+        let (rows, cursor) = grid_after(FormFeed::ClearScreen, data);
+        assert_eq!(rows[0], "x");
+        assert!(rows[1..].iter().all(|row| row.is_empty()), "{rows:?}");
+        assert_eq!(cursor, (1, 0));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn pty_output_is_rewritten() {
+        let mut manager = TerminalManager::new(10, 4).unwrap();
+        manager.set_form_feed(FormFeed::Newline);
+        crate::terminal::spawn::tests::run_script(&mut manager, r"printf 'one\ntwo\fx'");
+        let content = manager.terminal().read().content();
+        let rows: Vec<_> = content.lines().map(str::trim_end).collect();
+        assert_eq!(rows[..3], ["one", "two", "   x"]);
+    }
+
+    #[test]
+    fn form_feed_in_string_sequences_is_left_alone() {
+        let mut filter = FormFeedFilter::new(FormFeed::ClearScreen);
+        let osc = b"\x1b]2;a\x0cb\x07";
+        assert_eq!(filter.filter(osc), osc);
+        let dcs = b"\x1bPq\x0c\x1b\\";
+        assert_eq!(filter.filter(dcs), dcs);
+        assert_eq!(filter.filter(b"\x0c"), CLEAR_SCREEN);
+    }
+
+    #[test]
+    fn clear_inside_csi_waits_for_final_byte() {
+        let mut filter = FormFeedFilter::new(FormFeed::ClearScreen);
+        // Split across reads
+        assert_eq!(filter.filter(b"\x1b[3\x0c"), b"\x1b[3");
+        assert_eq!(filter.filter(b"1mA"), b"1m\x1b[H\x1b[2JA");
+    }
+}
@@ -1,6 +1,75 @@
+//! OSC 8 hyperlink queries and the hyperlink under the mouse.
+//!
+//! The frontend reports the link under the mouse with
+//! [`TerminalManager::set_hovered_hyperlink`].  Scripts and the status bar
+//! read it back with [`TerminalManager::hovered_hyperlink`], or follow it
+//! through [`TerminalManager::poll_hyperlink_hover`], which reports a change
+//! only once the mouse has rested for [`HYPERLINK_HOVER_DEBOUNCE`], so
+//! sweeping across a screen of links does not flood listeners.
+
+use std::time::{Duration, Instant};
+
 use super::TerminalManager;
 pub use par_term_emu_core_rust::terminal::HyperlinkInfo;
 
+/// How long the hovered link must stay the same before the change is reported.
+pub const HYPERLINK_HOVER_DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// A debounced change of the hyperlink under the mouse.
+#[derive(Debug, Clone)]
+pub enum HyperlinkHoverEvent {
+    /// The mouse rests on this link (replacing any link reported before)
+    Entered(HyperlinkInfo),
+    /// The mouse left the last reported link
+    Left,
+}
+
+/// Hovered link bookkeeping for [`TerminalManager`].
+#[derive(Debug, Default)]
+pub(crate) struct HyperlinkHoverTracker {
+    /// Link under the mouse right now
+    current: Option<HyperlinkInfo>,
+    /// When `current` last changed, while that change is unreported
+    changed_at: Option<Instant>,
+    /// Link reported by the last event
+    reported: Option<HyperlinkInfo>,
+}
+
+/// Same target and cells; the core type does not implement `PartialEq`.
+fn same_link(a: Option<&HyperlinkInfo>, b: Option<&HyperlinkInfo>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a.url == b.url && a.id == b.id && a.positions == b.positions,
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+impl HyperlinkHoverTracker {
+    pub(crate) fn set(&mut self, link: Option<HyperlinkInfo>, now: Instant) {
+        if !same_link(link.as_ref(), self.current.as_ref()) {
+            self.current = link;
+            self.changed_at = Some(now);
+        }
+    }
+
+    pub(crate) fn poll(&mut self, now: Instant) -> Option<HyperlinkHoverEvent> {
+        let changed_at = self.changed_at?;
+        if now.saturating_duration_since(changed_at) < HYPERLINK_HOVER_DEBOUNCE {
+            return None;
+        }
+        self.changed_at = None;
+        // Left and came back within the debounce: nothing to report
+        if same_link(self.current.as_ref(), self.reported.as_ref()) {
+            return None;
+        }
+        self.reported = self.current.clone();
+        Some(match &self.current {
+            Some(link) => HyperlinkHoverEvent::Entered(link.clone()),
+            None => HyperlinkHoverEvent::Left,
+        })
+    }
+}
+
 impl TerminalManager {
     /// Get all OSC 8 hyperlinks from the terminal
     pub fn get_all_hyperlinks(&self) -> Vec<HyperlinkInfo> {
@@ -29,4 +98,101 @@ impl TerminalManager {
         let term = terminal.read();
         term.get_hyperlink_url(hyperlink_id)
     }
+
+    /// Record the link under the mouse at `now` (`None` when there is none).
+    pub fn set_hovered_hyperlink(&self, link: Option<HyperlinkInfo>, now: Instant) {
+        self.hyperlink_hover.lock().set(link, now);
+    }
+
+    /// The link under the mouse, if any.
+    pub fn hovered_hyperlink(&self) -> Option<HyperlinkInfo> {
+        self.hyperlink_hover.lock().current.clone()
+    }
+
+    /// The hover change to report at `now`, once the hovered link has been
+    /// stable for [`HYPERLINK_HOVER_DEBOUNCE`].  Each change is returned once.
+    pub fn poll_hyperlink_hover(&self, now: Instant) -> Option<HyperlinkHoverEvent> {
+        self.hyperlink_hover.lock().poll(now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(url: &str, col: usize) -> HyperlinkInfo {
+        HyperlinkInfo {
+            url: url.to_string(),
+            positions: vec![(col, 0), (col + 1, 0)],
+            id: None,
+        }
+    }
+
+    fn entered_url(event: Option<HyperlinkHoverEvent>) -> Option<String> {
+        match event {
+            Some(HyperlinkHoverEvent::Entered(link)) => Some(link.url),
+            other => panic!("expected Entered, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn entering_and_leaving_a_link_emits_events() {
+        let manager = TerminalManager::new(20, 4).unwrap();
+        let t0 = Instant::now();
+        let later = |ms| t0 + Duration::from_millis(ms);
+
+        manager.set_hovered_hyperlink(Some(link("https://example.com/a", 2)), t0);
+        assert_eq!(
+            manager.hovered_hyperlink().map(|l| l.url).as_deref(),
+            Some("https://example.com/a"),
+            "the getter is not debounced"
+        );
+        assert!(manager.poll_hyperlink_hover(later(50)).is_none());
+        assert_eq!(
+            entered_url(manager.poll_hyperlink_hover(later(100))).as_deref(),
+            Some("https://example.com/a")
+        );
+        assert!(manager.poll_hyperlink_hover(later(200)).is_none(), "once");
+
+        // Moving within the same link reports nothing
+        manager.set_hovered_hyperlink(Some(link("https://example.com/a", 2)), later(300));
+        assert!(manager.poll_hyperlink_hover(later(500)).is_none());
+
+        manager.set_hovered_hyperlink(None, later(600));
+        assert!(manager.hovered_hyperlink().is_none());
+        assert!(matches!(
+            manager.poll_hyperlink_hover(later(700)),
+            Some(HyperlinkHoverEvent::Left)
+        ));
+        assert!(manager.poll_hyperlink_hover(later(800)).is_none());
+    }
+
+    #[test]
+    fn hover_changes_are_debounced() {
+        let manager = TerminalManager::new(20, 4).unwrap();
+        let t0 = Instant::now();
+        let later = |ms| t0 + Duration::from_millis(ms);
+
+        // Sweeping across two links reports only the one the mouse rests on
+        manager.set_hovered_hyperlink(Some(link("https://a.example", 0)), t0);
+        manager.set_hovered_hyperlink(None, later(20));
+        manager.set_hovered_hyperlink(Some(link("https://b.example", 5)), later(40));
+        assert!(manager.poll_hyperlink_hover(later(100)).is_none());
+        assert_eq!(
+            entered_url(manager.poll_hyperlink_hover(later(140))).as_deref(),
+            Some("https://b.example")
+        );
+
+        // Leaving and coming back before the debounce is not a change
+        manager.set_hovered_hyperlink(None, later(200));
+        manager.set_hovered_hyperlink(Some(link("https://b.example", 5)), later(250));
+        assert!(manager.poll_hyperlink_hover(later(400)).is_none());
+
+        // A different link replaces the reported one without a Left
+        manager.set_hovered_hyperlink(Some(link("https://c.example", 9)), later(500));
+        assert_eq!(
+            entered_url(manager.poll_hyperlink_hover(later(600))).as_deref(),
+            Some("https://c.example")
+        );
+    }
 }
@@ -0,0 +1,163 @@
+//! Input encoding override for hosts that do not send UTF-8.
+//!
+//! The emulator parses UTF-8.  Older hosts may send Latin-1, Shift_JIS or
+//! another legacy encoding, which then shows up as replacement characters.
+//! [`InputDecoder`] converts such output to UTF-8 before the emulator parses
+//! it.  Labels are the WHATWG encoding labels understood by `encoding_rs`
+//! (`latin1`, `iso-8859-15`, `shift_jis`, `gbk`, ...); note that `latin1`
+//! and `iso-8859-1` decode as windows-1252, as browsers do.
+//!
+//! Escape sequences are ASCII and pass through the ASCII-compatible
+//! encodings unchanged.  A multi-byte character split across two reads is
+//! held back until the rest arrives.
+//!
+//! Output is decoded first, before the other filters (see
+//! [`super::output_filters`]), both from the PTY and in tmux pane data.
+
+use anyhow::{Result, anyhow};
+use encoding_rs::{Decoder, Encoding, UTF_8};
+
+use super::TerminalManager;
+
+/// Streaming decoder from a legacy encoding to UTF-8.
+pub struct InputDecoder {
+    decoder: Decoder,
+}
+
+impl std::fmt::Debug for InputDecoder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InputDecoder")
+            .field("encoding", &self.decoder.encoding().name())
+            .finish()
+    }
+}
+
+impl InputDecoder {
+    /// Decoder for `encoding`.
+    pub fn new(encoding: &'static Encoding) -> Self {
+        Self {
+            decoder: encoding.new_decoder_without_bom_handling(),
+        }
+    }
+
+    /// Name of the encoding being decoded.
+    pub fn encoding_name(&self) -> &'static str {
+        self.decoder.encoding().name()
+    }
+
+    /// Decode the next chunk of output.
+    ///
+    /// Invalid bytes become U+FFFD; an incomplete trailing sequence is kept
+    /// for the next call.
+    pub fn decode(&mut self, data: &[u8]) -> String {
+        let capacity = self
+            .decoder
+            .max_utf8_buffer_length(data.len())
+            .unwrap_or(data.len() * 3);
+        let mut out = String::with_capacity(capacity);
+        let _ = self.decoder.decode_to_string(data, &mut out, false);
+        out
+    }
+}
+
+/// Look up an encoding by label, case-insensitively.
+pub fn encoding_for_label(label: &str) -> Result<&'static Encoding> {
+    Encoding::for_label(label.trim().as_bytes())
+        .ok_or_else(|| anyhow!("Unknown input encoding {label:?}"))
+}
+
+impl TerminalManager {
+    /// Decode terminal output from `label`'s encoding instead of UTF-8.
+    ///
+    /// `None` (or a UTF-8 label) restores the default.  Errors on an unknown
+    /// label, leaving the current encoding in place.
+    pub fn set_input_encoding(&mut self, label: Option<&str>) -> Result<()> {
+        let decoder = match label {
+            Some(label) => {
+                let encoding = encoding_for_label(label)?;
+                (encoding != UTF_8).then(|| InputDecoder::new(encoding))
+            }
+            None => None,
+        };
+        *self.output_filters.input_decoder.lock() = decoder;
+        Ok(())
+    }
+
+    /// Name of the encoding terminal output is decoded from.
+    pub fn input_encoding(&self) -> &'static str {
+        self.output_filters
+            .input_decoder
+            .lock()
+            .as_ref()
+            .map_or(UTF_8.name(), InputDecoder::encoding_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// First screen row after feeding `chunks`.
+    fn first_row(manager: &TerminalManager, chunks: &[&[u8]]) -> String {
+        for chunk in chunks {
+            manager.process_data(chunk);
+        }
+        let content = manager.terminal().read().content();
+        content
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .trim_end()
+            .to_string()
+    }
+
+    #[test]
+    fn latin1_bytes_decode_under_the_override() {
+        // "café über" in Latin-1, with a color change in between
+        let latin1: &[u8] = b"caf\xe9 \x1b[31m\xfcber\x1b[0m";
+
+        let plain = TerminalManager::new(20, 2).unwrap();
+        assert_eq!(plain.input_encoding(), "UTF-8");
+        assert_eq!(first_row(&plain, &[latin1]), "caf\u{fffd} \u{fffd}ber");
+
+        let mut manager = TerminalManager::new(20, 2).unwrap();
+        manager.set_input_encoding(Some("ISO-8859-1")).unwrap();
+        assert_eq!(manager.input_encoding(), "windows-1252");
+        assert_eq!(first_row(&manager, &[latin1]), "café über");
+
+        // Back to UTF-8
+        manager.set_input_encoding(Some("utf8")).unwrap();
+        assert_eq!(manager.input_encoding(), "UTF-8");
+        manager.set_input_encoding(None).unwrap();
+        assert_eq!(manager.input_encoding(), "UTF-8");
+    }
+
+    #[test]
+    fn multibyte_characters_split_across_reads_are_joined() {
+        let mut manager = TerminalManager::new(20, 2).unwrap();
+        manager.set_input_encoding(Some("shift_jis")).unwrap();
+        // 日本 in Shift_JIS is 93 fa 96 7b; split inside the first character
+        // (content() shows each wide character's spacer cell as a space)
+        assert_eq!(
+            first_row(&manager, &[b"\x93", b"\xfa\x96", b"\x7b!"]),
+            "日 本 !"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn pty_output_is_decoded() {
+        let mut manager = TerminalManager::new(20, 2).unwrap();
+        manager.set_input_encoding(Some("latin1")).unwrap();
+        crate::terminal::spawn::tests::run_script(&mut manager, r"printf 'caf\351'");
+        assert_eq!(first_row(&manager, &[]), "café");
+    }
+
+    #[test]
+    fn unknown_label_is_rejected() {
+        let mut manager = TerminalManager::new(20, 2).unwrap();
+        manager.set_input_encoding(Some("latin1")).unwrap();
+        assert!(manager.set_input_encoding(Some("klingon")).is_err());
+        assert_eq!(manager.input_encoding(), "windows-1252", "unchanged");
+    }
+}
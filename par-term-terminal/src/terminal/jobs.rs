@@ -0,0 +1,260 @@
+//! Foreground job detection from process groups.
+//!
+//! A shell with job control moves each foreground job into its own process
+//! group and hands it the terminal.  Comparing the shell's process group with
+//! the terminal's foreground process group therefore tells a running command
+//! apart from an idle prompt, and unlike the child process list it ignores
+//! jobs left running in the background.
+//!
+//! Process groups are read from `/proc` on Linux and from `ps` on macOS.
+//! Elsewhere, or when the lookup fails, the shell integration markers decide.
+
+use super::TerminalManager;
+
+/// Process groups of a shell and of its terminal's foreground job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessGroups {
+    /// Process group of the shell
+    pub shell: i32,
+    /// Foreground process group of the shell's terminal (`-1` if none)
+    pub foreground: i32,
+}
+
+impl ProcessGroups {
+    /// Whether a process group other than the shell's owns the terminal.
+    pub fn has_foreground_job(&self) -> bool {
+        self.foreground > 0 && self.foreground != self.shell
+    }
+
+    /// Parse the process and terminal foreground groups from the contents
+    /// of `/proc/<pid>/stat`.
+    pub fn from_proc_stat(stat: &str) -> Option<Self> {
+        // The command name may contain spaces and parentheses; the fields
+        // after it are `state ppid pgrp session tty_nr tpgid ...`
+        let fields: Vec<&str> = stat
+            .get(stat.rfind(')')? + 1..)?
+            .split_whitespace()
+            .collect();
+        Some(Self {
+            shell: fields.get(2)?.parse().ok()?,
+            foreground: fields.get(5)?.parse().ok()?,
+        })
+    }
+}
+
+/// What the shell's terminal is running, for close confirmation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ForegroundState {
+    /// The shell owns the terminal: it is at a prompt or running a builtin
+    Idle,
+    /// Another process group owns the terminal; `name` is its leader's name
+    Job { name: Option<String> },
+    /// Process groups could not be read
+    Unknown,
+}
+
+/// Name of the foreground job that should hold up closing, if any.
+///
+/// `running_command` is the shell integration fallback used when the state
+/// is [`ForegroundState::Unknown`].  Jobs named in `jobs_to_ignore`
+/// (case-insensitive) never hold up closing.
+pub fn foreground_job_to_confirm(
+    state: ForegroundState,
+    running_command: Option<String>,
+    jobs_to_ignore: &[String],
+) -> Option<String> {
+    let name = match state {
+        ForegroundState::Idle => return None,
+        ForegroundState::Job { name } => name.unwrap_or_else(|| "job".to_string()),
+        ForegroundState::Unknown => running_command?,
+    };
+    let ignored = jobs_to_ignore
+        .iter()
+        .any(|job| job.eq_ignore_ascii_case(&name));
+    (!ignored).then_some(name)
+}
+
+#[cfg(target_os = "linux")]
+fn read_process_groups(pid: u32) -> Option<ProcessGroups> {
+    let stat = std::fs::read_to_string(format!("/proc/{pid}/stat")).ok()?;
+    ProcessGroups::from_proc_stat(&stat)
+}
+
+#[cfg(target_os = "linux")]
+fn process_name(pid: i32) -> Option<String> {
+    let comm = std::fs::read_to_string(format!("/proc/{pid}/comm")).ok()?;
+    Some(comm.trim_end().to_string()).filter(|name| !name.is_empty())
+}
+
+#[cfg(target_os = "macos")]
+fn ps_field(pid: impl std::fmt::Display, format: &str) -> Option<String> {
+    let output = std::process::Command::new("ps")
+        .args(["-o", format, "-p", &pid.to_string()])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(target_os = "macos")]
+fn read_process_groups(pid: u32) -> Option<ProcessGroups> {
+    let fields = ps_field(pid, "pgid=,tpgid=")?;
+    let mut fields = fields.split_whitespace();
+    Some(ProcessGroups {
+        shell: fields.next()?.parse().ok()?,
+        foreground: fields.next()?.parse().ok()?,
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn process_name(pid: i32) -> Option<String> {
+    let comm = ps_field(pid, "comm=")?;
+    let name = std::path::Path::new(&comm).file_name()?.to_str()?;
+    Some(name.to_string()).filter(|name| !name.is_empty())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn read_process_groups(_pid: u32) -> Option<ProcessGroups> {
+    None
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn process_name(_pid: i32) -> Option<String> {
+    None
+}
+
+impl TerminalManager {
+    /// Process groups of the shell and its terminal's foreground job, when
+    /// the platform exposes them.
+    pub fn process_groups(&self) -> Option<ProcessGroups> {
+        read_process_groups(self.get_shell_pid()?)
+    }
+
+    /// What the shell's terminal is running, from its process groups.
+    pub fn foreground_state(&self) -> ForegroundState {
+        match self.process_groups() {
+            Some(groups) if groups.has_foreground_job() => ForegroundState::Job {
+                name: process_name(groups.foreground),
+            },
+            Some(_) => ForegroundState::Idle,
+            None => ForegroundState::Unknown,
+        }
+    }
+
+    /// Whether a foreground job (not just the shell) is running.
+    ///
+    /// Falls back to the shell integration markers when process groups are
+    /// unavailable.
+    pub fn has_foreground_job(&self) -> bool {
+        match self.foreground_state() {
+            ForegroundState::Idle => false,
+            ForegroundState::Job { .. } => true,
+            ForegroundState::Unknown => self.is_command_running(),
+        }
+    }
+
+    /// Name of the job that should hold up closing this terminal, if any.
+    ///
+    /// With `foreground_only`, only a foreground job counts; otherwise any
+    /// child process does (see [`TerminalManager::should_confirm_close`]).
+    pub fn close_confirmation_job(
+        &self,
+        jobs_to_ignore: &[String],
+        foreground_only: bool,
+    ) -> Option<String> {
+        if !foreground_only {
+            return self.should_confirm_close(jobs_to_ignore);
+        }
+        foreground_job_to_confirm(
+            self.foreground_state(),
+            self.get_running_command_name(),
+            jobs_to_ignore,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ignore() -> Vec<String> {
+        vec!["bash".to_string(), "Less".to_string()]
+    }
+
+    #[test]
+    fn idle_shell_needs_no_confirmation() {
+        let idle = ProcessGroups {
+            shell: 100,
+            foreground: 100,
+        };
+        assert!(!idle.has_foreground_job());
+        assert_eq!(
+            foreground_job_to_confirm(ForegroundState::Idle, Some("vim".into()), &ignore()),
+            None,
+            "process groups override a stale shell integration marker"
+        );
+
+        // No controlling terminal
+        let detached = ProcessGroups {
+            shell: 100,
+            foreground: -1,
+        };
+        assert!(!detached.has_foreground_job());
+    }
+
+    #[test]
+    fn running_job_needs_confirmation_unless_ignored() {
+        let running = ProcessGroups {
+            shell: 100,
+            foreground: 230,
+        };
+        assert!(running.has_foreground_job());
+
+        let job = |name: &str| ForegroundState::Job {
+            name: Some(name.to_string()),
+        };
+        assert_eq!(
+            foreground_job_to_confirm(job("vim"), None, &ignore()),
+            Some("vim".to_string())
+        );
+        assert_eq!(
+            foreground_job_to_confirm(job("less"), None, &ignore()),
+            None
+        );
+        assert_eq!(
+            foreground_job_to_confirm(ForegroundState::Job { name: None }, None, &ignore()),
+            Some("job".to_string())
+        );
+    }
+
+    #[test]
+    fn unknown_state_falls_back_to_shell_integration() {
+        assert_eq!(
+            foreground_job_to_confirm(ForegroundState::Unknown, Some("make".into()), &ignore()),
+            Some("make".to_string())
+        );
+        assert_eq!(
+            foreground_job_to_confirm(ForegroundState::Unknown, None, &ignore()),
+            None
+        );
+        assert_eq!(
+            foreground_job_to_confirm(ForegroundState::Unknown, Some("bash".into()), &ignore()),
+            None
+        );
+    }
+
+    #[test]
+    fn parses_proc_stat() {
+        let stat = "4242 (my (odd) sh) S 1 4242 4242 34816 5000 4194560 0 0";
+        assert_eq!(
+            ProcessGroups::from_proc_stat(stat),
+            Some(ProcessGroups {
+                shell: 4242,
+                foreground: 5000,
+            })
+        );
+        assert_eq!(ProcessGroups::from_proc_stat("garbage"), None);
+    }
+}
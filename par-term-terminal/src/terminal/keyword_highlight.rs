@@ -0,0 +1,216 @@
+//! Persistent keyword highlighting.
+//!
+//! Saved keyword sets (`keyword_highlights` in the config) recolor every
+//! occurrence of their keywords in the visible cells, in scrollback as well
+//! as on screen.  Highlights are applied while cells are generated for
+//! rendering, so toggling a set takes effect on the next frame without
+//! touching the terminal contents.  Trigger highlights are applied
+//! afterwards and win where both match; selected cells are left alone so
+//! the selection stays visible.
+
+use super::TerminalManager;
+use par_term_config::{Cell, KeywordHighlightSet};
+
+/// An enabled keyword set, prepared for matching.
+#[derive(Debug, Clone)]
+struct CompiledSet {
+    /// Keywords, lowercased unless the set is case-sensitive
+    keywords: Vec<String>,
+    case_sensitive: bool,
+    whole_word: bool,
+    foreground: Option<[u8; 3]>,
+    background: Option<[u8; 3]>,
+}
+
+/// Matcher for the enabled keyword sets.
+#[derive(Debug, Clone, Default)]
+pub struct KeywordHighlighter {
+    sets: Vec<CompiledSet>,
+}
+
+impl KeywordHighlighter {
+    /// Build a highlighter from the configured sets; disabled sets, sets
+    /// without colors, and empty keywords are skipped.
+    pub fn new(sets: &[KeywordHighlightSet]) -> Self {
+        let sets = sets
+            .iter()
+            .filter(|set| set.enabled && (set.foreground.is_some() || set.background.is_some()))
+            .filter_map(|set| {
+                let keywords: Vec<String> = set
+                    .keywords
+                    .iter()
+                    .map(|k| k.trim())
+                    .filter(|k| !k.is_empty())
+                    .map(|k| {
+                        if set.case_sensitive {
+                            k.to_string()
+                        } else {
+                            k.to_lowercase()
+                        }
+                    })
+                    .collect();
+                (!keywords.is_empty()).then_some(CompiledSet {
+                    keywords,
+                    case_sensitive: set.case_sensitive,
+                    whole_word: set.whole_word,
+                    foreground: set.foreground,
+                    background: set.background,
+                })
+            })
+            .collect();
+        Self { sets }
+    }
+
+    /// Recolor keyword matches in `cells` (row-major, `cols` per row).
+    ///
+    /// `skip(col, row)` excludes cells, e.g. those in the selection.
+    pub fn apply(&self, cells: &mut [Cell], cols: usize, skip: impl Fn(usize, usize) -> bool) {
+        if self.sets.is_empty() || cols == 0 {
+            return;
+        }
+        for (row, row_cells) in cells.chunks_mut(cols).enumerate() {
+            for set in &self.sets {
+                let line = RowText::new(row_cells, set.case_sensitive);
+                for keyword in &set.keywords {
+                    for (start, end) in line.matches(keyword, set.whole_word) {
+                        for (col, cell) in row_cells.iter_mut().enumerate().take(end).skip(start) {
+                            if skip(col, row) {
+                                continue;
+                            }
+                            if let Some([r, g, b]) = set.foreground {
+                                cell.fg_color = [r, g, b, 255];
+                            }
+                            if let Some([r, g, b]) = set.background {
+                                cell.bg_color = [r, g, b, 255];
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Text of one row with the byte offset where each cell starts.
+struct RowText {
+    text: String,
+    offsets: Vec<usize>,
+}
+
+impl RowText {
+    fn new(cells: &[Cell], case_sensitive: bool) -> Self {
+        let mut text = String::new();
+        let mut offsets = Vec::with_capacity(cells.len() + 1);
+        for cell in cells {
+            offsets.push(text.len());
+            if cell.wide_char_spacer {
+                continue;
+            }
+            if cell.grapheme.is_empty() {
+                text.push(' ');
+            } else if case_sensitive {
+                text.push_str(&cell.grapheme);
+            } else {
+                text.push_str(&cell.grapheme.to_lowercase());
+            }
+        }
+        offsets.push(text.len());
+        Self { text, offsets }
+    }
+
+    /// Column ranges (start..end) where `keyword` occurs.
+    fn matches(&self, keyword: &str, whole_word: bool) -> Vec<(usize, usize)> {
+        let is_word = |c: char| c.is_alphanumeric() || c == '_';
+        self.text
+            .match_indices(keyword)
+            .filter(|(start, matched)| {
+                let end = start + matched.len();
+                !whole_word
+                    || (!self.text[..*start].chars().next_back().is_some_and(is_word)
+                        && !self.text[end..].chars().next().is_some_and(is_word))
+            })
+            .filter_map(|(start, matched)| {
+                let end = start + matched.len();
+                let first = self
+                    .offsets
+                    .partition_point(|&o| o <= start)
+                    .checked_sub(1)?;
+                let last = self.offsets.partition_point(|&o| o < end);
+                (first < last).then_some((first, last.min(self.offsets.len() - 1)))
+            })
+            .collect()
+    }
+}
+
+impl TerminalManager {
+    /// Set the keyword sets highlighted in rendered cells.
+    pub fn set_keyword_highlights(&mut self, sets: &[KeywordHighlightSet]) {
+        self.keyword_highlighter = KeywordHighlighter::new(sets);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: [u8; 3] = [255, 0, 0];
+
+    fn set(name: &str, keywords: &[&str], enabled: bool) -> KeywordHighlightSet {
+        KeywordHighlightSet {
+            enabled,
+            keywords: keywords.iter().map(|k| k.to_string()).collect(),
+            foreground: Some(RED),
+            ..KeywordHighlightSet::new(name)
+        }
+    }
+
+    fn red_columns(cells: &[Cell]) -> Vec<usize> {
+        cells
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.fg_color == [255, 0, 0, 255])
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    #[test]
+    fn enabling_a_set_highlights_its_keywords_and_disabling_removes_them() {
+        let mut manager = TerminalManager::new(30, 2).unwrap();
+        manager.process_data(b"ok error: FATAL ERRORS");
+
+        let mut sets = vec![set("errors", &["ERROR", "fatal"], true)];
+        manager.set_keyword_highlights(&sets);
+        // Case-insensitive, whole words only: "error" and "FATAL", not "ERRORS"
+        let highlighted = red_columns(&manager.get_cells_with_scrollback(0, None, false, None));
+        assert_eq!(highlighted, (3..8).chain(10..15).collect::<Vec<_>>());
+
+        sets[0].enabled = false;
+        manager.set_keyword_highlights(&sets);
+        let cells = manager.get_cells_with_scrollback(0, None, false, None);
+        assert!(red_columns(&cells).is_empty());
+    }
+
+    #[test]
+    fn options_control_case_and_word_boundaries() {
+        let mut manager = TerminalManager::new(30, 2).unwrap();
+        manager.process_data(b"Warn warning WARN");
+
+        let mut warn = set("warn", &["WARN"], true);
+        warn.case_sensitive = true;
+        warn.whole_word = false;
+        manager.set_keyword_highlights(&[warn]);
+        let cells = manager.get_cells_with_scrollback(0, None, false, None);
+        assert_eq!(red_columns(&cells), (13..17).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn selected_cells_keep_selection_colors() {
+        let mut manager = TerminalManager::new(30, 2).unwrap();
+        manager.process_data(b"ERROR");
+        manager.set_keyword_highlights(&[set("errors", &["error"], true)]);
+
+        let selection = Some(((0, 0), (1, 0)));
+        let cells = manager.get_cells_with_scrollback(0, selection, false, None);
+        assert_eq!(red_columns(&cells), vec![2, 3, 4]);
+    }
+}
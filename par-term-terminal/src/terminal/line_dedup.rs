@@ -0,0 +1,287 @@
+//! Collapsing of repeated output lines.
+//!
+//! Log spam (the same line printed over and over) wastes scrollback.  When
+//! enabled, [`RepeatedLineFilter`] keeps the first line of a run and replaces
+//! the rest with a journald-style counter line (`[last line repeated 3
+//! times]`) before the emulator sees them, so the scrollback, search and
+//! exports all hold the collapsed output.  While a run goes on, the counter
+//! line is rewritten in place with `CR` and `EL` and the cursor stays on it;
+//! the next different line moves past it.
+//!
+//! A line is held back only while it can still turn out to repeat the
+//! previous one, and never past the end of a read, so prompts and partial
+//! lines are shown as soon as they arrive.  Lines are compared byte for byte,
+//! escape sequences included.
+//!
+//! OSC 133 prompt and command marks from shell integration are hard
+//! boundaries: a line carrying one is never collapsed into the line before
+//! it, so output from different commands is never merged.  Blank lines are
+//! never collapsed, and the filter stands aside on the alternate screen,
+//! where full-screen applications draw rather than log.  Both PTY output and
+//! tmux pane data are collapsed (see [`super::output_filters`]).
+
+use super::TerminalManager;
+
+/// Introducer of an OSC 133 sequence.
+const OSC133_PREFIX: &[u8] = b"\x1b]133;";
+
+/// Sequences that switch to the alternate screen.
+const ALT_SCREEN_ENTER: [&[u8]; 3] = [b"\x1b[?1049h", b"\x1b[?1047h", b"\x1b[?47h"];
+
+/// Streaming filter that collapses runs of identical lines.
+#[derive(Debug, Default)]
+pub struct RepeatedLineFilter {
+    enabled: bool,
+    /// Previous complete line, prompt marks removed; `None` after a blank
+    /// line or a screen switch
+    last: Option<Vec<u8>>,
+    /// Bytes of the current line
+    line: Vec<u8>,
+    /// `line` is being held back as a possible repeat of `last`
+    held: bool,
+    /// Copies of `last` dropped so far; the counter line is shown when non-zero
+    repeats: usize,
+    /// Passing data through untouched (alternate screen)
+    suspended: bool,
+}
+
+impl RepeatedLineFilter {
+    /// Create a filter, collapsing repeats when `enabled`.
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            ..Self::default()
+        }
+    }
+
+    /// Whether repeats are collapsed.
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Collapse repeated lines in `input`.  `alt_screen` is whether the
+    /// alternate screen is active before `input` is processed.
+    pub fn filter(&mut self, input: &[u8], alt_screen: bool) -> Vec<u8> {
+        if alt_screen {
+            self.suspend();
+        } else {
+            self.suspended = false;
+        }
+        let mut out = Vec::with_capacity(input.len() + 32);
+        for &byte in input {
+            self.push(byte, &mut out);
+        }
+        // Never keep a partial line from the screen until the next read
+        if self.held && !self.line.is_empty() {
+            self.release(&mut out);
+        }
+        out
+    }
+
+    fn push(&mut self, byte: u8, out: &mut Vec<u8>) {
+        if self.suspended {
+            out.push(byte);
+            return;
+        }
+        self.line.push(byte);
+        if self.held {
+            if byte == b'\n' && self.repeats_last() {
+                self.repeats += 1;
+                self.line.clear();
+                out.push(b'\r');
+                out.extend_from_slice(counter_text(self.repeats).as_bytes());
+                out.extend_from_slice(b"\x1b[K");
+                return;
+            }
+            if byte != b'\n' && self.may_repeat_last() {
+                return;
+            }
+            self.release(out);
+        } else {
+            out.push(byte);
+        }
+        if byte == b'\n' {
+            self.end_line();
+        } else if byte == b'h' && ALT_SCREEN_ENTER.iter().any(|seq| self.line.ends_with(seq)) {
+            self.suspend();
+        }
+    }
+
+    /// `line` is still a prefix of `last` (or of `last` and its `CR LF`).
+    fn may_repeat_last(&self) -> bool {
+        let Some(last) = &self.last else {
+            return false;
+        };
+        last.starts_with(&self.line)
+            || (self.line.len() == last.len() + 1
+                && self.line.starts_with(last)
+                && self.line.ends_with(b"\r"))
+    }
+
+    /// `line` is `last` followed by its line ending.
+    fn repeats_last(&self) -> bool {
+        let Some(last) = &self.last else {
+            return false;
+        };
+        self.line
+            .strip_prefix(last.as_slice())
+            .is_some_and(|ending| ending == b"\r\n" || ending == b"\n")
+    }
+
+    /// Write out the held line, ending the counter line first.
+    fn release(&mut self, out: &mut Vec<u8>) {
+        if self.repeats > 0 {
+            out.extend_from_slice(b"\r\n");
+            self.repeats = 0;
+        }
+        out.extend_from_slice(&self.line);
+        self.held = false;
+    }
+
+    /// A line was written out: it becomes the one later lines may repeat.
+    fn end_line(&mut self) {
+        let mut text = std::mem::take(&mut self.line);
+        while text.last().is_some_and(|&b| b == b'\n' || b == b'\r') {
+            text.pop();
+        }
+        let text = strip_prompt_marks(&text);
+        self.last = (!text.is_empty()).then_some(text);
+        self.held = true;
+    }
+
+    fn suspend(&mut self) {
+        self.suspended = true;
+        self.last = None;
+        self.line.clear();
+        self.held = false;
+        self.repeats = 0;
+    }
+}
+
+/// Text of the counter line standing for `repeats` dropped copies.
+fn counter_text(repeats: usize) -> String {
+    let times = if repeats == 1 { "time" } else { "times" };
+    format!("[last line repeated {repeats} {times}]")
+}
+
+/// `line` without its OSC 133 sequences.
+fn strip_prompt_marks(line: &[u8]) -> Vec<u8> {
+    let mut text = Vec::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest
+        .windows(OSC133_PREFIX.len())
+        .position(|window| window == OSC133_PREFIX)
+    {
+        text.extend_from_slice(&rest[..start]);
+        let marker = &rest[start + OSC133_PREFIX.len()..];
+        let end = marker
+            .iter()
+            .position(|&b| b == 0x07)
+            .map(|bel| bel + 1)
+            .or_else(|| {
+                marker
+                    .windows(2)
+                    .position(|window| window == b"\x1b\\")
+                    .map(|st| st + 2)
+            })
+            .unwrap_or(marker.len());
+        rest = &marker[end..];
+    }
+    text.extend_from_slice(rest);
+    text
+}
+
+impl TerminalManager {
+    /// Collapse consecutive identical output lines into a counter line.
+    pub fn set_collapse_repeated_lines(&mut self, enabled: bool) {
+        *self.output_filters.repeated_lines.lock() = RepeatedLineFilter::new(enabled);
+    }
+
+    /// Whether repeated output lines are collapsed.
+    pub fn collapse_repeated_lines(&self) -> bool {
+        self.output_filters.repeated_lines.lock().enabled()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(manager: &TerminalManager) -> Vec<String> {
+        let content = manager.terminal().read().content();
+        content
+            .lines()
+            .map(|row| row.trim_end().to_string())
+            .filter(|row| !row.is_empty())
+            .collect()
+    }
+
+    #[test]
+    fn three_identical_lines_collapse_to_one_counter_line() {
+        let mut filter = RepeatedLineFilter::new(true);
+        assert_eq!(
+            filter.filter(b"start\r\nspam\r\n", false),
+            b"start\r\nspam\r\n"
+        );
+        assert_eq!(
+            filter.filter(b"spam\r\nspam\r\n", false),
+            b"\r[last line repeated 1 time]\x1b[K\r[last line repeated 2 times]\x1b[K"
+        );
+        assert_eq!(filter.filter(b"end\r\n", false), b"\r\nend\r\n".as_slice());
+
+        let mut manager = TerminalManager::new(40, 6).unwrap();
+        manager.set_collapse_repeated_lines(true);
+        manager.process_data(b"start\r\nspam\r\nspam\r\nspam\r\nend\r\n");
+        assert_eq!(
+            rows(&manager),
+            ["start", "spam", "[last line repeated 2 times]", "end"]
+        );
+    }
+
+    #[test]
+    fn prompt_mark_is_a_collapse_boundary() {
+        let mut manager = TerminalManager::new(40, 6).unwrap();
+        manager.set_collapse_repeated_lines(true);
+        manager.process_data(b"spam\r\nspam\r\n\x1b]133;C\x07spam\r\nspam\r\n");
+        assert_eq!(
+            rows(&manager),
+            [
+                "spam",
+                "[last line repeated 1 time]",
+                "spam",
+                "[last line repeated 1 time]"
+            ]
+        );
+    }
+
+    #[test]
+    fn partial_lines_blank_lines_and_alt_screen_pass_through() {
+        let mut filter = RepeatedLineFilter::new(true);
+        filter.filter(b"$ \r\n", false);
+        // A possible repeat is not held past the end of the read
+        assert_eq!(filter.filter(b"$ ", false), b"$ ");
+        assert_eq!(filter.filter(b"\r\n\r\n\r\n", false), b"\r\n\r\n\r\n");
+
+        filter.filter(b"~\r\n", false);
+        assert_eq!(
+            filter.filter(b"\x1b[?1049h~\r\n~\r\n", false),
+            b"\x1b[?1049h~\r\n~\r\n"
+        );
+        assert_eq!(filter.filter(b"~\r\n~\r\n", true), b"~\r\n~\r\n");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn pty_output_is_collapsed() {
+        let mut manager = TerminalManager::new(40, 6).unwrap();
+        manager.set_collapse_repeated_lines(true);
+        crate::terminal::spawn::tests::run_script(
+            &mut manager,
+            r"printf 'spam\nspam\nspam\nspam\nend\n'",
+        );
+        assert_eq!(
+            rows(&manager),
+            ["spam", "[last line repeated 3 times]", "end"]
+        );
+    }
+}
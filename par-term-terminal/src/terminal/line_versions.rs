@@ -0,0 +1,129 @@
+//! Per-line change versions for incremental observers.
+//!
+//! The core emulator's dirty-row set is consumed by the renderer every frame,
+//! so other readers (the scripting observer, external integrations) cannot
+//! rely on it.  Instead each visible row remembers a content hash and the
+//! version at which it last changed; [`TerminalManager::changed_lines_since`]
+//! refreshes them on demand and returns the rows newer than the caller's
+//! last-seen version.  Any number of observers can poll independently, each
+//! holding its own version.
+
+use super::TerminalManager;
+use super::content_hash::row_content_hash;
+
+/// Row hashes and the version at which each row last changed.
+#[derive(Debug, Default)]
+pub(crate) struct LineVersions {
+    /// Bumped once per poll that observed at least one changed row.
+    version: u64,
+    row_hashes: Vec<u64>,
+    row_versions: Vec<u64>,
+}
+
+impl LineVersions {
+    /// Fold in the current row hashes and return the (possibly advanced) version.
+    ///
+    /// A change in row count (first poll or resize) marks every row changed.
+    fn update(&mut self, hashes: Vec<u64>) -> u64 {
+        if hashes.len() != self.row_hashes.len() {
+            self.version += 1;
+            self.row_versions = vec![self.version; hashes.len()];
+            self.row_hashes = hashes;
+            return self.version;
+        }
+
+        let next = self.version + 1;
+        let mut changed = false;
+        for (row, hash) in hashes.into_iter().enumerate() {
+            if self.row_hashes[row] != hash {
+                self.row_hashes[row] = hash;
+                self.row_versions[row] = next;
+                changed = true;
+            }
+        }
+        if changed {
+            self.version = next;
+        }
+        self.version
+    }
+
+    /// Rows whose last change is newer than `version`, in ascending order.
+    fn rows_since(&self, version: u64) -> Vec<usize> {
+        self.row_versions
+            .iter()
+            .enumerate()
+            .filter(|(_, row_version)| **row_version > version)
+            .map(|(row, _)| row)
+            .collect()
+    }
+}
+
+impl TerminalManager {
+    /// Visible rows changed after `version`, plus the current line version.
+    ///
+    /// Pass `0` on the first call to receive every row, then pass back the
+    /// returned version to receive only the rows that changed since.  Row
+    /// indices refer to the active screen (no scrollback); scrolling changes
+    /// every row that moved.  Independent of the renderer's dirty-row
+    /// tracking, so polling never hides changes from the renderer.
+    pub fn changed_lines_since(&self, version: u64) -> (Vec<usize>, u64) {
+        let hashes: Vec<u64> = {
+            let pty = self.pty_session.lock();
+            let terminal = pty.terminal();
+            let term = terminal.read();
+            let grid = term.active_grid();
+            (0..grid.rows())
+                .map(|row| grid.row(row).map_or(0, row_content_hash))
+                .collect()
+        };
+
+        let mut lines = self.line_versions.lock();
+        let current = lines.update(hashes);
+        (lines.rows_since(version), current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(manager: &TerminalManager, data: &[u8]) {
+        let terminal = manager.terminal();
+        terminal.write().process(data);
+    }
+
+    #[test]
+    fn first_poll_reports_every_row() {
+        let manager = TerminalManager::new(20, 5).unwrap();
+        let (rows, version) = manager.changed_lines_since(0);
+        assert_eq!(rows, vec![0, 1, 2, 3, 4]);
+        assert!(version > 0);
+    }
+
+    #[test]
+    fn writing_two_rows_reports_exactly_those_rows() {
+        let manager = TerminalManager::new(20, 5).unwrap();
+        let (_, version) = manager.changed_lines_since(0);
+
+        process(&manager, b"\x1b[2;1Hfoo\x1b[4;1Hbar");
+        let (rows, next) = manager.changed_lines_since(version);
+        assert_eq!(rows, vec![1, 3]);
+        assert!(next > version);
+
+        // Nothing changed since: no rows, version stays put.
+        assert_eq!(manager.changed_lines_since(next), (vec![], next));
+    }
+
+    #[test]
+    fn observers_polling_at_different_rates_see_all_changes() {
+        let manager = TerminalManager::new(20, 5).unwrap();
+        let (_, slow) = manager.changed_lines_since(0);
+
+        process(&manager, b"\x1b[1;1Hfirst");
+        let (_, fast) = manager.changed_lines_since(slow);
+        process(&manager, b"\x1b[5;1Hlast");
+
+        assert_eq!(manager.changed_lines_since(fast).0, vec![4]);
+        assert_eq!(manager.changed_lines_since(slow).0, vec![0, 4]);
+    }
+}
@@ -0,0 +1,245 @@
+//! Cap on the length of a single output line.
+//!
+//! A multi-megabyte line with no newline makes every wrap, reflow and
+//! logical-line scan in the emulator walk the whole line.  [`LongLineFilter`]
+//! bounds that cost: once a line reaches the configured number of
+//! characters, it inserts a break (`CR LF`) before the next character and
+//! counts the split in [`LongLineStats`], so callers can tell the line was
+//! cut.
+//!
+//! Characters are counted between line breaks.  CR, LF, VT and FF end a
+//! line, as do control sequences that move the cursor (any CSI other than
+//! SGR, and `IND`, `NEL`, `RI`, `DECRC`, `RIS`); SGR and string sequences
+//! such as OSC titles do not count towards the length.  Both PTY output and
+//! tmux pane data are capped (see [`super::output_filters`]).
+
+use super::TerminalManager;
+
+const ESC: u8 = 0x1b;
+const BEL: u8 = 0x07;
+const CAN: u8 = 0x18;
+const SUB: u8 = 0x1a;
+
+/// Break inserted when a line reaches the cap.
+const SOFT_BREAK: &[u8] = b"\r\n";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Ground,
+    /// After ESC, or inside an escape sequence's intermediate bytes
+    Escape,
+    /// Inside `ESC [` … final byte
+    Csi,
+    /// Inside an OSC/DCS/APC/PM/SOS string
+    String,
+    /// After ESC inside a string (possible ST)
+    StringEscape,
+}
+
+/// Lines cut by a [`LongLineFilter`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LongLineStats {
+    /// Breaks inserted so far
+    pub lines_split: u64,
+    /// The line being written has been cut at least once
+    pub current_line_truncated: bool,
+}
+
+/// Streaming filter that breaks lines longer than a maximum length.
+#[derive(Debug)]
+pub struct LongLineFilter {
+    /// Characters allowed per line; zero disables the cap
+    max_length: usize,
+    state: State,
+    /// Characters written since the last line break
+    length: usize,
+    stats: LongLineStats,
+}
+
+impl Default for LongLineFilter {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl LongLineFilter {
+    /// Create a filter that breaks lines after `max_length` characters
+    /// (zero disables the cap).
+    pub fn new(max_length: usize) -> Self {
+        Self {
+            max_length,
+            state: State::Ground,
+            length: 0,
+            stats: LongLineStats::default(),
+        }
+    }
+
+    /// Characters allowed per line, or zero when uncapped.
+    pub fn max_length(&self) -> usize {
+        self.max_length
+    }
+
+    /// Lines cut so far.
+    pub fn stats(&self) -> LongLineStats {
+        self.stats
+    }
+
+    /// Break the over-long lines in `input`.
+    pub fn filter(&mut self, input: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(input.len());
+        for &byte in input {
+            self.push(byte, &mut out);
+        }
+        out
+    }
+
+    fn push(&mut self, byte: u8, out: &mut Vec<u8>) {
+        match self.state {
+            State::Ground => match byte {
+                ESC => self.state = State::Escape,
+                b'\r' | b'\n' | 0x0b | 0x0c => self.end_line(),
+                // DEL, and UTF-8 continuation bytes of the character before
+                0x7f..=0xbf => {}
+                0x20.. => {
+                    if self.max_length > 0 && self.length >= self.max_length {
+                        out.extend_from_slice(SOFT_BREAK);
+                        self.length = 0;
+                        self.stats.lines_split += 1;
+                        self.stats.current_line_truncated = true;
+                    }
+                    self.length += 1;
+                }
+                _ => {}
+            },
+            State::Escape => self.escape(byte),
+            State::Csi => match byte {
+                ESC => self.state = State::Escape,
+                CAN | SUB => self.state = State::Ground,
+                0x40..=0x7e => {
+                    self.state = State::Ground;
+                    if byte != b'm' {
+                        self.end_line();
+                    }
+                }
+                _ => {}
+            },
+            State::String => match byte {
+                ESC => self.state = State::StringEscape,
+                BEL | CAN | SUB => self.state = State::Ground,
+                _ => {}
+            },
+            State::StringEscape => {
+                if byte == b'\\' {
+                    self.state = State::Ground;
+                } else {
+                    self.escape(byte);
+                }
+            }
+        }
+        out.push(byte);
+    }
+
+    /// Advance from the escape state on `byte`.
+    fn escape(&mut self, byte: u8) {
+        self.state = match byte {
+            b'[' => State::Csi,
+            b']' | b'P' | b'_' | b'^' | b'X' => State::String,
+            // Intermediates, e.g. `ESC ( B`
+            ESC | 0x20..=0x2f => State::Escape,
+            b'D' | b'E' | b'M' | b'8' | b'c' => {
+                self.end_line();
+                State::Ground
+            }
+            _ => State::Ground,
+        };
+    }
+
+    fn end_line(&mut self) {
+        self.length = 0;
+        self.stats.current_line_truncated = false;
+    }
+}
+
+impl TerminalManager {
+    /// Break output lines after `max_length` characters (zero disables the
+    /// cap).
+    pub fn set_max_line_length(&mut self, max_length: usize) {
+        *self.output_filters.long_lines.lock() = LongLineFilter::new(max_length);
+    }
+
+    /// Characters allowed per output line, or zero when uncapped.
+    pub fn max_line_length(&self) -> usize {
+        self.output_filters.long_lines.lock().max_length()
+    }
+
+    /// Lines cut by the line length cap so far.
+    pub fn long_line_stats(&self) -> LongLineStats {
+        self.output_filters.long_lines.lock().stats()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_over_the_cap_is_split_at_the_boundary() {
+        let mut filter = LongLineFilter::new(4);
+        assert_eq!(filter.filter(b"abcd"), b"abcd");
+        assert!(!filter.stats().current_line_truncated);
+        // Split across reads: the break goes before the fifth character
+        assert_eq!(filter.filter(b"efghij"), b"\r\nefgh\r\nij");
+        assert_eq!(filter.stats().lines_split, 2);
+        assert!(filter.stats().current_line_truncated);
+
+        // A newline starts over and clears the flag
+        assert_eq!(filter.filter(b"\nabcd\r\n"), b"\nabcd\r\n");
+        assert_eq!(filter.stats().lines_split, 2);
+        assert!(!filter.stats().current_line_truncated);
+
+        // Zero disables the cap
+        let long = vec![b'x'; 10_000];
+        let mut uncapped = LongLineFilter::default();
+        assert_eq!(uncapped.filter(&long), long);
+        assert_eq!(uncapped.stats(), LongLineStats::default());
+    }
+
+    #[test]
+    fn characters_not_bytes_or_sequences_are_counted() {
+        let mut filter = LongLineFilter::new(3);
+        // SGR and OSC do not count; a multi-byte character counts once
+        assert_eq!(
+            filter.filter("\x1b[1;31mé\x1b]0;title\x07\x1b(Bxy\x1b[0mz".as_bytes()),
+            "\x1b[1;31mé\x1b]0;title\x07\x1b(Bxy\x1b[0m\r\nz".as_bytes()
+        );
+        // Cursor movement ends the line, as does a carriage return redraw
+        let mut filter = LongLineFilter::new(3);
+        assert_eq!(filter.filter(b"abc\x1b[Hdef\rghi"), b"abc\x1b[Hdef\rghi");
+        assert_eq!(filter.stats().lines_split, 0);
+    }
+
+    #[test]
+    fn capped_line_wraps_in_the_grid() {
+        let mut manager = TerminalManager::new(20, 4).unwrap();
+        manager.set_max_line_length(5);
+        manager.process_data(b"abcdefghijkl\r\nok");
+        let content = manager.terminal().read().content();
+        let rows: Vec<&str> = content.lines().map(str::trim_end).collect();
+        assert_eq!(rows, ["abcde", "fghij", "kl", "ok"]);
+        let stats = manager.long_line_stats();
+        assert_eq!(stats.lines_split, 2);
+        assert!(!stats.current_line_truncated);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn pty_output_is_capped() {
+        let mut manager = TerminalManager::new(20, 4).unwrap();
+        manager.set_max_line_length(5);
+        crate::terminal::spawn::tests::run_script(&mut manager, "printf abcdefghijkl");
+        let content = manager.terminal().read().content();
+        let rows: Vec<&str> = content.lines().map(str::trim_end).collect();
+        assert_eq!(rows[..3], ["abcde", "fghij", "kl"]);
+        assert_eq!(manager.long_line_stats().lines_split, 2);
+    }
+}
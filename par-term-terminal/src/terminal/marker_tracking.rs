@@ -6,6 +6,9 @@
 use super::ShellLifecycleEvent;
 use par_term_emu_core_rust::shell_integration::ShellIntegrationMarker;
 
+/// Finished commands kept for a terminal nobody polls; older ones are dropped.
+const MAX_FINISHED_COMMANDS: usize = 64;
+
 /// Encapsulates the mutable shell-integration marker state that
 /// `TerminalManager` carries between frames.
 ///
@@ -27,6 +30,10 @@ pub(crate) struct MarkerTracker {
     pub captured_command_text: Option<(usize, String)>,
     /// Queued shell lifecycle events.
     pub shell_lifecycle_events: Vec<ShellLifecycleEvent>,
+    /// `CommandFinished` events queued separately for completion
+    /// notifications, which poll every terminal rather than only the
+    /// rendered one.
+    pub finished_commands: Vec<ShellLifecycleEvent>,
 }
 
 impl MarkerTracker {
@@ -37,6 +44,7 @@ impl MarkerTracker {
             command_start_pos: None,
             captured_command_text: None,
             shell_lifecycle_events: Vec::new(),
+            finished_commands: Vec::new(),
         }
     }
 
@@ -52,6 +60,11 @@ impl MarkerTracker {
         std::mem::take(&mut self.shell_lifecycle_events)
     }
 
+    /// Drain queued `CommandFinished` events for completion notifications.
+    pub fn drain_finished_commands(&mut self) -> Vec<ShellLifecycleEvent> {
+        std::mem::take(&mut self.finished_commands)
+    }
+
     /// Process a single shell integration event update.
     ///
     /// Updates `last_shell_marker`, `command_start_pos`, and
@@ -100,9 +113,24 @@ impl MarkerTracker {
     }
 
     /// Push a `CommandFinished` lifecycle event.
-    pub fn push_command_finished(&mut self, absolute_line: usize) {
-        self.shell_lifecycle_events
-            .push(ShellLifecycleEvent::CommandFinished { absolute_line });
+    pub fn push_command_finished(
+        &mut self,
+        absolute_line: usize,
+        command: Option<String>,
+        exit_code: Option<i32>,
+        duration_ms: Option<u64>,
+    ) {
+        let event = ShellLifecycleEvent::CommandFinished {
+            absolute_line,
+            command,
+            exit_code,
+            duration_ms,
+        };
+        if self.finished_commands.len() >= MAX_FINISHED_COMMANDS {
+            self.finished_commands.remove(0);
+        }
+        self.finished_commands.push(event.clone());
+        self.shell_lifecycle_events.push(event);
     }
 
     /// Take the captured command text, if any.
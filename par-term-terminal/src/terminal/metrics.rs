@@ -0,0 +1,179 @@
+//! Output counters for a terminal session.
+//!
+//! [`OutputCounters`] counts the bytes and newlines a [`TerminalManager`]
+//! receives, both from its PTY (through the output callback the reader thread
+//! invokes for every read) and through [`TerminalManager::process_data`].
+//! Recording is a few relaxed atomic adds per read, so it is always on.
+//! [`TerminalMetrics`] is a point-in-time copy for status and diagnostics
+//! views; [`TerminalMetrics::combine`] sums the panes of a tab.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use super::TerminalManager;
+
+/// Live counters shared with the PTY reader thread.
+#[derive(Debug)]
+pub(crate) struct OutputCounters {
+    started: Instant,
+    bytes: AtomicU64,
+    lines: AtomicU64,
+    /// Nanoseconds after `started` of the latest output; zero before any
+    last_activity_nanos: AtomicU64,
+}
+
+impl OutputCounters {
+    pub(crate) fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            bytes: AtomicU64::new(0),
+            lines: AtomicU64::new(0),
+            last_activity_nanos: AtomicU64::new(0),
+        }
+    }
+
+    /// Count one chunk of output.
+    pub(crate) fn record(&self, data: &[u8]) {
+        if data.is_empty() {
+            return;
+        }
+        let lines = data.iter().filter(|&&byte| byte == b'\n').count();
+        self.bytes.fetch_add(data.len() as u64, Ordering::Relaxed);
+        self.lines.fetch_add(lines as u64, Ordering::Relaxed);
+        let nanos = u64::try_from(self.started.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        self.last_activity_nanos
+            .fetch_max(nanos.max(1), Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> TerminalMetrics {
+        let last_activity = match self.last_activity_nanos.load(Ordering::Relaxed) {
+            0 => None,
+            nanos => Some(self.started + Duration::from_nanos(nanos)),
+        };
+        TerminalMetrics {
+            bytes_processed: self.bytes.load(Ordering::Relaxed),
+            lines_produced: self.lines.load(Ordering::Relaxed),
+            uptime: self.started.elapsed(),
+            last_activity,
+        }
+    }
+}
+
+/// Output counters of a terminal, or of several combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalMetrics {
+    /// Bytes of output received
+    pub bytes_processed: u64,
+    /// Newlines in that output
+    pub lines_produced: u64,
+    /// Time since the terminal was created
+    pub uptime: Duration,
+    /// When output last arrived, if it has
+    pub last_activity: Option<Instant>,
+}
+
+impl TerminalMetrics {
+    /// Totals of `self` and `other`: counters are summed, and the longest
+    /// uptime and the latest activity are kept.
+    pub fn combine(self, other: Self) -> Self {
+        Self {
+            bytes_processed: self.bytes_processed + other.bytes_processed,
+            lines_produced: self.lines_produced + other.lines_produced,
+            uptime: self.uptime.max(other.uptime),
+            last_activity: self.last_activity.max(other.last_activity),
+        }
+    }
+
+    /// Time since output last arrived, if it has.
+    pub fn idle_for(&self) -> Option<Duration> {
+        self.last_activity.map(|at| at.elapsed())
+    }
+}
+
+impl TerminalManager {
+    /// Output counters for this terminal.
+    pub fn metrics(&self) -> TerminalMetrics {
+        self.output_counters.snapshot()
+    }
+
+    /// Bytes of output received so far.
+    pub fn bytes_processed(&self) -> u64 {
+        self.output_counters.bytes.load(Ordering::Relaxed)
+    }
+
+    /// Newlines received so far.
+    pub fn lines_produced(&self) -> u64 {
+        self.output_counters.lines.load(Ordering::Relaxed)
+    }
+
+    /// Time since this terminal was created.
+    pub fn uptime(&self) -> Duration {
+        self.output_counters.started.elapsed()
+    }
+
+    /// When output last arrived, if it has.
+    pub fn last_activity(&self) -> Option<Instant> {
+        self.output_counters.snapshot().last_activity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counters_follow_processed_output() {
+        let manager = TerminalManager::new(20, 4).unwrap();
+        let fresh = manager.metrics();
+        assert_eq!(fresh.bytes_processed, 0);
+        assert_eq!(fresh.lines_produced, 0);
+        assert_eq!(fresh.last_activity, None);
+
+        manager.process_data(b"one\r\ntwo\r\n");
+        manager.process_data(b"");
+        manager.process_data(b"\x1b[31mthree");
+        assert_eq!(manager.bytes_processed(), 20);
+        assert_eq!(manager.lines_produced(), 2);
+        let first = manager.last_activity().expect("output was recorded");
+
+        manager.process_data(b"\n");
+        let metrics = manager.metrics();
+        assert_eq!(metrics.bytes_processed, 21);
+        assert_eq!(metrics.lines_produced, 3);
+        assert!(metrics.last_activity.unwrap() >= first);
+        assert!(metrics.uptime >= fresh.uptime);
+    }
+
+    #[test]
+    fn record_counts_bytes_and_newlines() {
+        let counters = OutputCounters::new();
+        counters.record(b"a\nb\nc");
+        counters.record(b"\n");
+        let metrics = counters.snapshot();
+        assert_eq!(metrics.bytes_processed, 6);
+        assert_eq!(metrics.lines_produced, 3);
+        assert!(metrics.last_activity.is_some());
+    }
+
+    #[test]
+    fn combined_metrics_sum_counters() {
+        let now = Instant::now();
+        let a = TerminalMetrics {
+            bytes_processed: 10,
+            lines_produced: 2,
+            uptime: Duration::from_secs(5),
+            last_activity: None,
+        };
+        let b = TerminalMetrics {
+            bytes_processed: 7,
+            lines_produced: 1,
+            uptime: Duration::from_secs(3),
+            last_activity: Some(now),
+        };
+        let total = a.combine(b);
+        assert_eq!(total.bytes_processed, 17);
+        assert_eq!(total.lines_produced, 3);
+        assert_eq!(total.uptime, Duration::from_secs(5));
+        assert_eq!(total.last_activity, Some(now));
+    }
+}
@@ -1,10 +1,9 @@
 use crate::scrollback_metadata::ScrollbackMetadata;
 use anyhow::Result;
 use par_term_config::Theme;
-use par_term_emu_core_rust::pty_session::PtySession;
 use par_term_emu_core_rust::terminal::Terminal;
 use parking_lot::{Mutex, RwLock};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 /// Events produced by shell-integration markers for command lifecycle consumers.
 #[derive(Debug, Clone)]
@@ -15,23 +14,55 @@ pub enum ShellLifecycleEvent {
         absolute_line: usize,
     },
     /// A command has finished executing (OSC 133 D marker).
-    CommandFinished { absolute_line: usize },
+    CommandFinished {
+        absolute_line: usize,
+        /// The command text, when its start was seen
+        command: Option<String>,
+        /// Exit status reported by the shell
+        exit_code: Option<i32>,
+        /// Time from the command's start to its end, when its start was seen
+        duration_ms: Option<u64>,
+    },
 }
 
 // Re-export clipboard types for use in other modules
 pub use par_term_emu_core_rust::terminal::{ClipboardEntry, ClipboardSlot};
 
 pub mod clipboard;
+pub(crate) mod color_limit;
+pub(crate) mod content_hash;
+pub(crate) mod cursor_style;
+pub mod cwd_history;
+pub mod desktop_notifications;
+pub mod export;
+pub mod form_feed;
 pub mod graphics;
 pub mod hyperlinks;
+pub mod input_encoding;
+pub mod jobs;
+pub mod keyword_highlight;
+pub mod line_dedup;
+pub(crate) mod line_versions;
+pub mod long_line;
 pub(crate) mod marker_tracking;
+pub mod metrics;
+pub mod mode_report;
 pub(crate) mod observers;
+pub mod osc52;
+pub(crate) mod output_filters;
+pub mod printer;
 pub(crate) mod progress;
+pub mod prompt_continuation;
+pub(crate) mod pty_host;
 pub mod rendering;
 pub mod scrollback;
+pub mod search;
+pub mod sensitive_input;
+pub mod sgr_coalesce;
 pub mod spawn;
 pub(crate) mod terminal_config;
 pub(crate) mod tmux_control;
+pub mod tmux_passthrough;
 pub(crate) mod triggers;
 
 // Re-export coprocess_env from spawn so existing callers keep working
@@ -40,7 +71,11 @@ pub use spawn::coprocess_env;
 /// Terminal manager that wraps the PTY session
 pub struct TerminalManager {
     /// The underlying PTY session
-    pub(crate) pty_session: Arc<Mutex<PtySession>>,
+    pub(crate) pty_session: Arc<Mutex<pty_host::PtyHost>>,
+    /// Exit code of the PTY child once reaped; cleared when a new one spawns.
+    pub(crate) exit_code: OnceLock<i32>,
+    /// Output rewriting shared with the PTY reader (see [`output_filters`]).
+    pub(crate) output_filters: Arc<output_filters::OutputFilters>,
     /// Terminal dimensions (cols, rows)
     pub(crate) dimensions: (usize, usize),
     /// Color theme for ANSI colors
@@ -63,6 +98,38 @@ pub struct TerminalManager {
     /// graphics via `adjust_for_scroll_up_with_scrollback()` and marks all
     /// rows dirty — those dirty rows must NOT trigger graphic invalidation.
     prev_scrollback_len: Mutex<usize>,
+    /// Per-screen DECSCUSR cursor style bookkeeping (primary vs alternate).
+    pub(crate) cursor_style_tracker: Mutex<cursor_style::CursorStyleTracker>,
+    /// What happens to marks on lines trimmed from a full scrollback.
+    pub(crate) mark_trim_policy: par_term_config::MarkTrimPolicy,
+    /// Last `(total_lines_scrolled, scrollback_len)` seen by
+    /// [`TerminalManager::update_scrollback_metadata`], for detecting trims.
+    pub(crate) scrollback_sample: Option<(usize, usize)>,
+    /// Per-row change versions for [`TerminalManager::changed_lines_since`].
+    line_versions: Mutex<line_versions::LineVersions>,
+    /// Unwrap tmux DCS passthrough in [`TerminalManager::process_data`].
+    pub(crate) unwrap_tmux_passthrough: bool,
+    /// Streaming state for tmux passthrough unwrapping (see [`tmux_passthrough`]).
+    pub(crate) tmux_passthrough: Mutex<tmux_passthrough::TmuxPassthroughFilter>,
+    /// Clipboard history shared with other terminals (see [`clipboard`]).
+    pub(crate) shared_clipboard: Mutex<Option<clipboard::SharedClipboardHistory>>,
+    /// OSC 133 continuation prompt folding in [`TerminalManager::process_data`]
+    /// (see [`prompt_continuation`]).
+    pub(crate) prompt_continuation: Mutex<prompt_continuation::PromptContinuationFilter>,
+    /// Continuation verdicts for OSC 133 markers in raw PTY output.
+    pub(crate) prompt_markers: Arc<prompt_continuation::PromptMarkerTap>,
+    /// Color depth while the alternate screen is active (see [`color_limit`]).
+    pub(crate) alt_screen_color_mode: Option<par_term_config::ColorMode>,
+    /// Saved keyword sets highlighted in rendered cells (see [`keyword_highlight`]).
+    pub(crate) keyword_highlighter: keyword_highlight::KeywordHighlighter,
+    /// Password prompt detection state (see [`sensitive_input`]).
+    pub(crate) sensitive_input: sensitive_input::SensitiveInput,
+    /// Link under the mouse (see [`hyperlinks`]).
+    pub(crate) hyperlink_hover: Mutex<hyperlinks::HyperlinkHoverTracker>,
+    /// Rate limit on application notifications (see [`desktop_notifications`]).
+    pub(crate) notification_limiter: Mutex<desktop_notifications::NotificationRateLimiter>,
+    /// Output byte/line counters, shared with the PTY reader (see [`metrics`]).
+    pub(crate) output_counters: Arc<metrics::OutputCounters>,
 }
 
 impl TerminalManager {
@@ -80,17 +147,48 @@ impl TerminalManager {
             scrollback_size
         );
 
-        let pty_session = PtySession::new(cols, rows, scrollback_size);
+        let output_filters = Arc::new(output_filters::OutputFilters::default());
+        let mut pty_session =
+            pty_host::PtyHost::new(cols, rows, scrollback_size, Arc::clone(&output_filters));
+        let output_counters = Arc::new(metrics::OutputCounters::new());
+        let counters = Arc::clone(&output_counters);
+        let prompt_markers = Arc::new(prompt_continuation::PromptMarkerTap::default());
+        let markers = Arc::clone(&prompt_markers);
+        pty_session.set_output_callback(Arc::new(move |data: &[u8]| {
+            counters.record(data);
+            markers.record(data);
+        }));
         let pty_session = Arc::new(Mutex::new(pty_session));
 
         Ok(Self {
             pty_session,
+            exit_code: OnceLock::new(),
+            output_filters,
             dimensions: (cols, rows),
             theme: Theme::default(),
             scrollback_metadata: ScrollbackMetadata::new(),
             marker_tracker: marker_tracking::MarkerTracker::new(),
             known_graphic_times: Mutex::new(std::collections::HashMap::new()),
             prev_scrollback_len: Mutex::new(0),
+            cursor_style_tracker: Mutex::new(cursor_style::CursorStyleTracker::default()),
+            mark_trim_policy: par_term_config::MarkTrimPolicy::default(),
+            scrollback_sample: None,
+            line_versions: Mutex::new(line_versions::LineVersions::default()),
+            unwrap_tmux_passthrough: false,
+            tmux_passthrough: Mutex::new(tmux_passthrough::TmuxPassthroughFilter::default()),
+            shared_clipboard: Mutex::new(None),
+            prompt_continuation: Mutex::new(
+                prompt_continuation::PromptContinuationFilter::default(),
+            ),
+            prompt_markers,
+            alt_screen_color_mode: None,
+            keyword_highlighter: keyword_highlight::KeywordHighlighter::default(),
+            sensitive_input: sensitive_input::SensitiveInput::default(),
+            hyperlink_hover: Mutex::new(hyperlinks::HyperlinkHoverTracker::default()),
+            notification_limiter: Mutex::new(
+                desktop_notifications::NotificationRateLimiter::default(),
+            ),
+            output_counters,
         })
     }
 
@@ -180,6 +278,19 @@ impl TerminalManager {
         pty.is_running()
     }
 
+    /// Exit code of the PTY child process, once it has exited.
+    ///
+    /// Returns `None` while the process is still running, or when no process
+    /// was spawned. Reaps the child on first call after exit; the status is
+    /// cached so later calls return the same code.
+    pub fn exit_code(&self) -> Option<i32> {
+        if let Some(&code) = self.exit_code.get() {
+            return Some(code);
+        }
+        let code = self.pty_session.lock().try_wait().ok().flatten()?;
+        Some(*self.exit_code.get_or_init(|| code))
+    }
+
     /// Kill the PTY process
     pub fn kill(&mut self) -> Result<()> {
         let mut pty = self.pty_session.lock();
@@ -453,6 +564,9 @@ impl TerminalManager {
         let terminal = pty.terminal();
         let mut term = terminal.write();
         term.set_cursor_style(style);
+        self.cursor_style_tracker
+            .lock()
+            .record_configured(style, term.is_alt_screen_active());
     }
 
     /// Check if cursor is visible
@@ -663,7 +777,11 @@ impl TerminalManager {
         term.report_mouse(mouse_event)
     }
 
-    /// Get the current generation number for dirty tracking
+    /// Get the current generation number for dirty tracking.
+    ///
+    /// Increases whenever terminal content may have changed; external render
+    /// drivers can pair it with [`TerminalManager::content_hash`] to skip
+    /// frames whose visible content is unchanged.
     pub fn update_generation(&self) -> u64 {
         let pty = self.pty_session.lock();
         pty.update_generation()
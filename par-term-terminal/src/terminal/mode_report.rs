@@ -0,0 +1,306 @@
+//! DECRQM (request mode) replies.
+//!
+//! Applications ask whether a mode is on with `CSI ? Ps $ p` (DEC private
+//! modes) or `CSI Ps $ p` (ANSI modes) and expect `CSI ? Ps ; Pm $ y` back,
+//! where `Pm` is one of the [`ModeState`] values.  The emulator answers only
+//! some of the modes it implements and reports all three mouse tracking modes
+//! as set when any one of them is.  [`ModeRequestFilter`] takes the queries out
+//! of the data before the emulator sees them, so each one is answered here
+//! from the terminal state at that point in the stream, or left unanswered
+//! when replies are turned off.  This is the last output filter (see
+//! [`super::output_filters`]) and covers PTY output and tmux pane data.
+
+use super::TerminalManager;
+use par_term_emu_core_rust::mouse::{MouseEncoding, MouseMode};
+use par_term_emu_core_rust::terminal::Terminal;
+use std::sync::atomic::Ordering;
+
+const ESC: u8 = 0x1b;
+
+/// State of a mode as reported in a DECRQM reply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModeState {
+    /// The mode is not implemented
+    NotRecognized,
+    Set,
+    Reset,
+    /// Always on; setting or resetting it has no effect
+    PermanentlySet,
+    /// Always off; setting or resetting it has no effect
+    PermanentlyReset,
+}
+
+impl ModeState {
+    fn from_flag(enabled: bool) -> Self {
+        if enabled { Self::Set } else { Self::Reset }
+    }
+
+    /// `Pm` value of the reply.
+    pub fn code(self) -> u8 {
+        match self {
+            Self::NotRecognized => 0,
+            Self::Set => 1,
+            Self::Reset => 2,
+            Self::PermanentlySet => 3,
+            Self::PermanentlyReset => 4,
+        }
+    }
+}
+
+/// A DECRQM query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModeRequest {
+    /// DEC private mode (`CSI ? Ps $ p`) rather than ANSI mode
+    pub private: bool,
+    pub mode: u16,
+}
+
+impl ModeRequest {
+    /// The DECRPM reply reporting `state` for this mode.
+    pub fn reply(self, state: ModeState) -> Vec<u8> {
+        let marker = if self.private { "?" } else { "" };
+        format!("\x1b[{marker}{};{}$y", self.mode, state.code()).into_bytes()
+    }
+
+    /// State of the mode in `term`.
+    pub(super) fn state_in(self, term: &Terminal) -> ModeState {
+        if self.private {
+            match self.mode {
+                1 => ModeState::from_flag(term.application_cursor()),
+                6 => ModeState::from_flag(term.origin_mode()),
+                7 => ModeState::from_flag(term.auto_wrap_mode()),
+                25 => ModeState::from_flag(term.cursor().visible),
+                1000 => ModeState::from_flag(term.mouse_mode() == MouseMode::Normal),
+                1002 => ModeState::from_flag(term.mouse_mode() == MouseMode::ButtonEvent),
+                1003 => ModeState::from_flag(term.mouse_mode() == MouseMode::AnyEvent),
+                1004 => ModeState::from_flag(term.focus_tracking()),
+                1005 => ModeState::from_flag(term.mouse_encoding() == MouseEncoding::Utf8),
+                1006 => ModeState::from_flag(term.mouse_encoding() == MouseEncoding::Sgr),
+                1015 => ModeState::from_flag(term.mouse_encoding() == MouseEncoding::Urxvt),
+                1049 => ModeState::from_flag(term.is_alt_screen_active()),
+                2004 => ModeState::from_flag(term.bracketed_paste()),
+                2026 => ModeState::from_flag(term.synchronized_updates()),
+                // Text is always segmented into grapheme clusters
+                2027 => ModeState::PermanentlySet,
+                _ => ModeState::NotRecognized,
+            }
+        } else {
+            match self.mode {
+                // KAM: the keyboard is never locked
+                2 => ModeState::PermanentlyReset,
+                4 => ModeState::from_flag(term.insert_mode()),
+                // SRM: input is never echoed locally
+                12 => ModeState::PermanentlySet,
+                20 => ModeState::from_flag(term.line_feed_new_line_mode()),
+                _ => ModeState::NotRecognized,
+            }
+        }
+    }
+}
+
+/// Part of the data split by a [`ModeRequestFilter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModeRequestSegment {
+    /// Bytes for the emulator
+    Output(Vec<u8>),
+    /// A query to answer once the output before it is processed
+    Request(ModeRequest),
+}
+
+/// How far a buffered escape sequence matches `CSI [?] Ps $ p`.
+enum Match {
+    Partial,
+    Complete(ModeRequest),
+    Mismatch,
+}
+
+fn match_request(sequence: &[u8]) -> Match {
+    let Some(body) = sequence.strip_prefix(b"\x1b[") else {
+        return if sequence == [ESC] {
+            Match::Partial
+        } else {
+            Match::Mismatch
+        };
+    };
+    let (private, body) = match body.strip_prefix(b"?") {
+        Some(rest) => (true, rest),
+        None => (false, body),
+    };
+    let digits = body.iter().take_while(|b| b.is_ascii_digit()).count();
+    let (param, rest) = body.split_at(digits);
+    match rest {
+        [] => Match::Partial,
+        b"$" if digits > 0 => Match::Partial,
+        b"$p" if digits > 0 => std::str::from_utf8(param)
+            .ok()
+            .and_then(|param| param.parse().ok())
+            .map_or(Match::Mismatch, |mode| {
+                Match::Complete(ModeRequest { private, mode })
+            }),
+        _ => Match::Mismatch,
+    }
+}
+
+/// Streaming filter that splits DECRQM queries out of terminal output.
+#[derive(Debug, Default)]
+pub struct ModeRequestFilter {
+    /// Start of a sequence that may still turn out to be a query
+    pending: Vec<u8>,
+}
+
+impl ModeRequestFilter {
+    /// Split `input` into output and the queries within it, in order.  A
+    /// query cut off at the end of `input` is completed by the next call.
+    pub fn filter(&mut self, input: &[u8]) -> Vec<ModeRequestSegment> {
+        let mut segments = Vec::new();
+        let mut output = Vec::with_capacity(input.len());
+        for &byte in input {
+            if self.pending.is_empty() && byte != ESC {
+                output.push(byte);
+                continue;
+            }
+            self.pending.push(byte);
+            match match_request(&self.pending) {
+                Match::Partial => {}
+                Match::Complete(request) => {
+                    self.pending.clear();
+                    if !output.is_empty() {
+                        segments.push(ModeRequestSegment::Output(std::mem::take(&mut output)));
+                    }
+                    segments.push(ModeRequestSegment::Request(request));
+                }
+                Match::Mismatch => {
+                    // An ESC that breaks the sequence may start a query itself
+                    let restart = byte == ESC && self.pending.len() > 1;
+                    if restart {
+                        self.pending.pop();
+                    }
+                    output.append(&mut self.pending);
+                    if restart {
+                        self.pending.push(ESC);
+                    }
+                }
+            }
+        }
+        if !output.is_empty() {
+            segments.push(ModeRequestSegment::Output(output));
+        }
+        segments
+    }
+}
+
+impl TerminalManager {
+    /// Answer DECRQM mode queries in terminal output (on by default).  When
+    /// off, the queries are dropped without a reply.
+    pub fn set_answer_mode_requests(&mut self, enabled: bool) {
+        self.output_filters
+            .drop_mode_requests
+            .store(!enabled, Ordering::Relaxed);
+    }
+
+    /// State of a mode as a DECRQM reply reports it.
+    ///
+    /// `private` selects DEC private modes (`CSI ? Ps h`) rather than ANSI
+    /// modes (`CSI Ps h`).
+    pub fn mode_state(&self, private: bool, mode: u16) -> ModeState {
+        let pty = self.pty_session.lock();
+        let terminal = pty.terminal();
+        let term = terminal.read();
+        ModeRequest { private, mode }.state_in(&term)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn responses(manager: &TerminalManager) -> Vec<u8> {
+        manager.terminal().write().drain_responses()
+    }
+
+    #[test]
+    fn supported_mode_reports_its_state() {
+        let manager = TerminalManager::new(20, 4).unwrap();
+        manager.process_data(b"\x1b[?2004$p");
+        assert_eq!(responses(&manager), b"\x1b[?2004;2$y");
+
+        // The state at the query, not at the end of the chunk
+        manager.process_data(b"\x1b[?2004h\x1b[?2004$p\x1b[?2004l");
+        assert_eq!(responses(&manager), b"\x1b[?2004;1$y");
+        assert_eq!(manager.mode_state(true, 2004), ModeState::Reset);
+
+        manager.process_data(b"\x1b[4h\x1b[4$p");
+        assert_eq!(responses(&manager), b"\x1b[4;1$y");
+    }
+
+    #[test]
+    fn unsupported_mode_is_not_recognized() {
+        let manager = TerminalManager::new(20, 4).unwrap();
+        manager.process_data(b"\x1b[?9999$p\x1b[31$p");
+        assert_eq!(responses(&manager), b"\x1b[?9999;0$y\x1b[31;0$y");
+        assert_eq!(manager.mode_state(true, 9999), ModeState::NotRecognized);
+    }
+
+    #[test]
+    fn mouse_modes_and_fixed_modes_are_reported_individually() {
+        let manager = TerminalManager::new(20, 4).unwrap();
+        manager.process_data(b"\x1b[?1002h\x1b[?1006h");
+        assert_eq!(manager.mode_state(true, 1000), ModeState::Reset);
+        assert_eq!(manager.mode_state(true, 1002), ModeState::Set);
+        assert_eq!(manager.mode_state(true, 1006), ModeState::Set);
+        assert_eq!(manager.mode_state(true, 1015), ModeState::Reset);
+        assert_eq!(manager.mode_state(true, 2027), ModeState::PermanentlySet);
+        assert_eq!(manager.mode_state(false, 2), ModeState::PermanentlyReset);
+    }
+
+    #[test]
+    fn queries_split_across_reads_are_answered_once() {
+        let mut filter = ModeRequestFilter::default();
+        assert_eq!(
+            filter.filter(b"ab\x1b[?20"),
+            [ModeRequestSegment::Output(b"ab".to_vec())]
+        );
+        assert_eq!(
+            filter.filter(b"04$pc"),
+            [
+                ModeRequestSegment::Request(ModeRequest {
+                    private: true,
+                    mode: 2004
+                }),
+                ModeRequestSegment::Output(b"c".to_vec()),
+            ]
+        );
+        // Other sequences pass through untouched, including a query's prefix
+        assert_eq!(
+            filter.filter(b"\x1b[?25h\x1b[2$\x1b[1m"),
+            [ModeRequestSegment::Output(
+                b"\x1b[?25h\x1b[2$\x1b[1m".to_vec()
+            )]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn pty_query_is_answered_once() {
+        // Read the replies back as raw input until the line goes quiet, so a
+        // second reply from the emulator core would show up too.
+        let mut manager = TerminalManager::new(40, 4).unwrap();
+        crate::terminal::spawn::tests::run_script(
+            &mut manager,
+            r#"stty raw -echo min 0 time 5; printf '\033[?1002h\033[?1000$p'; \
+              reply=$(dd bs=1 count=32 2>/dev/null); stty sane; \
+              printf '%s' "$reply" | od -An -c | tr -d ' \n'"#,
+        );
+        let content = manager.terminal().read().content();
+        assert_eq!(content.lines().next(), Some(r"033[?1000;2$y"));
+    }
+
+    #[test]
+    fn disabled_replies_drop_the_query() {
+        let mut manager = TerminalManager::new(20, 4).unwrap();
+        manager.set_answer_mode_requests(false);
+        manager.process_data(b"\x1b[?2004$pok");
+        assert!(responses(&manager).is_empty());
+        assert!(manager.terminal().read().content().starts_with("ok"));
+    }
+}
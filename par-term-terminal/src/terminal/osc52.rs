@@ -0,0 +1,335 @@
+//! Reassembly of chunked OSC 52 clipboard payloads.
+//!
+//! Programs copying large selections (and tmux forwarding them) may split an
+//! OSC 52 clipboard write across reads, or send it as several consecutive
+//! `ESC ] 52 ; <selection> ; <base64 chunk> BEL` sequences for the same
+//! selection.  Decoding each chunk separately corrupts the payload (base64
+//! quanta straddle the chunk boundaries) and every chunk overwrites the
+//! previous one, so [`Osc52Assembler`] joins the base64 text of adjacent
+//! chunks and hands the emulator a single OSC 52 to decode.
+//!
+//! A transfer ends at the first byte that is not part of another OSC 52 for
+//! the same selection, or at the end of a read.  Transfers whose payload
+//! exceeds the OSC data length cap are rejected as a whole, even when the cap
+//! is only crossed by a later chunk.  Queries (`?` payloads) pass through
+//! untouched.
+//!
+//! Reassembly applies to PTY output and tmux pane data alike (see
+//! [`super::output_filters`]).
+
+use super::TerminalManager;
+use std::sync::atomic::Ordering;
+
+/// Introducer of an OSC 52 sequence.
+const OSC52_PREFIX: &[u8] = b"\x1b]52;";
+
+const ESC: u8 = 0x1b;
+const BEL: u8 = 0x07;
+
+/// Longest selection parameter accepted (e.g. `c`, `p`, `s0`, `cp`).
+const MAX_SELECTION_LEN: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Outside a sequence; `matched` bytes of the prefix are buffered.
+    Ground { matched: usize },
+    /// Reading the selection parameter.
+    Selection,
+    /// Reading the base64 payload; `esc` is set after an ESC (possible ST).
+    Payload { esc: bool },
+}
+
+/// Consecutive chunks for one selection, waiting to be emitted.
+#[derive(Debug)]
+struct Transfer {
+    selection: Vec<u8>,
+    payload: Vec<u8>,
+    /// The cap was exceeded; the whole transfer is dropped.
+    rejected: bool,
+}
+
+/// Streaming filter that joins chunked OSC 52 clipboard writes.
+#[derive(Debug)]
+pub struct Osc52Assembler {
+    state: State,
+    /// Maximum reassembled payload size in bytes.
+    max_payload: usize,
+    /// Selection parameter of the sequence being read.
+    selection: Vec<u8>,
+    /// Payload of the sequence being read.
+    payload: Vec<u8>,
+    /// The sequence being read pushed its transfer over the cap.
+    overflow: bool,
+    /// Completed chunks not yet emitted.
+    transfer: Option<Transfer>,
+}
+
+impl Default for Osc52Assembler {
+    fn default() -> Self {
+        Self::new(par_term_config::defaults::max_osc_data_length())
+    }
+}
+
+impl Osc52Assembler {
+    /// Create an assembler that rejects payloads over `max_payload` bytes.
+    pub fn new(max_payload: usize) -> Self {
+        Self {
+            state: State::Ground { matched: 0 },
+            max_payload,
+            selection: Vec::new(),
+            payload: Vec::new(),
+            overflow: false,
+            transfer: None,
+        }
+    }
+
+    /// Change the payload size cap (applies to transfers started afterwards).
+    pub fn set_max_payload(&mut self, max_payload: usize) {
+        self.max_payload = max_payload;
+    }
+
+    /// Pass `input` through, replacing chunked OSC 52 writes with one
+    /// sequence carrying the joined payload.
+    ///
+    /// A sequence split across calls is held back until it completes; a
+    /// complete transfer is emitted at the end of the call at the latest.
+    pub fn filter(&mut self, input: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(input.len());
+        for &byte in input {
+            self.push(byte, &mut out);
+        }
+        if self.state == (State::Ground { matched: 0 }) {
+            self.flush_transfer(&mut out);
+        }
+        out
+    }
+
+    fn push(&mut self, byte: u8, out: &mut Vec<u8>) {
+        match self.state {
+            State::Ground { matched } => {
+                if byte == OSC52_PREFIX[matched] {
+                    if matched + 1 == OSC52_PREFIX.len() {
+                        self.selection.clear();
+                        self.payload.clear();
+                        self.overflow = false;
+                        self.state = State::Selection;
+                    } else {
+                        self.state = State::Ground {
+                            matched: matched + 1,
+                        };
+                    }
+                    return;
+                }
+                // Anything other than another OSC 52 ends the transfer.
+                self.flush_transfer(out);
+                out.extend_from_slice(&OSC52_PREFIX[..matched]);
+                if byte == ESC {
+                    self.state = State::Ground { matched: 1 };
+                } else {
+                    out.push(byte);
+                    self.state = State::Ground { matched: 0 };
+                }
+            }
+            State::Selection => {
+                if byte == b';' {
+                    self.state = State::Payload { esc: false };
+                } else if byte.is_ascii_alphanumeric() && self.selection.len() < MAX_SELECTION_LEN {
+                    self.selection.push(byte);
+                } else {
+                    // Not a clipboard write we understand: release it as-is.
+                    self.flush_transfer(out);
+                    out.extend_from_slice(OSC52_PREFIX);
+                    out.extend_from_slice(&self.selection);
+                    self.state = State::Ground { matched: 0 };
+                    self.push(byte, out);
+                }
+            }
+            State::Payload { esc: true } => {
+                self.complete_sequence(out);
+                if byte != b'\\' {
+                    // ESC followed by anything else still ends the string;
+                    // the byte belongs to whatever comes next.
+                    self.state = State::Ground { matched: 1 };
+                    self.push(byte, out);
+                }
+            }
+            State::Payload { esc: false } => match byte {
+                BEL => self.complete_sequence(out),
+                ESC => self.state = State::Payload { esc: true },
+                _ => {
+                    let joined = self.transfer_len() + self.payload.len();
+                    if joined >= self.max_payload {
+                        self.overflow = true;
+                    } else {
+                        self.payload.push(byte);
+                    }
+                }
+            },
+        }
+    }
+
+    /// Payload bytes already collected for the current selection's transfer.
+    fn transfer_len(&self) -> usize {
+        match &self.transfer {
+            Some(t) if t.selection == self.selection => t.payload.len(),
+            _ => 0,
+        }
+    }
+
+    /// A sequence finished: queue its chunk, or pass a query through.
+    fn complete_sequence(&mut self, out: &mut Vec<u8>) {
+        self.state = State::Ground { matched: 0 };
+        let selection = std::mem::take(&mut self.selection);
+        let payload = std::mem::take(&mut self.payload);
+
+        if payload == b"?" {
+            self.flush_transfer(out);
+            write_sequence(out, &selection, &payload);
+            return;
+        }
+
+        match &mut self.transfer {
+            Some(transfer) if transfer.selection == selection => {
+                if self.overflow {
+                    transfer.rejected = true;
+                    transfer.payload = Vec::new();
+                } else if !transfer.rejected {
+                    transfer.payload.extend_from_slice(&payload);
+                }
+            }
+            _ => {
+                self.flush_transfer(out);
+                self.transfer = Some(Transfer {
+                    selection,
+                    payload,
+                    rejected: self.overflow,
+                });
+            }
+        }
+    }
+
+    /// Emit the queued transfer as a single OSC 52, unless it was rejected.
+    fn flush_transfer(&mut self, out: &mut Vec<u8>) {
+        let Some(transfer) = self.transfer.take() else {
+            return;
+        };
+        if transfer.rejected {
+            log::warn!(
+                "Dropping OSC 52 clipboard write larger than {} bytes",
+                self.max_payload
+            );
+            return;
+        }
+        write_sequence(out, &transfer.selection, &transfer.payload);
+    }
+}
+
+fn write_sequence(out: &mut Vec<u8>, selection: &[u8], payload: &[u8]) {
+    out.extend_from_slice(OSC52_PREFIX);
+    out.extend_from_slice(selection);
+    out.push(b';');
+    out.extend_from_slice(payload);
+    out.push(BEL);
+}
+
+impl TerminalManager {
+    /// Enable or disable reassembly of chunked OSC 52 clipboard writes.
+    pub fn set_osc52_reassembly(&mut self, enabled: bool) {
+        let filters = &self.output_filters;
+        filters.reassemble_osc52.store(enabled, Ordering::Relaxed);
+        if !enabled {
+            let mut osc52 = filters.osc52.lock();
+            *osc52 = Osc52Assembler::new(osc52.max_payload);
+        }
+    }
+
+    /// Whether chunked OSC 52 clipboard writes are reassembled.
+    pub fn osc52_reassembly(&self) -> bool {
+        self.output_filters.reassemble_osc52.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // "hello, world" and its base64 split at a non-quantum boundary.
+    const TEXT: &str = "hello, world";
+    const CHUNKS: [&str; 3] = ["aGVsbG", "8sIHdv", "cmxk"];
+
+    fn chunked(selection: &str) -> Vec<u8> {
+        CHUNKS
+            .iter()
+            .flat_map(|c| format!("\x1b]52;{selection};{c}\x07").into_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn adjacent_chunks_are_joined_into_one_sequence() {
+        let mut assembler = Osc52Assembler::new(1024);
+        let mut input = b"a".to_vec();
+        input.extend(chunked("c"));
+        input.extend(b"b");
+
+        assert_eq!(
+            assembler.filter(&input),
+            b"a\x1b]52;c;aGVsbG8sIHdvcmxk\x07b".to_vec()
+        );
+    }
+
+    #[test]
+    fn other_sequences_and_queries_pass_through() {
+        let mut assembler = Osc52Assembler::new(1024);
+        let input = b"\x1b]0;title\x07\x1b[1m\x1b]52;c;?\x1b\\x";
+        assert_eq!(
+            assembler.filter(input),
+            b"\x1b]0;title\x07\x1b[1m\x1b]52;c;?\x07x".to_vec()
+        );
+    }
+
+    #[test]
+    fn split_payload_reassembles_into_clipboard() {
+        let mut manager = TerminalManager::new(20, 5).unwrap();
+        manager.set_osc52_reassembly(true);
+
+        let input = chunked("c");
+        // Split in the middle of a sequence and of the prefix.
+        for part in [&input[..9], &input[9..20], &input[20..]] {
+            manager.process_data(part);
+        }
+
+        assert_eq!(manager.get_clipboard().as_deref(), Some(TEXT));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn pty_output_is_reassembled() {
+        let mut manager = TerminalManager::new(20, 5).unwrap();
+        manager.set_osc52_reassembly(true);
+        let script = CHUNKS
+            .iter()
+            .map(|c| format!(r"\033]52;c;{c}\007"))
+            .collect::<String>();
+        crate::terminal::spawn::tests::run_script(&mut manager, &format!("printf '{script}'"));
+        assert_eq!(manager.get_clipboard().as_deref(), Some(TEXT));
+    }
+
+    #[test]
+    fn transfer_over_cap_is_rejected_mid_stream() {
+        let mut manager = TerminalManager::new(20, 5).unwrap();
+        manager.set_osc52_reassembly(true);
+        manager.set_max_osc_data_length(14);
+
+        // The first two chunks fit (12 bytes); the third crosses the cap.
+        let input = chunked("c");
+        let (head, tail) = input.split_at(input.len() - 4);
+        manager.process_data(head);
+        manager.process_data(tail);
+        manager.process_data(b"ok");
+
+        assert_eq!(manager.get_clipboard(), None);
+        let terminal = manager.terminal();
+        let text = terminal.read().content();
+        assert!(text.starts_with("ok"), "{text:?}");
+    }
+}
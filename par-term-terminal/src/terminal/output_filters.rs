@@ -0,0 +1,177 @@
+//! Output rewriting shared by both routes into the emulator.
+//!
+//! Output reaches the emulator two ways: the [`super::pty_host::PtyHost`]
+//! reader thread feeds ordinary shell and SSH output, and
+//! [`TerminalManager::process_data`] feeds tmux control-mode pane output.
+//! [`OutputFilters`] holds the streaming filters both run before the emulator
+//! parses the data, behind an `Arc` so the reader thread can use them.
+//!
+//! A tmux gateway's own PTY output is control-mode traffic, not terminal
+//! output; its panes' data is filtered when routed through
+//! [`TerminalManager::process_data`], so the reader passes it through as is.
+
+use super::form_feed::FormFeedFilter;
+use super::input_encoding::InputDecoder;
+use super::line_dedup::RepeatedLineFilter;
+use super::long_line::LongLineFilter;
+use super::mode_report::{ModeRequestFilter, ModeRequestSegment};
+use super::osc52::Osc52Assembler;
+use super::printer::{self, MediaCopyFilter};
+use super::sgr_coalesce::SgrCoalescer;
+use par_term_config::{FormFeed, PrinterOutput};
+use par_term_emu_core_rust::terminal::{ObserverDispatchBatch, Terminal};
+use parking_lot::Mutex;
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Streaming output filters, shared with the PTY reader thread.
+#[derive(Debug, Default)]
+pub(crate) struct OutputFilters {
+    /// Legacy encoding decoding (see [`super::input_encoding`]).
+    pub(crate) input_decoder: Mutex<Option<InputDecoder>>,
+    /// Media copy capture (see [`super::printer`]).
+    pub(crate) media_copy: Mutex<MediaCopyFilter>,
+    /// Where captured printer data goes.
+    pub(crate) printer_output: Mutex<PrinterOutput>,
+    /// Join chunked OSC 52 clipboard writes.
+    pub(crate) reassemble_osc52: AtomicBool,
+    /// Streaming state for OSC 52 reassembly (see [`super::osc52`]).
+    pub(crate) osc52: Mutex<Osc52Assembler>,
+    /// Form feed rewriting (see [`super::form_feed`]).
+    pub(crate) form_feed: Mutex<FormFeedFilter>,
+    /// Redundant SGR reset dropping (see [`super::sgr_coalesce`]).
+    pub(crate) sgr_coalescer: Mutex<SgrCoalescer>,
+    /// Line length cap (see [`super::long_line`]).
+    pub(crate) long_lines: Mutex<LongLineFilter>,
+    /// Repeated line collapsing (see [`super::line_dedup`]).
+    pub(crate) repeated_lines: Mutex<RepeatedLineFilter>,
+    /// Leave DECRQM queries unanswered instead of replying.
+    pub(crate) drop_mode_requests: AtomicBool,
+    /// DECRQM query splitting (see [`super::mode_report`]).
+    pub(crate) mode_requests: Mutex<ModeRequestFilter>,
+}
+
+impl OutputFilters {
+    /// Decode output to UTF-8, when a legacy encoding is set.
+    pub(crate) fn decode<'a>(&self, data: &'a [u8]) -> Cow<'a, [u8]> {
+        match self.input_decoder.lock().as_mut() {
+            Some(decoder) => Cow::Owned(decoder.decode(data).into_bytes()),
+            None => Cow::Borrowed(data),
+        }
+    }
+
+    /// Take media copy (printer) data out of the output and route it per
+    /// the printer output policy.
+    pub(crate) fn capture_printer_data<'a>(&self, data: &'a [u8]) -> Cow<'a, [u8]> {
+        let mut media_copy = self.media_copy.lock();
+        if media_copy.is_idle() && !data.contains(&0x1b) {
+            return Cow::Borrowed(data);
+        }
+        let out = media_copy.filter(data);
+        printer::route(&self.printer_output.lock(), &out.printer);
+        Cow::Owned(out.display)
+    }
+
+    /// Join chunked OSC 52 clipboard writes, when enabled.
+    pub(crate) fn reassemble_osc52<'a>(&self, data: &'a [u8]) -> Cow<'a, [u8]> {
+        if self.reassemble_osc52.load(Ordering::Relaxed) {
+            Cow::Owned(self.osc52.lock().filter(data))
+        } else {
+            Cow::Borrowed(data)
+        }
+    }
+
+    /// Rewrite form feeds per the configured mode.
+    pub(crate) fn rewrite_form_feeds<'a>(&self, data: &'a [u8]) -> Cow<'a, [u8]> {
+        let mut form_feed = self.form_feed.lock();
+        if form_feed.mode() == FormFeed::Ignore {
+            Cow::Borrowed(data)
+        } else {
+            Cow::Owned(form_feed.filter(data))
+        }
+    }
+
+    /// Drop redundant SGR resets, when enabled.
+    pub(crate) fn coalesce_sgr_resets<'a>(&self, data: &'a [u8]) -> Cow<'a, [u8]> {
+        let mut coalescer = self.sgr_coalescer.lock();
+        if coalescer.enabled() {
+            Cow::Owned(coalescer.filter(data))
+        } else {
+            Cow::Borrowed(data)
+        }
+    }
+
+    /// Break lines over the length cap, when one is set.
+    pub(crate) fn break_long_lines<'a>(&self, data: &'a [u8]) -> Cow<'a, [u8]> {
+        let mut long_lines = self.long_lines.lock();
+        if long_lines.max_length() == 0 {
+            Cow::Borrowed(data)
+        } else {
+            Cow::Owned(long_lines.filter(data))
+        }
+    }
+
+    /// Collapse runs of identical lines, when enabled.
+    pub(crate) fn collapse_repeated_lines<'a>(
+        &self,
+        term: &Terminal,
+        data: &'a [u8],
+    ) -> Cow<'a, [u8]> {
+        let mut repeated_lines = self.repeated_lines.lock();
+        if repeated_lines.enabled() {
+            Cow::Owned(repeated_lines.filter(data, term.is_alt_screen_active()))
+        } else {
+            Cow::Borrowed(data)
+        }
+    }
+
+    /// Feed `data` to `term`, answering the DECRQM queries in it from the
+    /// terminal state at that point.
+    ///
+    /// Returns the observer batches to deliver once the terminal lock is
+    /// released.
+    pub(crate) fn process_answering_mode_requests(
+        &self,
+        term: &mut Terminal,
+        data: &[u8],
+    ) -> Vec<ObserverDispatchBatch> {
+        let segments = self.mode_requests.lock().filter(data);
+        let answer = !self.drop_mode_requests.load(Ordering::Relaxed);
+        let mut batches = Vec::new();
+        for segment in segments {
+            match segment {
+                ModeRequestSegment::Output(bytes) => batches.push(term.process_deferred(&bytes)),
+                ModeRequestSegment::Request(request) if answer => {
+                    let reply = request.reply(request.state_in(term));
+                    term.push_response(&reply);
+                }
+                ModeRequestSegment::Request(request) => {
+                    log::debug!("Dropped mode request {request:?}");
+                }
+            }
+        }
+        batches
+    }
+
+    /// Filter a read of PTY output and feed it to `term`.
+    ///
+    /// Returns the observer batches to deliver once the terminal lock is
+    /// released.
+    pub(crate) fn process_pty_output(
+        &self,
+        term: &mut Terminal,
+        data: &[u8],
+    ) -> Vec<ObserverDispatchBatch> {
+        if term.is_tmux_control_mode() {
+            return vec![term.process_deferred(data)];
+        }
+        let data = self.decode(data);
+        let data = self.capture_printer_data(&data);
+        let data = self.reassemble_osc52(&data);
+        let data = self.rewrite_form_feeds(&data);
+        let data = self.coalesce_sgr_resets(&data);
+        let data = self.break_long_lines(&data);
+        let data = self.collapse_repeated_lines(term, &data);
+        self.process_answering_mode_requests(term, &data)
+    }
+}
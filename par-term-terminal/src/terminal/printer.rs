@@ -0,0 +1,225 @@
+//! Media copy (printer controller mode) capture.
+//!
+//! Legacy applications print by sending `CSI 5 i`, the data to print, and
+//! `CSI 4 i`.  The emulator's parser ignores these controls, so the printer
+//! data would otherwise be rendered into the grid.  [`MediaCopyFilter`] splits
+//! it out of PTY output and tmux pane data alike (see
+//! [`super::output_filters`]), and it is routed according to the configured
+//! [`PrinterOutput`] policy.
+//!
+//! The filter is streaming: controls split across reads are recognised.
+
+use super::TerminalManager;
+use par_term_config::PrinterOutput;
+use std::io::Write;
+
+/// Printer controller mode on (`CSI 5 i`).
+const PRINTER_ON: &[u8] = b"\x1b[5i";
+/// Printer controller mode off (`CSI 4 i`).
+const PRINTER_OFF: &[u8] = b"\x1b[4i";
+
+const ESC: u8 = 0x1b;
+
+/// Output of [`MediaCopyFilter::filter`].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct MediaCopyOutput {
+    /// Bytes for the emulator
+    pub display: Vec<u8>,
+    /// Bytes sent to the printer
+    pub printer: Vec<u8>,
+}
+
+/// Streaming filter that captures printer controller mode data.
+#[derive(Debug, Default)]
+pub struct MediaCopyFilter {
+    /// Inside `CSI 5 i` … `CSI 4 i`.
+    printing: bool,
+    /// Bytes of the control currently being watched for that have matched.
+    matched: usize,
+}
+
+impl MediaCopyFilter {
+    /// Split `input` into displayable and printer bytes.
+    ///
+    /// The controls themselves appear in neither.  A trailing partial control
+    /// (e.g. a lone ESC) is held back until the next call resolves it.
+    pub fn filter(&mut self, input: &[u8]) -> MediaCopyOutput {
+        let mut out = MediaCopyOutput::default();
+        for &byte in input {
+            self.push(byte, &mut out);
+        }
+        out
+    }
+
+    /// Whether printer controller mode is active.
+    pub fn is_printing(&self) -> bool {
+        self.printing
+    }
+
+    /// Neither printing nor partway through a control, so data without an
+    /// ESC passes through unchanged.
+    pub fn is_idle(&self) -> bool {
+        !self.printing && self.matched == 0
+    }
+
+    fn push(&mut self, byte: u8, out: &mut MediaCopyOutput) {
+        let (control, sink) = if self.printing {
+            (PRINTER_OFF, &mut out.printer)
+        } else {
+            (PRINTER_ON, &mut out.display)
+        };
+
+        if byte == control[self.matched] {
+            self.matched += 1;
+            if self.matched == control.len() {
+                self.matched = 0;
+                self.printing = !self.printing;
+            }
+            return;
+        }
+
+        // Not the control after all: release the buffered prefix and rescan
+        // this byte, which may itself start a new control.
+        sink.extend_from_slice(&control[..self.matched]);
+        if byte == ESC {
+            self.matched = 1;
+        } else {
+            sink.push(byte);
+            self.matched = 0;
+        }
+    }
+}
+
+/// Send captured printer bytes where `output` says.
+pub(super) fn route(output: &PrinterOutput, data: &[u8]) {
+    if data.is_empty() {
+        return;
+    }
+    match output {
+        PrinterOutput::Discard => {
+            log::debug!("Discarding {} bytes of printer output", data.len());
+        }
+        PrinterOutput::File { path } => {
+            if let Err(e) = append_to_file(path, data) {
+                log::warn!("Failed to write printer output to {}: {}", path, e);
+            }
+        }
+    }
+}
+
+/// Append `data` to the file at `path`.
+fn append_to_file(path: &str, data: &[u8]) -> std::io::Result<()> {
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(data)
+}
+
+impl TerminalManager {
+    /// Set where media copy (printer) output is sent.
+    pub fn set_printer_output(&mut self, output: PrinterOutput) {
+        *self.output_filters.printer_output.lock() = output;
+    }
+
+    /// Where media copy (printer) output is sent.
+    pub fn printer_output(&self) -> PrinterOutput {
+        self.output_filters.printer_output.lock().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen_text(manager: &TerminalManager) -> String {
+        let terminal = manager.terminal();
+        let term = terminal.read();
+        term.content()
+    }
+
+    #[test]
+    fn printer_data_is_split_from_display() {
+        let mut filter = MediaCopyFilter::default();
+        let out = filter.filter(b"a\x1b[5iPRINT\x1b[1mME\x1b[4ib");
+        assert_eq!(out.display, b"ab");
+        assert_eq!(out.printer, b"PRINT\x1b[1mME");
+        assert!(!filter.is_printing());
+    }
+
+    #[test]
+    fn controls_split_across_reads_are_recognised() {
+        let mut filter = MediaCopyFilter::default();
+        let mut display = Vec::new();
+        let mut printer = Vec::new();
+        for chunk in [&b"x\x1b["[..], b"5", b"ipage\x1b", b"[4", b"iy\x1b[5"] {
+            let out = filter.filter(chunk);
+            display.extend(out.display);
+            printer.extend(out.printer);
+        }
+        assert_eq!(display, b"xy");
+        assert_eq!(printer, b"page");
+
+        // "\x1b[5" followed by something else was an ordinary sequence.
+        let out = filter.filter(b"m");
+        assert_eq!(out.display, b"\x1b[5m");
+        assert!(!filter.is_printing());
+    }
+
+    #[test]
+    fn discarded_printer_data_is_not_rendered() {
+        let mut manager = TerminalManager::new(20, 5).unwrap();
+        manager.set_printer_output(PrinterOutput::Discard);
+        manager.process_data(b"A\x1b[5iSECRET\x1b[4iB");
+
+        let text = screen_text(&manager);
+        assert!(text.starts_with("AB"), "{text:?}");
+        assert!(!text.contains("SECRET"));
+    }
+
+    #[test]
+    fn printer_data_is_appended_to_file() {
+        let path =
+            std::env::temp_dir().join(format!("par-term-printer-test-{}.txt", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut manager = TerminalManager::new(20, 5).unwrap();
+        manager.set_printer_output(PrinterOutput::File {
+            path: path.to_string_lossy().into_owned(),
+        });
+        manager.process_data(b"A\x1b[5ipage one\r\n");
+        manager.process_data(b"page two\x1b[4iB");
+
+        let printed = std::fs::read(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(printed, b"page one\r\npage two");
+        let text = screen_text(&manager);
+        assert!(text.starts_with("AB"), "{text:?}");
+        assert!(!text.contains("page"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn pty_printer_data_is_captured_and_not_rendered() {
+        let path = std::env::temp_dir().join(format!(
+            "par-term-printer-pty-test-{}.txt",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        let mut manager = TerminalManager::new(20, 5).unwrap();
+        manager.set_printer_output(PrinterOutput::File {
+            path: path.to_string_lossy().into_owned(),
+        });
+        crate::terminal::spawn::tests::run_script(
+            &mut manager,
+            r"printf 'A\033[5ipage one\033[4iB'",
+        );
+
+        let printed = std::fs::read(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(printed, b"page one");
+        let text = screen_text(&manager);
+        assert!(text.starts_with("AB"), "{text:?}");
+    }
+}
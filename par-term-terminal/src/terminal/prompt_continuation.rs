@@ -0,0 +1,347 @@
+//! OSC 133 continuation prompt handling.
+//!
+//! While a command spans several input lines, shells show a secondary prompt
+//! (`PS2`) and mark it with `OSC 133;A;k=s` (or another non-initial `k=`
+//! kind), sometimes followed by a fresh `OSC 133;B`.  The emulator only looks
+//! at the marker letter, so each continuation row would start a new prompt:
+//! it gets its own scrollbar mark, the command start moves down to it, and
+//! the command's text is cut short.
+//!
+//! With [`PromptContinuation::Merge`], [`PromptContinuationFilter`] drops the
+//! continuation prompt-start and the command-start that follows it, so the
+//! command keeps the mark and start line of its first input line.  Any other
+//! OSC 133 marker ends the continuation.  Data fed through
+//! [`TerminalManager::process_data`] (tmux panes) is filtered directly.
+//!
+//! Ordinary PTY output is parsed by the core's reader thread, which cannot be
+//! filtered.  There a [`PromptMarkerTap`] runs the same filter over the raw
+//! bytes, from the output callback, and queues whether each marker would
+//! have been dropped.  The core reports every `A`/`B`/`C`/`D` marker as a
+//! shell integration event, in order, so
+//! [`TerminalManager::update_scrollback_metadata`] takes one verdict per
+//! event and skips the dropped ones.
+
+use super::TerminalManager;
+use par_term_config::PromptContinuation;
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+
+/// Introducer of an OSC 133 sequence.
+const OSC133_PREFIX: &[u8] = b"\x1b]133;";
+
+const ESC: u8 = 0x1b;
+const BEL: u8 = 0x07;
+
+/// Longest OSC 133 parameter string inspected; longer ones pass through.
+const MAX_PARAMS_LEN: usize = 256;
+
+/// Most marker verdicts a [`PromptMarkerTap`] holds before dropping the
+/// oldest, should events stop being polled.
+const MAX_PENDING_VERDICTS: usize = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Outside a sequence; `matched` bytes of the prefix are buffered.
+    Ground { matched: usize },
+    /// Reading the parameters; `esc` is set after an ESC (possible ST).
+    Params { esc: bool },
+}
+
+/// Streaming filter that folds continuation prompts into the command.
+#[derive(Debug)]
+pub struct PromptContinuationFilter {
+    mode: PromptContinuation,
+    state: State,
+    /// Parameters of the sequence being read.
+    params: Vec<u8>,
+    /// A continuation prompt was dropped and no other marker followed yet.
+    in_continuation: bool,
+    /// Marker letters (`A`-`D`) seen by [`Self::scan`], with whether each
+    /// was dropped.
+    verdicts: Vec<(u8, bool)>,
+}
+
+impl Default for PromptContinuationFilter {
+    fn default() -> Self {
+        Self::new(PromptContinuation::default())
+    }
+}
+
+impl PromptContinuationFilter {
+    /// Create a filter for `mode`.
+    pub fn new(mode: PromptContinuation) -> Self {
+        Self {
+            mode,
+            state: State::Ground { matched: 0 },
+            params: Vec::new(),
+            in_continuation: false,
+            verdicts: Vec::new(),
+        }
+    }
+
+    /// The continuation mode this filter applies.
+    pub fn mode(&self) -> PromptContinuation {
+        self.mode
+    }
+
+    /// Drop the continuation markers in `input`.
+    ///
+    /// A sequence split across calls is held back until it completes.
+    pub fn filter(&mut self, input: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(input.len());
+        for &byte in input {
+            self.push(byte, &mut out);
+        }
+        out
+    }
+
+    /// Run the filter over `input` for its verdicts only: which of the
+    /// `A`-`D` markers completed in `input` it would drop, in order.
+    pub fn scan(&mut self, input: &[u8]) -> Vec<(u8, bool)> {
+        let mut discard = Vec::new();
+        for &byte in input {
+            self.push(byte, &mut discard);
+            discard.clear();
+        }
+        std::mem::take(&mut self.verdicts)
+    }
+
+    fn push(&mut self, byte: u8, out: &mut Vec<u8>) {
+        match self.state {
+            State::Ground { matched } => {
+                if byte == OSC133_PREFIX[matched] {
+                    if matched + 1 == OSC133_PREFIX.len() {
+                        self.params.clear();
+                        self.state = State::Params { esc: false };
+                    } else {
+                        self.state = State::Ground {
+                            matched: matched + 1,
+                        };
+                    }
+                    return;
+                }
+                out.extend_from_slice(&OSC133_PREFIX[..matched]);
+                if byte == ESC {
+                    self.state = State::Ground { matched: 1 };
+                } else {
+                    out.push(byte);
+                    self.state = State::Ground { matched: 0 };
+                }
+            }
+            State::Params { esc: true } => {
+                if byte == b'\\' {
+                    self.complete_sequence(b"\x1b\\", out);
+                } else {
+                    // ESC followed by anything else aborts the sequence.
+                    out.extend_from_slice(OSC133_PREFIX);
+                    out.extend_from_slice(&self.params);
+                    self.state = State::Ground { matched: 1 };
+                    self.push(byte, out);
+                }
+            }
+            State::Params { esc: false } => match byte {
+                BEL => self.complete_sequence(&[BEL], out),
+                ESC => self.state = State::Params { esc: true },
+                _ if self.params.len() >= MAX_PARAMS_LEN => {
+                    out.extend_from_slice(OSC133_PREFIX);
+                    out.extend_from_slice(&self.params);
+                    out.push(byte);
+                    self.state = State::Ground { matched: 0 };
+                }
+                _ => self.params.push(byte),
+            },
+        }
+    }
+
+    /// A sequence ended with `terminator`: forward or drop it.
+    fn complete_sequence(&mut self, terminator: &[u8], out: &mut Vec<u8>) {
+        self.state = State::Ground { matched: 0 };
+        let params = std::mem::take(&mut self.params);
+        let keep = self.keep_marker(&params);
+        if keep {
+            out.extend_from_slice(OSC133_PREFIX);
+            out.extend_from_slice(&params);
+            out.extend_from_slice(terminator);
+        }
+        if let Some(&marker @ b'A'..=b'D') = params.first() {
+            self.verdicts.push((marker, !keep));
+        }
+        self.params = params;
+    }
+
+    /// Whether the marker with `params` reaches the emulator.
+    fn keep_marker(&mut self, params: &[u8]) -> bool {
+        let mut fields = params.split(|&b| b == b';');
+        let marker = fields.next().and_then(|m| m.first()).copied();
+        let continuation_prompt =
+            marker == Some(b'A') && fields.any(|field| field.starts_with(b"k=") && field != b"k=i");
+
+        if self.mode == PromptContinuation::NewPrompt {
+            return true;
+        }
+        if continuation_prompt {
+            self.in_continuation = true;
+            return false;
+        }
+        if marker == Some(b'B') && self.in_continuation {
+            return false;
+        }
+        self.in_continuation = false;
+        true
+    }
+}
+
+/// Classifies the OSC 133 markers of raw PTY output for the metadata path.
+#[derive(Debug, Default)]
+pub struct PromptMarkerTap {
+    filter: Mutex<PromptContinuationFilter>,
+    /// Marker letter and whether it is dropped, oldest first.
+    verdicts: Mutex<VecDeque<(u8, bool)>>,
+}
+
+impl PromptMarkerTap {
+    /// Scan a chunk of raw PTY output before the core parses it.
+    pub fn record(&self, data: &[u8]) {
+        let mut filter = self.filter.lock();
+        if filter.mode() == PromptContinuation::NewPrompt {
+            return;
+        }
+        let seen = filter.scan(data);
+        if seen.is_empty() {
+            return;
+        }
+        let mut verdicts = self.verdicts.lock();
+        verdicts.extend(seen);
+        let excess = verdicts.len().saturating_sub(MAX_PENDING_VERDICTS);
+        verdicts.drain(..excess);
+    }
+
+    /// Whether the marker behind a core shell integration event of
+    /// `event_type` was dropped.  Verdicts for other markers ahead of it are
+    /// discarded, so a lost event cannot shift the rest out of step.
+    pub fn take_dropped(&self, event_type: &str) -> bool {
+        let marker = match event_type {
+            "prompt_start" => b'A',
+            "command_start" => b'B',
+            "command_executed" => b'C',
+            "command_finished" => b'D',
+            _ => return false,
+        };
+        let mut verdicts = self.verdicts.lock();
+        while let Some((seen, dropped)) = verdicts.pop_front() {
+            if seen == marker {
+                return dropped;
+            }
+        }
+        false
+    }
+
+    fn set_mode(&self, mode: PromptContinuation) {
+        *self.filter.lock() = PromptContinuationFilter::new(mode);
+        self.verdicts.lock().clear();
+    }
+}
+
+impl TerminalManager {
+    /// Set how OSC 133 continuation prompts are handled.
+    pub fn set_prompt_continuation(&mut self, mode: PromptContinuation) {
+        *self.prompt_continuation.lock() = PromptContinuationFilter::new(mode);
+        self.prompt_markers.set_mode(mode);
+    }
+
+    /// How OSC 133 continuation prompts are handled.
+    pub fn prompt_continuation(&self) -> PromptContinuation {
+        self.prompt_continuation.lock().mode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A two-line prompt whose command continues onto a `PS2` row:
+    ///
+    /// ```text
+    /// 0  ~/src (main)
+    /// 1  ❯ for f in a b; do
+    /// 2  > echo $f; done
+    /// 3  a
+    /// 4  b
+    /// 5  ~/src (main)
+    /// 6  ❯
+    /// ```
+    const SESSION: &[u8] =
+        b"\x1b]133;A\x07~/src (main)\r\n\xe2\x9d\xaf \x1b]133;B\x07for f in a b; do\r\n\
+        \x1b]133;A;k=s\x1b\\> \x1b]133;B\x07echo $f; done\r\n\x1b]133;C\x07a\r\nb\r\n\
+        \x1b]133;D;0\x07\x1b]133;A\x07~/src (main)\r\n\xe2\x9d\xaf \x1b]133;B\x07";
+
+    fn marks_after(mode: PromptContinuation, chunk: usize) -> Vec<par_term_config::ScrollbackMark> {
+        let mut manager = TerminalManager::new(40, 10).unwrap();
+        manager.set_prompt_continuation(mode);
+        for piece in SESSION.chunks(chunk) {
+            manager.process_data(piece);
+        }
+        let (_, cursor_row) = manager.cursor_position();
+        let scrollback_len = manager.scrollback_len();
+        manager.update_scrollback_metadata(scrollback_len, cursor_row);
+        manager.scrollback_marks()
+    }
+
+    #[test]
+    fn command_mark_stays_on_first_input_line() {
+        for chunk in [1, 5, SESSION.len()] {
+            let marks = marks_after(PromptContinuation::Merge, chunk);
+            let lines: Vec<usize> = marks.iter().map(|m| m.line).collect();
+            // The command's mark sits at the top of its two-line prompt,
+            // and the continuation row (line 2) gets none.
+            assert_eq!(lines, vec![0, 5], "chunk {chunk}");
+            assert_eq!(marks[0].exit_code, Some(0));
+            assert_eq!(marks[0].command.as_deref(), Some("for f in a b; do"));
+        }
+    }
+
+    #[test]
+    fn pty_output_merges_through_the_marker_tap() {
+        for chunk in [1, 5, SESSION.len()] {
+            let mut manager = TerminalManager::new(40, 10).unwrap();
+            manager.set_prompt_continuation(PromptContinuation::Merge);
+            // As the PTY reader thread does: the output callback sees each
+            // chunk before the core parses it unfiltered.
+            for piece in SESSION.chunks(chunk) {
+                manager.prompt_markers.record(piece);
+                manager.terminal().write().process(piece);
+            }
+            let (_, cursor_row) = manager.cursor_position();
+            let scrollback_len = manager.scrollback_len();
+            manager.update_scrollback_metadata(scrollback_len, cursor_row);
+            let lines: Vec<usize> = manager.scrollback_marks().iter().map(|m| m.line).collect();
+            assert_eq!(lines, vec![0, 5], "chunk {chunk}");
+        }
+    }
+
+    #[test]
+    fn new_prompt_mode_marks_continuation_row() {
+        let marks = marks_after(PromptContinuation::NewPrompt, SESSION.len());
+        let lines: Vec<usize> = marks.iter().map(|m| m.line).collect();
+        assert_eq!(lines, vec![0, 2, 5]);
+    }
+
+    #[test]
+    fn continuation_ends_at_next_marker() {
+        let mut filter = PromptContinuationFilter::new(PromptContinuation::Merge);
+        assert_eq!(
+            filter.filter(b"\x1b]133;A;k=s\x07> \x1b]133;B\x07x"),
+            b"> x"
+        );
+        // An executed command ends the continuation; later B markers pass
+        assert_eq!(
+            filter.filter(b"\x1b]133;C\x07\x1b]133;B\x07"),
+            b"\x1b]133;C\x07\x1b]133;B\x07"
+        );
+        // Initial prompts and other OSC sequences are left alone
+        assert_eq!(
+            filter.filter(b"\x1b]133;A;k=i\x07\x1b]1337;x\x07"),
+            b"\x1b]133;A;k=i\x07\x1b]1337;x\x07"
+        );
+    }
+}
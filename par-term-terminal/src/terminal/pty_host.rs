@@ -0,0 +1,560 @@
+//! PTY hosting for [`TerminalManager`](super::TerminalManager).
+//!
+//! [`PtyHost`] runs the child process under a pseudoterminal and feeds its
+//! output to the emulator the way the core library's `PtySession` does: same
+//! child environment, device query replies, alternate-screen resize pulse,
+//! coprocess feeding and update generation.  The difference is that each
+//! read passes through the manager's [`OutputFilters`] before the emulator
+//! parses it.  The core's reader thread parses the raw bytes itself, so
+//! without this the output filters could only apply to tmux pane data fed
+//! through [`TerminalManager::process_data`](super::TerminalManager::process_data).
+
+use super::output_filters::OutputFilters;
+use par_term_emu_core_rust::coprocess::{CoprocessConfig, CoprocessId, CoprocessManager};
+use par_term_emu_core_rust::pty_error::PtyError;
+use par_term_emu_core_rust::pty_session::OutputCallback;
+use par_term_emu_core_rust::terminal::Terminal;
+use parking_lot::{Mutex, RwLock};
+use portable_pty::{Child, CommandBuilder, MasterPty, PtySize, native_pty_system};
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Inherited variables that would mislead the child: static size hints that
+/// go stale after a resize, and the parent's multiplexer session.
+const DROPPED_ENV_VARS: &[&str] = &["COLUMNS", "LINES", "TMUX", "TMUX_PANE", "STY", "WINDOW"];
+
+/// How long to wait for a reader thread to see EOF before detaching it.
+const READER_JOIN_TIMEOUT: Duration = Duration::from_secs(2);
+
+type PtyWriter = Arc<Mutex<Box<dyn Write + Send>>>;
+
+/// A child process under a pseudoterminal, feeding a [`Terminal`].
+pub(crate) struct PtyHost {
+    terminal: Arc<RwLock<Terminal>>,
+    filters: Arc<OutputFilters>,
+    /// Master end; the slave is dropped after spawning so the reader sees
+    /// EOF when the child exits.
+    master: Option<Box<dyn MasterPty + Send>>,
+    child: Option<Box<dyn Child + Send + Sync>>,
+    child_pid: Option<u32>,
+    reader_thread: Option<JoinHandle<()>>,
+    writer: Option<PtyWriter>,
+    running: Arc<AtomicBool>,
+    cols: u16,
+    rows: u16,
+    /// Cell size reported through `TIOCGWINSZ`, updated by
+    /// [`PtyHost::resize_with_pixels`].
+    cell_pixel_width: u16,
+    cell_pixel_height: u16,
+    update_generation: Arc<AtomicU64>,
+    /// Reply to XTWINOPS queries (`PAR_TERM_REPLY_XTWINOPS`, default on).
+    reply_xtwinops: bool,
+    output_callback: Arc<Mutex<Option<OutputCallback>>>,
+    coprocess_manager: Arc<Mutex<CoprocessManager>>,
+}
+
+impl PtyHost {
+    /// Create a host with no process yet.
+    pub(crate) fn new(
+        cols: usize,
+        rows: usize,
+        max_scrollback: usize,
+        filters: Arc<OutputFilters>,
+    ) -> Self {
+        let reply_xtwinops = std::env::var("PAR_TERM_REPLY_XTWINOPS")
+            .map(|v| v != "0" && !v.eq_ignore_ascii_case("false"))
+            .unwrap_or(true);
+        Self {
+            terminal: Arc::new(RwLock::new(Terminal::with_scrollback(
+                cols,
+                rows,
+                max_scrollback,
+            ))),
+            filters,
+            master: None,
+            child: None,
+            child_pid: None,
+            reader_thread: None,
+            writer: None,
+            running: Arc::new(AtomicBool::new(false)),
+            cols: cols as u16,
+            rows: rows as u16,
+            cell_pixel_width: 10,
+            cell_pixel_height: 20,
+            update_generation: Arc::new(AtomicU64::new(0)),
+            reply_xtwinops,
+            output_callback: Arc::new(Mutex::new(None)),
+            coprocess_manager: Arc::new(Mutex::new(CoprocessManager::new())),
+        }
+    }
+
+    /// Set a callback that sees each read of raw output before it is
+    /// filtered and parsed.
+    pub(crate) fn set_output_callback(&mut self, callback: OutputCallback) {
+        *self.output_callback.lock() = Some(callback);
+    }
+
+    /// Spawn the user's shell.
+    pub(crate) fn spawn_shell(&mut self) -> Result<(), PtyError> {
+        self.spawn_shell_with_env(None, None)
+    }
+
+    /// Spawn the user's shell with extra environment variables and/or a
+    /// working directory.
+    pub(crate) fn spawn_shell_with_env(
+        &mut self,
+        env: Option<&HashMap<String, String>>,
+        cwd: Option<&str>,
+    ) -> Result<(), PtyError> {
+        let shell = default_shell();
+        self.spawn_with_env(&shell, &[], env, cwd)
+    }
+
+    /// Spawn `command` with `args`.
+    pub(crate) fn spawn(&mut self, command: &str, args: &[&str]) -> Result<(), PtyError> {
+        self.spawn_with_env(command, args, None, None)
+    }
+
+    /// Spawn `command` with `args`, extra environment variables and/or a
+    /// working directory.
+    pub(crate) fn spawn_with_env(
+        &mut self,
+        command: &str,
+        args: &[&str],
+        env: Option<&HashMap<String, String>>,
+        cwd: Option<&str>,
+    ) -> Result<(), PtyError> {
+        if self.is_running() {
+            return Err(PtyError::ProcessSpawnError(
+                "Process is already running".to_string(),
+            ));
+        }
+        self.cleanup_previous_session();
+        log::info!("Spawning process: {} {:?}", command, args);
+
+        let pair = native_pty_system()
+            .openpty(self.pty_size())
+            .map_err(|e| PtyError::ProcessSpawnError(e.to_string()))?;
+
+        let mut cmd = CommandBuilder::new(command);
+        cmd.args(args);
+        // CommandBuilder starts from the parent environment, so the dropped
+        // variables have to be removed explicitly.
+        for &var in DROPPED_ENV_VARS {
+            cmd.env_remove(var);
+        }
+        for (key, value) in std::env::vars() {
+            if !DROPPED_ENV_VARS.contains(&key.as_str()) {
+                cmd.env(key, value);
+            }
+        }
+        cmd.env("TERM", "xterm-256color");
+        cmd.env("COLORTERM", "truecolor");
+        // Kitty protocol detection
+        cmd.env("TERM_PROGRAM", "kitty");
+        cmd.env("KITTY_WINDOW_ID", "1");
+        cmd.env("KITTY_PID", std::process::id().to_string());
+        for (key, value) in env.into_iter().flatten() {
+            cmd.env(key, value);
+        }
+        if let Some(cwd) = cwd {
+            cmd.cwd(cwd);
+        }
+
+        let child = pair
+            .slave
+            .spawn_command(cmd)
+            .map_err(|e| PtyError::ProcessSpawnError(e.to_string()))?;
+        drop(pair.slave);
+
+        let reader = pair
+            .master
+            .try_clone_reader()
+            .map_err(|e| PtyError::ProcessSpawnError(e.to_string()))?;
+        let writer: PtyWriter = Arc::new(Mutex::new(
+            pair.master
+                .take_writer()
+                .map_err(|e| PtyError::ProcessSpawnError(e.to_string()))?,
+        ));
+
+        self.child_pid = child.process_id();
+        self.master = Some(pair.master);
+        self.child = Some(child);
+        self.writer = Some(Arc::clone(&writer));
+        self.running.store(true, Ordering::SeqCst);
+        self.start_reader_thread(reader, writer);
+        Ok(())
+    }
+
+    fn pty_size(&self) -> PtySize {
+        PtySize {
+            rows: self.rows,
+            cols: self.cols,
+            pixel_width: self.cols * self.cell_pixel_width,
+            pixel_height: self.rows * self.cell_pixel_height,
+        }
+    }
+
+    fn start_reader_thread(&mut self, mut reader: Box<dyn Read + Send>, writer: PtyWriter) {
+        let reader_state = ReaderState {
+            terminal: Arc::clone(&self.terminal),
+            filters: Arc::clone(&self.filters),
+            update_generation: Arc::clone(&self.update_generation),
+            output_callback: Arc::clone(&self.output_callback),
+            coprocess_manager: Arc::clone(&self.coprocess_manager),
+            reply_xtwinops: self.reply_xtwinops,
+            child_pid: self.child_pid,
+            writer,
+        };
+        let running = Arc::clone(&self.running);
+
+        self.reader_thread = Some(std::thread::spawn(move || {
+            let mut buffer = [0u8; 16384];
+            loop {
+                match reader.read(&mut buffer) {
+                    Ok(0) => break,
+                    Ok(n) => reader_state.handle_output(&buffer[..n]),
+                    Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                    Err(e) => {
+                        // EIO once the child side closes on Linux
+                        log::debug!("PTY read ended: {}", e);
+                        break;
+                    }
+                }
+            }
+            running.store(false, Ordering::SeqCst);
+        }));
+    }
+
+    /// Close the previous child's PTY and reap it before spawning again.
+    fn cleanup_previous_session(&mut self) {
+        self.writer = None;
+        self.master = None;
+        if let Some(handle) = self.reader_thread.take() {
+            join_with_timeout(handle);
+        }
+        if let Some(mut child) = self.child.take()
+            && matches!(child.try_wait(), Ok(None))
+        {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+
+    /// Write input to the child.
+    pub(crate) fn write(&mut self, data: &[u8]) -> Result<(), PtyError> {
+        if !self.is_running() {
+            return Err(PtyError::NotStartedError);
+        }
+        self.terminal.write().record_input(data);
+        let writer = self.writer.as_ref().ok_or(PtyError::NotStartedError)?;
+        let mut writer = writer.lock();
+        writer.write_all(data).map_err(PtyError::IoError)?;
+        writer.flush().map_err(PtyError::IoError)
+    }
+
+    /// Write a string to the child.
+    pub(crate) fn write_str(&mut self, data: &str) -> Result<(), PtyError> {
+        self.write(data.as_bytes())
+    }
+
+    /// Resize the terminal and the PTY, and signal the child.
+    pub(crate) fn resize(&mut self, cols: u16, rows: u16) -> Result<(), PtyError> {
+        self.cols = cols;
+        self.rows = rows;
+        {
+            let mut term = self.terminal.write();
+            term.resize(cols as usize, rows as usize);
+            term.record_resize(cols as usize, rows as usize);
+        }
+        self.resize_pty(self.pty_size())
+    }
+
+    /// Resize the terminal and the PTY with the text area's pixel size, and
+    /// signal the child.
+    pub(crate) fn resize_with_pixels(
+        &mut self,
+        cols: u16,
+        rows: u16,
+        pixel_width: u16,
+        pixel_height: u16,
+    ) -> Result<(), PtyError> {
+        self.cols = cols;
+        self.rows = rows;
+        if cols > 0 && rows > 0 {
+            self.cell_pixel_width = (pixel_width / cols).max(1);
+            self.cell_pixel_height = (pixel_height / rows).max(1);
+        }
+        {
+            let mut term = self.terminal.write();
+            term.resize(cols as usize, rows as usize);
+            term.set_pixel_size(pixel_width as usize, pixel_height as usize);
+        }
+        self.resize_pty(PtySize {
+            rows,
+            cols,
+            pixel_width,
+            pixel_height,
+        })
+    }
+
+    fn resize_pty(&self, size: PtySize) -> Result<(), PtyError> {
+        if let Some(master) = &self.master {
+            master
+                .resize(size)
+                .map_err(|e| PtyError::ResizeError(e.to_string()))?;
+        }
+        // The resize ioctl does not reliably reach every process in the
+        // group, so signal it directly as well.
+        signal_resize(self.child_pid);
+        Ok(())
+    }
+
+    /// Whether the child is still running (cleared on EOF, exit or kill).
+    pub(crate) fn is_running(&self) -> bool {
+        self.running.load(Ordering::SeqCst)
+    }
+
+    /// PID of the spawned child.
+    pub(crate) fn child_pid(&self) -> Option<u32> {
+        self.child_pid
+    }
+
+    /// Exit code of the child, without blocking.  `None` while it runs.
+    pub(crate) fn try_wait(&mut self) -> Result<Option<i32>, PtyError> {
+        let child = self.child.as_mut().ok_or(PtyError::NotStartedError)?;
+        match child.try_wait().map_err(PtyError::IoError)? {
+            Some(status) => {
+                self.running.store(false, Ordering::SeqCst);
+                Ok(Some(status.exit_code() as i32))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Kill the child.
+    pub(crate) fn kill(&mut self) -> Result<(), PtyError> {
+        let child = self.child.as_mut().ok_or(PtyError::NotStartedError)?;
+        child.kill().map_err(PtyError::IoError)?;
+        self.running.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// The emulator fed by this PTY.
+    pub(crate) fn terminal(&self) -> Arc<RwLock<Terminal>> {
+        Arc::clone(&self.terminal)
+    }
+
+    /// Screen content as text.
+    pub(crate) fn content(&self) -> String {
+        self.terminal.read().content()
+    }
+
+    /// Scrollback lines as text.
+    pub(crate) fn scrollback(&self) -> Vec<String> {
+        self.terminal.read().scrollback()
+    }
+
+    /// Number of scrollback lines in the active screen.
+    pub(crate) fn scrollback_len(&self) -> usize {
+        self.terminal.read().active_grid().scrollback_len()
+    }
+
+    /// Cursor position as `(col, row)`.
+    pub(crate) fn cursor_position(&self) -> (usize, usize) {
+        let term = self.terminal.read();
+        let cursor = term.cursor();
+        (cursor.col, cursor.row)
+    }
+
+    /// Counter bumped whenever output changes the terminal.
+    pub(crate) fn update_generation(&self) -> u64 {
+        self.update_generation.load(Ordering::SeqCst)
+    }
+
+    /// Bells received so far.
+    pub(crate) fn bell_count(&self) -> u64 {
+        self.terminal.read().bell_count()
+    }
+
+    /// Start a coprocess fed with this PTY's output.
+    pub(crate) fn start_coprocess(&self, config: CoprocessConfig) -> Result<CoprocessId, String> {
+        self.coprocess_manager.lock().start(config)
+    }
+
+    /// Stop a coprocess.
+    pub(crate) fn stop_coprocess(&self, id: CoprocessId) -> Result<(), String> {
+        self.coprocess_manager.lock().stop(id)
+    }
+
+    /// Drain a coprocess's buffered output lines.
+    pub(crate) fn read_from_coprocess(&self, id: CoprocessId) -> Result<Vec<String>, String> {
+        self.coprocess_manager.lock().read(id)
+    }
+
+    /// IDs of all coprocesses.
+    pub(crate) fn list_coprocesses(&self) -> Vec<CoprocessId> {
+        self.coprocess_manager.lock().list()
+    }
+
+    /// Whether a coprocess is running; `None` for an unknown ID.
+    pub(crate) fn coprocess_status(&self, id: CoprocessId) -> Option<bool> {
+        self.coprocess_manager.lock().status(id)
+    }
+
+    /// Drain a coprocess's buffered stderr lines.
+    pub(crate) fn read_coprocess_errors(&self, id: CoprocessId) -> Result<Vec<String>, String> {
+        self.coprocess_manager.lock().read_errors(id)
+    }
+}
+
+impl Drop for PtyHost {
+    fn drop(&mut self) {
+        self.coprocess_manager.lock().stop_all();
+        if self.is_running() {
+            let _ = self.kill();
+        }
+        // Closing the master lets the blocked read return so the reader
+        // thread can be joined.
+        self.writer = None;
+        self.master = None;
+        if let Some(handle) = self.reader_thread.take() {
+            join_with_timeout(handle);
+        }
+    }
+}
+
+/// What the reader thread needs to process a read.
+struct ReaderState {
+    terminal: Arc<RwLock<Terminal>>,
+    filters: Arc<OutputFilters>,
+    update_generation: Arc<AtomicU64>,
+    output_callback: Arc<Mutex<Option<OutputCallback>>>,
+    coprocess_manager: Arc<Mutex<CoprocessManager>>,
+    reply_xtwinops: bool,
+    child_pid: Option<u32>,
+    writer: PtyWriter,
+}
+
+impl ReaderState {
+    fn handle_output(&self, data: &[u8]) {
+        // Bump before processing so the counter advances even if processing
+        // panics, and again after, so a frame rendered between the two from
+        // the old grid is not taken as current.
+        self.update_generation.fetch_add(1, Ordering::SeqCst);
+
+        if let Some(callback) = self.output_callback.lock().as_ref() {
+            callback(data);
+        }
+        self.coprocess_manager.lock().feed_output(data);
+
+        let (batches, responses) = {
+            let mut term = self.terminal.write();
+            let was_alt_screen = term.is_alt_screen_active();
+            let batches = self.filters.process_pty_output(&mut term, data);
+            term.record_output(data);
+            term.process_trigger_scans();
+            let mut responses = if term.has_pending_responses() {
+                term.drain_responses()
+            } else {
+                Vec::new()
+            };
+            if !self.reply_xtwinops {
+                responses = drop_xtwinops_replies(&responses);
+            }
+            // Entering the alternate screen: a resize pulse makes programs
+            // such as tmux recalculate their layout.
+            if !was_alt_screen && term.is_alt_screen_active() {
+                signal_resize(self.child_pid);
+            }
+            self.update_generation.fetch_add(1, Ordering::SeqCst);
+            (batches, responses)
+        };
+
+        // Write device query replies and run observers outside the lock
+        if !responses.is_empty() {
+            let mut writer = self.writer.lock();
+            let _ = writer.write_all(&responses);
+            let _ = writer.flush();
+        }
+        for batch in batches {
+            batch.deliver();
+        }
+    }
+}
+
+/// Remove XTWINOPS (`CSI … t`) replies, which some shells echo visibly.
+fn drop_xtwinops_replies(responses: &[u8]) -> Vec<u8> {
+    let mut kept = Vec::with_capacity(responses.len());
+    let mut i = 0;
+    while i < responses.len() {
+        if responses[i..].starts_with(b"\x1b[") {
+            let end = responses[i + 2..]
+                .iter()
+                .position(u8::is_ascii_alphabetic)
+                .map_or(responses.len(), |pos| i + 2 + pos + 1);
+            if responses[end - 1] != b't' {
+                kept.extend_from_slice(&responses[i..end]);
+            }
+            i = end;
+        } else {
+            kept.push(responses[i]);
+            i += 1;
+        }
+    }
+    kept
+}
+
+/// Send SIGWINCH to the child's process group.
+fn signal_resize(child_pid: Option<u32>) {
+    #[cfg(unix)]
+    if let Some(pid) = child_pid {
+        // SAFETY: kill(2) with a negated PID signals a process group and
+        // touches no memory.
+        let result = unsafe { libc::kill(-(pid as libc::pid_t), libc::SIGWINCH) };
+        if result != 0 {
+            log::debug!(
+                "Failed to send SIGWINCH to process group {}: {}",
+                pid,
+                std::io::Error::last_os_error()
+            );
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = child_pid;
+}
+
+/// Join a reader thread, detaching it if it does not finish in time.
+fn join_with_timeout(handle: JoinHandle<()>) {
+    let start = Instant::now();
+    while !handle.is_finished() && start.elapsed() < READER_JOIN_TIMEOUT {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    if handle.is_finished() {
+        let _ = handle.join();
+    } else {
+        log::info!("PTY reader thread did not finish in time; detaching it");
+    }
+}
+
+/// The user's shell: `$SHELL` (default `/bin/bash`), or `/bin/sh` when that
+/// does not exist; `%COMSPEC%` on Windows.
+fn default_shell() -> String {
+    #[cfg(windows)]
+    {
+        std::env::var("COMSPEC").unwrap_or_else(|_| "cmd.exe".to_string())
+    }
+    #[cfg(not(windows))]
+    {
+        let shell = std::env::var("SHELL").unwrap_or_else(|_| "/bin/bash".to_string());
+        if std::path::Path::new(&shell).is_file() {
+            shell
+        } else {
+            "/bin/sh".to_string()
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use super::TerminalManager;
-use par_term_config::{Cell, Theme};
+use super::color_limit::limit_color;
+use par_term_config::{Cell, ColorMode, Theme};
 
 /// Shared context for row-level cell rendering helpers.
 ///
@@ -17,12 +18,14 @@ pub(crate) struct RowRenderContext<'a> {
         par_term_emu_core_rust::cursor::CursorStyle,
     )>,
     pub theme: &'a Theme,
+    /// Color depth to reduce cell colors to (alternate screen only)
+    pub color_mode: Option<ColorMode>,
 }
 
 impl TerminalManager {
     /// Non-blocking variant of [`get_cells_with_scrollback`].
     ///
-    /// Uses `try_lock()` on the internal `PtySession` and `Terminal` mutexes
+    /// Uses `try_lock()` on the internal `PtyHost` and `Terminal` mutexes
     /// instead of blocking `lock()`.  Returns `None` when either lock is held
     /// by the PTY reader thread, allowing the caller to fall back to cached
     /// cells without stalling the render loop.
@@ -35,6 +38,7 @@ impl TerminalManager {
         let pty = self.pty_session.try_lock()?;
         let terminal = pty.terminal();
         let mut term = terminal.try_write()?;
+        let color_mode = self.active_color_mode(&term);
         let grid = term.active_grid();
 
         let rows = grid.rows();
@@ -62,6 +66,7 @@ impl TerminalManager {
                             rectangular,
                             cursor: None,
                             theme: &self.theme,
+                            color_mode,
                         },
                     );
                 } else {
@@ -80,12 +85,20 @@ impl TerminalManager {
                         rectangular,
                         cursor: None,
                         theme: &self.theme,
+                        color_mode,
                     },
                 );
             }
         }
 
-        // Apply trigger highlights on top of cell colors
+        // Apply saved keyword highlights, then trigger highlights on top
+        self.keyword_highlighter
+            .apply(&mut cells, cols, |col, row| {
+                Self::is_cell_selected(col, row, selection, rectangular)
+            });
+        let cursor_line = scrollback_len + term.cursor().row;
+        self.sensitive_input
+            .mask_cells(&mut cells, cols, start_line, cursor_line);
         let highlights = term.get_trigger_highlights();
         for highlight in &highlights {
             let abs_row = scrollback_len + highlight.row;
@@ -171,6 +184,7 @@ impl TerminalManager {
         let pty = self.pty_session.lock();
         let terminal = pty.terminal();
         let mut term = terminal.write();
+        let color_mode = self.active_color_mode(&term);
         let grid = term.active_grid();
 
         let cursor_with_style = None;
@@ -200,6 +214,7 @@ impl TerminalManager {
                             rectangular,
                             cursor: cursor_with_style,
                             theme: &self.theme,
+                            color_mode,
                         },
                     );
                 } else {
@@ -218,12 +233,20 @@ impl TerminalManager {
                         rectangular,
                         cursor: cursor_with_style,
                         theme: &self.theme,
+                        color_mode,
                     },
                 );
             }
         }
 
-        // Apply trigger highlights on top of cell colors
+        // Apply saved keyword highlights, then trigger highlights on top
+        self.keyword_highlighter
+            .apply(&mut cells, cols, |col, row| {
+                Self::is_cell_selected(col, row, selection, rectangular)
+            });
+        let cursor_line = scrollback_len + term.cursor().row;
+        self.sensitive_input
+            .mask_cells(&mut cells, cols, start_line, cursor_line);
         let highlights = term.get_trigger_highlights();
         for highlight in &highlights {
             let abs_row = scrollback_len + highlight.row;
@@ -249,6 +272,15 @@ impl TerminalManager {
         cells
     }
 
+    /// Color depth for the screen `term` is showing.
+    fn active_color_mode(
+        &self,
+        term: &par_term_emu_core_rust::terminal::Terminal,
+    ) -> Option<ColorMode> {
+        self.alt_screen_color_mode
+            .filter(|_| term.is_alt_screen_active())
+    }
+
     pub(crate) fn push_line_from_slice(
         line: &[par_term_emu_core_rust::cell::Cell],
         ctx: &mut RowRenderContext<'_>,
@@ -269,6 +301,7 @@ impl TerminalManager {
                 is_selected,
                 cursor_info,
                 ctx.theme,
+                ctx.color_mode,
             ));
         }
 
@@ -298,6 +331,7 @@ impl TerminalManager {
                     is_selected,
                     cursor_info,
                     ctx.theme,
+                    ctx.color_mode,
                 ));
             } else {
                 ctx.dest.push(Cell::default());
@@ -348,10 +382,18 @@ impl TerminalManager {
         is_selected: bool,
         cursor_info: Option<(f32, par_term_emu_core_rust::cursor::CursorStyle)>,
         theme: &Theme,
+        color_mode: Option<ColorMode>,
     ) -> Cell {
         use par_term_emu_core_rust::color::{Color as TermColor, NamedColor};
         use par_term_emu_core_rust::cursor::CursorStyle as TermCursorStyle;
 
+        let limit = |color: TermColor| match color_mode {
+            Some(mode) => limit_color(color, mode, theme),
+            None => color,
+        };
+        let term_fg = limit(term_cell.fg());
+        let term_bg = limit(term_cell.bg());
+
         let bg_rgb = term_cell.bg().to_rgb();
         let fg_rgb = term_cell.fg().to_rgb();
         let has_colored_bg = bg_rgb != (0, 0, 0);
@@ -380,7 +422,7 @@ impl TerminalManager {
         }
 
         // Apply theme colors for ANSI colors (Named colors)
-        let fg = match &term_cell.fg() {
+        let fg = match &term_fg {
             TermColor::Named(named) => {
                 #[allow(unreachable_patterns)]
                 let theme_color = match named {
@@ -404,10 +446,10 @@ impl TerminalManager {
                 };
                 (theme_color.r, theme_color.g, theme_color.b)
             }
-            _ => term_cell.fg().to_rgb(),
+            _ => term_fg.to_rgb(),
         };
 
-        let bg = match &term_cell.bg() {
+        let bg = match &term_bg {
             TermColor::Named(named) => {
                 #[allow(unreachable_patterns)]
                 let theme_color = match named {
@@ -431,7 +473,7 @@ impl TerminalManager {
                 };
                 (theme_color.r, theme_color.g, theme_color.b)
             }
-            _ => term_cell.bg().to_rgb(),
+            _ => term_bg.to_rgb(),
         };
 
         let is_reverse = term_cell.flags().reverse();
@@ -1,9 +1,36 @@
 use super::TerminalManager;
 use crate::scrollback_metadata::{CommandSnapshot, LineMetadata, ScrollbackMark};
+use par_term_config::MarkTrimPolicy;
 use par_term_emu_core_rust::shell_integration::ShellIntegrationMarker;
 use par_term_emu_core_rust::terminal::Terminal;
 
+/// Lines evicted from the top of a full scrollback between two samples.
+///
+/// Samples are `(total_lines_scrolled, scrollback_len)` of the primary grid.
+/// Lines scrolled off the screen either grow the scrollback or, once it is
+/// full, push its oldest lines out.  A drop in the scrolled count means the
+/// scrollback was cleared, which resets the metadata instead.
+fn evicted_lines(
+    prev: Option<(usize, usize)>,
+    now: (usize, usize),
+    max_scrollback: usize,
+) -> usize {
+    let Some((prev_scrolled, prev_len)) = prev else {
+        return 0;
+    };
+    let (scrolled, len) = now;
+    if len < max_scrollback || scrolled < prev_scrolled {
+        return 0;
+    }
+    (scrolled - prev_scrolled).saturating_sub(len.saturating_sub(prev_len))
+}
+
 impl TerminalManager {
+    /// Set what happens to prompt marks whose lines are trimmed from scrollback.
+    pub fn set_mark_trim_policy(&mut self, policy: MarkTrimPolicy) {
+        self.mark_trim_policy = policy;
+    }
+
     /// Update scrollback metadata based on shell integration events from the core.
     ///
     /// Drains the queued `ShellIntegrationEvent` events from the terminal, each of
@@ -14,11 +41,26 @@ impl TerminalManager {
     /// Command text is captured from the terminal grid when the marker changes
     /// away from CommandStart, then injected into scrollback marks after
     /// `apply_event()` creates them.
+    ///
+    /// Lines trimmed from a full scrollback since the previous call are
+    /// applied first, so existing marks stay on the content they point at.
     pub fn update_scrollback_metadata(&mut self, scrollback_len: usize, cursor_row: usize) {
         let pty = self.pty_session.lock();
         let terminal = pty.terminal();
         let mut term = terminal.write();
 
+        let grid = term.grid();
+        let sample = (grid.total_lines_scrolled(), grid.scrollback_len());
+        let evicted = evicted_lines(
+            self.scrollback_sample.replace(sample),
+            sample,
+            grid.max_scrollback(),
+        );
+        if evicted > 0 {
+            self.scrollback_metadata
+                .trim(evicted, self.mark_trim_policy);
+        }
+
         // Drain any screen-cleared events first.  Only reset scrollback mark
         // metadata when the scrollback buffer itself was cleared (ESC[3J).
         // A screen-only clear (ESC[2J, e.g. alt-screen entry) does not touch
@@ -57,6 +99,11 @@ impl TerminalManager {
         // Process each queued event at its recorded cursor position.
         if !shell_events.is_empty() {
             for (event_type, event_command, exit_code, _timestamp, cursor_line) in &shell_events {
+                // A continuation prompt folded into the command being entered
+                if self.prompt_markers.take_dropped(event_type) {
+                    continue;
+                }
+
                 let marker = match event_type.as_str() {
                     "prompt_start" => Some(ShellIntegrationMarker::PromptStart),
                     "command_start" => Some(ShellIntegrationMarker::CommandStart),
@@ -80,6 +127,12 @@ impl TerminalManager {
                     },
                 );
 
+                // A command entered at a password prompt is kept out of history
+                let sensitive = self.sensitive_input.on_marker(marker);
+                if sensitive {
+                    self.marker_tracker.captured_command_text = None;
+                }
+
                 // Only pass history/exit info for CommandFinished events.
                 let is_finished = matches!(marker, Some(ShellIntegrationMarker::CommandFinished));
 
@@ -107,20 +160,32 @@ impl TerminalManager {
                                     .map(|(_, t)| t.clone())
                             })
                             .unwrap_or_default();
-                        if !cmd_text.is_empty() {
+                        if !cmd_text.is_empty() && !sensitive {
                             term.start_command_execution(cmd_text.clone());
                             self.marker_tracker.push_command_started(cmd_text, abs_line);
                         }
                     }
                     "command_finished" => {
+                        // Only a command that was started has a duration; otherwise the
+                        // last history entry belongs to an earlier command.
+                        let started = term.get_current_command().is_some();
                         term.end_command_execution(*exit_code);
-                        self.marker_tracker.push_command_finished(abs_line);
+                        let finished = term.get_command_history().last().filter(|_| started);
+                        self.marker_tracker.push_command_finished(
+                            abs_line,
+                            finished.map(|c| c.command.clone()),
+                            *exit_code,
+                            finished.and_then(|c| c.duration_ms),
+                        );
                     }
                     _ => {}
                 }
             }
         }
 
+        self.sensitive_input
+            .detect(&term, scrollback_len, &self.marker_tracker);
+
         drop(term);
         drop(terminal);
         drop(pty);
@@ -209,6 +274,30 @@ impl TerminalManager {
         self.scrollback_metadata.metadata_for_line(line)
     }
 
+    /// The last `n` completed commands with their text, exit codes, durations
+    /// and output line ranges, most recent first.
+    pub fn recent_commands(&self, n: usize) -> Vec<CommandSnapshot> {
+        self.scrollback_metadata.recent_commands(n)
+    }
+
+    /// Absolute lines holding the output of the running command, or of the
+    /// last finished command when the shell is idle.
+    ///
+    /// A running command's output runs from the line after the last prompt
+    /// mark to the bottom of the screen.  `None` without shell integration.
+    pub fn current_command_output_lines(&self) -> Option<std::ops::Range<usize>> {
+        if self.is_command_running() {
+            let total_lines = self.scrollback_len() + self.dimensions().1;
+            let prompt_line = self.scrollback_metadata.previous_mark(total_lines)?;
+            return Some((prompt_line + 1).min(total_lines)..total_lines);
+        }
+        self.scrollback_metadata
+            .recent_commands(1)
+            .into_iter()
+            .next()
+            .and_then(|command| command.output_lines)
+    }
+
     /// Get command history from the core library (commands tracked via shell integration).
     ///
     /// Returns commands as `(command_text, exit_code, duration_ms)` tuples.
@@ -344,6 +433,7 @@ impl TerminalManager {
                         rectangular: false,
                         cursor: None,
                         theme: &self.theme,
+                        color_mode: None,
                     },
                 );
             } else {
@@ -374,16 +464,12 @@ impl TerminalManager {
         self.marker_tracker.drain_events()
     }
 
-    /// Search for text in the visible screen.
-    pub fn search(
-        &self,
-        query: &str,
-        case_sensitive: bool,
-    ) -> Vec<par_term_emu_core_rust::terminal::SearchMatch> {
-        let pty = self.pty_session.lock();
-        let terminal = pty.terminal();
-        let term = terminal.write();
-        term.search_text(query, case_sensitive)
+    /// Drain `CommandFinished` events queued for completion notifications.
+    ///
+    /// Kept apart from [`Self::drain_shell_lifecycle_events`], which only the
+    /// rendered terminal drains, so every tab and pane can be polled.
+    pub fn drain_finished_commands(&mut self) -> Vec<super::ShellLifecycleEvent> {
+        self.marker_tracker.drain_finished_commands()
     }
 
     /// Search for text in the scrollback buffer.
@@ -427,3 +513,89 @@ impl TerminalManager {
         results
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::ShellLifecycleEvent;
+    use super::*;
+
+    #[test]
+    fn eviction_counts_only_lines_pushed_out_of_full_scrollback() {
+        // Scrollback filling up: nothing evicted.
+        assert_eq!(evicted_lines(Some((10, 10)), (30, 30), 100), 0);
+        // Crossing the limit: 20 scrolled, 10 of them grew the scrollback.
+        assert_eq!(evicted_lines(Some((90, 90)), (110, 100), 100), 10);
+        // Already full: every scrolled line evicts one.
+        assert_eq!(evicted_lines(Some((110, 100)), (115, 100), 100), 5);
+        // First sample or cleared scrollback.
+        assert_eq!(evicted_lines(None, (115, 100), 100), 0);
+        assert_eq!(evicted_lines(Some((115, 100)), (0, 0), 100), 0);
+    }
+
+    fn finished_events(manager: &mut TerminalManager, data: &[u8]) -> Vec<ShellLifecycleEvent> {
+        manager.process_data(data);
+        let (_, row) = manager.cursor_position();
+        manager.update_scrollback_metadata(manager.scrollback_len(), row);
+        manager
+            .drain_shell_lifecycle_events()
+            .into_iter()
+            .filter(|event| matches!(event, ShellLifecycleEvent::CommandFinished { .. }))
+            .collect()
+    }
+
+    #[test]
+    fn command_finished_event_reports_command_exit_and_duration() {
+        let mut manager = TerminalManager::new(40, 10).unwrap();
+        finished_events(&mut manager, b"\x1b]133;A\x07$ \x1b]133;B\x07");
+        let events = finished_events(&mut manager, b"make\r\n\x1b]133;C\x07\x1b]133;D;2\x07");
+        let [
+            ShellLifecycleEvent::CommandFinished {
+                command,
+                exit_code,
+                duration_ms,
+                ..
+            },
+        ] = events.as_slice()
+        else {
+            panic!("expected one finished command, got {events:?}");
+        };
+        assert_eq!(command.as_deref(), Some("make"));
+        assert_eq!(*exit_code, Some(2));
+        assert!(duration_ms.is_some());
+
+        // A finish without a started command has nothing to time
+        let events = finished_events(&mut manager, b"\x1b]133;A\x07$ \x1b]133;D;0\x07");
+        let [
+            ShellLifecycleEvent::CommandFinished {
+                command,
+                exit_code,
+                duration_ms,
+                ..
+            },
+        ] = events.as_slice()
+        else {
+            panic!("expected one finished command, got {events:?}");
+        };
+        assert_eq!(*command, None);
+        assert_eq!(*exit_code, Some(0));
+        assert_eq!(*duration_ms, None);
+    }
+
+    #[test]
+    fn finished_commands_drain_apart_from_lifecycle_events() {
+        let mut manager = TerminalManager::new(40, 10).unwrap();
+        finished_events(&mut manager, b"\x1b]133;A\x07$ \x1b]133;B\x07");
+        let events = finished_events(&mut manager, b"make\r\n\x1b]133;C\x07\x1b]133;D;1\x07");
+        assert_eq!(events.len(), 1);
+
+        let finished = manager.drain_finished_commands();
+        assert!(matches!(
+            finished.as_slice(),
+            [ShellLifecycleEvent::CommandFinished {
+                exit_code: Some(1),
+                ..
+            }]
+        ));
+        assert!(manager.drain_finished_commands().is_empty());
+    }
+}
@@ -0,0 +1,645 @@
+//! Regex, case and whole-word search over scrollback and screen.
+//!
+//! [`TerminalManager::search_with_options`] searches the same plain text that
+//! [`segments_to_plain_text`](super::export::segments_to_plain_text) produces
+//! for copy and export, one logical line at a time: soft-wrapped rows are
+//! joined first, so a pattern can match across a wrap, and `^`/`$` anchor to
+//! the logical line.  Match positions are counted in grapheme clusters, which
+//! is one per rendered cell (wide characters included), not in bytes.
+//!
+//! A match's `line` is the absolute row its logical line starts on (0 = oldest
+//! scrollback line); on a soft-wrapped line the `column` can therefore exceed
+//! the terminal width.  Empty matches (e.g. `\b` or `x*`) are skipped.
+//!
+//! Whole-word matching uses the same word characters as double-click
+//! selection ([`is_word_char`]): a match is kept only when the characters on
+//! either side of it are not word characters.  With the default
+//! `word_characters` that means `log` matches in `(log)` and `log:` but not
+//! in `login` or `app.log`, and text without spaces, such as CJK, is one word
+//! until punctuation (`、`, `。`) or a space breaks it.
+//!
+//! [`TerminalManager::search_incremental`] scans outward from a given line in
+//! batches of rows, so a caller can show the nearest matches while the rest of
+//! a long scrollback is still unread, or stop once it has enough.  Line text
+//! is read straight from the grid cells, once per row, and searched in place:
+//! no theme colors or styled segments are computed, so a line without a match
+//! costs one regex scan.  Each batch takes the terminal lock on its own, so
+//! output that arrives between batches is seen by the later ones.
+
+use anyhow::{Context, Result};
+use par_term_emu_core_rust::cell::Cell;
+use par_term_emu_core_rust::grid::Grid;
+use regex::{Regex, RegexBuilder};
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::TerminalManager;
+use crate::SearchMatch;
+
+/// Rows read per batch by [`IncrementalSearch`].
+const SEARCH_BATCH_ROWS: usize = 2000;
+
+/// How [`TerminalManager::search_with_options`] interprets the query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchOptions {
+    /// Treat the query as a regular expression instead of literal text
+    pub regex: bool,
+    /// Match letter case exactly
+    pub case_sensitive: bool,
+    /// Only match whole words, not text inside a longer word
+    pub whole_word: bool,
+    /// Non-alphanumeric characters that count as part of a word for
+    /// `whole_word`, as in the `word_characters` selection setting
+    pub word_characters: String,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            regex: false,
+            case_sensitive: false,
+            whole_word: false,
+            word_characters: par_term_config::defaults::word_characters(),
+        }
+    }
+}
+
+impl SearchOptions {
+    /// Compile `query` into the regex these options describe.
+    ///
+    /// Word boundaries are not part of the regex; `whole_word` is applied
+    /// by [`find_in_line`].
+    pub fn build_regex(&self, query: &str) -> Result<Regex> {
+        let pattern = if self.regex {
+            query.to_string()
+        } else {
+            regex::escape(query)
+        };
+        RegexBuilder::new(&pattern)
+            .case_insensitive(!self.case_sensitive)
+            .build()
+            .with_context(|| format!("Invalid search pattern {query:?}"))
+    }
+
+    /// Word characters to check around each match, when `whole_word` is set.
+    fn word_boundary(&self) -> Option<String> {
+        self.whole_word.then(|| self.word_characters.clone())
+    }
+}
+
+/// Whether `ch` is part of a word: alphanumeric, or one of the user's
+/// `word_characters`.
+///
+/// Underscore is not hardcoded as a word character; it is in the default
+/// `word_characters` (`/-+\~_.`) and can be removed there.  Shared by
+/// double-click selection, copy mode word motions and whole-word search.
+pub fn is_word_char(ch: char, word_characters: &str) -> bool {
+    ch.is_alphanumeric() || word_characters.contains(ch)
+}
+
+/// Non-empty matches of `regex` in one logical line of `text` starting on
+/// absolute row `line`, with columns and lengths in grapheme clusters.
+///
+/// With `word_characters`, only matches not touching a word character on
+/// either side are kept (see [`is_word_char`]).
+pub fn find_in_line(
+    regex: &Regex,
+    word_characters: Option<&str>,
+    line: usize,
+    text: &str,
+) -> Vec<SearchMatch> {
+    let ranges = match word_characters {
+        None => regex
+            .find_iter(text)
+            .filter(|m| !m.is_empty())
+            .map(|m| m.range())
+            .collect(),
+        Some(word_characters) => whole_word_ranges(regex, word_characters, text),
+    };
+    if ranges.is_empty() {
+        return Vec::new();
+    }
+
+    let starts: Vec<usize> = text.grapheme_indices(true).map(|(i, _)| i).collect();
+    ranges
+        .into_iter()
+        .map(|range| {
+            // A match starting inside a cluster is attributed to that cluster.
+            let column = starts
+                .partition_point(|&s| s <= range.start)
+                .saturating_sub(1);
+            let end = starts.partition_point(|&s| s < range.end);
+            SearchMatch::new(line, column, end - column)
+        })
+        .collect()
+}
+
+/// Byte ranges of non-empty matches of `regex` in `text` that are whole
+/// words.
+///
+/// A rejected match is retried one character further on rather than after
+/// its end, so `ab` inside `aab ab` still finds the second word.
+fn whole_word_ranges(
+    regex: &Regex,
+    word_characters: &str,
+    text: &str,
+) -> Vec<std::ops::Range<usize>> {
+    let is_word = |c: char| is_word_char(c, word_characters);
+    let mut ranges = Vec::new();
+    let mut pos = 0;
+    while let Some(m) = regex.find_at(text, pos) {
+        let whole = !m.is_empty()
+            && !text[..m.start()].chars().next_back().is_some_and(is_word)
+            && !text[m.end()..].chars().next().is_some_and(is_word);
+        if whole {
+            ranges.push(m.range());
+            pos = m.end();
+        } else {
+            match text[m.start()..].chars().next() {
+                Some(c) => pos = m.start() + c.len_utf8(),
+                None => break,
+            }
+        }
+    }
+    ranges
+}
+
+/// Which way [`TerminalManager::search_incremental`] scans.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SearchDirection {
+    /// Towards the newest line (down)
+    #[default]
+    Forward,
+    /// Towards the oldest scrollback line (up)
+    Backward,
+}
+
+/// Row access across scrollback and screen by absolute line number.
+pub(super) struct GridLines<'g> {
+    grid: &'g Grid,
+    scrollback_len: usize,
+    pub(super) total: usize,
+}
+
+impl<'g> GridLines<'g> {
+    pub(super) fn new(grid: &'g Grid) -> Self {
+        let scrollback_len = grid.scrollback_len();
+        Self {
+            grid,
+            scrollback_len,
+            total: scrollback_len + grid.rows(),
+        }
+    }
+
+    /// Whether absolute row `row` continues on the next row.
+    fn is_wrapped(&self, row: usize) -> bool {
+        if row < self.scrollback_len {
+            self.grid.is_scrollback_wrapped(row)
+        } else {
+            self.grid.is_line_wrapped(row - self.scrollback_len)
+        }
+    }
+
+    /// First row of the logical line containing `row`.
+    fn logical_start(&self, mut row: usize) -> usize {
+        while row > 0 && self.is_wrapped(row - 1) {
+            row -= 1;
+        }
+        row
+    }
+
+    /// `end` moved forward to the next logical line boundary.
+    pub(super) fn logical_end(&self, mut end: usize) -> usize {
+        while end > 0 && end < self.total && self.is_wrapped(end - 1) {
+            end += 1;
+        }
+        end
+    }
+
+    /// Append the text of absolute row `row`, as copy and export see it.
+    fn push_row_text(&self, row: usize, text: &mut String) {
+        let cells: &[Cell] = if row < self.scrollback_len {
+            self.grid.scrollback_line(row).unwrap_or_default()
+        } else {
+            self.grid.row(row - self.scrollback_len).unwrap_or_default()
+        };
+        let cols = self.grid.cols().min(cells.len());
+        for cell in cells[..cols]
+            .iter()
+            .filter(|c| !c.flags().wide_char_spacer())
+        {
+            cell.push_grapheme(text);
+        }
+    }
+
+    /// Logical lines in `start..end` (on logical line boundaries) as
+    /// trimmed plain text, each with the row it starts on.
+    fn logical_lines(&self, start: usize, end: usize) -> Vec<(usize, String)> {
+        let mut lines = Vec::new();
+        let mut text = String::new();
+        let mut line_start = start;
+        for row in start..end {
+            self.push_row_text(row, &mut text);
+            if !self.is_wrapped(row) || row + 1 == end {
+                text.truncate(text.trim_end().len());
+                lines.push((line_start, std::mem::take(&mut text)));
+                line_start = row + 1;
+            }
+        }
+        lines
+    }
+}
+
+/// Lazy search started by [`TerminalManager::search_incremental`].
+///
+/// Each item is the matches found in the next batch of rows, nearest first;
+/// a batch with no matches yields an empty `Vec`.
+pub struct IncrementalSearch<'a> {
+    manager: &'a TerminalManager,
+    regex: Option<Regex>,
+    /// Word characters for whole-word matching, if enabled.
+    word_characters: Option<String>,
+    direction: SearchDirection,
+    /// Forward: first row of the next batch.  Backward: row just past it.
+    /// `None` before the first batch or once the scan is done.
+    cursor: Option<usize>,
+    /// Line the scan starts from.
+    start_line: usize,
+    started: bool,
+    /// Rows read per batch (extended to the end of a wrapped line).
+    batch_rows: usize,
+}
+
+impl IncrementalSearch<'_> {
+    /// Position `cursor` for the first batch.
+    fn start(&self, lines: &GridLines<'_>) -> Option<usize> {
+        let line = self.start_line.min(lines.total.checked_sub(1)?);
+        Some(match self.direction {
+            // Skip the rest of a logical line that started before `line`
+            SearchDirection::Forward => lines.logical_end(line),
+            // Include the whole logical line containing `line`
+            SearchDirection::Backward => lines.logical_end(line + 1),
+        })
+    }
+}
+
+impl Iterator for IncrementalSearch<'_> {
+    type Item = Vec<SearchMatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let regex = self.regex.as_ref()?;
+        let text = {
+            let pty = self.manager.pty_session.lock();
+            let terminal = pty.terminal();
+            let term = terminal.write();
+            let lines = GridLines::new(term.active_grid());
+            if !self.started {
+                self.started = true;
+                self.cursor = self.start(&lines);
+            }
+            let cursor = self.cursor?.min(lines.total);
+            match self.direction {
+                SearchDirection::Forward => {
+                    if cursor >= lines.total {
+                        self.cursor = None;
+                        return None;
+                    }
+                    let end = lines.logical_end((cursor + self.batch_rows).min(lines.total));
+                    self.cursor = Some(end).filter(|&end| end < lines.total);
+                    lines.logical_lines(cursor, end)
+                }
+                SearchDirection::Backward => {
+                    if cursor == 0 {
+                        self.cursor = None;
+                        return None;
+                    }
+                    let start = lines.logical_start(cursor.saturating_sub(self.batch_rows));
+                    self.cursor = Some(start).filter(|&start| start > 0);
+                    lines.logical_lines(start, cursor)
+                }
+            }
+        };
+
+        let mut matches: Vec<SearchMatch> = text
+            .iter()
+            .flat_map(|(line, text)| {
+                find_in_line(regex, self.word_characters.as_deref(), *line, text)
+            })
+            .collect();
+        if self.direction == SearchDirection::Backward {
+            matches.reverse();
+        }
+        Some(matches)
+    }
+}
+
+impl TerminalManager {
+    /// Search the visible screen for `query` as described by `options`.
+    ///
+    /// Like [`search_with_options`](Self::search_with_options), but only
+    /// logical lines starting on screen are searched; match lines are still
+    /// absolute.  Errors when `query` is not a valid regular expression.
+    pub fn search(&self, query: &str, options: SearchOptions) -> Result<Vec<SearchMatch>> {
+        let screen_start = {
+            let pty = self.pty_session.lock();
+            let terminal = pty.terminal();
+            let term = terminal.read();
+            term.active_grid().scrollback_len()
+        };
+        let search =
+            self.search_incremental(query, options, screen_start, SearchDirection::Forward)?;
+        Ok(search.flatten().collect())
+    }
+
+    /// Search scrollback and screen for `query` as described by `options`.
+    ///
+    /// Returns matches in buffer order; an empty query matches nothing.
+    /// Errors when `query` is not a valid regular expression.
+    pub fn search_with_options(
+        &self,
+        query: &str,
+        options: SearchOptions,
+    ) -> Result<Vec<SearchMatch>> {
+        let search = self.search_incremental(query, options, 0, SearchDirection::Forward)?;
+        Ok(search.flatten().collect())
+    }
+
+    /// Search lazily from absolute line `start_line` in `direction`.
+    ///
+    /// Forward, the scan covers the logical lines starting at or after
+    /// `start_line`; backward, those starting at or before it.  A
+    /// `start_line` past the end is clamped to the last line.  Nothing is
+    /// read until the first batch is requested.  Errors when `query` is not
+    /// a valid regular expression.
+    pub fn search_incremental(
+        &self,
+        query: &str,
+        options: SearchOptions,
+        start_line: usize,
+        direction: SearchDirection,
+    ) -> Result<IncrementalSearch<'_>> {
+        let regex = if query.is_empty() {
+            None
+        } else {
+            Some(options.build_regex(query)?)
+        };
+        Ok(IncrementalSearch {
+            manager: self,
+            regex,
+            word_characters: options.word_boundary(),
+            direction,
+            cursor: None,
+            start_line,
+            started: false,
+            batch_rows: SEARCH_BATCH_ROWS,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn regex(query: &str, options: &SearchOptions) -> Regex {
+        options.build_regex(query).unwrap()
+    }
+
+    const REGEX: SearchOptions = SearchOptions {
+        regex: true,
+        case_sensitive: true,
+        whole_word: false,
+        word_characters: String::new(),
+    };
+
+    #[test]
+    fn digits_are_located_in_grapheme_columns() {
+        let re = regex(r"\d+", &REGEX);
+        // Accented and wide characters are one column each
+        assert_eq!(
+            find_in_line(&re, None, 7, "héllo 日本 42 x 7"),
+            vec![SearchMatch::new(7, 9, 2), SearchMatch::new(7, 14, 1)]
+        );
+
+        let manager = TerminalManager::new(20, 4).unwrap();
+        manager.process_data(b"build 12\r\nno digits\r\nexit 0");
+        assert_eq!(
+            manager.search_with_options(r"\d+", REGEX).unwrap(),
+            vec![SearchMatch::new(0, 6, 2), SearchMatch::new(2, 5, 1)]
+        );
+    }
+
+    #[test]
+    fn case_and_whole_word_options() {
+        let text = "Error: errors and ERROR";
+        let literal = SearchOptions::default();
+        assert_eq!(
+            find_in_line(&regex("error", &literal), None, 0, text),
+            vec![
+                SearchMatch::new(0, 0, 5),
+                SearchMatch::new(0, 7, 5),
+                SearchMatch::new(0, 18, 5)
+            ]
+        );
+        let exact = SearchOptions {
+            case_sensitive: true,
+            ..literal.clone()
+        };
+        assert_eq!(
+            find_in_line(&regex("error", &exact), None, 0, text),
+            vec![SearchMatch::new(0, 7, 5)]
+        );
+        let word = SearchOptions {
+            whole_word: true,
+            ..literal.clone()
+        };
+        assert_eq!(
+            find_in_line(&regex("error", &word), Some(""), 0, text).len(),
+            2
+        );
+
+        // Literal mode escapes regex syntax
+        assert_eq!(
+            find_in_line(&regex("a.c", &literal), None, 0, "abc a.c"),
+            vec![SearchMatch::new(0, 4, 3)]
+        );
+    }
+
+    #[test]
+    fn whole_words_are_bounded_by_selection_word_characters() {
+        let word = SearchOptions {
+            whole_word: true,
+            ..SearchOptions::default()
+        };
+        let find = |query: &str, options: &SearchOptions, text: &str| {
+            find_in_line(
+                &regex(query, options),
+                options.word_boundary().as_deref(),
+                0,
+                text,
+            )
+        };
+
+        // Punctuation outside word_characters ends a word; the match still
+        // covers just the query
+        assert_eq!(
+            find("log", &word, "login (log) log: log, catalog"),
+            vec![
+                SearchMatch::new(0, 7, 3),
+                SearchMatch::new(0, 12, 3),
+                SearchMatch::new(0, 17, 3)
+            ]
+        );
+        // '.' and '-' are word characters by default, as for double-click
+        assert!(find("log", &word, "app.log log-in").is_empty());
+        let dotless = SearchOptions {
+            word_characters: "_".to_string(),
+            ..word.clone()
+        };
+        assert_eq!(
+            find("log", &dotless, "app.log log-in"),
+            vec![SearchMatch::new(0, 4, 3), SearchMatch::new(0, 8, 3)]
+        );
+        // A rejected hit does not hide an overlapping whole word
+        assert_eq!(find("ab", &word, "aab ab"), vec![SearchMatch::new(0, 4, 2)]);
+
+        // CJK runs have no spaces: ideographs are alphanumeric, so only
+        // punctuation or spaces separate words, unlike a plain substring hit
+        let text = "日本語 「日本」、日本。";
+        assert_eq!(find("日本", &SearchOptions::default(), text).len(), 3);
+        assert_eq!(
+            find("日本", &word, text),
+            vec![SearchMatch::new(0, 5, 2), SearchMatch::new(0, 9, 2)]
+        );
+
+        // Through the manager, `search` covers only the visible screen
+        let manager = TerminalManager::new(20, 2).unwrap();
+        manager.process_data(b"log in\r\nlogin\r\nthe log");
+        assert_eq!(
+            manager.search_with_options("log", word.clone()).unwrap(),
+            vec![SearchMatch::new(0, 0, 3), SearchMatch::new(2, 4, 3)]
+        );
+        assert_eq!(
+            manager.search("log", word).unwrap(),
+            vec![SearchMatch::new(2, 4, 3)]
+        );
+    }
+
+    #[test]
+    fn zero_width_matches_are_skipped() {
+        assert!(find_in_line(&regex(r"\b", &REGEX), None, 0, "two words").is_empty());
+        assert_eq!(
+            find_in_line(&regex("x*", &REGEX), None, 0, "axxb"),
+            vec![SearchMatch::new(0, 1, 2)]
+        );
+        assert!(SearchOptions::default().build_regex("(").is_ok());
+        assert!(REGEX.build_regex("(").is_err());
+    }
+
+    #[test]
+    fn anchors_apply_to_soft_wrapped_logical_lines() {
+        let manager = TerminalManager::new(10, 4).unwrap();
+        // 14 characters wrap onto a second row
+        manager.process_data(b"abcdefghij1234\r\nnext");
+        assert_eq!(
+            manager.search_with_options(r"j1\d+$", REGEX).unwrap(),
+            vec![SearchMatch::new(0, 9, 5)]
+        );
+        assert_eq!(
+            manager.search_with_options("^next", REGEX).unwrap(),
+            vec![SearchMatch::new(2, 0, 4)]
+        );
+    }
+
+    /// Ten lines `hit 0`..`hit 9`, the first six in scrollback.
+    fn ten_lines() -> TerminalManager {
+        let manager = TerminalManager::new(20, 4).unwrap();
+        let text: Vec<String> = (0..10).map(|i| format!("hit {i}")).collect();
+        manager.process_data(text.join("\r\n").as_bytes());
+        manager
+    }
+
+    fn lines_of(batches: Vec<Vec<SearchMatch>>) -> Vec<Vec<usize>> {
+        batches
+            .iter()
+            .map(|batch| batch.iter().map(|m| m.line).collect())
+            .collect()
+    }
+
+    #[test]
+    fn incremental_search_scans_outward_in_batches() {
+        let manager = ten_lines();
+        let search = |start, direction| {
+            let mut search = manager
+                .search_incremental("hit", SearchOptions::default(), start, direction)
+                .unwrap();
+            search.batch_rows = 3;
+            lines_of(search.collect())
+        };
+
+        assert_eq!(
+            search(5, SearchDirection::Forward),
+            vec![vec![5, 6, 7], vec![8, 9]]
+        );
+        assert_eq!(
+            search(5, SearchDirection::Backward),
+            vec![vec![5, 4, 3], vec![2, 1, 0]]
+        );
+        // A start past the end is clamped to the last line
+        assert_eq!(search(99, SearchDirection::Backward)[0], vec![9, 8, 7]);
+        assert_eq!(search(99, SearchDirection::Forward), vec![vec![9]]);
+
+        // Stopping early leaves the rest unread
+        let mut search = manager
+            .search_incremental("hit", SearchOptions::default(), 0, SearchDirection::Forward)
+            .unwrap();
+        search.batch_rows = 3;
+        assert_eq!(search.next().unwrap().len(), 3);
+        assert_eq!(search.cursor, Some(3));
+
+        assert_eq!(
+            manager
+                .search_incremental("", SearchOptions::default(), 0, SearchDirection::Forward)
+                .unwrap()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn incremental_batches_keep_wrapped_lines_whole() {
+        let manager = TerminalManager::new(10, 6).unwrap();
+        // Line 0 wraps over rows 0-2; "needle" straddles the first wrap
+        manager.process_data(b"abcdneedle0123456789x\r\nneedle");
+        let search = |start, direction| {
+            let mut search = manager
+                .search_incremental("needle", SearchOptions::default(), start, direction)
+                .unwrap();
+            search.batch_rows = 1;
+            search.flatten().collect::<Vec<_>>()
+        };
+
+        let all = vec![SearchMatch::new(0, 4, 6), SearchMatch::new(3, 0, 6)];
+        assert_eq!(search(0, SearchDirection::Forward), all);
+        // Starting inside the wrapped line: forward skips it, backward
+        // includes it
+        assert_eq!(search(1, SearchDirection::Forward), all[1..]);
+        assert_eq!(search(1, SearchDirection::Backward), all[..1]);
+        assert_eq!(
+            manager
+                .search_with_options("needle", SearchOptions::default())
+                .unwrap(),
+            all
+        );
+    }
+
+    #[test]
+    fn searched_text_matches_copied_text() {
+        let manager = TerminalManager::new(20, 4).unwrap();
+        // Wide characters and trailing blanks with a background color
+        manager.process_data("日本 ok\x1b[41m   \x1b[0m\r\nlast".as_bytes());
+        assert_eq!(manager.read_text(0).unwrap(), "日本 ok\nlast");
+        assert_eq!(
+            manager.search_with_options(r"ok$", REGEX).unwrap(),
+            vec![SearchMatch::new(0, 3, 2)]
+        );
+    }
+}
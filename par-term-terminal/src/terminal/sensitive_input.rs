@@ -0,0 +1,357 @@
+//! Sensitive input (password prompt) detection.
+//!
+//! When the text before the cursor looks like a password prompt
+//! (`Password:`, `[sudo] password for`, `Enter passphrase`, ...), the
+//! terminal enters sensitive input mode: the next command reported by shell
+//! integration is not added to the command history (and its mark gets no
+//! command text), and with masking enabled any characters echoed after the
+//! prompt are drawn as bullets.  The mode ends after that command, or at the
+//! next shell prompt.
+//!
+//! While the user is editing a shell command line (after the OSC 133 B
+//! marker) only the prompt part of the line is examined, so typing
+//! `echo password:` does not trigger it, but a shell-integrated prompt that
+//! itself asks for a password does.
+
+use super::TerminalManager;
+use super::marker_tracking::MarkerTracker;
+use par_term_config::Cell;
+use par_term_emu_core_rust::shell_integration::ShellIntegrationMarker;
+use par_term_emu_core_rust::terminal::Terminal;
+
+/// Common password prompt patterns (case-insensitive matching).
+///
+/// These patterns are matched against the text of the cursor line, up to the
+/// cursor (or up to the OSC 133 B position while a command line is being
+/// edited), to detect when the user is being asked for a password.
+///
+/// SEC-009: Includes patterns for common non-English password prompts to reduce
+/// redaction gaps for internationalized systems and multilingual users.
+pub const PASSWORD_PROMPT_PATTERNS: &[&str] = &[
+    "password:",
+    "password for",
+    "passwd:",
+    "[sudo]",
+    "passphrase:",
+    "passphrase for",
+    "enter pin",
+    "enter passphrase",
+    "enter password",
+    "old password:",
+    "new password:",
+    "retype password:",
+    "confirm password:",
+    "current password:",
+    "verification code:",
+    "login password:",
+    "ldap password:",
+    "key password:",
+    "decryption password:",
+    "encryption password:",
+    "(current) unix password:",
+    "token:",
+    // Two-factor / MFA prompts
+    "authenticator code:",
+    "2fa code:",
+    "otp:",
+    "one-time password:",
+    "one time password:",
+    "security code:",
+    "totp:",
+    // API key / secret prompts (interactive tools that prompt for credentials)
+    "api key:",
+    "api secret:",
+    "secret key:",
+    "access key:",
+    "access token:",
+    "secret token:",
+    "private key:",
+    "client secret:",
+    "auth token:",
+    "bearer token:",
+    // SSH / GPG passphrases
+    "enter passphrase for key",
+    "key passphrase:",
+    "gpg passphrase:",
+    // Database / service credential prompts
+    "db password:",
+    "database password:",
+    "mysql password:",
+    "postgres password:",
+    "redis password:",
+    // Cloud / vault prompts
+    "vault token:",
+    "vault password:",
+    "aws secret",
+    "azure secret",
+    // SEC-009: Non-English password prompt patterns.
+    // Covers Portuguese, Spanish, French, Russian, German, Japanese, Korean,
+    // Chinese, Italian, Dutch, Polish, Turkish, Hindi, Arabic, and Hebrew.
+    // All matched case-insensitively.
+    // Portuguese
+    "senha:",
+    "digite a senha",
+    "informe a senha",
+    "senha atual:",
+    "nova senha:",
+    "confirme a senha",
+    // Spanish
+    "contrase\u{00f1}a:", // contraseña:
+    "contrasena:",        // contrasena: (ASCII fallback)
+    "introduzca la contrase\u{00f1}a",
+    "contrase\u{00f1}a actual:",
+    "nueva contrase\u{00f1}a:",
+    "confirme la contrase\u{00f1}a",
+    // French
+    "mot de passe:",
+    "entrez le mot de passe",
+    "mot de passe actuel:",
+    "nouveau mot de passe:",
+    "confirmez le mot de passe",
+    // Russian
+    "\u{043f}\u{0430}\u{0440}\u{043e}\u{043b}\u{044c}:", // пароль:
+    "\u{0432}\u{0432}\u{0435}\u{0434}\u{0438}\u{0442}\u{0435} \u{043f}\u{0430}\u{0440}\u{043e}\u{043b}\u{044c}", // введите пароль
+    "\u{043d}\u{043e}\u{0432}\u{044b}\u{0439} \u{043f}\u{0430}\u{0440}\u{043e}\u{043b}\u{044c}:", // новый пароль:
+    // German
+    "passwort:",
+    "geben sie das passwort",
+    "passwort eingeben",
+    "neues passwort:",
+    "passwort best\u{00e4}tigen", // passwort bestätigen
+    // Japanese
+    "\u{30d1}\u{30b9}\u{30ef}\u{30fc}\u{30c9}:", // パスワード:
+    "\u{30d1}\u{30b9}\u{30ef}\u{30fc}\u{30c9}\u{5165}\u{529b}", // パスワード入力
+    // Korean
+    "암호:",     // 암호:
+    "비밀번호:", // 비밀번호:
+    // Chinese (Simplified)
+    "密码:",      // 密码:
+    "请输入密码", // 请输入密码
+    // Italian
+    "password:", // (already covered by English "password:" — case-insensitive)
+    "inserire la password",
+    "nuova password:",
+    // Dutch
+    "wachtwoord:",
+    "voer het wachtwoord",
+    "nieuw wachtwoord:",
+    // Polish
+    "has\u{0142}o:",         // hasło:
+    "wprowadź has\u{0142}o", // wprowadź hasło
+    "nowe has\u{0142}o:",    // nowe hasło:
+    // Turkish
+    "ş}ifre:", // şifre: (note: also covered by lowercase match of "Şifre:")
+    "parola:",
+    // Hindi
+    "प}ासवर्द}:", // पासवर्ड: (Hindi often uses English loanword)
+    // Arabic
+    "ك}لمة ا}لمرور}:", // كلمة المرور:
+    // Hebrew
+    "ס}יסמא}:", // סיסמא:
+];
+
+/// Character drawn in place of masked input.
+const MASK: &str = "\u{2022}";
+
+/// Whether `text` (the line up to the cursor) ends in a password prompt.
+pub fn is_password_prompt(text: &str) -> bool {
+    let line = text.trim().to_lowercase();
+    !line.is_empty()
+        && PASSWORD_PROMPT_PATTERNS
+            .iter()
+            .any(|pattern| line.contains(pattern))
+}
+
+/// Sensitive input mode of one terminal.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SensitiveInput {
+    /// Detect password prompts at all
+    pub(crate) detect: bool,
+    /// Draw echoed input after the prompt as bullets
+    pub(crate) mask: bool,
+    /// End of the detected prompt: `(absolute_line, col)`
+    pub(crate) prompt: Option<(usize, usize)>,
+}
+
+impl SensitiveInput {
+    /// Re-check the cursor line for a password prompt.
+    ///
+    /// Without shell integration there is no marker to end the mode, so it
+    /// ends as soon as the cursor leaves the prompt line.
+    pub(crate) fn detect(
+        &mut self,
+        term: &Terminal,
+        scrollback_len: usize,
+        markers: &MarkerTracker,
+    ) {
+        if !self.detect {
+            self.prompt = None;
+            return;
+        }
+        let grid = term.active_grid();
+        let cursor = term.cursor();
+        let abs_line = scrollback_len + cursor.row;
+        match self.prompt {
+            Some((line, _)) if line == abs_line => return,
+            Some(_) if markers.last_shell_marker.is_none() => self.prompt = None,
+            _ => {}
+        }
+        // While a command line is being edited, only the prompt before the
+        // OSC 133 B position counts
+        let end = match (markers.last_shell_marker, markers.command_start_pos) {
+            (Some(ShellIntegrationMarker::CommandStart), Some((line, col))) if line == abs_line => {
+                col.min(cursor.col)
+            }
+            _ => cursor.col,
+        };
+        let text: String = grid.row_text(cursor.row).chars().take(end).collect();
+        if is_password_prompt(&text) {
+            log::debug!("Password prompt detected on line {abs_line}; sensitive input on");
+            self.prompt = Some((abs_line, cursor.col));
+        }
+    }
+
+    /// Apply a shell integration marker; returns `true` when the command it
+    /// starts is sensitive input and must not be recorded.
+    pub(crate) fn on_marker(&mut self, marker: Option<ShellIntegrationMarker>) -> bool {
+        match marker {
+            Some(ShellIntegrationMarker::CommandExecuted) => self.prompt.take().is_some(),
+            Some(ShellIntegrationMarker::PromptStart) => {
+                self.prompt = None;
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Replace echoed input after the prompt with bullets.
+    ///
+    /// `cells` holds the rows from absolute line `start_line` onward and
+    /// `cursor_line` is the cursor's absolute line; nothing is masked once
+    /// the cursor has left the prompt line.
+    pub(crate) fn mask_cells(
+        &self,
+        cells: &mut [Cell],
+        cols: usize,
+        start_line: usize,
+        cursor_line: usize,
+    ) {
+        let Some((line, col)) = self.prompt.filter(|_| self.mask) else {
+            return;
+        };
+        if line != cursor_line || line < start_line || cols == 0 {
+            return;
+        }
+        let Some(row) = cells.chunks_mut(cols).nth(line - start_line) else {
+            return;
+        };
+        for cell in row.iter_mut().skip(col) {
+            if !cell.wide_char_spacer && !cell.grapheme.trim().is_empty() {
+                cell.grapheme = MASK.to_string();
+                cell.wide_char = false;
+            }
+        }
+    }
+}
+
+impl TerminalManager {
+    /// Configure password prompt detection and input masking.
+    pub fn set_sensitive_input_detection(&mut self, detect: bool, mask: bool) {
+        self.sensitive_input.detect = detect;
+        self.sensitive_input.mask = mask;
+        if !detect {
+            self.sensitive_input.prompt = None;
+        }
+    }
+
+    /// Whether a password prompt is waiting for input.
+    pub fn is_sensitive_input_active(&self) -> bool {
+        self.sensitive_input.prompt.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Feed output and run the per-frame metadata update (markers are
+    /// resolved against the cursor at update time, so each frame's output
+    /// is fed separately).
+    fn feed(manager: &mut TerminalManager, data: &[u8]) {
+        manager.process_data(data);
+        let (_, row) = manager.cursor_position();
+        manager.update_scrollback_metadata(manager.scrollback_len(), row);
+    }
+
+    fn manager(detect: bool, mask: bool) -> TerminalManager {
+        let mut manager = TerminalManager::new(40, 10).unwrap();
+        manager.set_sensitive_input_detection(detect, mask);
+        manager
+    }
+
+    fn history(manager: &TerminalManager) -> Vec<String> {
+        manager
+            .core_command_history()
+            .into_iter()
+            .map(|(command, _, _)| command)
+            .collect()
+    }
+
+    #[test]
+    fn detected_prompt_suppresses_history_for_following_input() {
+        let mut manager = manager(true, false);
+        feed(&mut manager, b"\x1b]133;A\x07Password: \x1b]133;B\x07");
+        assert!(manager.is_sensitive_input_active());
+
+        feed(&mut manager, b"hunter2\r\n\x1b]133;C\x07");
+        feed(&mut manager, b"\x1b]133;D;0\x07");
+        assert!(!manager.is_sensitive_input_active());
+        assert!(history(&manager).is_empty());
+        assert!(
+            manager
+                .scrollback_marks()
+                .iter()
+                .all(|mark| mark.command.as_deref() != Some("hunter2"))
+        );
+
+        // The next ordinary command is recorded again
+        feed(&mut manager, b"\x1b]133;A\x07$ \x1b]133;B\x07");
+        feed(&mut manager, b"ls\r\n\x1b]133;C\x07");
+        feed(&mut manager, b"\x1b]133;D;0\x07");
+        assert_eq!(history(&manager), vec!["ls".to_string()]);
+    }
+
+    #[test]
+    fn typed_command_text_and_disabled_detection_do_not_trigger() {
+        let mut manager = manager(true, false);
+        feed(&mut manager, b"\x1b]133;A\x07$ \x1b]133;B\x07");
+        feed(&mut manager, b"echo password:");
+        assert!(!manager.is_sensitive_input_active());
+
+        let mut manager = self::manager(false, false);
+        feed(&mut manager, b"[sudo] password for alice: ");
+        assert!(!manager.is_sensitive_input_active());
+
+        assert!(is_password_prompt(
+            "Enter passphrase for key '/home/a/.ssh/id_ed25519': "
+        ));
+        assert!(!is_password_prompt("$ "));
+    }
+
+    #[test]
+    fn echoed_input_is_masked_until_the_line_is_submitted() {
+        let mut manager = manager(true, true);
+        feed(&mut manager, b"Password: ");
+        feed(&mut manager, b"abc");
+        let cells = manager.get_cells_with_scrollback(0, None, false, None);
+        let row: String = cells[..13].iter().map(|c| c.grapheme.as_str()).collect();
+        assert_eq!(row, "Password: \u{2022}\u{2022}\u{2022}");
+
+        // Without shell integration the mode ends when the cursor moves on
+        feed(&mut manager, b"\r\n");
+        assert!(!manager.is_sensitive_input_active());
+        let cells = manager.get_cells_with_scrollback(0, None, false, None);
+        let row: String = cells[..13].iter().map(|c| c.grapheme.as_str()).collect();
+        assert_eq!(row, "Password: abc");
+    }
+}
@@ -0,0 +1,310 @@
+//! Coalescing of redundant SGR resets.
+//!
+//! Some programs emit `CSI 0 m` before every cell or word even when nothing
+//! has changed the attributes since the last reset.  Each one costs the
+//! emulator a full attribute reset.  [`SgrCoalescer`] drops a reset when the
+//! previous escape sequence it forwarded was also a reset and only text and
+//! C0 controls came in between, so the attributes are already at their
+//! defaults and the grid comes out identical.
+//!
+//! Any other escape sequence (a color change, `DECRC`, a mode switch, an
+//! OSC string) makes the attribute state unknown, and the next reset is
+//! forwarded again.  The filter runs on PTY output and on tmux pane data
+//! (see [`super::output_filters`]); when coalescing is off it is skipped
+//! altogether.
+
+use super::TerminalManager;
+
+const ESC: u8 = 0x1b;
+const BEL: u8 = 0x07;
+const CAN: u8 = 0x18;
+const SUB: u8 = 0x1a;
+
+/// Longest CSI sequence buffered for inspection; longer ones pass through.
+const MAX_CSI_LEN: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Ground,
+    /// After ESC (held until the next byte)
+    Escape,
+    /// Inside `ESC [` … final byte, buffered
+    Csi,
+    /// Inside an over-long CSI sequence, passed through
+    CsiPassthrough,
+    /// Inside an OSC/DCS/APC/PM/SOS string
+    String,
+}
+
+/// SGR sequences seen by a [`SgrCoalescer`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SgrCoalesceStats {
+    /// SGR sequences forwarded to the emulator (attribute mutations)
+    pub applied: u64,
+    /// Redundant resets dropped
+    pub dropped: u64,
+}
+
+/// Streaming filter that drops redundant SGR resets.
+#[derive(Debug)]
+pub struct SgrCoalescer {
+    enabled: bool,
+    state: State,
+    /// Buffered CSI sequence, starting with `ESC [`
+    csi: Vec<u8>,
+    /// The last escape sequence forwarded was an SGR reset
+    attrs_reset: bool,
+    stats: SgrCoalesceStats,
+}
+
+impl Default for SgrCoalescer {
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+impl SgrCoalescer {
+    /// Create a filter; when not `enabled` it passes everything through.
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            state: State::Ground,
+            csi: Vec::new(),
+            attrs_reset: false,
+            stats: SgrCoalesceStats::default(),
+        }
+    }
+
+    /// Whether redundant resets are dropped.
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// SGR sequences forwarded and dropped while enabled.
+    pub fn stats(&self) -> SgrCoalesceStats {
+        self.stats
+    }
+
+    /// Drop the redundant resets in `input`.
+    ///
+    /// An incomplete escape sequence at the end of `input` is held until the
+    /// next call.
+    pub fn filter(&mut self, input: &[u8]) -> Vec<u8> {
+        if !self.enabled {
+            return input.to_vec();
+        }
+        let mut out = Vec::with_capacity(input.len());
+        for &byte in input {
+            self.push(byte, &mut out);
+        }
+        out
+    }
+
+    fn push(&mut self, byte: u8, out: &mut Vec<u8>) {
+        match self.state {
+            State::Ground => {
+                if byte == ESC {
+                    self.state = State::Escape;
+                    return;
+                }
+            }
+            State::Escape => {
+                match byte {
+                    b'[' => {
+                        self.csi.clear();
+                        self.csi.extend_from_slice(&[ESC, b'[']);
+                        self.state = State::Csi;
+                        return;
+                    }
+                    ESC => {
+                        self.attrs_reset = false;
+                        out.push(ESC);
+                        return;
+                    }
+                    b']' | b'P' | b'_' | b'^' | b'X' => self.state = State::String,
+                    _ => self.state = State::Ground,
+                }
+                self.attrs_reset = false;
+                out.push(ESC);
+            }
+            State::Csi => {
+                match byte {
+                    0x40..=0x7e => {
+                        self.csi.push(byte);
+                        self.finish_csi(out);
+                    }
+                    ESC => {
+                        // Sequence aborted
+                        self.flush_csi(out);
+                        self.state = State::Escape;
+                    }
+                    CAN | SUB => {
+                        self.flush_csi(out);
+                        out.push(byte);
+                        self.state = State::Ground;
+                    }
+                    _ if self.csi.len() >= MAX_CSI_LEN => {
+                        self.flush_csi(out);
+                        out.push(byte);
+                        self.state = State::CsiPassthrough;
+                    }
+                    _ => self.csi.push(byte),
+                }
+                return;
+            }
+            State::CsiPassthrough => match byte {
+                ESC => {
+                    self.state = State::Escape;
+                    return;
+                }
+                CAN | SUB | 0x40..=0x7e => self.state = State::Ground,
+                _ => {}
+            },
+            State::String => match byte {
+                // ST, or the start of a sequence that ends the string
+                ESC => {
+                    self.state = State::Escape;
+                    return;
+                }
+                BEL | CAN | SUB => self.state = State::Ground,
+                _ => {}
+            },
+        }
+        out.push(byte);
+    }
+
+    /// Forward a buffered CSI sequence that did not complete normally.
+    fn flush_csi(&mut self, out: &mut Vec<u8>) {
+        self.attrs_reset = false;
+        out.extend_from_slice(&self.csi);
+        self.csi.clear();
+    }
+
+    /// Forward or drop the completed CSI sequence in the buffer.
+    fn finish_csi(&mut self, out: &mut Vec<u8>) {
+        self.state = State::Ground;
+        let is_sgr = self.csi.last() == Some(&b'm')
+            && self.csi[2..self.csi.len() - 1]
+                .iter()
+                .all(|b| matches!(b, b'0'..=b'9' | b';' | b':'));
+        if !is_sgr {
+            self.flush_csi(out);
+            return;
+        }
+        let is_reset = self.csi[2..self.csi.len() - 1]
+            .iter()
+            .all(|b| matches!(b, b'0' | b';'));
+        if is_reset && self.attrs_reset {
+            self.stats.dropped += 1;
+            self.csi.clear();
+            return;
+        }
+        self.stats.applied += 1;
+        out.extend_from_slice(&self.csi);
+        self.csi.clear();
+        self.attrs_reset = is_reset;
+    }
+}
+
+impl TerminalManager {
+    /// Drop SGR resets that repeat an earlier reset in terminal output.
+    pub fn set_coalesce_sgr_resets(&mut self, enabled: bool) {
+        *self.output_filters.sgr_coalescer.lock() = SgrCoalescer::new(enabled);
+    }
+
+    /// Whether redundant SGR resets are dropped.
+    pub fn coalesce_sgr_resets(&self) -> bool {
+        self.output_filters.sgr_coalescer.lock().enabled()
+    }
+
+    /// SGR sequences applied and dropped since coalescing was turned on.
+    pub fn sgr_coalesce_stats(&self) -> SgrCoalesceStats {
+        self.output_filters.sgr_coalescer.lock().stats()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Feed `data` in `chunk`-byte reads and return the grid and stats.
+    fn run(enabled: bool, data: &[u8], chunk: usize) -> (String, Vec<String>, SgrCoalesceStats) {
+        let mut manager = TerminalManager::new(20, 6).unwrap();
+        manager.set_coalesce_sgr_resets(enabled);
+        for piece in data.chunks(chunk) {
+            manager.process_data(piece);
+        }
+        let content = manager.terminal().read().content();
+        let cells = manager
+            .get_cells_with_scrollback(0, None, false, None)
+            .iter()
+            .map(|cell| format!("{cell:?}"))
+            .collect();
+        (content, cells, manager.sgr_coalesce_stats())
+    }
+
+    #[test]
+    fn redundant_resets_give_same_grid_with_fewer_mutations() {
+        let mut data = Vec::new();
+        data.extend_from_slice(b"\x1b[1;31mred\x1b[0m ");
+        for i in 0..200 {
+            data.extend_from_slice(if i % 2 == 0 { b"\x1b[0m" } else { b"\x1b[m" });
+            data.push(b'a' + (i % 26) as u8);
+            if i % 20 == 19 {
+                data.extend_from_slice(b"\r\n");
+            }
+        }
+        data.extend_from_slice(b"\x1b[4mu\x1b[0;0mv");
+
+        for chunk in [1, 7, data.len()] {
+            let (plain, plain_cells, plain_stats) = run(false, &data, chunk);
+            let (fast, fast_cells, fast_stats) = run(true, &data, chunk);
+            assert_eq!(plain, fast);
+            assert_eq!(plain_cells, fast_cells);
+
+            // Nothing is scanned while off
+            assert_eq!(plain_stats, SgrCoalesceStats::default());
+            // Bold/red, its reset, underline and its reset; every reset in
+            // the loop follows another reset
+            assert_eq!(fast_stats.applied, 4);
+            assert_eq!(fast_stats.dropped, 200);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn pty_output_is_coalesced() {
+        let mut manager = TerminalManager::new(20, 4).unwrap();
+        manager.set_coalesce_sgr_resets(true);
+        crate::terminal::spawn::tests::run_script(
+            &mut manager,
+            r"printf '\033[0ma\033[0mb\033[mc'",
+        );
+        assert!(manager.terminal().read().content().starts_with("abc"));
+        assert_eq!(
+            manager.sgr_coalesce_stats(),
+            SgrCoalesceStats {
+                applied: 1,
+                dropped: 2
+            }
+        );
+    }
+
+    #[test]
+    fn reset_after_other_sequences_is_kept() {
+        let mut filter = SgrCoalescer::new(true);
+        assert_eq!(filter.filter(b"\x1b[0mA\x1b[0mB"), b"\x1b[0mAB");
+        // A restore cursor may bring back saved attributes
+        assert_eq!(filter.filter(b"\x1b8\x1b[0m"), b"\x1b8\x1b[0m");
+        // Cursor movement leaves attributes alone but is not special-cased
+        assert_eq!(filter.filter(b"\x1b[H\x1b[m"), b"\x1b[H\x1b[m");
+        // So are strings, and a reset split across reads is still seen
+        assert_eq!(
+            filter.filter(b"\x1b]0;title\x07\x1b[0"),
+            b"\x1b]0;title\x07"
+        );
+        assert_eq!(filter.filter(b"m\x1b"), b"\x1b[0m");
+        assert_eq!(filter.filter(b"[0;0m"), b"");
+        assert_eq!(filter.stats().dropped, 2);
+    }
+}
@@ -63,6 +63,7 @@ impl TerminalManager {
     /// Spawn a shell in the terminal
     pub fn spawn_shell(&mut self) -> Result<()> {
         log::info!("Spawning shell in PTY");
+        self.exit_code.take();
         let mut pty = self.pty_session.lock();
         pty.spawn_shell()
             .map_err(|e| anyhow::anyhow!("Failed to spawn shell: {}", e))?;
@@ -72,6 +73,7 @@ impl TerminalManager {
     /// Spawn a custom shell command in the terminal
     pub fn spawn_custom_shell(&mut self, command: &str) -> Result<()> {
         log::info!("Spawning custom shell: {}", command);
+        self.exit_code.take();
         let mut pty = self.pty_session.lock();
         let args: Vec<&str> = Vec::new();
         pty.spawn(command, &args)
@@ -82,6 +84,7 @@ impl TerminalManager {
     /// Spawn a custom shell with arguments
     pub fn spawn_custom_shell_with_args(&mut self, command: &str, args: &[String]) -> Result<()> {
         log::info!("Spawning custom shell: {} with args: {:?}", command, args);
+        self.exit_code.take();
         let mut pty = self.pty_session.lock();
         let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
         pty.spawn(command, &args_refs)
@@ -100,6 +103,7 @@ impl TerminalManager {
             working_dir,
             env_vars
         );
+        self.exit_code.take();
         let mut pty = self.pty_session.lock();
         pty.spawn_shell_with_env(env_vars, working_dir)
             .map_err(|e| anyhow::anyhow!("Failed to spawn shell with env: {}", e))
@@ -124,6 +128,7 @@ impl TerminalManager {
             .map(|a| a.iter().map(|s| s.as_str()).collect())
             .unwrap_or_default();
 
+        self.exit_code.take();
         let mut pty = self.pty_session.lock();
         pty.spawn_with_env(command, &args_refs, env_vars, working_dir)
             .map_err(|e| anyhow::anyhow!("Failed to spawn custom shell with env: {}", e))
@@ -159,11 +164,58 @@ impl TerminalManager {
     }
 
     /// Process raw data through the terminal emulator (for tmux output routing).
+    ///
+    /// The data is counted in [`TerminalManager::metrics`] as received.  Data
+    /// from a legacy host is first decoded to UTF-8 when an encoding is set
+    /// via [`TerminalManager::set_input_encoding`].  tmux DCS passthrough
+    /// sequences are unwrapped next when enabled via
+    /// [`TerminalManager::set_unwrap_tmux_passthrough`].  Media copy (printer)
+    /// data is then split out and routed per
+    /// [`TerminalManager::set_printer_output`].  Form feeds are rewritten next,
+    /// per [`TerminalManager::set_form_feed`], then redundant SGR resets are
+    /// dropped, per [`TerminalManager::set_coalesce_sgr_resets`], and
+    /// over-long lines are broken, per [`TerminalManager::set_max_line_length`].
+    /// Repeated lines are then collapsed, per
+    /// [`TerminalManager::set_collapse_repeated_lines`], and DECRQM mode
+    /// queries are answered last, per
+    /// [`TerminalManager::set_answer_mode_requests`].
     pub fn process_data(&self, data: &[u8]) {
-        let pty = self.pty_session.lock();
-        let terminal = pty.terminal();
-        let mut term = terminal.write();
-        term.process(data);
+        self.output_counters.record(data);
+        let data = self.output_filters.decode(data);
+        let filtered;
+        let data = if self.unwrap_tmux_passthrough {
+            filtered = self.tmux_passthrough.lock().filter(&data);
+            filtered.as_slice()
+        } else {
+            &data[..]
+        };
+        let display = self.output_filters.capture_printer_data(data);
+        let display = self.output_filters.reassemble_osc52(&display);
+        let display = self.output_filters.rewrite_form_feeds(&display);
+        let merged;
+        let display = {
+            let mut continuation = self.prompt_continuation.lock();
+            if continuation.mode() == par_term_config::PromptContinuation::NewPrompt {
+                &display[..]
+            } else {
+                merged = continuation.filter(&display);
+                merged.as_slice()
+            }
+        };
+        let display = self.output_filters.coalesce_sgr_resets(display);
+        let display = self.output_filters.break_long_lines(&display);
+
+        let batches = {
+            let pty = self.pty_session.lock();
+            let terminal = pty.terminal();
+            let mut term = terminal.write();
+            let display = self.output_filters.collapse_repeated_lines(&term, &display);
+            self.output_filters
+                .process_answering_mode_requests(&mut term, &display)
+        };
+        for batch in batches {
+            batch.deliver();
+        }
     }
 
     /// Paste text to the terminal with proper bracketed paste handling.
@@ -318,3 +370,76 @@ impl TerminalManager {
         pty.read_coprocess_errors(id)
     }
 }
+
+#[cfg(all(test, unix))]
+pub(crate) mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    /// Run `script` with `/bin/sh` in `manager`'s PTY.
+    pub(crate) fn spawn_script(manager: &mut TerminalManager, script: &str) {
+        manager
+            .spawn_custom_shell_with_dir(
+                "/bin/sh",
+                Some(&["-c".to_string(), script.to_string()]),
+                None,
+                None,
+            )
+            .unwrap();
+    }
+
+    /// Run `script` in `manager`'s PTY and wait until all of its output has
+    /// been processed.
+    pub(crate) fn run_script(manager: &mut TerminalManager, script: &str) {
+        spawn_script(manager, script);
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while manager.is_running() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    fn exit_code_of(script: &str) -> Option<i32> {
+        let mut manager = TerminalManager::new(20, 5).unwrap();
+        spawn_script(&mut manager, script);
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while Instant::now() < deadline {
+            if let Some(code) = manager.exit_code() {
+                return Some(code);
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        None
+    }
+
+    #[test]
+    fn exit_code_reports_success() {
+        assert_eq!(exit_code_of("exit 0"), Some(0));
+    }
+
+    #[test]
+    fn exit_code_reports_failure() {
+        assert_eq!(exit_code_of("exit 3"), Some(3));
+    }
+
+    #[test]
+    fn exit_code_is_cached_until_respawn() {
+        let mut manager = TerminalManager::new(20, 5).unwrap();
+        run_script(&mut manager, "exit 3");
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while manager.exit_code().is_none() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(manager.exit_code(), Some(3));
+        assert_eq!(manager.exit_code(), Some(3), "reaped status is kept");
+
+        spawn_script(&mut manager, "sleep 5");
+        assert_eq!(manager.exit_code(), None);
+        manager.kill().unwrap();
+    }
+
+    #[test]
+    fn exit_code_is_none_without_process() {
+        let manager = TerminalManager::new(20, 5).unwrap();
+        assert_eq!(manager.exit_code(), None);
+    }
+}
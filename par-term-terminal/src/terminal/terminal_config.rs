@@ -40,6 +40,7 @@ impl TerminalManager {
 
     /// Set the maximum total OSC data length in bytes (QA-012 memory-exhaustion guard)
     pub fn set_max_osc_data_length(&self, max: usize) {
+        self.output_filters.osc52.lock().set_max_payload(max);
         let pty = self.pty_session.lock();
         let terminal = pty.terminal();
         let mut term = terminal.write();
@@ -47,12 +48,20 @@ impl TerminalManager {
     }
 
     /// Register a callback invoked for every chunk of raw PTY output
+    /// (output is still counted in [`Self::metrics`] and scanned for
+    /// continuation prompts)
     pub fn set_output_callback<F>(&self, callback: F)
     where
         F: Fn(&[u8]) + Send + Sync + 'static,
     {
+        let counters = std::sync::Arc::clone(&self.output_counters);
+        let markers = std::sync::Arc::clone(&self.prompt_markers);
         let mut pty = self.pty_session.lock();
-        pty.set_output_callback(std::sync::Arc::new(callback));
+        pty.set_output_callback(std::sync::Arc::new(move |data: &[u8]| {
+            counters.record(data);
+            markers.record(data);
+            callback(data);
+        }));
     }
 
     /// Begin a new recording session
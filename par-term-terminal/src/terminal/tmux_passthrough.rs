@@ -0,0 +1,164 @@
+//! Unwrapping of tmux DCS passthrough (`ESC P tmux; … ESC \`).
+//!
+//! An application running inside a nested tmux wraps escape sequences meant
+//! for the outer terminal as `ESC P tmux; <payload> ESC \`, doubling every
+//! ESC inside the payload.  The emulator's parser has no notion of this
+//! wrapper, so payload bytes are lost or misparsed; [`TmuxPassthroughFilter`]
+//! strips the wrapper and un-doubles the ESCs so the inner sequences apply.
+//!
+//! Applied to data fed through [`TerminalManager::process_data`] (tmux
+//! `%output` routing) when enabled via
+//! [`TerminalManager::set_unwrap_tmux_passthrough`].  The filter is streaming:
+//! a wrapper split across reads is handled correctly.
+
+use super::TerminalManager;
+
+/// Introducer of a tmux passthrough sequence.
+const PASSTHROUGH_PREFIX: &[u8] = b"\x1bPtmux;";
+
+const ESC: u8 = 0x1b;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Outside a passthrough; `matched` bytes of the prefix are buffered.
+    Ground { matched: usize },
+    /// Inside a passthrough payload; `esc` is set after a lone ESC.
+    Payload { esc: bool },
+}
+
+/// Streaming filter that unwraps tmux passthrough sequences.
+#[derive(Debug)]
+pub struct TmuxPassthroughFilter {
+    state: State,
+}
+
+impl Default for TmuxPassthroughFilter {
+    fn default() -> Self {
+        Self {
+            state: State::Ground { matched: 0 },
+        }
+    }
+}
+
+impl TmuxPassthroughFilter {
+    /// Filter `input`, returning the bytes to hand to the emulator.
+    ///
+    /// A trailing partial prefix (e.g. a lone ESC) is held back until the
+    /// next call decides whether it starts a passthrough.
+    pub fn filter(&mut self, input: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(input.len());
+        for &byte in input {
+            self.push(byte, &mut out);
+        }
+        out
+    }
+
+    fn push(&mut self, byte: u8, out: &mut Vec<u8>) {
+        match self.state {
+            State::Ground { matched } => {
+                if byte == PASSTHROUGH_PREFIX[matched] {
+                    self.state = if matched + 1 == PASSTHROUGH_PREFIX.len() {
+                        State::Payload { esc: false }
+                    } else {
+                        State::Ground {
+                            matched: matched + 1,
+                        }
+                    };
+                    return;
+                }
+                // Not a passthrough after all: release the buffered prefix and
+                // rescan this byte, which may itself start a new prefix.
+                out.extend_from_slice(&PASSTHROUGH_PREFIX[..matched]);
+                if byte == ESC {
+                    self.state = State::Ground { matched: 1 };
+                } else {
+                    out.push(byte);
+                    self.state = State::Ground { matched: 0 };
+                }
+            }
+            State::Payload { esc: false } => {
+                if byte == ESC {
+                    self.state = State::Payload { esc: true };
+                } else {
+                    out.push(byte);
+                }
+            }
+            State::Payload { esc: true } => match byte {
+                // Doubled ESC inside the payload stands for one ESC.
+                ESC => {
+                    out.push(ESC);
+                    self.state = State::Payload { esc: false };
+                }
+                // ESC \ (ST) ends the passthrough.
+                b'\\' => self.state = State::Ground { matched: 0 },
+                // Malformed (undoubled) ESC: pass it through as-is.
+                _ => {
+                    out.extend_from_slice(&[ESC, byte]);
+                    self.state = State::Payload { esc: false };
+                }
+            },
+        }
+    }
+}
+
+impl TerminalManager {
+    /// Enable or disable unwrapping of tmux DCS passthrough sequences.
+    pub fn set_unwrap_tmux_passthrough(&mut self, enabled: bool) {
+        self.unwrap_tmux_passthrough = enabled;
+        if !enabled {
+            *self.tmux_passthrough.lock() = TmuxPassthroughFilter::default();
+        }
+    }
+
+    /// Whether tmux DCS passthrough sequences are unwrapped.
+    pub fn unwrap_tmux_passthrough(&self) -> bool {
+        self.unwrap_tmux_passthrough
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use par_term_emu_core_rust::color::{Color, NamedColor};
+
+    #[test]
+    fn unwraps_payload_and_undoubles_esc() {
+        let mut filter = TmuxPassthroughFilter::default();
+        let out = filter.filter(b"a\x1bPtmux;\x1b\x1b[31m\x1b\\b");
+        assert_eq!(out, b"a\x1b[31mb");
+    }
+
+    #[test]
+    fn wrapper_split_across_reads_is_unwrapped() {
+        let mut filter = TmuxPassthroughFilter::default();
+        let mut out = filter.filter(b"x\x1bPtm");
+        out.extend(filter.filter(b"ux;\x1b"));
+        out.extend(filter.filter(b"\x1b]0;title\x07\x1b"));
+        out.extend(filter.filter(b"\\y"));
+        assert_eq!(out, b"x\x1b]0;title\x07y");
+    }
+
+    #[test]
+    fn other_escape_sequences_pass_through_unchanged() {
+        let mut filter = TmuxPassthroughFilter::default();
+        let input = b"\x1b[1m\x1bPq#0\x1b\\\x1b\x1bPt";
+        let mut out = filter.filter(input);
+        // The trailing "\x1bPt" could still become a passthrough.
+        assert_eq!(out, b"\x1b[1m\x1bPq#0\x1b\\\x1b");
+        out.extend(filter.filter(b"!"));
+        assert_eq!(out, input.iter().chain(b"!").copied().collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn wrapped_sgr_applies_to_following_text() {
+        let mut manager = TerminalManager::new(20, 5).unwrap();
+        manager.set_unwrap_tmux_passthrough(true);
+        manager.process_data(b"\x1bPtmux;\x1b\x1b[31m\x1b\\X");
+
+        let terminal = manager.terminal();
+        let term = terminal.read();
+        let cell = term.active_grid().get(0, 0).expect("cell");
+        assert_eq!(cell.c(), 'X');
+        assert_eq!(cell.fg(), Color::Named(NamedColor::Red));
+    }
+}
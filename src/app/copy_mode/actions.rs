@@ -0,0 +1,232 @@
+//! Named copy mode actions and their default keys.
+//!
+//! Every copy mode command has an action name (`copy_mode_move_left`,
+//! `copy_mode_yank`, ...) that keybindings with `mode: copy` can use, and the
+//! `copy_mode_search_*` actions can be bound with `mode: search` for typing a
+//! search query.  Keys without a user binding fall back to the vi-style
+//! defaults in [`default_copy_mode_action`] and
+//! [`default_copy_mode_search_action`].
+//!
+//! Counts, marks (`m`, `'`) and the `g` prefix read the key that follows
+//! them, so they stay on their default keys.
+
+use crate::app::window_state::WindowState;
+use crate::copy_mode::{PendingOperator, SearchDirection, VisualMode};
+use winit::keyboard::{Key, NamedKey};
+
+/// Action bound by default to `key` in copy mode.
+pub(crate) fn default_copy_mode_action(key: &Key, ctrl: bool) -> Option<&'static str> {
+    let action = match key {
+        Key::Character(ch) if !ctrl => match ch.as_str() {
+            "h" => "copy_mode_move_left",
+            "j" => "copy_mode_move_down",
+            "k" => "copy_mode_move_up",
+            "l" => "copy_mode_move_right",
+            "0" => "copy_mode_line_start",
+            "$" => "copy_mode_line_end",
+            "^" => "copy_mode_first_non_blank",
+            "w" => "copy_mode_word_forward",
+            "b" => "copy_mode_word_backward",
+            "e" => "copy_mode_word_end",
+            "W" => "copy_mode_big_word_forward",
+            "B" => "copy_mode_big_word_backward",
+            "E" => "copy_mode_big_word_end",
+            "G" => "copy_mode_goto_bottom",
+            "v" => "copy_mode_visual_char",
+            "V" => "copy_mode_visual_line",
+            "y" => "copy_mode_yank",
+            "/" => "copy_mode_search_forward",
+            "?" => "copy_mode_search_backward",
+            "n" => "copy_mode_search_next",
+            "N" => "copy_mode_search_previous",
+            "q" => "copy_mode_exit",
+            _ => return None,
+        },
+        Key::Character(ch) => match ch.as_str() {
+            "u" => "copy_mode_half_page_up",
+            "d" => "copy_mode_half_page_down",
+            "b" => "copy_mode_page_up",
+            "f" => "copy_mode_page_down",
+            "v" => "copy_mode_visual_block",
+            _ => return None,
+        },
+        Key::Named(NamedKey::Escape) => "copy_mode_cancel",
+        Key::Named(NamedKey::ArrowLeft) => "copy_mode_move_left",
+        Key::Named(NamedKey::ArrowRight) => "copy_mode_move_right",
+        Key::Named(NamedKey::ArrowUp) => "copy_mode_move_up",
+        Key::Named(NamedKey::ArrowDown) => "copy_mode_move_down",
+        Key::Named(NamedKey::Home) => "copy_mode_line_start",
+        Key::Named(NamedKey::End) => "copy_mode_line_end",
+        Key::Named(NamedKey::PageUp) => "copy_mode_page_up",
+        Key::Named(NamedKey::PageDown) => "copy_mode_page_down",
+        _ => return None,
+    };
+    Some(action)
+}
+
+/// Action bound by default to `key` while typing a copy mode search.
+pub(crate) fn default_copy_mode_search_action(key: &Key) -> Option<&'static str> {
+    match key {
+        Key::Named(NamedKey::Escape) => Some("copy_mode_search_cancel"),
+        Key::Named(NamedKey::Enter) => Some("copy_mode_search_submit"),
+        Key::Named(NamedKey::Backspace) => Some("copy_mode_search_backspace"),
+        _ => None,
+    }
+}
+
+impl WindowState {
+    /// Run a named copy mode action. Returns false if `action` is not one.
+    pub(crate) fn run_copy_mode_action(&mut self, action: &str) -> bool {
+        match action {
+            // === Directional motions ===
+            "copy_mode_move_left" => self.copy_mode.move_left(),
+            "copy_mode_move_down" => self.copy_mode.move_down(),
+            "copy_mode_move_up" => self.copy_mode.move_up(),
+            "copy_mode_move_right" => self.copy_mode.move_right(),
+
+            // === Line motions ===
+            "copy_mode_line_start" => self.copy_mode.move_to_line_start(),
+            "copy_mode_line_end" => self.copy_mode.move_to_line_end(),
+            "copy_mode_first_non_blank" => {
+                let Some(text) = self.get_copy_mode_line_text() else {
+                    return true;
+                };
+                self.copy_mode.move_to_first_non_blank(&text);
+            }
+
+            // === Word motions ===
+            "copy_mode_word_forward" | "copy_mode_word_backward" | "copy_mode_word_end" => {
+                let Some(text) = self.get_copy_mode_line_text() else {
+                    return true;
+                };
+                let word_chars = self.config.load().word_characters.clone();
+                match action {
+                    "copy_mode_word_forward" => {
+                        self.copy_mode.move_word_forward(&text, &word_chars)
+                    }
+                    "copy_mode_word_backward" => {
+                        self.copy_mode.move_word_backward(&text, &word_chars)
+                    }
+                    _ => self.copy_mode.move_word_end(&text, &word_chars),
+                }
+            }
+            "copy_mode_big_word_forward"
+            | "copy_mode_big_word_backward"
+            | "copy_mode_big_word_end" => {
+                let Some(text) = self.get_copy_mode_line_text() else {
+                    return true;
+                };
+                match action {
+                    "copy_mode_big_word_forward" => self.copy_mode.move_big_word_forward(&text),
+                    "copy_mode_big_word_backward" => self.copy_mode.move_big_word_backward(&text),
+                    _ => self.copy_mode.move_big_word_end(&text),
+                }
+            }
+
+            // === Page/buffer motions ===
+            "copy_mode_goto_top" => self.copy_mode.goto_top(),
+            "copy_mode_goto_bottom" => {
+                if let Some(count) = self.copy_mode.count.take() {
+                    // {count}G goes to absolute line
+                    self.copy_mode.goto_line(count.saturating_sub(1));
+                } else {
+                    self.copy_mode.goto_bottom();
+                }
+            }
+            "copy_mode_half_page_up" => self.copy_mode.half_page_up(),
+            "copy_mode_half_page_down" => self.copy_mode.half_page_down(),
+            "copy_mode_page_up" => self.copy_mode.page_up(),
+            "copy_mode_page_down" => self.copy_mode.page_down(),
+
+            // === Visual modes ===
+            "copy_mode_visual_char" => {
+                if self.copy_mode.pending_operator.is_some() {
+                    // yv = yank to current pos (do nothing special)
+                    self.copy_mode.pending_operator = None;
+                    return true;
+                }
+                self.copy_mode.toggle_visual_char();
+            }
+            "copy_mode_visual_line" => self.copy_mode.toggle_visual_line(),
+            "copy_mode_visual_block" => self.copy_mode.toggle_visual_block(),
+
+            // === Yank ===
+            "copy_mode_yank" => {
+                if self.copy_mode.visual_mode != VisualMode::None {
+                    // In visual mode, yank the selection
+                    self.yank_copy_mode_selection();
+                } else {
+                    // Set pending yank operator (yy = yank line, yw = yank word, etc.)
+                    // For simplicity, just yank current line on 'y' in normal mode
+                    self.copy_mode.pending_operator = Some(PendingOperator::Yank);
+                }
+                return true;
+            }
+
+            // === Search ===
+            "copy_mode_search_forward" | "copy_mode_search_backward" => {
+                let direction = if action == "copy_mode_search_forward" {
+                    SearchDirection::Forward
+                } else {
+                    SearchDirection::Backward
+                };
+                self.copy_mode.start_search(direction);
+                self.focus_state.needs_redraw = true;
+                self.request_redraw();
+                return true;
+            }
+            "copy_mode_search_next" => {
+                self.execute_copy_mode_search(false);
+                return true;
+            }
+            "copy_mode_search_previous" => {
+                self.execute_copy_mode_search(true);
+                return true;
+            }
+
+            // === Exit ===
+            "copy_mode_cancel" => {
+                if self.copy_mode.visual_mode != VisualMode::None {
+                    // Exit visual mode first
+                    self.copy_mode.visual_mode = VisualMode::None;
+                    self.copy_mode.selection_anchor = None;
+                    self.with_active_tab_mut(|tab| {
+                        tab.selection_mouse_mut().selection = None;
+                        tab.active_cache_mut().cells = None;
+                    });
+                    self.focus_state.needs_redraw = true;
+                    self.request_redraw();
+                } else {
+                    self.exit_copy_mode();
+                }
+                return true;
+            }
+            "copy_mode_exit" => {
+                self.exit_copy_mode();
+                return true;
+            }
+
+            _ => return false,
+        }
+        self.after_copy_mode_motion();
+        true
+    }
+
+    /// Run a named copy mode search action. Returns false if `action` is
+    /// not one.
+    pub(crate) fn run_copy_mode_search_action(&mut self, action: &str) -> bool {
+        match action {
+            "copy_mode_search_cancel" => self.copy_mode.cancel_search(),
+            "copy_mode_search_submit" => {
+                self.copy_mode.is_searching = false;
+                self.execute_copy_mode_search(false);
+                return true;
+            }
+            "copy_mode_search_backspace" => self.copy_mode.search_backspace(),
+            _ => return false,
+        }
+        self.focus_state.needs_redraw = true;
+        self.request_redraw();
+        true
+    }
+}
@@ -2,12 +2,13 @@
 //!
 //! Handles entering/exiting copy mode and the main key dispatch loop.
 //! Search helpers, motion post-processing, and clipboard operations live in
-//! `copy_mode_search`.
+//! `copy_mode_search`; the named actions keys dispatch to live in `actions`.
 
+use super::actions::default_copy_mode_action;
 use crate::app::window_state::WindowState;
-use crate::copy_mode::{SearchDirection, VisualMode};
+use crate::keybindings::{KeybindingMode, SequenceMatch};
 use winit::event::KeyEvent;
-use winit::keyboard::{Key, NamedKey};
+use winit::keyboard::Key;
 
 impl WindowState {
     /// Check if copy mode is currently active
@@ -68,7 +69,9 @@ impl WindowState {
     pub(crate) fn handle_copy_mode_key(&mut self, event: &KeyEvent) {
         // Handle search input mode
         if self.copy_mode.is_searching {
-            self.handle_copy_mode_search_key(event);
+            if !self.copy_mode_binding(event, KeybindingMode::Search) {
+                self.handle_copy_mode_search_key(event);
+            }
             return;
         }
 
@@ -116,254 +119,88 @@ impl WindowState {
             return;
         }
 
-        // Check modifiers for Ctrl key combinations
-        let modifiers = &self.input_handler.modifiers;
-        let ctrl = modifiers.state().control_key();
-
-        match &event.logical_key {
-            // === Directional motions ===
-            Key::Character(ch) if !ctrl => match ch.as_str() {
-                "h" => {
-                    self.copy_mode.move_left();
-                    self.after_copy_mode_motion();
-                }
-                "j" => {
-                    self.copy_mode.move_down();
-                    self.after_copy_mode_motion();
-                }
-                "k" => {
-                    self.copy_mode.move_up();
-                    self.after_copy_mode_motion();
-                }
-                "l" => {
-                    self.copy_mode.move_right();
-                    self.after_copy_mode_motion();
-                }
-
-                // === Line motions ===
-                "0" => {
-                    // '0' can be part of a count or line start
-                    if self.copy_mode.count.is_some() {
-                        self.copy_mode.push_count_digit(0);
-                    } else {
-                        self.copy_mode.move_to_line_start();
-                        self.after_copy_mode_motion();
-                    }
-                }
-                "$" => {
-                    self.copy_mode.move_to_line_end();
-                    self.after_copy_mode_motion();
-                }
-                "^" => {
-                    if let Some(text) = self.get_copy_mode_line_text() {
-                        self.copy_mode.move_to_first_non_blank(&text);
-                        self.after_copy_mode_motion();
-                    }
-                }
-
-                // === Word motions ===
-                "w" => {
-                    if let Some(text) = self.get_copy_mode_line_text() {
-                        let word_chars = self.config.load().word_characters.clone();
-                        self.copy_mode.move_word_forward(&text, &word_chars);
-                        self.after_copy_mode_motion();
-                    }
-                }
-                "b" => {
-                    if let Some(text) = self.get_copy_mode_line_text() {
-                        let word_chars = self.config.load().word_characters.clone();
-                        self.copy_mode.move_word_backward(&text, &word_chars);
-                        self.after_copy_mode_motion();
-                    }
-                }
-                "e" => {
-                    if let Some(text) = self.get_copy_mode_line_text() {
-                        let word_chars = self.config.load().word_characters.clone();
-                        self.copy_mode.move_word_end(&text, &word_chars);
-                        self.after_copy_mode_motion();
-                    }
-                }
-                "W" => {
-                    if let Some(text) = self.get_copy_mode_line_text() {
-                        self.copy_mode.move_big_word_forward(&text);
-                        self.after_copy_mode_motion();
-                    }
-                }
-                "B" => {
-                    if let Some(text) = self.get_copy_mode_line_text() {
-                        self.copy_mode.move_big_word_backward(&text);
-                        self.after_copy_mode_motion();
-                    }
-                }
-                "E" => {
-                    if let Some(text) = self.get_copy_mode_line_text() {
-                        self.copy_mode.move_big_word_end(&text);
-                        self.after_copy_mode_motion();
-                    }
-                }
+        // User bindings scoped to copy mode come before the defaults
+        if self.copy_mode_binding(event, KeybindingMode::Copy) {
+            return;
+        }
 
-                // === Page/buffer motions ===
-                "G" => {
-                    if let Some(count) = self.copy_mode.count.take() {
-                        // {count}G goes to absolute line
-                        self.copy_mode.goto_line(count.saturating_sub(1));
-                    } else {
-                        self.copy_mode.goto_bottom();
-                    }
-                    self.after_copy_mode_motion();
+        let ctrl = self.input_handler.modifiers.state().control_key();
+        if let Key::Character(ch) = &event.logical_key
+            && !ctrl
+        {
+            match ch.as_str() {
+                // '0' can be part of a count or line start
+                "0" if self.copy_mode.count.is_some() => {
+                    self.copy_mode.push_count_digit(0);
+                    return;
                 }
                 "g" => {
                     self.copy_mode.pending_g = true;
-                }
-
-                // === Visual modes ===
-                "v" => {
-                    if self.copy_mode.pending_operator.is_some() {
-                        // yv = yank to current pos (do nothing special)
-                        self.copy_mode.pending_operator = None;
-                    } else {
-                        self.copy_mode.toggle_visual_char();
-                        self.after_copy_mode_motion();
-                    }
-                }
-                "V" => {
-                    self.copy_mode.toggle_visual_line();
-                    self.after_copy_mode_motion();
-                }
-
-                // === Yank ===
-                "y" => {
-                    if self.copy_mode.visual_mode != VisualMode::None {
-                        // In visual mode, yank the selection
-                        self.yank_copy_mode_selection();
-                    } else {
-                        // Set pending yank operator (yy = yank line, yw = yank word, etc.)
-                        // For simplicity, just yank current line on 'y' in normal mode
-                        self.copy_mode.pending_operator =
-                            Some(crate::copy_mode::PendingOperator::Yank);
-                    }
-                }
-
-                // === Search ===
-                "/" => {
-                    self.copy_mode.start_search(SearchDirection::Forward);
-                    self.focus_state.needs_redraw = true;
-                    self.request_redraw();
-                }
-                "?" => {
-                    self.copy_mode.start_search(SearchDirection::Backward);
-                    self.focus_state.needs_redraw = true;
-                    self.request_redraw();
-                }
-                "n" => {
-                    self.execute_copy_mode_search(false);
-                }
-                "N" => {
-                    self.execute_copy_mode_search(true);
+                    return;
                 }
 
                 // === Marks ===
                 "m" => {
                     self.copy_mode.pending_mark_set = true;
+                    return;
                 }
                 "'" => {
                     self.copy_mode.pending_mark_goto = true;
+                    return;
                 }
 
                 // === Count prefix ===
-                "1" => self.copy_mode.push_count_digit(1),
-                "2" => self.copy_mode.push_count_digit(2),
-                "3" => self.copy_mode.push_count_digit(3),
-                "4" => self.copy_mode.push_count_digit(4),
-                "5" => self.copy_mode.push_count_digit(5),
-                "6" => self.copy_mode.push_count_digit(6),
-                "7" => self.copy_mode.push_count_digit(7),
-                "8" => self.copy_mode.push_count_digit(8),
-                "9" => self.copy_mode.push_count_digit(9),
-
-                // === Exit ===
-                "q" => {
-                    self.exit_copy_mode();
-                }
-
+                "1" => return self.copy_mode.push_count_digit(1),
+                "2" => return self.copy_mode.push_count_digit(2),
+                "3" => return self.copy_mode.push_count_digit(3),
+                "4" => return self.copy_mode.push_count_digit(4),
+                "5" => return self.copy_mode.push_count_digit(5),
+                "6" => return self.copy_mode.push_count_digit(6),
+                "7" => return self.copy_mode.push_count_digit(7),
+                "8" => return self.copy_mode.push_count_digit(8),
+                "9" => return self.copy_mode.push_count_digit(9),
                 _ => {}
-            },
+            }
+        }
 
-            // === Ctrl key combinations ===
-            Key::Character(ch) if ctrl => match ch.as_str() {
-                "u" => {
-                    self.copy_mode.half_page_up();
-                    self.after_copy_mode_motion();
-                }
-                "d" => {
-                    self.copy_mode.half_page_down();
-                    self.after_copy_mode_motion();
-                }
-                "b" => {
-                    self.copy_mode.page_up();
-                    self.after_copy_mode_motion();
-                }
-                "f" => {
-                    self.copy_mode.page_down();
-                    self.after_copy_mode_motion();
-                }
-                "v" => {
-                    self.copy_mode.toggle_visual_block();
-                    self.after_copy_mode_motion();
-                }
-                _ => {}
-            },
+        if let Some(action) = default_copy_mode_action(&event.logical_key, ctrl) {
+            self.run_copy_mode_action(action);
+        }
+    }
 
-            // === Named keys ===
-            Key::Named(NamedKey::Escape) => {
-                if self.copy_mode.visual_mode != VisualMode::None {
-                    // Exit visual mode first
-                    self.copy_mode.visual_mode = VisualMode::None;
-                    self.copy_mode.selection_anchor = None;
-                    self.with_active_tab_mut(|tab| {
-                        tab.selection_mouse_mut().selection = None;
-                        tab.active_cache_mut().cells = None;
-                    });
-                    self.focus_state.needs_redraw = true;
-                    self.request_redraw();
-                } else {
-                    self.exit_copy_mode();
+    /// Look up a user keybinding for `mode` and run it.  Returns true if the
+    /// key was consumed, including as the start of a chord.
+    fn copy_mode_binding(&mut self, event: &KeyEvent, mode: KeybindingMode) -> bool {
+        let config = self.config.load();
+        let lookup = self.keybinding_registry.lookup_sequence(
+            event,
+            &self.input_handler.modifiers,
+            &config.modifier_remapping,
+            config.use_physical_keys,
+            mode,
+            std::time::Instant::now(),
+        );
+        // The prefix of a chord the key broke off runs first
+        if let Some(action) = self.keybinding_registry.take_interrupted_fallback() {
+            self.run_copy_mode_binding(mode, &action);
+        }
+        match lookup {
+            SequenceMatch::Matched(action) => {
+                let handled = self.run_copy_mode_binding(mode, &action);
+                if !handled {
+                    log::warn!("Unknown {:?} mode keybinding action '{}'", mode, action);
                 }
+                handled
             }
-            Key::Named(NamedKey::ArrowLeft) => {
-                self.copy_mode.move_left();
-                self.after_copy_mode_motion();
-            }
-            Key::Named(NamedKey::ArrowRight) => {
-                self.copy_mode.move_right();
-                self.after_copy_mode_motion();
-            }
-            Key::Named(NamedKey::ArrowUp) => {
-                self.copy_mode.move_up();
-                self.after_copy_mode_motion();
-            }
-            Key::Named(NamedKey::ArrowDown) => {
-                self.copy_mode.move_down();
-                self.after_copy_mode_motion();
-            }
-            Key::Named(NamedKey::Home) => {
-                self.copy_mode.move_to_line_start();
-                self.after_copy_mode_motion();
-            }
-            Key::Named(NamedKey::End) => {
-                self.copy_mode.move_to_line_end();
-                self.after_copy_mode_motion();
-            }
-            Key::Named(NamedKey::PageUp) => {
-                self.copy_mode.page_up();
-                self.after_copy_mode_motion();
-            }
-            Key::Named(NamedKey::PageDown) => {
-                self.copy_mode.page_down();
-                self.after_copy_mode_motion();
-            }
+            SequenceMatch::Pending => true,
+            SequenceMatch::NoMatch => false,
+        }
+    }
 
-            _ => {}
+    fn run_copy_mode_binding(&mut self, mode: KeybindingMode, action: &str) -> bool {
+        match mode {
+            KeybindingMode::Search => self.run_copy_mode_search_action(action),
+            _ => self.run_copy_mode_action(action),
         }
     }
 }
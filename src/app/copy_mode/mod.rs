@@ -1,7 +1,9 @@
 //! Copy mode implementation for WindowState.
 //!
+//! - `actions`: Named copy mode actions and their default keys.
 //! - `handler`: Key dispatch, enter/exit, and main loop.
 //! - `search`: Search input, forward/backward scanning, and motion helpers.
 
+pub mod actions;
 pub mod handler;
 pub mod search;
@@ -11,37 +11,23 @@
 //! - `follow_copy_mode_cursor` — viewport scrolling to follow cursor
 //! - `yank_copy_mode_selection` — clipboard yank
 
+use super::actions::default_copy_mode_search_action;
 use crate::app::window_state::WindowState;
 use crate::copy_mode::SearchDirection;
 use winit::event::KeyEvent;
-use winit::keyboard::{Key, NamedKey};
+use winit::keyboard::Key;
 
 impl WindowState {
     /// Handle key events during search input mode
     pub(crate) fn handle_copy_mode_search_key(&mut self, event: &KeyEvent) {
-        match &event.logical_key {
-            Key::Named(NamedKey::Escape) => {
-                self.copy_mode.cancel_search();
-                self.focus_state.needs_redraw = true;
-                self.request_redraw();
+        if let Some(action) = default_copy_mode_search_action(&event.logical_key) {
+            self.run_copy_mode_search_action(action);
+        } else if let Key::Character(ch) = &event.logical_key {
+            for c in ch.chars() {
+                self.copy_mode.search_input(c);
             }
-            Key::Named(NamedKey::Enter) => {
-                self.copy_mode.is_searching = false;
-                self.execute_copy_mode_search(false);
-            }
-            Key::Named(NamedKey::Backspace) => {
-                self.copy_mode.search_backspace();
-                self.focus_state.needs_redraw = true;
-                self.request_redraw();
-            }
-            Key::Character(ch) => {
-                for c in ch.chars() {
-                    self.copy_mode.search_input(c);
-                }
-                self.focus_state.needs_redraw = true;
-                self.request_redraw();
-            }
-            _ => {}
+            self.focus_state.needs_redraw = true;
+            self.request_redraw();
         }
     }
 
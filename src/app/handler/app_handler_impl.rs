@@ -473,10 +473,12 @@ impl ApplicationHandler for WindowManager {
             self.close_window(window_id);
         }
 
-        // Sync coprocess and script running state to settings window
+        // Sync coprocess, script, badge variable, and GPU state to settings window
         if self.settings_window.is_some() {
             self.sync_coprocess_running_state();
             self.sync_script_running_state();
+            self.sync_badge_variables_to_settings();
+            self.sync_gpu_info_to_settings();
         }
 
         // Request redraw for settings window if it needs continuous updates
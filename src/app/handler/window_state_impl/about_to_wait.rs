@@ -3,7 +3,8 @@
 //! Contains:
 //! - `about_to_wait`: per-frame polling for notifications, tmux, config reload,
 //!   cursor blink, smooth scrolling, power saving, flicker reduction, throughput mode,
-//!   resize/toast overlay timers, shader animation, file transfers, anti-idle keep-alive.
+//!   resize/toast overlay timers, shader animation, file transfers, anti-idle keep-alive,
+//!   app-level key repeat, chord keybinding timeouts.
 
 use crate::app::window_state::WindowState;
 use winit::event_loop::{ActiveEventLoop, ControlFlow};
@@ -34,6 +35,9 @@ impl WindowState {
         // The call is cheap (two atomic loads) when no new failures happened.
         crate::debug::maybe_log_try_lock_telemetry();
 
+        // Replay held keys configured for app-level key repeat
+        self.fire_key_repeats(event_loop);
+
         // Check for and deliver notifications (OSC 9/777/99)
         self.check_notifications();
 
@@ -58,6 +62,9 @@ impl WindowState {
         // Check for session exit notifications
         self.check_session_exit_notifications();
 
+        // Check every tab and pane for long-running commands that finished
+        self.check_command_finished_notifications();
+
         // Check for shader hot reload events
         if self.check_shader_reload() {
             log::debug!("Shader hot reload triggered redraw");
@@ -75,6 +82,10 @@ impl WindowState {
         // Check for MCP shader diagnostics requests (.shader-diagnostics-request.json)
         self.check_shader_diagnostics_request_file();
 
+        // Check for MCP terminal write requests (.terminal-write-request.json)
+        self.check_terminal_write_request_file();
+        self.check_terminal_read_request_file();
+
         // Check for tmux control mode notifications
         if self.check_tmux_notifications() {
             self.focus_state.needs_redraw = true;
@@ -244,8 +255,10 @@ impl WindowState {
 
         // 1. Cursor Blinking
         // Wake up exactly when the cursor needs to toggle visibility or fade.
-        // Skip cursor blinking when unfocused with pause_refresh_on_blur to save power.
+        // Skip cursor blinking when unfocused with pause_refresh_on_blur to save power,
+        // or while blinking is frozen for an unfocused window.
         if self.config.load().cursor.cursor_blink
+            && !self.cursor_anim.blink_frozen
             && (self.focus_state.is_focused || !self.config.load().pause_refresh_on_blur)
         {
             if self.cursor_anim.cursor_blink_timer.is_none() {
@@ -403,6 +416,41 @@ impl WindowState {
             }
         }
 
+        // Scrollbar auto-hide: wake when the fade-out starts; the fade itself
+        // animates through needs_continuous_render() above.
+        if let Some(renderer) = &self.renderer
+            && let Some(fade_start) = renderer.scrollbar_fade_start()
+            && fade_start < next_wake
+        {
+            next_wake = fade_start;
+        }
+
+        // Inactive pane dimming: keep redrawing at ~60fps while a pane fades
+        // to its new dim level after a focus change.
+        if let Some(tab) = self.tab_manager.active_tab()
+            && let Some(pm) = tab.pane_manager.as_ref()
+            && pm.all_panes().iter().any(|p| p.dim_fade.is_animating(now))
+        {
+            if can_render {
+                self.focus_state.needs_redraw = true;
+            }
+            let next_frame = now + std::time::Duration::from_millis(16);
+            if next_frame < next_wake {
+                next_wake = next_frame;
+            }
+        }
+
+        // Pane focus follows mouse: a resting mouse sends no more moves, so
+        // wake up to finish a pending switch when its hover delay is up.
+        self.update_pane_hover_focus(false);
+        let hover_delay =
+            std::time::Duration::from_millis(self.config.load().mouse.pane_focus_hover_delay_ms);
+        if let Some(deadline) = self.focus_state.pane_hover_focus.deadline(hover_delay)
+            && deadline < next_wake
+        {
+            next_wake = deadline;
+        }
+
         // 7. Shader Install Dialog
         // Force continuous redraws when shader install dialog is visible (for spinner animation)
         // and when installation is in progress (to check for completion)
@@ -430,6 +478,25 @@ impl WindowState {
             }
         }
 
+        // Chord keybindings: a chord prefix that is also a binding runs once
+        // the chord times out, so wake up for the deadline.
+        if let Some(action) = self.keybinding_registry.expire_pending(now) {
+            crate::debug_info!("KEYBINDING", "Chord timed out, running action={}", action);
+            self.execute_keybinding_action(&action);
+        }
+        if let Some(deadline) = self.keybinding_registry.pending_deadline()
+            && deadline < next_wake
+        {
+            next_wake = deadline;
+        }
+
+        // App-level key repeat: wake for the next scheduled repeat
+        if let Some(next_repeat) = self.input_handler.key_repeat.next_deadline()
+            && next_repeat < next_wake
+        {
+            next_wake = next_repeat;
+        }
+
         // 9. Anti-idle Keep-alive
         // Periodically send keep-alive codes to prevent SSH/connection timeouts.
         if let Some(next_anti_idle) = self.handle_anti_idle(now)
@@ -37,6 +37,9 @@ impl WindowState {
             self.focus_state.focus_click_pending = false;
             self.focus_state.focus_click_suppressed_while_unfocused_at = None;
             self.focus_state.pending_focus_tab_switch = None;
+            // The key release will go to another window; stop app-level repeat.
+            self.input_handler.key_repeat.cancel();
+            self.keybinding_registry.cancel_pending();
         }
 
         // Update renderer focus state for unfocused cursor styling
@@ -119,10 +122,12 @@ impl WindowState {
         // is immediately visible. If the cursor was in the blink-off phase (opacity=0)
         // when focus changed and cursor blink is paused-on-blur, the cursor would stay
         // invisible forever, causing the hollow cursor to never render.
+        self.cursor_anim.set_focused(
+            focused,
+            std::time::Instant::now(),
+            self.config.load().cursor.cursor_blink_only_focused,
+        );
         if !focused {
-            self.cursor_anim.cursor_opacity = 1.0;
-            self.cursor_anim.last_cursor_blink = None;
-            self.cursor_anim.cursor_blink_timer = None;
             log::info!("[FOCUS] Lost focus: reset cursor_opacity=1.0");
         } else {
             log::info!("[FOCUS] Gained focus");
@@ -78,10 +78,13 @@ impl WindowState {
                 log::info!("Close requested for window");
 
                 // Check if prompt_on_quit is enabled and there are active sessions
+                // (with foreground jobs, when only those count)
                 let tab_count = self.tab_manager.visible_tab_count();
-                if self.config.load().prompt_on_quit
+                let config = self.config.load();
+                if config.prompt_on_quit
                     && tab_count > 0
                     && !self.overlay_ui.quit_confirmation_ui.is_visible()
+                    && (!config.confirm_foreground_jobs_only || self.any_foreground_job())
                 {
                     log::info!(
                         "Showing quit confirmation dialog ({} active sessions)",
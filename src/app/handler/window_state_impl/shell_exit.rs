@@ -3,6 +3,8 @@
 //! Contains:
 //! - `handle_shell_exit`: dispatches shell exit actions (Keep, Close, Restart variants)
 //!   for all tabs and panes. Returns true if the window should close.
+//! - `hold_failed_shells`: keeps panes open after a non-zero exit when
+//!   `shell_exit_keep_on_failure` is set.
 
 use crate::app::window_state::WindowState;
 
@@ -14,7 +16,12 @@ impl WindowState {
         use crate::config::ShellExitAction;
         use crate::pane::RestartState;
 
-        match self.config.load().shell_exit_action {
+        let action = self.config.load().shell_exit_action;
+        if self.config.load().shell_exit_keep_on_failure && action != ShellExitAction::Keep {
+            self.hold_failed_shells(action);
+        }
+
+        match action {
             ShellExitAction::Keep => {
                 // Do nothing - keep dead shells showing
             }
@@ -144,7 +151,10 @@ impl WindowState {
                             let is_running = pane.is_running();
 
                             // Check if pane needs restart action
-                            if !is_running && pane.restart_state.is_none() {
+                            if !is_running
+                                && pane.restart_state.is_none()
+                                && pane.failed_exit_code.is_none()
+                            {
                                 // Shell just exited, handle based on action
                                 match self.config.load().shell_exit_action {
                                     ShellExitAction::RestartImmediately => {
@@ -203,4 +213,40 @@ impl WindowState {
 
         false // Window stays open
     }
+
+    /// Hold panes whose shell exited non-zero (`shell_exit_keep_on_failure`).
+    ///
+    /// Runs before the close/restart pass so a failed shell stays visible with
+    /// an exit status line; held panes are skipped by that pass.
+    fn hold_failed_shells(&mut self, action: crate::config::ShellExitAction) {
+        for tab in self.tab_manager.tabs_mut() {
+            if tab.tmux.tmux_gateway_active || tab.tmux.tmux_pane_id.is_some() {
+                continue;
+            }
+            let Some(pm) = tab.pane_manager_mut() else {
+                continue;
+            };
+            for pane in pm.all_panes_mut() {
+                if pane.is_running()
+                    || pane.restart_state.is_some()
+                    || pane.failed_exit_code.is_some()
+                {
+                    continue;
+                }
+                // blocking_read: runs once per exited shell, and the exit code must be
+                // known before the close/restart pass decides the pane's fate.
+                let Some(exit_code) = pane.terminal.blocking_read().exit_code() else {
+                    continue;
+                };
+                if action.for_exit_code(true, exit_code) == crate::config::ShellExitAction::Keep {
+                    log::info!(
+                        "Pane {} shell exited with code {}, keeping it open",
+                        pane.id,
+                        exit_code
+                    );
+                    pane.hold_after_failed_exit(exit_code);
+                }
+            }
+        }
+    }
 }
@@ -157,7 +157,7 @@ impl WindowState {
 
         // Sanitize clipboard content to strip dangerous control characters
         // (escape sequences, C0/C1 controls) before sending to PTY
-        let text = crate::paste_transform::sanitize_paste_content(text);
+        let text = crate::paste_transform::prepare_paste(text, &self.config.load());
 
         // Try to paste via tmux if connected
         if self.paste_via_tmux(&text) {
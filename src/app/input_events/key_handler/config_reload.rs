@@ -40,10 +40,40 @@ impl WindowState {
                     new.right_option_key_mode = new_config.right_option_key_mode;
                     std::sync::Arc::new(new)
                 });
+                self.config.rcu(|old| {
+                    let mut new = (**old).clone();
+                    new.alt_sends_escape = new_config.alt_sends_escape;
+                    std::sync::Arc::new(new)
+                });
                 self.input_handler.update_option_key_modes(
                     new_config.left_option_key_mode,
                     new_config.right_option_key_mode,
                 );
+                self.input_handler
+                    .update_alt_sends_escape(new_config.alt_sends_escape);
+
+                // Update Backspace byte (DEL vs BS)
+                self.config.rcu(|old| {
+                    let mut new = (**old).clone();
+                    new.backspace_sends = new_config.backspace_sends;
+                    std::sync::Arc::new(new)
+                });
+                self.input_handler
+                    .update_backspace_sends(new_config.backspace_sends);
+
+                // Update app-level key repeat
+                self.config.rcu(|old| {
+                    let mut new = (**old).clone();
+                    new.key_repeat_keys = new_config.key_repeat_keys.clone();
+                    new.key_repeat_delay_ms = new_config.key_repeat_delay_ms;
+                    new.key_repeat_interval_ms = new_config.key_repeat_interval_ms;
+                    std::sync::Arc::new(new)
+                });
+                self.input_handler.update_key_repeat(
+                    new_config.key_repeat_delay_ms,
+                    new_config.key_repeat_interval_ms,
+                    &new_config.key_repeat_keys,
+                );
 
                 // Update modifier remapping and physical keys preference
                 self.config.rcu(|old| {
@@ -131,6 +161,35 @@ impl WindowState {
                     log::info!("Applied new theme: {}", new_config.theme);
                 }
 
+                // Update saved keyword highlight sets
+                if self.config.load().search.keyword_highlights
+                    != new_config.search.keyword_highlights
+                {
+                    self.config.rcu(|old| {
+                        let mut new = (**old).clone();
+                        new.search.keyword_highlights =
+                            new_config.search.keyword_highlights.clone();
+                        std::sync::Arc::new(new)
+                    });
+                    let sets = &new_config.search.keyword_highlights;
+                    for tab in self.tab_manager.tabs_mut() {
+                        if let Ok(mut term) = tab.terminal.try_write() {
+                            term.set_keyword_highlights(sets);
+                        }
+                        let tab_terminal = std::sync::Arc::clone(&tab.terminal);
+                        if let Some(pm) = tab.pane_manager_mut() {
+                            for pane in pm.all_panes_mut() {
+                                if !std::sync::Arc::ptr_eq(&pane.terminal, &tab_terminal)
+                                    && let Ok(mut term) = pane.terminal.try_write()
+                                {
+                                    term.set_keyword_highlights(sets);
+                                }
+                                pane.cache.invalidate_pane_cells();
+                            }
+                        }
+                    }
+                }
+
                 // Note: Clipboard history and notification settings not yet available in core library
                 // Config reloading for these features will be enabled when APIs become available
 
@@ -150,14 +209,20 @@ impl WindowState {
                 }
 
                 // Refresh keybinding registry if keybindings changed
-                if new_config.keybindings != self.config.load().keybindings {
-                    self.keybinding_registry = crate::keybindings::KeybindingRegistry::from_config(
-                        &new_config.keybindings,
-                    );
+                if new_config.keybindings != self.config.load().keybindings
+                    || new_config.keybinding_chord_timeout_ms
+                        != self.config.load().keybinding_chord_timeout_ms
+                    || new_config.keybinding_leader != self.config.load().keybinding_leader
+                {
+                    self.keybinding_registry = Self::build_keybinding_registry(&new_config);
                     let kb = new_config.keybindings.clone();
+                    let chord_timeout_ms = new_config.keybinding_chord_timeout_ms;
+                    let leader = new_config.keybinding_leader.clone();
                     self.config.rcu(|old| {
                         let mut new = (**old).clone();
                         new.keybindings = kb.clone();
+                        new.keybinding_chord_timeout_ms = chord_timeout_ms;
+                        new.keybinding_leader = leader.clone();
                         std::sync::Arc::new(new)
                     });
                     log::info!("Keybindings reloaded");
@@ -2,6 +2,7 @@
 //!
 //! This module handles all keyboard input routing:
 //! - `handle_key_event`: main key dispatch entry point (this file)
+//! - `fire_key_repeats`: replays held keys configured for app-level repeat
 //! - `scroll`: PageUp/PageDown, Home/End, mark navigation
 //! - `config_reload`: F5 config reload + `reload_config`
 //! - `clipboard`: clipboard history, paste special, `paste_text`
@@ -23,14 +24,60 @@ mod ui_toggles;
 mod utility;
 
 use crate::app::window_state::WindowState;
+use crate::keybindings::{KeybindingMode, LEADER_ACTION, SequenceMatch};
 use std::sync::Arc;
 use winit::event::ElementState;
 use winit::event::KeyEvent;
 use winit::event_loop::ActiveEventLoop;
 use winit::keyboard::{Key, NamedKey};
 
+/// Whether `key` is a bare modifier press, which must not break off a chord
+/// when it is re-pressed between the chord's keys.
+fn is_modifier_key(key: &Key) -> bool {
+    matches!(
+        key,
+        Key::Named(
+            NamedKey::Control
+                | NamedKey::Shift
+                | NamedKey::Alt
+                | NamedKey::AltGraph
+                | NamedKey::Super
+                | NamedKey::Meta
+                | NamedKey::Hyper
+        )
+    )
+}
+
 impl WindowState {
     pub(crate) fn handle_key_event(&mut self, event: KeyEvent, event_loop: &ActiveEventLoop) {
+        // Keys in `key_repeat_keys` repeat at par-term's own rate: drop the OS
+        // repeats and let `fire_key_repeats` replay the press instead.
+        if self.input_handler.key_repeat.key_event(
+            &event.logical_key,
+            event.state == ElementState::Pressed,
+            event.repeat,
+            &event,
+            std::time::Instant::now(),
+        ) {
+            return;
+        }
+
+        self.dispatch_key_event(event, event_loop);
+    }
+
+    /// Replay held keys whose app-level repeat is due.
+    pub(crate) fn fire_key_repeats(&mut self, event_loop: &ActiveEventLoop) {
+        let Some((mut event, count)) = self.input_handler.key_repeat.due(std::time::Instant::now())
+        else {
+            return;
+        };
+        event.repeat = true;
+        for _ in 0..count {
+            self.dispatch_key_event(event.clone(), event_loop);
+        }
+    }
+
+    fn dispatch_key_event(&mut self, event: KeyEvent, event_loop: &ActiveEventLoop) {
         // Synthesize modifier state from physical key events.  On Windows, WM_NCACTIVATE can
         // cause ModifiersChanged(empty) without a matching WM_KILLFOCUS, leaving modifier state
         // permanently zeroed until the key is re-pressed.  Synthesizing here ensures the state
@@ -81,7 +128,7 @@ impl WindowState {
         let is_running = if let Some(tab) = self.tab_manager.active_tab() {
             // Tmux-managed tabs (gateway and display) have no local PTY process —
             // panes are created via Pane::new_for_tmux() which does not spawn a shell,
-            // so PtySession::running is initialized to false. Never treat these as
+            // so PtyHost::running is initialized to false. Never treat these as
             // exited; the actual process is the remote tmux session.
             if tab.tmux.tmux_gateway_active || tab.tmux.tmux_pane_id.is_some() {
                 true
@@ -158,33 +205,61 @@ impl WindowState {
         }
 
         // Check user-defined keybindings first (before hardcoded shortcuts)
-        if event.state == ElementState::Pressed
-            && let Some(action) = self.keybinding_registry.lookup_with_options(
+        if event.state == ElementState::Pressed && !is_modifier_key(&event.logical_key) {
+            let config = self.config.load();
+            let lookup = self.keybinding_registry.lookup_sequence(
                 &event,
                 &self.input_handler.modifiers,
-                &self.config.load().modifier_remapping,
-                self.config.load().use_physical_keys,
-            )
-        {
-            crate::debug_info!(
-                "KEYBINDING",
-                "Keybinding matched: action={}, key={:?}, modifiers={:?}",
-                action,
-                event.logical_key,
-                self.input_handler.modifiers
+                &config.modifier_remapping,
+                config.use_physical_keys,
+                KeybindingMode::Normal,
+                std::time::Instant::now(),
             );
-            // Clone to avoid borrow conflict
-            let action = action.to_string();
-            if self.execute_keybinding_action(&action) {
-                return; // Key was handled by user-defined keybinding
+            // A chord prefix that is also a binding runs when the next key
+            // breaks the chord off, before that key is handled
+            if let Some(action) = self.keybinding_registry.take_interrupted_fallback() {
+                crate::debug_info!("KEYBINDING", "Chord broken off, running action={}", action);
+                self.execute_keybinding_action(&action);
+            }
+            match lookup {
+                SequenceMatch::Matched(action) if action == LEADER_ACTION => {
+                    crate::debug_info!(
+                        "KEYBINDING",
+                        "Leader key consumed key={:?}, armed={}",
+                        event.logical_key,
+                        self.keybinding_registry.is_leader_armed()
+                    );
+                    return; // Leader key (or Escape cancelling it), don't send to terminal
+                }
+                SequenceMatch::Matched(action) => {
+                    crate::debug_info!(
+                        "KEYBINDING",
+                        "Keybinding matched: action={}, key={:?}, modifiers={:?}",
+                        action,
+                        event.logical_key,
+                        self.input_handler.modifiers
+                    );
+                    if self.execute_keybinding_action(&action) {
+                        return; // Key was handled by user-defined keybinding
+                    }
+                }
+                SequenceMatch::Pending => {
+                    crate::debug_info!(
+                        "KEYBINDING",
+                        "Chord pending after key={:?}, waiting for next key",
+                        event.logical_key
+                    );
+                    return; // Key is part of a chord, don't send to terminal
+                }
+                SequenceMatch::NoMatch => {
+                    crate::debug_log!(
+                        "KEYBINDING",
+                        "No keybinding match for key={:?}, modifiers={:?}",
+                        event.logical_key,
+                        self.input_handler.modifiers
+                    );
+                }
             }
-        } else if event.state == ElementState::Pressed {
-            crate::debug_log!(
-                "KEYBINDING",
-                "No keybinding match for key={:?}, modifiers={:?}",
-                event.logical_key,
-                self.input_handler.modifiers
-            );
         }
 
         // Check if this is a scroll navigation key
@@ -293,7 +368,7 @@ impl WindowState {
 
             if is_paste {
                 if let Some(text) = self.input_handler.paste_from_clipboard() {
-                    let text = crate::paste_transform::sanitize_paste_content(&text);
+                    let text = crate::paste_transform::prepare_paste(&text, &self.config.load());
                     log::debug!("Paste: got {} chars of text from clipboard", text.len());
                     if let Some(tab) = self.tab_manager.active_tab() {
                         let terminal_clone = Arc::clone(&tab.terminal);
@@ -152,7 +152,8 @@ impl WindowState {
                         if let Some(text) = self.input_handler.paste_from_primary_selection()
                             && let Some(tab) = self.tab_manager.active_tab()
                         {
-                            let text = crate::paste_transform::sanitize_paste_content(&text);
+                            let text =
+                                crate::paste_transform::prepare_paste(&text, &self.config.load());
 
                             // Route paste to the focused pane's terminal and compute
                             // pane-local click coordinates for the mouse-tracking focus-click.
@@ -227,13 +228,16 @@ impl WindowState {
         suppress_terminal_mouse_click: bool,
     ) {
         // --- 2. URL Clicking ---
-        // Check for modifier+Click on URL to open it in default browser
-        // macOS: Cmd+Click (matches iTerm2 and system conventions)
-        // Windows/Linux: Ctrl+Click (matches platform conventions)
-        #[cfg(target_os = "macos")]
-        let url_modifier_pressed = self.input_handler.modifiers.state().super_key();
-        #[cfg(not(target_os = "macos"))]
-        let url_modifier_pressed = self.input_handler.modifiers.state().control_key();
+        // Check for modifier+Click on URL to open it in default browser.
+        // The modifier comes from `hyperlink_click_modifier`; the default is
+        // Cmd+Click on macOS (matches iTerm2) and Ctrl+Click elsewhere.
+        let modifiers = self.input_handler.modifiers.state();
+        let url_modifier_pressed = self.config.load().hyperlink_click_modifier.opens_link(
+            modifiers.control_key(),
+            modifiers.alt_key(),
+            modifiers.shift_key(),
+            modifiers.super_key(),
+        );
 
         // Use pane-local coordinates when split panes are active so the col/row
         // match the URL positions detected from the focused pane's terminal.
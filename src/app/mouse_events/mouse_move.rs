@@ -1,8 +1,10 @@
 use crate::app::window_state::WindowState;
-use crate::selection::{Selection, SelectionMode};
+use crate::selection::{Selection, SelectionMode, is_past_drag_threshold};
 use crate::ui_constants::DRAG_THRESHOLD_PX;
-use crate::url_detection;
+use crate::url_detection::{self, DetectedUrl};
+use par_term_terminal::HyperlinkInfo;
 use std::sync::Arc;
+use std::time::Instant;
 
 impl WindowState {
     pub(crate) fn handle_mouse_move(&mut self, position: (f64, f64)) {
@@ -18,6 +20,9 @@ impl WindowState {
         // Notify status bar of mouse activity (for auto-hide timer)
         self.status_bar_ui.on_mouse_activity();
 
+        // Pane focus follows mouse (after the hover delay)
+        self.update_pane_hover_focus(true);
+
         // Check if profile drawer is open - let egui handle mouse events
         if self.overlay_ui.profile_drawer_ui.expanded {
             self.clear_url_hover_if_needed();
@@ -90,6 +95,7 @@ impl WindowState {
                         tab.active_mouse_mut().hovered_url_bounds =
                             Some((url.row, url.start_col, url.end_col));
                     }
+                    self.report_hovered_hyperlink(Some(url));
                     if let Some(window) = &self.window {
                         // Visual feedback: hand pointer + URL tooltip in title
                         window.set_cursor(winit::window::CursorIcon::Pointer);
@@ -104,6 +110,7 @@ impl WindowState {
                     tab.active_mouse_mut().hovered_url = None;
                     tab.active_mouse_mut().hovered_url_bounds = None;
                 }
+                self.report_hovered_hyperlink(None);
                 if let Some(window) = &self.window {
                     window.set_cursor(winit::window::CursorIcon::Text);
                     // Restore terminal-controlled title or config default
@@ -185,10 +192,8 @@ impl WindowState {
                     let suppress_drag = button == 32
                         && match tracking_press {
                             None => true,
-                            Some((px, py)) => {
-                                let dx = position.0 - px;
-                                let dy = position.1 - py;
-                                (dx * dx + dy * dy) < DRAG_THRESHOLD_PX * DRAG_THRESHOLD_PX
+                            Some(press) => {
+                                !is_past_drag_threshold(press, position, DRAG_THRESHOLD_PX)
                             }
                         };
 
@@ -375,16 +380,13 @@ impl WindowState {
             && button_pressed
             && (!alt_screen_active || shift_held)
         {
-            // Minimum pixel distance before a click becomes a drag selection.
-            // Prevents accidental micro-drags (e.g. trackpad taps) from creating
-            // tiny selections that overwrite clipboard content (including images).
-            // Slightly larger dead zone to avoid accidental selection starts from
-            // trackpad jitter / tap-to-click movement noise.
-            let past_drag_threshold = click_pixel_position.is_some_and(|(cx, cy)| {
-                let dx = position.0 - cx;
-                let dy = position.1 - cy;
-                (dx * dx + dy * dy) >= DRAG_THRESHOLD_PX * DRAG_THRESHOLD_PX
-            });
+            // Minimum pixel distance before a click becomes a drag selection
+            // (`selection_drag_threshold_px`). Prevents accidental micro-drags
+            // (e.g. trackpad taps) from creating tiny selections that overwrite
+            // clipboard content (including images).
+            let threshold = f64::from(self.config.load().mouse.selection_drag_threshold_px);
+            let past_drag_threshold = click_pixel_position
+                .is_some_and(|press| is_past_drag_threshold(press, position, threshold));
 
             if click_count == 1
                 && !is_selecting
@@ -431,6 +433,70 @@ impl WindowState {
         }
     }
 
+    /// Focus the split pane under the mouse once it has been hovered for
+    /// `pane_focus_hover_delay_ms` (`pane_focus_follows_mouse`).
+    ///
+    /// `moved` is false for the wake-up in `about_to_wait`, which only
+    /// completes a switch a mouse move started.  Nothing switches while a
+    /// button is held (selection, divider drag) or a drawer has the mouse.
+    pub(crate) fn update_pane_hover_focus(&mut self, moved: bool) {
+        let (enabled, delay) = {
+            let config = self.config.load();
+            (
+                config.mouse.pane_focus_follows_mouse,
+                std::time::Duration::from_millis(config.mouse.pane_focus_hover_delay_ms),
+            )
+        };
+        let candidate = self.tab_manager.active_tab().and_then(|tab| {
+            let mouse = tab.active_mouse();
+            let busy = mouse.button_pressed || mouse.dragging_divider.is_some();
+            let pm = tab
+                .pane_manager
+                .as_ref()
+                .filter(|_| tab.has_multiple_panes());
+            match pm {
+                Some(pm) if !busy => {
+                    let (x, y) = mouse.position;
+                    Some((
+                        pm.pane_id_at(x as f32, y as f32),
+                        pm.focused_pane_id(),
+                        mouse.position,
+                    ))
+                }
+                _ => None,
+            }
+        });
+        let Some((hovered, focused, position)) = candidate.filter(|_| {
+            enabled && self.focus_state.is_focused && !self.overlay_ui.profile_drawer_ui.expanded
+        }) else {
+            self.focus_state.pane_hover_focus.cancel();
+            return;
+        };
+
+        let now = std::time::Instant::now();
+        let hover = &mut self.focus_state.pane_hover_focus;
+        let target = if moved {
+            hover.update(hovered, focused, now, delay)
+        } else {
+            hover.poll(hovered, focused, now, delay)
+        };
+        let Some(pane_id) = target else {
+            return;
+        };
+
+        if let Some(tab) = self.tab_manager.active_tab_mut()
+            && let Some(pm) = tab.pane_manager.as_mut()
+        {
+            pm.focus_pane(pane_id);
+            // The new pane's mouse state has not seen this position yet
+            tab.active_mouse_mut().position = position;
+        }
+        log::debug!("Focused pane {} via mouse hover", pane_id);
+        self.set_tmux_focused_pane_from_native(pane_id);
+        self.set_scroll_target(0);
+        self.focus_state.needs_redraw = true;
+    }
+
     /// Clear URL hover state (hovered_url, cursor, title) if a URL is currently hovered.
     ///
     /// Called before early returns that skip the main URL detection block (e.g. tab bar,
@@ -455,6 +521,7 @@ impl WindowState {
             tab.active_mouse_mut().hovered_url = None;
             tab.active_mouse_mut().hovered_url_bounds = None;
         }
+        self.report_hovered_hyperlink(None);
         if let Some(window) = &self.window {
             window.set_cursor(winit::window::CursorIcon::Text);
             if self.config.load().allow_title_change && !terminal_title.is_empty() {
@@ -464,4 +531,24 @@ impl WindowState {
             }
         }
     }
+
+    /// Tell the active tab's terminal which link is under the mouse, for the
+    /// `hyperlink_hover` script event.
+    fn report_hovered_hyperlink(&self, url: Option<&DetectedUrl>) {
+        let Some(tab) = self.tab_manager.active_tab() else {
+            return;
+        };
+        let link = url.map(|url| HyperlinkInfo {
+            url: url.url.clone(),
+            positions: (url.start_col..url.end_col)
+                .map(|col| (col, url.row))
+                .collect(),
+            id: None,
+        });
+        // try_lock: acceptable — hover reporting is best effort and the next
+        // mouse move reports again.
+        if let Ok(term) = tab.terminal.try_read() {
+            term.set_hovered_hyperlink(link, Instant::now());
+        }
+    }
 }
@@ -77,7 +77,7 @@ impl WindowState {
                 } else {
                     // Non-tmux path: write directly to the local PTY.
                     // read().await (not write().await): TerminalManager::write() takes &self,
-                    // mutation happens behind the inner parking_lot::Mutex<PtySession>.
+                    // mutation happens behind the inner parking_lot::Mutex<PtyHost>.
                     let terminal_clone = Arc::clone(&terminal_arc);
                     let runtime = Arc::clone(&self.runtime);
                     runtime.spawn(async move {
@@ -2,6 +2,9 @@ use crate::app::window_state::WindowState;
 use std::sync::Arc;
 use winit::event::MouseScrollDelta;
 
+/// Pixels of trackpad movement per line before the renderer knows its cell size.
+const WHEEL_PIXELS_PER_LINE: f64 = 20.0;
+
 impl WindowState {
     pub(crate) fn handle_mouse_wheel(&mut self, delta: MouseScrollDelta) {
         // Check if profile drawer is open - let egui handle scroll events
@@ -55,11 +58,8 @@ impl WindowState {
         let is_mouse_tracking = mouse_tracking_state.unwrap_or(false);
 
         if is_mouse_tracking && let Some(terminal_arc) = terminal_for_tracking {
-            // Calculate scroll amounts based on delta type (Line vs Pixel)
-            let (scroll_x, scroll_y) = match delta {
-                MouseScrollDelta::LineDelta(x, y) => (x as i32, y as i32),
-                MouseScrollDelta::PixelDelta(pos) => ((pos.x / 20.0) as i32, (pos.y / 20.0) as i32),
-            };
+            // One reported event per wheel notch or line's worth of pixels
+            let (scroll_x, scroll_y) = self.wheel_steps(delta, 1.0);
 
             // Get mouse position from active tab
             let mouse_position = self
@@ -136,12 +136,8 @@ impl WindowState {
 
         // --- 2. Local Scrolling ---
         // Normal behavior: scroll through the local scrollback buffer.
-        let scroll_lines = match delta {
-            MouseScrollDelta::LineDelta(_x, y) => {
-                (y * self.config.load().mouse.mouse_scroll_speed) as i32
-            }
-            MouseScrollDelta::PixelDelta(pos) => (pos.y / 20.0) as i32,
-        };
+        let lines_per_notch = f64::from(self.config.load().mouse.mouse_scroll_speed);
+        let (_, scroll_lines) = self.wheel_steps(delta, lines_per_notch);
 
         let scrollback_len = self.get_active_scrollback_len();
 
@@ -157,6 +153,42 @@ impl WindowState {
         self.set_scroll_target(new_target);
     }
 
+    /// Whole-line scroll steps `(x, y)` for a wheel delta, positive up/left.
+    ///
+    /// Wheel notches count `lines_per_notch` lines each; trackpad pixel
+    /// deltas count a line per cell height, so they track the finger.
+    /// `natural_scroll_direction` flips both axes.  Fractions of a line carry
+    /// over to the next delta in the active pane's scroll state.
+    fn wheel_steps(&mut self, delta: MouseScrollDelta, lines_per_notch: f64) -> (i32, i32) {
+        let sign = if self.config.load().mouse.natural_scroll_direction {
+            -1.0
+        } else {
+            1.0
+        };
+        let line_height = self
+            .renderer
+            .as_ref()
+            .map_or(WHEEL_PIXELS_PER_LINE, |r| f64::from(r.cell_height()));
+        let Some(tab) = self.tab_manager.active_tab_mut() else {
+            return (0, 0);
+        };
+        let scroll = tab.active_scroll_state_mut();
+        match delta {
+            MouseScrollDelta::LineDelta(x, y) => (
+                scroll
+                    .wheel_x
+                    .add_lines(sign * f64::from(x) * lines_per_notch),
+                scroll
+                    .wheel_y
+                    .add_lines(sign * f64::from(y) * lines_per_notch),
+            ),
+            MouseScrollDelta::PixelDelta(pos) => (
+                scroll.wheel_x.add_pixels(sign * pos.x, line_height),
+                scroll.wheel_y.add_pixels(sign * pos.y, line_height),
+            ),
+        }
+    }
+
     /// Set scroll target and initiate smooth interpolation animation.
     pub(crate) fn set_scroll_target(&mut self, new_offset: usize) {
         let target_set = if let Some(tab) = self.tab_manager.active_tab_mut() {
@@ -173,6 +173,47 @@ pub(super) fn render_scrollbar_mark_tooltip(ctx: &egui::Context, mark: Option<&S
         });
 }
 
+/// Render the full target of the hovered link just below the mouse pointer.
+///
+/// Link text on screen is often a shortened label (OSC 8) or cut off by the
+/// window edge, so the tooltip shows the whole URL or path, wrapped when it is
+/// wider than the window allows.  Pass `None` to skip.
+pub(super) fn render_hyperlink_tooltip(ctx: &egui::Context, url: Option<&str>) {
+    let Some(url) = url else {
+        return;
+    };
+    let Some(mouse_pos) = ctx.pointer_hover_pos() else {
+        return;
+    };
+
+    let screen = ctx.content_rect();
+    let max_width = (screen.width() - 40.0).clamp(100.0, 600.0);
+    // Below the pointer so the link itself stays visible
+    let tooltip_x = mouse_pos.x.min(screen.right() - max_width - 20.0).max(10.0);
+    let tooltip_y = mouse_pos.y + 20.0;
+
+    egui::Area::new(egui::Id::new("hyperlink_tooltip"))
+        .order(egui::Order::Tooltip)
+        .fixed_pos(egui::pos2(tooltip_x, tooltip_y))
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::NONE
+                .fill(egui::Color32::from_rgba_unmultiplied(30, 30, 30, 240))
+                .inner_margin(egui::Margin::same(6))
+                .corner_radius(4.0)
+                .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(80, 80, 80)))
+                .show(ui, |ui| {
+                    ui.set_max_width(max_width);
+                    ui.style_mut().visuals.override_text_color =
+                        Some(egui::Color32::from_rgb(220, 220, 220));
+                    ui.add(
+                        egui::Label::new(egui::RichText::new(url).monospace().size(12.0))
+                            .wrap_mode(egui::TextWrapMode::Wrap),
+                    );
+                });
+        });
+}
+
 /// Render the copy-mode status bar overlay pinned to the bottom-left of the window.
 ///
 /// Shows the current copy-mode type (COPY / VISUAL / V-LINE / V-BLOCK / SEARCH) and
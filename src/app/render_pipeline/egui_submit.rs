@@ -16,6 +16,8 @@ use crate::progress_bar::{ProgressBarSnapshot, render_progress_bars};
 pub(super) struct RenderEguiParams<'a> {
     pub(super) actions: &'a mut PostRenderActions,
     pub(super) hovered_mark: &'a Option<crate::scrollback_metadata::ScrollbackMark>,
+    /// Full target of the link under the mouse, when its tooltip is enabled
+    pub(super) hovered_link: Option<&'a str>,
     pub(super) window_size_for_badge: Option<&'a winit::dpi::PhysicalSize<u32>>,
     pub(super) progress_snapshot: &'a Option<ProgressBarSnapshot>,
     pub(super) visible_lines: usize,
@@ -37,6 +39,7 @@ impl WindowState {
         let RenderEguiParams {
             actions,
             hovered_mark,
+            hovered_link,
             window_size_for_badge,
             progress_snapshot,
             visible_lines,
@@ -312,6 +315,9 @@ impl WindowState {
                     // Scrollbar mark tooltip (near mouse pointer)
                     egui_overlays::render_scrollbar_mark_tooltip(ctx, hovered_mark.as_ref());
 
+                    // Hovered link target (near mouse pointer)
+                    egui_overlays::render_hyperlink_tooltip(ctx, hovered_link);
+
                     // Render tab bar if visible (action handled after closure)
                     let tab_bar_right_reserved = if self.overlay_ui.ai_inspector.open {
                         self.overlay_ui.ai_inspector.consumed_width()
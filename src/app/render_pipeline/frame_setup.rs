@@ -70,10 +70,12 @@ impl WindowState {
             .last_tab_title_refresh
             .is_none_or(|last| now.duration_since(last) >= Self::TAB_TITLE_REFRESH_INTERVAL);
         if should_refresh_titles {
+            let config = self.config.load();
             self.tab_manager.update_all_titles(
-                self.config.load().tab_title_mode,
-                self.config.load().remote_tab_title_format,
-                self.config.load().remote_tab_title_osc_priority,
+                config.tab_title_mode,
+                config.remote_tab_title_format,
+                config.remote_tab_title_osc_priority,
+                &config.tab_title_template,
             );
             self.render_loop.last_tab_title_refresh = Some(now);
         }
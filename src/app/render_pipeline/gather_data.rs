@@ -84,6 +84,7 @@ impl WindowState {
             cache_grid_dims,
             terminal: terminal.clone(),
             was_alt_screen,
+            cursor: self.tab_manager.active_cursor_override(),
         })?;
 
         let mut cells = snap.cells;
@@ -134,7 +135,7 @@ impl WindowState {
             pane.cache.pane_cells_grid_dims = cell_grid_dims;
         }
 
-        let mut show_scrollbar = self.should_show_scrollbar();
+        let mut show_scrollbar = self.update_scrollbar_visibility();
 
         let (scrollback_len, terminal_title, shell_lifecycle_events) = self
             .collect_scrollback_state(
@@ -154,6 +155,22 @@ impl WindowState {
             self.play_alert_sound(crate::config::AlertEvent::CommandComplete);
         }
 
+        // Queue a split when a configured long-running command starts.
+        {
+            let config = self.config.load();
+            if let Some(entry) = shell_lifecycle_events.iter().find_map(|e| match e {
+                par_term_terminal::ShellLifecycleEvent::CommandStarted { command, .. } => {
+                    crate::pane::split_on_command::matching_split_command(
+                        &config.split_on_commands,
+                        command,
+                    )
+                }
+                _ => None,
+            }) {
+                self.pending_command_split = Some(entry.to_string());
+            }
+        }
+
         // Update cache scrollback and clamp scroll state.
         //
         // In pane mode the focused pane's own terminal holds the scrollback, not
@@ -196,9 +213,13 @@ impl WindowState {
         // and visually indistinguishable from marks that belong to a different tab.
         // In multi-pane mode, `scrollback_len` comes from tab.terminal which may
         // differ from the focused pane's terminal; skip this override and let the
-        // per-pane scrollbar logic (should_show_scrollbar) handle it.
+        // per-pane scrollbar logic (update_scrollbar_visibility) handle it.
         if marks_override_scrollbar && scrollback_len > 0 && !has_multiple_panes {
             show_scrollbar = true;
+            if let Some(renderer) = &mut self.renderer {
+                renderer.record_scrollbar_activity(std::time::Instant::now());
+                renderer.apply_scrollbar_fade();
+            }
         }
 
         // Update window title if terminal has set one via OSC sequences.
@@ -212,7 +212,18 @@ impl WindowState {
                         &term,
                         visible_lines,
                     );
-                self.overlay_ui.search_ui.update_search(lines_iter);
+                let command_output = match self.overlay_ui.search_ui.scope() {
+                    crate::search::SearchScope::Command => term.current_command_output_lines(),
+                    _ => None,
+                };
+                let scope_lines = self.overlay_ui.search_ui.scope().line_range(
+                    term.scrollback_len(),
+                    visible_lines,
+                    command_output,
+                );
+                self.overlay_ui
+                    .search_ui
+                    .update_search(lines_iter, scope_lines);
             }
 
             // Force GPU cell update when search is visible: highlights are applied to
@@ -195,6 +195,20 @@ impl WindowState {
             }
         }
 
+        // Capture the hovered tab's preview before the tab bar is drawn.
+        self.update_tab_hover_preview();
+
+        // Full target of the hovered link for its tooltip. The window title
+        // shows it too, and title updates stay suppressed while it is hovered
+        // (see `update_window_title_if_changed`).
+        let hovered_link = if self.config.load().link_hover_tooltip && !any_modal_visible {
+            self.tab_manager
+                .active_tab()
+                .and_then(|tab| tab.active_mouse().hovered_url.clone())
+        } else {
+            None
+        };
+
         // =====================================================================
         // Phase 3: egui overlay rendering
         //
@@ -205,6 +219,7 @@ impl WindowState {
             self.render_egui_frame(RenderEguiParams {
                 actions: &mut actions,
                 hovered_mark: &gpu.hovered_mark,
+                hovered_link: hovered_link.as_deref(),
                 window_size_for_badge: window_size_for_badge.as_ref(),
                 progress_snapshot: &progress_snapshot,
                 visible_lines,
@@ -349,6 +364,7 @@ impl WindowState {
                                 [c[0], c[1], c[2], 255],
                                 self.config.load().link_highlight_color_enabled,
                                 self.config.load().link_highlight_underline,
+                                self.config.load().osc8_links_hover_only,
                             ))
                         });
 
@@ -379,6 +395,7 @@ impl WindowState {
                                         url_color,
                                         do_color,
                                         do_underline,
+                                        osc8_hover_only,
                                     )) = url_overlay
                                     {
                                         super::overlay_cells::apply_url_overlays_to_cells(
@@ -391,6 +408,7 @@ impl WindowState {
                                                 url_color,
                                                 do_color,
                                                 do_underline,
+                                                osc8_hover_only,
                                             },
                                         );
                                     }
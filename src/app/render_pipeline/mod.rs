@@ -7,6 +7,7 @@
 //! - `gpu_submit`: `submit_gpu_frame` — egui + wgpu render pass, returns `PostRenderActions`
 //! - `post_render`: `update_post_render_state` — dispatch post-render action queue
 //! - `pane_render`: `gather_pane_render_data` + `render_split_panes_with_data` + `PaneRenderData`
+//! - `tab_preview`: `update_tab_hover_preview` — offscreen capture for tab bar hover previews
 //! - `egui_overlays`: standalone egui overlay renderers (FPS, resize, toast, tooltip, pane-id)
 //! - `types`: shared data-transfer types (`RendererSizing`, `FrameRenderData`, `PostRenderActions`)
 
@@ -20,6 +21,7 @@ mod overlay_cells;
 mod pane_render;
 mod post_render;
 mod renderer_ops;
+mod tab_preview;
 mod tab_snapshot;
 mod types;
 mod viewport;
@@ -65,6 +67,7 @@ impl WindowState {
 
         let t_post = std::time::Instant::now();
         self.update_post_render_state(actions);
+        self.process_pending_command_split();
         self.process_pending_config_save();
         let post_ms = t_post.elapsed().as_millis();
 
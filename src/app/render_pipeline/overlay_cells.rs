@@ -24,6 +24,8 @@ pub(super) struct UrlOverlayParams<'a> {
     pub(super) do_color: bool,
     /// Whether to underline detected URL cells.
     pub(super) do_underline: bool,
+    /// Leave OSC 8 hyperlinks unstyled unless they are hovered.
+    pub(super) osc8_hover_only: bool,
 }
 
 /// Apply URL underline/hover styling to the final pane cell buffer.
@@ -37,6 +39,7 @@ pub(super) fn apply_url_overlays_to_cells(params: UrlOverlayParams<'_>) {
         url_color,
         do_color,
         do_underline,
+        osc8_hover_only,
     } = params;
 
     if detected_urls.is_empty() || cols == 0 {
@@ -49,6 +52,9 @@ pub(super) fn apply_url_overlays_to_cells(params: UrlOverlayParams<'_>) {
         }
         let viewport_row = url.row - url_scroll_offset;
         let is_hovered = hovered_bounds == Some((url.row, url.start_col, url.end_col));
+        if osc8_hover_only && url.hyperlink_id.is_some() && !is_hovered {
+            continue;
+        }
         for col in url.start_col..url.end_col {
             if col >= cols {
                 break;
@@ -82,6 +88,31 @@ mod tests {
         }
     }
 
+    fn osc8_link(row: usize, start_col: usize, end_col: usize) -> DetectedUrl {
+        DetectedUrl {
+            hyperlink_id: Some(1),
+            ..detected_url(row, start_col, end_col)
+        }
+    }
+
+    fn apply_osc8_hover_only(
+        cells: &mut [crate::cell_renderer::Cell],
+        urls: &[DetectedUrl],
+        hovered_bounds: Option<(usize, usize, usize)>,
+    ) {
+        apply_url_overlays_to_cells(UrlOverlayParams {
+            cells,
+            cols: 10,
+            detected_urls: urls,
+            url_scroll_offset: 0,
+            hovered_bounds,
+            url_color: [1, 2, 3, 255],
+            do_color: true,
+            do_underline: true,
+            osc8_hover_only: true,
+        });
+    }
+
     fn detected_path(row: usize, start_col: usize, end_col: usize) -> DetectedUrl {
         DetectedUrl {
             url: "~/Repos/par-term".to_string(),
@@ -112,6 +143,7 @@ mod tests {
             url_color: [1, 2, 3, 255],
             do_color: true,
             do_underline: true,
+            osc8_hover_only: false,
         });
 
         for (idx, cell) in cells.iter().enumerate().take(6).skip(2) {
@@ -134,6 +166,7 @@ mod tests {
             url_color: [1, 2, 3, 255],
             do_color: false,
             do_underline: true,
+            osc8_hover_only: false,
         });
 
         assert!(cells[11].underline);
@@ -155,9 +188,48 @@ mod tests {
             url_color: [1, 2, 3, 255],
             do_color: true,
             do_underline: true,
+            osc8_hover_only: false,
         });
 
         assert!(cells[2].underline);
         assert_eq!(cells[2].fg_color, [9, 8, 7, 255]);
     }
+
+    #[test]
+    fn osc8_hover_only_leaves_unhovered_links_unstyled() {
+        let mut cells = vec![crate::cell_renderer::Cell::default(); 10];
+        apply_osc8_hover_only(&mut cells, &[osc8_link(0, 2, 6)], None);
+
+        for cell in &cells {
+            assert!(!cell.underline);
+            assert_eq!(
+                cell.fg_color,
+                crate::cell_renderer::Cell::default().fg_color
+            );
+        }
+    }
+
+    #[test]
+    fn osc8_hover_only_styles_hovered_link_span() {
+        let mut cells = vec![crate::cell_renderer::Cell::default(); 10];
+        apply_osc8_hover_only(&mut cells, &[osc8_link(0, 2, 6)], Some((0, 2, 6)));
+
+        for (idx, cell) in cells.iter().enumerate() {
+            let in_span = (2..6).contains(&idx);
+            assert_eq!(cell.underline, in_span, "cell {idx}");
+            if in_span {
+                assert_eq!(cell.fg_color, [1, 2, 3, 255]);
+            }
+        }
+    }
+
+    #[test]
+    fn osc8_hover_only_keeps_regex_urls_underlined() {
+        let mut cells = vec![crate::cell_renderer::Cell::default(); 10];
+        apply_osc8_hover_only(&mut cells, &[detected_url(0, 0, 3)], None);
+
+        assert!(cells[0].underline);
+        assert!(cells[2].underline);
+        assert!(!cells[3].underline);
+    }
 }
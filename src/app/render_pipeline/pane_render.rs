@@ -6,7 +6,7 @@
 //! - `render_split_panes_with_data`: drives the GPU split-pane render pass
 
 use super::types::RendererSizing;
-use crate::cell_renderer::PaneViewport;
+use crate::cell_renderer::{PaneViewport, SelectionRegion};
 use crate::config::{Config, PaneTitlePosition, color_u8_to_f32};
 use crate::renderer::{
     DividerRenderInfo, PaneDividerSettings, PaneRenderInfo, PaneTitleInfo, Renderer,
@@ -40,6 +40,8 @@ pub(super) struct PaneRenderData {
     pub(super) graphics: Vec<par_term_emu_core_rust::graphics::TerminalGraphic>,
     /// Kitty virtual placements (U=1) used for Unicode placeholder rendering.
     pub(super) virtual_placements: Vec<par_term_emu_core_rust::graphics::TerminalGraphic>,
+    /// Selected cells in viewport coordinates (the region baked into `cells`)
+    pub(super) selection: Option<SelectionRegion>,
 }
 
 /// Result of `gather_pane_render_data`.
@@ -117,6 +119,8 @@ pub(super) fn gather_pane_render_data(
     } else {
         1.0
     };
+    let dim_fade_duration = std::time::Duration::from_millis(config.pane_dim_fade_duration);
+    let now = std::time::Instant::now();
 
     // Title settings (all in physical pixels)
     let show_titles = config.show_pane_titles;
@@ -138,6 +142,11 @@ pub(super) fn gather_pane_render_data(
         let is_focused = Some(*pane_id) == focused_pane_id;
         let bounds = pane.bounds;
 
+        // Fade towards the new dim level when focus or the opacity changes;
+        // about_to_wait keeps redrawing while the fade runs.
+        let dim_target = if is_focused { 1.0 } else { inactive_opacity };
+        pane.dim_fade.set_target(dim_target, now, dim_fade_duration);
+
         // Viewport y and height accounting for title bar position
         let (viewport_y, viewport_height) = if show_titles {
             match title_position {
@@ -187,11 +196,7 @@ pub(super) fn gather_pane_render_data(
             bounds.width,
             viewport_height,
             is_focused,
-            if is_focused {
-                pane_bg_opacity
-            } else {
-                pane_bg_opacity * inactive_opacity
-            },
+            pane_bg_opacity * pane.dim_fade.level(now),
             physical_pane_padding,
         );
         viewport.content_offset_x = center_offset_x;
@@ -308,6 +313,16 @@ pub(super) fn gather_pane_render_data(
             Arc::new(Vec::new())
         };
 
+        // The renderer rounds the outer corners of the selection baked into the cells
+        let selection = current_selection.map(|sel| {
+            let (start, end) = sel.viewport_adjusted(scroll_offset).normalized();
+            SelectionRegion {
+                start,
+                end,
+                rectangular: sel.mode == SelectionMode::Rectangular,
+            }
+        });
+
         // Gather marks and scrollback length — use cached scrollback_len on lock miss
         let (marks, pane_scrollback_len) = if need_marks {
             if let Ok(mut term) = pane.terminal.try_write() {
@@ -426,6 +441,7 @@ pub(super) fn gather_pane_render_data(
             background: pane_background,
             graphics: pane_graphics,
             virtual_placements: pane_virtual_placements,
+            selection,
         });
     }
 
@@ -502,6 +518,7 @@ impl crate::app::window_state::WindowState {
                 background: pane.background,
                 graphics: pane.graphics,
                 virtual_placements: pane.virtual_placements,
+                selection: pane.selection,
             });
         }
 
@@ -99,6 +99,11 @@ pub(super) fn update_gpu_renderer_state(
         debug_update_cells_time = t.elapsed();
     }
 
+    // Resolve the cursor color through the active tab's profile, so switching to
+    // or closing a profile tab recolors the cursor on the next frame.
+    let cursor_color = tab_manager.active_cursor_override().color(&config.cursor);
+    renderer.update_cursor_color(cursor_color);
+
     // Update cursor position and style for geometric rendering.
     if let (Some(pos), Some(opacity), Some(style)) = (
         current_cursor_pos,
@@ -114,7 +119,7 @@ pub(super) fn update_gpu_renderer_state(
     // visibility. Alt-screen apps may hide the terminal cursor while still moving it;
     // shader uniforms should continue tracking iCurrentCursor in that case.
     if let (Some(pos), Some(style)) = (shader_cursor_pos, shader_cursor_style) {
-        let cursor_color = color_u8_to_f32_a(config.cursor.cursor_color, 1.0);
+        let cursor_color = color_u8_to_f32_a(cursor_color, 1.0);
         let shader_opacity = if current_cursor_pos.is_some() {
             cursor_anim.cursor_opacity
         } else {
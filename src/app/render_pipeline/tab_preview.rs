@@ -0,0 +1,68 @@
+//! Tab bar hover previews.
+//!
+//! `update_tab_hover_preview` runs before the egui pass each frame.  When
+//! `tab_hover_preview` is enabled and the mouse is over an inactive tab, it
+//! has the renderer capture that tab's focused pane offscreen (the renderer
+//! caches captures per tab and only redraws when the terminal's generation
+//! moves on) and hands the result to the tab bar, which shows it next to the
+//! tab.
+
+use crate::app::window_state::WindowState;
+use crate::renderer::TabPreviewParams;
+
+impl WindowState {
+    /// Capture the hovered inactive tab for the tab bar preview and free the
+    /// previews of closed tabs.
+    pub(super) fn update_tab_hover_preview(&mut self) {
+        self.tab_bar_ui.hover_preview = None;
+        let Some(renderer) = self.renderer.as_mut() else {
+            return;
+        };
+        renderer.retain_tab_previews(|id| self.tab_manager.get_tab(id).is_some());
+
+        if !self.config.load().tab_hover_preview {
+            return;
+        }
+        let Some(tab_id) = self.tab_bar_ui.hovered_tab else {
+            return;
+        };
+        if self.tab_manager.active_tab_id() == Some(tab_id) {
+            return;
+        }
+        let Some(tab) = self.tab_manager.get_tab(tab_id) else {
+            return;
+        };
+        let terminal = tab
+            .pane_manager
+            .as_ref()
+            .and_then(|pm| pm.focused_pane())
+            .map(|pane| pane.terminal.clone())
+            .unwrap_or_else(|| tab.terminal.clone());
+
+        // try_lock: acceptable — a busy terminal keeps its previous preview
+        // until a later frame.
+        let captured = terminal.try_read().ok().and_then(|term| {
+            let generation = term.update_generation();
+            if !renderer.tab_preview_needs_capture(tab_id, generation) {
+                return renderer.tab_preview(tab_id);
+            }
+            let (cols, rows) = term.dimensions();
+            let cells = term.try_get_cells_with_scrollback(0, None, false)?;
+            renderer
+                .capture_tab_preview(TabPreviewParams {
+                    tab: tab_id,
+                    generation,
+                    cells: &cells,
+                    cols,
+                    rows,
+                })
+                .unwrap_or_else(|e| {
+                    log::warn!("Failed to capture tab preview: {}", e);
+                    None
+                })
+        });
+        self.tab_bar_ui.hover_preview = captured
+            .or_else(|| renderer.tab_preview(tab_id))
+            .map(|image| (tab_id, image));
+    }
+}
@@ -7,8 +7,9 @@
 //! the tab is needed after the lock is released.
 
 use crate::app::window_state::WindowState;
-use crate::config::CursorStyle;
 use crate::selection::SelectionMode;
+use crate::tab::term_cursor_style;
+use par_term_config::CursorOverride;
 use par_term_emu_core_rust::cursor::CursorStyle as TermCursorStyle;
 use std::sync::Arc;
 
@@ -25,6 +26,8 @@ pub(super) struct TabCellsParams {
     pub terminal: Arc<tokio::sync::RwLock<par_term_terminal::TerminalManager>>,
     /// Previous frame's alt-screen state (used as fallback when terminal is locked).
     pub was_alt_screen: bool,
+    /// Cursor settings of the tab's profile, applied over the global ones.
+    pub cursor: CursorOverride,
 }
 
 /// Data returned by `extract_tab_cells`.
@@ -85,6 +88,7 @@ impl WindowState {
             cache_grid_dims,
             terminal,
             was_alt_screen,
+            cursor,
         } = p;
         if let Ok(term) = terminal.try_read() {
             // Get current generation to check if terminal content has changed
@@ -104,6 +108,8 @@ impl WindowState {
                 (None, false)
             };
 
+            // Restore the primary-screen DECSCUSR style when a full-screen app exits.
+            term.sync_cursor_style_for_screen(self.config.load().cursor.lock_cursor_style);
             let is_alt_screen = term.is_alt_screen_active();
 
             // Get cursor position and opacity (only show the geometric cursor if we're at the
@@ -132,32 +138,23 @@ impl WindowState {
 
             // Get cursor style for geometric rendering.
             // In copy mode, always use SteadyBlock for clear visibility.
-            // If lock_cursor_style is enabled, use the config's cursor style instead of the
-            // terminal's. If lock_cursor_blink is enabled and cursor_blink is false, force steady.
+            // If lock_cursor_style is enabled, use the configured cursor style (the tab's
+            // profile first, then the global one) instead of the terminal's. If
+            // lock_cursor_blink is enabled and blinking is configured off, force steady.
+            let config = self.config.load();
+            let cursor_config = &config.cursor;
             let cursor_style = if self.copy_mode.active && current_cursor_pos.is_some() {
                 Some(TermCursorStyle::SteadyBlock)
             } else if current_cursor_pos.is_some() {
-                if self.config.load().cursor.lock_cursor_style {
-                    let style = if self.config.load().cursor.cursor_blink {
-                        match self.config.load().cursor.cursor_style {
-                            CursorStyle::Block => TermCursorStyle::BlinkingBlock,
-                            CursorStyle::Beam => TermCursorStyle::BlinkingBar,
-                            CursorStyle::Underline => TermCursorStyle::BlinkingUnderline,
-                        }
-                    } else {
-                        match self.config.load().cursor.cursor_style {
-                            CursorStyle::Block => TermCursorStyle::SteadyBlock,
-                            CursorStyle::Beam => TermCursorStyle::SteadyBar,
-                            CursorStyle::Underline => TermCursorStyle::SteadyUnderline,
-                        }
-                    };
-                    Some(style)
+                if cursor_config.lock_cursor_style {
+                    Some(term_cursor_style(
+                        cursor.style(cursor_config),
+                        cursor.blink(cursor_config),
+                    ))
                 } else {
                     let mut style = term.cursor_style();
                     // If blink is locked off, convert blinking styles to steady
-                    if self.config.load().cursor.lock_cursor_blink
-                        && !self.config.load().cursor.cursor_blink
-                    {
+                    if cursor_config.lock_cursor_blink && !cursor.blink(cursor_config) {
                         style = match style {
                             TermCursorStyle::BlinkingBlock => TermCursorStyle::SteadyBlock,
                             TermCursorStyle::BlinkingBar => TermCursorStyle::SteadyBar,
@@ -9,6 +9,7 @@
 //!   pixel dimensions needed at the start of every gather pass.
 
 use crate::app::window_state::WindowState;
+use crate::config::resolve_cursor_shader_config;
 use winit::dpi::PhysicalSize;
 
 impl WindowState {
@@ -24,50 +25,24 @@ impl WindowState {
 
     /// Resolve whether the cursor shader should hide the default terminal cursor.
     ///
-    /// Precedence: per-shader config override → shader metadata defaults → global config.
+    /// Precedence: per-shader config override → shader metadata defaults → global config,
+    /// via the same [`resolve_cursor_shader_config`] used at renderer init.
     /// Returns `true` when the cursor should be hidden for the current shader.
     ///
     /// Requires `&mut self` because `cursor_shader_metadata_cache` is a lazy-loading
     /// cache whose `.get()` method may read from disk on first access.
     pub(super) fn resolve_cursor_shader_hide(&mut self, is_alt_screen: bool) -> bool {
-        // Clone the shader name to an owned String so that the shared borrow on
-        // `self.config` is released before the mutable borrow on
-        // `self.shader_state.shader.cursor_shader_metadata_cache` in the closures below.
         let cfg = self.config.load();
-        let cursor_shader_name: Option<String> = cfg.shader.cursor_shader.clone();
+        if !cfg.shader.cursor_shader_enabled {
+            return false;
+        }
 
-        // hides_cursor: per-shader config override -> metadata defaults -> global config
-        let hides_cursor_from_config = cursor_shader_name
-            .as_deref()
-            .and_then(|name| cfg.cursor_shader_configs.get(name))
-            .and_then(|c| c.hides_cursor);
+        let cursor_shader_name = cfg.shader.cursor_shader.as_deref();
+        let user_override = cursor_shader_name.and_then(|name| cfg.cursor_shader_configs.get(name));
+        let metadata = cursor_shader_name
+            .and_then(|name| self.shader_state.cursor_shader_metadata_cache.get(name));
 
-        let resolved_hides_cursor = hides_cursor_from_config
-            .or_else(|| {
-                cursor_shader_name
-                    .as_deref()
-                    .and_then(|name| self.shader_state.cursor_shader_metadata_cache.get(name))
-                    .and_then(|meta| meta.defaults.hides_cursor)
-            })
-            .unwrap_or(cfg.shader.cursor_shader_hides_cursor);
-
-        // disable_in_alt_screen: per-shader override -> metadata defaults -> global config
-        let disable_in_alt_screen_from_config = cursor_shader_name
-            .as_deref()
-            .and_then(|name| cfg.cursor_shader_configs.get(name))
-            .and_then(|c| c.disable_in_alt_screen);
-
-        let resolved_disable_in_alt_screen = disable_in_alt_screen_from_config
-            .or_else(|| {
-                cursor_shader_name
-                    .as_deref()
-                    .and_then(|name| self.shader_state.cursor_shader_metadata_cache.get(name))
-                    .and_then(|meta| meta.defaults.disable_in_alt_screen)
-            })
-            .unwrap_or(cfg.shader.cursor_shader_disable_in_alt_screen);
-
-        cfg.shader.cursor_shader_enabled
-            && resolved_hides_cursor
-            && !(resolved_disable_in_alt_screen && is_alt_screen)
+        resolve_cursor_shader_config(user_override, metadata, &cfg)
+            .hides_default_cursor(is_alt_screen)
     }
 }
@@ -3,6 +3,7 @@
 use std::sync::Arc;
 
 use super::super::window_state::WindowState;
+use crate::config::snippets::ActionSplitDirection;
 
 impl WindowState {
     /// Shared implementation for trigger- and keyboard-initiated pane splits.
@@ -136,6 +137,33 @@ impl WindowState {
         }
     }
 
+    /// Split off a fresh shell for a `split_on_commands` job queued while
+    /// gathering the frame, leaving the job running in its pane.
+    pub(crate) fn process_pending_command_split(&mut self) {
+        let Some(entry) = self.pending_command_split.take() else {
+            return;
+        };
+        // Split panes are owned by tmux while connected
+        if self.is_tmux_connected() {
+            return;
+        }
+        let (direction, percent) = {
+            let config = self.config.load();
+            let direction = match config.split_on_command_direction {
+                ActionSplitDirection::Horizontal => crate::pane::SplitDirection::Horizontal,
+                ActionSplitDirection::Vertical => crate::pane::SplitDirection::Vertical,
+            };
+            (direction, config.split_on_command_percent)
+        };
+        crate::debug_info!(
+            "PANE_SPLIT",
+            "split-on-command: '{}' started, splitting {:?}",
+            entry,
+            direction
+        );
+        self.split_pane_direction(direction, true, None, percent);
+    }
+
     /// Split the current pane horizontally (panes stacked top/bottom)
     pub fn split_pane_horizontal(&mut self) {
         // In tmux mode, send split command to tmux instead
@@ -250,14 +278,18 @@ impl WindowState {
             let focused_id = pane_manager.focused_pane_id()?;
             let pane = pane_manager.get_pane(focused_id)?;
             // blocking_read: user-initiated close — must not silently skip confirmation.
-            // should_confirm_close() only needs &self; read lock is correct.
+            // close_confirmation_job() only needs &self; read lock is correct.
             let term = pane.terminal.blocking_read();
             log::info!(
                 "[CLOSE_CONFIRM] check_current_pane_running_job (split pane): marker={:?} command={:?}",
                 term.shell_integration_marker(),
                 term.shell_integration_command()
             );
-            return term.should_confirm_close(&self.config.load().jobs_to_ignore);
+            let config = self.config.load();
+            return term.close_confirmation_job(
+                &config.jobs_to_ignore,
+                config.confirm_foreground_jobs_only,
+            );
         }
 
         // Single pane - use the tab's terminal.
@@ -269,7 +301,8 @@ impl WindowState {
             term.shell_integration_command(),
             term.is_command_running()
         );
-        term.should_confirm_close(&self.config.load().jobs_to_ignore)
+        let config = self.config.load();
+        term.close_confirmation_job(&config.jobs_to_ignore, config.confirm_foreground_jobs_only)
     }
 
     /// Check if the current tab has multiple panes
@@ -12,6 +12,12 @@ use super::super::window_state::WindowState;
 impl WindowState {
     /// Open a new tab from a profile
     pub fn open_profile(&mut self, profile_id: ProfileId) {
+        self.open_profile_with_cwd(profile_id, None);
+    }
+
+    /// Open a new tab from a profile, overriding its working directory when
+    /// `cwd` is given (the `--cwd` startup flag).
+    pub fn open_profile_with_cwd(&mut self, profile_id: ProfileId, cwd: Option<String>) {
         log::debug!("open_profile called with id: {:?}", profile_id);
 
         // Check max tabs limit
@@ -32,13 +38,16 @@ impl WindowState {
             return;
         }
 
-        let profile = match self.overlay_ui.profile_manager.get(&profile_id) {
+        let mut profile = match self.overlay_ui.profile_manager.get(&profile_id) {
             Some(p) => p.clone(),
             None => {
                 log::error!("Profile not found: {:?}", profile_id);
                 return;
             }
         };
+        if cwd.is_some() {
+            profile.working_directory = cwd;
+        }
         log::debug!("Found profile: {}", profile.name);
 
         // Get current grid size from renderer
@@ -5,6 +5,7 @@
 //! - `has_multiple_tabs` — query predicate
 //! - `active_terminal` — accessor for the active tab's terminal
 //! - `check_current_tab_running_job` — running-job confirmation gate
+//! - `any_foreground_job` — quit confirmation gate
 
 //! - Debug logging for close confirmation flow
 
@@ -98,7 +99,7 @@ impl WindowState {
     pub(super) fn check_current_tab_running_job(&self) -> Option<String> {
         let tab = self.tab_manager.active_tab()?;
         // blocking_read: user-initiated close — we must not silently skip confirmation.
-        // should_confirm_close() only needs &self so a shared read lock is correct.
+        // close_confirmation_job() only needs &self so a shared read lock is correct.
         // blocking_read() waits for any active writer (e.g. async key/mouse task) to
         // finish rather than returning None and letting the tab close without prompting.
         let term = tab.terminal.blocking_read();
@@ -116,8 +117,34 @@ impl WindowState {
             command_name,
             is_command_running
         );
-        let result = term.should_confirm_close(&self.config.load().jobs_to_ignore);
-        log::info!("[CLOSE_CONFIRM] should_confirm_close result={:?}", result);
+        let config = self.config.load();
+        let result = term
+            .close_confirmation_job(&config.jobs_to_ignore, config.confirm_foreground_jobs_only);
+        log::info!("[CLOSE_CONFIRM] close_confirmation_job result={:?}", result);
         result
     }
+
+    /// Whether any terminal in this window (every pane of every tab) has a
+    /// foreground job running that is not in `jobs_to_ignore`.
+    pub(crate) fn any_foreground_job(&self) -> bool {
+        let config = self.config.load();
+        // blocking_read: user-initiated quit — must not skip confirmation.
+        let needs_confirm =
+            |terminal: &Arc<tokio::sync::RwLock<crate::terminal::TerminalManager>>| {
+                terminal
+                    .blocking_read()
+                    .close_confirmation_job(&config.jobs_to_ignore, true)
+                    .is_some()
+            };
+        self.tab_manager
+            .tabs()
+            .iter()
+            .any(|tab| match tab.pane_manager() {
+                Some(pm) if pm.pane_count() > 0 => pm
+                    .all_panes()
+                    .iter()
+                    .any(|pane| needs_confirm(&pane.terminal)),
+                _ => needs_confirm(&tab.terminal),
+            })
+    }
 }
@@ -35,6 +35,20 @@ impl WindowManager {
             // Detect what changed
             let changes = ConfigChanges::detect(&window_state.config.load(), config);
 
+            {
+                let old = window_state.config.load();
+                if old.key_repeat_keys != config.key_repeat_keys
+                    || old.key_repeat_delay_ms != config.key_repeat_delay_ms
+                    || old.key_repeat_interval_ms != config.key_repeat_interval_ms
+                {
+                    window_state.input_handler.update_key_repeat(
+                        config.key_repeat_delay_ms,
+                        config.key_repeat_interval_ms,
+                        &config.key_repeat_keys,
+                    );
+                }
+            }
+
             // Update the config
             // QA-001 resolved: Using ArcSwap<Config> for atomic config swaps.
             // Reads via self.config.load() are zero-cost (guard deref, no clone).
@@ -51,7 +65,7 @@ impl WindowManager {
             // Rebuild keybinding registry if keybindings changed
             if changes.keybindings {
                 window_state.keybinding_registry =
-                    crate::keybindings::KeybindingRegistry::from_config(&config.keybindings);
+                    crate::app::window_state::WindowState::build_keybinding_registry(config);
                 log::info!(
                     "Keybinding registry rebuilt with {} bindings",
                     config.keybindings.len()
@@ -109,13 +123,38 @@ impl WindowManager {
             // Apply font rendering changes that can update live
             if changes.font_rendering {
                 if let Some(renderer) = &mut window_state.renderer {
-                    let mut updated = false;
-                    updated |= renderer.update_font_antialias(config.font_rendering.font_antialias);
-                    updated |= renderer.update_font_hinting(config.font_rendering.font_hinting);
-                    updated |=
-                        renderer.update_font_thin_strokes(config.font_rendering.font_thin_strokes);
+                    let cell_size_before = (renderer.cell_width(), renderer.cell_height());
+                    let mut updated = renderer.update_font_rendering(
+                        config.font_rendering.font_antialias,
+                        config.font_rendering.font_hinting,
+                        config.font_rendering.font_thin_strokes,
+                    );
                     updated |=
                         renderer.update_minimum_contrast(config.font_rendering.minimum_contrast);
+
+                    // Re-rasterized metrics may change the cell size; keep the
+                    // terminals' grid and pixel dimensions in step.
+                    let cell_width = renderer.cell_width();
+                    let cell_height = renderer.cell_height();
+                    if (cell_width, cell_height) != cell_size_before {
+                        let (cols, rows) = renderer.grid_size();
+                        let width_px = (cols as f32 * cell_width) as usize;
+                        let height_px = (rows as f32 * cell_height) as usize;
+                        for tab in window_state.tab_manager.tabs_mut() {
+                            if let Ok(mut term) = tab.terminal.try_write() {
+                                term.set_cell_dimensions(cell_width as u32, cell_height as u32);
+                                if let Err(e) =
+                                    term.resize_with_pixels(cols, rows, width_px, height_px)
+                                {
+                                    crate::debug_error!(
+                                        "TERMINAL",
+                                        "resize_with_pixels failed (font_rendering): {e}"
+                                    );
+                                }
+                            }
+                            tab.active_cache_mut().cells = None;
+                        }
+                    }
                     if updated {
                         window_state.focus_state.needs_redraw = true;
                     }
@@ -223,6 +262,13 @@ impl WindowManager {
                 window_state.badge_state.mark_dirty();
             }
 
+            // Re-attach pane clipboard histories when the clipboard scope changed
+            if changes.clipboard_scope {
+                for tab in window_state.tab_manager.tabs() {
+                    tab.apply_clipboard_scope(config.clipboard_scope);
+                }
+            }
+
             // Sync status bar monitor state after config changes
             window_state.status_bar_ui.sync_monitor_state(config);
 
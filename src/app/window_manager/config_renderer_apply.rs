@@ -10,7 +10,7 @@ use std::sync::Arc;
 
 use crate::app::window_state::WindowState;
 use crate::app::window_state::config_updates::ConfigChanges;
-use crate::config::{Config, resolve_shader_config};
+use crate::config::{Config, resolve_cursor_shader_config, resolve_shader_config};
 use par_term_terminal::conversion::{
     to_core_ambiguous_width, to_core_normalization_form, to_core_unicode_version,
 };
@@ -48,11 +48,26 @@ pub(super) fn apply_renderer_config(
         window_state.focus_state.needs_redraw = true;
     }
 
+    if changes.text_shadow {
+        renderer.update_text_shadow(
+            config.text_shadow_enabled,
+            config.text_shadow_color,
+            config.text_shadow_offset,
+            config.text_shadow_blur,
+        );
+        window_state.focus_state.needs_redraw = true;
+    }
+
     if changes.link_underline_style {
         renderer.set_link_underline_style(config.link_underline_style);
         window_state.focus_state.needs_redraw = true;
     }
 
+    if changes.selection_corner_radius {
+        renderer.update_selection_corner_radius(config.selection_corner_radius);
+        window_state.focus_state.needs_redraw = true;
+    }
+
     // Update vsync mode if changed
     if changes.vsync_mode {
         let (actual_mode, _changed) = renderer.update_vsync_mode(config.vsync_mode);
@@ -77,11 +92,20 @@ pub(super) fn apply_renderer_config(
         config.scrollbar_thumb_color,
         config.scrollbar_track_color,
     );
+    renderer.update_scrollbar_autohide(
+        config.scrollbar_autohide_delay,
+        config.scrollbar_fade_duration,
+    );
     window_state.focus_state.needs_redraw = true;
 
-    // Update cursor color
+    // Update cursor color (the active tab's profile color takes precedence)
     if changes.cursor_color {
-        renderer.update_cursor_color(config.cursor.cursor_color);
+        renderer.update_cursor_color(
+            window_state
+                .tab_manager
+                .active_cursor_override()
+                .color(&config.cursor),
+        );
     }
 
     // Update cursor text color
@@ -89,26 +113,14 @@ pub(super) fn apply_renderer_config(
         renderer.update_cursor_text_color(config.cursor.cursor_text_color);
     }
 
-    // Update cursor style and blink for all tabs
+    // Update cursor style and blink for all tabs, keeping each tab's profile overrides
     if changes.cursor_style || changes.cursor_blink {
-        use crate::config::CursorStyle as ConfigCursorStyle;
-        use par_term_emu_core_rust::cursor::CursorStyle as TermCursorStyle;
-
-        let term_style = if config.cursor.cursor_blink {
-            match config.cursor.cursor_style {
-                ConfigCursorStyle::Block => TermCursorStyle::BlinkingBlock,
-                ConfigCursorStyle::Beam => TermCursorStyle::BlinkingBar,
-                ConfigCursorStyle::Underline => TermCursorStyle::BlinkingUnderline,
-            }
-        } else {
-            match config.cursor.cursor_style {
-                ConfigCursorStyle::Block => TermCursorStyle::SteadyBlock,
-                ConfigCursorStyle::Beam => TermCursorStyle::SteadyBar,
-                ConfigCursorStyle::Underline => TermCursorStyle::SteadyUnderline,
-            }
-        };
-
         for tab in window_state.tab_manager.tabs_mut() {
+            let cursor = tab.profile.cursor;
+            let term_style = crate::tab::term_cursor_style(
+                cursor.style(&config.cursor),
+                cursor.blink(&config.cursor),
+            );
             if let Ok(mut term) = tab.terminal.try_write() {
                 term.set_cursor_style(term_style);
             }
@@ -133,6 +145,12 @@ pub(super) fn apply_renderer_config(
             );
             renderer
                 .update_cursor_boost(config.cursor.cursor_boost, config.cursor.cursor_boost_color);
+            renderer.update_cursor_morph_duration(config.cursor.cursor_morph_duration_ms);
+            renderer.update_cursor_smear(
+                config.cursor.cursor_smear_length,
+                config.cursor.cursor_smear_decay_ms,
+            );
+            renderer.update_cursor_ligature_width(config.cursor.cursor_ligature_width);
             renderer.update_unfocused_cursor_style(config.cursor.unfocused_cursor_style);
         }
         window_state.focus_state.needs_redraw = true;
@@ -259,6 +277,30 @@ pub(super) fn apply_renderer_config(
         }
     }
 
+    // Apply keyword highlight sets to all tabs and all pane terminals
+    if changes.keyword_highlights {
+        let sets = &config.search.keyword_highlights;
+        for tab in window_state.tab_manager.tabs_mut() {
+            if let Ok(mut term) = tab.terminal.try_write() {
+                term.set_keyword_highlights(sets);
+            }
+            // Highlights recolor cells without bumping update_generation, so
+            // every pane's cell cache must be invalidated (as for themes).
+            let tab_terminal = Arc::clone(&tab.terminal);
+            if let Some(pm) = tab.pane_manager_mut() {
+                for pane in pm.all_panes_mut() {
+                    if !Arc::ptr_eq(&pane.terminal, &tab_terminal)
+                        && let Ok(mut term) = pane.terminal.try_write()
+                    {
+                        term.set_keyword_highlights(sets);
+                    }
+                    pane.cache.invalidate_pane_cells();
+                }
+            }
+        }
+        window_state.focus_state.needs_redraw = true;
+    }
+
     // Update ENQ answerback string across all tabs when changed
     if changes.answerback_string {
         let answerback = if config.answerback_string.is_empty() {
@@ -291,6 +333,31 @@ pub(super) fn apply_renderer_config(
         }
     }
 
+    // Update password prompt detection across all tabs and pane terminals
+    if changes.sensitive_input {
+        for tab in window_state.tab_manager.tabs_mut() {
+            if let Ok(mut term) = tab.terminal.try_write() {
+                term.set_sensitive_input_detection(
+                    config.password_prompt_detection,
+                    config.mask_password_input,
+                );
+            }
+            let tab_terminal = Arc::clone(&tab.terminal);
+            if let Some(pm) = tab.pane_manager_mut() {
+                for pane in pm.all_panes_mut() {
+                    if !Arc::ptr_eq(&pane.terminal, &tab_terminal)
+                        && let Ok(mut term) = pane.terminal.try_write()
+                    {
+                        term.set_sensitive_input_detection(
+                            config.password_prompt_detection,
+                            config.mask_password_input,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
     // Apply Unicode width settings
     if changes.unicode_width {
         let width_config = par_term_emu_core_rust::WidthConfig::new(
@@ -368,6 +435,14 @@ pub(super) fn apply_renderer_config(
         None // No change attempted
     };
 
+    // Apply shader content overscan (no shader reload needed)
+    if changes.shader_overscan
+        && let Some(renderer) = &mut window_state.renderer
+    {
+        renderer.update_custom_shader_overscan(config.shader.custom_shader_overscan);
+        window_state.focus_state.needs_redraw = true;
+    }
+
     // Apply use_background_as_channel0 setting
     if (changes.any_shader_change()
         || changes.shader_use_background_as_channel0
@@ -398,6 +473,32 @@ pub(super) fn apply_renderer_config(
         None // No change attempted
     };
 
+    // Apply resolved cursor shader parameters (per-shader override -> metadata -> global)
+    if changes.cursor_shader_params || changes.cursor_shader_path {
+        let cursor_override = config
+            .shader
+            .cursor_shader
+            .as_ref()
+            .and_then(|name| config.cursor_shader_configs.get(name));
+        let cursor_metadata = config.shader.cursor_shader.as_ref().and_then(|name| {
+            window_state
+                .shader_state
+                .cursor_shader_metadata_cache
+                .get(name)
+                .cloned()
+        });
+        let resolved_cursor =
+            resolve_cursor_shader_config(cursor_override, cursor_metadata.as_ref(), config);
+        if let Some(renderer) = &mut window_state.renderer {
+            renderer.update_cursor_shader_config(
+                resolved_cursor.cursor_color,
+                resolved_cursor.trail_duration,
+                resolved_cursor.glow_radius,
+                resolved_cursor.glow_intensity,
+            );
+        }
+    }
+
     if let Some(result) = &shader_result {
         window_state.shader_state.background_shader_last_error = result.clone();
     }
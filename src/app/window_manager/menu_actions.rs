@@ -454,7 +454,9 @@ impl WindowManager {
                 }
             }
             MenuAction::OpenProfile(profile_id) => {
-                if let Some(window_id) = focused_window
+                if self.config.load().remember_profile_window_geometry {
+                    self.open_profile_in_window(event_loop, profile_id);
+                } else if let Some(window_id) = focused_window
                     && let Some(window_state) = self.windows.get_mut(&window_id)
                 {
                     window_state.open_profile(profile_id);
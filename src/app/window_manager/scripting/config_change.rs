@@ -34,6 +34,8 @@ pub(super) enum PendingScriptAction {
         value: serde_json::Value,
         config_index: usize,
     },
+    /// Send the selected text back to the script (gated by `allow_read_selection`).
+    GetSelection { config_index: usize },
 }
 
 /// Tokenise a command string into `(program, args)` without invoking a shell.
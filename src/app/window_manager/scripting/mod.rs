@@ -33,8 +33,9 @@ impl WindowManager {
         //
         // Safe commands (Log, SetPanel, ClearPanel) are executed immediately.
         // Commands that need `WindowState` methods (Notify, SetBadge, etc.) or
-        // require permission checks (WriteText, RunCommand, ChangeConfig) are
-        // deferred into `pending_actions` and processed in Pass 2.
+        // require permission checks (WriteText, RunCommand, ChangeConfig,
+        // GetSelection) are deferred into `pending_actions` and processed in
+        // Pass 2.
         struct ScriptPassResult {
             running_state: Vec<bool>,
             error_state: Vec<String>,
@@ -59,6 +60,26 @@ impl WindowManager {
             let mut panels = Vec::with_capacity(script_count);
             let mut pending: Vec<PendingScriptAction> = Vec::new();
 
+            // Hyperlink hover is raised by the frontend, not the terminal
+            // core, so it is pushed into each forwarder here.
+            // try_lock: acceptable — the change stays pending until next time.
+            let hover_event = tab
+                .terminal
+                .try_read()
+                .ok()
+                .and_then(|term| term.poll_hyperlink_hover(std::time::Instant::now()))
+                .map(|event| match event {
+                    par_term_terminal::HyperlinkHoverEvent::Entered(link) => {
+                        crate::scripting::protocol::ScriptEvent::hyperlink_hover(
+                            Some(link.url),
+                            link.id,
+                        )
+                    }
+                    par_term_terminal::HyperlinkHoverEvent::Left => {
+                        crate::scripting::protocol::ScriptEvent::hyperlink_hover(None, None)
+                    }
+                });
+
             for i in 0..script_count {
                 let has_script_id = tab.scripting.script_ids.get(i).and_then(|opt| *opt);
                 let is_running =
@@ -67,6 +88,9 @@ impl WindowManager {
                 // Drain events from forwarder and send to script
                 if is_running && let Some(Some(forwarder)) = tab.scripting.script_forwarders.get(i)
                 {
+                    if let Some(event) = &hover_event {
+                        forwarder.push_event(event.clone());
+                    }
                     let events = forwarder.drain_events();
                     if let Some(script_id) = has_script_id {
                         for event in &events {
@@ -142,6 +166,9 @@ impl WindowManager {
                                     config_index: i,
                                 });
                             }
+                            crate::scripting::protocol::ScriptCommand::GetSelection {} => {
+                                pending.push(PendingScriptAction::GetSelection { config_index: i });
+                            }
                         }
                     }
                 }
@@ -431,6 +458,31 @@ impl WindowManager {
 
                         Self::apply_script_config_change(ws, &key, &value, config_index);
                     }
+
+                    // ── GetSelection ────────────────────────────────────────────
+                    // Replies with a `selection` event to the requesting script
+                    // only; the text is never logged.
+                    PendingScriptAction::GetSelection { config_index } => {
+                        let Some(event) = crate::scripting::protocol::ScriptEvent::selection_reply(
+                            ws.config.load().scripts.get(config_index),
+                            || ws.get_selected_text(),
+                        ) else {
+                            log::warn!(
+                                "Script[{}] GetSelection DENIED: \
+                                     allow_read_selection=false",
+                                config_index
+                            );
+                            continue;
+                        };
+                        if let Some(tab) = ws.tab_manager.active_tab_mut()
+                            && let Some(sid) =
+                                tab.scripting.script_ids.get(config_index).and_then(|o| *o)
+                            && let Err(e) = tab.scripting.script_manager.send_event(sid, &event)
+                        {
+                            log::warn!("Script[{}] GetSelection reply failed: {}", config_index, e);
+                        }
+                        crate::debug_info!("SCRIPT", "AUDIT Script[{}] GetSelection", config_index);
+                    }
                 }
             }
         }
@@ -64,6 +64,9 @@ impl WindowManager {
             return;
         }
 
+        // The terminal window the settings were opened from
+        let focused = self.get_focused_window_id();
+
         // Create new settings window using shared runtime
         let config = (**self.config.load()).clone();
         let runtime = std::sync::Arc::clone(&self.runtime);
@@ -128,6 +131,18 @@ impl WindowManager {
                     .map(|ws| ws.overlay_ui.profile_manager.to_vec())
                     .unwrap_or_default();
                 settings_window.settings_ui.sync_profiles(profiles);
+                // Show the focused tab's profile in the quick settings strip
+                settings_window.settings_ui.quick_settings_profile = focused
+                    .and_then(|id| self.windows.get(&id))
+                    .or_else(|| self.windows.values().next())
+                    .and_then(|ws| {
+                        let tab = ws.tab_manager.active_tab()?;
+                        let id = tab
+                            .profile
+                            .auto_applied_dir_profile_id
+                            .or(tab.profile.auto_applied_profile_id)?;
+                        ws.overlay_ui.profile_manager.resolve_profile(&id)
+                    });
                 // Sync available agents from first window's discovered agents
                 if let Some(ws) = self.windows.values().next() {
                     settings_window.settings_ui.available_agent_ids = ws
@@ -140,6 +155,8 @@ impl WindowManager {
                 self.settings_window = Some(settings_window);
                 // Sync arrangement data to settings UI
                 self.sync_arrangements_to_settings();
+                self.sync_badge_variables_to_settings();
+                self.sync_gpu_info_to_settings();
             }
             Err(e) => {
                 log::error!("Failed to create settings window: {}", e);
@@ -295,4 +312,42 @@ impl WindowManager {
             settings_window.request_redraw();
         }
     }
+
+    /// Sync the focused window's badge variables to the settings window
+    /// (live badge preview and variable autocompletion).
+    pub fn sync_badge_variables_to_settings(&mut self) {
+        if self.settings_window.is_none() {
+            return;
+        }
+        let focused = self.get_focused_window_id();
+        let variables = focused
+            .and_then(|id| self.windows.get(&id))
+            .or_else(|| self.windows.values().next())
+            .map(|ws| ws.badge_state.variables.read().available_variables());
+        if let Some(sw) = &mut self.settings_window
+            && let Some(variables) = variables
+        {
+            sw.settings_ui.badge_variables = variables;
+        }
+    }
+
+    /// Sync the focused window's GPU adapter list and diagnostics to the
+    /// settings window (Advanced > GPU).
+    pub fn sync_gpu_info_to_settings(&mut self) {
+        if self.settings_window.is_none() {
+            return;
+        }
+        let focused = self.get_focused_window_id();
+        let info = focused
+            .and_then(|id| self.windows.get(&id))
+            .or_else(|| self.windows.values().next())
+            .and_then(|ws| ws.renderer.as_ref())
+            .map(|r| (r.gpu_adapters().to_vec(), r.gpu_diagnostics()));
+        if let Some(sw) = &mut self.settings_window
+            && let Some((adapters, diagnostics)) = info
+        {
+            sw.settings_ui.gpu_adapters = adapters;
+            sw.settings_ui.gpu_diagnostics = diagnostics;
+        }
+    }
 }
@@ -19,6 +19,8 @@ use winit::window::WindowId;
 use crate::app::window_state::WindowState;
 use crate::config::Config;
 use crate::menu::MenuManager;
+use crate::profile::ProfileId;
+use par_term_config::{MonitorRect, WindowGeometry};
 
 use super::WindowManager;
 use super::update_checker::update_available_version;
@@ -146,14 +148,36 @@ impl WindowManager {
                 // Set window index for title formatting (window_number calculated earlier)
                 window_state.window_index = window_number;
 
+                // `--profile` / `--cwd` apply to the initial tab of the first window
+                let startup = self.start_time.is_none();
+                let startup_profile = self
+                    .runtime_options
+                    .profile
+                    .as_deref()
+                    .filter(|_| startup)
+                    .and_then(|name| window_state.overlay_ui.profile_manager.find_by_name(name))
+                    .map(|profile| profile.id);
+                let startup_cwd = self.runtime_options.cwd.clone().filter(|_| startup);
+
                 // Initialize async components using the shared runtime
                 // (GPU setup — this blocks for 2-3 seconds)
                 let runtime = Arc::clone(&self.runtime);
-                if let Err(e) = runtime.block_on(window_state.initialize_async(window, false, None))
-                {
+                let first_tab_cwd = if startup_profile.is_some() {
+                    None
+                } else {
+                    startup_cwd.clone()
+                };
+                if let Err(e) = runtime.block_on(window_state.initialize_async(
+                    window,
+                    startup_profile.is_some(),
+                    first_tab_cwd,
+                )) {
                     log::error!("Failed to initialize window: {}", e);
                     return;
                 }
+                if let Some(profile_id) = startup_profile {
+                    window_state.open_profile_with_cwd(profile_id, startup_cwd);
+                }
 
                 // Attach menu to the window (platform-specific: per-window on Windows/Linux)
                 if let Some(menu) = &self.menu
@@ -556,8 +580,145 @@ impl WindowManager {
         }
     }
 
+    /// Open a profile in its own window at the geometry remembered for it.
+    ///
+    /// Used when `remember_profile_window_geometry` is enabled. A saved rect is
+    /// clamped onto an available monitor first; a profile without one opens at
+    /// the configured default size, cascaded from the focused window.
+    pub(crate) fn open_profile_in_window(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        profile_id: ProfileId,
+    ) {
+        let focused = self
+            .get_focused_window_id()
+            .and_then(|id| self.windows.get(&id));
+        let Some(profile) =
+            focused.and_then(|ws| ws.overlay_ui.profile_manager.get(&profile_id).cloned())
+        else {
+            log::error!("Profile not found: {:?}", profile_id);
+            return;
+        };
+
+        let geometry = match profile.window_geometry {
+            Some(saved) => {
+                let monitors: Vec<MonitorRect> = event_loop
+                    .available_monitors()
+                    .map(|m| {
+                        let (pos, size) = (m.position(), m.size());
+                        MonitorRect {
+                            x: pos.x,
+                            y: pos.y,
+                            width: size.width,
+                            height: size.height,
+                        }
+                    })
+                    .collect();
+                let clamped = saved.clamped_to_monitors(&monitors);
+                if clamped != saved {
+                    log::info!(
+                        "Saved geometry {:?} for profile '{}' is off-screen; clamped to {:?}",
+                        saved,
+                        profile.name,
+                        clamped
+                    );
+                }
+                clamped
+            }
+            None => {
+                let window = focused.and_then(|ws| ws.window.as_ref());
+                let scale = window.map_or(1.0, |w| w.scale_factor() as f32);
+                let (width, height) =
+                    crate::font_metrics::window_size_from_config(&self.config.load(), scale)
+                        .unwrap_or((800, 600));
+                let source_pos = window
+                    .and_then(|w| w.outer_position().ok())
+                    .unwrap_or_default();
+                let pos = Self::compute_moved_tab_outer_position(
+                    event_loop,
+                    source_pos,
+                    winit::dpi::PhysicalSize::new(width, height),
+                );
+                WindowGeometry {
+                    x: pos.x,
+                    y: pos.y,
+                    width,
+                    height,
+                }
+            }
+        };
+
+        let Some(window_id) = self.create_window_for_moved_tab(
+            event_loop,
+            winit::dpi::PhysicalSize::new(geometry.width, geometry.height),
+            winit::dpi::PhysicalPosition::new(geometry.x, geometry.y),
+        ) else {
+            return;
+        };
+        let opened = self
+            .windows
+            .get_mut(&window_id)
+            .is_some_and(|window_state| {
+                window_state.geometry_profile_id = Some(profile_id);
+                window_state.open_profile(profile_id);
+                window_state.tab_manager.tab_count() > 0
+            });
+        if !opened {
+            // The window was created without a default tab; don't leave it empty.
+            self.close_window(window_id);
+        }
+    }
+
+    /// Store the current size/position of a window opened via
+    /// [`Self::open_profile_in_window`] in its profile and persist profiles.
+    fn record_profile_window_geometry(&mut self, window_id: WindowId) {
+        if !self.config.load().remember_profile_window_geometry {
+            return;
+        }
+        let Some(window_state) = self.windows.get(&window_id) else {
+            return;
+        };
+        let Some(profile_id) = window_state.geometry_profile_id else {
+            return;
+        };
+        let Some(window) = &window_state.window else {
+            return;
+        };
+        // Fullscreen/maximized rects are not a useful "preferred" geometry.
+        if window_state.is_fullscreen || window.is_maximized() {
+            return;
+        }
+        let Ok(pos) = window.outer_position() else {
+            return;
+        };
+        let size = window.inner_size();
+        let geometry = WindowGeometry {
+            x: pos.x,
+            y: pos.y,
+            width: size.width,
+            height: size.height,
+        };
+
+        // Every window holds its own ProfileManager copy; keep them in sync.
+        for ws in self.windows.values_mut() {
+            if let Some(profile) = ws.overlay_ui.profile_manager.get_mut(&profile_id) {
+                profile.window_geometry = Some(geometry);
+            }
+        }
+        if let Some(ws) = self.windows.get(&window_id) {
+            ws.save_profiles();
+        }
+        log::info!(
+            "Remembered window geometry {:?} for profile {:?}",
+            geometry,
+            profile_id
+        );
+    }
+
     /// Close a specific window
     pub fn close_window(&mut self, window_id: WindowId) {
+        self.record_profile_window_geometry(window_id);
+
         // Save session state before removing the last window (while data is still available).
         if self.config.load().restore_session
             && self.windows.len() == 1
@@ -88,6 +88,12 @@ impl WindowState {
                 self.request_redraw();
             }
             TabBarAction::RenameTab(id, name) => {
+                let tab_index = self
+                    .tab_manager
+                    .tabs()
+                    .iter()
+                    .position(|t| t.id == id)
+                    .unwrap_or(0);
                 if let Some(tab) = self.tab_manager.get_tab_mut(id) {
                     if name.is_empty() {
                         // Blank name: revert to auto title mode
@@ -103,10 +109,13 @@ impl WindowState {
                             pane.has_default_title = true;
                         }
                         // Trigger immediate title update
+                        let config = self.config.load();
                         tab.update_title(
-                            self.config.load().tab_title_mode,
-                            self.config.load().remote_tab_title_format,
-                            self.config.load().remote_tab_title_osc_priority,
+                            config.tab_title_mode,
+                            config.remote_tab_title_format,
+                            config.remote_tab_title_osc_priority,
+                            &config.tab_title_template,
+                            tab_index + 1,
                         );
                     } else {
                         tab.set_title(&name);
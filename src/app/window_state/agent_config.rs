@@ -149,15 +149,25 @@ impl WindowState {
     /// Apply config updates from the ACP agent.
     ///
     /// Updates the in-memory config, applies changes to the renderer, and
-    /// saves to disk. Returns `Ok(())` on success or an error string.
+    /// saves to disk. A `null` value resets the key to its default, which is
+    /// how `config_update`'s `include_previous` reports a key `config.yaml`
+    /// does not set. Returns `Ok(())` on success or an error string.
     pub(super) fn apply_agent_config_updates(
         &mut self,
         updates: &std::collections::HashMap<String, serde_json::Value>,
     ) -> Result<(), String> {
         let mut errors = Vec::new();
         let old_config = (**self.config.load()).clone();
+        let defaults = updates
+            .values()
+            .any(serde_json::Value::is_null)
+            .then(|| serde_json::to_value(Config::default()).unwrap_or_default());
 
         for (key, value) in updates {
+            let value = match &defaults {
+                Some(defaults) if value.is_null() => defaults.get(key).unwrap_or(value),
+                _ => value,
+            };
             if let Err(e) = self.apply_single_config_update(key, value) {
                 errors.push(format!("{key}: {e}"));
             }
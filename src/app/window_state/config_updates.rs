@@ -12,6 +12,9 @@ pub(crate) struct ConfigChanges {
     // Theme
     pub theme: bool,
 
+    // Saved keyword highlight sets
+    pub keyword_highlights: bool,
+
     // Background shader
     pub shader_animation: bool,
     pub shader_enabled: bool,
@@ -26,6 +29,7 @@ pub(crate) struct ConfigChanges {
     pub shader_use_background_as_channel0: bool,
     pub shader_background_blend_mode: bool,
     pub shader_readability: bool,
+    pub shader_overscan: bool,
 
     // Cursor shader
     pub cursor_shader_path: bool,
@@ -33,6 +37,7 @@ pub(crate) struct ConfigChanges {
     pub cursor_shader_animation: bool,
     pub cursor_shader_speed: bool,
     pub cursor_shader_disable_in_alt_screen: bool,
+    pub cursor_shader_params: bool,
 
     // Window
     pub window_title: bool,
@@ -59,6 +64,9 @@ pub(crate) struct ConfigChanges {
     pub max_notifications: bool,
     pub max_osc_data_length: bool,
 
+    // Password prompt detection / input masking
+    pub sensitive_input: bool,
+
     // Unicode width settings
     pub unicode_width: bool,
 
@@ -90,7 +98,9 @@ pub(crate) struct ConfigChanges {
     // Transparency mode
     pub transparency_mode: bool,
     pub keep_text_opaque: bool,
+    pub text_shadow: bool,
     pub link_underline_style: bool,
+    pub selection_corner_radius: bool,
 
     // Blur settings (macOS only)
     pub blur: bool,
@@ -101,6 +111,9 @@ pub(crate) struct ConfigChanges {
     // Badge
     pub badge: bool,
 
+    // Clipboard history scope
+    pub clipboard_scope: bool,
+
     // Command separator lines
     pub command_separator: bool,
 
@@ -119,6 +132,8 @@ impl ConfigChanges {
         Self {
             theme: new.theme != old.theme,
 
+            keyword_highlights: new.search.keyword_highlights != old.search.keyword_highlights,
+
             shader_animation: new.shader.custom_shader_animation
                 != old.shader.custom_shader_animation,
             shader_enabled: new.shader.custom_shader_enabled != old.shader.custom_shader_enabled,
@@ -160,6 +175,10 @@ impl ConfigChanges {
                     - old.shader.custom_shader_readability_brightness)
                     .abs()
                     > f32::EPSILON,
+            shader_overscan: (new.shader.custom_shader_overscan
+                - old.shader.custom_shader_overscan)
+                .abs()
+                > f32::EPSILON,
             shader_per_shader_config: {
                 // Check if the per-shader config for the current shader changed
                 let old_override = old
@@ -186,6 +205,33 @@ impl ConfigChanges {
                 > f32::EPSILON,
             cursor_shader_disable_in_alt_screen: new.shader.cursor_shader_disable_in_alt_screen
                 != old.shader.cursor_shader_disable_in_alt_screen,
+            cursor_shader_params: {
+                // Glow/trail/color globals, or the per-shader override for the
+                // current cursor shader (edited live from the settings UI)
+                let old_override = old
+                    .shader
+                    .cursor_shader
+                    .as_ref()
+                    .and_then(|name| old.cursor_shader_configs.get(name));
+                let new_override = new
+                    .shader
+                    .cursor_shader
+                    .as_ref()
+                    .and_then(|name| new.cursor_shader_configs.get(name));
+                old_override != new_override
+                    || new.shader.cursor_shader_color != old.shader.cursor_shader_color
+                    || (new.shader.cursor_shader_trail_duration
+                        - old.shader.cursor_shader_trail_duration)
+                        .abs()
+                        > f32::EPSILON
+                    || (new.shader.cursor_shader_glow_radius - old.shader.cursor_shader_glow_radius)
+                        .abs()
+                        > f32::EPSILON
+                    || (new.shader.cursor_shader_glow_intensity
+                        - old.shader.cursor_shader_glow_intensity)
+                        .abs()
+                        > f32::EPSILON
+            },
 
             window_title: new.window_title != old.window_title,
             window_decorations: new.window.window_decorations != old.window.window_decorations,
@@ -209,6 +255,11 @@ impl ConfigChanges {
                     > f32::EPSILON
                 || (new.cursor.cursor_boost - old.cursor.cursor_boost).abs() > f32::EPSILON
                 || new.cursor.cursor_boost_color != old.cursor.cursor_boost_color
+                || new.cursor.cursor_morph_duration_ms != old.cursor.cursor_morph_duration_ms
+                || (new.cursor.cursor_smear_length - old.cursor.cursor_smear_length).abs()
+                    > f32::EPSILON
+                || new.cursor.cursor_smear_decay_ms != old.cursor.cursor_smear_decay_ms
+                || new.cursor.cursor_ligature_width != old.cursor.cursor_ligature_width
                 || new.cursor.unfocused_cursor_style != old.cursor.unfocused_cursor_style,
 
             answerback_string: new.answerback_string != old.answerback_string,
@@ -217,6 +268,9 @@ impl ConfigChanges {
                 != old.notifications.notification_max_buffer,
             max_osc_data_length: new.max_osc_data_length != old.max_osc_data_length,
 
+            sensitive_input: new.password_prompt_detection != old.password_prompt_detection
+                || new.mask_password_input != old.mask_password_input,
+
             unicode_width: new.unicode.unicode_version != old.unicode.unicode_version
                 || new.unicode.ambiguous_width != old.unicode.ambiguous_width,
 
@@ -238,6 +292,9 @@ impl ConfigChanges {
                 || new.font_family_bold != old.font_family_bold
                 || new.font_family_italic != old.font_family_italic
                 || new.font_family_bold_italic != old.font_family_bold_italic
+                || new.font_axes != old.font_axes
+                || new.font_ligature_fallback != old.font_ligature_fallback
+                || new.synthesize_styles != old.synthesize_styles
                 || (new.font_size - old.font_size).abs() > f32::EPSILON
                 || (new.line_spacing - old.line_spacing).abs() > f32::EPSILON
                 || (new.char_spacing - old.char_spacing).abs() > f32::EPSILON,
@@ -257,12 +314,21 @@ impl ConfigChanges {
             transparency_mode: new.transparency_affects_only_default_background
                 != old.transparency_affects_only_default_background,
             keep_text_opaque: new.keep_text_opaque != old.keep_text_opaque,
+            text_shadow: new.text_shadow_enabled != old.text_shadow_enabled
+                || new.text_shadow_color != old.text_shadow_color
+                || new.text_shadow_offset != old.text_shadow_offset
+                || (new.text_shadow_blur - old.text_shadow_blur).abs() > f32::EPSILON,
             link_underline_style: new.link_underline_style != old.link_underline_style,
+            selection_corner_radius: (new.selection_corner_radius - old.selection_corner_radius)
+                .abs()
+                > f32::EPSILON,
 
             blur: new.window.blur_enabled != old.window.blur_enabled
                 || new.window.blur_radius != old.window.blur_radius,
 
-            keybindings: new.keybindings != old.keybindings,
+            keybindings: new.keybindings != old.keybindings
+                || new.keybinding_chord_timeout_ms != old.keybinding_chord_timeout_ms
+                || new.keybinding_leader != old.keybinding_leader,
 
             badge: new.badge_enabled != old.badge_enabled
                 || new.badge_format != old.badge_format
@@ -275,6 +341,8 @@ impl ConfigChanges {
                 || (new.badge_max_width - old.badge_max_width).abs() > f32::EPSILON
                 || (new.badge_max_height - old.badge_max_height).abs() > f32::EPSILON,
 
+            clipboard_scope: new.clipboard_scope != old.clipboard_scope,
+
             command_separator: new.command_separator_enabled != old.command_separator_enabled
                 || (new.command_separator_thickness - old.command_separator_thickness).abs()
                     > f32::EPSILON
@@ -1,15 +1,18 @@
 //! Config file watcher setup and polling for WindowState.
 //!
 //! Handles live config reload (YAML changes), config update channel polling,
-//! and MCP screenshot request polling.
+//! and MCP screenshot, shader diagnostics and terminal read/write request polling.
 
 use crate::app::window_state::WindowState;
 use crate::config::Config;
 use par_term_mcp::{
     SCREENSHOT_REQUEST_FILENAME, SCREENSHOT_RESPONSE_FILENAME, SHADER_DIAGNOSTICS_REQUEST_FILENAME,
     SHADER_DIAGNOSTICS_RESPONSE_FILENAME, ShaderDiagnostics, ShaderDiagnosticsEntry,
-    ShaderDiagnosticsRequest, ShaderDiagnosticsResponse, TerminalScreenshotRequest,
-    TerminalScreenshotResponse,
+    ShaderDiagnosticsRequest, ShaderDiagnosticsResponse, TERMINAL_READ_REQUEST_FILENAME,
+    TERMINAL_READ_RESPONSE_FILENAME, TERMINAL_WRITE_MAX_BYTES, TERMINAL_WRITE_REQUEST_FILENAME,
+    TERMINAL_WRITE_RESPONSE_FILENAME, TerminalReadRequest, TerminalReadResponse,
+    TerminalScreenshotRequest, TerminalScreenshotResponse, TerminalWriteRequest,
+    TerminalWriteResponse,
 };
 
 impl WindowState {
@@ -140,6 +143,44 @@ impl WindowState {
         }
     }
 
+    /// Initialize the watcher for `.terminal-write-request.json` (MCP terminal write tool).
+    ///
+    /// The MCP server writes terminal write requests to this file. We watch it,
+    /// write the text to the focused pane's PTY, write a response to
+    /// `.terminal-write-response.json`, and clear the request file.
+    pub(crate) fn init_terminal_write_request_watcher(&mut self) {
+        let request_path = Config::config_dir().join(TERMINAL_WRITE_REQUEST_FILENAME);
+
+        if !request_path.exists() {
+            if let Some(parent) = request_path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            let _ = std::fs::write(&request_path, "");
+        }
+
+        let response_path = Config::config_dir().join(TERMINAL_WRITE_RESPONSE_FILENAME);
+        if !response_path.exists() {
+            if let Some(parent) = response_path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            let _ = std::fs::write(&response_path, "");
+        }
+
+        match crate::config::watcher::ConfigWatcher::new(&request_path, 100) {
+            Ok(watcher) => {
+                debug_info!("CONFIG", "Terminal-write-request watcher initialized");
+                self.watcher_state.terminal_write_request_watcher = Some(watcher);
+            }
+            Err(e) => {
+                debug_info!(
+                    "CONFIG",
+                    "Failed to initialize terminal-write-request watcher: {}",
+                    e
+                );
+            }
+        }
+    }
+
     /// Check for pending config update file changes (from MCP server).
     ///
     /// When the MCP server writes `.config-update.json`, this reads it,
@@ -314,6 +355,253 @@ impl WindowState {
         let _ = std::fs::write(&request_path, "");
     }
 
+    /// Initialize the watcher for `.terminal-read-request.json` (MCP terminal read tool).
+    ///
+    /// The MCP server writes terminal read requests to this file. We watch it,
+    /// read the focused pane's text, write a response to
+    /// `.terminal-read-response.json`, and clear the request file.
+    pub(crate) fn init_terminal_read_request_watcher(&mut self) {
+        let request_path = Config::config_dir().join(TERMINAL_READ_REQUEST_FILENAME);
+
+        if !request_path.exists() {
+            if let Some(parent) = request_path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            let _ = std::fs::write(&request_path, "");
+        }
+
+        let response_path = Config::config_dir().join(TERMINAL_READ_RESPONSE_FILENAME);
+        if !response_path.exists() {
+            if let Some(parent) = response_path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            let _ = std::fs::write(&response_path, "");
+        }
+
+        match crate::config::watcher::ConfigWatcher::new(&request_path, 100) {
+            Ok(watcher) => {
+                debug_info!("CONFIG", "Terminal-read-request watcher initialized");
+                self.watcher_state.terminal_read_request_watcher = Some(watcher);
+            }
+            Err(e) => {
+                debug_info!(
+                    "CONFIG",
+                    "Failed to initialize terminal-read-request watcher: {}",
+                    e
+                );
+            }
+        }
+    }
+
+    /// Check for pending terminal write request file changes (from MCP server).
+    ///
+    /// When the MCP server writes `.terminal-write-request.json`, this writes the
+    /// text to the focused pane's PTY and writes a response to
+    /// `.terminal-write-response.json`.
+    pub(crate) fn check_terminal_write_request_file(&mut self) {
+        let Some(watcher) = &self.watcher_state.terminal_write_request_watcher else {
+            return;
+        };
+        if watcher.try_recv().is_none() {
+            return;
+        }
+
+        let request_path = Config::config_dir().join(TERMINAL_WRITE_REQUEST_FILENAME);
+        let response_path = Config::config_dir().join(TERMINAL_WRITE_RESPONSE_FILENAME);
+
+        let content = match std::fs::read_to_string(&request_path) {
+            Ok(c) if c.trim().is_empty() => return,
+            Ok(c) => c,
+            Err(e) => {
+                log::warn!("ACP terminal write: failed to read request file: {e}");
+                return;
+            }
+        };
+
+        // Clear request file first so the text is written at most once.
+        let _ = std::fs::write(&request_path, "");
+
+        let request = match serde_json::from_str::<TerminalWriteRequest>(&content) {
+            Ok(req) => req,
+            Err(e) => {
+                log::error!("ACP terminal write: invalid JSON in request file: {e}");
+                return;
+            }
+        };
+
+        let response = match self.write_terminal_mcp_request(&request) {
+            Ok(bytes_written) => TerminalWriteResponse {
+                request_id: request.request_id.clone(),
+                ok: true,
+                error: None,
+                bytes_written: Some(bytes_written),
+            },
+            Err(e) => TerminalWriteResponse {
+                request_id: request.request_id.clone(),
+                ok: false,
+                error: Some(e),
+                bytes_written: None,
+            },
+        };
+
+        match serde_json::to_vec_pretty(&response) {
+            Ok(bytes) => {
+                let tmp = response_path.with_extension("json.tmp");
+                if let Err(e) =
+                    std::fs::write(&tmp, &bytes).and_then(|_| std::fs::rename(&tmp, &response_path))
+                {
+                    let _ = std::fs::remove_file(&tmp);
+                    log::error!(
+                        "ACP terminal write: failed to write response {}: {}",
+                        response_path.display(),
+                        e
+                    );
+                }
+            }
+            Err(e) => {
+                log::error!("ACP terminal write: failed to serialize response: {e}");
+            }
+        }
+    }
+
+    /// Write an MCP terminal write request to the focused pane's PTY,
+    /// returning the number of bytes written.
+    fn write_terminal_mcp_request(&self, request: &TerminalWriteRequest) -> Result<usize, String> {
+        // The MCP server validates too; re-check since the file is the trust boundary.
+        if request.text.is_empty() {
+            return Err("Refusing to write empty text".to_string());
+        }
+        if request.text.len() > TERMINAL_WRITE_MAX_BYTES {
+            return Err(format!(
+                "Text is {} bytes; the limit is {TERMINAL_WRITE_MAX_BYTES}",
+                request.text.len()
+            ));
+        }
+
+        let tab = self
+            .tab_manager
+            .active_tab()
+            .ok_or_else(|| "No active terminal".to_string())?;
+        let terminal = tab
+            .pane_manager
+            .as_ref()
+            .and_then(|pm| pm.focused_pane())
+            .map(|pane| pane.terminal.clone())
+            .unwrap_or_else(|| tab.terminal.clone());
+
+        let mut data = request.text.clone().into_bytes();
+        if request.submit {
+            data.push(b'\r');
+        }
+        // blocking_read: agent-requested write — must not be dropped on a busy lock.
+        terminal
+            .blocking_read()
+            .write(&data)
+            .map_err(|e| format!("Failed to write to terminal: {e}"))?;
+        log::info!(
+            "ACP terminal write: wrote {} bytes (submit={})",
+            data.len(),
+            request.submit
+        );
+        Ok(data.len())
+    }
+
+    /// Check for pending terminal read request file changes (from MCP server).
+    ///
+    /// When the MCP server writes `.terminal-read-request.json`, this reads the
+    /// focused pane's text and writes a response to
+    /// `.terminal-read-response.json`.
+    pub(crate) fn check_terminal_read_request_file(&mut self) {
+        let Some(watcher) = &self.watcher_state.terminal_read_request_watcher else {
+            return;
+        };
+        if watcher.try_recv().is_none() {
+            return;
+        }
+
+        let request_path = Config::config_dir().join(TERMINAL_READ_REQUEST_FILENAME);
+        let response_path = Config::config_dir().join(TERMINAL_READ_RESPONSE_FILENAME);
+
+        let content = match std::fs::read_to_string(&request_path) {
+            Ok(c) if c.trim().is_empty() => return,
+            Ok(c) => c,
+            Err(e) => {
+                log::warn!("ACP terminal read: failed to read request file: {e}");
+                return;
+            }
+        };
+
+        let _ = std::fs::write(&request_path, "");
+
+        let request = match serde_json::from_str::<TerminalReadRequest>(&content) {
+            Ok(req) => req,
+            Err(e) => {
+                log::error!("ACP terminal read: invalid JSON in request file: {e}");
+                return;
+            }
+        };
+
+        let response = match self.read_terminal_mcp_text(&request) {
+            Ok(text) => TerminalReadResponse {
+                request_id: request.request_id.clone(),
+                ok: true,
+                error: None,
+                text: Some(text),
+            },
+            Err(e) => TerminalReadResponse {
+                request_id: request.request_id.clone(),
+                ok: false,
+                error: Some(e),
+                text: None,
+            },
+        };
+
+        match serde_json::to_vec_pretty(&response) {
+            Ok(bytes) => {
+                let tmp = response_path.with_extension("json.tmp");
+                if let Err(e) =
+                    std::fs::write(&tmp, &bytes).and_then(|_| std::fs::rename(&tmp, &response_path))
+                {
+                    let _ = std::fs::remove_file(&tmp);
+                    log::error!(
+                        "ACP terminal read: failed to write response {}: {}",
+                        response_path.display(),
+                        e
+                    );
+                }
+            }
+            Err(e) => {
+                log::error!("ACP terminal read: failed to serialize response: {e}");
+            }
+        }
+    }
+
+    /// Read the focused pane's screen text for an MCP terminal read request.
+    fn read_terminal_mcp_text(&self, request: &TerminalReadRequest) -> Result<String, String> {
+        let tab = self
+            .tab_manager
+            .active_tab()
+            .ok_or_else(|| "No active terminal".to_string())?;
+        let terminal = tab
+            .pane_manager
+            .as_ref()
+            .and_then(|pm| pm.focused_pane())
+            .map(|pane| pane.terminal.clone())
+            .unwrap_or_else(|| tab.terminal.clone());
+
+        // blocking_read: agent-requested read — must not fail on a busy lock.
+        let text = terminal
+            .blocking_read()
+            .read_text(request.include_scrollback_lines as usize)
+            .map_err(|e| format!("Failed to read terminal: {e}"))?;
+        log::info!(
+            "ACP terminal read: returned {} bytes ({} scrollback lines requested)",
+            text.len(),
+            request.include_scrollback_lines
+        );
+        Ok(text)
+    }
+
     fn capture_shader_diagnostics_mcp_response(
         &self,
         request_id: &str,
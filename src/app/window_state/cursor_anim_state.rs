@@ -2,18 +2,30 @@
 //!
 //! Groups the four fields that govern cursor blink animation so they travel
 //! together and can be reasoned about in isolation from the rest of `WindowState`.
+//!
+//! Blinking is driven by a single shared clock (`last_cursor_blink` is its
+//! epoch).  Every pane samples the same phase via [`CursorAnimState::blink_opacity`],
+//! so split-pane cursors blink in lockstep instead of drifting out of phase.
+//!
+//! With `cursor_blink_only_focused`, losing window focus freezes the clock:
+//! the cursor stays fully visible until focus returns, when the cycle
+//! restarts from the visible phase.
+
+use std::time::{Duration, Instant};
 
 /// Animation state for the terminal cursor (blink, fade, timing).
 #[derive(Debug)]
 pub(crate) struct CursorAnimState {
     /// Cursor opacity for smooth fade animation (0.0 = invisible, 1.0 = fully visible)
     pub(crate) cursor_opacity: f32,
-    /// Time of last cursor blink toggle
+    /// Epoch of the shared blink clock (reset on key press and focus change)
     pub(crate) last_cursor_blink: Option<std::time::Instant>,
     /// Time of last key press (to reset cursor blink)
     pub(crate) last_key_press: Option<std::time::Instant>,
     /// When to blink cursor next (used by about_to_wait scheduling)
     pub(crate) cursor_blink_timer: Option<std::time::Instant>,
+    /// Blinking is frozen steady while the window is unfocused
+    pub(crate) blink_frozen: bool,
 }
 
 impl Default for CursorAnimState {
@@ -23,6 +35,173 @@ impl Default for CursorAnimState {
             last_cursor_blink: None,
             last_key_press: None,
             cursor_blink_timer: None,
+            blink_frozen: false,
+        }
+    }
+}
+
+impl CursorAnimState {
+    /// Update blink state for a window focus change at `now`.
+    ///
+    /// Losing focus shows the cursor fully and stops the clock (so a cursor
+    /// caught in the off phase doesn't stay hidden); with `freeze_unfocused`
+    /// it stays steady until focus returns.  Gaining focus restarts the
+    /// clock from the visible phase.
+    pub(crate) fn set_focused(&mut self, focused: bool, now: Instant, freeze_unfocused: bool) {
+        if focused {
+            if self.blink_frozen {
+                self.blink_frozen = false;
+                self.last_cursor_blink = Some(now);
+            }
+        } else {
+            self.cursor_opacity = 1.0;
+            self.last_cursor_blink = None;
+            self.cursor_blink_timer = None;
+            self.blink_frozen = freeze_unfocused;
+        }
+    }
+
+    /// Position in the shared blink cycle at `now`, in `[0.0, 2.0)`.
+    ///
+    /// One unit corresponds to one `interval`; the phase wraps every two
+    /// intervals (a full on → off → on → off cycle).  Returns `0.0` (fully
+    /// visible) before the clock has started and while it is frozen.
+    pub(crate) fn blink_phase(&self, now: Instant, interval: Duration) -> f32 {
+        let Some(epoch) = self.last_cursor_blink.filter(|_| !self.blink_frozen) else {
+            return 0.0;
+        };
+        let interval_ms = interval.as_millis().max(1);
+        let elapsed_ms = now.saturating_duration_since(epoch).as_millis() % (interval_ms * 2);
+        elapsed_ms as f32 / interval_ms as f32
+    }
+
+    /// Cursor opacity sampled from the shared blink clock.
+    ///
+    /// Uses a cosine wave for a smooth fade (1.0 → 0.0 → 1.0 per interval).
+    pub(crate) fn blink_opacity(&self, now: Instant, interval: Duration) -> f32 {
+        (self.blink_phase(now, interval) * std::f32::consts::PI)
+            .cos()
+            .abs()
+            .clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INTERVAL: Duration = Duration::from_millis(500);
+
+    /// On/off state a pane derives from the shared clock.
+    fn is_on(state: &CursorAnimState, now: Instant) -> bool {
+        state.blink_opacity(now, INTERVAL) >= 0.5
+    }
+
+    #[test]
+    fn panes_sampling_shared_clock_agree() {
+        let epoch = Instant::now();
+        let ms = |n: u64| Duration::from_millis(n);
+
+        // The left pane has been open since the clock started; the right pane
+        // was split off later, mid-cycle, and has its own key and fade state.
+        // Both are driven by the same clock epoch.
+        let left = CursorAnimState {
+            last_cursor_blink: Some(epoch),
+            ..CursorAnimState::default()
+        };
+        let right = CursorAnimState {
+            cursor_opacity: 0.3,
+            last_cursor_blink: left.last_cursor_blink,
+            last_key_press: Some(epoch + ms(730)),
+            cursor_blink_timer: Some(epoch + ms(900)),
+            blink_frozen: false,
+        };
+
+        let mut phases_seen = Vec::new();
+        for n in (740..4000).step_by(37) {
+            let now = epoch + ms(n);
+            let left_phase = left.blink_phase(now, INTERVAL);
+            let right_phase = right.blink_phase(now, INTERVAL);
+            assert_eq!(left_phase, right_phase, "pane phases differ at {n}ms");
+            assert_eq!(is_on(&left, now), is_on(&right, now), "{n}ms");
+            phases_seen.push(left_phase);
         }
+        // The samples cover the whole cycle, so agreement is not trivial
+        assert!(phases_seen.iter().any(|&p| p > 1.5));
+        assert!(phases_seen.iter().any(|&p| (0.4..0.6).contains(&p)));
+
+        // A pane with its own clock started at split time would drift
+        let own_clock = CursorAnimState {
+            last_cursor_blink: Some(epoch + ms(730)),
+            ..CursorAnimState::default()
+        };
+        assert_ne!(
+            own_clock.blink_phase(epoch + ms(1000), INTERVAL),
+            left.blink_phase(epoch + ms(1000), INTERVAL)
+        );
+    }
+
+    #[test]
+    fn blink_phase_wraps_every_two_intervals() {
+        let epoch = Instant::now();
+        let state = CursorAnimState {
+            last_cursor_blink: Some(epoch),
+            ..CursorAnimState::default()
+        };
+
+        assert!(is_on(&state, epoch));
+        assert!(!is_on(&state, epoch + Duration::from_millis(250)));
+        assert!(is_on(&state, epoch + Duration::from_millis(500)));
+        assert_eq!(
+            state.blink_phase(epoch + Duration::from_millis(1100), INTERVAL),
+            state.blink_phase(epoch + Duration::from_millis(100), INTERVAL)
+        );
+    }
+
+    #[test]
+    fn unfocused_blink_is_frozen_and_resumes_on_focus() {
+        let epoch = Instant::now();
+        let mut state = CursorAnimState {
+            last_cursor_blink: Some(epoch),
+            ..CursorAnimState::default()
+        };
+        let ms = |n: u64| Duration::from_millis(n);
+
+        // Blur mid-cycle while the cursor is in its off phase
+        state.cursor_opacity = 0.0;
+        state.set_focused(false, epoch + ms(250), true);
+        assert!(state.blink_frozen);
+        assert_eq!(state.cursor_opacity, 1.0);
+        // Even if something restarts the clock, the cursor stays steady
+        state.last_cursor_blink = Some(epoch + ms(300));
+        for n in (300..3000).step_by(50) {
+            assert_eq!(state.blink_opacity(epoch + ms(n), INTERVAL), 1.0, "{n}ms");
+        }
+
+        // Focus returns: the cycle restarts visible at the configured rate
+        let focus = epoch + ms(5000);
+        state.set_focused(true, focus, true);
+        assert!(!state.blink_frozen);
+        assert!(is_on(&state, focus));
+        assert!(!is_on(&state, focus + ms(250)));
+        assert!(is_on(&state, focus + INTERVAL));
+        let slow = Duration::from_millis(1000);
+        assert_eq!(state.blink_phase(focus + ms(500), slow), 0.5);
+    }
+
+    #[test]
+    fn unfocused_blink_continues_without_freeze() {
+        let epoch = Instant::now();
+        let mut state = CursorAnimState::default();
+        state.set_focused(false, epoch, false);
+        assert!(!state.blink_frozen);
+        state.last_cursor_blink = Some(epoch);
+        assert!(!is_on(&state, epoch + Duration::from_millis(250)));
+    }
+
+    #[test]
+    fn clock_not_started_is_fully_visible() {
+        let state = CursorAnimState::default();
+        assert_eq!(state.blink_opacity(Instant::now(), INTERVAL), 1.0);
     }
 }
@@ -2,6 +2,7 @@
 //!
 //! Extracted from `WindowState` as part of the God Object decomposition (ARC-001).
 
+use crate::pane::hover_focus::PaneHoverFocus;
 use crate::tab::TabId;
 use std::time::Instant;
 
@@ -47,6 +48,8 @@ pub(crate) struct FocusState {
     /// rects) as a fallback in case egui's own click detection doesn't fire
     /// (e.g. pointer state was stale when the window was unfocused).
     pub(crate) pending_focus_tab_switch: Option<TabId>,
+    /// Pending pane focus switch for `pane_focus_follows_mouse`
+    pub(crate) pane_hover_focus: PaneHoverFocus,
 }
 
 impl Default for FocusState {
@@ -64,6 +67,7 @@ impl Default for FocusState {
             focus_click_pending: false,
             focus_click_suppressed_while_unfocused_at: None,
             pending_focus_tab_switch: None,
+            pane_hover_focus: PaneHoverFocus::default(),
         }
     }
 }
@@ -365,7 +365,7 @@ impl Drop for WindowState {
 
         // Fire-and-forget: drop the cloned terminal Arcs on background threads.
         // When our clone is the last reference, TerminalManager::drop runs,
-        // which triggers PtySession::drop (up to 2s reader thread wait).
+        // which triggers PtyHost::drop (up to 2s reader thread wait).
         // By running these in parallel, all sessions clean up concurrently.
         // We intentionally do NOT join these threads — the process is exiting
         // and the OS will reclaim all resources.
@@ -21,6 +21,24 @@ use tokio::runtime::Runtime;
 use winit::window::Window;
 
 impl WindowState {
+    /// Build the keybinding registry for `config`, including its chord timeout
+    /// and leader key.
+    pub(crate) fn build_keybinding_registry(config: &Config) -> KeybindingRegistry {
+        let leader = match config.keybinding_leader.trim() {
+            "" => None,
+            leader => crate::keybindings::parser::parse_key_combo(leader)
+                .inspect_err(|error| {
+                    log::warn!("Invalid keybinding leader '{}': {}", leader, error)
+                })
+                .ok(),
+        };
+        KeybindingRegistry::from_config(&config.keybindings)
+            .with_chord_timeout(std::time::Duration::from_millis(
+                config.keybinding_chord_timeout_ms,
+            ))
+            .with_leader(leader)
+    }
+
     pub(crate) fn parse_custom_action_prefix_combo(prefix_key: &str) -> Option<KeyCombo> {
         let trimmed = prefix_key.trim();
         if trimmed.is_empty() {
@@ -42,7 +60,7 @@ impl WindowState {
 
     /// Create a new window state with the given configuration
     pub fn new(config: Config, runtime: Arc<Runtime>) -> Self {
-        let keybinding_registry = KeybindingRegistry::from_config(&config.keybindings);
+        let keybinding_registry = Self::build_keybinding_registry(&config);
         let custom_action_prefix_combo =
             Self::parse_custom_action_prefix_combo(&config.custom_action_prefix_key);
         let shaders_dir = Config::shaders_dir();
@@ -52,6 +70,13 @@ impl WindowState {
         // Initialize Option/Alt key modes from config
         input_handler
             .update_option_key_modes(config.left_option_key_mode, config.right_option_key_mode);
+        input_handler.update_alt_sends_escape(config.alt_sends_escape);
+        input_handler.update_backspace_sends(config.backspace_sends);
+        input_handler.update_key_repeat(
+            config.key_repeat_delay_ms,
+            config.key_repeat_interval_ms,
+            &config.key_repeat_keys,
+        );
 
         // Create badge state and overlay UI before wrapping config in ArcSwap
         let badge_state = BadgeState::new(&config);
@@ -87,6 +112,7 @@ impl WindowState {
             is_recording: false,
             is_shutting_down: false,
             window_index: 1, // Will be set by WindowManager when window is created
+            geometry_profile_id: None,
 
             focus_state: FocusState::default(),
 
@@ -124,6 +150,7 @@ impl WindowState {
             notification_click_state: super::NotificationClickState::default(),
 
             pending_snap_size: None,
+            pending_command_split: None,
 
             last_workflow_context: std::sync::Arc::new(std::sync::Mutex::new(None)),
         }
@@ -349,6 +376,10 @@ impl WindowState {
         // Initialize shader-diagnostics-request watcher (MCP server diagnostics tool writes here)
         self.init_shader_diagnostics_request_watcher();
 
+        // Initialize terminal-write-request watcher (MCP server terminal write tool writes here)
+        self.init_terminal_write_request_watcher();
+        self.init_terminal_read_request_watcher();
+
         // Sync status bar monitor state based on config
         {
             let cfg = self.config.load();
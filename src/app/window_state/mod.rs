@@ -169,6 +169,9 @@ pub struct WindowState {
     pub(crate) is_shutting_down: bool,
     /// Window index (1-based) for display in title bar
     pub(crate) window_index: usize,
+    /// Profile this window was opened for when `remember_profile_window_geometry`
+    /// is enabled; its geometry is written back to the profile on close.
+    pub(crate) geometry_profile_id: Option<crate::profile::ProfileId>,
 
     // =========================================================================
     // egui overlay layer (ARC-001 extraction: EguiState)
@@ -253,4 +256,11 @@ pub struct WindowState {
     /// Tracks the last size we requested via `request_inner_size` for snap-to-grid.
     /// Cleared once we receive a Resized event matching this size, preventing infinite re-snap.
     pub(crate) pending_snap_size: Option<winit::dpi::PhysicalSize<u32>>,
+
+    // =========================================================================
+    // Split-on-command
+    // =========================================================================
+    /// `split_on_commands` entry that started in the focused pane this frame;
+    /// the split happens once the frame has been rendered.
+    pub(crate) pending_command_split: Option<String>,
 }
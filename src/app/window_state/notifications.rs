@@ -5,6 +5,7 @@
 //! - OSC 99 (Kitty) notification metadata: id-based grouping/replacement and
 //!   click-to-activate `focus`/`report` actions
 //! - Bell events (audio, visual, desktop)
+//! - Completion of long-running commands while the window is unfocused
 //!
 //! ## OSC 99 click-to-activate
 //!
@@ -31,7 +32,7 @@ use crate::terminal::TerminalManager;
 use par_term_emu_core_rust::terminal::Urgency;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, OnceLock, Weak};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
 /// Maximum number of pending click registrations retained per window. Oldest
@@ -104,6 +105,47 @@ struct CollectedNotification {
     actions: Vec<String>,
 }
 
+/// Title and body of a command completion notification: the outcome and tab
+/// in the title, the command with its exit status and run time in the body.
+fn command_finished_notification(
+    tab_title: &str,
+    command: Option<&str>,
+    exit_code: Option<i32>,
+    elapsed: Duration,
+) -> (String, String) {
+    let outcome = match exit_code {
+        Some(code) if code != 0 => "Command failed",
+        _ => "Command finished",
+    };
+    let title = if tab_title.is_empty() {
+        outcome.to_string()
+    } else {
+        format!("{outcome} in {tab_title}")
+    };
+
+    let status = match exit_code {
+        Some(code) => format!("Exit status {code} after {}", format_elapsed(elapsed)),
+        None => format!("Finished after {}", format_elapsed(elapsed)),
+    };
+    let message = match command {
+        Some(command) if !command.is_empty() => format!("{command}\n{status}"),
+        _ => status,
+    };
+    (title, message)
+}
+
+/// Format a run time as hours and minutes, minutes and seconds, or seconds.
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs >= 3600 {
+        format!("{}h {}m", secs / 3600, (secs % 3600) / 60)
+    } else if secs >= 60 {
+        format!("{}m {}s", secs / 60, secs % 60)
+    } else {
+        format!("{secs}s")
+    }
+}
+
 impl WindowState {
     /// Check for OSC 9/777/99 notifications across every tab and pane's terminal.
     ///
@@ -111,13 +153,21 @@ impl WindowState {
     /// (not just the focused one) — falling back to `tab.terminal` when there is no
     /// pane manager — so notifications emitted in a background tab/pane are delivered
     /// promptly instead of sitting queued until the user focuses it.
+    ///
+    /// Tabs whose profile (or the global config) turns OSC notifications off
+    /// still have their queues drained so they don't pile up, and each terminal
+    /// is held to `notification_osc_rate_limit` per minute.
     pub(crate) fn check_notifications(&mut self) {
         // Collect notifications from all tabs/panes first, deliver after releasing
         // the terminal locks (matches the borrow-safety pattern used elsewhere in
         // this file, e.g. `check_session_exit_notifications`).
         let mut notifications_to_send: Vec<CollectedNotification> = Vec::new();
+        let config = self.config.load();
+        let notification_config = &config.notifications;
 
         for tab in self.tab_manager.tabs() {
+            let enabled =
+                notification_config.osc_notifications_enabled(tab.profile.osc_notifications);
             // Every pane's terminal (falls back to tab.terminal when there's no pane manager),
             // paired with the pane id so a click can re-focus the exact origin pane.
             let terminals: Vec<(Option<PaneId>, Arc<RwLock<TerminalManager>>)> = tab
@@ -140,10 +190,20 @@ impl WindowState {
                 if let Ok(term) = terminal.try_read()
                     && term.has_notifications()
                 {
-                    for notif in term.take_notifications() {
+                    if !enabled {
+                        let dropped = term.take_notifications();
+                        log::debug!(
+                            "Dropped {} OSC notification(s): disabled for this tab",
+                            dropped.len()
+                        );
+                        continue;
+                    }
+                    for notif in term
+                        .take_desktop_notifications(notification_config.notification_osc_rate_limit)
+                    {
                         notifications_to_send.push(CollectedNotification {
-                            title: notif.title,
-                            message: notif.message,
+                            title: notif.title.unwrap_or_default(),
+                            message: notif.body,
                             urgency: notif.urgency,
                             tab_id: tab.id,
                             pane_id,
@@ -156,6 +216,8 @@ impl WindowState {
             }
         }
 
+        drop(config);
+
         for n in notifications_to_send {
             self.deliver_osc99_notification(
                 n.tab_id, n.pane_id, n.terminal, &n.title, &n.message, n.urgency, n.osc_id,
@@ -539,6 +601,79 @@ impl WindowState {
         }
     }
 
+    /// Check every tab and pane for shell commands that finished, notifying
+    /// those that ran long enough while the window was unfocused (see
+    /// `NotificationConfig::should_notify_command_finished`).
+    ///
+    /// Background terminals are never rendered, so their shell integration
+    /// markers are brought up to date here rather than during frame gathering.
+    /// Queues are drained even when the notification is off so that enabling
+    /// it later doesn't report stale commands.
+    pub(crate) fn check_command_finished_notifications(&mut self) {
+        let config = self.config.load();
+        let notification_config = &config.notifications;
+        let enabled = notification_config.notification_command_finished;
+        let mut notifications_to_send: Vec<(String, String)> = Vec::new();
+
+        for tab in self.tab_manager.tabs() {
+            let terminals: Vec<Arc<RwLock<TerminalManager>>> = tab
+                .pane_manager
+                .as_ref()
+                .map(|pm| {
+                    pm.all_panes()
+                        .into_iter()
+                        .map(|pane| Arc::clone(&pane.terminal))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_else(|| vec![Arc::clone(&tab.terminal)]);
+
+            for terminal in terminals {
+                // try_lock: intentional — completion polling in about_to_wait (sync loop).
+                // On miss: finished commands stay queued until the next poll.
+                let Ok(mut term) = terminal.try_write() else {
+                    continue;
+                };
+                if enabled {
+                    let (_, cursor_row) = term.cursor_position();
+                    let sb_len = term.scrollback_len();
+                    term.update_scrollback_metadata(sb_len, cursor_row);
+                }
+                for event in term.drain_finished_commands() {
+                    let par_term_terminal::ShellLifecycleEvent::CommandFinished {
+                        command,
+                        exit_code,
+                        duration_ms,
+                        ..
+                    } = event
+                    else {
+                        continue;
+                    };
+                    let duration = duration_ms.map(Duration::from_millis);
+                    if !notification_config.should_notify_command_finished(
+                        self.focus_state.is_focused,
+                        duration,
+                        exit_code,
+                    ) {
+                        continue;
+                    }
+                    notifications_to_send.push(command_finished_notification(
+                        &tab.title,
+                        command.as_deref(),
+                        exit_code,
+                        duration.unwrap_or_default(),
+                    ));
+                }
+            }
+        }
+
+        drop(config);
+
+        for (title, message) in notifications_to_send {
+            log::info!("Command completion notification: {}", title);
+            self.deliver_notification(&title, &message);
+        }
+    }
+
     /// Check for activity/idle notifications across all tabs.
     ///
     /// This method handles two types of notifications:
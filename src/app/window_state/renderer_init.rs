@@ -5,9 +5,9 @@
 //! and `initialize_async()`.
 
 use crate::config::{
-    BackgroundImageMode, BackgroundMode, Config, CursorShaderMetadata, FontRange, PowerPreference,
-    ShaderMetadata, ThinStrokesMode, UnfocusedCursorStyle, VsyncMode, resolve_cursor_shader_config,
-    resolve_shader_config,
+    BackgroundImageMode, BackgroundMode, BlendColorSpace, Config, CursorShaderMetadata, FontAxes,
+    FontRange, PowerPreference, ShaderMetadata, ThinStrokesMode, UnfocusedCursorStyle, VsyncMode,
+    resolve_cursor_shader_config, resolve_shader_config,
 };
 
 /// Expand tilde in path to home directory
@@ -34,6 +34,9 @@ pub(crate) struct RendererInitParams {
     pub font_family_italic: Option<String>,
     pub font_family_bold_italic: Option<String>,
     pub font_ranges: Vec<FontRange>,
+    pub font_axes: FontAxes,
+    pub font_ligature_fallback: Option<String>,
+    pub synthesize_styles: bool,
     pub font_size: f32,
     pub window_padding: f32,
     pub line_spacing: f32,
@@ -42,6 +45,8 @@ pub(crate) struct RendererInitParams {
     pub scrollbar_width: f32,
     pub scrollbar_thumb_color: [f32; 4],
     pub scrollbar_track_color: [f32; 4],
+    pub scrollbar_autohide_delay: u64,
+    pub scrollbar_fade_duration: u64,
     pub enable_text_shaping: bool,
     pub enable_ligatures: bool,
     pub enable_kerning: bool,
@@ -49,8 +54,12 @@ pub(crate) struct RendererInitParams {
     pub font_hinting: bool,
     pub font_thin_strokes: ThinStrokesMode,
     pub minimum_contrast: f32,
+    pub glyph_atlas_size: u32,
+    pub glyph_atlas_max_pages: u32,
     pub vsync_mode: VsyncMode,
     pub power_preference: PowerPreference,
+    pub gpu_adapter: Option<String>,
+    pub blend_color_space: BlendColorSpace,
     pub window_opacity: f32,
     /// Theme background color (used for Default mode and cell backgrounds)
     pub background_color: [u8; 3],
@@ -88,7 +97,12 @@ pub(crate) struct RendererInitParams {
     pub cursor_shader_color: [u8; 3],
     pub transparency_affects_only_default_background: bool,
     pub keep_text_opaque: bool,
+    pub text_shadow_enabled: bool,
+    pub text_shadow_color: [u8; 4],
+    pub text_shadow_offset: [f32; 2],
+    pub text_shadow_blur: f32,
     pub link_underline_style: par_term_config::LinkUnderlineStyle,
+    pub selection_corner_radius: f32,
     // Cursor enhancements
     pub cursor_guide_enabled: bool,
     pub cursor_guide_color: [u8; 4],
@@ -98,6 +112,11 @@ pub(crate) struct RendererInitParams {
     pub cursor_shadow_blur: f32,
     pub cursor_boost: f32,
     pub cursor_boost_color: [u8; 3],
+    pub cursor_morph_duration_ms: u64,
+    pub cursor_smear_length: f32,
+    pub cursor_smear_decay_ms: u64,
+    pub cursor_ligature_width: bool,
+    pub custom_shader_overscan: f32,
     pub unfocused_cursor_style: UnfocusedCursorStyle,
     // Command separator settings
     pub command_separator_enabled: bool,
@@ -164,6 +183,9 @@ impl RendererInitParams {
             font_family_italic: config.font_family_italic.clone(),
             font_family_bold_italic: config.font_family_bold_italic.clone(),
             font_ranges: config.font_ranges.clone(),
+            font_axes: config.font_axes.clone(),
+            font_ligature_fallback: config.font_ligature_fallback.clone(),
+            synthesize_styles: config.synthesize_styles,
             font_size: config.font_size,
             window_padding: config.window.window_padding,
             line_spacing: config.line_spacing,
@@ -172,6 +194,8 @@ impl RendererInitParams {
             scrollbar_width: config.scrollbar_width,
             scrollbar_thumb_color: config.scrollbar_thumb_color,
             scrollbar_track_color: config.scrollbar_track_color,
+            scrollbar_autohide_delay: config.scrollbar_autohide_delay,
+            scrollbar_fade_duration: config.scrollbar_fade_duration,
             enable_text_shaping: config.enable_text_shaping,
             enable_ligatures: config.enable_ligatures,
             enable_kerning: config.enable_kerning,
@@ -179,8 +203,12 @@ impl RendererInitParams {
             font_hinting: config.font_rendering.font_hinting,
             font_thin_strokes: config.font_rendering.font_thin_strokes,
             minimum_contrast: config.font_rendering.minimum_contrast,
+            glyph_atlas_size: config.font_rendering.glyph_atlas_size,
+            glyph_atlas_max_pages: config.font_rendering.glyph_atlas_max_pages,
             vsync_mode: config.vsync_mode,
             power_preference: config.power_preference,
+            gpu_adapter: config.gpu_adapter.clone(),
+            blend_color_space: config.blend_color_space,
             window_opacity: config.window.window_opacity,
             background_color: theme.background.as_array(),
             background_mode: config.background_mode,
@@ -233,7 +261,12 @@ impl RendererInitParams {
             transparency_affects_only_default_background: config
                 .transparency_affects_only_default_background,
             keep_text_opaque: config.keep_text_opaque,
+            text_shadow_enabled: config.text_shadow_enabled,
+            text_shadow_color: config.text_shadow_color,
+            text_shadow_offset: config.text_shadow_offset,
+            text_shadow_blur: config.text_shadow_blur,
             link_underline_style: config.link_underline_style,
+            selection_corner_radius: config.selection_corner_radius,
             cursor_guide_enabled: config.cursor.cursor_guide_enabled,
             cursor_guide_color: config.cursor.cursor_guide_color,
             cursor_shadow_enabled: config.cursor.cursor_shadow_enabled,
@@ -242,6 +275,11 @@ impl RendererInitParams {
             cursor_shadow_blur: config.cursor.cursor_shadow_blur,
             cursor_boost: config.cursor.cursor_boost,
             cursor_boost_color: config.cursor.cursor_boost_color,
+            cursor_morph_duration_ms: config.cursor.cursor_morph_duration_ms,
+            cursor_smear_length: config.cursor.cursor_smear_length,
+            cursor_smear_decay_ms: config.cursor.cursor_smear_decay_ms,
+            cursor_ligature_width: config.cursor.cursor_ligature_width,
+            custom_shader_overscan: config.shader.custom_shader_overscan,
             unfocused_cursor_style: config.cursor.unfocused_cursor_style,
             command_separator_enabled: config.command_separator_enabled,
             command_separator_thickness: config.command_separator_thickness,
@@ -261,6 +299,9 @@ impl RendererInitParams {
             font_family_italic: self.font_family_italic.as_deref(),
             font_family_bold_italic: self.font_family_bold_italic.as_deref(),
             font_ranges: &self.font_ranges,
+            font_axes: &self.font_axes,
+            font_ligature_fallback: self.font_ligature_fallback.as_deref(),
+            synthesize_styles: self.synthesize_styles,
             font_size: self.font_size,
             window_padding: self.window_padding,
             line_spacing: self.line_spacing,
@@ -276,8 +317,12 @@ impl RendererInitParams {
             font_hinting: self.font_hinting,
             font_thin_strokes: self.font_thin_strokes,
             minimum_contrast: self.minimum_contrast,
+            glyph_atlas_size: self.glyph_atlas_size,
+            glyph_atlas_max_pages: self.glyph_atlas_max_pages,
             vsync_mode: self.vsync_mode,
             power_preference: self.power_preference,
+            gpu_adapter: self.gpu_adapter.as_deref(),
+            blend_color_space: self.blend_color_space,
             window_opacity: self.window_opacity,
             background_color: self.background_color,
             background_image_path: self.background_image_path.as_deref(),
@@ -311,7 +356,14 @@ impl RendererInitParams {
             self.transparency_affects_only_default_background,
         );
         renderer.set_keep_text_opaque(self.keep_text_opaque);
+        renderer.update_text_shadow(
+            self.text_shadow_enabled,
+            self.text_shadow_color,
+            self.text_shadow_offset,
+            self.text_shadow_blur,
+        );
         renderer.set_link_underline_style(self.link_underline_style);
+        renderer.update_selection_corner_radius(self.selection_corner_radius);
 
         // Apply background mode (Default, Color, or Image)
         // This must be called after renderer creation to properly set up solid color mode
@@ -343,6 +395,12 @@ impl RendererInitParams {
             self.cursor_shadow_blur,
         );
         renderer.update_cursor_boost(self.cursor_boost, self.cursor_boost_color);
+        renderer.update_cursor_morph_duration(self.cursor_morph_duration_ms);
+        renderer.update_cursor_smear(self.cursor_smear_length, self.cursor_smear_decay_ms);
+        renderer.update_cursor_ligature_width(self.cursor_ligature_width);
+        renderer.update_custom_shader_overscan(self.custom_shader_overscan);
+        renderer
+            .update_scrollbar_autohide(self.scrollbar_autohide_delay, self.scrollbar_fade_duration);
         renderer.update_unfocused_cursor_style(self.unfocused_cursor_style);
 
         // Apply command separator settings
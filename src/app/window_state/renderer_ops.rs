@@ -369,8 +369,9 @@ impl WindowState {
             self.config.load().cursor.cursor_blink
         };
 
-        if !cursor_should_blink {
-            // Smoothly fade to full visibility if blinking disabled (by DECSCUSR or config)
+        if !cursor_should_blink || self.cursor_anim.blink_frozen {
+            // Smoothly fade to full visibility if blinking disabled (by DECSCUSR or
+            // config) or frozen while the window is unfocused
             self.cursor_anim.cursor_opacity = (self.cursor_anim.cursor_opacity + 0.1).min(1.0);
             return;
         }
@@ -386,25 +387,15 @@ impl WindowState {
             return;
         }
 
-        // Smooth cursor blink animation using sine wave for natural fade
+        // Smooth cursor blink animation sampled from the shared blink clock so
+        // every pane's cursor is in the same phase.
         let blink_interval =
             std::time::Duration::from_millis(self.config.load().cursor.cursor_blink_interval);
 
-        if let Some(last_blink) = self.cursor_anim.last_cursor_blink {
-            let elapsed = now.duration_since(last_blink);
-            let progress = (elapsed.as_millis() as f32) / (blink_interval.as_millis() as f32);
-
-            // Use cosine wave for smooth fade in/out (starts at 1.0, fades to 0.0, back to 1.0)
-            self.cursor_anim.cursor_opacity = ((progress * std::f32::consts::PI).cos())
-                .abs()
-                .clamp(0.0, 1.0);
-
-            // Reset timer after full cycle (2x interval for full on+off)
-            if elapsed >= blink_interval * 2 {
-                self.cursor_anim.last_cursor_blink = Some(now);
-            }
+        if self.cursor_anim.last_cursor_blink.is_some() {
+            self.cursor_anim.cursor_opacity = self.cursor_anim.blink_opacity(now, blink_interval);
         } else {
-            // First time, start the blink timer with cursor fully visible
+            // First time, start the blink clock with cursor fully visible
             self.cursor_anim.cursor_opacity = 1.0;
             self.cursor_anim.last_cursor_blink = Some(now);
         }
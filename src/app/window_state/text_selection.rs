@@ -332,7 +332,8 @@ impl WindowState {
 
     /// Extract selected text and normalize it for clipboard copy operations.
     ///
-    /// Applies the `copy_trailing_newline` setting and drops selections that become
+    /// Applies the `copy_trailing_newline` and `copy_line_ending` settings and
+    /// drops selections that become
     /// empty after normalization to avoid clobbering an existing clipboard payload
     /// (for example an image clipboard) with an empty text write.
     pub(crate) fn get_selected_text_for_copy(&self) -> Option<String> {
@@ -341,8 +342,9 @@ impl WindowState {
             return None;
         }
 
+        let config = self.config.load();
         // Inverted config logic: false means strip trailing line endings.
-        if !self.config.load().copy_trailing_newline {
+        if !config.copy_trailing_newline {
             while selected_text.ends_with('\n') || selected_text.ends_with('\r') {
                 selected_text.pop();
             }
@@ -355,6 +357,6 @@ impl WindowState {
             return None;
         }
 
-        Some(selected_text)
+        Some(config.copy_line_ending.apply(&selected_text))
     }
 }
@@ -3,7 +3,7 @@
 //! Covers:
 //! - egui pointer / keyboard ownership queries (`is_egui_using_pointer`, `is_egui_using_keyboard`)
 //! - Modal-visibility query helpers (`any_modal_ui_visible`, `has_egui_text_overlay_visible`)
-//! - Scrollbar visibility logic (`should_show_scrollbar`)
+//! - Scrollbar visibility and auto-hide fade (`update_scrollbar_visibility`)
 
 use super::WindowState;
 
@@ -86,28 +86,51 @@ impl WindowState {
     // Scrollbar visibility
     // ========================================================================
 
-    /// Determine if scrollbar should be visible based on autohide setting and recent activity
-    pub(crate) fn should_show_scrollbar(&self) -> bool {
+    /// Update the scrollbar auto-hide fade and report whether it is visible.
+    ///
+    /// Dragging, being scrolled away from the bottom, and hovering near the
+    /// scrollbar edge count as activity every frame; otherwise the last scroll
+    /// input starts the autohide delay, after which the scrollbar fades out.
+    pub(crate) fn update_scrollbar_visibility(&mut self) -> bool {
+        let activity = {
+            let tab = match self.tab_manager.active_tab() {
+                Some(t) => t,
+                None => return false,
+            };
+
+            // No scrollbar needed if no scrollback available
+            if tab.active_cache().scrollback_len == 0 {
+                return false;
+            }
+
+            if self.scrollbar_pinned_visible() {
+                std::time::Instant::now()
+            } else {
+                tab.active_scroll_state().last_activity
+            }
+        };
+
+        match &mut self.renderer {
+            Some(renderer) => {
+                renderer.record_scrollbar_activity(activity);
+                renderer.apply_scrollbar_fade() > 0.0
+            }
+            None => true,
+        }
+    }
+
+    /// Whether the scrollbar must stay fully visible regardless of autohide.
+    fn scrollbar_pinned_visible(&self) -> bool {
         let tab = match self.tab_manager.active_tab() {
             Some(t) => t,
             None => return false,
         };
 
-        // No scrollbar needed if no scrollback available
-        if tab.active_cache().scrollback_len == 0 {
-            return false;
-        }
-
         // Always show when dragging or moving
         if tab.active_scroll_state().dragging {
             return true;
         }
 
-        // If autohide disabled, always show
-        if self.config.load().scrollbar_autohide_delay == 0 {
-            return true;
-        }
-
         // If scrolled away from bottom, keep visible
         if tab.active_scroll_state().offset > 0 || tab.active_scroll_state().target_offset > 0 {
             return true;
@@ -126,11 +149,6 @@ impl WindowState {
             }
         }
 
-        // Otherwise, hide after delay
-        tab.active_scroll_state()
-            .last_activity
-            .elapsed()
-            .as_millis()
-            < self.config.load().scrollbar_autohide_delay as u128
+        false
     }
 }
@@ -15,4 +15,8 @@ pub(crate) struct WatcherState {
     pub(crate) screenshot_request_watcher: Option<ConfigWatcher>,
     /// Watcher for `.shader-diagnostics-request.json` written by the MCP server
     pub(crate) shader_diagnostics_request_watcher: Option<ConfigWatcher>,
+    /// Watcher for `.terminal-write-request.json` written by the MCP server
+    pub(crate) terminal_write_request_watcher: Option<ConfigWatcher>,
+    /// Watcher for `.terminal-read-request.json` written by the MCP server
+    pub(crate) terminal_read_request_watcher: Option<ConfigWatcher>,
 }
@@ -9,6 +9,9 @@ use std::sync::Arc;
 
 use crate::config::Config;
 use crate::profile::Profile;
+use par_term_config::badge::{
+    BADGE_VARIABLE_PREFIX, BadgeVariable, builtin_badge_variables, interpolate_badge_placeholders,
+};
 
 /// Session variables available for badge interpolation
 #[derive(Debug, Clone, Default)]
@@ -100,6 +103,28 @@ impl SessionVariables {
         }
     }
 
+    /// Enumerate every variable available for interpolation with its current value.
+    ///
+    /// Built-in variables come first in display order, followed by user-set
+    /// custom variables sorted by name.  Feeds the settings UI preview and
+    /// autocomplete list.
+    pub fn available_variables(&self) -> Vec<BadgeVariable> {
+        let mut variables = builtin_badge_variables();
+        for variable in &mut variables {
+            variable.value = self.get(&variable.name);
+        }
+
+        let mut custom: Vec<(&String, &String)> = self.custom.iter().collect();
+        custom.sort_by(|a, b| a.0.cmp(b.0));
+        variables.extend(custom.into_iter().map(|(name, value)| BadgeVariable {
+            name: format!("{BADGE_VARIABLE_PREFIX}{name}"),
+            description: "User variable".to_string(),
+            value: Some(value.clone()),
+            user_defined: true,
+        }));
+        variables
+    }
+
     /// Update the working directory
     pub fn set_path(&mut self, path: String) {
         self.path = path;
@@ -323,34 +348,7 @@ impl BadgeState {
 /// - `\(session.exit_code)` - Last command exit code
 /// - `\(session.current_command)` - Currently running command name
 pub fn interpolate_badge_format(format: &str, variables: &SessionVariables) -> String {
-    let mut result = String::with_capacity(format.len());
-    let mut chars = format.chars().peekable();
-
-    while let Some(ch) = chars.next() {
-        if ch == '\\' && chars.peek() == Some(&'(') {
-            // Skip the '('
-            chars.next();
-
-            // Collect variable name until ')'
-            let mut var_name = String::new();
-            for c in chars.by_ref() {
-                if c == ')' {
-                    break;
-                }
-                var_name.push(c);
-            }
-
-            // Look up variable value
-            if let Some(value) = variables.get(&var_name) {
-                result.push_str(&value);
-            }
-            // If variable not found, output nothing (empty string)
-        } else {
-            result.push(ch);
-        }
-    }
-
-    result
+    interpolate_badge_placeholders(format, |name| variables.get(name))
 }
 
 /// Decode base64-encoded badge format (for OSC 1337 SetBadgeFormat)
@@ -539,6 +537,43 @@ mod tests {
         assert_eq!(vars.get("session.myvar"), Some("myvalue".to_string()));
     }
 
+    #[test]
+    fn test_available_variables_include_builtins() {
+        let vars = SessionVariables {
+            hostname: "myhost".to_string(),
+            ..Default::default()
+        };
+        let available = vars.available_variables();
+
+        for (name, _) in par_term_config::badge::BUILTIN_BADGE_VARIABLES {
+            assert!(available.iter().any(|v| v.name == *name), "missing {name}");
+        }
+        let hostname = available
+            .iter()
+            .find(|v| v.name == "session.hostname")
+            .unwrap();
+        assert_eq!(hostname.value.as_deref(), Some("myhost"));
+        assert!(!hostname.user_defined);
+    }
+
+    #[test]
+    fn test_available_variables_include_user_vars() {
+        let mut vars = SessionVariables::default();
+        vars.set_custom("zeta", "z".to_string());
+        vars.set_custom("alpha", "a".to_string());
+        let available = vars.available_variables();
+
+        let user: Vec<(&str, Option<&str>)> = available
+            .iter()
+            .filter(|v| v.user_defined)
+            .map(|v| (v.name.as_str(), v.value.as_deref()))
+            .collect();
+        assert_eq!(
+            user,
+            vec![("session.alpha", Some("a")), ("session.zeta", Some("z"))]
+        );
+    }
+
     #[test]
     fn test_interpolate_exit_code() {
         let vars = SessionVariables {
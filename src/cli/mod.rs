@@ -6,6 +6,8 @@
 pub mod install;
 
 use crate::config::ShellType;
+use crate::profile::ProfileManager;
+use anyhow::{Result, bail};
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
@@ -58,6 +60,19 @@ pub struct Cli {
     /// Set debug log level (overrides config and RUST_LOG)
     #[arg(long, value_enum, value_name = "LEVEL")]
     pub log_level: Option<LogLevelArg>,
+
+    /// Open the initial tab with the named profile
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Working directory for the initial tab (overrides the profile's)
+    #[arg(long, value_name = "DIR")]
+    pub cwd: Option<PathBuf>,
+
+    /// Check the config file and profiles for invalid values, print any
+    /// problems, and exit (non-zero if there are errors)
+    #[arg(long)]
+    pub check_config: bool,
 }
 
 /// Log level argument for CLI
@@ -166,6 +181,10 @@ pub struct RuntimeOptions {
     pub log_session: bool,
     /// Log level override from CLI
     pub log_level: Option<log::LevelFilter>,
+    /// Profile name for the initial tab (validated at startup)
+    pub profile: Option<String>,
+    /// Absolute working directory for the initial tab
+    pub cwd: Option<String>,
 }
 
 /// Result of CLI processing
@@ -224,17 +243,121 @@ pub fn process_cli() -> CliResult {
             crate::mcp_server::run_mcp_server();
             CliResult::Exit(0)
         }
+        None if cli.check_config => CliResult::Exit(check_config_cli()),
         None => {
-            // Extract runtime options from CLI flags
-            let options = RuntimeOptions {
-                shader: cli.shader,
-                exit_after: cli.exit_after,
-                screenshot: cli.screenshot,
-                command_to_send: cli.command_to_send,
-                log_session: cli.log_session,
-                log_level: cli.log_level.map(|l| l.to_level_filter()),
+            // Profiles are only read when a profile was requested
+            let profiles = if cli.profile.is_some() {
+                match crate::profile::storage::load_profiles() {
+                    Ok(profiles) => profiles,
+                    Err(e) => {
+                        eprintln!("par-term: error: {e:#}");
+                        return CliResult::Exit(2);
+                    }
+                }
+            } else {
+                ProfileManager::new()
             };
-            CliResult::Continue(options)
+            startup_result(cli, &profiles)
+        }
+    }
+}
+
+/// Load and validate the config and profiles, printing each problem found.
+///
+/// Nothing is written: a missing config file is reported, not created.
+/// Returns the exit code: 1 if the config could not be loaded or has errors,
+/// 0 otherwise (warnings alone do not fail the check).
+pub fn check_config_cli() -> i32 {
+    let path = crate::config::Config::config_path();
+    let config = match crate::config::Config::load_existing() {
+        Ok(Some(config)) => config,
+        Ok(None) => {
+            println!("{}: no config file (defaults apply)", path.display());
+            crate::config::Config::default()
+        }
+        Err(e) => {
+            eprintln!("par-term: error: {}: {e:#}", path.display());
+            return 1;
+        }
+    };
+    let profiles = match crate::profile::storage::load_profiles() {
+        Ok(profiles) => Some(profiles),
+        Err(e) => {
+            eprintln!("par-term: error: profiles: {e:#}");
+            None
+        }
+    };
+    let warnings = config.validate_with(&crate::config::Config::shaders_dir(), profiles.as_ref());
+    for warning in &warnings {
+        println!("{warning}");
+    }
+    if warnings.is_empty() && profiles.is_some() {
+        println!("{}: OK", path.display());
+    }
+    check_config_exit_code(&warnings, profiles.is_some())
+}
+
+/// Exit code for `--check-config`: 1 if any problem is an error or the
+/// profiles could not be loaded.
+pub fn check_config_exit_code(
+    warnings: &[crate::config::ConfigWarning],
+    profiles_loaded: bool,
+) -> i32 {
+    i32::from(!profiles_loaded || warnings.iter().any(|w| w.is_error()))
+}
+
+/// Resolve the options for a normal startup, printing an error and exiting
+/// with code 2 when a startup flag is invalid.
+pub fn startup_result(cli: Cli, profiles: &ProfileManager) -> CliResult {
+    match runtime_options(cli, profiles) {
+        Ok(options) => CliResult::Continue(options),
+        Err(e) => {
+            eprintln!("par-term: error: {e}");
+            CliResult::Exit(2)
         }
     }
 }
+
+/// Extract runtime options from CLI flags.
+///
+/// `--profile` must name a profile in `profiles` (case-insensitive) and
+/// `--cwd` must be an existing directory; relative paths are made absolute.
+pub fn runtime_options(cli: Cli, profiles: &ProfileManager) -> Result<RuntimeOptions> {
+    if let Some(ref name) = cli.profile
+        && profiles.find_by_name(name).is_none()
+    {
+        let mut available: Vec<&str> = profiles
+            .profiles_ordered()
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        if available.is_empty() {
+            available.push("none defined");
+        }
+        bail!(
+            "Profile '{}' not found (available: {})",
+            name,
+            available.join(", ")
+        );
+    }
+    let cwd = match cli.cwd {
+        Some(dir) => {
+            if !dir.is_dir() {
+                bail!("Working directory '{}' does not exist", dir.display());
+            }
+            Some(std::path::absolute(&dir)?.to_string_lossy().into_owned())
+        }
+        None => None,
+    };
+
+    Ok(RuntimeOptions {
+        shader: cli.shader,
+        exit_after: cli.exit_after,
+        screenshot: cli.screenshot,
+        command_to_send: cli.command_to_send,
+        log_session: cli.log_session,
+        log_level: cli.log_level.map(|l| l.to_level_filter()),
+        profile: cli.profile,
+        cwd,
+    })
+}
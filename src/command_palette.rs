@@ -0,0 +1,364 @@
+//! Data provider for the command palette.
+//!
+//! [`CommandPalette`] gathers everything the palette can run into one list:
+//! keybinding actions, profiles, snippets and recent commands.
+//! [`CommandPalette::items`] filters that list for a query and ranks it.
+//!
+//! Ranking uses the same skim fuzzy matcher as the command history search,
+//! ignoring case.
+//! Exact matches rank above prefix matches, which rank above substring
+//! matches, which rank above purely fuzzy ones.  Within a tier the fuzzy
+//! score decides.  Recent commands also get a small bonus for how recently
+//! they ran ([`recency_bonus`]).  It breaks ties but never lifts an item into
+//! a higher tier.
+
+use crate::command_history::{CommandHistory, CommandHistoryEntry};
+use crate::config::{Config, KeyBinding, Profile, ProfileManager, SnippetConfig};
+use crate::keybindings::is_unbind_action;
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
+
+/// Score gap between match tiers; larger than any fuzzy score plus bonus.
+const TIER_WEIGHT: i64 = 1_000_000;
+
+/// Where a palette item comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PaletteSource {
+    /// An action with a keybinding
+    Keybinding,
+    /// A profile to open
+    Profile,
+    /// A snippet to insert
+    Snippet,
+    /// A command from the shell history
+    RecentCommand,
+}
+
+/// One entry in the command palette.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaletteItem {
+    /// Where the item comes from
+    pub source: PaletteSource,
+    /// What to run: the action name, profile ID, snippet ID or command text
+    pub id: String,
+    /// Text shown and matched against the query
+    pub label: String,
+    /// Secondary text, e.g. the key combination of an action
+    pub detail: Option<String>,
+    /// Ranking score for the last query (higher is better)
+    pub score: i64,
+}
+
+impl PaletteItem {
+    fn new(source: PaletteSource, id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            source,
+            id: id.into(),
+            label: label.into(),
+            detail: None,
+            score: 0,
+        }
+    }
+
+    fn with_detail(mut self, detail: Option<String>) -> Self {
+        self.detail = detail.filter(|d| !d.is_empty());
+        self
+    }
+}
+
+/// Searchable list of everything the command palette can run.
+pub struct CommandPalette {
+    /// Items in source order, with their recency bonus as the score
+    items: Vec<PaletteItem>,
+    matcher: SkimMatcherV2,
+}
+
+impl Default for CommandPalette {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandPalette {
+    /// Create an empty palette.
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            matcher: SkimMatcherV2::default().ignore_case(),
+        }
+    }
+
+    /// Build the palette from the config, profiles and command history.
+    pub fn from_sources(
+        config: &Config,
+        profiles: &ProfileManager,
+        history: &CommandHistory,
+        now_ms: u64,
+    ) -> Self {
+        let mut palette = Self::new();
+        palette.add_keybindings(&config.keybindings);
+        palette.add_profiles(profiles.profiles_ordered());
+        palette.add_snippets(&config.snippets);
+        palette.add_recent_commands(history.entries(), now_ms);
+        palette
+    }
+
+    /// Add one item per bound action, listing all of its key combinations.
+    ///
+    /// Keys bound to `unbound`/`none` have no action to run and are skipped.
+    pub fn add_keybindings(&mut self, keybindings: &[KeyBinding]) {
+        for binding in keybindings.iter().filter(|b| !is_unbind_action(&b.action)) {
+            let existing = self
+                .items
+                .iter_mut()
+                .find(|item| item.source == PaletteSource::Keybinding && item.id == binding.action);
+            match existing {
+                Some(item) => {
+                    let keys = item.detail.get_or_insert_with(String::new);
+                    keys.push_str(", ");
+                    keys.push_str(&binding.key);
+                }
+                None => self.items.push(
+                    PaletteItem::new(
+                        PaletteSource::Keybinding,
+                        &binding.action,
+                        binding.action.replace('_', " "),
+                    )
+                    .with_detail(Some(binding.key.clone())),
+                ),
+            }
+        }
+    }
+
+    /// Add one item per profile.
+    pub fn add_profiles<'a>(&mut self, profiles: impl IntoIterator<Item = &'a Profile>) {
+        for profile in profiles {
+            self.items.push(
+                PaletteItem::new(
+                    PaletteSource::Profile,
+                    profile.id.to_string(),
+                    &profile.name,
+                )
+                .with_detail(profile.working_directory.clone()),
+            );
+        }
+    }
+
+    /// Add one item per enabled snippet.
+    pub fn add_snippets(&mut self, snippets: &[SnippetConfig]) {
+        for snippet in snippets.iter().filter(|s| s.enabled) {
+            self.items.push(
+                PaletteItem::new(PaletteSource::Snippet, &snippet.id, &snippet.title)
+                    .with_detail(snippet.folder.clone()),
+            );
+        }
+    }
+
+    /// Add history entries (newest first), weighted by how recently they ran.
+    pub fn add_recent_commands<'a>(
+        &mut self,
+        entries: impl IntoIterator<Item = &'a CommandHistoryEntry>,
+        now_ms: u64,
+    ) {
+        for entry in entries {
+            let mut item =
+                PaletteItem::new(PaletteSource::RecentCommand, &entry.command, &entry.command);
+            item.score = recency_bonus(entry.timestamp_ms, now_ms);
+            self.items.push(item);
+        }
+    }
+
+    /// Total number of items, before filtering.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether the palette has no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Items matching `query`, best first.
+    ///
+    /// An empty query returns every item in source order.
+    pub fn items(&self, query: &str) -> Vec<PaletteItem> {
+        let query = query.trim();
+        if query.is_empty() {
+            return self.items.clone();
+        }
+
+        let query_lower = query.to_lowercase();
+        let mut matches: Vec<PaletteItem> = self
+            .items
+            .iter()
+            .filter_map(|item| {
+                let fuzzy = self.matcher.fuzzy_match(&item.label, query)?;
+                let mut item = item.clone();
+                item.score += match_tier(&item.label, &query_lower) * TIER_WEIGHT + fuzzy;
+                Some(item)
+            })
+            .collect();
+        // Stable, so equal scores keep source order
+        matches.sort_by_key(|item| std::cmp::Reverse(item.score));
+        matches
+    }
+}
+
+/// 3 for an exact match, 2 for a prefix, 1 for a substring, else 0.
+fn match_tier(label: &str, query_lower: &str) -> i64 {
+    let label = label.to_lowercase();
+    if label == query_lower {
+        3
+    } else if label.starts_with(query_lower) {
+        2
+    } else if label.contains(query_lower) {
+        1
+    } else {
+        0
+    }
+}
+
+/// Ranking bonus for a command last run at `timestamp_ms`.
+///
+/// History keeps one entry per command, moved to the front each time it
+/// runs, so recency stands in for frequency here.
+pub fn recency_bonus(timestamp_ms: u64, now_ms: u64) -> i64 {
+    const HOUR_MS: u64 = 60 * 60 * 1000;
+    match now_ms.saturating_sub(timestamp_ms) {
+        age if age < HOUR_MS => 100,
+        age if age < 24 * HOUR_MS => 70,
+        age if age < 7 * 24 * HOUR_MS => 50,
+        age if age < 30 * 24 * HOUR_MS => 30,
+        _ => 10,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW_MS: u64 = 1_700_000_000_000;
+
+    fn entry(command: &str, age_ms: u64) -> CommandHistoryEntry {
+        CommandHistoryEntry {
+            command: command.to_string(),
+            timestamp_ms: NOW_MS - age_ms,
+            exit_code: Some(0),
+            duration_ms: None,
+        }
+    }
+
+    fn palette() -> CommandPalette {
+        let mut palette = CommandPalette::new();
+        palette.add_keybindings(&[
+            KeyBinding {
+                key: "CmdOrCtrl+Shift+B".to_string(),
+                action: "toggle_background_shader".to_string(),
+                ..Default::default()
+            },
+            KeyBinding {
+                key: "CmdOrCtrl+T".to_string(),
+                action: "new_tab".to_string(),
+                ..Default::default()
+            },
+            KeyBinding {
+                key: "CmdOrCtrl+Alt+T".to_string(),
+                action: "new_tab".to_string(),
+                ..Default::default()
+            },
+            KeyBinding {
+                key: "CmdOrCtrl+V".to_string(),
+                action: "unbound".to_string(),
+                ..Default::default()
+            },
+        ]);
+        palette.add_profiles(&[Profile::new("Dev Server"), Profile::new("Tabletop")]);
+        let mut disabled =
+            SnippetConfig::new("old".to_string(), "Old deploy".to_string(), "x".to_string());
+        disabled.enabled = false;
+        palette.add_snippets(&[
+            SnippetConfig::new(
+                "git-status".to_string(),
+                "Git status".to_string(),
+                "git status -sb".to_string(),
+            ),
+            disabled,
+        ]);
+        palette.add_recent_commands(
+            &[
+                entry("cargo test", 0),
+                entry("new tabs", 0),
+                entry("top", 40 * 86_400_000),
+            ],
+            NOW_MS,
+        );
+        palette
+    }
+
+    #[test]
+    fn items_from_every_source_are_listed() {
+        let palette = palette();
+        let all = palette.items("");
+        assert_eq!(all.len(), palette.len());
+        let labels: Vec<(PaletteSource, &str)> =
+            all.iter().map(|i| (i.source, i.label.as_str())).collect();
+        assert_eq!(
+            labels,
+            vec![
+                (PaletteSource::Keybinding, "toggle background shader"),
+                (PaletteSource::Keybinding, "new tab"),
+                (PaletteSource::Profile, "Dev Server"),
+                (PaletteSource::Profile, "Tabletop"),
+                (PaletteSource::Snippet, "Git status"),
+                (PaletteSource::RecentCommand, "cargo test"),
+                (PaletteSource::RecentCommand, "new tabs"),
+                (PaletteSource::RecentCommand, "top"),
+            ]
+        );
+        // Repeated actions are merged, disabled snippets left out
+        assert_eq!(all[1].id, "new_tab");
+        assert_eq!(
+            all[1].detail.as_deref(),
+            Some("CmdOrCtrl+T, CmdOrCtrl+Alt+T")
+        );
+
+        assert_eq!(palette.items("dev")[0].source, PaletteSource::Profile);
+        assert_eq!(palette.items("git")[0].id, "git-status");
+        assert_eq!(
+            palette.items("cargo")[0].source,
+            PaletteSource::RecentCommand
+        );
+        assert!(palette.items("zzz").is_empty());
+    }
+
+    #[test]
+    fn exact_matches_rank_first() {
+        let palette = palette();
+
+        // The exact label wins over a prefix match with a recency bonus
+        let results = palette.items("new tab");
+        assert_eq!(results[0].source, PaletteSource::Keybinding);
+        assert_eq!(results[0].label, "new tab");
+        assert_eq!(results[1].label, "new tabs");
+
+        // Prefix beats substring, case-insensitively; the recency bonus
+        // only orders the two substring matches
+        let results = palette.items("TAB");
+        let labels: Vec<&str> = results.iter().map(|i| i.label.as_str()).collect();
+        assert_eq!(labels, vec!["Tabletop", "new tabs", "new tab"]);
+        assert!(results.windows(2).all(|w| w[0].score > w[1].score));
+
+        // An old exact match still beats a fresh substring match
+        assert_eq!(palette.items("top")[0].source, PaletteSource::RecentCommand);
+    }
+
+    #[test]
+    fn recency_bonus_steps_down_with_age() {
+        let day = 86_400_000;
+        assert_eq!(recency_bonus(NOW_MS, NOW_MS), 100);
+        assert_eq!(recency_bonus(NOW_MS - 2 * day, NOW_MS), 50);
+        assert_eq!(recency_bonus(NOW_MS - 90 * day, NOW_MS), 10);
+        // Clock skew counts as just run
+        assert_eq!(recency_bonus(NOW_MS + day, NOW_MS), 100);
+    }
+}
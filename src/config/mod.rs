@@ -33,9 +33,9 @@ pub use par_term_config::watcher;
 
 // --- Types and structs ---
 pub use par_term_config::{
-    ALLOWED_ENV_VARS, Cell, Color, Config, CustomAcpAgentActionConfig, CustomAcpAgentConfig,
-    ScrollbackMark, Theme, is_env_var_allowed, substitute_variables,
-    substitute_variables_with_allowlist,
+    ALLOWED_ENV_VARS, Cell, Color, Config, ConfigWarning, ConfigWarningSeverity,
+    CustomAcpAgentActionConfig, CustomAcpAgentConfig, ScrollbackMark, Theme, is_env_var_allowed,
+    substitute_variables, substitute_variables_with_allowlist,
 };
 
 // --- Color conversion helpers ---
@@ -46,17 +46,19 @@ pub use par_term_config::{
 
 // --- Config types ---
 pub use par_term_config::{
-    AlertEvent, AlertSoundConfig, BackgroundImageMode, BackgroundMode, CursorShaderConfig,
-    CursorShaderMetadata, CursorStyle, DividerRect, DividerStyle, DownloadSaveLocation,
-    DroppedFileQuoteStyle, FontRange, ImageScalingMode, InstallPromptState, IntegrationVersions,
-    KeyBinding, KeyModifier, LinkUnderlineStyle, LogLevel, ModifierRemapping, ModifierTarget,
+    AlertEvent, AlertSoundConfig, BackgroundImageMode, BackgroundMode, BackspaceSends,
+    BlendColorSpace, ClipboardScope, CopyLineEnding, CursorShaderConfig, CursorShaderMetadata,
+    CursorStyle, DividerRect, DividerStyle, DownloadSaveLocation, DroppedFileQuoteStyle, FontRange,
+    FormFeed, ImageScalingMode, InstallPromptState, IntegrationVersions, KeyBinding, KeyModifier,
+    LinkUnderlineStyle, LogLevel, MarkTrimPolicy, ModifierRemapping, ModifierTarget,
     NewTabPosition, OptionKeyMode, PaneBackground, PaneBackgroundConfig, PaneId, PaneTitlePosition,
-    PowerPreference, ProgressBarPosition, ProgressBarStyle, ResolvedCursorShaderConfig,
+    PasteBracketMarkers, PasteControlSequences, PowerPreference, PrinterOutput,
+    ProgressBarPosition, ProgressBarStyle, PromptContinuation, ResolvedCursorShaderConfig,
     ResolvedShaderConfig, SemanticHistoryEditorMode, SeparatorMark, SessionLogFormat, ShaderConfig,
     ShaderInstallPrompt, ShaderMetadata, ShellExitAction, ShellType, SmartSelectionPrecision,
-    SmartSelectionRule, StartupDirectoryMode, StatusBarPosition, TabBarMode, TabBarPosition, TabId,
-    TabStyle, TabTitleMode, ThinStrokesMode, UnfocusedCursorStyle, UpdateCheckFrequency, VsyncMode,
-    WindowType, default_smart_selection_rules,
+    SmartSelectionRule, StartupDirectoryMode, StatusBarPosition, TabBarMode, TabBarPosition,
+    TabFontFamily, TabId, TabStyle, TabTitleMode, ThinStrokesMode, UnfocusedCursorStyle,
+    UpdateCheckFrequency, VsyncMode, WindowType, default_smart_selection_rules,
 };
 
 // --- Automation ---
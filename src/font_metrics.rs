@@ -39,12 +39,14 @@ const EMBEDDED_FONT: &[u8] = include_bytes!("../fonts/DejaVuSansMono.ttf");
 ///
 /// # Arguments
 /// * `font_family` - Primary font family name (None uses embedded DejaVu Sans Mono)
+/// * `font_axes` - Variable font axis values for the primary font, e.g. `[("wght", 450.0)]`
 /// * `font_size` - Font size in points
 /// * `line_spacing` - Line height multiplier (1.0 = tight, 1.2 = default)
 /// * `char_spacing` - Character width multiplier (1.0 = normal)
 /// * `scale_factor` - Display scale factor (1.0 for standard DPI, 2.0 for Retina)
 pub fn calculate_font_metrics(
     font_family: Option<&str>,
+    font_axes: &[(String, f32)],
     font_size: f32,
     line_spacing: f32,
     char_spacing: f32,
@@ -99,8 +101,12 @@ pub fn calculate_font_metrics(
     let base_font_pixels = font_size * platform_dpi / 72.0;
     let font_size_pixels = (base_font_pixels * scale_factor).max(1.0);
 
-    // Extract font metrics
-    let metrics = font_ref.metrics(&[]);
+    // Extract font metrics at the configured variable font axis values
+    let coords: Vec<_> = font_ref
+        .variations()
+        .normalized_coords(font_axes.iter().map(|(tag, value)| (tag.as_str(), *value)))
+        .collect();
+    let metrics = font_ref.metrics(&coords);
     let scale = font_size_pixels / metrics.units_per_em as f32;
 
     let ascent = metrics.ascent * scale;
@@ -109,7 +115,7 @@ pub fn calculate_font_metrics(
 
     // Get advance width for 'm' character (standard monospace reference)
     let glyph_id = font_ref.charmap().map('m');
-    let char_advance = font_ref.glyph_metrics(&[]).advance_width(glyph_id) * scale;
+    let char_advance = font_ref.glyph_metrics(&coords).advance_width(glyph_id) * scale;
 
     // Calculate cell dimensions (matching CellRenderer::new logic)
     let natural_line_height = ascent + descent + leading;
@@ -171,6 +177,7 @@ pub fn calculate_window_size(
 pub fn window_size_from_config(config: &Config, scale_factor: f32) -> Result<(u32, u32)> {
     let metrics = calculate_font_metrics(
         Some(&config.font_family),
+        &config.font_axes.regular,
         config.font_size,
         config.line_spacing,
         config.char_spacing,
@@ -214,7 +221,7 @@ mod tests {
     #[test]
     fn test_calculate_font_metrics_embedded() {
         // Test with embedded font (no font family specified)
-        let metrics = calculate_font_metrics(None, 13.0, 1.0, 1.0, 1.0)
+        let metrics = calculate_font_metrics(None, &[], 13.0, 1.0, 1.0, 1.0)
             .expect("embedded font metrics calculation should succeed");
 
         assert!(metrics.cell_width > 0.0);
@@ -252,9 +259,9 @@ mod tests {
 
     #[test]
     fn test_line_spacing_affects_cell_height() {
-        let metrics_tight = calculate_font_metrics(None, 13.0, 1.0, 1.0, 1.0)
+        let metrics_tight = calculate_font_metrics(None, &[], 13.0, 1.0, 1.0, 1.0)
             .expect("tight line spacing metrics should succeed");
-        let metrics_spacious = calculate_font_metrics(None, 13.0, 1.5, 1.0, 1.0)
+        let metrics_spacious = calculate_font_metrics(None, &[], 13.0, 1.5, 1.0, 1.0)
             .expect("spacious line spacing metrics should succeed");
 
         // Cell height should be 50% larger with 1.5 line spacing
@@ -264,9 +271,9 @@ mod tests {
 
     #[test]
     fn test_char_spacing_affects_cell_width() {
-        let metrics_normal = calculate_font_metrics(None, 13.0, 1.0, 1.0, 1.0)
+        let metrics_normal = calculate_font_metrics(None, &[], 13.0, 1.0, 1.0, 1.0)
             .expect("normal char spacing metrics should succeed");
-        let metrics_wide = calculate_font_metrics(None, 13.0, 1.0, 1.5, 1.0)
+        let metrics_wide = calculate_font_metrics(None, &[], 13.0, 1.0, 1.5, 1.0)
             .expect("wide char spacing metrics should succeed");
 
         // Cell width should be 50% larger with 1.5 char spacing
@@ -276,9 +283,9 @@ mod tests {
 
     #[test]
     fn test_scale_factor_affects_metrics() {
-        let metrics_1x = calculate_font_metrics(None, 13.0, 1.0, 1.0, 1.0)
+        let metrics_1x = calculate_font_metrics(None, &[], 13.0, 1.0, 1.0, 1.0)
             .expect("1x scale factor metrics should succeed");
-        let metrics_2x = calculate_font_metrics(None, 13.0, 1.0, 1.0, 2.0)
+        let metrics_2x = calculate_font_metrics(None, &[], 13.0, 1.0, 1.0, 2.0)
             .expect("2x scale factor metrics should succeed");
 
         // At 2x scale, metrics should be doubled
@@ -1,8 +1,9 @@
 //! Keybinding system re-exports from the `par-term-keybindings` crate.
 
 pub use par_term_keybindings::{
-    KeyCombo, KeybindingMatcher, KeybindingRegistry, ParseError, key_combo_to_bytes,
-    parse_key_sequence,
+    KeyCombo, KeybindingConflict, KeybindingMatcher, KeybindingMode, KeybindingRegistry,
+    LEADER_ACTION, ParseError, SequenceMatch, detect_conflicts, is_unbind_action,
+    key_combo_to_bytes, parse_key_sequence,
 };
 
 // Re-export submodule for backward compatibility
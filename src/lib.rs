@@ -59,7 +59,8 @@ pub mod badge;
 pub mod cell_renderer {
     //! Cell renderer re-exports from par-term-render crate.
     pub use par_term_render::cell_renderer::{
-        Cell, CellRenderer, PaneViewport, atlas, background, block_chars, pipeline, render, types,
+        Cell, CellRenderer, PaneViewport, SelectionRegion, atlas, background, block_chars,
+        pipeline, render, types,
     };
 }
 pub mod cli;
@@ -67,6 +68,7 @@ pub mod clipboard_history_ui;
 pub mod close_confirmation_ui;
 pub mod command_history;
 pub mod command_history_ui;
+pub mod command_palette;
 pub mod config;
 pub mod copy_mode;
 pub mod font_metrics;
@@ -103,7 +105,8 @@ pub(crate) mod renderer {
     //! Renderer re-exports from the `par-term-render` sub-crate.
     pub use par_term_render::renderer::{
         DividerRenderInfo, PaneDividerSettings, PaneRenderInfo, PaneTitleInfo, Renderer,
-        RendererParams, SplitPanesRenderParams, compute_visible_separator_marks,
+        RendererParams, SplitPanesRenderParams, TAB_PREVIEW_REFRESH, TabPreviewImage,
+        TabPreviewParams, compute_visible_separator_marks,
     };
 }
 pub mod scripting;
@@ -156,7 +159,9 @@ pub mod terminal {
 }
 pub mod text_shaper {
     //! Text shaping re-exports from par-term-fonts crate.
-    pub use par_term_fonts::text_shaper::{ShapedGlyph, ShapedRun, ShapingOptions, TextShaper};
+    pub use par_term_fonts::text_shaper::{
+        ShapedGlyph, ShapedRun, ShapedSegment, ShapingOptions, TextShaper,
+    };
 }
 pub(crate) mod themes {
     //! Terminal color themes re-exports from par-term-config crate.
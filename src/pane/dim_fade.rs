@@ -0,0 +1,144 @@
+//! Animated dimming of inactive panes.
+//!
+//! With `dim_inactive_panes` enabled, unfocused panes render at
+//! `inactive_pane_opacity`.  Instead of jumping between levels when focus
+//! moves, each pane fades from its current level to the new target over
+//! `pane_dim_fade_duration`.  A fade in progress keeps the window redrawing
+//! until it settles; changing the target mid-fade (focus moving again, or the
+//! opacity slider being dragged) continues smoothly from the current level.
+
+use std::time::{Duration, Instant};
+
+/// Dim level of one pane, animated between targets.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PaneDimFade {
+    /// `None` until the first target is set
+    state: Option<FadeState>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FadeState {
+    from: f32,
+    to: f32,
+    start: Instant,
+    duration: Duration,
+}
+
+impl FadeState {
+    fn level(&self, now: Instant) -> f32 {
+        let elapsed = now.saturating_duration_since(self.start);
+        if elapsed >= self.duration {
+            return self.to;
+        }
+        let t = elapsed.as_secs_f32() / self.duration.as_secs_f32();
+        self.from + (self.to - self.from) * t
+    }
+}
+
+impl PaneDimFade {
+    /// Fade towards `target` over `duration`, starting at `now`.
+    ///
+    /// The first target is applied immediately, so new panes do not fade
+    /// in.  Setting the current target again has no effect.
+    pub fn set_target(&mut self, target: f32, now: Instant, duration: Duration) {
+        match &mut self.state {
+            Some(state) if state.to == target => {}
+            Some(state) => {
+                *state = FadeState {
+                    from: state.level(now),
+                    to: target,
+                    start: now,
+                    duration,
+                };
+            }
+            None => {
+                self.state = Some(FadeState {
+                    from: target,
+                    to: target,
+                    start: now,
+                    duration: Duration::ZERO,
+                });
+            }
+        }
+    }
+
+    /// Opacity multiplier at `now` (1.0 before any target is set).
+    pub fn level(&self, now: Instant) -> f32 {
+        self.state.map_or(1.0, |state| state.level(now))
+    }
+
+    /// Whether the level is still changing at `now`.
+    pub fn is_animating(&self, now: Instant) -> bool {
+        self.state
+            .is_some_and(|state| now.saturating_duration_since(state.start) < state.duration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FADE: Duration = Duration::from_millis(200);
+    const DIM: f32 = 0.6;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    fn approx(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn focus_loss_fades_down_over_duration() {
+        let t0 = Instant::now();
+        let mut dim = PaneDimFade::default();
+        assert_eq!(dim.level(t0), 1.0);
+
+        // Focused pane: first target applies without a fade
+        dim.set_target(1.0, t0, FADE);
+        assert!(!dim.is_animating(t0));
+
+        // Focus moves away
+        dim.set_target(DIM, t0, FADE);
+        assert!(dim.is_animating(t0));
+        assert_eq!(dim.level(t0), 1.0);
+        assert!(approx(dim.level(t0 + ms(50)), 0.9));
+        assert!(approx(dim.level(t0 + ms(100)), 0.8));
+        assert!(approx(dim.level(t0 + ms(150)), 0.7));
+        assert!(dim.is_animating(t0 + ms(199)));
+        assert_eq!(dim.level(t0 + FADE), DIM);
+        assert!(!dim.is_animating(t0 + FADE));
+        assert_eq!(dim.level(t0 + ms(5000)), DIM);
+    }
+
+    #[test]
+    fn refocus_mid_fade_reverses_from_current_level() {
+        let t0 = Instant::now();
+        let mut dim = PaneDimFade::default();
+        dim.set_target(1.0, t0, FADE);
+        dim.set_target(DIM, t0, FADE);
+
+        // Halfway down (0.8), focus comes back
+        dim.set_target(1.0, t0 + ms(100), FADE);
+        assert!(approx(dim.level(t0 + ms(100)), 0.8));
+        assert!(approx(dim.level(t0 + ms(200)), 0.9));
+        assert_eq!(dim.level(t0 + ms(300)), 1.0);
+
+        // Re-setting the same target does not restart the fade
+        dim.set_target(1.0, t0 + ms(250), FADE);
+        assert_eq!(dim.level(t0 + ms(300)), 1.0);
+    }
+
+    #[test]
+    fn new_pane_and_zero_duration_do_not_animate() {
+        let t0 = Instant::now();
+        let mut dim = PaneDimFade::default();
+        dim.set_target(DIM, t0, FADE);
+        assert_eq!(dim.level(t0), DIM, "initial target is immediate");
+
+        dim.set_target(1.0, t0, Duration::ZERO);
+        assert_eq!(dim.level(t0), 1.0);
+        assert!(!dim.is_animating(t0));
+    }
+}
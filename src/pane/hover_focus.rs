@@ -0,0 +1,146 @@
+//! Focus follows mouse between split panes.
+//!
+//! With `pane_focus_follows_mouse` enabled, the pane under the mouse takes
+//! focus once the mouse has rested over it for `pane_focus_hover_delay_ms`.
+//! Crossing a pane on the way to another one does not steal focus: the delay
+//! restarts whenever the hovered pane changes, and leaving the panes (or
+//! returning to the focused one) drops the pending switch.  Only mouse
+//! movement starts a switch, so focus moved with the keyboard stays put
+//! while the mouse rests elsewhere.
+
+use crate::pane::PaneId;
+use std::time::{Duration, Instant};
+
+/// Pending hover-to-focus switch for one window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PaneHoverFocus {
+    /// Unfocused pane under the mouse and when the mouse reached it
+    pending: Option<(PaneId, Instant)>,
+}
+
+impl PaneHoverFocus {
+    /// Record that the mouse is over `hovered` at `now` while `focused` has
+    /// focus.
+    ///
+    /// Returns the pane to focus once it has been hovered for `delay`.
+    pub fn update(
+        &mut self,
+        hovered: Option<PaneId>,
+        focused: Option<PaneId>,
+        now: Instant,
+        delay: Duration,
+    ) -> Option<PaneId> {
+        let Some(hovered) = hovered.filter(|&id| Some(id) != focused) else {
+            self.pending = None;
+            return None;
+        };
+        let since = match self.pending {
+            Some((id, since)) if id == hovered => since,
+            _ => {
+                self.pending = Some((hovered, now));
+                now
+            }
+        };
+        if now.saturating_duration_since(since) < delay {
+            return None;
+        }
+        self.pending = None;
+        Some(hovered)
+    }
+
+    /// Re-check a pending switch without mouse movement, e.g. once its
+    /// delay is up.  Never starts a new one.
+    pub fn poll(
+        &mut self,
+        hovered: Option<PaneId>,
+        focused: Option<PaneId>,
+        now: Instant,
+        delay: Duration,
+    ) -> Option<PaneId> {
+        self.pending?;
+        self.update(hovered, focused, now, delay)
+    }
+
+    /// When the pending switch is due, if one is pending.
+    pub fn deadline(&self, delay: Duration) -> Option<Instant> {
+        self.pending.map(|(_, since)| since + delay)
+    }
+
+    /// Drop the pending switch.
+    pub fn cancel(&mut self) {
+        self.pending = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DELAY: Duration = Duration::from_millis(150);
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn focus_switches_after_hover_delay() {
+        let t0 = Instant::now();
+        let mut hover = PaneHoverFocus::default();
+
+        assert_eq!(hover.update(Some(2), Some(1), t0, DELAY), None);
+        assert_eq!(hover.deadline(DELAY), Some(t0 + DELAY));
+        assert_eq!(hover.update(Some(2), Some(1), t0 + ms(149), DELAY), None);
+        assert_eq!(hover.update(Some(2), Some(1), t0 + DELAY, DELAY), Some(2));
+        assert_eq!(hover.deadline(DELAY), None, "switch fires once");
+
+        // Zero delay focuses on the first move
+        assert_eq!(hover.update(Some(3), Some(2), t0, Duration::ZERO), Some(3));
+    }
+
+    #[test]
+    fn moving_to_another_pane_restarts_the_delay() {
+        let t0 = Instant::now();
+        let mut hover = PaneHoverFocus::default();
+        hover.update(Some(2), Some(1), t0, DELAY);
+
+        // Crossing pane 2 into pane 3 before the delay is up
+        assert_eq!(hover.update(Some(3), Some(1), t0 + ms(100), DELAY), None);
+        assert_eq!(hover.update(Some(3), Some(1), t0 + ms(200), DELAY), None);
+        assert_eq!(hover.update(Some(3), Some(1), t0 + ms(250), DELAY), Some(3));
+    }
+
+    #[test]
+    fn focused_pane_or_no_pane_drops_pending_switch() {
+        let t0 = Instant::now();
+        let mut hover = PaneHoverFocus::default();
+
+        hover.update(Some(2), Some(1), t0, DELAY);
+        assert_eq!(hover.update(Some(1), Some(1), t0 + ms(50), DELAY), None);
+        assert_eq!(hover.deadline(DELAY), None);
+        // Coming back to pane 2 starts over
+        assert_eq!(hover.update(Some(2), Some(1), t0 + ms(160), DELAY), None);
+
+        // Over a divider or the tab bar
+        assert_eq!(hover.update(None, Some(1), t0 + ms(400), DELAY), None);
+        assert_eq!(hover.deadline(DELAY), None);
+
+        hover.update(Some(2), Some(1), t0, DELAY);
+        hover.cancel();
+        assert_eq!(hover.deadline(DELAY), None);
+    }
+
+    #[test]
+    fn poll_completes_but_never_starts_a_switch() {
+        let t0 = Instant::now();
+        let mut hover = PaneHoverFocus::default();
+
+        // The mouse stopped over pane 2; the wake-up at the deadline focuses it
+        hover.update(Some(2), Some(1), t0, DELAY);
+        assert_eq!(hover.poll(Some(2), Some(1), t0 + ms(100), DELAY), None);
+        assert_eq!(hover.poll(Some(2), Some(1), t0 + DELAY, DELAY), Some(2));
+
+        // Keyboard moves focus to pane 3 with the mouse still over pane 2
+        assert_eq!(hover.poll(Some(2), Some(3), t0 + ms(1000), DELAY), None);
+        assert_eq!(hover.deadline(DELAY), None);
+    }
+}
@@ -104,6 +104,11 @@ impl PaneManager {
         None
     }
 
+    /// ID of the pane at a given pixel position, without focusing it
+    pub fn pane_id_at(&self, x: f32, y: f32) -> Option<PaneId> {
+        Some(self.root.as_ref()?.find_pane_at(x, y)?.id)
+    }
+
     /// Get the currently focused pane
     pub fn focused_pane(&self) -> Option<&Pane> {
         self.focused_pane_id
@@ -7,9 +7,12 @@
 //! - `PaneId`: Unique identifier for each pane
 
 pub mod bell;
+pub mod dim_fade;
+pub mod hover_focus;
 mod manager;
 pub mod mouse;
 pub mod render_cache;
+pub mod split_on_command;
 mod tmux_helpers;
 mod types;
 
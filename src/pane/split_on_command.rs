@@ -0,0 +1,92 @@
+//! Split-on-command: move the prompt to a new pane when a long-running job starts.
+//!
+//! With `split_on_commands` configured, a command that starts in the focused
+//! pane (reported by shell integration) and matches one of the entries keeps
+//! running where it is while the pane is split and a fresh shell, in the same
+//! working directory, takes focus next to it.
+//!
+//! An entry matches when the command's words start with the entry's words.
+//! The program is compared by file name, so `cargo` matches
+//! `/usr/bin/cargo build`, and leading `VAR=value` assignments are skipped.
+//! Multi-word entries such as `npm run dev` only match that subcommand.
+
+/// The entry in `commands` that `command_line` matches, if any.
+pub fn matching_split_command<'a>(commands: &'a [String], command_line: &str) -> Option<&'a str> {
+    let mut words = command_line
+        .split_whitespace()
+        .skip_while(|word| is_env_assignment(word));
+    let program = words.next()?;
+    let program = program.rsplit('/').next().unwrap_or(program);
+    let args: Vec<&str> = words.collect();
+
+    commands.iter().map(String::as_str).find(|entry| {
+        let mut entry_words = entry.split_whitespace();
+        entry_words.next() == Some(program)
+            && entry_words
+                .enumerate()
+                .all(|(i, word)| args.get(i) == Some(&word))
+    })
+}
+
+/// Whether `word` is a `NAME=value` environment assignment.
+fn is_env_assignment(word: &str) -> bool {
+    word.split_once('=').is_some_and(|(name, _)| {
+        !name.is_empty()
+            && !name.starts_with(|c: char| c.is_ascii_digit())
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commands() -> Vec<String> {
+        ["cargo", "npm run dev", "tail -f"]
+            .map(String::from)
+            .to_vec()
+    }
+
+    #[test]
+    fn matching_command_triggers_a_split() {
+        let commands = commands();
+        assert_eq!(
+            matching_split_command(&commands, "cargo build --release"),
+            Some("cargo")
+        );
+        assert_eq!(
+            matching_split_command(&commands, "  RUST_LOG=debug /usr/bin/cargo run"),
+            Some("cargo")
+        );
+        assert_eq!(
+            matching_split_command(&commands, "npm run dev -- --port 3000"),
+            Some("npm run dev")
+        );
+        assert_eq!(
+            matching_split_command(&commands, "tail -f /var/log/syslog"),
+            Some("tail -f")
+        );
+    }
+
+    #[test]
+    fn other_commands_do_not_split() {
+        let commands = commands();
+        for command in [
+            "ls -la",
+            "cargo-watch",
+            "echo cargo",
+            "npm run build",
+            "npm",
+            "tail /var/log/syslog",
+            "",
+            "FOO=1",
+        ] {
+            assert_eq!(
+                matching_split_command(&commands, command),
+                None,
+                "{command}"
+            );
+        }
+        assert_eq!(matching_split_command(&[], "cargo build"), None);
+    }
+}
@@ -9,6 +9,7 @@ use tokio::task::JoinHandle;
 
 use crate::config::Config;
 use crate::pane::bell::BellState;
+use crate::pane::dim_fade::PaneDimFade;
 use crate::pane::mouse::MouseState;
 use crate::pane::render_cache::RenderCache;
 use crate::scroll_state::ScrollState;
@@ -72,12 +73,17 @@ pub struct Pane {
     pub bounds: PaneBounds,
     /// Per-pane background settings (overrides global config if image_path is set)
     pub background: PaneBackground,
+    /// Animated inactive-pane dim level
+    pub dim_fade: PaneDimFade,
     /// Last-known title from OSC sequences or CWD fallback (empty if never set)
     pub title: String,
     /// True when pane still has its default/fallback title
     pub has_default_title: bool,
     /// State for shell restart behavior (None = shell running or closed normally)
     pub restart_state: Option<RestartState>,
+    /// Non-zero exit code of a shell held open by `shell_exit_keep_on_failure`.
+    /// Held panes are skipped by the close/restart shell-exit handling.
+    pub failed_exit_code: Option<i32>,
     /// Whether the parent tab is active (shared with tab for refresh throttling)
     pub is_active: Arc<AtomicBool>,
     /// When true, Drop impl skips cleanup (terminal Arcs are dropped on background threads)
@@ -146,7 +152,9 @@ impl Pane {
             title: String::new(),
             has_default_title: true,
             background: PaneBackground::new(),
+            dim_fade: PaneDimFade::default(),
             restart_state: None,
+            failed_exit_code: None,
             is_active: Arc::new(AtomicBool::new(false)),
             shutdown_fast: false,
         })
@@ -213,7 +221,9 @@ impl Pane {
             title: String::new(),
             has_default_title: true,
             background: PaneBackground::new(),
+            dim_fade: PaneDimFade::default(),
             restart_state: None,
+            failed_exit_code: None,
             is_active: Arc::new(AtomicBool::new(false)),
             shutdown_fast: false,
         })
@@ -262,7 +272,9 @@ impl Pane {
             title: String::new(),
             has_default_title: true,
             background: PaneBackground::new(),
+            dim_fade: PaneDimFade::default(),
             restart_state: None,
+            failed_exit_code: None,
             is_active,
             shutdown_fast: false,
         }
@@ -313,7 +325,9 @@ impl Pane {
             title: String::new(),
             has_default_title: true,
             background: PaneBackground::new(),
+            dim_fade: PaneDimFade::default(),
             restart_state: None,
+            failed_exit_code: None,
             is_active: Arc::new(AtomicBool::new(false)),
             shutdown_fast: false,
         })
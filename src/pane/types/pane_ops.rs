@@ -22,6 +22,7 @@ impl Pane {
     pub fn respawn_shell(&mut self, config: &Config) -> anyhow::Result<()> {
         // Clear restart state
         self.restart_state = None;
+        self.failed_exit_code = None;
         self.exit_notified = false;
 
         // Determine the shell command to use
@@ -89,6 +90,18 @@ impl Pane {
         }
     }
 
+    /// Hold this pane open after its shell exited with `exit_code`, writing an
+    /// exit status line so the failure stays visible.
+    pub fn hold_after_failed_exit(&mut self, exit_code: i32) {
+        self.failed_exit_code = Some(exit_code);
+        // blocking_read: runs once per exited shell, and the status line must
+        // not be lost to a render holding the lock.
+        let message = crate::config::ShellExitAction::exit_status_line(exit_code);
+        self.terminal
+            .blocking_read()
+            .process_data(message.as_bytes());
+    }
+
     /// Get the title for this pane (from OSC or CWD)
     pub fn get_title(&self) -> String {
         if let Ok(term) = self.terminal.try_read() {
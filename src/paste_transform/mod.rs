@@ -8,7 +8,8 @@
 //!
 //! - [`case`] — case conversion (title, camel, pascal, snake, screaming snake, kebab)
 //! - [`encoding`] — Base64, URL, Hex, and JSON escape/unescape
-//! - [`sanitize`] — clipboard content sanitization (strip dangerous control chars)
+//! - [`sanitize`] — clipboard content sanitization (strip dangerous control chars
+//!   and nested bracketed paste markers)
 //! - [`shell`] — shell quoting and backslash escaping
 //! - [`whitespace`] — whitespace and newline normalization
 
@@ -23,8 +24,13 @@ mod tests;
 
 use std::fmt;
 
+use crate::config::Config;
+
 // Re-export the public API
-pub use sanitize::{paste_contains_control_chars, sanitize_paste_content};
+pub use sanitize::{
+    filter_control_sequences, paste_contains_control_chars, sanitize_paste, sanitize_paste_content,
+    strip_bracketed_paste_markers,
+};
 
 use case::{camel_case, kebab_case, pascal_case, screaming_snake_case, snake_case, title_case};
 use encoding::{
@@ -34,7 +40,7 @@ use encoding::{
 use shell::{shell_backslash_escape, shell_double_quote, shell_single_quote};
 use whitespace::{
     add_newlines, collapse_spaces, normalize_line_endings, paste_as_single_line,
-    remove_empty_lines, remove_newlines, trim_lines,
+    remove_empty_lines, remove_newlines, trim_lines, withhold_final_newline,
 };
 
 /// Available paste transformations.
@@ -225,6 +231,24 @@ impl fmt::Display for PasteTransform {
     }
 }
 
+/// Prepare clipboard text for sending to the terminal.
+///
+/// Sanitizes it per `paste_bracket_markers` and `paste_control_sequences`,
+/// and with `multiline_paste_suppress_final_newline` drops the newline that
+/// would run the last line of a multi-line paste.
+pub fn prepare_paste(input: &str, config: &Config) -> String {
+    let mut text = sanitize_paste(
+        input,
+        config.paste_bracket_markers,
+        config.paste_control_sequences,
+    );
+    if config.multiline_paste_suppress_final_newline {
+        let kept = withhold_final_newline(&text).len();
+        text.truncate(kept);
+    }
+    text
+}
+
 /// Apply a transformation to the input text.
 ///
 /// Returns `Ok(transformed_text)` on success, or `Err(error_message)` if the
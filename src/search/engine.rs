@@ -351,6 +351,60 @@ mod tests {
         assert_eq!(matches[0].length, 5);
     }
 
+    #[test]
+    fn test_scope_limits_matches_to_line_range() {
+        use super::super::types::SearchScope;
+
+        let mut engine = SearchEngine::new();
+        // Three commands; the last five lines are on screen
+        let lines: Vec<&str> = vec![
+            "$ make",
+            "error: build failed",
+            "$ make test",
+            "error: test failed",
+            "ok",
+            "$ make docs",
+            "error: docs failed",
+            "$ ",
+        ];
+        let (scrollback_len, screen_rows) = (3, 5);
+        let last_output = Some(6..7);
+        let config = SearchConfig::default();
+
+        let matched_lines = |engine: &mut SearchEngine, range: std::ops::Range<usize>| {
+            let scoped = make_lines(&lines).filter(|(line, _)| range.contains(line));
+            engine
+                .search(scoped, "error", &config)
+                .iter()
+                .map(|m| m.line)
+                .collect::<Vec<_>>()
+        };
+
+        let all = SearchScope::All.line_range(scrollback_len, screen_rows, last_output.clone());
+        assert_eq!(all, 0..8);
+        assert_eq!(matched_lines(&mut engine, all), vec![1, 3, 6]);
+
+        let screen =
+            SearchScope::Screen.line_range(scrollback_len, screen_rows, last_output.clone());
+        assert_eq!(screen, 3..8);
+        assert_eq!(matched_lines(&mut engine, screen), vec![3, 6]);
+
+        let command = SearchScope::Command.line_range(scrollback_len, screen_rows, last_output);
+        assert_eq!(command, 6..7);
+        assert_eq!(matched_lines(&mut engine, command), vec![6]);
+
+        // Without shell integration the command scope searches the screen
+        assert_eq!(
+            SearchScope::Command.line_range(scrollback_len, screen_rows, None),
+            3..8
+        );
+        // A running command's range is clamped to the buffer
+        assert_eq!(
+            SearchScope::Command.line_range(scrollback_len, screen_rows, Some(6..20)),
+            6..8
+        );
+    }
+
     #[test]
     fn test_invalid_regex() {
         let mut engine = SearchEngine::new();
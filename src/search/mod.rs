@@ -8,9 +8,10 @@ mod engine;
 pub mod types;
 
 pub use engine::SearchEngine;
-pub use types::{SearchAction, SearchConfig, SearchMatch};
+pub use types::{SearchAction, SearchConfig, SearchMatch, SearchScope};
 
 use egui::{Color32, Context, Frame, Key, RichText, Window, epaint::Shadow};
+use std::ops::Range;
 use std::time::Instant;
 
 /// Search debounce delay in milliseconds.
@@ -28,6 +29,8 @@ pub struct SearchUI {
     use_regex: bool,
     /// Whether to match whole words only.
     whole_word: bool,
+    /// Part of the buffer to search.
+    scope: SearchScope,
     /// All matches found.
     matches: Vec<SearchMatch>,
     /// Index of the currently highlighted match.
@@ -46,6 +49,8 @@ pub struct SearchUI {
     last_searched_use_regex: bool,
     /// Last whole word setting that was searched.
     last_searched_whole_word: bool,
+    /// Last scope that was searched.
+    last_searched_scope: SearchScope,
     /// Whether the text input should request focus.
     request_focus: bool,
     /// Regex error message (if any).
@@ -67,6 +72,7 @@ impl SearchUI {
             case_sensitive: false,
             use_regex: false,
             whole_word: false,
+            scope: SearchScope::default(),
             matches: Vec::new(),
             current_match_index: 0,
             engine: SearchEngine::new(),
@@ -76,6 +82,7 @@ impl SearchUI {
             last_searched_case_sensitive: false,
             last_searched_use_regex: false,
             last_searched_whole_word: false,
+            last_searched_scope: SearchScope::default(),
             request_focus: false,
             regex_error: None,
         }
@@ -105,6 +112,11 @@ impl SearchUI {
         &self.query
     }
 
+    /// Get the part of the buffer being searched.
+    pub fn scope(&self) -> SearchScope {
+        self.scope
+    }
+
     /// Get all current matches.
     pub fn matches(&self) -> &[SearchMatch] {
         &self.matches
@@ -153,7 +165,9 @@ impl SearchUI {
     ///
     /// # Arguments
     /// * `lines` - Iterator of (line_index, line_text) pairs from scrollback
-    pub fn update_search<I>(&mut self, lines: I)
+    /// * `scope_lines` - Absolute line range of the current [`SearchScope`];
+    ///   lines outside it are skipped
+    pub fn update_search<I>(&mut self, lines: I, scope_lines: Range<usize>)
     where
         I: Iterator<Item = (usize, String)>,
     {
@@ -167,7 +181,8 @@ impl SearchUI {
         // Check if settings changed
         let settings_changed = self.case_sensitive != self.last_searched_case_sensitive
             || self.use_regex != self.last_searched_use_regex
-            || self.whole_word != self.last_searched_whole_word;
+            || self.whole_word != self.last_searched_whole_word
+            || self.scope != self.last_searched_scope;
 
         // Only re-search if query or settings changed
         if !self.needs_search && self.query == self.last_searched_query && !settings_changed {
@@ -179,6 +194,7 @@ impl SearchUI {
         self.last_searched_case_sensitive = self.case_sensitive;
         self.last_searched_use_regex = self.use_regex;
         self.last_searched_whole_word = self.whole_word;
+        self.last_searched_scope = self.scope;
         self.regex_error = None;
 
         let config = SearchConfig {
@@ -199,6 +215,7 @@ impl SearchUI {
             return;
         }
 
+        let lines = lines.filter(|(line, _)| scope_lines.contains(line));
         self.matches = self.engine.search(lines, &self.query, &config);
 
         // Reset current match index if it's out of bounds
@@ -404,6 +421,17 @@ impl SearchUI {
                         self.needs_search = true;
                     }
 
+                    ui.separator();
+
+                    // Scope selector
+                    for scope in SearchScope::ALL {
+                        let scope_btn = ui.selectable_label(self.scope == scope, scope.label());
+                        if scope_btn.on_hover_text(scope.description()).clicked() {
+                            self.scope = scope;
+                            self.needs_search = true;
+                        }
+                    }
+
                     // Show regex error if present
                     if let Some(ref error) = self.regex_error {
                         ui.colored_label(
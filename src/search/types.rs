@@ -1,5 +1,7 @@
 //! Types for terminal search functionality.
 
+use std::ops::Range;
+
 /// A single search match in the terminal scrollback.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SearchMatch {
@@ -46,6 +48,63 @@ impl Default for SearchConfig {
     }
 }
 
+/// Which part of the terminal buffer a search scans.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SearchScope {
+    /// The live screen only (no scrollback).
+    Screen,
+    /// Output of the running command, or of the last finished command.
+    Command,
+    /// All scrollback plus the screen.
+    #[default]
+    All,
+}
+
+impl SearchScope {
+    /// All scopes, in selector order.
+    pub const ALL: [SearchScope; 3] = [Self::Screen, Self::Command, Self::All];
+
+    /// Short label for the search bar selector.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Screen => "Screen",
+            Self::Command => "Command",
+            Self::All => "All",
+        }
+    }
+
+    /// Hover text for the search bar selector.
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Screen => "Search the visible screen only",
+            Self::Command => "Search the current command's output (needs shell integration)",
+            Self::All => "Search all scrollback",
+        }
+    }
+
+    /// Absolute line range this scope scans (0 = oldest scrollback line).
+    ///
+    /// `command_output` is the output of the current command as recorded by
+    /// shell integration.  Without it, the command scope falls back to the
+    /// screen, where the latest output is.
+    pub fn line_range(
+        self,
+        scrollback_len: usize,
+        screen_rows: usize,
+        command_output: Option<Range<usize>>,
+    ) -> Range<usize> {
+        let total_lines = scrollback_len + screen_rows;
+        match self {
+            Self::All => 0..total_lines,
+            Self::Screen => scrollback_len..total_lines,
+            Self::Command => match command_output {
+                Some(lines) => lines.start.min(total_lines)..lines.end.min(total_lines),
+                None => scrollback_len..total_lines,
+            },
+        }
+    }
+}
+
 /// Actions that can result from search UI interaction.
 #[derive(Debug, Clone)]
 pub enum SearchAction {
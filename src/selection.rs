@@ -88,10 +88,37 @@ impl Selection {
     }
 }
 
+/// Whether the mouse at `position` has moved far enough from where the button
+/// was pressed (`press`) for the press to start a drag selection.
+///
+/// Movement shorter than `threshold` pixels keeps the press a plain click.
+pub fn is_past_drag_threshold(press: (f64, f64), position: (f64, f64), threshold: f64) -> bool {
+    let dx = position.0 - press.0;
+    let dy = position.1 - press.1;
+    // Squared to avoid a sqrt per mouse move
+    dx * dx + dy * dy >= threshold * threshold
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_drag_threshold() {
+        let press = (100.0, 50.0);
+        // Sub-threshold movement is still a click
+        assert!(!is_past_drag_threshold(press, press, 8.0));
+        assert!(!is_past_drag_threshold(press, (105.0, 55.0), 8.0));
+        assert!(!is_past_drag_threshold(press, (92.5, 50.0), 8.0));
+        // At or beyond the threshold a drag starts selecting
+        assert!(is_past_drag_threshold(press, (108.0, 50.0), 8.0));
+        assert!(is_past_drag_threshold(press, (94.0, 58.0), 8.0));
+        // A larger threshold swallows the same movement
+        assert!(!is_past_drag_threshold(press, (108.0, 50.0), 12.0));
+        // Zero threshold selects on any movement
+        assert!(is_past_drag_threshold(press, (100.5, 50.0), 0.0));
+    }
+
     #[test]
     fn test_selection_normalization() {
         // Forward selection
@@ -30,7 +30,8 @@
 //! # Heuristic Redaction Limitations
 //!
 //! The password-prompt detection is **heuristic** and relies on a fixed list of known
-//! prompt strings (see `PASSWORD_PROMPT_PATTERNS` in `core`). It **cannot** guarantee
+//! prompt strings (see `PASSWORD_PROMPT_PATTERNS` in
+//! `par_term_terminal::terminal::sensitive_input`). It **cannot** guarantee
 //! that all sensitive input is redacted. Scenarios where credentials may still be
 //! captured include:
 //!
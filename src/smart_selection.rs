@@ -8,6 +8,10 @@
 use crate::config::SmartSelectionRule;
 use regex::Regex;
 
+// Shared with whole-word search in the terminal crate, so selection and
+// search agree on what a word is.
+pub use par_term_terminal::is_word_char;
+
 /// Compiled smart selection rules with cached regex patterns
 pub struct SmartSelectionMatcher {
     /// Compiled rules sorted by precision (highest first)
@@ -116,19 +120,6 @@ fn byte_to_char_offset(s: &str, byte_offset: usize) -> Option<usize> {
     Some(s[..byte_offset].chars().count())
 }
 
-/// Check if a character should be considered part of a word.
-///
-/// A character is part of a word if:
-/// - It is alphanumeric (a-z, A-Z, 0-9)
-/// - It is in the user-defined word_characters set
-///
-/// Note: Unlike some terminals, underscore is NOT hardcoded as a word character.
-/// It is included in the default word_characters setting (`/-+\~_.`) but can be
-/// removed by the user for full control over word selection behavior.
-pub fn is_word_char(ch: char, word_characters: &str) -> bool {
-    ch.is_alphanumeric() || word_characters.contains(ch)
-}
-
 /// Find word boundaries at the given position using configurable word characters.
 ///
 /// Returns (start_col, end_col) as inclusive indices.
@@ -14,13 +14,17 @@ use crate::tab::profile_state::TabProfileState;
 use crate::tab::scripting_state::TabScriptingState;
 use crate::tab::setup::{
     apply_login_shell_flag, build_shell_env, create_base_terminal, get_shell_command,
+    term_cursor_style,
 };
+use crate::tab::startup_commands::{build_startup_command_payloads, send_startup_commands};
 use crate::tab::tmux_state::TabTmuxState;
 use crate::terminal::TerminalManager;
 use par_term_config::TabId;
+use par_term_terminal::ClipboardHistoryStore;
 use par_term_terminal::conversion::to_core_restart_policy;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU8};
+use std::time::Duration;
 use tokio::runtime::Runtime;
 use tokio::sync::RwLock;
 
@@ -50,7 +54,7 @@ impl Tab {
         // Sync triggers from config into the core TriggerRegistry
         let trigger_security = terminal.sync_triggers(&config.triggers);
 
-        // Auto-start configured coprocesses via the PtySession's built-in manager
+        // Auto-start configured coprocesses via the PTY host's built-in manager
         let mut coprocess_ids = Vec::with_capacity(config.coprocesses.len());
         for coproc_config in &config.coprocesses {
             if coproc_config.auto_start {
@@ -173,7 +177,7 @@ impl Tab {
             Arc::clone(&is_active),
         );
 
-        Ok(Self {
+        let tab = Self {
             id: params.id,
             terminal,
             pane_manager: Some(pane_manager),
@@ -198,12 +202,15 @@ impl Tab {
             was_alt_screen: false,
             is_active,
             shutdown_fast: false,
+            clipboard_history: ClipboardHistoryStore::new_shared(),
             is_hidden: false,
             cached_modify_other_keys_mode: AtomicU8::new(0),
             cached_application_cursor: AtomicBool::new(false),
             cached_alt_screen_active: AtomicBool::new(false),
             cached_has_tmux_child: AtomicBool::new(false),
-        })
+        };
+        tab.apply_clipboard_scope(config.clipboard_scope);
+        Ok(tab)
     }
 
     /// Create a new tab with a terminal session
@@ -281,7 +288,7 @@ impl Tab {
     /// # Arguments
     /// * `id` - Unique tab identifier
     /// * `config` - Terminal configuration
-    /// * `_runtime` - Tokio runtime (unused: profile tabs don't send initial text)
+    /// * `runtime` - Tokio runtime used to send the profile's startup commands
     /// * `profile` - Profile configuration to use
     /// * `grid_size` - Optional (cols, rows) override for initial terminal size
     ///
@@ -290,12 +297,16 @@ impl Tab {
     /// - SSH command detection (`profile.ssh_command_args()`)
     /// - Per-profile `login_shell` override (takes precedence over `config.login_shell`)
     /// - Per-profile `SHELL` env-var injection when `profile.shell` is set
+    /// - Per-profile `COLORTERM` and alternate-screen color mode
     /// - Title derived from `profile.tab_name` → `profile.name` (not "Tab N")
-    /// - Profile tabs do NOT send `config.initial_text` on startup
+    /// - Per-profile `tab_title_template` stored on the tab
+    /// - Per-profile cursor color/style/blink stored on the tab
+    /// - Profile tabs do NOT send `config.initial_text` on startup; they send
+    ///   `profile.startup_commands` instead
     pub fn new_from_profile(
         id: TabId,
         config: &Config,
-        _runtime: Arc<Runtime>,
+        runtime: Arc<Runtime>,
         profile: &Profile,
         grid_size: Option<(usize, usize)>,
     ) -> anyhow::Result<Self> {
@@ -350,6 +361,23 @@ impl Tab {
             env.insert("SHELL".to_string(), shell_path.clone());
         }
 
+        // Advertise the profile's color depth; an empty COLORTERM tells
+        // applications not to use 24-bit color.
+        if let Some(mode) = profile.color_mode
+            && let Some(ref mut env) = shell_env
+        {
+            env.insert("COLORTERM".to_string(), mode.colorterm().to_string());
+        }
+        terminal.set_alt_screen_color_mode(profile.alt_screen_color_mode);
+        if let Err(e) = terminal.set_input_encoding(profile.input_encoding.as_deref()) {
+            log::warn!("Profile '{}': {e}", profile.name);
+        }
+        let cursor = profile.cursor_override();
+        terminal.set_cursor_style(term_cursor_style(
+            cursor.style(&config.cursor),
+            cursor.blink(&config.cursor),
+        ));
+
         terminal.spawn_custom_shell_with_dir(
             &shell_cmd,
             shell_args_deref,
@@ -371,7 +399,7 @@ impl Tab {
         // Session log title uses profile name (Tab::new uses "Tab N")
         let session_title = profile.name.clone();
 
-        Self::new_internal(
+        let mut tab = Self::new_internal(
             TabInitParams {
                 id,
                 title,
@@ -383,7 +411,23 @@ impl Tab {
             terminal,
             config,
             session_title,
-        )
+        )?;
+        tab.profile.title_template = profile.tab_title_template.clone();
+        tab.profile.cursor = cursor;
+        tab.profile.osc_notifications = profile.osc_notifications;
+
+        // Type the profile's startup commands into the new session
+        let payloads = build_startup_command_payloads(&profile.startup_commands);
+        if !payloads.is_empty() {
+            let delay = Duration::from_millis(profile.effective_startup_command_delay_ms());
+            let terminal = Arc::clone(&tab.terminal);
+            runtime.spawn(send_startup_commands(
+                payloads,
+                delay,
+                async move |data: &[u8]| terminal.read().await.write(data),
+            ));
+        }
+        Ok(tab)
     }
 
     /// Create a new tab wrapping an existing `Pane` (e.g., from a promote operation).
@@ -394,7 +438,7 @@ impl Tab {
     pub fn new_from_pane(
         id: TabId,
         pane: Pane,
-        _config: &Config,
+        config: &Config,
         _runtime: Arc<Runtime>,
         tab_number: usize,
     ) -> Self {
@@ -408,7 +452,7 @@ impl Tab {
 
         let title = format!("Tab {}", tab_number);
 
-        Self {
+        let tab = Self {
             id,
             terminal,
             pane_manager: Some(pane_manager),
@@ -429,12 +473,16 @@ impl Tab {
             was_alt_screen: false,
             is_active,
             shutdown_fast: false,
+            clipboard_history: ClipboardHistoryStore::new_shared(),
             is_hidden: false,
             cached_modify_other_keys_mode: AtomicU8::new(0),
             cached_application_cursor: AtomicBool::new(false),
             cached_alt_screen_active: AtomicBool::new(false),
             cached_has_tmux_child: AtomicBool::new(false),
-        }
+        };
+        // The pane now belongs to this tab's clipboard scope
+        tab.apply_clipboard_scope(config.clipboard_scope);
+        tab
     }
 }
 
@@ -475,6 +523,7 @@ impl Tab {
             was_alt_screen: false,
             is_active,
             shutdown_fast: false,
+            clipboard_history: ClipboardHistoryStore::new_shared(),
             is_hidden: false,
             cached_modify_other_keys_mode: AtomicU8::new(0),
             cached_application_cursor: AtomicBool::new(false),
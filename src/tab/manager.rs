@@ -74,7 +74,7 @@ impl TabManager {
     ) -> Result<TabId> {
         // Optionally inherit working directory from active tab
         let working_dir = if inherit_cwd_from_active {
-            self.active_tab().and_then(|tab| tab.get_cwd())
+            self.inherited_cwd(config.tab_inherit_latest_cwd)
         } else {
             None
         };
@@ -95,6 +95,22 @@ impl TabManager {
         Ok(id)
     }
 
+    /// Working directory a new tab inherits.
+    ///
+    /// This is the active tab's directory, or with `latest_change` the
+    /// directory of the most recent `cd` in any tab of this window.  Without
+    /// any tracked change it falls back to the active tab's directory.
+    fn inherited_cwd(&self, latest_change: bool) -> Option<String> {
+        if latest_change
+            && let Some(change) = par_term_terminal::most_recent_change(
+                self.tabs.iter().filter_map(|tab| tab.last_cwd_change()),
+            )
+        {
+            return Some(change.new_cwd);
+        }
+        self.active_tab().and_then(|tab| tab.get_cwd())
+    }
+
     /// Create a new tab with a specific working directory
     ///
     /// Used by arrangement restore to create tabs with saved CWDs.
@@ -248,6 +264,14 @@ impl TabManager {
             .and_then(|id| self.tabs.iter().find(|t| t.id == id))
     }
 
+    /// Cursor overrides of the active tab's profile (empty when no tab is
+    /// active or it was not opened from a profile)
+    pub fn active_cursor_override(&self) -> par_term_config::CursorOverride {
+        self.active_tab()
+            .map(|tab| tab.profile.cursor)
+            .unwrap_or_default()
+    }
+
     /// Get a mutable reference to the active tab
     pub fn active_tab_mut(&mut self) -> Option<&mut Tab> {
         let active_id = self.active_tab_id;
@@ -323,9 +347,16 @@ impl TabManager {
         title_mode: par_term_config::TabTitleMode,
         remote_format: par_term_config::RemoteTabTitleFormat,
         remote_osc_priority: bool,
+        template: &str,
     ) {
-        for tab in &mut self.tabs {
-            tab.update_title(title_mode, remote_format, remote_osc_priority);
+        for (i, tab) in self.tabs.iter_mut().enumerate() {
+            tab.update_title(
+                title_mode,
+                remote_format,
+                remote_osc_priority,
+                template,
+                i + 1,
+            );
         }
     }
 
@@ -542,6 +573,31 @@ mod tests {
         assert_eq!(ids, vec![3, 1, 2]);
     }
 
+    #[test]
+    fn profile_cursor_applies_while_its_tab_is_active() {
+        use crate::config::CursorStyle;
+
+        let config = Config::default();
+        let profile = Profile::new("Prod")
+            .cursor_color([255, 0, 0])
+            .cursor_style(CursorStyle::Beam)
+            .cursor_blink(!config.cursor.cursor_blink);
+        let mut mgr = manager_with_ids(&[1, 2]);
+        mgr.get_tab_mut(2).expect("tab 2").profile.cursor = profile.cursor_override();
+
+        let cursor = mgr.active_cursor_override();
+        assert_eq!(cursor.color(&config.cursor), [255, 0, 0]);
+        assert_eq!(cursor.style(&config.cursor), CursorStyle::Beam);
+        assert_eq!(cursor.blink(&config.cursor), !config.cursor.cursor_blink);
+
+        // Closing the profile tab reverts to the global cursor
+        mgr.close_tab(2);
+        let cursor = mgr.active_cursor_override();
+        assert_eq!(cursor.color(&config.cursor), config.cursor.cursor_color);
+        assert_eq!(cursor.style(&config.cursor), config.cursor.cursor_style);
+        assert_eq!(cursor.blink(&config.cursor), config.cursor.cursor_blink);
+    }
+
     #[test]
     #[ignore = "requires PTY spawn"]
     fn remove_insert_round_trip_preserves_tab_fields() {
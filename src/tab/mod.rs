@@ -18,6 +18,7 @@ mod refresh_task;
 mod scripting_state;
 mod session_logging;
 mod setup;
+mod startup_commands;
 mod tmux_state;
 
 pub(crate) use activity_state::TabActivityMonitor;
@@ -29,8 +30,10 @@ use crate::pane::PaneManager;
 use crate::session_logger::SharedSessionLogger;
 use crate::terminal::TerminalManager;
 pub use manager::TabManager;
+use par_term_terminal::SharedClipboardHistory;
 pub(crate) use setup::{
     apply_login_shell_flag, build_shell_env, configure_terminal_from_config, get_shell_command,
+    term_cursor_style,
 };
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
@@ -125,6 +128,8 @@ pub struct Tab {
     pub(in crate::tab) is_active: Arc<AtomicBool>,
     /// When true, Drop impl skips cleanup (terminal Arcs are dropped on background threads)
     pub(crate) shutdown_fast: bool,
+    /// Clipboard history shared by this tab's panes under `clipboard_scope: per_tab`
+    pub(crate) clipboard_history: SharedClipboardHistory,
     /// When true, this tab is hidden from the tab bar (e.g., tmux gateway tab while windows are active)
     pub(crate) is_hidden: bool,
     /// Last-known modifyOtherKeys level. Updated on every successful read of
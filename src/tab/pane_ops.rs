@@ -4,7 +4,7 @@
 //! within a tab. Pane operations use `PaneManager` to manage the binary tree
 //! layout of panes.
 
-use crate::config::Config;
+use crate::config::{ClipboardScope, Config};
 use crate::pane::{NavigationDirection, PaneManager, SplitDirection};
 use crate::tab::Tab;
 use std::sync::{Arc, atomic::Ordering};
@@ -33,6 +33,19 @@ impl Tab {
             .unwrap_or(1)
     }
 
+    /// Output metrics of all panes in this tab, summed.
+    ///
+    /// Terminals locked by another task are skipped; `None` if all were.
+    pub fn metrics(&self) -> Option<par_term_terminal::TerminalMetrics> {
+        let Some(pm) = self.pane_manager.as_ref() else {
+            return self.terminal.try_read().ok().map(|term| term.metrics());
+        };
+        pm.all_panes()
+            .into_iter()
+            .filter_map(|pane| pane.terminal.try_read().ok().map(|term| term.metrics()))
+            .reduce(par_term_terminal::TerminalMetrics::combine)
+    }
+
     /// Split the current pane horizontally (panes stacked vertically)
     ///
     /// Returns the new pane ID if successful.
@@ -156,12 +169,38 @@ impl Tab {
             if let Some(id) = new_pane_id {
                 log::info!("Split tab {} {:?}, new pane {}", self.id, direction, id);
             }
+            self.apply_clipboard_scope(config.clipboard_scope);
             Ok(new_pane_id)
         } else {
             Ok(None)
         }
     }
 
+    /// Attach every pane's clipboard history to the store selected by `scope`.
+    ///
+    /// `Global` uses the process-wide store, `PerTab` this tab's store, and
+    /// `PerPane` detaches so each pane keeps its own history.
+    pub fn apply_clipboard_scope(&self, scope: ClipboardScope) {
+        let store = match scope {
+            ClipboardScope::Global => Some(par_term_terminal::global_clipboard_history()),
+            ClipboardScope::PerTab => Some(Arc::clone(&self.clipboard_history)),
+            ClipboardScope::PerPane => None,
+        };
+        let Some(pm) = &self.pane_manager else {
+            return;
+        };
+        for pane in pm.all_panes() {
+            if let Ok(term) = pane.terminal.try_read() {
+                term.set_shared_clipboard_history(store.clone());
+            } else {
+                log::warn!(
+                    "Could not apply clipboard scope to pane {}: terminal busy",
+                    pane.id
+                );
+            }
+        }
+    }
+
     /// Close the focused pane
     ///
     /// Returns true if this was the last pane (tab should close)
@@ -206,7 +245,12 @@ impl Tab {
                         pane.bounds.width,
                         pane.bounds.height
                     );
-                    if !is_running { Some(pane.id) } else { None }
+                    // Panes held open after a failed exit are kept (shell_exit_keep_on_failure)
+                    if !is_running && pane.failed_exit_code.is_none() {
+                        Some(pane.id)
+                    } else {
+                        None
+                    }
                 })
                 .collect()
         } else {
@@ -368,6 +412,7 @@ impl Tab {
                     pm.pane_count()
                 );
                 self.pane_manager = Some(pm);
+                self.apply_clipboard_scope(config.clipboard_scope);
             }
             Err(e) => {
                 log::warn!(
@@ -16,6 +16,15 @@ pub(crate) struct TabProfileState {
     pub(crate) pre_profile_title: Option<String>,
     /// Badge text override from auto-applied profile (overrides global badge_format)
     pub(crate) badge_override: Option<String>,
+    /// Tab title template from the profile the tab was opened with
+    /// (overrides global tab_title_template)
+    pub(crate) title_template: Option<String>,
+    /// Cursor color/style/blink from the profile the tab was opened with
+    /// (overrides the global cursor settings)
+    pub(crate) cursor: par_term_config::CursorOverride,
+    /// Whether OSC 9/777/99 notifications reach the desktop, from the profile
+    /// the tab was opened with (None = global `notification_osc_enabled`)
+    pub(crate) osc_notifications: Option<bool>,
     /// Profile saved before SSH auto-switch (for revert on disconnect)
     pub(crate) pre_ssh_switch_profile: Option<crate::profile::ProfileId>,
     /// Whether current profile was auto-applied due to SSH hostname detection
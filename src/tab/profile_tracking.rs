@@ -28,12 +28,18 @@ impl Tab {
     ///   1. Explicit OSC title
     ///   2. Last CWD component (only in `TabTitleMode::Auto`)
     ///
+    /// When a title template is set (the tab's profile `tab_title_template`, else
+    /// `template`), the rendered template replaces the derived tab title; `index`
+    /// is the tab's 1-based position for `{index}`. Pane titles are unaffected.
+    ///
     /// User-named tabs are never auto-updated.
     pub fn update_title(
         &mut self,
         title_mode: par_term_config::TabTitleMode,
         remote_format: par_term_config::RemoteTabTitleFormat,
         remote_osc_priority: bool,
+        template: &str,
+        index: usize,
     ) {
         // User-named tabs are static — never auto-update
         if self.user_named {
@@ -52,6 +58,9 @@ impl Tab {
         let local_hostname = hostname::get().ok().and_then(|h| h.into_string().ok());
         let home_dir = dirs::home_dir();
 
+        let template = self.profile.title_template.as_deref().unwrap_or(template);
+        let mut template_vars: Option<par_term_config::TabTitleVars> = None;
+
         // Step 3 — Iterate all panes and update each one's title from its own terminal.
         // try_read: intentional — called every frame; blocking would stall rendering.
         // On contention: skip that pane this frame, no data loss.
@@ -62,6 +71,19 @@ impl Tab {
                     let hostname = term.shell_integration_hostname();
                     let username = term.shell_integration_username();
                     let cwd = term.shell_integration_cwd();
+                    if !template.is_empty() && Some(pane.id) == focused_id {
+                        template_vars = Some(par_term_config::TabTitleVars {
+                            cwd: cwd.as_ref().map(|cwd| match home_dir {
+                                Some(ref home) => {
+                                    cwd.replace(&home.to_string_lossy().to_string(), "~")
+                                }
+                                None => cwd.clone(),
+                            }),
+                            command: term.shell_integration_command(),
+                            exit: term.shell_integration_exit_code(),
+                            index,
+                        });
+                    }
                     drop(term);
 
                     let is_remote = if let Some(reported_host) = &hostname {
@@ -110,6 +132,21 @@ impl Tab {
         }
         // mutable borrow of pane_manager ends here
 
+        // Templated title: render from the focused pane's shell integration state.
+        // If the focused pane was contended this frame, keep the previous title
+        // rather than flashing the un-templated one.
+        if !template.is_empty() {
+            let Some(vars) = template_vars else {
+                return;
+            };
+            let rendered = vars.render(template);
+            if !rendered.trim().is_empty() {
+                self.title = rendered;
+                self.has_default_title = false;
+                return;
+            }
+        }
+
         // Step 4 — Derive tab.title from the focused pane (immutable re-borrow is now safe).
         if let Some((focused_id, pm)) = focused_id.zip(self.pane_manager.as_ref())
             && let Some(pane) = pm.get_pane(focused_id)
@@ -172,6 +209,18 @@ impl Tab {
         }
     }
 
+    /// The most recent change of directory in any of this tab's panes
+    pub fn last_cwd_change(&self) -> Option<par_term_emu_core_rust::terminal::CwdChange> {
+        let pm = self.pane_manager.as_ref()?;
+        // try_read: a pane busy with output this instant is skipped
+        par_term_terminal::most_recent_change(pm.all_panes().into_iter().filter_map(|pane| {
+            pane.terminal
+                .try_read()
+                .ok()
+                .and_then(|term| term.last_cwd_change())
+        }))
+    }
+
     /// Set a custom color for this tab
     pub fn set_custom_color(&mut self, color: [u8; 3]) {
         self.custom_color = Some(color);
@@ -22,6 +22,48 @@ pub(crate) fn configure_terminal_from_config(terminal: &mut TerminalManager, con
     terminal.set_max_notifications(config.notifications.notification_max_buffer);
     terminal.set_max_osc_data_length(config.max_osc_data_length);
 
+    // Share clipboard history app-wide; per-tab stores are attached by the tab
+    if config.clipboard_scope == crate::config::ClipboardScope::Global {
+        terminal.set_shared_clipboard_history(Some(par_term_terminal::global_clipboard_history()));
+    }
+
+    // Collapse repeated output lines into a counter line
+    terminal.set_collapse_repeated_lines(config.scrollback.collapse_repeated_lines);
+
+    // Re-index or pin prompt marks when full scrollback trims lines
+    terminal.set_mark_trim_policy(config.scrollback.mark_trim_policy);
+    terminal.set_prompt_continuation(config.scrollback.prompt_continuation);
+
+    // Unwrap DCS tmux; passthrough in routed tmux output
+    terminal.set_unwrap_tmux_passthrough(config.tmux_unwrap_passthrough);
+
+    // Route media copy (CSI 5 i / 4 i) printer output
+    terminal.set_printer_output(config.printer_output.clone());
+
+    // Form feed handling in terminal output
+    terminal.set_form_feed(config.form_feed);
+
+    // Drop redundant SGR resets in terminal output
+    terminal.set_coalesce_sgr_resets(config.coalesce_sgr_resets);
+
+    // Break over-long lines in terminal output
+    terminal.set_max_line_length(config.max_line_length);
+
+    // Answer DECRQM mode queries in terminal output
+    terminal.set_answer_mode_requests(config.answer_mode_requests);
+
+    // Join chunked OSC 52 clipboard writes in terminal output
+    terminal.set_osc52_reassembly(config.osc52_reassemble_chunks);
+
+    // Highlight saved keyword sets in rendered output
+    terminal.set_keyword_highlights(&config.search.keyword_highlights);
+
+    // Keep input typed at password prompts out of command history
+    terminal.set_sensitive_input_detection(
+        config.password_prompt_detection,
+        config.mask_password_input,
+    );
+
     // Set answerback string for ENQ response (if configured)
     if !config.answerback_string.is_empty() {
         terminal.set_answerback_string(Some(config.answerback_string.clone()));
@@ -40,22 +82,32 @@ pub(crate) fn configure_terminal_from_config(terminal: &mut TerminalManager, con
     ));
 
     // Initialize cursor style from config
+    terminal.set_cursor_style(term_cursor_style(
+        config.cursor.cursor_style,
+        config.cursor.cursor_blink,
+    ));
+}
+
+/// Terminal cursor style for a configured style and blink setting
+pub(crate) fn term_cursor_style(
+    style: crate::config::CursorStyle,
+    blink: bool,
+) -> par_term_emu_core_rust::cursor::CursorStyle {
     use crate::config::CursorStyle as ConfigCursorStyle;
     use par_term_emu_core_rust::cursor::CursorStyle as TermCursorStyle;
-    let term_style = if config.cursor.cursor_blink {
-        match config.cursor.cursor_style {
+    if blink {
+        match style {
             ConfigCursorStyle::Block => TermCursorStyle::BlinkingBlock,
             ConfigCursorStyle::Underline => TermCursorStyle::BlinkingUnderline,
             ConfigCursorStyle::Beam => TermCursorStyle::BlinkingBar,
         }
     } else {
-        match config.cursor.cursor_style {
+        match style {
             ConfigCursorStyle::Block => TermCursorStyle::SteadyBlock,
             ConfigCursorStyle::Underline => TermCursorStyle::SteadyUnderline,
             ConfigCursorStyle::Beam => TermCursorStyle::SteadyBar,
         }
-    };
-    terminal.set_cursor_style(term_style);
+    }
 }
 
 /// Get the platform-specific PATH separator
@@ -0,0 +1,93 @@
+//! Per-profile startup commands.
+//!
+//! A profile's `startup_commands` are typed into the new session once the
+//! shell has been spawned, one at a time with the profile's delay before
+//! each, so a prompt (or a remote/tmux session) has time to appear between
+//! them.  Commands accept the same escape sequences as `initial_text` and
+//! are each followed by Enter.
+
+use std::time::Duration;
+
+use super::initial_text::build_initial_text_payload;
+
+/// Build the PTY payload for each startup command, in order.
+/// Blank commands are skipped.
+pub(crate) fn build_startup_command_payloads(commands: &[String]) -> Vec<Vec<u8>> {
+    commands
+        .iter()
+        .filter(|command| !command.trim().is_empty())
+        .filter_map(|command| build_initial_text_payload(command, true))
+        .collect()
+}
+
+/// Write `payloads` in order, sleeping `delay` before each.
+///
+/// Stops at the first failed write (the PTY has most likely exited).
+pub(crate) async fn send_startup_commands<W>(payloads: Vec<Vec<u8>>, delay: Duration, mut write: W)
+where
+    W: AsyncFnMut(&[u8]) -> anyhow::Result<()>,
+{
+    for (index, payload) in payloads.iter().enumerate() {
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        if let Err(err) = write(payload).await {
+            log::warn!(
+                "Failed to send startup command {} of {}: {}",
+                index + 1,
+                payloads.len(),
+                err
+            );
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commands(list: &[&str]) -> Vec<String> {
+        list.iter().map(|c| c.to_string()).collect()
+    }
+
+    #[test]
+    fn payloads_end_with_enter_and_skip_blank_commands() {
+        let payloads =
+            build_startup_command_payloads(&commands(&["ssh host", "  ", "tmux attach\n", ""]));
+        assert_eq!(
+            payloads,
+            vec![b"ssh host\r".to_vec(), b"tmux attach\r".to_vec()]
+        );
+    }
+
+    #[tokio::test]
+    async fn commands_are_written_in_order() {
+        let payloads = build_startup_command_payloads(&commands(&["cd ~/src", "git status"]));
+        let mut written: Vec<Vec<u8>> = Vec::new();
+        send_startup_commands(payloads, Duration::from_millis(1), async |data: &[u8]| {
+            written.push(data.to_vec());
+            Ok(())
+        })
+        .await;
+        assert_eq!(
+            written,
+            vec![b"cd ~/src\r".to_vec(), b"git status\r".to_vec()]
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_write_stops_remaining_commands() {
+        let payloads = build_startup_command_payloads(&commands(&["one", "two", "three"]));
+        let mut attempts = 0;
+        send_startup_commands(payloads, Duration::ZERO, async |_: &[u8]| {
+            attempts += 1;
+            if attempts == 2 {
+                anyhow::bail!("PTY closed");
+            }
+            Ok(())
+        })
+        .await;
+        assert_eq!(attempts, 2);
+    }
+}
@@ -11,6 +11,7 @@ use crate::ui_constants::{
 use super::CHEVRON_RESERVED;
 use super::TabBarAction;
 use super::state::TabBarUI;
+use super::style::TabBarStyle;
 use super::tab_rendering::TabRenderParams;
 
 impl TabBarUI {
@@ -43,9 +44,9 @@ impl TabBarUI {
             TAB_NEW_BTN_BASE_WIDTH + if show_chevron { CHEVRON_RESERVED } else { 0.0 };
         let scroll_btn_width = TAB_SCROLL_BTN_WIDTH;
 
-        let bar_bg = config.tab_bar_background;
-        let frame =
-            egui::Frame::NONE.fill(egui::Color32::from_rgb(bar_bg[0], bar_bg[1], bar_bg[2]));
+        let style =
+            TabBarStyle::from_config(config, &ctx.style().text_styles[&egui::TextStyle::Button]);
+        let frame = egui::Frame::NONE.fill(style.bar_background);
 
         let panel = if config.tab_bar_position == TabBarPosition::Bottom {
             egui::Panel::bottom("tab_bar").exact_size(config.tab_bar_height)
@@ -180,6 +181,7 @@ impl TabBarUI {
                                     is_bell_active,
                                     custom_color: tab.custom_color,
                                     config,
+                                    style: &style,
                                     tab_size: tab_width,
                                     tab_count,
                                 },
@@ -234,6 +236,7 @@ impl TabBarUI {
                                     is_bell_active,
                                     custom_color: tab.custom_color,
                                     config,
+                                    style: &style,
                                     tab_size: tab_width,
                                     tab_count,
                                 },
@@ -324,6 +327,8 @@ impl TabBarUI {
             self.render_ghost_tab(ctx, config);
         }
 
+        self.render_hover_preview(ctx, config);
+
         // Handle context menu (color picker popup)
         if let Some(context_tab_id) = self.context_menu_tab {
             let menu_action = self.render_context_menu(ctx, context_tab_id);
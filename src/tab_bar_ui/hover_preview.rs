@@ -0,0 +1,68 @@
+//! Live preview of an inactive tab, shown while hovering it.
+//!
+//! The image is captured offscreen by the render pipeline and handed over in
+//! [`TabBarUI::hover_preview`] each frame; this module only places it next to
+//! the hovered tab.
+
+use crate::config::{Config, TabBarPosition};
+use crate::renderer::TAB_PREVIEW_REFRESH;
+
+use super::TabBarUI;
+
+/// Gap in pixels between the tab and its preview.
+const PREVIEW_GAP: f32 = 4.0;
+
+impl TabBarUI {
+    /// Render the hovered tab's preview beside it, if one is available.
+    pub(super) fn render_hover_preview(&self, ctx: &egui::Context, config: &Config) {
+        if self.drag_in_progress || self.context_menu_tab.is_some() {
+            return;
+        }
+        let Some((tab_id, image)) = self.hover_preview else {
+            return;
+        };
+        if self.hovered_tab != Some(tab_id) {
+            return;
+        }
+        let Some(&(_, tab_rect)) = self.tab_rects.iter().find(|(id, _)| *id == tab_id) else {
+            return;
+        };
+        if image.size[0] <= 0.0 || image.size[1] <= 0.0 {
+            return;
+        }
+
+        let width = config.tab_hover_preview_width;
+        let size = egui::vec2(width, width * image.size[1] / image.size[0]);
+        let (pivot, pos) = match config.tab_bar_position {
+            TabBarPosition::Top => (
+                egui::Align2::LEFT_TOP,
+                tab_rect.left_bottom() + egui::vec2(0.0, PREVIEW_GAP),
+            ),
+            TabBarPosition::Bottom => (
+                egui::Align2::LEFT_BOTTOM,
+                tab_rect.left_top() - egui::vec2(0.0, PREVIEW_GAP),
+            ),
+            TabBarPosition::Left => (
+                egui::Align2::LEFT_TOP,
+                tab_rect.right_top() + egui::vec2(PREVIEW_GAP, 0.0),
+            ),
+        };
+
+        egui::Area::new(egui::Id::new("tab_hover_preview"))
+            .fixed_pos(pos)
+            .pivot(pivot)
+            .order(egui::Order::Foreground)
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.add(
+                        egui::Image::new(egui::load::SizedTexture::new(image.texture, size))
+                            .uv(image.uv),
+                    );
+                });
+            });
+
+        // Keep the preview live while the tab produces output
+        ctx.request_repaint_after(TAB_PREVIEW_REFRESH);
+    }
+}
@@ -8,7 +8,9 @@
 //! - [`horizontal`]: Horizontal layout rendering (`render_horizontal`).
 //! - [`context_menu`]: Right-click context menu (rename, color, icon, duplicate, close).
 //! - [`drag_drop`]: Drag-and-drop state and rendering for tab reordering.
+//! - [`hover_preview`]: Live preview of an inactive tab shown on hover.
 //! - [`profile_menu`]: Profile selection popup for the new-tab chevron button.
+//! - [`style`]: Tab bar colors and title font resolved from the config.
 //! - [`tab_rendering`]: Vertical tab rendering and shared params/helpers.
 //! - [`tab_painter`]: Horizontal per-tab painting (`render_tab_with_width`).
 //! - [`title_utils`]: HTML title parsing, emoji sanitization, and styled segment rendering.
@@ -16,14 +18,17 @@
 mod context_menu;
 mod drag_drop;
 mod horizontal;
+mod hover_preview;
 mod profile_menu;
 mod state;
+mod style;
 mod tab_painter;
 mod tab_rendering;
 mod title_utils;
 
 // Re-export TabBarUI so external callers are unaffected.
 pub use state::TabBarUI;
+pub use style::TabBarStyle;
 
 use crate::config::{Config, TabBarMode, TabBarPosition};
 use crate::tab::{TabId, TabManager};
@@ -124,13 +129,14 @@ impl TabBarUI {
         let mut action = TabBarAction::None;
         let active_tab_id = tabs.active_tab_id();
 
-        let bar_bg = config.tab_bar_background;
+        let style =
+            TabBarStyle::from_config(config, &ctx.style().text_styles[&egui::TextStyle::Button]);
         let tab_spacing = TAB_SPACING;
         let tab_height = config.tab_bar_height; // Reuse height config for per-tab row height
 
         egui::Panel::left("tab_bar")
             .exact_size(config.tab_bar_width)
-            .frame(egui::Frame::NONE.fill(egui::Color32::from_rgb(bar_bg[0], bar_bg[1], bar_bg[2])))
+            .frame(egui::Frame::NONE.fill(style.bar_background))
             .show(ctx, |ui| {
                 egui::ScrollArea::vertical()
                     .scroll_bar_visibility(
@@ -208,6 +214,7 @@ impl TabBarUI {
                                         is_bell_active,
                                         custom_color: tab.custom_color,
                                         config,
+                                        style: &style,
                                         tab_size: tab_height,
                                         tab_count,
                                     },
@@ -235,6 +242,8 @@ impl TabBarUI {
             self.render_ghost_tab(ctx, config);
         }
 
+        self.render_hover_preview(ctx, config);
+
         // Handle context menu
         if let Some(context_tab_id) = self.context_menu_tab {
             let menu_action = self.render_context_menu(ctx, context_tab_id);
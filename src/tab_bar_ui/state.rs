@@ -1,5 +1,6 @@
 //! `TabBarUI` struct definition and constructor.
 
+use crate::renderer::TabPreviewImage;
 use crate::tab::TabId;
 use crate::ui_constants::TAB_SPACING;
 use winit::event::MouseScrollDelta;
@@ -66,6 +67,9 @@ pub struct TabBarUI {
     pub(crate) move_source_tab_count: usize,
     /// Set per-frame: true when the context-menu tab has multiple panes.
     pub(crate) tab_has_multiple_panes: bool,
+    /// Set per-frame: offscreen preview of the hovered inactive tab, if
+    /// `tab_hover_preview` is enabled and a capture is available.
+    pub(crate) hover_preview: Option<(TabId, TabPreviewImage)>,
 }
 
 impl TabBarUI {
@@ -100,6 +104,7 @@ impl TabBarUI {
             move_gateway_active: false,
             move_source_tab_count: 0,
             tab_has_multiple_panes: false,
+            hover_preview: None,
         }
     }
 }
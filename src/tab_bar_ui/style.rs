@@ -0,0 +1,64 @@
+//! Tab bar chrome style resolved from the config.
+//!
+//! Collects the configured tab bar colors and title font into the egui
+//! values the horizontal and vertical renderers paint with, so both layouts
+//! share one mapping from config to visuals.
+
+use crate::config::{Config, TabFontFamily};
+
+/// egui visuals for the tab bar chrome.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabBarStyle {
+    /// Fill behind the whole tab bar
+    pub bar_background: egui::Color32,
+    /// Fill of the active tab
+    pub active_background: egui::Color32,
+    /// Fill of inactive tabs (before dimming)
+    pub inactive_background: egui::Color32,
+    /// Fill of a hovered inactive tab
+    pub hover_background: egui::Color32,
+    /// Title color of the active tab
+    pub active_text: egui::Color32,
+    /// Title color of inactive tabs (before dimming)
+    pub inactive_text: egui::Color32,
+    /// Font for tab titles
+    pub title_font: egui::FontId,
+}
+
+impl TabBarStyle {
+    /// Resolve the style from `config`.
+    ///
+    /// `default_font` is the UI's button font, used for the title when
+    /// `tab_font_size` is 0.
+    pub fn from_config(config: &Config, default_font: &egui::FontId) -> Self {
+        let rgb = |c: [u8; 3]| egui::Color32::from_rgb(c[0], c[1], c[2]);
+        let size = if config.tab_font_size > 0.0 {
+            config.tab_font_size
+        } else {
+            default_font.size
+        };
+        let family = match config.tab_font_family {
+            TabFontFamily::Proportional => egui::FontFamily::Proportional,
+            TabFontFamily::Monospace => egui::FontFamily::Monospace,
+        };
+        Self {
+            bar_background: rgb(config.tab_bar_background),
+            active_background: rgb(config.tab_active_background),
+            inactive_background: rgb(config.tab_inactive_background),
+            hover_background: rgb(config.tab_hover_background),
+            active_text: rgb(config.tab_active_text),
+            inactive_text: rgb(config.tab_inactive_text),
+            title_font: egui::FontId::new(size, family),
+        }
+    }
+
+    /// Title color for a tab; inactive titles take the dimming `opacity`.
+    pub fn text_color(&self, is_active: bool, opacity: u8) -> egui::Color32 {
+        if is_active {
+            self.active_text
+        } else {
+            let [r, g, b, _] = self.inactive_text.to_array();
+            egui::Color32::from_rgba_unmultiplied(r, g, b, opacity)
+        }
+    }
+}
@@ -37,6 +37,7 @@ impl TabBarUI {
             is_bell_active,
             custom_color,
             config,
+            style,
             tab_size: tab_width,
             tab_count,
         } = p;
@@ -45,7 +46,8 @@ impl TabBarUI {
         // Determine if this tab should be dimmed
         // Active tabs and hovered inactive tabs are NOT dimmed
         // Also dim the tab being dragged
-        let (bg_color, opacity) = self.compute_tab_bg_color(id, is_active, custom_color, config);
+        let (bg_color, opacity) =
+            self.compute_tab_bg_color(id, is_active, custom_color, config, style);
 
         // Whether this inactive tab should render as outline-only (no fill)
         let outline_only = config.tab_inactive_outline_only && !is_active;
@@ -158,7 +160,6 @@ impl TabBarUI {
                 };
 
                 // Title rendering with width-aware truncation
-                let base_font_id = ui.style().text_styles[&egui::TextStyle::Button].clone();
                 let indicator_width = if is_bell_active {
                     18.0
                 } else if has_activity && !is_active {
@@ -185,25 +186,22 @@ impl TabBarUI {
                     - padding)
                     .max(TAB_CONTENT_PAD_X * 2.0);
 
-                let max_chars = estimate_max_chars(ui, &base_font_id, title_available_width);
+                let max_chars = estimate_max_chars(ui, &style.title_font, title_available_width);
 
-                let text_color = if is_active {
-                    let c = config.tab_active_text;
-                    egui::Color32::from_rgba_unmultiplied(c[0], c[1], c[2], 255)
-                } else {
-                    let c = config.tab_inactive_text;
-                    egui::Color32::from_rgba_unmultiplied(c[0], c[1], c[2], opacity)
-                };
+                let text_color = style.text_color(is_active, opacity);
 
-                if config.tab_html_titles {
-                    let segments = sanitize_styled_segments_for_egui(parse_html_title(title));
-                    let truncated = truncate_segments(&segments, max_chars);
-                    render_segments(ui, &truncated, text_color);
-                } else {
-                    let safe_title = sanitize_egui_title_text(title);
-                    let display_title = truncate_plain(safe_title.as_ref(), max_chars);
-                    ui.label(egui::RichText::new(display_title).color(text_color));
-                }
+                ui.scope(|ui| {
+                    ui.style_mut().override_font_id = Some(style.title_font.clone());
+                    if config.tab_html_titles {
+                        let segments = sanitize_styled_segments_for_egui(parse_html_title(title));
+                        let truncated = truncate_segments(&segments, max_chars);
+                        render_segments(ui, &truncated, text_color);
+                    } else {
+                        let safe_title = sanitize_egui_title_text(title);
+                        let display_title = truncate_plain(safe_title.as_ref(), max_chars);
+                        ui.label(egui::RichText::new(display_title).color(text_color));
+                    }
+                });
 
                 // Hotkey indicator (only for tabs 1-9) - show on right side, leave space for close button
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
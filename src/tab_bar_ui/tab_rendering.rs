@@ -17,6 +17,7 @@ use crate::ui_constants::{
 
 use super::TabBarAction;
 use super::TabBarUI;
+use super::style::TabBarStyle;
 use super::title_utils::{estimate_max_chars, sanitize_egui_title_text, truncate_plain};
 
 /// Parameters shared by both `render_vertical_tab` and `render_tab_with_width`.
@@ -33,6 +34,8 @@ pub(super) struct TabRenderParams<'a> {
     pub is_bell_active: bool,
     pub custom_color: Option<[u8; 3]>,
     pub config: &'a Config,
+    /// Colors and title font resolved from `config`
+    pub style: &'a TabBarStyle,
     /// Tab height (vertical layout) or tab width (horizontal layout).
     pub tab_size: f32,
     pub tab_count: usize,
@@ -49,6 +52,7 @@ impl TabBarUI {
         is_active: bool,
         custom_color: Option<[u8; 3]>,
         config: &Config,
+        style: &TabBarStyle,
     ) -> (egui::Color32, u8) {
         let is_hovered = self.hovered_tab == Some(id);
         let is_being_dragged = self.dragging_tab == Some(id) && self.drag_in_progress;
@@ -88,14 +92,12 @@ impl TabBarUI {
                 )
             }
         } else if is_active {
-            let c = config.tab_active_background;
-            egui::Color32::from_rgba_unmultiplied(c[0], c[1], c[2], 255)
+            style.active_background
         } else if is_hovered {
-            let c = config.tab_hover_background;
-            egui::Color32::from_rgba_unmultiplied(c[0], c[1], c[2], 255)
+            style.hover_background
         } else {
-            let c = config.tab_inactive_background;
-            egui::Color32::from_rgba_unmultiplied(c[0], c[1], c[2], opacity)
+            let [r, g, b, _] = style.inactive_background.to_array();
+            egui::Color32::from_rgba_unmultiplied(r, g, b, opacity)
         };
 
         (bg_color, opacity)
@@ -118,6 +120,7 @@ impl TabBarUI {
             is_bell_active,
             custom_color,
             config,
+            style,
             tab_size: tab_height,
             tab_count,
         } = p;
@@ -125,7 +128,8 @@ impl TabBarUI {
 
         let is_hovered = self.hovered_tab == Some(id);
 
-        let (bg_color, opacity) = self.compute_tab_bg_color(id, is_active, custom_color, config);
+        let (bg_color, opacity) =
+            self.compute_tab_bg_color(id, is_active, custom_color, config, style);
 
         // Whether this inactive tab should render as outline-only (no fill)
         let outline_only = config.tab_inactive_outline_only && !is_active;
@@ -215,13 +219,7 @@ impl TabBarUI {
                     0.0
                 };
 
-                let text_color = if is_active {
-                    let c = config.tab_active_text;
-                    egui::Color32::from_rgba_unmultiplied(c[0], c[1], c[2], 255)
-                } else {
-                    let c = config.tab_inactive_text;
-                    egui::Color32::from_rgba_unmultiplied(c[0], c[1], c[2], opacity)
-                };
+                let text_color = style.text_color(is_active, opacity);
 
                 // Truncate title to fit available width
                 let close_width = if config.tab_show_close_button {
@@ -231,11 +229,14 @@ impl TabBarUI {
                 };
                 let available = (full_width - TAB_CONTENT_PAD_X * 2.0 - icon_width - close_width)
                     .max(TAB_CONTENT_PAD_X * 2.0 + TAB_CLOSE_BTN_MARGIN);
-                let base_font_id = ui.style().text_styles[&egui::TextStyle::Button].clone();
-                let max_chars = estimate_max_chars(ui, &base_font_id, available);
+                let max_chars = estimate_max_chars(ui, &style.title_font, available);
                 let safe_title = sanitize_egui_title_text(title);
                 let display_title = truncate_plain(safe_title.as_ref(), max_chars);
-                ui.label(egui::RichText::new(display_title).color(text_color));
+                ui.label(
+                    egui::RichText::new(display_title)
+                        .color(text_color)
+                        .font(style.title_font.clone()),
+                );
             });
 
             // Close button at right edge
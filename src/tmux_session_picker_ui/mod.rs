@@ -1,8 +1,12 @@
 //! tmux Session Picker UI
 //!
 //! An egui dialog that lists available tmux sessions and allows the user to:
+//! - Filter sessions by fuzzy name match
+//! - Preview a session's windows and pane counts
 //! - Attach to an existing session
 //! - Create a new session
+//!
+//! Filtering and preview fetching live in [`preview`].
 
 use crate::ui_constants::{
     TMUX_PICKER_LIST_MAX_HEIGHT, TMUX_PICKER_WINDOW_DEFAULT_HEIGHT,
@@ -11,6 +15,13 @@ use crate::ui_constants::{
 use egui::{Color32, Context, Frame, RichText, Window, epaint::Shadow};
 use std::process::Command;
 
+mod preview;
+
+pub use preview::{
+    SessionMatch, TmuxSessionPreview, TmuxWindowPreview, fetch_session_preview,
+    fuzzy_filter_sessions, parse_list_windows,
+};
+
 /// Information about a tmux session
 #[derive(Debug, Clone)]
 pub struct TmuxSessionInfo {
@@ -47,6 +58,12 @@ pub struct TmuxSessionPickerUI {
     error_message: Option<String>,
     /// Whether we've loaded sessions
     sessions_loaded: bool,
+    /// Fuzzy filter for the session list
+    filter: String,
+    /// Session whose preview is shown
+    selected_session: Option<String>,
+    /// Window preview of the selected session (or the error fetching it)
+    preview: Option<Result<TmuxSessionPreview, String>>,
 }
 
 impl TmuxSessionPickerUI {
@@ -58,6 +75,9 @@ impl TmuxSessionPickerUI {
             new_session_name: String::new(),
             error_message: None,
             sessions_loaded: false,
+            filter: String::new(),
+            selected_session: None,
+            preview: None,
         }
     }
 
@@ -67,6 +87,7 @@ impl TmuxSessionPickerUI {
         self.sessions_loaded = false; // Refresh on open
         self.error_message = None;
         self.new_session_name.clear();
+        self.filter.clear();
     }
 
     /// Hide the session picker
@@ -85,6 +106,8 @@ impl TmuxSessionPickerUI {
 
     /// Refresh the session list
     pub fn refresh_sessions(&mut self, tmux_path: &str) {
+        self.selected_session = None;
+        self.preview = None;
         match Self::list_tmux_sessions(tmux_path) {
             Ok(sessions) => {
                 self.sessions = sessions;
@@ -190,21 +213,50 @@ impl TmuxSessionPickerUI {
                 ui.heading("Existing Sessions");
                 ui.separator();
 
+                let filter_response = ui.add(
+                    egui::TextEdit::singleline(&mut self.filter)
+                        .hint_text("Filter sessions...")
+                        .desired_width(f32::INFINITY),
+                );
+                ui.add_space(4.0);
+
+                let matches = fuzzy_filter_sessions(&self.sessions, &self.filter);
+
+                // Enter in the filter box attaches to the best match
+                if filter_response.lost_focus()
+                    && ui.input(|i| i.key_pressed(egui::Key::Enter))
+                    && let Some(best) = matches.first()
+                {
+                    action = SessionPickerAction::Attach(self.sessions[best.index].name.clone());
+                    close_requested = true;
+                }
+
+                let mut clicked_session: Option<String> = None;
                 if self.sessions.is_empty() {
                     ui.label(RichText::new("No tmux sessions found").italics());
+                } else if matches.is_empty() {
+                    ui.label(RichText::new("No sessions match the filter").italics());
                 } else {
                     egui::ScrollArea::vertical()
                         .max_height(TMUX_PICKER_LIST_MAX_HEIGHT)
                         .show(ui, |ui| {
-                            for session in &self.sessions {
+                            for session in matches.iter().map(|m| &self.sessions[m.index]) {
                                 ui.horizontal(|ui| {
-                                    // Session name
+                                    // Session name (click to preview)
                                     let name_text = if session.attached {
                                         RichText::new(&session.name).strong()
                                     } else {
                                         RichText::new(&session.name)
                                     };
-                                    ui.label(name_text);
+                                    let selected = self.selected_session.as_deref()
+                                        == Some(session.name.as_str());
+                                    if ui
+                                        .selectable_label(selected, name_text)
+                                        .on_hover_text("Show windows")
+                                        .clicked()
+                                    {
+                                        clicked_session = Some(session.name.clone());
+                                    }
 
                                     // Window count
                                     ui.label(
@@ -240,6 +292,16 @@ impl TmuxSessionPickerUI {
                         });
                 }
 
+                if let Some(name) = clicked_session {
+                    self.preview = Some(fetch_session_preview(tmux_path, &name));
+                    self.selected_session = Some(name);
+                }
+
+                if let (Some(name), Some(preview)) = (&self.selected_session, &self.preview) {
+                    ui.add_space(8.0);
+                    Self::show_preview(ui, name, preview);
+                }
+
                 ui.add_space(16.0);
 
                 // Refresh button
@@ -286,6 +348,45 @@ impl TmuxSessionPickerUI {
 
         action
     }
+
+    /// Show the window list of the selected session
+    fn show_preview(ui: &mut egui::Ui, name: &str, preview: &Result<TmuxSessionPreview, String>) {
+        ui.label(RichText::new(format!("Windows in {name}")).strong());
+        match preview {
+            Ok(preview) if preview.windows.is_empty() => {
+                ui.label(RichText::new("No windows").italics());
+            }
+            Ok(preview) => {
+                for window in &preview.windows {
+                    let text = format!(
+                        "{}: {} ({} pane{})",
+                        window.index,
+                        window.name,
+                        window.pane_count,
+                        if window.pane_count == 1 { "" } else { "s" }
+                    );
+                    let text = if window.active {
+                        RichText::new(format!("{text} *")).strong()
+                    } else {
+                        RichText::new(text)
+                    };
+                    ui.label(text);
+                }
+                ui.label(
+                    RichText::new(format!(
+                        "{} pane{} total",
+                        preview.pane_count(),
+                        if preview.pane_count() == 1 { "" } else { "s" }
+                    ))
+                    .small()
+                    .weak(),
+                );
+            }
+            Err(e) => {
+                ui.colored_label(Color32::from_rgb(255, 100, 100), e);
+            }
+        }
+    }
 }
 
 impl Default for TmuxSessionPickerUI {
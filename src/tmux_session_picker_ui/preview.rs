@@ -0,0 +1,189 @@
+//! Session filtering and window previews for the tmux session picker.
+//!
+//! - [`fuzzy_filter_sessions`] ranks sessions against the picker's filter box.
+//! - [`fetch_session_preview`] runs `tmux list-windows` for one session and
+//!   [`parse_list_windows`] turns its output into a [`TmuxSessionPreview`].
+
+use super::TmuxSessionInfo;
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use std::process::Command;
+
+/// `list-windows` format: the window name goes last so colons in it survive.
+const LIST_WINDOWS_FORMAT: &str = "#{window_index}:#{window_active}:#{window_panes}:#{window_name}";
+
+/// A session that matched the filter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionMatch {
+    /// Index into the session list
+    pub index: usize,
+    /// Match score (higher is better; 0 when the filter is empty)
+    pub score: i64,
+}
+
+/// Rank `sessions` against `query`, best match first.
+///
+/// An empty query keeps every session in its original order.  Sessions with
+/// equal scores keep their original relative order.
+pub fn fuzzy_filter_sessions(sessions: &[TmuxSessionInfo], query: &str) -> Vec<SessionMatch> {
+    let query = query.trim();
+    if query.is_empty() {
+        return (0..sessions.len())
+            .map(|index| SessionMatch { index, score: 0 })
+            .collect();
+    }
+
+    let matcher = SkimMatcherV2::default().ignore_case();
+    let mut matches: Vec<SessionMatch> = sessions
+        .iter()
+        .enumerate()
+        .filter_map(|(index, session)| {
+            matcher
+                .fuzzy_match(&session.name, query)
+                .map(|score| SessionMatch { index, score })
+        })
+        .collect();
+    matches.sort_by_key(|m| std::cmp::Reverse(m.score));
+    matches
+}
+
+/// One window of a session preview.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TmuxWindowPreview {
+    /// Window index within the session
+    pub index: usize,
+    /// Window name
+    pub name: String,
+    /// Number of panes in the window
+    pub pane_count: usize,
+    /// Whether this is the session's current window
+    pub active: bool,
+}
+
+/// Windows and panes of a tmux session.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TmuxSessionPreview {
+    /// Windows in index order
+    pub windows: Vec<TmuxWindowPreview>,
+}
+
+impl TmuxSessionPreview {
+    /// Total number of panes across all windows.
+    pub fn pane_count(&self) -> usize {
+        self.windows.iter().map(|w| w.pane_count).sum()
+    }
+}
+
+/// Parse `list-windows` output produced with [`LIST_WINDOWS_FORMAT`].
+///
+/// Malformed lines are skipped.
+pub fn parse_list_windows(output: &str) -> TmuxSessionPreview {
+    let mut windows: Vec<TmuxWindowPreview> = output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(4, ':');
+            let index = parts.next()?.parse().ok()?;
+            let active = parts.next()? == "1";
+            let pane_count = parts.next()?.parse().ok()?;
+            let name = parts.next()?.to_string();
+            Some(TmuxWindowPreview {
+                index,
+                name,
+                pane_count,
+                active,
+            })
+        })
+        .collect();
+    windows.sort_by_key(|w| w.index);
+    TmuxSessionPreview { windows }
+}
+
+/// Fetch the window preview of `session_name` via `tmux list-windows`.
+pub fn fetch_session_preview(
+    tmux_path: &str,
+    session_name: &str,
+) -> Result<TmuxSessionPreview, String> {
+    // "=name" matches the session name exactly instead of by prefix.
+    let target = format!("={session_name}");
+    let output = Command::new(tmux_path)
+        .args(["list-windows", "-t", &target, "-F", LIST_WINDOWS_FORMAT])
+        .output()
+        .map_err(|e| format!("Failed to run tmux: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("tmux error: {}", stderr.trim()));
+    }
+
+    Ok(parse_list_windows(&String::from_utf8_lossy(&output.stdout)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sessions(names: &[&str]) -> Vec<TmuxSessionInfo> {
+        names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| TmuxSessionInfo {
+                id: format!("${i}"),
+                name: (*name).to_string(),
+                window_count: 1,
+                attached: false,
+            })
+            .collect()
+    }
+
+    fn ranked_names(list: &[TmuxSessionInfo], query: &str) -> Vec<String> {
+        fuzzy_filter_sessions(list, query)
+            .into_iter()
+            .map(|m| list[m.index].name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn empty_query_keeps_every_session_in_order() {
+        let list = sessions(&["work", "play", "misc"]);
+        assert_eq!(ranked_names(&list, "  "), ["work", "play", "misc"]);
+    }
+
+    #[test]
+    fn fuzzy_filter_drops_non_matches_and_ranks_contiguous_first() {
+        let list = sessions(&["docs-server-viewer", "notes", "dev-server", "dsv"]);
+        let ranked = ranked_names(&list, "dsv");
+        assert!(!ranked.contains(&"notes".to_string()));
+        assert_eq!(ranked[0], "dsv");
+        assert_eq!(ranked.len(), 3);
+    }
+
+    #[test]
+    fn fuzzy_filter_ignores_case() {
+        let list = sessions(&["Main", "other"]);
+        assert_eq!(ranked_names(&list, "main"), ["Main"]);
+    }
+
+    #[test]
+    fn parse_list_windows_builds_preview() {
+        let output = "1:0:1:logs\n0:1:3:editor: main.rs\nbogus\n2:0:x:bad\n";
+        let preview = parse_list_windows(output);
+        assert_eq!(
+            preview.windows,
+            vec![
+                TmuxWindowPreview {
+                    index: 0,
+                    name: "editor: main.rs".to_string(),
+                    pane_count: 3,
+                    active: true,
+                },
+                TmuxWindowPreview {
+                    index: 1,
+                    name: "logs".to_string(),
+                    pane_count: 1,
+                    active: false,
+                },
+            ]
+        );
+        assert_eq!(preview.pane_count(), 4);
+    }
+}
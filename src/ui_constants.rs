@@ -111,7 +111,7 @@ pub const INTEGRATIONS_BUTTON_HEIGHT: f32 = 32.0;
 pub const INTEGRATIONS_OK_BUTTON_WIDTH: f32 = 120.0;
 
 // ---------------------------------------------------------------------------
-// tmux Session Picker UI  (src/tmux_session_picker_ui.rs)
+// tmux Session Picker UI  (src/tmux_session_picker_ui/)
 // ---------------------------------------------------------------------------
 
 /// Default width of the tmux Session Picker window.
@@ -242,11 +242,12 @@ pub const FPS_OVERLAY_ROUNDING: f32 = 4.0;
 // Mouse / interaction thresholds
 // ---------------------------------------------------------------------------
 
-/// Minimum squared pixel distance a mouse must travel from press position before
-/// a click-and-hold is treated as a drag selection. Prevents accidental selections
-/// from trackpad tap-to-click jitter.
+/// Minimum pixel distance a mouse must travel from press position before a
+/// button-held motion is reported to mouse-tracking applications as a drag.
+/// Prevents trackpad tap-to-click jitter from reaching e.g. tmux as a drag.
 ///
-/// Note: compared against `dx*dx + dy*dy` (squared distance) to avoid a sqrt.
+/// The equivalent dead zone for local text selection is the
+/// `selection_drag_threshold_px` setting.
 pub const DRAG_THRESHOLD_PX: f64 = 8.0;
 
 /// Maximum pixel distance a click-and-release may travel while still being
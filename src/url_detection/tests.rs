@@ -45,6 +45,40 @@ fn test_find_url_at_position() {
     assert!(find_url_at_position(&urls, 10, 6).is_none());
 }
 
+#[test]
+fn test_hovered_link_hit_test_yields_full_target() {
+    // An OSC 8 link whose text ("docs") is far shorter than its target
+    let target = "https://example.com/guides/configuration/advanced?section=hyperlinks";
+    let mut cells = vec![crate::cell_renderer::Cell::default(); 12];
+    for (col, ch) in "see docs".chars().enumerate() {
+        cells[col].grapheme = ch.to_string();
+    }
+    for cell in &mut cells[4..8] {
+        cell.hyperlink_id = Some(7);
+    }
+    let targets = std::collections::HashMap::from([(7, target.to_string())]);
+    let links = detect_osc8_hyperlinks(&cells, 3, &targets);
+
+    for col in 4..8 {
+        let hovered = find_url_at_position(&links, col, 3).expect("link under mouse");
+        assert_eq!(hovered.url, target);
+    }
+    assert!(find_url_at_position(&links, 3, 3).is_none());
+    assert!(find_url_at_position(&links, 8, 3).is_none());
+
+    // A detected URL reports the whole address from any of its cells
+    let urls = detect_urls_in_line("go https://example.com/a/b?c=d now", 0);
+    assert_eq!(
+        find_url_at_position(&urls, 3, 0).map(|url| url.url.as_str()),
+        Some("https://example.com/a/b?c=d")
+    );
+    assert_eq!(
+        find_url_at_position(&urls, 29, 0).map(|url| url.url.as_str()),
+        Some("https://example.com/a/b?c=d")
+    );
+    assert!(find_url_at_position(&urls, 30, 0).is_none());
+}
+
 #[test]
 fn test_no_urls() {
     let text = "This line has no URLs at all";
@@ -0,0 +1,94 @@
+//! Tests for startup flags parsed by `par_term::cli`.
+
+use clap::Parser;
+use par_term::cli::{Cli, CliResult, check_config_exit_code, runtime_options, startup_result};
+use par_term::profile::{Profile, ProfileManager};
+
+fn profiles() -> ProfileManager {
+    let mut manager = ProfileManager::new();
+    manager.add(Profile::new("Work"));
+    manager.add(Profile::new("Server"));
+    manager
+}
+
+fn parse(args: &[&str]) -> Cli {
+    Cli::try_parse_from(std::iter::once("par-term").chain(args.iter().copied()))
+        .expect("args should parse")
+}
+
+#[test]
+fn profile_and_cwd_flags_reach_runtime_options() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let dir_arg = dir.path().to_string_lossy().into_owned();
+    let cli = parse(&["--profile", "work", "--cwd", &dir_arg]);
+    assert_eq!(cli.profile.as_deref(), Some("work"));
+
+    match startup_result(cli, &profiles()) {
+        CliResult::Continue(options) => {
+            assert_eq!(options.profile.as_deref(), Some("work"));
+            assert_eq!(options.cwd.as_deref(), Some(dir_arg.as_str()));
+        }
+        CliResult::Exit(code) => panic!("unexpected exit {code}"),
+    }
+
+    // Neither flag: nothing to apply
+    let options = runtime_options(parse(&[]), &ProfileManager::new()).expect("no flags");
+    assert_eq!(options.profile, None);
+    assert_eq!(options.cwd, None);
+}
+
+#[test]
+fn cwd_flag_is_made_absolute() {
+    let options = runtime_options(parse(&["--cwd", "."]), &ProfileManager::new())
+        .expect("current directory exists");
+    let cwd = options.cwd.expect("cwd set");
+    assert!(std::path::Path::new(&cwd).is_absolute(), "{cwd}");
+}
+
+#[test]
+fn unknown_profile_is_an_error() {
+    let err = runtime_options(parse(&["--profile", "Missing"]), &profiles())
+        .expect_err("profile does not exist");
+    let message = err.to_string();
+    assert!(message.contains("Profile 'Missing' not found"), "{message}");
+    assert!(
+        message.contains("Work") && message.contains("Server"),
+        "{message}"
+    );
+
+    assert!(matches!(
+        startup_result(parse(&["--profile", "Missing"]), &profiles()),
+        CliResult::Exit(2)
+    ));
+}
+
+#[test]
+fn missing_cwd_is_an_error() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let missing = dir.path().join("nope");
+    let err = runtime_options(
+        parse(&["--cwd", &missing.to_string_lossy()]),
+        &ProfileManager::new(),
+    )
+    .expect_err("directory does not exist");
+    assert!(err.to_string().contains("does not exist"), "{err}");
+}
+
+#[test]
+fn check_config_fails_only_on_errors() {
+    assert!(parse(&["--check-config"]).check_config);
+    assert!(!parse(&[]).check_config);
+
+    let mut config = par_term::config::Config::default();
+    config.window.window_opacity = 2.0;
+    config.shader.custom_shader = Some("missing.glsl".to_string());
+    let dir = tempfile::tempdir().expect("tempdir");
+    let warnings = config.validate_with(dir.path(), None);
+    assert_eq!(check_config_exit_code(&warnings, true), 1);
+
+    config.window.window_opacity = 1.0;
+    let warnings = config.validate_with(dir.path(), None);
+    assert_eq!(warnings.len(), 1, "{warnings:?}");
+    assert_eq!(check_config_exit_code(&warnings, true), 0);
+    assert_eq!(check_config_exit_code(&[], false), 1);
+}
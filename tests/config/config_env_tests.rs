@@ -87,6 +87,37 @@ fn test_substitute_variables_default_value_not_used_when_set() {
     unsafe { remove_test_var("PAR_TERM_SET_WITH_DEFAULT") };
 }
 
+#[test]
+fn test_substitute_variables_default_value_with_escaped_brace() {
+    unsafe { remove_test_var("PAR_TERM_MISSING_BRACE_DEFAULT") };
+    let result = substitute_variables(r"badge: ${PAR_TERM_MISSING_BRACE_DEFAULT:-{none\}}");
+    assert_eq!(result, "badge: {none}");
+}
+
+#[test]
+fn test_substitute_variables_alt_value_when_set() {
+    unsafe { set_test_var("PAR_TERM_SET_WITH_ALT", "production") };
+    let result = substitute_variables("badge: ${PAR_TERM_SET_WITH_ALT:+LIVE} host");
+    assert_eq!(result, "badge: LIVE host");
+    unsafe { remove_test_var("PAR_TERM_SET_WITH_ALT") };
+}
+
+#[test]
+fn test_substitute_variables_alt_value_empty_when_unset() {
+    unsafe { remove_test_var("PAR_TERM_MISSING_WITH_ALT") };
+    let result = substitute_variables("badge: ${PAR_TERM_MISSING_WITH_ALT:+LIVE} host");
+    assert_eq!(result, "badge:  host");
+}
+
+#[test]
+fn test_substitute_variables_bare_dollar_is_literal() {
+    // Only the brace form is substituted, so `$VAR` in shell snippets survives
+    unsafe { set_test_var("PAR_TERM_TEST_BARE", "expanded") };
+    let result = substitute_variables("command: echo $PAR_TERM_TEST_BARE");
+    assert_eq!(result, "command: echo $PAR_TERM_TEST_BARE");
+    unsafe { remove_test_var("PAR_TERM_TEST_BARE") };
+}
+
 #[test]
 fn test_substitute_variables_escaped_dollar() {
     // $${VAR} should produce the literal ${VAR}
@@ -234,6 +265,19 @@ fn test_substitute_non_allowlisted_var_blocked() {
     unsafe { remove_test_var("SECRET_API_KEY_TEST_M3") };
 }
 
+#[test]
+fn test_substitute_non_allowlisted_var_with_default_or_alt_blocked() {
+    // Neither form may reveal whether a non-allowlisted variable is set
+    unsafe { set_test_var("SECRET_API_KEY_TEST_BRACES", "super_secret") };
+    let input = "a: ${SECRET_API_KEY_TEST_BRACES:-fallback}\nb: ${SECRET_API_KEY_TEST_BRACES:+set}";
+    assert_eq!(substitute_variables(input), input);
+    assert_eq!(
+        substitute_variables_with_allowlist("b: ${SECRET_API_KEY_TEST_BRACES:+set}", true),
+        "b: set"
+    );
+    unsafe { remove_test_var("SECRET_API_KEY_TEST_BRACES") };
+}
+
 #[test]
 fn test_substitute_par_term_prefix_resolves() {
     unsafe { set_test_var("PAR_TERM_MY_SETTING", "custom_value") };
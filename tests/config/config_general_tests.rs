@@ -525,6 +525,8 @@ fn test_config_advanced_mouse_defaults() {
     assert!(config.mouse.option_click_moves_cursor);
     // Focus follows mouse should be disabled by default (opt-in)
     assert!(!config.mouse.focus_follows_mouse);
+    assert!(!config.mouse.pane_focus_follows_mouse);
+    assert_eq!(config.mouse.pane_focus_hover_delay_ms, 150);
     // Horizontal scroll reporting should be enabled by default
     assert!(config.mouse.report_horizontal_scroll);
 }
@@ -534,11 +536,15 @@ fn test_config_advanced_mouse_yaml_deserialization() {
     let yaml = r#"
 option_click_moves_cursor: false
 focus_follows_mouse: true
+pane_focus_follows_mouse: true
+pane_focus_hover_delay_ms: 300
 report_horizontal_scroll: false
 "#;
     let config: Config = serde_yaml_ng::from_str(yaml).unwrap();
     assert!(!config.mouse.option_click_moves_cursor);
     assert!(config.mouse.focus_follows_mouse);
+    assert!(config.mouse.pane_focus_follows_mouse);
+    assert_eq!(config.mouse.pane_focus_hover_delay_ms, 300);
     assert!(!config.mouse.report_horizontal_scroll);
 }
 
@@ -561,6 +567,18 @@ fn test_config_advanced_mouse_yaml_serialization() {
     assert!(yaml.contains("report_horizontal_scroll: false"));
 }
 
+#[test]
+fn test_config_scroll_lines_per_wheel_and_natural_scrolling() {
+    let yaml = r#"
+scroll_lines_per_wheel: 1.5
+natural_scroll_direction: true
+"#;
+    let config: Config = serde_yaml_ng::from_str(yaml).unwrap();
+    assert_eq!(config.mouse.mouse_scroll_speed, 1.5);
+    assert!(config.mouse.natural_scroll_direction);
+    assert!(!Config::default().mouse.natural_scroll_direction);
+}
+
 #[test]
 fn test_config_advanced_mouse_partial_yaml() {
     // Test that default values are used for missing fields
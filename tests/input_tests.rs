@@ -5,7 +5,7 @@
 // These tests verify the InputHandler can be created and basic state management works.
 // More comprehensive testing would be done through integration tests or manual testing.
 
-use par_term::config::OptionKeyMode;
+use par_term::config::{BackspaceSends, OptionKeyMode};
 use par_term::input::InputHandler;
 
 #[test]
@@ -307,3 +307,89 @@ fn test_ctrl_alt_letter_mode0_sends_esc_prefixed_control_byte() {
         "Ctrl+Alt+P in mode 0 must send ESC-prefixed Ctrl+P, not collapse to plain Ctrl+P"
     );
 }
+
+#[test]
+fn test_alt_sends_escape_prefixes_alt_letter() {
+    // Alt+b is emacs backward-word: ESC b in ESC-prefix mode, whatever the
+    // per-side Option/Alt modes say
+    let mut handler = InputHandler::new();
+    handler.update_option_key_modes(OptionKeyMode::Normal, OptionKeyMode::Meta);
+    handler.update_alt_sends_escape(true);
+    handler.update_modifiers(Modifiers::from(ModifiersState::ALT));
+
+    let result = handler.handle_key_event(char_event("b", KeyCode::KeyB));
+    assert_eq!(result, Some(b"\x1bb".to_vec()));
+}
+
+#[test]
+fn test_alt_sends_escape_prefixes_alt_backspace() {
+    // Alt+Backspace is backward-kill-word: ESC DEL, not a bare DEL
+    let mut handler = InputHandler::new();
+    handler.update_option_key_modes(OptionKeyMode::Meta, OptionKeyMode::Meta);
+    handler.update_alt_sends_escape(true);
+    handler.update_modifiers(Modifiers::from(ModifiersState::ALT));
+
+    let event = make_key_event(
+        Key::Named(NamedKey::Backspace),
+        PhysicalKey::Code(KeyCode::Backspace),
+    );
+    assert_eq!(handler.handle_key_event(event), Some(b"\x1b\x7f".to_vec()));
+
+    handler.update_alt_sends_escape(false);
+    let event = make_key_event(
+        Key::Named(NamedKey::Backspace),
+        PhysicalKey::Code(KeyCode::Backspace),
+    );
+    assert_eq!(
+        handler.handle_key_event(event),
+        Some(vec![0x7f]),
+        "Meta mode leaves Alt+Backspace unprefixed"
+    );
+}
+
+#[test]
+fn test_alt_letter_sends_raw_byte_without_escape_prefix() {
+    let mut handler = InputHandler::new();
+    handler.update_option_key_modes(OptionKeyMode::Normal, OptionKeyMode::Normal);
+    handler.update_modifiers(Modifiers::from(ModifiersState::ALT));
+
+    let result = handler.handle_key_event(char_event("b", KeyCode::KeyB));
+    assert_eq!(result, Some(b"b".to_vec()));
+}
+
+#[test]
+fn test_backspace_sends_del_by_default() {
+    let mut handler = InputHandler::new();
+    handler.update_modifiers(Modifiers::default());
+
+    let event = make_key_event(
+        Key::Named(NamedKey::Backspace),
+        PhysicalKey::Code(KeyCode::Backspace),
+    );
+    assert_eq!(handler.handle_key_event(event), Some(vec![0x7f]));
+}
+
+#[test]
+fn test_backspace_sends_bs_when_configured() {
+    let mut handler = InputHandler::new();
+    handler.update_backspace_sends(BackspaceSends::Backspace);
+
+    // The configured byte is sent regardless of modifiers
+    for mods in [
+        ModifiersState::empty(),
+        ModifiersState::CONTROL,
+        ModifiersState::SHIFT,
+    ] {
+        handler.update_modifiers(Modifiers::from(mods));
+        let event = make_key_event(
+            Key::Named(NamedKey::Backspace),
+            PhysicalKey::Code(KeyCode::Backspace),
+        );
+        assert_eq!(
+            handler.handle_key_event(event),
+            Some(vec![0x08]),
+            "Backspace with {:?} should send BS (0x08)",
+            mods
+        );
+    }
+}
@@ -19,6 +19,7 @@ fn make_integration_config(args: Vec<String>) -> ScriptConfig {
         allow_write_text: false,
         allow_run_command: false,
         allow_change_config: false,
+        allow_read_selection: false,
         write_text_rate_limit: 0,
         run_command_rate_limit: 0,
     }
@@ -1,6 +1,27 @@
+use par_term::config::scripting::ScriptConfig;
 use par_term::scripting::protocol::{ScriptCommand, ScriptEvent, ScriptEventData};
 use std::collections::HashMap;
 
+fn selection_script(allow_read_selection: bool) -> ScriptConfig {
+    ScriptConfig {
+        name: "selection-reader".to_string(),
+        enabled: true,
+        script_path: "reader.py".to_string(),
+        args: Vec::new(),
+        auto_start: false,
+        restart_policy: par_term::config::automation::RestartPolicy::Never,
+        restart_delay_ms: 0,
+        subscriptions: Vec::new(),
+        env_vars: HashMap::new(),
+        allow_write_text: false,
+        allow_run_command: false,
+        allow_change_config: false,
+        allow_read_selection,
+        write_text_rate_limit: 0,
+        run_command_rate_limit: 0,
+    }
+}
+
 // ─── ScriptEvent serialization tests ───
 
 #[test]
@@ -252,6 +273,92 @@ fn test_command_deserialization_clear_panel() {
     assert!(matches!(cmd, ScriptCommand::ClearPanel {}));
 }
 
+#[test]
+fn test_command_deserialization_get_selection() {
+    let json = r#"{"type": "GetSelection"}"#;
+    let cmd: ScriptCommand = serde_json::from_str(json).unwrap();
+    assert!(matches!(cmd, ScriptCommand::GetSelection {}));
+    assert!(cmd.requires_permission());
+    assert_eq!(cmd.permission_flag_name(), Some("allow_read_selection"));
+}
+
+#[test]
+fn test_selection_event_returns_selected_text() {
+    let event = ScriptEvent::selection(Some("cargo build --release".to_string()));
+    let json = serde_json::to_string(&event).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(parsed["kind"], "selection");
+    assert_eq!(parsed["data"]["data_type"], "Selection");
+    assert_eq!(parsed["data"]["text"], "cargo build --release");
+}
+
+#[test]
+fn test_get_selection_denied_without_allow_read_selection() {
+    let script = selection_script(false);
+    let mut read = false;
+    let reply = ScriptEvent::selection_reply(Some(&script), || {
+        read = true;
+        Some("secret-token".to_string())
+    });
+
+    assert!(reply.is_none());
+    assert!(
+        !read,
+        "a denied script must not cause the selection to be read"
+    );
+}
+
+#[test]
+fn test_get_selection_denied_for_unknown_script() {
+    let reply = ScriptEvent::selection_reply(None, || Some("secret-token".to_string()));
+    assert!(reply.is_none());
+}
+
+#[test]
+fn test_get_selection_allowed_returns_selection() {
+    let script = selection_script(true);
+    let reply = ScriptEvent::selection_reply(Some(&script), || Some("ls -la".to_string()));
+
+    assert_eq!(
+        reply,
+        Some(ScriptEvent::selection(Some("ls -la".to_string())))
+    );
+    let reply = ScriptEvent::selection_reply(Some(&script), || None).unwrap();
+    assert_eq!(
+        reply.data,
+        ScriptEventData::Selection {
+            text: String::new()
+        }
+    );
+}
+
+#[test]
+fn test_hyperlink_hover_event_serialization() {
+    let event = ScriptEvent::hyperlink_hover(Some("https://example.com".to_string()), None);
+    let json = serde_json::to_string(&event).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(parsed["kind"], "hyperlink_hover");
+    assert_eq!(parsed["data"]["data_type"], "HyperlinkHover");
+    assert_eq!(parsed["data"]["url"], "https://example.com");
+
+    let left = ScriptEvent::hyperlink_hover(None, None);
+    let parsed = serde_json::to_value(&left).unwrap();
+    assert!(parsed["data"]["url"].is_null());
+}
+
+#[test]
+fn test_selection_event_is_empty_without_selection() {
+    let event = ScriptEvent::selection(None);
+    assert_eq!(
+        event.data,
+        ScriptEventData::Selection {
+            text: String::new()
+        }
+    );
+}
+
 #[test]
 fn test_unknown_command_type_returns_error() {
     let json = r#"{"type": "DoSomethingUnknown", "data": "irrelevant"}"#;
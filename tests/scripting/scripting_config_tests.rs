@@ -27,6 +27,7 @@ fn test_script_config_yaml_roundtrip() {
         allow_write_text: false,
         allow_run_command: false,
         allow_change_config: false,
+        allow_read_selection: false,
         write_text_rate_limit: 0,
         run_command_rate_limit: 0,
     };
@@ -131,6 +132,7 @@ fn test_config_with_scripts_yaml_roundtrip() {
             allow_write_text: false,
             allow_run_command: false,
             allow_change_config: false,
+            allow_read_selection: false,
             write_text_rate_limit: 0,
             run_command_rate_limit: 0,
         }],
@@ -187,6 +189,10 @@ script_path: /bin/test
         !script.allow_change_config,
         "allow_change_config should default to false"
     );
+    assert!(
+        !script.allow_read_selection,
+        "allow_read_selection should default to false"
+    );
     assert_eq!(
         script.write_text_rate_limit, 0,
         "write_text_rate_limit should default to 0"
@@ -205,6 +211,7 @@ script_path: /bin/test
 allow_write_text: true
 allow_run_command: true
 allow_change_config: true
+allow_read_selection: true
 write_text_rate_limit: 20
 run_command_rate_limit: 5
 "#;
@@ -212,6 +219,7 @@ run_command_rate_limit: 5
     assert!(script.allow_write_text);
     assert!(script.allow_run_command);
     assert!(script.allow_change_config);
+    assert!(script.allow_read_selection);
     assert_eq!(script.write_text_rate_limit, 20);
     assert_eq!(script.run_command_rate_limit, 5);
 }
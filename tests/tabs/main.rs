@@ -1,10 +1,11 @@
 //! Integration tests for tab bar UI and tab management.
 //!
 //! Covers: context menu lifecycle, tab interactions and drag state,
-//! rendering and visibility, custom tab colors, and tab stability.
+//! rendering and visibility, custom tab colors, chrome style, and tab stability.
 
 mod tab_bar_context_menu_tests;
 mod tab_bar_interaction_tests;
 mod tab_bar_rendering_tests;
+mod tab_bar_style_tests;
 mod tab_color_tests;
 mod tab_stability_tests;
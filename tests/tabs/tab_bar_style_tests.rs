@@ -0,0 +1,64 @@
+//! Tests for resolving tab bar chrome style from the config
+
+use par_term::config::{Config, TabFontFamily};
+use par_term::tab_bar_ui::TabBarStyle;
+
+fn button_font() -> egui::FontId {
+    egui::FontId::proportional(14.0)
+}
+
+#[test]
+fn test_style_maps_configured_colors() {
+    let config = Config {
+        tab_bar_background: [10, 20, 30],
+        tab_active_background: [40, 50, 60],
+        tab_inactive_background: [70, 80, 90],
+        tab_hover_background: [1, 2, 3],
+        tab_active_text: [250, 240, 230],
+        tab_inactive_text: [120, 110, 100],
+        ..Config::default()
+    };
+    let style = TabBarStyle::from_config(&config, &button_font());
+
+    assert_eq!(style.bar_background, egui::Color32::from_rgb(10, 20, 30));
+    assert_eq!(style.active_background, egui::Color32::from_rgb(40, 50, 60));
+    assert_eq!(
+        style.inactive_background,
+        egui::Color32::from_rgb(70, 80, 90)
+    );
+    assert_eq!(style.hover_background, egui::Color32::from_rgb(1, 2, 3));
+    assert_eq!(
+        style.text_color(true, 100),
+        egui::Color32::from_rgb(250, 240, 230),
+        "active titles are never dimmed"
+    );
+    assert_eq!(
+        style.text_color(false, 128),
+        egui::Color32::from_rgba_unmultiplied(120, 110, 100, 128)
+    );
+}
+
+#[test]
+fn test_style_title_font_defaults_to_button_font() {
+    let style = TabBarStyle::from_config(&Config::default(), &button_font());
+    assert_eq!(style.title_font, button_font());
+}
+
+#[test]
+fn test_style_title_font_from_config() {
+    let config = Config {
+        tab_font_size: 11.5,
+        tab_font_family: TabFontFamily::Monospace,
+        ..Config::default()
+    };
+    let style = TabBarStyle::from_config(&config, &button_font());
+    assert_eq!(style.title_font, egui::FontId::monospace(11.5));
+
+    // Family alone keeps the default size
+    let config = Config {
+        tab_font_family: TabFontFamily::Monospace,
+        ..Config::default()
+    };
+    let style = TabBarStyle::from_config(&config, &button_font());
+    assert_eq!(style.title_font, egui::FontId::monospace(14.0));
+}